@@ -6,5 +6,8 @@ cargo build
 cargo test --lib
 
 # All tests.
+(cd rust-jni-core && cargo test --features libjvm)
+(cd rust-jni-java && cargo test --features rust-jni-core/libjvm)
 (cd rust-jni && cargo test --features libjvm)
 (cd java && cargo test)
+(cd testkit && cargo test --features libjvm)
@@ -1,6 +1,22 @@
 set -e
 
 cargo build --verbose
+# The core of the library must build without the standard library parts.
+# Without the `std` feature the library is `#![no_std]`, so this also checks that the core
+# doesn't use `std`. Building for targets without `std`, like `thumbv7em-none-eabihf`, needs
+# `jni-sys` and `cesu8` versions that support `no_std`.
+(cd rust-jni-core && cargo build --verbose --no-default-features)
+(cd rust-jni-java && cargo build --verbose --no-default-features)
+(cd rust-jni && cargo build --verbose --no-default-features)
+(cd rust-jni && cargo build --verbose --no-default-features --features java-util,stats)
+# The core of the library must build without the raw JNI accessors.
+(cd rust-jni && cargo build --verbose --features deny-unsafe-passthrough)
+# Native-method libraries can depend on the core alone.
+(cd rust-jni-core && cargo build --verbose)
+# The library must build without the Java VM creation API.
+(cd rust-jni && cargo build --verbose --features no-invocation)
+# The test mocks must build for downstream crates.
+(cd rust-jni && cargo build --verbose --features testing)
 
 # Unit tests only.
 cargo test --verbose --lib
@@ -8,5 +24,14 @@ cargo test --verbose --lib
 # All tests.
 # TOOD(https://github.com/rust-lang/cargo/issues/5015): stop cd-ing into individual
 # crates once the features bug is fixed.
+(cd rust-jni-core && cargo test --verbose --features libjvm)
+(cd rust-jni-java && cargo test --verbose --features rust-jni-core/libjvm)
 (cd rust-jni && cargo test --verbose --features libjvm)
+# Tests that create a Java VM are skipped without the Java VM creation API.
+(cd rust-jni && cargo test --verbose --features libjvm,no-invocation)
 (cd java && cargo test --verbose)
+(cd testkit && cargo test --verbose --features libjvm)
+# The generator tests also compile generated code against the in-repo `rust-jni`.
+(cd generator && cargo test --verbose)
+# Code generated by `java_generate!` must build in a 2021 edition crate.
+(cd examples/edition-2021 && cargo build --verbose)
@@ -0,0 +1,68 @@
+//! Code generated by `java_generate!` must build in a 2021 edition crate.
+
+pub mod a {
+    pub mod b {
+        use rust_jni_generator::java_generate;
+
+        java_generate! {
+            package a.b;
+
+            import java.lang.Object;
+            import java.lang.String;
+
+            public interface TestInterface {
+                @RustName(interface_method)
+                long interfaceMethod(int value);
+            }
+
+            public class TestClass implements TestInterface {
+                @RustName(init)
+                public TestClass();
+
+                @RustName(to_java_string)
+                public String toJavaString(Object value);
+
+                @RustName(interface_method)
+                long interfaceMethod(int value);
+
+                @RustName(create)
+                public static TestClass create(TestClass value);
+
+                @RustName(native_method)
+                public native long nativeMethod(TestClass value) {
+                    let _ = value;
+                    Ok(0)
+                };
+            }
+        }
+    }
+}
+
+pub mod c {
+    use rust_jni_generator::java_generate;
+
+    java_generate! {
+        package c;
+
+        import a.b.TestClass;
+        import a.b.TestInterface;
+        import java.lang.String;
+        import java.lang.Throwable;
+
+        public class TestClassExtended extends TestClass implements TestInterface {
+            @RustName(interface_method)
+            long interfaceMethod(int value);
+
+            @RustName(throwable)
+            public Throwable throwable(String message);
+        }
+
+        metadata {
+            class a.b.TestClass implements a.b.TestInterface;
+            interface a.b.TestInterface {
+                @RustName(interface_method)
+                long interfaceMethod(int value);
+            }
+        }
+    }
+}
@@ -0,0 +1,345 @@
+#[cfg(test)]
+use super::assert_tokens_equals;
+use generate::*;
+use proc_macro2::*;
+
+/// Attributes of a hand-written class wrapper.
+#[derive(Debug)]
+struct JavaClassWrapper {
+    class: Ident,
+    signature: String,
+    super_class: Option<TokenStream>,
+}
+
+pub fn derive_java_class_wrapper(input: TokenStream) -> TokenStream {
+    let JavaClassWrapper {
+        class,
+        signature,
+        super_class,
+    } = parse_java_class_wrapper(input);
+    let object = quote! {::rust_jni::java::lang::Object};
+    let mut transitive_extends = vec![];
+    let super_class = match super_class {
+        Some(super_class) => {
+            transitive_extends.push(super_class.clone());
+            if !is_object(&super_class) {
+                transitive_extends.push(object);
+            }
+            super_class
+        }
+        None => {
+            transitive_extends.push(object.clone());
+            object
+        }
+    };
+    let full_signature = Literal::string(&format!("L{};", signature));
+    let mut tokens = generate_class_type_implementations(
+        &class,
+        &super_class,
+        &full_signature,
+        &transitive_extends,
+    );
+    tokens.extend(generate_class_comparison_implementations(&class));
+    tokens
+}
+
+fn parse_java_class_wrapper(input: TokenStream) -> JavaClassWrapper {
+    let tokens = input.into_iter().collect::<Vec<_>>();
+    let mut attribute = None;
+    let mut class = None;
+    let mut has_object_field = false;
+    let mut index = 0;
+    while index < tokens.len() {
+        match &tokens[index] {
+            TokenTree::Punct(punct) if punct.as_char() == '#' && class.is_none() => {
+                if let Some(TokenTree::Group(group)) = tokens.get(index + 1) {
+                    if let Some(arguments) = java_attribute_arguments(group) {
+                        attribute = Some(arguments);
+                    }
+                    index += 1;
+                }
+            }
+            token if is_identifier(token, "struct") => match tokens.get(index + 1) {
+                Some(TokenTree::Ident(identifier)) => {
+                    class = Some(identifier.clone());
+                    index += 1;
+                }
+                token => panic!("Expected struct name, got {:?}.", token),
+            },
+            token if is_identifier(token, "enum") || is_identifier(token, "union") => {
+                panic!("JavaClassWrapper can only be derived for structs.")
+            }
+            TokenTree::Group(group) if class.is_some() => {
+                has_object_field = has_object_field || is_object_field(group.stream());
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    let class = class.expect("JavaClassWrapper can only be derived for structs.");
+    if !has_object_field {
+        panic!("Expected struct {} to have an \"object\" field.", class);
+    }
+    let (signature, super_class) = parse_java_attribute(attribute.unwrap_or_default());
+    JavaClassWrapper {
+        class,
+        signature: signature.expect("Expected #[java(class = \"path/to/Class\")] attribute."),
+        super_class,
+    }
+}
+
+/// Get the arguments of a `java(...)` attribute.
+fn java_attribute_arguments(group: &Group) -> Option<TokenStream> {
+    if group.delimiter() != Delimiter::Bracket {
+        return None;
+    }
+    let tokens = group.stream().into_iter().collect::<Vec<_>>();
+    if tokens.is_empty() || !is_identifier(&tokens[0], "java") {
+        return None;
+    }
+    match tokens.get(1) {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            Some(group.stream())
+        }
+        token => panic!("Expected java attribute arguments, got {:?}.", token),
+    }
+}
+
+fn parse_java_attribute(input: TokenStream) -> (Option<String>, Option<TokenStream>) {
+    let mut signature = None;
+    let mut super_class = None;
+    let tokens = input.into_iter().collect::<Vec<_>>();
+    for argument in tokens.split(|token| is_punctuation(token, ',')) {
+        if argument.is_empty() {
+            continue;
+        }
+        if argument.len() < 3 || !is_punctuation(&argument[1], '=') {
+            panic!(
+                "Expected java attribute argument in form \"name = value\", got {:?}.",
+                argument
+            );
+        }
+        let value = &argument[2..];
+        match &argument[0] {
+            token if is_identifier(token, "class") => {
+                signature = Some(parse_class_signature(value));
+            }
+            token if is_identifier(token, "extends") => {
+                super_class = Some(value.iter().cloned().collect());
+            }
+            token => panic!("Unknown java attribute argument {}.", token),
+        }
+    }
+    (signature, super_class)
+}
+
+fn parse_class_signature(value: &[TokenTree]) -> String {
+    match value {
+        [TokenTree::Literal(literal)] => {
+            let literal = literal.to_string();
+            if literal.len() > 2 && literal.starts_with('"') && literal.ends_with('"') {
+                literal[1..literal.len() - 1].to_owned()
+            } else {
+                panic!("Expected class name string, got {}.", literal)
+            }
+        }
+        value => panic!("Expected class name string, got {:?}.", value),
+    }
+}
+
+fn is_object_field(fields: TokenStream) -> bool {
+    let tokens = fields.into_iter().collect::<Vec<_>>();
+    tokens
+        .windows(2)
+        .any(|tokens| is_identifier(&tokens[0], "object") && is_punctuation(&tokens[1], ':'))
+}
+
+fn is_identifier(token: &TokenTree, name: &str) -> bool {
+    match token {
+        TokenTree::Ident(identifier) => identifier == name,
+        _ => false,
+    }
+}
+
+fn is_punctuation(token: &TokenTree, value: char) -> bool {
+    match token {
+        TokenTree::Punct(punct) => punct.spacing() == Spacing::Alone && punct.as_char() == value,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod derive_java_class_wrapper_tests {
+    use super::*;
+
+    fn reference(class: TokenStream, target: TokenStream) -> TokenStream {
+        quote! {
+            impl<'a> ::rust_jni::__generator::convert::AsRef<#target<'a>> for #class<'a> {
+                fn as_ref(&self) -> &#target<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<#target<'a>> for #class<'a> {
+                fn borrow(&self) -> &#target<'a> {
+                    self
+                }
+            }
+        }
+    }
+
+    fn expected(
+        class: TokenStream,
+        super_class: TokenStream,
+        references: TokenStream,
+    ) -> TokenStream {
+        quote! {
+            impl<'a> ::rust_jni::JniSignature for #class<'a> {
+                fn signature() -> &'static str {
+                    "La/b/TestClass1;"
+                }
+            }
+
+            impl<'a> ::rust_jni::FromObject<'a> for #class<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <#super_class as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ops::Deref for #class<'a> {
+                type Target = #super_class<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::From<#class<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: #class<'a>) -> Self {
+                    value.object.into()
+                }
+            }
+
+            impl<'env> ::rust_jni::Detachable for #class<'env> {
+                type Attached<'a> = #class<'a>;
+            }
+
+            #references
+
+            impl<'a> ::rust_jni::__generator::fmt::Display for #class<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
+                }
+            }
+
+            impl<'a, T> PartialEq<T> for #class<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
+                fn eq(&self, other: &T) -> bool {
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
+                }
+            }
+
+            impl<'a> Eq for #class<'a> {}
+        }
+    }
+
+    #[test]
+    fn object_subclass() {
+        let input = quote! {
+            #[derive(Debug)]
+            #[java(class = "a/b/TestClass1")]
+            pub struct TestClass1<'env> {
+                object: ::rust_jni::java::lang::Object<'env>,
+            }
+        };
+        let references = reference(quote! {TestClass1}, quote! {::rust_jni::java::lang::Object});
+        assert_tokens_equals(
+            derive_java_class_wrapper(input),
+            expected(
+                quote! {TestClass1},
+                quote! {::rust_jni::java::lang::Object},
+                references,
+            ),
+        );
+    }
+
+    #[test]
+    fn extends() {
+        let input = quote! {
+            #[java(class = "a/b/TestClass1", extends = c::d::TestClass2)]
+            struct TestClass1<'env> {
+                object: c::d::TestClass2<'env>,
+            }
+        };
+        let mut references = reference(quote! {TestClass1}, quote! {c::d::TestClass2});
+        references.extend(reference(
+            quote! {TestClass1},
+            quote! {::rust_jni::java::lang::Object},
+        ));
+        assert_tokens_equals(
+            derive_java_class_wrapper(input),
+            expected(quote! {TestClass1}, quote! {c::d::TestClass2}, references),
+        );
+    }
+
+    #[test]
+    fn extends_object() {
+        let input = quote! {
+            #[java(extends = java::lang::Object, class = "a/b/TestClass1",)]
+            struct TestClass1<'env> {
+                object: java::lang::Object<'env>,
+            }
+        };
+        let references = reference(quote! {TestClass1}, quote! {java::lang::Object});
+        assert_tokens_equals(
+            derive_java_class_wrapper(input),
+            expected(quote! {TestClass1}, quote! {java::lang::Object}, references),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected #[java(class = \"path/to/Class\")] attribute")]
+    fn no_class() {
+        let input = quote! {
+            struct TestClass1<'env> {
+                object: java::lang::Object<'env>,
+            }
+        };
+        derive_java_class_wrapper(input);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown java attribute argument")]
+    fn unknown_argument() {
+        let input = quote! {
+            #[java(class = "a/b/TestClass1", implements = c::d::TestInterface1)]
+            struct TestClass1<'env> {
+                object: java::lang::Object<'env>,
+            }
+        };
+        derive_java_class_wrapper(input);
+    }
+
+    #[test]
+    #[should_panic(expected = "to have an \"object\" field")]
+    fn no_object_field() {
+        let input = quote! {
+            #[java(class = "a/b/TestClass1")]
+            struct TestClass1<'env> {
+                value: java::lang::Object<'env>,
+            }
+        };
+        derive_java_class_wrapper(input);
+    }
+
+    #[test]
+    #[should_panic(expected = "can only be derived for structs")]
+    fn not_struct() {
+        let input = quote! {
+            #[java(class = "a/b/TestClass1")]
+            enum TestClass1 {}
+        };
+        derive_java_class_wrapper(input);
+    }
+}
@@ -1,7 +1,6 @@
 #[cfg(test)]
 use super::assert_tokens_equals;
 use proc_macro2::*;
-use std::iter;
 use std::iter::FromIterator;
 
 #[derive(Debug)]
@@ -12,6 +11,25 @@ pub struct ClassMethod {
     pub argument_names: Vec<Ident>,
     pub argument_types: Vec<TokenStream>,
     pub public: bool,
+    pub monitor_guard: bool,
+    pub map_result: Option<MapResult>,
+    /// Convert a `null` result into a `NullPointerException`.
+    pub nonnull_result: bool,
+    /// The feature that enables an unsafe `_unchecked` variant of the method.
+    pub unchecked_feature: Option<String>,
+    /// The Java feature release that introduced the method, checked before calling it.
+    pub since: Option<Literal>,
+    /// Rust visibility set with `@rust_visibility`, overrides the Java visibility.
+    pub visibility: Option<TokenStream>,
+    /// The exception class of `@throws`, whose exceptions are returned as a typed error.
+    pub throws: Option<TokenStream>,
+}
+
+/// A user function applied to the result of a Java method call.
+#[derive(Debug)]
+pub struct MapResult {
+    pub function: TokenStream,
+    pub result_type: TokenStream,
 }
 
 #[derive(Debug)]
@@ -39,9 +57,11 @@ pub struct NativeMethod {
     pub return_type: TokenStream,
     pub argument_names: Vec<Ident>,
     pub argument_types: Vec<TokenStream>,
-    pub argument_types_no_lifetime: Vec<TokenStream>,
     pub public: bool,
-    pub code: TokenStream,
+    /// The Rust body, `None` for methods implemented with the `<Class>Natives` trait.
+    pub code: Option<TokenStream>,
+    /// Rust visibility set with `@rust_visibility`, overrides the Java visibility.
+    pub visibility: Option<TokenStream>,
 }
 
 #[derive(Debug)]
@@ -50,6 +70,47 @@ pub struct Constructor {
     pub argument_names: Vec<Ident>,
     pub argument_types: Vec<TokenStream>,
     pub public: bool,
+    /// The Java feature release that introduced the constructor, checked before calling it.
+    pub since: Option<Literal>,
+    /// Rust visibility set with `@rust_visibility`, overrides the Java visibility.
+    pub visibility: Option<TokenStream>,
+}
+
+/// `extern "C"` functions that expose a class to C and C++ code, see the `c_abi` option.
+#[derive(Debug)]
+pub struct CAbi {
+    /// Name of the function that deletes global references returned by the shims.
+    pub release: Ident,
+    pub shims: Vec<CAbiShim>,
+}
+
+/// An `extern "C"` function that calls a constructor or a method of a class.
+#[derive(Debug)]
+pub struct CAbiShim {
+    /// Name of the exported function, like `a_b_TestClass1_init`.
+    pub name: Ident,
+    /// Name of the Rust constructor or method the function calls.
+    pub method: Ident,
+    pub kind: CAbiShimKind,
+    pub argument_names: Vec<Ident>,
+    pub argument_types: Vec<CAbiType>,
+    pub return_type: CAbiType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CAbiShimKind {
+    Constructor,
+    Method,
+    StaticMethod,
+}
+
+/// How a value is passed to or returned from a C ABI shim.
+#[derive(Debug)]
+pub enum CAbiType {
+    /// A primitive type, passed as it's JNI type.
+    Primitive(TokenStream),
+    /// An object, passed as a `jobject`. `class` is the Rust wrapper type without a lifetime.
+    Object { class: TokenStream, nullable: bool },
 }
 
 #[derive(Debug)]
@@ -67,11 +128,19 @@ pub struct Class {
     pub implements: Vec<InterfaceImplementation>,
     pub signature: Literal,
     pub full_signature: Literal,
+    /// Binary name of the class, like `a.b.Outer$Inner`.
+    pub binary_name: Literal,
+    /// Name of the superclass, like `java/lang/Object`.
+    pub super_signature: Literal,
+    /// Names of the interfaces declared in the class definition.
+    pub interface_signatures: Vec<Literal>,
     pub constructors: Vec<Constructor>,
     pub methods: Vec<ClassMethod>,
     pub static_methods: Vec<ClassMethod>,
     pub native_methods: Vec<NativeMethod>,
     pub static_native_methods: Vec<NativeMethod>,
+    /// C ABI shims of classes selected with the `c_abi` option.
+    pub c_abi: Option<CAbi>,
 }
 
 #[derive(Debug)]
@@ -82,10 +151,23 @@ pub struct Interface {
     pub methods: Vec<InterfaceMethod>,
 }
 
+/// Static methods of an interface, generated as associated functions of a companion struct.
+#[derive(Debug)]
+pub struct InterfaceStatics {
+    pub name: Ident,
+    pub public: bool,
+    pub signature: Literal,
+    pub full_signature: Literal,
+    pub static_methods: Vec<ClassMethod>,
+}
+
 #[derive(Debug)]
 pub enum GeneratorDefinition {
     Interface(Interface),
     Class(Class),
+    /// A trait with abstract methods of an abstract class.
+    AbstractMethods(Interface),
+    InterfaceStatics(InterfaceStatics),
 }
 
 #[derive(Debug)]
@@ -102,10 +184,94 @@ pub fn generate(data: &GeneratorData) -> TokenStream {
     )
 }
 
+/// Version of the generated code, must match `rust_jni::introspection::COMPATIBLE_WITH`.
+///
+/// `rust-jni` and `rust-jni-generator` are released together, so both take it from the
+/// major and minor versions of their packages.
+const RUST_JNI_COMPATIBLE_WITH: &str = concat!(
+    env!("CARGO_PKG_VERSION_MAJOR"),
+    ".",
+    env!("CARGO_PKG_VERSION_MINOR")
+);
+
+/// Check at compile time that the generated code is supported by the `rust-jni` version
+/// it's compiled with.
+pub fn generate_compatibility_check() -> TokenStream {
+    quote! {
+        const _: () = ::rust_jni::introspection::__check_compatible_with(#RUST_JNI_COMPATIBLE_WITH);
+    }
+}
+
+pub fn generate_binding_info(data: &GeneratorData) -> TokenStream {
+    let class_names = data
+        .definitions
+        .iter()
+        .filter_map(|definition| match definition {
+            GeneratorDefinition::Class(class) => Some(class.class.to_string()),
+            _ => None,
+        });
+    let class_signatures = data
+        .definitions
+        .iter()
+        .filter_map(|definition| match definition {
+            GeneratorDefinition::Class(class) => Some(class.full_signature.clone()),
+            _ => None,
+        });
+    let class_super_signatures =
+        data.definitions
+            .iter()
+            .filter_map(|definition| match definition {
+                GeneratorDefinition::Class(class) => Some(class.super_signature.clone()),
+                _ => None,
+            });
+    let class_interface_signatures =
+        data.definitions
+            .iter()
+            .filter_map(|definition| match definition {
+                GeneratorDefinition::Class(class) => Some(class.interface_signatures.iter()),
+                _ => None,
+            });
+    let interface_names = data
+        .definitions
+        .iter()
+        .filter_map(|definition| match definition {
+            GeneratorDefinition::Interface(interface) => Some(interface.interface.to_string()),
+            _ => None,
+        });
+    let generator_version = env!("CARGO_PKG_VERSION");
+    quote! {
+        #[doc(hidden)]
+        pub fn __rust_jni_binding_info() -> ::rust_jni::introspection::BindingInfo {
+            ::rust_jni::introspection::BindingInfo {
+                crate_name: env!("CARGO_PKG_NAME"),
+                crate_version: env!("CARGO_PKG_VERSION"),
+                generator_version: #generator_version,
+                classes: &[
+                    #(
+                        ::rust_jni::introspection::ClassBindingInfo {
+                            name: #class_names,
+                            signature: #class_signatures,
+                            super_class: #class_super_signatures,
+                            interfaces: &[#(#class_interface_signatures,)*],
+                        },
+                    )*
+                ],
+                interfaces: &[#(#interface_names,)*],
+            }
+        }
+
+        ::rust_jni::introspection::__inventory::submit! {
+            ::rust_jni::introspection::BindingRegistration::new(__rust_jni_binding_info)
+        }
+    }
+}
+
 fn generate_definition(definition: &GeneratorDefinition) -> TokenStream {
     match definition {
         GeneratorDefinition::Interface(interface) => generate_interface(interface),
         GeneratorDefinition::Class(class) => generate_class(class),
+        GeneratorDefinition::AbstractMethods(interface) => generate_interface(interface),
+        GeneratorDefinition::InterfaceStatics(statics) => generate_interface_statics(statics),
     }
 }
 
@@ -148,6 +314,41 @@ fn generate_interface_method(method: &InterfaceMethod) -> TokenStream {
     }
 }
 
+fn generate_interface_statics(definition: &InterfaceStatics) -> TokenStream {
+    let InterfaceStatics {
+        name,
+        public,
+        signature,
+        full_signature,
+        static_methods,
+    } = definition;
+    let static_methods = static_methods.iter().map(generate_static_class_method);
+    let public = generate_public(*public);
+    quote! {
+        #[derive(Debug)]
+        #public struct #name<'env> {
+            _env: ::rust_jni::__generator::marker::PhantomData<&'env ()>,
+        }
+
+        impl<'a> ::rust_jni::JniSignature for #name<'a> {
+            fn signature() -> &'static str {
+                #full_signature
+            }
+        }
+
+        impl<'a> #name<'a> {
+            pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
+                -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
+                ::rust_jni::java::lang::Class::find(env, token, #signature)
+            }
+
+            #(
+                #static_methods
+            )*
+        }
+    }
+}
+
 fn generate_class(definition: &Class) -> TokenStream {
     let Class {
         class,
@@ -157,15 +358,18 @@ fn generate_class(definition: &Class) -> TokenStream {
         implements,
         signature,
         full_signature,
+        binary_name,
         constructors,
         methods,
         static_methods,
         native_methods,
         static_native_methods,
+        c_abi,
+        ..
     } = definition;
-    let multiplied_class = iter::repeat(class);
-    let transitive_extends_1 = transitive_extends.iter();
-    let transitive_extends = transitive_extends.iter();
+    let type_implementations =
+        generate_class_type_implementations(class, super_class, full_signature, transitive_extends);
+    let comparison_implementations = generate_class_comparison_implementations(class);
     let methods = methods.iter().map(generate_class_method);
     let static_methods = static_methods.iter().map(generate_static_class_method);
     let native_method_functions = native_methods
@@ -174,10 +378,15 @@ fn generate_class(definition: &Class) -> TokenStream {
     let static_native_method_functions = static_native_methods
         .iter()
         .map(|method| generate_static_class_native_method_function(method, class));
-    let native_methods = native_methods.iter().map(generate_class_native_method);
+    let natives_trait =
+        generate_natives_trait(class, *public, native_methods, static_native_methods);
+    let native_methods = native_methods
+        .iter()
+        .filter_map(generate_class_native_method);
     let static_native_methods = static_native_methods
         .iter()
-        .map(generate_static_class_native_method);
+        .filter_map(generate_static_class_native_method);
+    let c_abi = c_abi.as_ref().map(|c_abi| generate_c_abi(c_abi, class));
     let constructors = constructors.iter().map(generate_constructor);
     let implementations = implements
         .iter()
@@ -189,71 +398,30 @@ fn generate_class(definition: &Class) -> TokenStream {
             object: #super_class<'env>,
         }
 
-        impl<'a> ::rust_jni::JavaType for #class<'a> {
-            #[doc(hidden)]
-            type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-            #[doc(hidden)]
-            fn __signature() -> &'static str {
-                #full_signature
-            }
-        }
-
-        impl<'a> ::rust_jni::__generator::ToJni for #class<'a> {
-            unsafe fn __to_jni(&self) -> Self::__JniType {
-                self.raw_object()
-            }
-        }
-
-        impl<'a> ::rust_jni::__generator::FromJni<'a> for #class<'a> {
-            unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
-                Self {
-                    object: <#super_class as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
-                }
-            }
-        }
-
-        impl<'a> ::rust_jni::Cast<'a, #class<'a>> for #class<'a> {
-            #[doc(hidden)]
-            fn cast<'b>(&'b self) -> &'b #class<'a> {
-                self
-            }
-        }
-
-        #(
-            impl<'a> ::rust_jni::Cast<'a, #transitive_extends<'a>> for #multiplied_class<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b #transitive_extends_1<'a> {
-                    self
-                }
-            }
-        )*
-
-        impl<'a> ::std::ops::Deref for #class<'a> {
-            type Target = #super_class<'a>;
-
-            fn deref(&self) -> &Self::Target {
-                &self.object
-            }
-        }
+        #type_implementations
 
         impl<'a> #class<'a> {
+            /// Binary name of the Java class, as returned by `Class.getName()`.
+            pub const BINARY_NAME: &'static str = #binary_name;
+            /// JNI type descriptor of the Java class.
+            pub const DESCRIPTOR: &'static str = #full_signature;
+            /// Internal name of the Java class, as accepted by `FindClass`.
+            pub const SLASH_NAME: &'static str = #signature;
+
             pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                 -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                ::rust_jni::java::lang::Class::find(env, #signature, token)
+                ::rust_jni::java::lang::Class::find(env, token, #signature)
             }
 
             pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
             where
                 Self: Sized,
             {
-                self.object
-                    .clone(token)
-                    .map(|object| Self { object })
+                ::rust_jni::JavaClassExt::clone_object(self, token)
             }
 
             pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                 self.object.to_string(token)
             }
 
@@ -278,6 +446,8 @@ fn generate_class(definition: &Class) -> TokenStream {
             )*
         }
 
+        #natives_trait
+
         // TODO: put them into an anonymous module.
 
         #(
@@ -288,53 +458,150 @@ fn generate_class(definition: &Class) -> TokenStream {
             #static_native_method_functions
         )*
 
-        impl<'a> ::std::fmt::Display for #class<'a> {
-            fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                self.object.fmt(formatter)
+        #c_abi
+
+        #comparison_implementations
+
+        #(
+            #implementations
+        )*
+    }
+}
+
+/// Check if a class path names `java.lang.Object`, judging by the last path segment.
+pub fn is_object(class: &TokenStream) -> bool {
+    match class.clone().into_iter().last() {
+        Some(TokenTree::Ident(identifier)) => identifier == "Object",
+        _ => false,
+    }
+}
+
+/// Generate trait implementations that make a struct with an `object` field a class wrapper.
+///
+/// `AsRef` and `Borrow` are implemented for `Object` and every transitive superclass,
+/// so that generic functions bounded on `AsRef<Object>` accept the class. `Detachable` and
+/// the conversion into `Object` allow wrapping the class in an `OwnedObject`.
+///
+/// Shared by generated classes and `#[derive(JavaClassWrapper)]`.
+pub fn generate_class_type_implementations(
+    class: &Ident,
+    super_class: &TokenStream,
+    full_signature: &Literal,
+    transitive_extends: &[TokenStream],
+) -> TokenStream {
+    let mut ancestors = transitive_extends.to_vec();
+    if !ancestors.iter().any(is_object) {
+        ancestors.push(quote! {::rust_jni::java::lang::Object});
+    }
+    let reference_implementations = ancestors.iter().map(|ancestor| {
+        quote! {
+            impl<'a> ::rust_jni::__generator::convert::AsRef<#ancestor<'a>> for #class<'a> {
+                fn as_ref(&self) -> &#ancestor<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<#ancestor<'a>> for #class<'a> {
+                fn borrow(&self) -> &#ancestor<'a> {
+                    self
+                }
+            }
+        }
+    });
+    quote! {
+        impl<'a> ::rust_jni::JniSignature for #class<'a> {
+            fn signature() -> &'static str {
+                #full_signature
+            }
+        }
+
+        impl<'a> ::rust_jni::FromObject<'a> for #class<'a> {
+            unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                Self {
+                    object: <#super_class as ::rust_jni::FromObject<'a>>::from_object(object),
+                }
             }
         }
 
-        impl<'a, T> PartialEq<T> for #class<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
-            fn eq(&self, other: &T) -> bool {
-                self.object.eq(other)
+        impl<'a> ::rust_jni::__generator::ops::Deref for #class<'a> {
+            type Target = #super_class<'a>;
+
+            fn deref(&self) -> &Self::Target {
+                &self.object
             }
         }
 
-        impl<'a> Eq for #class<'a> {}
+        impl<'a> ::rust_jni::__generator::convert::From<#class<'a>> for ::rust_jni::java::lang::Object<'a> {
+            fn from(value: #class<'a>) -> Self {
+                value.object.into()
+            }
+        }
+
+        impl<'env> ::rust_jni::Detachable for #class<'env> {
+            type Attached<'a> = #class<'a>;
+        }
 
         #(
-            #implementations
+            #reference_implementations
         )*
     }
 }
 
+/// Generate `Display`, `PartialEq` and `Eq` implementations for a class wrapper.
+pub fn generate_class_comparison_implementations(class: &Ident) -> TokenStream {
+    quote! {
+        impl<'a> ::rust_jni::__generator::fmt::Display for #class<'a> {
+            fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
+            }
+        }
+
+        impl<'a, T> PartialEq<T> for #class<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
+            fn eq(&self, other: &T) -> bool {
+                ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
+            }
+        }
+
+        impl<'a> Eq for #class<'a> {}
+    }
+}
+
 fn generate_constructor(method: &Constructor) -> TokenStream {
     let Constructor {
         name,
         public,
         argument_names,
         argument_types,
+        since,
+        visibility,
     } = method;
     let argument_names_1 = argument_names.iter();
     let argument_names = argument_names.iter();
     let argument_types_1 = argument_types.iter();
     let argument_types = argument_types.iter();
-    let public = generate_public(*public);
+    let public = generate_visibility(*public, visibility);
+    let call = quote! {
+        // Safe because the method name and arguments are correct and only the result leaves
+        // the local frame. The frame fits the class, the result and a thrown exception.
+        unsafe {
+            env.with_local_frame(token, 4, |token| {
+                ::rust_jni::call_constructor::<Self, _, fn(#(#argument_types_1,)*)>
+                (
+                    env,
+                    token,
+                    (#(#argument_names_1,)*),
+                )
+            })
+        }
+    };
+    let (result_type, result) = generate_since(&quote! {Self}, call, since, quote! {env});
     quote! {
         #public fn #name(
             env: &'a ::rust_jni::JniEnv<'a>,
             #(#argument_names: #argument_types,)*
             token: &::rust_jni::NoException<'a>,
-        ) -> ::rust_jni::JavaResult<'a, Self> {
-            // Safe because the method name and arguments are correct.
-            unsafe {
-                ::rust_jni::__generator::call_constructor::<Self, _, fn(#(#argument_types_1,)*)>
-                (
-                    env,
-                    (#(#argument_names_1,)*),
-                    token,
-                )
-            }
+        ) -> #result_type {
+            #result
         }
     }
 }
@@ -347,31 +614,95 @@ fn generate_class_method(method: &ClassMethod) -> TokenStream {
         public,
         argument_names,
         argument_types,
+        monitor_guard,
+        map_result,
+        nonnull_result,
+        unchecked_feature,
+        since,
+        visibility,
+        throws,
     } = method;
-    let argument_names_1 = argument_names.iter();
+    let public = generate_visibility(*public, visibility);
+    let monitor_guard = if *monitor_guard {
+        quote! {
+            let _monitor_guard = ::rust_jni::MonitorGuard::new(self, token)?;
+        }
+    } else {
+        quote! {}
+    };
+    let call = {
+        let argument_names = argument_names.iter();
+        let argument_types = argument_types.iter();
+        quote! {
+            self.env().with_local_frame(token, 4, |token| {
+                ::rust_jni::call_method::<_, _, _,
+                    fn(#(#argument_types,)*) -> #return_type
+                >
+                (
+                    self,
+                    token,
+                    concat!(#java_name, "\0"),
+                    (#(#argument_names,)*),
+                )
+            })
+        }
+    };
+    let checked_call = quote! {
+        // Safe because the method name and arguments are correct and only the result leaves
+        // the local frame. The frame fits the class, the result and a thrown exception.
+        unsafe {
+            #call
+        }
+    };
+    let checked_call = generate_nonnull_result(checked_call, *nonnull_result, quote! {self.env()});
+    let (checked_return_type, checked_call) = generate_throws(return_type, checked_call, throws);
+    let (result_type, result) = generate_map_result(&checked_return_type, checked_call, map_result);
+    let unchecked_method = unchecked_feature.as_ref().map(|feature| {
+        let unchecked_name = generate_unchecked_name(name);
+        let unchecked_call = generate_unchecked_nonnull_result(call.clone(), *nonnull_result);
+        let (unchecked_result_type, unchecked_result) = generate_since(
+            return_type,
+            quote! {
+                #monitor_guard
+                #unchecked_call
+            },
+            since,
+            quote! {self.env()},
+        );
+        let argument_names = argument_names.iter();
+        let argument_types = argument_types.iter();
+        quote! {
+            #[cfg(feature = #feature)]
+            #public unsafe fn #unchecked_name(
+                &self,
+                #(#argument_names: #argument_types,)*
+                token: &::rust_jni::NoException<'a>,
+            ) -> #unchecked_result_type {
+                #unchecked_result
+            }
+        }
+    });
+    let (result_type, result) = generate_since(
+        &result_type,
+        quote! {
+            #monitor_guard
+            #result
+        },
+        since,
+        quote! {self.env()},
+    );
     let argument_names = argument_names.iter();
-    let argument_types_1 = argument_types.iter();
     let argument_types = argument_types.iter();
-    let public = generate_public(*public);
     quote! {
         #public fn #name(
             &self,
             #(#argument_names: #argument_types,)*
             token: &::rust_jni::NoException<'a>,
-        ) -> ::rust_jni::JavaResult<'a, #return_type> {
-            // Safe because the method name and arguments are correct.
-            unsafe {
-                ::rust_jni::__generator::call_method::<_, _, _,
-                    fn(#(#argument_types_1,)*) -> #return_type
-                >
-                (
-                    self,
-                    #java_name,
-                    (#(#argument_names_1,)*),
-                    token,
-                )
-            }
+        ) -> #result_type {
+            #result
         }
+
+        #unchecked_method
     }
 }
 
@@ -383,68 +714,311 @@ fn generate_static_class_method(method: &ClassMethod) -> TokenStream {
         public,
         argument_names,
         argument_types,
+        monitor_guard,
+        map_result,
+        nonnull_result,
+        unchecked_feature,
+        since,
+        visibility,
+        throws,
     } = method;
-    let argument_names_1 = argument_names.iter();
+    let public = generate_visibility(*public, visibility);
+    let monitor_guard = if *monitor_guard {
+        quote! {
+            // Static synchronized methods hold the monitor of the class object.
+            let class = Self::get_class(env, token)?;
+            let _monitor_guard = ::rust_jni::MonitorGuard::new(&class, token)?;
+        }
+    } else {
+        quote! {}
+    };
+    let call = {
+        let argument_names = argument_names.iter();
+        let argument_types = argument_types.iter();
+        quote! {
+            env.with_local_frame(token, 4, |token| {
+                ::rust_jni::call_static_method::<Self, _, _,
+                    fn(#(#argument_types,)*) -> #return_type
+                >
+                (
+                    env,
+                    token,
+                    concat!(#java_name, "\0"),
+                    (#(#argument_names,)*),
+                )
+            })
+        }
+    };
+    let checked_call = quote! {
+        // Safe because the method name and arguments are correct and only the result leaves
+        // the local frame. The frame fits the class, the result and a thrown exception.
+        unsafe {
+            #call
+        }
+    };
+    let checked_call = generate_nonnull_result(checked_call, *nonnull_result, quote! {env});
+    let (checked_return_type, checked_call) = generate_throws(return_type, checked_call, throws);
+    let (result_type, result) = generate_map_result(&checked_return_type, checked_call, map_result);
+    let unchecked_method = unchecked_feature.as_ref().map(|feature| {
+        let unchecked_name = generate_unchecked_name(name);
+        let unchecked_call = generate_unchecked_nonnull_result(call.clone(), *nonnull_result);
+        let (unchecked_result_type, unchecked_result) = generate_since(
+            return_type,
+            quote! {
+                #monitor_guard
+                #unchecked_call
+            },
+            since,
+            quote! {env},
+        );
+        let argument_names = argument_names.iter();
+        let argument_types = argument_types.iter();
+        quote! {
+            #[cfg(feature = #feature)]
+            #public unsafe fn #unchecked_name(
+                env: &'a ::rust_jni::JniEnv<'a>,
+                #(#argument_names: #argument_types,)*
+                token: &::rust_jni::NoException<'a>,
+            ) -> #unchecked_result_type {
+                #unchecked_result
+            }
+        }
+    });
+    let (result_type, result) = generate_since(
+        &result_type,
+        quote! {
+            #monitor_guard
+            #result
+        },
+        since,
+        quote! {env},
+    );
     let argument_names = argument_names.iter();
-    let argument_types_1 = argument_types.iter();
     let argument_types = argument_types.iter();
-    let public = generate_public(*public);
     quote! {
         #public fn #name(
             env: &'a ::rust_jni::JniEnv<'a>,
             #(#argument_names: #argument_types,)*
             token: &::rust_jni::NoException<'a>,
-        ) -> ::rust_jni::JavaResult<'a, #return_type> {
-            // Safe because the method name and arguments are correct.
-            unsafe {
-                ::rust_jni::__generator::call_static_method::<Self, _, _,
-                    fn(#(#argument_types_1,)*) -> #return_type
-                >
-                (
-                    env,
-                    #java_name,
-                    (#(#argument_names_1,)*),
-                    token,
-                )
-            }
+        ) -> #result_type {
+            #result
         }
+
+        #unchecked_method
     }
 }
 
-fn generate_class_native_method(method: &NativeMethod) -> TokenStream {
-    let NativeMethod {
-        rust_name,
-        return_type,
-        public,
-        argument_names,
-        argument_types,
-        code,
-        ..
-    } = method;
-    let public = generate_public(*public);
-    quote! {
-        #public fn #rust_name(
-            &self,
-            #(#argument_names: #argument_types,)*
-            token: &::rust_jni::NoException<'a>,
-        ) -> ::rust_jni::JavaResult<'a, #return_type> {
-            #code
+/// Name of the `_unchecked` variant of a method.
+fn generate_unchecked_name(name: &Ident) -> Ident {
+    let name_string = name.to_string();
+    // Raw identifiers, like `r#match`, are not keywords with a suffix.
+    let name_string = name_string.trim_start_matches("r#");
+    Ident::new(&format!("{}_unchecked", name_string), name.span())
+}
+
+/// Convert a `null` result of a method returning an object into a `NullPointerException`.
+fn generate_nonnull_result(
+    call: TokenStream,
+    nonnull_result: bool,
+    env: TokenStream,
+) -> TokenStream {
+    if nonnull_result {
+        quote! {
+            (#call).and_then(|result| ::rust_jni::NullableJavaClassExt::or_npe(result, #env, token))
         }
+    } else {
+        call
     }
 }
 
-fn generate_static_class_native_method(method: &NativeMethod) -> TokenStream {
-    let NativeMethod {
+/// Unwrap a result of a method returning an object without checking for `null`. Callers of
+/// `_unchecked` methods guarantee that the method doesn't return `null`.
+fn generate_unchecked_nonnull_result(call: TokenStream, nonnull_result: bool) -> TokenStream {
+    if nonnull_result {
+        quote! {
+            (#call).map(|result| result.unwrap_unchecked())
+        }
+    } else {
+        call
+    }
+}
+
+/// Check the feature release of the Java VM before calling a method marked with `@since`.
+///
+/// Returns the full result type and the body of the method. Methods that are not available
+/// in the Java VM return an `UnsupportedOnThisJvm` error instead of throwing a
+/// `NoSuchMethodError`.
+fn generate_since(
+    result_type: &TokenStream,
+    body: TokenStream,
+    since: &Option<Literal>,
+    env: TokenStream,
+) -> (TokenStream, TokenStream) {
+    match since {
+        None => (quote! {::rust_jni::JavaResult<'a, #result_type>}, body),
+        Some(since) => (
+            quote! {
+                ::rust_jni::__generator::result::Result<
+                    ::rust_jni::JavaResult<'a, #result_type>,
+                    ::rust_jni::UnsupportedOnThisJvm,
+                >
+            },
+            quote! {
+                match ::rust_jni::require_jvm_feature_level(#env, token, #since) {
+                    Err(exception) => return Ok(Err(exception)),
+                    Ok(Err(error)) => return Err(error),
+                    Ok(Ok(())) => {}
+                }
+                Ok((|| -> ::rust_jni::JavaResult<'a, #result_type> { #body })())
+            },
+        ),
+    }
+}
+
+/// Catch exceptions of the class marked with `@throws`, returning them as a typed error.
+///
+/// Returns the result type and the call.
+fn generate_throws(
+    return_type: &TokenStream,
+    call: TokenStream,
+    throws: &Option<TokenStream>,
+) -> (TokenStream, TokenStream) {
+    match throws {
+        None => (return_type.clone(), call),
+        Some(throws) => (
+            quote! {::rust_jni::__generator::result::Result<#return_type, #throws>},
+            quote! {
+                ::rust_jni::JavaResultExt::catching::<#throws>(#call, token)
+            },
+        ),
+    }
+}
+
+fn generate_map_result(
+    return_type: &TokenStream,
+    call: TokenStream,
+    map_result: &Option<MapResult>,
+) -> (TokenStream, TokenStream) {
+    match map_result {
+        None => (return_type.clone(), call),
+        Some(MapResult {
+            function,
+            result_type,
+        }) => (
+            result_type.clone(),
+            quote! {
+                let result = #call?;
+                #function(result, token)
+            },
+        ),
+    }
+}
+
+/// Generate a trait with native methods declared without a body, like
+/// `native long compute(int x);`. The user implements it for the class with the Rust bodies,
+/// which the JNI entry points call.
+fn generate_natives_trait(
+    class: &Ident,
+    public: bool,
+    native_methods: &[NativeMethod],
+    static_native_methods: &[NativeMethod],
+) -> TokenStream {
+    let methods = native_methods
+        .iter()
+        .filter(|method| method.code.is_none())
+        .map(|method| {
+            let NativeMethod {
+                rust_name,
+                return_type,
+                argument_names,
+                argument_types,
+                ..
+            } = method;
+            quote! {
+                fn #rust_name(
+                    &self,
+                    #(#argument_names: #argument_types,)*
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, #return_type>;
+            }
+        })
+        .collect::<Vec<_>>();
+    let static_methods = static_native_methods
+        .iter()
+        .filter(|method| method.code.is_none())
+        .map(|method| {
+            let NativeMethod {
+                rust_name,
+                return_type,
+                argument_names,
+                argument_types,
+                ..
+            } = method;
+            quote! {
+                fn #rust_name(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    #(#argument_names: #argument_types,)*
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, #return_type>;
+            }
+        })
+        .collect::<Vec<_>>();
+    if methods.is_empty() && static_methods.is_empty() {
+        return quote! {};
+    }
+    let trait_name = Ident::new(&format!("{}Natives", class), class.span());
+    let public = generate_public(public);
+    quote! {
+        #public trait #trait_name<'a> {
+            #(
+                #methods
+            )*
+
+            #(
+                #static_methods
+            )*
+        }
+    }
+}
+
+fn generate_class_native_method(method: &NativeMethod) -> Option<TokenStream> {
+    let NativeMethod {
         rust_name,
         return_type,
         public,
         argument_names,
         argument_types,
         code,
+        visibility,
         ..
     } = method;
-    let public = generate_public(*public);
-    quote! {
+    let code = code.as_ref()?;
+    let public = generate_visibility(*public, visibility);
+    Some(quote! {
+        #public fn #rust_name(
+            &self,
+            #(#argument_names: #argument_types,)*
+            token: &::rust_jni::NoException<'a>,
+        ) -> ::rust_jni::JavaResult<'a, #return_type> {
+            #code
+        }
+    })
+}
+
+fn generate_static_class_native_method(method: &NativeMethod) -> Option<TokenStream> {
+    let NativeMethod {
+        rust_name,
+        return_type,
+        public,
+        argument_names,
+        argument_types,
+        code,
+        visibility,
+        ..
+    } = method;
+    let code = code.as_ref()?;
+    let public = generate_visibility(*public, visibility);
+    Some(quote! {
         #public fn #rust_name(
             env: &'a ::rust_jni::JniEnv<'a>,
             #(#argument_names: #argument_types,)*
@@ -452,7 +1026,7 @@ fn generate_static_class_native_method(method: &NativeMethod) -> TokenStream {
         ) -> ::rust_jni::JavaResult<'a, #return_type> {
             #code
         }
-    }
+    })
 }
 
 fn generate_class_native_method_function(method: &NativeMethod, class_name: &Ident) -> TokenStream {
@@ -461,51 +1035,30 @@ fn generate_class_native_method_function(method: &NativeMethod, class_name: &Ide
         java_name,
         return_type,
         argument_names,
-        argument_types_no_lifetime,
+        argument_types,
         ..
     } = method;
     let argument_names_1 = argument_names.iter();
     let argument_names_2 = argument_names.iter();
     let argument_names_3 = argument_names.iter();
     let argument_names = argument_names.iter();
-    let argument_types_no_lifetime_1 = argument_types_no_lifetime.iter();
-    let argument_types_no_lifetime = argument_types_no_lifetime.iter();
     quote! {
         #[no_mangle]
         #[doc(hidden)]
         pub unsafe extern "C" fn #java_name<'a>(
             raw_env: *mut ::jni_sys::JNIEnv,
             object: ::jni_sys::jobject,
-            #(#argument_names: <#argument_types_no_lifetime as ::rust_jni::JavaType>::__JniType,)*
-        ) -> <#return_type as ::rust_jni::JavaType>::__JniType {
-            // TODO: make sure `#return_type: ::rust_jni::__generator::FromJni`.
-            // Compile-time check that declared arguments implement the `JniArgumentType`
-            // trait.
-            #(::rust_jni::__generator::test_jni_argument_type(#argument_names_1);)*
+            #(#argument_names: <#argument_types as ::rust_jni::__generator::NativeMethodArgument<'a>>::JniType,)*
+        ) -> <#return_type as ::rust_jni::__generator::ToJavaNativeResult>::JniType {
             ::rust_jni::__generator::native_method_wrapper(raw_env, |env, token| {
-                // Compile-time check that declared arguments implement the `FromJni` trait.
+                let object = <#class_name as ::rust_jni::__generator::NativeMethodArgument>::from_jni(env, token, object)?;
                 #(
-                    {
-                        let value =
-                            <#argument_types_no_lifetime_1 as ::rust_jni::__generator::FromJni>
-                                ::__from_jni(env, #argument_names_2);
-                        ::rust_jni::__generator::test_from_jni_type(&value);
-                        ::std::mem::forget(value);
-                    }
+                    let #argument_names_1 =
+                        ::rust_jni::__generator::NativeMethodArgument::from_jni(env, token, #argument_names_2)?;
                 )*
-
-                let object = <#class_name as ::rust_jni::__generator::FromJni>::__from_jni(env, object);
                 object
-                    .#rust_name(
-                        #(::rust_jni::__generator::FromJni::__from_jni(env, #argument_names_3),)*
-                        &token,
-                    )
-                    .map(|value| {
-                        let result = ::rust_jni::__generator::ToJni::__to_jni(&value);
-                        // We don't want to delete the reference to result for object results.
-                        ::std::mem::forget(value);
-                        result
-                    })
+                    .#rust_name(#(#argument_names_3,)* token)
+                    .map(::rust_jni::__generator::to_native_result)
             })
         }
     }
@@ -521,15 +1074,13 @@ fn generate_static_class_native_method_function(
         java_name,
         return_type,
         argument_names,
-        argument_types_no_lifetime,
+        argument_types,
         ..
     } = method;
     let argument_names_1 = argument_names.iter();
     let argument_names_2 = argument_names.iter();
     let argument_names_3 = argument_names.iter();
     let argument_names = argument_names.iter();
-    let argument_types_no_lifetime_1 = argument_types_no_lifetime.iter();
-    let argument_types_no_lifetime = argument_types_no_lifetime.iter();
     let class_mismatch_error = format!(
         "Native method {} does not belong to class {}",
         name.to_string(),
@@ -541,27 +1092,12 @@ fn generate_static_class_native_method_function(
         pub unsafe extern "C" fn #java_name<'a>(
             raw_env: *mut ::jni_sys::JNIEnv,
             raw_class: ::jni_sys::jclass,
-            #(#argument_names: <#argument_types_no_lifetime as ::rust_jni::JavaType>::__JniType,)*
-        ) -> <#return_type as ::rust_jni::JavaType>::__JniType {
-            // TODO: make sure `#return_type: ::rust_jni::__generator::FromJni`.
-            // Compile-time check that declared arguments implement the `JniArgumentType`
-            // trait.
-            #(::rust_jni::__generator::test_jni_argument_type(#argument_names_1);)*
+            #(#argument_names: <#argument_types as ::rust_jni::__generator::NativeMethodArgument<'a>>::JniType,)*
+        ) -> <#return_type as ::rust_jni::__generator::ToJavaNativeResult>::JniType {
             ::rust_jni::__generator::native_method_wrapper(raw_env, |env, token| {
-                // Compile-time check that declared arguments implement the `FromJni` trait.
-                #(
-                    {
-                        let value =
-                            <#argument_types_no_lifetime_1 as ::rust_jni::__generator::FromJni>
-                                ::__from_jni(env, #argument_names_2);
-                        ::rust_jni::__generator::test_from_jni_type(&value);
-                        ::std::mem::forget(value);
-                    }
-                )*
-
-                let class = #class_name::get_class(env, &token)?;
-                let raw_class = <::rust_jni::java::lang::Class as ::rust_jni::__generator::FromJni>::__from_jni(env, raw_class);
-                if !class.is_same_as(&raw_class, &token) {
+                let class = #class_name::get_class(env, token)?;
+                let raw_class = <::rust_jni::java::lang::Class as ::rust_jni::__generator::NativeMethodArgument>::from_jni(env, token, raw_class)?;
+                if !class.is_same_as(token, &raw_class) {
                     // This should never happen, as native method's link name has the class,
                     // so it must be bound to a correct clas by the JVM.
                     // Still, this is a good test to ensure that the system
@@ -569,17 +1105,150 @@ fn generate_static_class_native_method_function(
                     panic!(#class_mismatch_error);
                 }
 
-                #class_name::#rust_name(
-                    env,
-                    #(::rust_jni::__generator::FromJni::__from_jni(env, #argument_names_3),)*
-                    &token,
-                )
-                .map(|value| {
-                    let result = ::rust_jni::__generator::ToJni::__to_jni(&value);
-                    // We don't want to delete the reference to result for object results.
-                    ::std::mem::forget(value);
-                    result
-                })
+                #(
+                    let #argument_names_1 =
+                        ::rust_jni::__generator::NativeMethodArgument::from_jni(env, token, #argument_names_2)?;
+                )*
+                #class_name::#rust_name(env, #(#argument_names_3,)* token)
+                    .map(::rust_jni::__generator::to_native_result)
+            })
+        }
+    }
+}
+
+/// Generate `extern "C"` functions that call constructors and methods of the class, so that
+/// C and C++ code can reuse the generated bindings.
+///
+/// The functions take the `JNIEnv` of the current thread, the object for instance methods and
+/// the arguments as JNI values. Objects are returned as new global references, which are
+/// deleted with the `release` function. Exceptions are left pending and the functions return
+/// zero or `null` in that case, like JNI functions do.
+fn generate_c_abi(c_abi: &CAbi, class: &Ident) -> TokenStream {
+    let CAbi { release, shims } = c_abi;
+    let shims = shims.iter().map(|shim| generate_c_abi_shim(shim, class));
+    quote! {
+        #(
+            #shims
+        )*
+
+        #[no_mangle]
+        #[doc(hidden)]
+        pub unsafe extern "C" fn #release(
+            raw_env: *mut ::jni_sys::JNIEnv,
+            object: ::jni_sys::jobject,
+        ) {
+            ((**raw_env).DeleteGlobalRef.unwrap())(raw_env, object)
+        }
+    }
+}
+
+fn generate_c_abi_shim(shim: &CAbiShim, class: &Ident) -> TokenStream {
+    let CAbiShim {
+        name,
+        method,
+        kind,
+        argument_names,
+        argument_types,
+        return_type,
+    } = shim;
+    let arguments =
+        argument_names
+            .iter()
+            .zip(argument_types.iter())
+            .map(|(name, argument_type)| match argument_type {
+                CAbiType::Primitive(primitive) => {
+                    quote! {#name: <#primitive as ::rust_jni::__generator::NativeMethodArgument>::JniType}
+                }
+                CAbiType::Object { .. } => quote! {#name: ::jni_sys::jobject},
+            });
+    let conversions = argument_names.iter().zip(argument_types.iter()).map(
+        |(name, argument_type)| match argument_type {
+            CAbiType::Primitive(primitive) => quote! {
+                let #name =
+                    <#primitive as ::rust_jni::__generator::NativeMethodArgument>::from_jni(env, token, #name)?;
+            },
+            // Arguments are references owned by the caller.
+            CAbiType::Object {
+                class,
+                nullable: false,
+            } => quote! {
+                let #name = ::rust_jni::__generator::mem::ManuallyDrop::new(
+                    <#class as ::rust_jni::__generator::NativeMethodArgument>::from_jni(env, token, #name)?
+                );
+            },
+            CAbiType::Object {
+                class,
+                nullable: true,
+            } => quote! {
+                let #name = ::rust_jni::__generator::mem::ManuallyDrop::new(
+                    <::rust_jni::__generator::option::Option<#class> as ::rust_jni::__generator::NativeMethodArgument>
+                        ::from_jni(env, token, #name)?
+                );
+            },
+        },
+    );
+    let values = argument_names
+        .iter()
+        .zip(argument_types.iter())
+        .map(|(name, argument_type)| match argument_type {
+            CAbiType::Primitive(_) => quote! {#name},
+            CAbiType::Object {
+                nullable: false, ..
+            } => quote! {&*#name},
+            CAbiType::Object { nullable: true, .. } => quote! {(*#name).as_ref()},
+        });
+    let (receiver, receiver_conversion, call) = match kind {
+        CAbiShimKind::Method => (
+            quote! {object: ::jni_sys::jobject,},
+            quote! {
+                let object = ::rust_jni::__generator::mem::ManuallyDrop::new(
+                    <#class as ::rust_jni::__generator::NativeMethodArgument>::from_jni(env, token, object)?
+                );
+            },
+            quote! {object.#method(#(#values,)* token)},
+        ),
+        _ => (
+            quote! {},
+            quote! {},
+            quote! {#class::#method(env, #(#values,)* token)},
+        ),
+    };
+    let global_reference = quote! {
+        ((**raw_env).NewGlobalRef.unwrap())(
+            raw_env,
+            ::rust_jni::__generator::ToJavaNativeResult::to_java_native_result(&value),
+        )
+    };
+    let (result_type, result) = match return_type {
+        CAbiType::Primitive(primitive) => (
+            quote! {<#primitive as ::rust_jni::__generator::ToJavaNativeResult>::JniType},
+            quote! {::rust_jni::__generator::ToJavaNativeResult::to_java_native_result(&value)},
+        ),
+        CAbiType::Object {
+            nullable: false, ..
+        } => (quote! {::jni_sys::jobject}, global_reference),
+        CAbiType::Object { nullable: true, .. } => (
+            quote! {::jni_sys::jobject},
+            quote! {
+                match value {
+                    None => ::rust_jni::__generator::ptr::null_mut(),
+                    Some(value) => #global_reference,
+                }
+            },
+        ),
+    };
+    quote! {
+        #[no_mangle]
+        #[doc(hidden)]
+        pub unsafe extern "C" fn #name(
+            raw_env: *mut ::jni_sys::JNIEnv,
+            #receiver
+            #(#arguments,)*
+        ) -> #result_type {
+            ::rust_jni::__generator::native_method_wrapper(raw_env, |env, token| {
+                #receiver_conversion
+                #(#conversions)*
+                #call.map(|value| #result)
             })
         }
     }
@@ -643,6 +1312,13 @@ fn generate_public(public: bool) -> TokenStream {
     }
 }
 
+/// Rust visibility of a method: the `@rust_visibility` override or the Java visibility.
+fn generate_visibility(public: bool, visibility: &Option<TokenStream>) -> TokenStream {
+    visibility
+        .clone()
+        .unwrap_or_else(|| generate_public(public))
+}
+
 #[cfg(test)]
 mod generate_tests {
     use super::*;
@@ -680,11 +1356,15 @@ mod generate_tests {
                     implements: vec![],
                     signature: Literal::string("test/sign1"),
                     full_signature: Literal::string("test/signature1"),
+                    binary_name: Literal::string("test.sign1"),
+                    super_signature: Literal::string("java/lang/Object"),
+                    interface_signatures: vec![],
                     methods: vec![],
                     static_methods: vec![],
                     constructors: vec![],
                     native_methods: vec![],
                     static_native_methods: vec![],
+                    c_abi: None,
                 }),
                 GeneratorDefinition::Class(Class {
                     class: Ident::new("test2", Span::call_site()),
@@ -694,10 +1374,14 @@ mod generate_tests {
                     implements: vec![],
                     signature: Literal::string("test/sign2"),
                     full_signature: Literal::string("test/signature2"),
+                    binary_name: Literal::string("test.sign2"),
+                    super_signature: Literal::string("java/lang/Object"),
+                    interface_signatures: vec![],
                     methods: vec![],
                     static_methods: vec![],
                     native_methods: vec![],
                     static_native_methods: vec![],
+                    c_abi: None,
                     constructors: vec![],
                 }),
             ],
@@ -714,75 +1398,85 @@ mod generate_tests {
                 object: c::d::test3<'env>,
             }
 
-            impl<'a> ::rust_jni::JavaType for test1<'a> {
-                #[doc(hidden)]
-                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-                #[doc(hidden)]
-                fn __signature() -> &'static str {
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
                     "test/signature1"
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::ToJni for test1<'a> {
-                unsafe fn __to_jni(&self) -> Self::__JniType {
-                    self.raw_object()
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test3 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
+                type Target = c::d::test3<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::FromJni<'a> for test1<'a> {
-                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
-                    Self {
-                        object: <c::d::test3 as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
-                    }
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, test1<'a>> for test1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b test1<'a> {
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
                     self
                 }
             }
 
-            impl<'a> ::std::ops::Deref for test1<'a> {
-                type Target = c::d::test3<'a>;
-
-                fn deref(&self) -> &Self::Target {
-                    &self.object
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
                 }
             }
 
             impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
                 {
-                    self.object
-                        .clone(token)
-                        .map(|object| Self { object })
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
                 }
 
                 pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                     self.object.to_string(token)
                 }
             }
 
-            impl<'a> ::std::fmt::Display for test1<'a> {
-                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    self.object.fmt(formatter)
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
-                    self.object.eq(other)
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
                 }
             }
 
@@ -793,75 +1487,85 @@ mod generate_tests {
                 object: c::d::test4<'env>,
             }
 
-            impl<'a> ::rust_jni::JavaType for test2<'a> {
-                #[doc(hidden)]
-                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-                #[doc(hidden)]
-                fn __signature() -> &'static str {
+            impl<'a> ::rust_jni::JniSignature for test2<'a> {
+                fn signature() -> &'static str {
                     "test/signature2"
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::ToJni for test2<'a> {
-                unsafe fn __to_jni(&self) -> Self::__JniType {
-                    self.raw_object()
+            impl<'a> ::rust_jni::FromObject<'a> for test2<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test4 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::FromJni<'a> for test2<'a> {
-                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
-                    Self {
-                        object: <c::d::test4 as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
-                    }
+            impl<'a> ::rust_jni::__generator::ops::Deref for test2<'a> {
+                type Target = c::d::test4<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, test2<'a>> for test2<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b test2<'a> {
-                    self
+            impl<'a> ::rust_jni::__generator::convert::From<test2<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test2<'a>) -> Self {
+                    value.object.into()
                 }
             }
 
-            impl<'a> ::std::ops::Deref for test2<'a> {
-                type Target = c::d::test4<'a>;
+            impl<'env> ::rust_jni::Detachable for test2<'env> {
+                type Attached<'a> = test2<'a>;
+            }
 
-                fn deref(&self) -> &Self::Target {
-                    &self.object
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test2<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test2<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
                 }
             }
 
             impl<'a> test2<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign2";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature2";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign2";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "test/sign2", token)
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign2")
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
                 {
-                    self.object
-                        .clone(token)
-                        .map(|object| Self { object })
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
                 }
 
                 pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                     self.object.to_string(token)
                 }
             }
 
-            impl<'a> ::std::fmt::Display for test2<'a> {
-                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    self.object.fmt(formatter)
+            impl<'a> ::rust_jni::__generator::fmt::Display for test2<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for test2<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for test2<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
-                    self.object.eq(other)
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
                 }
             }
 
@@ -871,6 +1575,111 @@ mod generate_tests {
     }
 }
 
+#[cfg(test)]
+mod generate_compatibility_check_tests {
+    use super::*;
+
+    #[test]
+    fn check() {
+        assert_tokens_equals(
+            generate_compatibility_check(),
+            quote! {
+                const _: () = ::rust_jni::introspection::__check_compatible_with(#RUST_JNI_COMPATIBLE_WITH);
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod generate_binding_info_tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let input = GeneratorData {
+            definitions: vec![],
+        };
+        let generator_version = env!("CARGO_PKG_VERSION");
+        let expected = quote! {
+            #[doc(hidden)]
+            pub fn __rust_jni_binding_info() -> ::rust_jni::introspection::BindingInfo {
+                ::rust_jni::introspection::BindingInfo {
+                    crate_name: env!("CARGO_PKG_NAME"),
+                    crate_version: env!("CARGO_PKG_VERSION"),
+                    generator_version: #generator_version,
+                    classes: &[],
+                    interfaces: &[],
+                }
+            }
+
+            ::rust_jni::introspection::__inventory::submit! {
+                ::rust_jni::introspection::BindingRegistration::new(__rust_jni_binding_info)
+            }
+        };
+        assert_tokens_equals(generate_binding_info(&input), expected);
+    }
+
+    #[test]
+    fn definitions() {
+        let input = GeneratorData {
+            definitions: vec![
+                GeneratorDefinition::Interface(Interface {
+                    interface: Ident::new("test1", Span::call_site()),
+                    public: false,
+                    extends: vec![],
+                    methods: vec![],
+                }),
+                GeneratorDefinition::Class(Class {
+                    class: Ident::new("test2", Span::call_site()),
+                    public: false,
+                    super_class: quote! {c::d::test3},
+                    transitive_extends: vec![],
+                    implements: vec![],
+                    signature: Literal::string("test/sign2"),
+                    full_signature: Literal::string("test/signature2"),
+                    binary_name: Literal::string("test.sign2"),
+                    super_signature: Literal::string("c/d/test3"),
+                    interface_signatures: vec![
+                        Literal::string("e/f/test4"),
+                        Literal::string("e/f/test5"),
+                    ],
+                    methods: vec![],
+                    static_methods: vec![],
+                    native_methods: vec![],
+                    static_native_methods: vec![],
+                    c_abi: None,
+                    constructors: vec![],
+                }),
+            ],
+        };
+        let generator_version = env!("CARGO_PKG_VERSION");
+        let expected = quote! {
+            #[doc(hidden)]
+            pub fn __rust_jni_binding_info() -> ::rust_jni::introspection::BindingInfo {
+                ::rust_jni::introspection::BindingInfo {
+                    crate_name: env!("CARGO_PKG_NAME"),
+                    crate_version: env!("CARGO_PKG_VERSION"),
+                    generator_version: #generator_version,
+                    classes: &[
+                        ::rust_jni::introspection::ClassBindingInfo {
+                            name: "test2",
+                            signature: "test/signature2",
+                            super_class: "c/d/test3",
+                            interfaces: &["e/f/test4", "e/f/test5",],
+                        },
+                    ],
+                    interfaces: &["test1",],
+                }
+            }
+
+            ::rust_jni::introspection::__inventory::submit! {
+                ::rust_jni::introspection::BindingRegistration::new(__rust_jni_binding_info)
+            }
+        };
+        assert_tokens_equals(generate_binding_info(&input), expected);
+    }
+}
+
 #[cfg(test)]
 mod generate_interface_tests {
     use super::*;
@@ -909,6 +1718,73 @@ mod generate_interface_tests {
         assert_tokens_equals(generate(&input), expected);
     }
 
+    #[test]
+    fn static_methods() {
+        let input = GeneratorData {
+            definitions: vec![GeneratorDefinition::InterfaceStatics(InterfaceStatics {
+                name: Ident::new("test1Statics", Span::call_site()),
+                public: true,
+                signature: Literal::string("test/sign1"),
+                full_signature: Literal::string("test/signature1"),
+                static_methods: vec![ClassMethod {
+                    name: Ident::new("test_method_1", Span::call_site()),
+                    java_name: Literal::string("testMethod1"),
+                    return_type: quote! {return_type_1},
+                    public: true,
+                    argument_names: vec![Ident::new("arg1", Span::call_site())],
+                    argument_types: vec![quote! {type1}],
+                    monitor_guard: false,
+                    map_result: None,
+                    nonnull_result: false,
+                    unchecked_feature: None,
+                    since: None,
+                    visibility: None,
+                    throws: None,
+                }],
+            })],
+        };
+        let expected = quote! {
+            #[derive(Debug)]
+            pub struct test1Statics<'env> {
+                _env: ::rust_jni::__generator::marker::PhantomData<&'env ()>,
+            }
+
+            impl<'a> ::rust_jni::JniSignature for test1Statics<'a> {
+                fn signature() -> &'static str {
+                    "test/signature1"
+                }
+            }
+
+            impl<'a> test1Statics<'a> {
+                pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
+                }
+
+                pub fn test_method_1(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    arg1: type1,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, return_type_1> {
+                    unsafe {
+                        env.with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_static_method::<Self, _, _,
+                                fn(type1,) -> return_type_1
+                            >
+                            (
+                                env,
+                                token,
+                                concat!("testMethod1", "\0"),
+                                (arg1,),
+                            )
+                        })
+                    }
+                }
+            }
+        };
+        assert_tokens_equals(generate(&input), expected);
+    }
+
     #[test]
     fn extends() {
         let input = GeneratorData {
@@ -985,10 +1861,14 @@ mod generate_class_tests {
                 implements: vec![],
                 signature: Literal::string("test/sign1"),
                 full_signature: Literal::string("test/signature1"),
+                binary_name: Literal::string("test.sign1"),
+                super_signature: Literal::string("java/lang/Object"),
+                interface_signatures: vec![],
                 methods: vec![],
                 static_methods: vec![],
                 native_methods: vec![],
                 static_native_methods: vec![],
+                c_abi: None,
                 constructors: vec![],
             })],
         };
@@ -998,38 +1878,21 @@ mod generate_class_tests {
                 object: c::d::test2<'env>,
             }
 
-            impl<'a> ::rust_jni::JavaType for test1<'a> {
-                #[doc(hidden)]
-                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-                #[doc(hidden)]
-                fn __signature() -> &'static str {
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
                     "test/signature1"
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::ToJni for test1<'a> {
-                unsafe fn __to_jni(&self) -> Self::__JniType {
-                    self.raw_object()
-                }
-            }
-
-            impl<'a> ::rust_jni::__generator::FromJni<'a> for test1<'a> {
-                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
                     Self {
-                        object: <c::d::test2 as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
+                        object: <c::d::test2 as ::rust_jni::FromObject<'a>>::from_object(object),
                     }
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, test1<'a>> for test1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b test1<'a> {
-                    self
-                }
-            }
-
-            impl<'a> ::std::ops::Deref for test1<'a> {
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
                 type Target = c::d::test2<'a>;
 
                 fn deref(&self) -> &Self::Target {
@@ -1037,36 +1900,63 @@ mod generate_class_tests {
                 }
             }
 
-            impl<'a> test1<'a> {
-                pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
+                }
+            }
+
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
+                pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
                 {
-                    self.object
-                        .clone(token)
-                        .map(|object| Self { object })
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
                 }
 
                 pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                     self.object.to_string(token)
                 }
             }
 
-            impl<'a> ::std::fmt::Display for test1<'a> {
-                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    self.object.fmt(formatter)
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
-                    self.object.eq(other)
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
                 }
             }
 
@@ -1086,10 +1976,14 @@ mod generate_class_tests {
                 implements: vec![],
                 signature: Literal::string("test/sign1"),
                 full_signature: Literal::string("test/signature1"),
+                binary_name: Literal::string("test.sign1"),
+                super_signature: Literal::string("java/lang/Object"),
+                interface_signatures: vec![],
                 methods: vec![],
                 static_methods: vec![],
                 native_methods: vec![],
                 static_native_methods: vec![],
+                c_abi: None,
                 constructors: vec![],
             })],
         };
@@ -1099,75 +1993,85 @@ mod generate_class_tests {
                 object: c::d::test2<'env>,
             }
 
-            impl<'a> ::rust_jni::JavaType for test1<'a> {
-                #[doc(hidden)]
-                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-                #[doc(hidden)]
-                fn __signature() -> &'static str {
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
                     "test/signature1"
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::ToJni for test1<'a> {
-                unsafe fn __to_jni(&self) -> Self::__JniType {
-                    self.raw_object()
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::FromJni<'a> for test1<'a> {
-                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
-                    Self {
-                        object: <c::d::test2 as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
-                    }
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
+                type Target = c::d::test2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, test1<'a>> for test1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b test1<'a> {
-                    self
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
                 }
             }
 
-            impl<'a> ::std::ops::Deref for test1<'a> {
-                type Target = c::d::test2<'a>;
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
 
-                fn deref(&self) -> &Self::Target {
-                    &self.object
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
                 }
             }
 
             impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
                 {
-                    self.object
-                        .clone(token)
-                        .map(|object| Self { object })
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
                 }
 
                 pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                     self.object.to_string(token)
                 }
             }
 
-            impl<'a> ::std::fmt::Display for test1<'a> {
-                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    self.object.fmt(formatter)
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
-                    self.object.eq(other)
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
                 }
             }
 
@@ -1187,10 +2091,14 @@ mod generate_class_tests {
                 implements: vec![],
                 signature: Literal::string("test/sign1"),
                 full_signature: Literal::string("test/signature1"),
+                binary_name: Literal::string("test.sign1"),
+                super_signature: Literal::string("java/lang/Object"),
+                interface_signatures: vec![],
                 methods: vec![],
                 static_methods: vec![],
                 native_methods: vec![],
                 static_native_methods: vec![],
+                c_abi: None,
                 constructors: vec![],
             })],
         };
@@ -1200,89 +2108,109 @@ mod generate_class_tests {
                 object: c::d::test2<'env>,
             }
 
-            impl<'a> ::rust_jni::JavaType for test1<'a> {
-                #[doc(hidden)]
-                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-                #[doc(hidden)]
-                fn __signature() -> &'static str {
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
                     "test/signature1"
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::ToJni for test1<'a> {
-                unsafe fn __to_jni(&self) -> Self::__JniType {
-                    self.raw_object()
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::FromJni<'a> for test1<'a> {
-                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
-                    Self {
-                        object: <c::d::test2 as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
-                    }
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
+                type Target = c::d::test2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, test1<'a>> for test1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b test1<'a> {
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<c::d::test2<'a>> for test1<'a> {
+                fn as_ref(&self) -> &c::d::test2<'a> {
                     self
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, c::d::test2<'a>> for test1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b c::d::test2<'a> {
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<c::d::test2<'a>> for test1<'a> {
+                fn borrow(&self) -> &c::d::test2<'a> {
                     self
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, c::d::test3<'a>> for test1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b c::d::test3<'a> {
+            impl<'a> ::rust_jni::__generator::convert::AsRef<c::d::test3<'a>> for test1<'a> {
+                fn as_ref(&self) -> &c::d::test3<'a> {
                     self
                 }
             }
 
-            impl<'a> ::std::ops::Deref for test1<'a> {
-                type Target = c::d::test2<'a>;
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<c::d::test3<'a>> for test1<'a> {
+                fn borrow(&self) -> &c::d::test3<'a> {
+                    self
+                }
+            }
 
-                fn deref(&self) -> &Self::Target {
-                    &self.object
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
                 }
             }
 
             impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
                 {
-                    self.object
-                        .clone(token)
-                        .map(|object| Self { object })
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
                 }
 
                 pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                     self.object.to_string(token)
                 }
             }
 
-            impl<'a> ::std::fmt::Display for test1<'a> {
-                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    self.object.fmt(formatter)
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
-                    self.object.eq(other)
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
                 }
             }
 
@@ -1302,6 +2230,9 @@ mod generate_class_tests {
                 implements: vec![],
                 signature: Literal::string("test/sign1"),
                 full_signature: Literal::string("test/signature1"),
+                binary_name: Literal::string("test.sign1"),
+                super_signature: Literal::string("java/lang/Object"),
+                interface_signatures: vec![],
                 methods: vec![
                     ClassMethod {
                         name: Ident::new("test_method_1", Span::call_site()),
@@ -1313,6 +2244,13 @@ mod generate_class_tests {
                             Ident::new("arg2", Span::call_site()),
                         ],
                         argument_types: vec![quote! {type1}, quote! {type2}],
+                        monitor_guard: false,
+                        map_result: None,
+                        nonnull_result: false,
+                        unchecked_feature: None,
+                        since: None,
+                        visibility: None,
+                        throws: None,
                     },
                     ClassMethod {
                         name: Ident::new("test_method_2", Span::call_site()),
@@ -1321,11 +2259,19 @@ mod generate_class_tests {
                         public: true,
                         argument_names: vec![],
                         argument_types: vec![],
+                        monitor_guard: false,
+                        map_result: None,
+                        nonnull_result: false,
+                        unchecked_feature: None,
+                        since: None,
+                        visibility: None,
+                        throws: None,
                     },
                 ],
                 static_methods: vec![],
                 native_methods: vec![],
                 static_native_methods: vec![],
+                c_abi: None,
                 constructors: vec![],
             })],
         };
@@ -1335,62 +2281,72 @@ mod generate_class_tests {
                 object: c::d::test2<'env>,
             }
 
-            impl<'a> ::rust_jni::JavaType for test1<'a> {
-                #[doc(hidden)]
-                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-                #[doc(hidden)]
-                fn __signature() -> &'static str {
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
                     "test/signature1"
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::ToJni for test1<'a> {
-                unsafe fn __to_jni(&self) -> Self::__JniType {
-                    self.raw_object()
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
+                type Target = c::d::test2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::FromJni<'a> for test1<'a> {
-                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
-                    Self {
-                        object: <c::d::test2 as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
-                    }
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, test1<'a>> for test1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b test1<'a> {
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
                     self
                 }
             }
 
-            impl<'a> ::std::ops::Deref for test1<'a> {
-                type Target = c::d::test2<'a>;
-
-                fn deref(&self) -> &Self::Target {
-                    &self.object
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
                 }
             }
 
             impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
                 {
-                    self.object
-                        .clone(token)
-                        .map(|object| Self { object })
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
                 }
 
                 pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                     self.object.to_string(token)
                 }
 
@@ -1401,15 +2357,17 @@ mod generate_class_tests {
                     token: &::rust_jni::NoException<'a>,
                 ) -> ::rust_jni::JavaResult<'a, return_type_1> {
                     unsafe {
-                        ::rust_jni::__generator::call_method::<_, _, _,
-                            fn(type1, type2,) -> return_type_1
-                        >
-                        (
-                            self,
-                            "testMethod1",
-                            (arg1, arg2,),
-                            token,
-                        )
+                        self.env().with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_method::<_, _, _,
+                                fn(type1, type2,) -> return_type_1
+                            >
+                            (
+                                self,
+                                token,
+                                concat!("testMethod1", "\0"),
+                                (arg1, arg2,),
+                            )
+                        })
                     }
                 }
 
@@ -1418,28 +2376,30 @@ mod generate_class_tests {
                     token: &::rust_jni::NoException<'a>,
                 ) -> ::rust_jni::JavaResult<'a, return_type_2> {
                     unsafe {
-                        ::rust_jni::__generator::call_method::<_, _, _,
-                            fn() -> return_type_2
-                        >
-                        (
-                            self,
-                            "testMethod2",
-                            (),
-                            token,
-                        )
+                        self.env().with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_method::<_, _, _,
+                                fn() -> return_type_2
+                            >
+                            (
+                                self,
+                                token,
+                                concat!("testMethod2", "\0"),
+                                (),
+                            )
+                        })
                     }
                 }
             }
 
-            impl<'a> ::std::fmt::Display for test1<'a> {
-                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    self.object.fmt(formatter)
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
-                    self.object.eq(other)
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
                 }
             }
 
@@ -1459,31 +2419,1863 @@ mod generate_class_tests {
                 implements: vec![],
                 signature: Literal::string("test/sign1"),
                 full_signature: Literal::string("test/signature1"),
+                binary_name: Literal::string("test.sign1"),
+                super_signature: Literal::string("java/lang/Object"),
+                interface_signatures: vec![],
                 methods: vec![],
                 static_methods: vec![
                     ClassMethod {
                         name: Ident::new("test_method_1", Span::call_site()),
-                        java_name: Literal::string("testMethod1"),
-                        return_type: quote! {return_type_1},
+                        java_name: Literal::string("testMethod1"),
+                        return_type: quote! {return_type_1},
+                        public: false,
+                        argument_names: vec![
+                            Ident::new("arg1", Span::call_site()),
+                            Ident::new("arg2", Span::call_site()),
+                        ],
+                        argument_types: vec![quote! {type1}, quote! {type2}],
+                        monitor_guard: false,
+                        map_result: None,
+                        nonnull_result: false,
+                        unchecked_feature: None,
+                        since: None,
+                        visibility: None,
+                        throws: None,
+                    },
+                    ClassMethod {
+                        name: Ident::new("test_method_2", Span::call_site()),
+                        java_name: Literal::string("testMethod2"),
+                        return_type: quote! {return_type_2},
+                        public: true,
+                        argument_names: vec![],
+                        argument_types: vec![],
+                        monitor_guard: false,
+                        map_result: None,
+                        nonnull_result: false,
+                        unchecked_feature: None,
+                        since: None,
+                        visibility: None,
+                        throws: None,
+                    },
+                ],
+                native_methods: vec![],
+                static_native_methods: vec![],
+                c_abi: None,
+                constructors: vec![],
+            })],
+        };
+        let expected = quote! {
+            #[derive(Debug)]
+            struct test1<'env> {
+                object: c::d::test2<'env>,
+            }
+
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
+                    "test/signature1"
+                }
+            }
+
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
+                type Target = c::d::test2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
+                }
+            }
+
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
+                pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
+                }
+
+                pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
+                where
+                    Self: Sized,
+                {
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
+                }
+
+                pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
+                    self.object.to_string(token)
+                }
+
+                fn test_method_1(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    arg1: type1,
+                    arg2: type2,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, return_type_1> {
+                    unsafe {
+                        env.with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_static_method::<Self, _, _,
+                                fn(type1, type2,) -> return_type_1
+                            >
+                            (
+                                env,
+                                token,
+                                concat!("testMethod1", "\0"),
+                                (arg1, arg2,),
+                            )
+                        })
+                    }
+                }
+
+                pub fn test_method_2(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, return_type_2> {
+                    unsafe {
+                        env.with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_static_method::<Self, _, _,
+                                fn() -> return_type_2
+                            >
+                            (
+                                env,
+                                token,
+                                concat!("testMethod2", "\0"),
+                                (),
+                            )
+                        })
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
+                }
+            }
+
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
+                fn eq(&self, other: &T) -> bool {
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
+                }
+            }
+
+            impl<'a> Eq for test1<'a> {}
+        };
+        assert_tokens_equals(generate(&input), expected);
+    }
+
+    #[test]
+    fn synchronized_methods() {
+        let input = GeneratorData {
+            definitions: vec![GeneratorDefinition::Class(Class {
+                class: Ident::new("test1", Span::call_site()),
+                public: false,
+                super_class: quote! {c::d::test2},
+                transitive_extends: vec![],
+                implements: vec![],
+                signature: Literal::string("test/sign1"),
+                full_signature: Literal::string("test/signature1"),
+                binary_name: Literal::string("test.sign1"),
+                super_signature: Literal::string("java/lang/Object"),
+                interface_signatures: vec![],
+                methods: vec![ClassMethod {
+                    name: Ident::new("test_method_1", Span::call_site()),
+                    java_name: Literal::string("testMethod1"),
+                    return_type: quote! {return_type_1},
+                    public: false,
+                    argument_names: vec![Ident::new("arg1", Span::call_site())],
+                    argument_types: vec![quote! {type1}],
+                    monitor_guard: true,
+                    map_result: None,
+                    nonnull_result: false,
+                    unchecked_feature: None,
+                    since: None,
+                    visibility: None,
+                    throws: None,
+                }],
+                static_methods: vec![ClassMethod {
+                    name: Ident::new("test_method_2", Span::call_site()),
+                    java_name: Literal::string("testMethod2"),
+                    return_type: quote! {return_type_2},
+                    public: true,
+                    argument_names: vec![],
+                    argument_types: vec![],
+                    monitor_guard: true,
+                    map_result: None,
+                    nonnull_result: false,
+                    unchecked_feature: None,
+                    since: None,
+                    visibility: None,
+                    throws: None,
+                }],
+                native_methods: vec![],
+                static_native_methods: vec![],
+                c_abi: None,
+                constructors: vec![],
+            })],
+        };
+        let expected = quote! {
+            #[derive(Debug)]
+            struct test1<'env> {
+                object: c::d::test2<'env>,
+            }
+
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
+                    "test/signature1"
+                }
+            }
+
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
+                type Target = c::d::test2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
+                }
+            }
+
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
+                pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
+                }
+
+                pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
+                where
+                    Self: Sized,
+                {
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
+                }
+
+                pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
+                    self.object.to_string(token)
+                }
+
+                fn test_method_1(
+                    &self,
+                    arg1: type1,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, return_type_1> {
+                    let _monitor_guard = ::rust_jni::MonitorGuard::new(self, token)?;
+                    unsafe {
+                        self.env().with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_method::<_, _, _,
+                                fn(type1,) -> return_type_1
+                            >
+                            (
+                                self,
+                                token,
+                                concat!("testMethod1", "\0"),
+                                (arg1,),
+                            )
+                        })
+                    }
+                }
+
+                pub fn test_method_2(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, return_type_2> {
+                    let class = Self::get_class(env, token)?;
+                    let _monitor_guard = ::rust_jni::MonitorGuard::new(&class, token)?;
+                    unsafe {
+                        env.with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_static_method::<Self, _, _,
+                                fn() -> return_type_2
+                            >
+                            (
+                                env,
+                                token,
+                                concat!("testMethod2", "\0"),
+                                (),
+                            )
+                        })
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
+                }
+            }
+
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
+                fn eq(&self, other: &T) -> bool {
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
+                }
+            }
+
+            impl<'a> Eq for test1<'a> {}
+        };
+        assert_tokens_equals(generate(&input), expected);
+    }
+
+    #[test]
+    fn since_methods() {
+        let input = GeneratorData {
+            definitions: vec![GeneratorDefinition::Class(Class {
+                class: Ident::new("test1", Span::call_site()),
+                public: false,
+                super_class: quote! {c::d::test2},
+                transitive_extends: vec![],
+                implements: vec![],
+                signature: Literal::string("test/sign1"),
+                full_signature: Literal::string("test/signature1"),
+                binary_name: Literal::string("test.sign1"),
+                super_signature: Literal::string("java/lang/Object"),
+                interface_signatures: vec![],
+                methods: vec![ClassMethod {
+                    name: Ident::new("test_method_1", Span::call_site()),
+                    java_name: Literal::string("testMethod1"),
+                    return_type: quote! {return_type_1},
+                    public: false,
+                    argument_names: vec![Ident::new("arg1", Span::call_site())],
+                    argument_types: vec![quote! {type1}],
+                    monitor_guard: false,
+                    map_result: None,
+                    nonnull_result: false,
+                    unchecked_feature: None,
+                    since: Some(Literal::u32_unsuffixed(11)),
+                    visibility: None,
+                    throws: None,
+                }],
+                static_methods: vec![ClassMethod {
+                    name: Ident::new("test_method_2", Span::call_site()),
+                    java_name: Literal::string("testMethod2"),
+                    return_type: quote! {return_type_2},
+                    public: true,
+                    argument_names: vec![],
+                    argument_types: vec![],
+                    monitor_guard: false,
+                    map_result: None,
+                    nonnull_result: false,
+                    unchecked_feature: None,
+                    since: Some(Literal::u32_unsuffixed(11)),
+                    visibility: None,
+                    throws: None,
+                }],
+                native_methods: vec![],
+                static_native_methods: vec![],
+                c_abi: None,
+                constructors: vec![Constructor {
+                    name: Ident::new("init", Span::call_site()),
+                    public: true,
+                    argument_names: vec![],
+                    argument_types: vec![],
+                    since: Some(Literal::u32_unsuffixed(9)),
+                    visibility: None,
+                }],
+            })],
+        };
+        let expected = quote! {
+            #[derive(Debug)]
+            struct test1<'env> {
+                object: c::d::test2<'env>,
+            }
+
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
+                    "test/signature1"
+                }
+            }
+
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
+                type Target = c::d::test2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
+                }
+            }
+
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
+                pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
+                }
+
+                pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
+                where
+                    Self: Sized,
+                {
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
+                }
+
+                pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
+                    self.object.to_string(token)
+                }
+
+                pub fn init(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::__generator::result::Result<
+                    ::rust_jni::JavaResult<'a, Self>,
+                    ::rust_jni::UnsupportedOnThisJvm,
+                > {
+                    match ::rust_jni::require_jvm_feature_level(env, token, 9) {
+                        Err(exception) => return Ok(Err(exception)),
+                        Ok(Err(error)) => return Err(error),
+                        Ok(Ok(())) => {}
+                    }
+                    Ok((|| -> ::rust_jni::JavaResult<'a, Self> {
+                        unsafe {
+                            env.with_local_frame(token, 4, |token| {
+                                ::rust_jni::call_constructor::<Self, _, fn()>
+                                (
+                                    env,
+                                    token,
+                                    (),
+                                )
+                            })
+                        }
+                    })())
+                }
+
+                fn test_method_1(
+                    &self,
+                    arg1: type1,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::__generator::result::Result<
+                    ::rust_jni::JavaResult<'a, return_type_1>,
+                    ::rust_jni::UnsupportedOnThisJvm,
+                > {
+                    match ::rust_jni::require_jvm_feature_level(self.env(), token, 11) {
+                        Err(exception) => return Ok(Err(exception)),
+                        Ok(Err(error)) => return Err(error),
+                        Ok(Ok(())) => {}
+                    }
+                    Ok((|| -> ::rust_jni::JavaResult<'a, return_type_1> {
+                        unsafe {
+                            self.env().with_local_frame(token, 4, |token| {
+                                ::rust_jni::call_method::<_, _, _,
+                                    fn(type1,) -> return_type_1
+                                >
+                                (
+                                    self,
+                                    token,
+                                    concat!("testMethod1", "\0"),
+                                    (arg1,),
+                                )
+                            })
+                        }
+                    })())
+                }
+
+                pub fn test_method_2(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::__generator::result::Result<
+                    ::rust_jni::JavaResult<'a, return_type_2>,
+                    ::rust_jni::UnsupportedOnThisJvm,
+                > {
+                    match ::rust_jni::require_jvm_feature_level(env, token, 11) {
+                        Err(exception) => return Ok(Err(exception)),
+                        Ok(Err(error)) => return Err(error),
+                        Ok(Ok(())) => {}
+                    }
+                    Ok((|| -> ::rust_jni::JavaResult<'a, return_type_2> {
+                        unsafe {
+                            env.with_local_frame(token, 4, |token| {
+                                ::rust_jni::call_static_method::<Self, _, _,
+                                    fn() -> return_type_2
+                                >
+                                (
+                                    env,
+                                    token,
+                                    concat!("testMethod2", "\0"),
+                                    (),
+                                )
+                            })
+                        }
+                    })())
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
+                }
+            }
+
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
+                fn eq(&self, other: &T) -> bool {
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
+                }
+            }
+
+            impl<'a> Eq for test1<'a> {}
+        };
+        assert_tokens_equals(generate(&input), expected);
+    }
+
+    #[test]
+    fn rust_visibility_methods() {
+        let input = GeneratorData {
+            definitions: vec![GeneratorDefinition::Class(Class {
+                class: Ident::new("test1", Span::call_site()),
+                public: false,
+                super_class: quote! {c::d::test2},
+                transitive_extends: vec![],
+                implements: vec![],
+                signature: Literal::string("test/sign1"),
+                full_signature: Literal::string("test/signature1"),
+                binary_name: Literal::string("test.sign1"),
+                super_signature: Literal::string("java/lang/Object"),
+                interface_signatures: vec![],
+                methods: vec![ClassMethod {
+                    name: Ident::new("test_method_1", Span::call_site()),
+                    java_name: Literal::string("testMethod1"),
+                    return_type: quote! {return_type_1},
+                    public: true,
+                    argument_names: vec![Ident::new("arg1", Span::call_site())],
+                    argument_types: vec![quote! {type1}],
+                    monitor_guard: false,
+                    map_result: None,
+                    nonnull_result: false,
+                    unchecked_feature: None,
+                    since: None,
+                    visibility: Some(quote! {pub(crate)}),
+                    throws: None,
+                }],
+                static_methods: vec![ClassMethod {
+                    name: Ident::new("test_method_2", Span::call_site()),
+                    java_name: Literal::string("testMethod2"),
+                    return_type: quote! {return_type_2},
+                    public: true,
+                    argument_names: vec![],
+                    argument_types: vec![],
+                    monitor_guard: false,
+                    map_result: None,
+                    nonnull_result: false,
+                    unchecked_feature: None,
+                    since: None,
+                    visibility: Some(quote! {}),
+                    throws: None,
+                }],
+                native_methods: vec![],
+                static_native_methods: vec![],
+                c_abi: None,
+                constructors: vec![Constructor {
+                    name: Ident::new("init", Span::call_site()),
+                    public: true,
+                    argument_names: vec![],
+                    argument_types: vec![],
+                    since: None,
+                    visibility: Some(quote! {pub(super)}),
+                }],
+            })],
+        };
+        let expected = quote! {
+            #[derive(Debug)]
+            struct test1<'env> {
+                object: c::d::test2<'env>,
+            }
+
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
+                    "test/signature1"
+                }
+            }
+
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
+                type Target = c::d::test2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
+                }
+            }
+
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
+                pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
+                }
+
+                pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
+                where
+                    Self: Sized,
+                {
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
+                }
+
+                pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
+                    self.object.to_string(token)
+                }
+
+                pub(super) fn init(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, Self> {
+                    unsafe {
+                        env.with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_constructor::<Self, _, fn()>
+                            (
+                                env,
+                                token,
+                                (),
+                            )
+                        })
+                    }
+                }
+
+                pub(crate) fn test_method_1(
+                    &self,
+                    arg1: type1,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, return_type_1> {
+                    unsafe {
+                        self.env().with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_method::<_, _, _,
+                                fn(type1,) -> return_type_1
+                            >
+                            (
+                                self,
+                                token,
+                                concat!("testMethod1", "\0"),
+                                (arg1,),
+                            )
+                        })
+                    }
+                }
+
+                fn test_method_2(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, return_type_2> {
+                    unsafe {
+                        env.with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_static_method::<Self, _, _,
+                                fn() -> return_type_2
+                            >
+                            (
+                                env,
+                                token,
+                                concat!("testMethod2", "\0"),
+                                (),
+                            )
+                        })
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
+                }
+            }
+
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
+                fn eq(&self, other: &T) -> bool {
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
+                }
+            }
+
+            impl<'a> Eq for test1<'a> {}
+        };
+        assert_tokens_equals(generate(&input), expected);
+    }
+
+    #[test]
+    fn map_result_methods() {
+        let input = GeneratorData {
+            definitions: vec![GeneratorDefinition::Class(Class {
+                class: Ident::new("test1", Span::call_site()),
+                public: false,
+                super_class: quote! {c::d::test2},
+                transitive_extends: vec![],
+                implements: vec![],
+                signature: Literal::string("test/sign1"),
+                full_signature: Literal::string("test/signature1"),
+                binary_name: Literal::string("test.sign1"),
+                super_signature: Literal::string("java/lang/Object"),
+                interface_signatures: vec![],
+                methods: vec![ClassMethod {
+                    name: Ident::new("test_method_1", Span::call_site()),
+                    java_name: Literal::string("testMethod1"),
+                    return_type: quote! {return_type_1},
+                    public: false,
+                    argument_names: vec![Ident::new("arg1", Span::call_site())],
+                    argument_types: vec![quote! {type1}],
+                    monitor_guard: false,
+                    map_result: Some(MapResult {
+                        function: quote! {a::b::map1},
+                        result_type: quote! {result_type_1},
+                    }),
+                    nonnull_result: false,
+                    unchecked_feature: None,
+                    since: None,
+                    visibility: None,
+                    throws: None,
+                }],
+                static_methods: vec![ClassMethod {
+                    name: Ident::new("test_method_2", Span::call_site()),
+                    java_name: Literal::string("testMethod2"),
+                    return_type: quote! {return_type_2},
+                    public: true,
+                    argument_names: vec![],
+                    argument_types: vec![],
+                    monitor_guard: false,
+                    map_result: Some(MapResult {
+                        function: quote! {map2},
+                        result_type: quote! {result_type_2},
+                    }),
+                    nonnull_result: false,
+                    unchecked_feature: None,
+                    since: None,
+                    visibility: None,
+                    throws: None,
+                }],
+                native_methods: vec![],
+                static_native_methods: vec![],
+                c_abi: None,
+                constructors: vec![],
+            })],
+        };
+        let expected = quote! {
+            #[derive(Debug)]
+            struct test1<'env> {
+                object: c::d::test2<'env>,
+            }
+
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
+                    "test/signature1"
+                }
+            }
+
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
+                type Target = c::d::test2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
+                }
+            }
+
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
+                pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
+                }
+
+                pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
+                where
+                    Self: Sized,
+                {
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
+                }
+
+                pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
+                    self.object.to_string(token)
+                }
+
+                fn test_method_1(
+                    &self,
+                    arg1: type1,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, result_type_1> {
+                    let result = unsafe {
+                        self.env().with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_method::<_, _, _,
+                                fn(type1,) -> return_type_1
+                            >
+                            (
+                                self,
+                                token,
+                                concat!("testMethod1", "\0"),
+                                (arg1,),
+                            )
+                        })
+                    }?;
+                    a::b::map1(result, token)
+                }
+
+                pub fn test_method_2(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, result_type_2> {
+                    let result = unsafe {
+                        env.with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_static_method::<Self, _, _,
+                                fn() -> return_type_2
+                            >
+                            (
+                                env,
+                                token,
+                                concat!("testMethod2", "\0"),
+                                (),
+                            )
+                        })
+                    }?;
+                    map2(result, token)
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
+                }
+            }
+
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
+                fn eq(&self, other: &T) -> bool {
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
+                }
+            }
+
+            impl<'a> Eq for test1<'a> {}
+        };
+        assert_tokens_equals(generate(&input), expected);
+    }
+
+    #[test]
+    fn throws_methods() {
+        let input = GeneratorData {
+            definitions: vec![GeneratorDefinition::Class(Class {
+                class: Ident::new("test1", Span::call_site()),
+                public: false,
+                super_class: quote! {c::d::test2},
+                transitive_extends: vec![],
+                implements: vec![],
+                signature: Literal::string("test/sign1"),
+                full_signature: Literal::string("test/signature1"),
+                binary_name: Literal::string("test.sign1"),
+                super_signature: Literal::string("java/lang/Object"),
+                interface_signatures: vec![],
+                methods: vec![ClassMethod {
+                    name: Ident::new("test_method_1", Span::call_site()),
+                    java_name: Literal::string("testMethod1"),
+                    return_type: quote! {return_type_1},
+                    public: false,
+                    argument_names: vec![Ident::new("arg1", Span::call_site())],
+                    argument_types: vec![quote! {type1}],
+                    monitor_guard: false,
+                    map_result: None,
+                    nonnull_result: false,
+                    unchecked_feature: None,
+                    since: None,
+                    visibility: None,
+                    throws: Some(quote! {exception_1}),
+                }],
+                static_methods: vec![ClassMethod {
+                    name: Ident::new("test_method_2", Span::call_site()),
+                    java_name: Literal::string("testMethod2"),
+                    return_type: quote! {return_type_2},
+                    public: true,
+                    argument_names: vec![],
+                    argument_types: vec![],
+                    monitor_guard: false,
+                    map_result: None,
+                    nonnull_result: false,
+                    unchecked_feature: None,
+                    since: None,
+                    visibility: None,
+                    throws: Some(quote! {exception_2}),
+                }],
+                native_methods: vec![],
+                static_native_methods: vec![],
+                c_abi: None,
+                constructors: vec![],
+            })],
+        };
+        let expected = quote! {
+            #[derive(Debug)]
+            struct test1<'env> {
+                object: c::d::test2<'env>,
+            }
+
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
+                    "test/signature1"
+                }
+            }
+
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
+                type Target = c::d::test2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
+                }
+            }
+
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
+                pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
+                }
+
+                pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
+                where
+                    Self: Sized,
+                {
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
+                }
+
+                pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
+                    self.object.to_string(token)
+                }
+
+                fn test_method_1(
+                    &self,
+                    arg1: type1,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::result::Result<return_type_1, exception_1> > {
+                    ::rust_jni::JavaResultExt::catching::<exception_1>(unsafe {
+                        self.env().with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_method::<_, _, _,
+                                fn(type1,) -> return_type_1
+                            >
+                            (
+                                self,
+                                token,
+                                concat!("testMethod1", "\0"),
+                                (arg1,),
+                            )
+                        })
+                    }, token)
+                }
+
+                pub fn test_method_2(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::result::Result<return_type_2, exception_2> > {
+                    ::rust_jni::JavaResultExt::catching::<exception_2>(unsafe {
+                        env.with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_static_method::<Self, _, _,
+                                fn() -> return_type_2
+                            >
+                            (
+                                env,
+                                token,
+                                concat!("testMethod2", "\0"),
+                                (),
+                            )
+                        })
+                    }, token)
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
+                }
+            }
+
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
+                fn eq(&self, other: &T) -> bool {
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
+                }
+            }
+
+            impl<'a> Eq for test1<'a> {}
+        };
+        assert_tokens_equals(generate(&input), expected);
+    }
+
+    #[test]
+    fn nonnull_result_methods() {
+        let input = GeneratorData {
+            definitions: vec![GeneratorDefinition::Class(Class {
+                class: Ident::new("test1", Span::call_site()),
+                public: false,
+                super_class: quote! {c::d::test2},
+                transitive_extends: vec![],
+                implements: vec![],
+                signature: Literal::string("test/sign1"),
+                full_signature: Literal::string("test/signature1"),
+                binary_name: Literal::string("test.sign1"),
+                super_signature: Literal::string("java/lang/Object"),
+                interface_signatures: vec![],
+                methods: vec![ClassMethod {
+                    name: Ident::new("test_method_1", Span::call_site()),
+                    java_name: Literal::string("testMethod1"),
+                    return_type: quote! {return_type_1},
+                    public: false,
+                    argument_names: vec![Ident::new("arg1", Span::call_site())],
+                    argument_types: vec![quote! {type1}],
+                    monitor_guard: false,
+                    map_result: None,
+                    nonnull_result: true,
+                    unchecked_feature: None,
+                    since: None,
+                    visibility: None,
+                    throws: None,
+                }],
+                static_methods: vec![ClassMethod {
+                    name: Ident::new("test_method_2", Span::call_site()),
+                    java_name: Literal::string("testMethod2"),
+                    return_type: quote! {return_type_2},
+                    public: true,
+                    argument_names: vec![],
+                    argument_types: vec![],
+                    monitor_guard: false,
+                    map_result: Some(MapResult {
+                        function: quote! {map2},
+                        result_type: quote! {result_type_2},
+                    }),
+                    nonnull_result: true,
+                    unchecked_feature: None,
+                    since: None,
+                    visibility: None,
+                    throws: None,
+                }],
+                native_methods: vec![],
+                static_native_methods: vec![],
+                c_abi: None,
+                constructors: vec![],
+            })],
+        };
+        let expected = quote! {
+            #[derive(Debug)]
+            struct test1<'env> {
+                object: c::d::test2<'env>,
+            }
+
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
+                    "test/signature1"
+                }
+            }
+
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
+                type Target = c::d::test2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
+                }
+            }
+
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
+                pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
+                }
+
+                pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
+                where
+                    Self: Sized,
+                {
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
+                }
+
+                pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
+                    self.object.to_string(token)
+                }
+
+                fn test_method_1(
+                    &self,
+                    arg1: type1,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, return_type_1> {
+                    (unsafe {
+                        self.env().with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_method::<_, _, _,
+                                fn(type1,) -> return_type_1
+                            >
+                            (
+                                self,
+                                token,
+                                concat!("testMethod1", "\0"),
+                                (arg1,),
+                            )
+                        })
+                    })
+                    .and_then(|result| ::rust_jni::NullableJavaClassExt::or_npe(result, self.env(), token))
+                }
+
+                pub fn test_method_2(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, result_type_2> {
+                    let result = (unsafe {
+                        env.with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_static_method::<Self, _, _,
+                                fn() -> return_type_2
+                            >
+                            (
+                                env,
+                                token,
+                                concat!("testMethod2", "\0"),
+                                (),
+                            )
+                        })
+                    })
+                    .and_then(|result| ::rust_jni::NullableJavaClassExt::or_npe(result, env, token))?;
+                    map2(result, token)
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
+                }
+            }
+
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
+                fn eq(&self, other: &T) -> bool {
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
+                }
+            }
+
+            impl<'a> Eq for test1<'a> {}
+        };
+        assert_tokens_equals(generate(&input), expected);
+    }
+
+    #[test]
+    fn unchecked_methods() {
+        let input = GeneratorData {
+            definitions: vec![GeneratorDefinition::Class(Class {
+                class: Ident::new("test1", Span::call_site()),
+                public: false,
+                super_class: quote! {c::d::test2},
+                transitive_extends: vec![],
+                implements: vec![],
+                signature: Literal::string("test/sign1"),
+                full_signature: Literal::string("test/signature1"),
+                binary_name: Literal::string("test.sign1"),
+                super_signature: Literal::string("java/lang/Object"),
+                interface_signatures: vec![],
+                methods: vec![ClassMethod {
+                    name: Ident::new("test_method_1", Span::call_site()),
+                    java_name: Literal::string("testMethod1"),
+                    return_type: quote! {return_type_1},
+                    public: false,
+                    argument_names: vec![Ident::new("arg1", Span::call_site())],
+                    argument_types: vec![quote! {type1}],
+                    monitor_guard: false,
+                    map_result: None,
+                    nonnull_result: true,
+                    unchecked_feature: Some("unchecked".to_owned()),
+                    since: None,
+                    visibility: None,
+                    throws: None,
+                }],
+                static_methods: vec![ClassMethod {
+                    name: Ident::new("test_method_2", Span::call_site()),
+                    java_name: Literal::string("testMethod2"),
+                    return_type: quote! {return_type_2},
+                    public: true,
+                    argument_names: vec![],
+                    argument_types: vec![],
+                    monitor_guard: false,
+                    map_result: Some(MapResult {
+                        function: quote! {map2},
+                        result_type: quote! {result_type_2},
+                    }),
+                    nonnull_result: true,
+                    unchecked_feature: Some("unchecked".to_owned()),
+                    since: None,
+                    visibility: None,
+                    throws: None,
+                }],
+                native_methods: vec![],
+                static_native_methods: vec![],
+                c_abi: None,
+                constructors: vec![],
+            })],
+        };
+        let expected = quote! {
+            #[derive(Debug)]
+            struct test1<'env> {
+                object: c::d::test2<'env>,
+            }
+
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
+                    "test/signature1"
+                }
+            }
+
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
+                type Target = c::d::test2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
+                }
+            }
+
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
+                pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
+                }
+
+                pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
+                where
+                    Self: Sized,
+                {
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
+                }
+
+                pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
+                    self.object.to_string(token)
+                }
+
+                fn test_method_1(
+                    &self,
+                    arg1: type1,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, return_type_1> {
+                    (unsafe {
+                        self.env().with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_method::<_, _, _,
+                                fn(type1,) -> return_type_1
+                            >
+                            (
+                                self,
+                                token,
+                                concat!("testMethod1", "\0"),
+                                (arg1,),
+                            )
+                        })
+                    })
+                    .and_then(|result| ::rust_jni::NullableJavaClassExt::or_npe(result, self.env(), token))
+                }
+
+                #[cfg(feature = "unchecked")]
+                unsafe fn test_method_1_unchecked(
+                    &self,
+                    arg1: type1,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, return_type_1> {
+                    (self.env().with_local_frame(token, 4, |token| {
+                        ::rust_jni::call_method::<_, _, _,
+                            fn(type1,) -> return_type_1
+                        >
+                        (
+                            self,
+                            token,
+                            concat!("testMethod1", "\0"),
+                            (arg1,),
+                        )
+                    }))
+                    .map(|result| result.unwrap_unchecked())
+                }
+
+                pub fn test_method_2(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, result_type_2> {
+                    let result = (unsafe {
+                        env.with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_static_method::<Self, _, _,
+                                fn() -> return_type_2
+                            >
+                            (
+                                env,
+                                token,
+                                concat!("testMethod2", "\0"),
+                                (),
+                            )
+                        })
+                    })
+                    .and_then(|result| ::rust_jni::NullableJavaClassExt::or_npe(result, env, token))?;
+                    map2(result, token)
+                }
+
+                #[cfg(feature = "unchecked")]
+                pub unsafe fn test_method_2_unchecked(
+                    env: &'a ::rust_jni::JniEnv<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, return_type_2> {
+                    (env.with_local_frame(token, 4, |token| {
+                        ::rust_jni::call_static_method::<Self, _, _,
+                            fn() -> return_type_2
+                        >
+                        (
+                            env,
+                            token,
+                            concat!("testMethod2", "\0"),
+                            (),
+                        )
+                    }))
+                    .map(|result| result.unwrap_unchecked())
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
+                }
+            }
+
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
+                fn eq(&self, other: &T) -> bool {
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
+                }
+            }
+
+            impl<'a> Eq for test1<'a> {}
+        };
+        assert_tokens_equals(generate(&input), expected);
+    }
+
+    #[test]
+    fn c_abi() {
+        let input = GeneratorData {
+            definitions: vec![GeneratorDefinition::Class(Class {
+                class: Ident::new("test1", Span::call_site()),
+                public: false,
+                super_class: quote! {c::d::test2},
+                transitive_extends: vec![],
+                implements: vec![],
+                signature: Literal::string("test/sign1"),
+                full_signature: Literal::string("test/signature1"),
+                binary_name: Literal::string("test.sign1"),
+                super_signature: Literal::string("java/lang/Object"),
+                interface_signatures: vec![],
+                methods: vec![],
+                static_methods: vec![],
+                native_methods: vec![],
+                static_native_methods: vec![],
+                c_abi: Some(CAbi {
+                    release: Ident::new("test_sign1_release", Span::call_site()),
+                    shims: vec![
+                        CAbiShim {
+                            name: Ident::new("test_sign1_init", Span::call_site()),
+                            method: Ident::new("init", Span::call_site()),
+                            kind: CAbiShimKind::Constructor,
+                            argument_names: vec![Ident::new("arg1", Span::call_site())],
+                            argument_types: vec![CAbiType::Primitive(quote! {i32})],
+                            return_type: CAbiType::Object {
+                                class: quote! {test1},
+                                nullable: false,
+                            },
+                        },
+                        CAbiShim {
+                            name: Ident::new("test_sign1_test_method_1", Span::call_site()),
+                            method: Ident::new("test_method_1", Span::call_site()),
+                            kind: CAbiShimKind::Method,
+                            argument_names: vec![
+                                Ident::new("arg1", Span::call_site()),
+                                Ident::new("arg2", Span::call_site()),
+                            ],
+                            argument_types: vec![
+                                CAbiType::Object {
+                                    class: quote! {type1},
+                                    nullable: false,
+                                },
+                                CAbiType::Object {
+                                    class: quote! {type2},
+                                    nullable: true,
+                                },
+                            ],
+                            return_type: CAbiType::Primitive(quote! {i64}),
+                        },
+                        CAbiShim {
+                            name: Ident::new("test_sign1_test_method_2", Span::call_site()),
+                            method: Ident::new("test_method_2", Span::call_site()),
+                            kind: CAbiShimKind::StaticMethod,
+                            argument_names: vec![],
+                            argument_types: vec![],
+                            return_type: CAbiType::Object {
+                                class: quote! {type3},
+                                nullable: true,
+                            },
+                        },
+                    ],
+                }),
+                constructors: vec![],
+            })],
+        };
+        let expected = quote! {
+            #[derive(Debug)]
+            struct test1<'env> {
+                object: c::d::test2<'env>,
+            }
+
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
+                    "test/signature1"
+                }
+            }
+
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
+                type Target = c::d::test2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
+                }
+            }
+
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
+                pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
+                }
+
+                pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
+                where
+                    Self: Sized,
+                {
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
+                }
+
+                pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
+                    self.object.to_string(token)
+                }
+            }
+
+            #[no_mangle]
+            #[doc(hidden)]
+            pub unsafe extern "C" fn test_sign1_init(
+                raw_env: *mut ::jni_sys::JNIEnv,
+                arg1: <i32 as ::rust_jni::__generator::NativeMethodArgument>::JniType,
+            ) -> ::jni_sys::jobject {
+                ::rust_jni::__generator::native_method_wrapper(raw_env, |env, token| {
+                    let arg1 =
+                        <i32 as ::rust_jni::__generator::NativeMethodArgument>::from_jni(env, token, arg1)?;
+                    test1::init(env, arg1, token).map(|value| ((**raw_env).NewGlobalRef.unwrap())(
+                        raw_env,
+                        ::rust_jni::__generator::ToJavaNativeResult::to_java_native_result(&value),
+                    ))
+                })
+            }
+
+            #[no_mangle]
+            #[doc(hidden)]
+            pub unsafe extern "C" fn test_sign1_test_method_1(
+                raw_env: *mut ::jni_sys::JNIEnv,
+                object: ::jni_sys::jobject,
+                arg1: ::jni_sys::jobject,
+                arg2: ::jni_sys::jobject,
+            ) -> <i64 as ::rust_jni::__generator::ToJavaNativeResult>::JniType {
+                ::rust_jni::__generator::native_method_wrapper(raw_env, |env, token| {
+                    let object = ::rust_jni::__generator::mem::ManuallyDrop::new(
+                        <test1 as ::rust_jni::__generator::NativeMethodArgument>::from_jni(env, token, object)?
+                    );
+                    let arg1 = ::rust_jni::__generator::mem::ManuallyDrop::new(
+                        <type1 as ::rust_jni::__generator::NativeMethodArgument>::from_jni(env, token, arg1)?
+                    );
+                    let arg2 = ::rust_jni::__generator::mem::ManuallyDrop::new(
+                        <::rust_jni::__generator::option::Option<type2> as ::rust_jni::__generator::NativeMethodArgument>
+                            ::from_jni(env, token, arg2)?
+                    );
+                    object.test_method_1(&*arg1, (*arg2).as_ref(), token).map(|value|
+                        ::rust_jni::__generator::ToJavaNativeResult::to_java_native_result(&value)
+                    )
+                })
+            }
+
+            #[no_mangle]
+            #[doc(hidden)]
+            pub unsafe extern "C" fn test_sign1_test_method_2(
+                raw_env: *mut ::jni_sys::JNIEnv,
+            ) -> ::jni_sys::jobject {
+                ::rust_jni::__generator::native_method_wrapper(raw_env, |env, token| {
+                    test1::test_method_2(env, token).map(|value| match value {
+                        None => ::rust_jni::__generator::ptr::null_mut(),
+                        Some(value) => ((**raw_env).NewGlobalRef.unwrap())(
+                            raw_env,
+                            ::rust_jni::__generator::ToJavaNativeResult::to_java_native_result(&value),
+                        ),
+                    })
+                })
+            }
+
+            #[no_mangle]
+            #[doc(hidden)]
+            pub unsafe extern "C" fn test_sign1_release(
+                raw_env: *mut ::jni_sys::JNIEnv,
+                object: ::jni_sys::jobject,
+            ) {
+                ((**raw_env).DeleteGlobalRef.unwrap())(raw_env, object)
+            }
+
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
+                }
+            }
+
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
+                fn eq(&self, other: &T) -> bool {
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
+                }
+            }
+
+            impl<'a> Eq for test1<'a> {}
+        };
+        assert_tokens_equals(generate(&input), expected);
+    }
+
+    #[test]
+    fn constructors() {
+        let input = GeneratorData {
+            definitions: vec![GeneratorDefinition::Class(Class {
+                class: Ident::new("test1", Span::call_site()),
+                public: false,
+                super_class: quote! {c::d::test2},
+                transitive_extends: vec![],
+                implements: vec![],
+                signature: Literal::string("test/sign1"),
+                full_signature: Literal::string("test/signature1"),
+                binary_name: Literal::string("test.sign1"),
+                super_signature: Literal::string("java/lang/Object"),
+                interface_signatures: vec![],
+                methods: vec![],
+                static_methods: vec![],
+                native_methods: vec![],
+                static_native_methods: vec![],
+                c_abi: None,
+                constructors: vec![
+                    Constructor {
+                        name: Ident::new("test_method_1", Span::call_site()),
                         public: false,
                         argument_names: vec![
                             Ident::new("arg1", Span::call_site()),
                             Ident::new("arg2", Span::call_site()),
                         ],
                         argument_types: vec![quote! {type1}, quote! {type2}],
+                        since: None,
+                        visibility: None,
                     },
-                    ClassMethod {
+                    Constructor {
                         name: Ident::new("test_method_2", Span::call_site()),
-                        java_name: Literal::string("testMethod2"),
-                        return_type: quote! {return_type_2},
                         public: true,
                         argument_names: vec![],
                         argument_types: vec![],
+                        since: None,
+                        visibility: None,
                     },
                 ],
-                native_methods: vec![],
-                static_native_methods: vec![],
-                constructors: vec![],
             })],
         };
         let expected = quote! {
@@ -1492,62 +4284,72 @@ mod generate_class_tests {
                 object: c::d::test2<'env>,
             }
 
-            impl<'a> ::rust_jni::JavaType for test1<'a> {
-                #[doc(hidden)]
-                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-                #[doc(hidden)]
-                fn __signature() -> &'static str {
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
                     "test/signature1"
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::ToJni for test1<'a> {
-                unsafe fn __to_jni(&self) -> Self::__JniType {
-                    self.raw_object()
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
+                type Target = c::d::test2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::FromJni<'a> for test1<'a> {
-                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
-                    Self {
-                        object: <c::d::test2 as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
-                    }
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, test1<'a>> for test1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b test1<'a> {
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
                     self
                 }
             }
 
-            impl<'a> ::std::ops::Deref for test1<'a> {
-                type Target = c::d::test2<'a>;
-
-                fn deref(&self) -> &Self::Target {
-                    &self.object
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
                 }
             }
 
             impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
                 {
-                    self.object
-                        .clone(token)
-                        .map(|object| Self { object })
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
                 }
 
                 pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                     self.object.to_string(token)
                 }
 
@@ -1556,47 +4358,49 @@ mod generate_class_tests {
                     arg1: type1,
                     arg2: type2,
                     token: &::rust_jni::NoException<'a>,
-                ) -> ::rust_jni::JavaResult<'a, return_type_1> {
+                ) -> ::rust_jni::JavaResult<'a, Self> {
                     unsafe {
-                        ::rust_jni::__generator::call_static_method::<Self, _, _,
-                            fn(type1, type2,) -> return_type_1
-                        >
-                        (
-                            env,
-                            "testMethod1",
-                            (arg1, arg2,),
-                            token,
-                        )
+                        env.with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_constructor::<Self, _,
+                                fn(type1, type2,)
+                            >
+                            (
+                                env,
+                                token,
+                                (arg1, arg2,),
+                            )
+                        })
                     }
                 }
 
                 pub fn test_method_2(
                     env: &'a ::rust_jni::JniEnv<'a>,
                     token: &::rust_jni::NoException<'a>,
-                ) -> ::rust_jni::JavaResult<'a, return_type_2> {
+                ) -> ::rust_jni::JavaResult<'a, Self> {
                     unsafe {
-                        ::rust_jni::__generator::call_static_method::<Self, _, _,
-                            fn() -> return_type_2
-                        >
-                        (
-                            env,
-                            "testMethod2",
-                            (),
-                            token,
-                        )
+                        env.with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_constructor::<Self, _,
+                                fn()
+                            >
+                            (
+                                env,
+                                token,
+                                (),
+                            )
+                        })
                     }
                 }
             }
 
-            impl<'a> ::std::fmt::Display for test1<'a> {
-                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    self.object.fmt(formatter)
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
-                    self.object.eq(other)
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
                 }
             }
 
@@ -1606,7 +4410,7 @@ mod generate_class_tests {
     }
 
     #[test]
-    fn constructors() {
+    fn native_methods() {
         let input = GeneratorData {
             definitions: vec![GeneratorDefinition::Class(Class {
                 class: Ident::new("test1", Span::call_site()),
@@ -1616,27 +4420,41 @@ mod generate_class_tests {
                 implements: vec![],
                 signature: Literal::string("test/sign1"),
                 full_signature: Literal::string("test/signature1"),
+                binary_name: Literal::string("test.sign1"),
+                super_signature: Literal::string("java/lang/Object"),
+                interface_signatures: vec![],
                 methods: vec![],
                 static_methods: vec![],
-                native_methods: vec![],
-                static_native_methods: vec![],
-                constructors: vec![
-                    Constructor {
+                native_methods: vec![
+                    NativeMethod {
                         name: Ident::new("test_method_1", Span::call_site()),
+                        rust_name: Ident::new("test_method_1_rust", Span::call_site()),
+                        java_name: Ident::new("testMethod1", Span::call_site()),
+                        return_type: quote! {return_type_1},
                         public: false,
                         argument_names: vec![
                             Ident::new("arg1", Span::call_site()),
                             Ident::new("arg2", Span::call_site()),
                         ],
-                        argument_types: vec![quote! {type1}, quote! {type2}],
+                        argument_types: vec![quote! {type1<'a>}, quote! {type2<'a>}],
+                        code: Some(quote! {test code 1}),
+                        visibility: None,
                     },
-                    Constructor {
+                    NativeMethod {
                         name: Ident::new("test_method_2", Span::call_site()),
+                        rust_name: Ident::new("test_method_2_rust", Span::call_site()),
+                        java_name: Ident::new("testMethod2", Span::call_site()),
+                        return_type: quote! {return_type_2},
                         public: true,
                         argument_names: vec![],
                         argument_types: vec![],
+                        code: Some(quote! {test code 2}),
+                        visibility: None,
                     },
                 ],
+                static_native_methods: vec![],
+                c_abi: None,
+                constructors: vec![],
             })],
         };
         let expected = quote! {
@@ -1645,109 +4463,135 @@ mod generate_class_tests {
                 object: c::d::test2<'env>,
             }
 
-            impl<'a> ::rust_jni::JavaType for test1<'a> {
-                #[doc(hidden)]
-                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-                #[doc(hidden)]
-                fn __signature() -> &'static str {
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
                     "test/signature1"
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::ToJni for test1<'a> {
-                unsafe fn __to_jni(&self) -> Self::__JniType {
-                    self.raw_object()
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::FromJni<'a> for test1<'a> {
-                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
-                    Self {
-                        object: <c::d::test2 as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
-                    }
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
+                type Target = c::d::test2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, test1<'a>> for test1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b test1<'a> {
-                    self
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
                 }
             }
 
-            impl<'a> ::std::ops::Deref for test1<'a> {
-                type Target = c::d::test2<'a>;
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
 
-                fn deref(&self) -> &Self::Target {
-                    &self.object
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
                 }
             }
 
             impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
                 {
-                    self.object
-                        .clone(token)
-                        .map(|object| Self { object })
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
                 }
 
                 pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                     self.object.to_string(token)
                 }
 
-                fn test_method_1(
-                    env: &'a ::rust_jni::JniEnv<'a>,
-                    arg1: type1,
-                    arg2: type2,
+                fn test_method_1_rust(
+                    &self,
+                    arg1: type1<'a>,
+                    arg2: type2<'a>,
                     token: &::rust_jni::NoException<'a>,
-                ) -> ::rust_jni::JavaResult<'a, Self> {
-                    unsafe {
-                        ::rust_jni::__generator::call_constructor::<Self, _,
-                            fn(type1, type2,)
-                        >
-                        (
-                            env,
-                            (arg1, arg2,),
-                            token,
-                        )
-                    }
+                ) -> ::rust_jni::JavaResult<'a, return_type_1> {
+                    test code 1
                 }
 
-                pub fn test_method_2(
-                    env: &'a ::rust_jni::JniEnv<'a>,
+                pub fn test_method_2_rust(
+                    &self,
                     token: &::rust_jni::NoException<'a>,
-                ) -> ::rust_jni::JavaResult<'a, Self> {
-                    unsafe {
-                        ::rust_jni::__generator::call_constructor::<Self, _,
-                            fn()
-                        >
-                        (
-                            env,
-                            (),
-                            token,
-                        )
-                    }
+                ) -> ::rust_jni::JavaResult<'a, return_type_2> {
+                    test code 2
                 }
             }
 
-            impl<'a> ::std::fmt::Display for test1<'a> {
-                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    self.object.fmt(formatter)
+            #[no_mangle]
+            #[doc(hidden)]
+            pub unsafe extern "C" fn testMethod1<'a>(
+                raw_env: *mut ::jni_sys::JNIEnv,
+                object: ::jni_sys::jobject,
+                arg1: <type1<'a> as ::rust_jni::__generator::NativeMethodArgument<'a>>::JniType,
+                arg2: <type2<'a> as ::rust_jni::__generator::NativeMethodArgument<'a>>::JniType,
+            ) -> <return_type_1 as ::rust_jni::__generator::ToJavaNativeResult>::JniType {
+                ::rust_jni::__generator::native_method_wrapper(raw_env, |env, token| {
+                    let object = <test1 as ::rust_jni::__generator::NativeMethodArgument>::from_jni(env, token, object)?;
+                    let arg1 =
+                        ::rust_jni::__generator::NativeMethodArgument::from_jni(env, token, arg1)?;
+                    let arg2 =
+                        ::rust_jni::__generator::NativeMethodArgument::from_jni(env, token, arg2)?;
+                    object
+                        .test_method_1_rust(arg1, arg2, token)
+                        .map(::rust_jni::__generator::to_native_result)
+                })
+            }
+
+            #[no_mangle]
+            #[doc(hidden)]
+            pub unsafe extern "C" fn testMethod2<'a>(
+                raw_env: *mut ::jni_sys::JNIEnv,
+                object: ::jni_sys::jobject,
+            ) -> <return_type_2 as ::rust_jni::__generator::ToJavaNativeResult>::JniType {
+                ::rust_jni::__generator::native_method_wrapper(raw_env, |env, token| {
+                    let object = <test1 as ::rust_jni::__generator::NativeMethodArgument>::from_jni(env, token, object)?;
+                    object
+                        .test_method_2_rust(token)
+                        .map(::rust_jni::__generator::to_native_result)
+                })
+            }
+
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
-                    self.object.eq(other)
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
                 }
             }
 
@@ -1757,7 +4601,7 @@ mod generate_class_tests {
     }
 
     #[test]
-    fn native_methods() {
+    fn native_methods_without_body() {
         let input = GeneratorData {
             definitions: vec![GeneratorDefinition::Class(Class {
                 class: Ident::new("test1", Span::call_site()),
@@ -1767,6 +4611,9 @@ mod generate_class_tests {
                 implements: vec![],
                 signature: Literal::string("test/sign1"),
                 full_signature: Literal::string("test/signature1"),
+                binary_name: Literal::string("test.sign1"),
+                super_signature: Literal::string("java/lang/Object"),
+                interface_signatures: vec![],
                 methods: vec![],
                 static_methods: vec![],
                 native_methods: vec![
@@ -1781,8 +4628,8 @@ mod generate_class_tests {
                             Ident::new("arg2", Span::call_site()),
                         ],
                         argument_types: vec![quote! {type1<'a>}, quote! {type2<'a>}],
-                        argument_types_no_lifetime: vec![quote! {type1}, quote! {type2}],
-                        code: quote! {test code 1},
+                        code: None,
+                        visibility: None,
                     },
                     NativeMethod {
                         name: Ident::new("test_method_2", Span::call_site()),
@@ -1792,11 +4639,12 @@ mod generate_class_tests {
                         public: true,
                         argument_names: vec![],
                         argument_types: vec![],
-                        argument_types_no_lifetime: vec![],
-                        code: quote! {test code 2},
+                        code: Some(quote! {test code 2}),
+                        visibility: None,
                     },
                 ],
                 static_native_methods: vec![],
+                c_abi: None,
                 constructors: vec![],
             })],
         };
@@ -1806,74 +4654,75 @@ mod generate_class_tests {
                 object: c::d::test2<'env>,
             }
 
-            impl<'a> ::rust_jni::JavaType for test1<'a> {
-                #[doc(hidden)]
-                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-                #[doc(hidden)]
-                fn __signature() -> &'static str {
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
                     "test/signature1"
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::ToJni for test1<'a> {
-                unsafe fn __to_jni(&self) -> Self::__JniType {
-                    self.raw_object()
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::FromJni<'a> for test1<'a> {
-                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
-                    Self {
-                        object: <c::d::test2 as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
-                    }
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
+                type Target = c::d::test2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, test1<'a>> for test1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b test1<'a> {
-                    self
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
                 }
             }
 
-            impl<'a> ::std::ops::Deref for test1<'a> {
-                type Target = c::d::test2<'a>;
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
 
-                fn deref(&self) -> &Self::Target {
-                    &self.object
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
                 }
             }
 
             impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
                 {
-                    self.object
-                        .clone(token)
-                        .map(|object| Self { object })
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
                 }
 
                 pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                     self.object.to_string(token)
                 }
 
-                fn test_method_1_rust(
-                    &self,
-                    arg1: type1<'a>,
-                    arg2: type2<'a>,
-                    token: &::rust_jni::NoException<'a>,
-                ) -> ::rust_jni::JavaResult<'a, return_type_1> {
-                    test code 1
-                }
-
                 pub fn test_method_2_rust(
                     &self,
                     token: &::rust_jni::NoException<'a>,
@@ -1882,44 +4731,32 @@ mod generate_class_tests {
                 }
             }
 
+            trait test1Natives<'a> {
+                fn test_method_1_rust(
+                    &self,
+                    arg1: type1<'a>,
+                    arg2: type2<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, return_type_1>;
+            }
+
             #[no_mangle]
             #[doc(hidden)]
             pub unsafe extern "C" fn testMethod1<'a>(
                 raw_env: *mut ::jni_sys::JNIEnv,
                 object: ::jni_sys::jobject,
-                arg1: <type1 as ::rust_jni::JavaType>::__JniType,
-                arg2: <type2 as ::rust_jni::JavaType>::__JniType,
-            ) -> <return_type_1 as ::rust_jni::JavaType>::__JniType {
-                ::rust_jni::__generator::test_jni_argument_type(arg1);
-                ::rust_jni::__generator::test_jni_argument_type(arg2);
+                arg1: <type1<'a> as ::rust_jni::__generator::NativeMethodArgument<'a>>::JniType,
+                arg2: <type2<'a> as ::rust_jni::__generator::NativeMethodArgument<'a>>::JniType,
+            ) -> <return_type_1 as ::rust_jni::__generator::ToJavaNativeResult>::JniType {
                 ::rust_jni::__generator::native_method_wrapper(raw_env, |env, token| {
-                    {
-                        let value =
-                            <type1 as ::rust_jni::__generator::FromJni>
-                                ::__from_jni(env, arg1);
-                        ::rust_jni::__generator::test_from_jni_type(&value);
-                        ::std::mem::forget(value);
-                    }
-                    {
-                        let value =
-                            <type2 as ::rust_jni::__generator::FromJni>
-                                ::__from_jni(env, arg2);
-                        ::rust_jni::__generator::test_from_jni_type(&value);
-                        ::std::mem::forget(value);
-                    }
-
-                    let object = <test1 as ::rust_jni::__generator::FromJni>::__from_jni(env, object);
+                    let object = <test1 as ::rust_jni::__generator::NativeMethodArgument>::from_jni(env, token, object)?;
+                    let arg1 =
+                        ::rust_jni::__generator::NativeMethodArgument::from_jni(env, token, arg1)?;
+                    let arg2 =
+                        ::rust_jni::__generator::NativeMethodArgument::from_jni(env, token, arg2)?;
                     object
-                        .test_method_1_rust(
-                            ::rust_jni::__generator::FromJni::__from_jni(env, arg1),
-                            ::rust_jni::__generator::FromJni::__from_jni(env, arg2),
-                            &token,
-                        )
-                        .map(|value| {
-                            let result = ::rust_jni::__generator::ToJni::__to_jni(&value);
-                            ::std::mem::forget(value);
-                            result
-                        })
+                        .test_method_1_rust(arg1, arg2, token)
+                        .map(::rust_jni::__generator::to_native_result)
                 })
             }
 
@@ -1928,30 +4765,24 @@ mod generate_class_tests {
             pub unsafe extern "C" fn testMethod2<'a>(
                 raw_env: *mut ::jni_sys::JNIEnv,
                 object: ::jni_sys::jobject,
-            ) -> <return_type_2 as ::rust_jni::JavaType>::__JniType {
+            ) -> <return_type_2 as ::rust_jni::__generator::ToJavaNativeResult>::JniType {
                 ::rust_jni::__generator::native_method_wrapper(raw_env, |env, token| {
-                    let object = <test1 as ::rust_jni::__generator::FromJni>::__from_jni(env, object);
+                    let object = <test1 as ::rust_jni::__generator::NativeMethodArgument>::from_jni(env, token, object)?;
                     object
-                        .test_method_2_rust(
-                            &token,
-                        )
-                        .map(|value| {
-                            let result = ::rust_jni::__generator::ToJni::__to_jni(&value);
-                            ::std::mem::forget(value);
-                            result
-                        })
+                        .test_method_2_rust(token)
+                        .map(::rust_jni::__generator::to_native_result)
                 })
             }
 
-            impl<'a> ::std::fmt::Display for test1<'a> {
-                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    self.object.fmt(formatter)
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
-                    self.object.eq(other)
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
                 }
             }
 
@@ -1971,9 +4802,13 @@ mod generate_class_tests {
                 implements: vec![],
                 signature: Literal::string("test/sign1"),
                 full_signature: Literal::string("test/signature1"),
+                binary_name: Literal::string("test.sign1"),
+                super_signature: Literal::string("java/lang/Object"),
+                interface_signatures: vec![],
                 methods: vec![],
                 static_methods: vec![],
                 native_methods: vec![],
+                c_abi: None,
                 static_native_methods: vec![
                     NativeMethod {
                         name: Ident::new("test_method_1", Span::call_site()),
@@ -1986,8 +4821,8 @@ mod generate_class_tests {
                             Ident::new("arg2", Span::call_site()),
                         ],
                         argument_types: vec![quote! {type1<'a>}, quote! {type2<'a>}],
-                        argument_types_no_lifetime: vec![quote! {type1}, quote! {type2}],
-                        code: quote! {test code 1},
+                        code: Some(quote! {test code 1}),
+                        visibility: None,
                     },
                     NativeMethod {
                         name: Ident::new("test_method_2", Span::call_site()),
@@ -1997,8 +4832,8 @@ mod generate_class_tests {
                         public: true,
                         argument_names: vec![],
                         argument_types: vec![],
-                        argument_types_no_lifetime: vec![],
-                        code: quote! {test code 2},
+                        code: Some(quote! {test code 2}),
+                        visibility: None,
                     },
                 ],
                 constructors: vec![],
@@ -2010,62 +4845,72 @@ mod generate_class_tests {
                 object: c::d::test2<'env>,
             }
 
-            impl<'a> ::rust_jni::JavaType for test1<'a> {
-                #[doc(hidden)]
-                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-                #[doc(hidden)]
-                fn __signature() -> &'static str {
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
                     "test/signature1"
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::ToJni for test1<'a> {
-                unsafe fn __to_jni(&self) -> Self::__JniType {
-                    self.raw_object()
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
+                type Target = c::d::test2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::FromJni<'a> for test1<'a> {
-                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
-                    Self {
-                        object: <c::d::test2 as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
-                    }
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, test1<'a>> for test1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b test1<'a> {
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
                     self
                 }
             }
 
-            impl<'a> ::std::ops::Deref for test1<'a> {
-                type Target = c::d::test2<'a>;
-
-                fn deref(&self) -> &Self::Target {
-                    &self.object
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
                 }
             }
 
             impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
                 {
-                    self.object
-                        .clone(token)
-                        .map(|object| Self { object })
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
                 }
 
                 pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                     self.object.to_string(token)
                 }
 
@@ -2091,44 +4936,22 @@ mod generate_class_tests {
             pub unsafe extern "C" fn testMethod1<'a>(
                 raw_env: *mut ::jni_sys::JNIEnv,
                 raw_class: ::jni_sys::jclass,
-                arg1: <type1 as ::rust_jni::JavaType>::__JniType,
-                arg2: <type2 as ::rust_jni::JavaType>::__JniType,
-            ) -> <return_type_1 as ::rust_jni::JavaType>::__JniType {
-                ::rust_jni::__generator::test_jni_argument_type(arg1);
-                ::rust_jni::__generator::test_jni_argument_type(arg2);
+                arg1: <type1<'a> as ::rust_jni::__generator::NativeMethodArgument<'a>>::JniType,
+                arg2: <type2<'a> as ::rust_jni::__generator::NativeMethodArgument<'a>>::JniType,
+            ) -> <return_type_1 as ::rust_jni::__generator::ToJavaNativeResult>::JniType {
                 ::rust_jni::__generator::native_method_wrapper(raw_env, |env, token| {
-                    {
-                        let value =
-                            <type1 as ::rust_jni::__generator::FromJni>
-                                ::__from_jni(env, arg1);
-                        ::rust_jni::__generator::test_from_jni_type(&value);
-                        ::std::mem::forget(value);
-                    }
-                    {
-                        let value =
-                            <type2 as ::rust_jni::__generator::FromJni>
-                                ::__from_jni(env, arg2);
-                        ::rust_jni::__generator::test_from_jni_type(&value);
-                        ::std::mem::forget(value);
-                    }
-
-                    let class = test1::get_class(env, &token)?;
-                    let raw_class = <::rust_jni::java::lang::Class as ::rust_jni::__generator::FromJni>::__from_jni(env, raw_class);
-                    if !class.is_same_as(&raw_class, &token) {
+                    let class = test1::get_class(env, token)?;
+                    let raw_class = <::rust_jni::java::lang::Class as ::rust_jni::__generator::NativeMethodArgument>::from_jni(env, token, raw_class)?;
+                    if !class.is_same_as(token, &raw_class) {
                         panic!("Native method test_method_1 does not belong to class test1");
                     }
 
-                    test1::test_method_1_rust(
-                        env,
-                        ::rust_jni::__generator::FromJni::__from_jni(env, arg1),
-                        ::rust_jni::__generator::FromJni::__from_jni(env, arg2),
-                        &token,
-                    )
-                    .map(|value| {
-                        let result = ::rust_jni::__generator::ToJni::__to_jni(&value);
-                        ::std::mem::forget(value);
-                        result
-                    })
+                    let arg1 =
+                        ::rust_jni::__generator::NativeMethodArgument::from_jni(env, token, arg1)?;
+                    let arg2 =
+                        ::rust_jni::__generator::NativeMethodArgument::from_jni(env, token, arg2)?;
+                    test1::test_method_1_rust(env, arg1, arg2, token)
+                        .map(::rust_jni::__generator::to_native_result)
                 })
             }
 
@@ -2137,35 +4960,28 @@ mod generate_class_tests {
             pub unsafe extern "C" fn testMethod2<'a>(
                 raw_env: *mut ::jni_sys::JNIEnv,
                 raw_class: ::jni_sys::jclass,
-            ) -> <return_type_2 as ::rust_jni::JavaType>::__JniType {
+            ) -> <return_type_2 as ::rust_jni::__generator::ToJavaNativeResult>::JniType {
                 ::rust_jni::__generator::native_method_wrapper(raw_env, |env, token| {
-                    let class = test1::get_class(env, &token)?;
-                    let raw_class = <::rust_jni::java::lang::Class as ::rust_jni::__generator::FromJni>::__from_jni(env, raw_class);
-                    if !class.is_same_as(&raw_class, &token) {
+                    let class = test1::get_class(env, token)?;
+                    let raw_class = <::rust_jni::java::lang::Class as ::rust_jni::__generator::NativeMethodArgument>::from_jni(env, token, raw_class)?;
+                    if !class.is_same_as(token, &raw_class) {
                         panic!("Native method test_method_2 does not belong to class test1");
                     }
 
-                    test1::test_method_2_rust(
-                        env,
-                        &token,
-                    )
-                    .map(|value| {
-                        let result = ::rust_jni::__generator::ToJni::__to_jni(&value);
-                        ::std::mem::forget(value);
-                        result
-                    })
+                    test1::test_method_2_rust(env, token)
+                        .map(::rust_jni::__generator::to_native_result)
                 })
             }
 
-            impl<'a> ::std::fmt::Display for test1<'a> {
-                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    self.object.fmt(formatter)
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
-                    self.object.eq(other)
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
                 }
             }
 
@@ -2194,10 +5010,14 @@ mod generate_class_tests {
                 ],
                 signature: Literal::string("test/sign1"),
                 full_signature: Literal::string("test/signature1"),
+                binary_name: Literal::string("test.sign1"),
+                super_signature: Literal::string("java/lang/Object"),
+                interface_signatures: vec![],
                 methods: vec![],
                 static_methods: vec![],
                 native_methods: vec![],
                 static_native_methods: vec![],
+                c_abi: None,
                 constructors: vec![],
             })],
         };
@@ -2207,75 +5027,85 @@ mod generate_class_tests {
                 object: c::d::test2<'env>,
             }
 
-            impl<'a> ::rust_jni::JavaType for test1<'a> {
-                #[doc(hidden)]
-                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-                #[doc(hidden)]
-                fn __signature() -> &'static str {
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
                     "test/signature1"
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::ToJni for test1<'a> {
-                unsafe fn __to_jni(&self) -> Self::__JniType {
-                    self.raw_object()
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::FromJni<'a> for test1<'a> {
-                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
-                    Self {
-                        object: <c::d::test2 as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
-                    }
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
+                type Target = c::d::test2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, test1<'a>> for test1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b test1<'a> {
-                    self
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
                 }
             }
 
-            impl<'a> ::std::ops::Deref for test1<'a> {
-                type Target = c::d::test2<'a>;
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
 
-                fn deref(&self) -> &Self::Target {
-                    &self.object
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
                 }
             }
 
             impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
                 {
-                    self.object
-                        .clone(token)
-                        .map(|object| Self { object })
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
                 }
 
                 pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                     self.object.to_string(token)
                 }
             }
 
-            impl<'a> ::std::fmt::Display for test1<'a> {
-                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    self.object.fmt(formatter)
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
-                    self.object.eq(other)
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
                 }
             }
 
@@ -2322,10 +5152,14 @@ mod generate_class_tests {
                 }],
                 signature: Literal::string("test/sign1"),
                 full_signature: Literal::string("test/signature1"),
+                binary_name: Literal::string("test.sign1"),
+                super_signature: Literal::string("java/lang/Object"),
+                interface_signatures: vec![],
                 methods: vec![],
                 static_methods: vec![],
                 native_methods: vec![],
                 static_native_methods: vec![],
+                c_abi: None,
                 constructors: vec![],
             })],
         };
@@ -2335,75 +5169,85 @@ mod generate_class_tests {
                 object: c::d::test2<'env>,
             }
 
-            impl<'a> ::rust_jni::JavaType for test1<'a> {
-                #[doc(hidden)]
-                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-                #[doc(hidden)]
-                fn __signature() -> &'static str {
+            impl<'a> ::rust_jni::JniSignature for test1<'a> {
+                fn signature() -> &'static str {
                     "test/signature1"
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::ToJni for test1<'a> {
-                unsafe fn __to_jni(&self) -> Self::__JniType {
-                    self.raw_object()
+            impl<'a> ::rust_jni::FromObject<'a> for test1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <c::d::test2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::FromJni<'a> for test1<'a> {
-                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
-                    Self {
-                        object: <c::d::test2 as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
-                    }
+            impl<'a> ::rust_jni::__generator::ops::Deref for test1<'a> {
+                type Target = c::d::test2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, test1<'a>> for test1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b test1<'a> {
-                    self
+            impl<'a> ::rust_jni::__generator::convert::From<test1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: test1<'a>) -> Self {
+                    value.object.into()
                 }
             }
 
-            impl<'a> ::std::ops::Deref for test1<'a> {
-                type Target = c::d::test2<'a>;
+            impl<'env> ::rust_jni::Detachable for test1<'env> {
+                type Attached<'a> = test1<'a>;
+            }
 
-                fn deref(&self) -> &Self::Target {
-                    &self.object
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for test1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
                 }
             }
 
             impl<'a> test1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "test.sign1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "test/signature1";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "test/sign1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "test/sign1", token)
+                    ::rust_jni::java::lang::Class::find(env, token, "test/sign1")
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
                 {
-                    self.object
-                        .clone(token)
-                        .map(|object| Self { object })
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
                 }
 
                 pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                     self.object.to_string(token)
                 }
             }
 
-            impl<'a> ::std::fmt::Display for test1<'a> {
-                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    self.object.fmt(formatter)
+            impl<'a> ::rust_jni::__generator::fmt::Display for test1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for test1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
-                    self.object.eq(other)
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
                 }
             }
 
@@ -1,6 +1,6 @@
+use parse::BUILTIN_CLASSES;
 use proc_macro2::*;
 use quote::ToTokens;
-use rust_jni;
 use std::hash::{Hash, Hasher};
 use std::iter::FromIterator;
 use std::ops::Deref;
@@ -28,9 +28,10 @@ impl Hash for JavaName {
     }
 }
 
+/// Names are compared by their tokens, ignoring spans, consistently with the `Hash` implementation.
 impl PartialEq for JavaName {
     fn eq(&self, other: &Self) -> bool {
-        format!("{:?}", self) == format!("{:?}", other)
+        self.to_string() == other.to_string()
     }
 }
 
@@ -78,6 +79,10 @@ impl JavaName {
         let tokens = flat_map_threaded(tokens, false, |token, was_identifier| {
             match (token, was_identifier) {
                 (TokenTree::Ident(_), false) => true,
+                (TokenTree::Literal(literal), false) if literal.to_string().starts_with('"') => {
+                    java_identifier(literal);
+                    true
+                }
                 (TokenTree::Punct(punct), true) => {
                     if punct.as_char() != '.' {
                         panic!("Expected a dot, got {:?}.", punct);
@@ -93,7 +98,7 @@ impl JavaName {
             }
         })
         .filter(|token| match token {
-            TokenTree::Ident(_) => true,
+            TokenTree::Ident(_) | TokenTree::Literal(_) => true,
             _ => false,
         });
         let tokens = TokenStream::from_iter(tokens.cloned());
@@ -104,35 +109,53 @@ impl JavaName {
     }
 
     pub fn name(self) -> Ident {
-        match self.0.into_iter().last().unwrap() {
-            TokenTree::Ident(identifier) => identifier,
-            token => panic!("Expected an identifier, got {:?}", token),
-        }
+        rust_identifier(&self.0.into_iter().last().unwrap())
+    }
+
+    /// Append a suffix to the class name, keeping the package.
+    pub fn with_name_suffix(self, suffix: &str) -> JavaName {
+        let mut tokens = self.0.into_iter().collect::<Vec<_>>();
+        let name = rust_identifier(&tokens.pop().unwrap());
+        tokens.push(TokenTree::Ident(Ident::new(
+            &format!("{}{}", name, suffix),
+            name.span(),
+        )));
+        JavaName(TokenStream::from_iter(tokens))
     }
 
     pub fn with_slashes(self) -> String {
         self.0
             .into_iter()
-            .map(|token| token.to_string())
+            .map(|token| java_component(&token))
             .collect::<Vec<_>>()
             .join("/")
     }
 
+    /// The name mangled for JNI native function names and signatures.
     pub fn with_underscores(self) -> String {
         self.0
             .into_iter()
-            .map(|token| token.to_string())
+            .map(|token| mangle_jni_name(&java_component(&token)))
             .collect::<Vec<_>>()
             .join("_")
     }
 
-    pub fn with_double_colons(self) -> TokenStream {
-        let mut tokens = vec![];
-        for token in self.0.into_iter() {
-            tokens.extend(quote! {::});
-            tokens.push(token);
+    /// Path of the Rust wrapper of the class.
+    ///
+    /// Classes provided by `rust-jni` are taken from it. Other classes are expected to be
+    /// generated in modules of the current crate that match their packages, like
+    /// `crate::a::b::TestClass` for `a.b.TestClass`.
+    pub fn as_rust_path(self) -> TokenStream {
+        let tokens = self
+            .0
+            .clone()
+            .into_iter()
+            .map(|token| rust_identifier(&token));
+        if BUILTIN_CLASSES.contains(&self.with_slashes().replace("/", ".").as_str()) {
+            quote! {::rust_jni #(:: #tokens)*}
+        } else {
+            quote! {crate #(:: #tokens)*}
         }
-        TokenStream::from_iter(tokens.iter().cloned())
     }
 
     pub fn with_dots(self) -> TokenStream {
@@ -170,7 +193,7 @@ impl JavaName {
             } else if is_identifier(&token, "void") {
                 Some(quote! {()})
             } else if is_identifier(&token, "short") {
-                Some(quote! {i64})
+                Some(quote! {i16})
             } else {
                 None
             }
@@ -184,26 +207,26 @@ impl JavaName {
         if tokens.len() == 1 {
             let token = &tokens[0];
             if is_identifier(&token, "int") {
-                <i32 as rust_jni::JavaType>::__signature().to_owned()
+                "I".to_owned()
             } else if is_identifier(&token, "long") {
-                <i64 as rust_jni::JavaType>::__signature().to_owned()
+                "J".to_owned()
             } else if is_identifier(&token, "char") {
-                <char as rust_jni::JavaType>::__signature().to_owned()
+                "C".to_owned()
             } else if is_identifier(&token, "byte") {
-                <u8 as rust_jni::JavaType>::__signature().to_owned()
+                "B".to_owned()
             } else if is_identifier(&token, "boolean") {
-                <bool as rust_jni::JavaType>::__signature().to_owned()
+                "Z".to_owned()
             } else if is_identifier(&token, "float") {
                 panic!(
                     "float values are not supported for not. \
                      See https://github.com/Monnoroch/rust-jni/issues/25 for more details"
                 )
             } else if is_identifier(&token, "double") {
-                <f64 as rust_jni::JavaType>::__signature().to_owned()
+                "D".to_owned()
             } else if is_identifier(&token, "void") {
-                <() as rust_jni::JavaType>::__signature().to_owned()
+                "V".to_owned()
             } else if is_identifier(&token, "short") {
-                <i16 as rust_jni::JavaType>::__signature().to_owned()
+                "S".to_owned()
             } else {
                 format!("L{}_2", self.clone().with_underscores())
             }
@@ -214,21 +237,106 @@ impl JavaName {
 
     pub fn as_rust_type(self) -> TokenStream {
         let primitive = self.as_primitive_type();
-        let with_double_colons = self.with_double_colons();
-        primitive.unwrap_or(quote! {#with_double_colons <'a>})
+        let path = self.as_rust_path();
+        primitive.unwrap_or(quote! {#path <'a>})
     }
 
     pub fn as_rust_type_no_lifetime(self) -> TokenStream {
         let primitive = self.as_primitive_type();
-        let with_double_colons = self.with_double_colons();
-        primitive.unwrap_or(quote! {#with_double_colons})
+        let path = self.as_rust_path();
+        primitive.unwrap_or(quote! {#path})
     }
 
     pub fn as_rust_type_reference(self) -> TokenStream {
         let primitive = self.as_primitive_type();
-        let with_double_colons = self.with_double_colons();
-        primitive.unwrap_or(quote! {& #with_double_colons <'a>})
+        let path = self.as_rust_path();
+        primitive.unwrap_or(quote! {& #path <'a>})
+    }
+}
+
+/// Get the value of a string literal used as a Java identifier, like `"Outer$Inner"`.
+pub fn java_identifier(literal: &Literal) -> String {
+    let string = literal.to_string();
+    if string.len() < 2
+        || !string.starts_with('"')
+        || !string.ends_with('"')
+        || string.contains('\\')
+    {
+        panic!(
+            "Expected a Java identifier in a string literal without escapes, got {}.",
+            string
+        );
+    }
+    let identifier = string[1..string.len() - 1].to_owned();
+    if identifier.is_empty()
+        || identifier
+            .chars()
+            .any(|character| !character.is_alphanumeric() && character != '_' && character != '$')
+    {
+        panic!("Expected a Java identifier, got {}.", string);
+    }
+    identifier
+}
+
+/// The Java spelling of a name component.
+fn java_component(token: &TokenTree) -> String {
+    match token {
+        TokenTree::Literal(literal) => java_identifier(literal),
+        token => token.to_string(),
+    }
+}
+
+/// The Rust identifier of a name component.
+fn rust_identifier(token: &TokenTree) -> Ident {
+    match token {
+        TokenTree::Ident(identifier) => identifier.clone(),
+        TokenTree::Literal(literal) => Ident::new(
+            &to_rust_identifier(&java_identifier(literal)),
+            literal.span(),
+        ),
+        token => panic!("Expected an identifier, got {:?}", token),
+    }
+}
+
+/// Map a Java identifier to a valid Rust identifier.
+///
+/// ASCII letters, digits and underscores are kept, `$` is replaced with `_` and other characters
+/// are replaced with `_u` followed by their lowercase hex code, like `_u00e9` for `é`.
+pub fn to_rust_identifier(name: &str) -> String {
+    let mut identifier = String::new();
+    if name.starts_with(|character: char| character.is_ascii_digit()) {
+        identifier.push('_');
+    }
+    for character in name.chars() {
+        match character {
+            character if character.is_ascii_alphanumeric() || character == '_' => {
+                identifier.push(character)
+            }
+            '$' => identifier.push('_'),
+            character => identifier.push_str(&format!("_u{:04x}", character as u32)),
+        }
+    }
+    identifier
+}
+
+/// Mangle a Java identifier for JNI native function names.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/design.html#resolving-native-method-names)
+pub fn mangle_jni_name(name: &str) -> String {
+    let mut mangled = String::new();
+    for character in name.chars() {
+        match character {
+            character if character.is_ascii_alphanumeric() => mangled.push(character),
+            '_' => mangled.push_str("_1"),
+            character => {
+                let mut buffer = [0; 2];
+                for unit in character.encode_utf16(&mut buffer) {
+                    mangled.push_str(&format!("_0{:04x}", unit));
+                }
+            }
+        }
     }
+    mangled
 }
 
 fn is_identifier(token: &TokenTree, name: &str) -> bool {
@@ -237,3 +345,123 @@ fn is_identifier(token: &TokenTree, name: &str) -> bool {
         _ => false,
     }
 }
+
+#[cfg(test)]
+mod as_primitive_type_tests {
+    use super::*;
+
+    #[test]
+    fn primitives() {
+        for (java_type, rust_type, signature) in vec![
+            (quote! {int}, quote! {i32}, "I"),
+            (quote! {long}, quote! {i64}, "J"),
+            (quote! {short}, quote! {i16}, "S"),
+            (quote! {char}, quote! {char}, "C"),
+            (quote! {byte}, quote! {u8}, "B"),
+            (quote! {boolean}, quote! {bool}, "Z"),
+            (quote! {double}, quote! {f64}, "D"),
+            (quote! {void}, quote! {()}, "V"),
+        ] {
+            let name = JavaName(java_type);
+            assert_eq!(
+                format!("{:?}", name.as_primitive_type().unwrap()),
+                format!("{:?}", rust_type)
+            );
+            assert_eq!(name.get_jni_signature(), signature);
+        }
+    }
+
+    #[test]
+    fn class() {
+        let name = JavaName(quote! {a b TestClass});
+        assert!(name.as_primitive_type().is_none());
+        assert_eq!(name.get_jni_signature(), "La_b_TestClass_2");
+    }
+}
+
+#[cfg(test)]
+mod eq_tests {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+
+    fn hash(name: &JavaName) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        name.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn same_tokens() {
+        let quoted = JavaName(quote! {a b TestClass});
+        let parsed = JavaName("a b TestClass".parse().unwrap());
+        assert_eq!(quoted, parsed);
+        assert_eq!(hash(&quoted), hash(&parsed));
+    }
+
+    #[test]
+    fn different_tokens() {
+        assert_ne!(
+            JavaName(quote! {a b TestClass}),
+            JavaName(quote! {a c TestClass})
+        );
+    }
+}
+
+#[cfg(test)]
+mod literal_name_tests {
+    use super::*;
+
+    fn name(tokens: TokenStream) -> JavaName {
+        JavaName::from_tokens(tokens.into_iter().collect::<Vec<_>>().iter())
+    }
+
+    #[test]
+    fn literal_component() {
+        let name = name(quote! {a.b."Outer$Inner"});
+        assert_eq!(name.clone().with_slashes(), "a/b/Outer$Inner");
+        assert_eq!(name.clone().name().to_string(), "Outer_Inner");
+        assert_eq!(
+            name.clone().as_rust_path().to_string(),
+            "crate :: a :: b :: Outer_Inner"
+        );
+        assert_eq!(name.get_jni_signature(), "La_b_Outer_00024Inner_2");
+    }
+
+    #[test]
+    fn unicode_component() {
+        let name = name(quote! {"pakeť"."Café"});
+        assert_eq!(name.clone().with_slashes(), "pakeť/Café");
+        assert_eq!(name.clone().name().to_string(), "Caf_u00e9");
+        assert_eq!(name.with_underscores(), "pake_00165_Caf_000e9");
+    }
+
+    #[test]
+    fn with_name_suffix() {
+        let name = name(quote! {a."Weird$Name"}).with_name_suffix("Statics");
+        assert_eq!(name.with_slashes(), "a/Weird_NameStatics");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected a Java identifier, got \"a.b\"")]
+    fn not_identifier() {
+        name(quote! {a."a.b"});
+    }
+
+    #[test]
+    fn rust_identifier() {
+        assert_eq!(to_rust_identifier("name"), "name");
+        assert_eq!(to_rust_identifier("Outer$Inner"), "Outer_Inner");
+        assert_eq!(to_rust_identifier("$1"), "_1");
+        assert_eq!(to_rust_identifier("1a"), "_1a");
+        assert_eq!(to_rust_identifier("ñ"), "_u00f1");
+    }
+
+    #[test]
+    fn jni_name() {
+        assert_eq!(mangle_jni_name("name"), "name");
+        assert_eq!(mangle_jni_name("a_b"), "a_1b");
+        assert_eq!(mangle_jni_name("a$b"), "a_00024b");
+        assert_eq!(mangle_jni_name("ñ"), "_000f1");
+        assert_eq!(mangle_jni_name("😀"), "_0d83d_0de00");
+    }
+}
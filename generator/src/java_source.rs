@@ -0,0 +1,658 @@
+//! Conversion of Java source code into `java_generate!` definitions.
+//!
+//! Used with the `java_source` option, so that definitions can be copied from Java sources.
+//! Method bodies, fields, initializers, nested types, enums and annotation types are ignored,
+//! generic types are erased and comments are dropped by the Rust tokenizer.
+
+use indexmap::IndexMap;
+use parse::strip_comments;
+use proc_macro2::*;
+use std::mem;
+
+/// Annotations that the generator understands. Other Java annotations are dropped.
+const GENERATOR_ANNOTATIONS: &[&str] = &[
+    "RustName",
+    "RustSynchronized",
+    "RustMapResult",
+    "nullable",
+    "nonnull",
+    "returns_self",
+];
+
+const MODIFIERS: &[&str] = &[
+    "public",
+    "protected",
+    "private",
+    "static",
+    "final",
+    "abstract",
+    "synchronized",
+    "native",
+    "default",
+    "transient",
+    "volatile",
+    "strictfp",
+];
+
+/// Erased types of type variables in scope.
+type TypeVariables = IndexMap<String, Vec<TokenTree>>;
+
+/// Convert Java source code into `java_generate!` definitions.
+///
+/// The trailing `metadata { ... }` block is kept as is.
+pub fn java_source_to_definitions(tokens: Vec<TokenTree>) -> Vec<TokenTree> {
+    let mut tokens = strip_comments(&tokens);
+    let metadata = if tokens.len() > 1 && is_identifier(&tokens[tokens.len() - 2], "metadata") {
+        tokens.split_off(tokens.len() - 2)
+    } else {
+        vec![]
+    };
+    let mut package = TokenStream::new();
+    let mut imports = TokenStream::new();
+    let mut definitions = TokenStream::new();
+    for declaration in split_declarations(&tokens) {
+        if is_identifier(&declaration[0], "package") {
+            package.extend(declaration);
+            package.extend(quote! {;});
+        } else if is_identifier(&declaration[0], "import") {
+            if !is_identifier(&declaration[1], "static") {
+                imports.extend(declaration);
+                imports.extend(quote! {;});
+            }
+        } else if let Some(definition) = convert_type_declaration(&declaration) {
+            definitions.extend(definition);
+        }
+    }
+    // Classes from `java.lang` are imported implicitly in Java.
+    let mut result = quote! {
+        #package
+        #imports
+        import java.lang.*;
+        #definitions
+    };
+    result.extend(metadata);
+    result.into_iter().collect()
+}
+
+/// Split a compilation unit or a class body into declarations, which end either with a semicolon
+/// or with a body in braces. Braces after an `=` belong to a field initializer.
+fn split_declarations(tokens: &[TokenTree]) -> Vec<Vec<TokenTree>> {
+    let mut declarations = vec![];
+    let mut current = vec![];
+    for token in tokens {
+        if is_punctuation(token, ';') {
+            declarations.push(mem::replace(&mut current, vec![]));
+        } else if is_group(token, Delimiter::Brace)
+            && !current.iter().any(|token| is_punctuation(token, '='))
+        {
+            current.push(token.clone());
+            declarations.push(mem::replace(&mut current, vec![]));
+        } else {
+            current.push(token.clone());
+        }
+    }
+    declarations.push(current);
+    declarations
+        .into_iter()
+        .filter(|declaration| !declaration.is_empty())
+        .collect()
+}
+
+/// Split tokens by commas that are not inside of generic type arguments.
+fn split_by_commas(tokens: &[TokenTree]) -> Vec<Vec<TokenTree>> {
+    let mut parts = vec![];
+    let mut current = vec![];
+    let mut depth = 0;
+    for token in tokens {
+        if is_punctuation(token, '<') {
+            depth += 1;
+        } else if is_punctuation(token, '>') {
+            depth -= 1;
+        } else if depth == 0 && is_punctuation(token, ',') {
+            parts.push(mem::replace(&mut current, vec![]));
+            continue;
+        }
+        current.push(token.clone());
+    }
+    parts.push(current);
+    parts.into_iter().filter(|part| !part.is_empty()).collect()
+}
+
+/// Take leading annotations and modifiers, which can be mixed in Java.
+///
+/// Returns annotations converted to the generator ones, modifiers and the rest of the tokens.
+fn take_annotations_and_modifiers(
+    tokens: &[TokenTree],
+) -> (Vec<TokenStream>, Vec<Ident>, &[TokenTree]) {
+    let mut annotations = vec![];
+    let mut modifiers = vec![];
+    let mut tokens = tokens;
+    loop {
+        match tokens {
+            [TokenTree::Punct(at), TokenTree::Ident(name), ..]
+                if at.as_char() == '@' && name != "interface" =>
+            {
+                // Annotation names can be qualified, like `@javax.annotation.Nullable`.
+                let mut name = name.clone();
+                tokens = &tokens[2..];
+                while let [TokenTree::Punct(dot), TokenTree::Ident(next), ..] = tokens {
+                    if dot.as_char() != '.' {
+                        break;
+                    }
+                    name = next.clone();
+                    tokens = &tokens[2..];
+                }
+                let value = match tokens.first() {
+                    Some(TokenTree::Group(group))
+                        if group.delimiter() == Delimiter::Parenthesis =>
+                    {
+                        tokens = &tokens[1..];
+                        Some(group.clone())
+                    }
+                    _ => None,
+                };
+                annotations.extend(convert_annotation(name, value));
+            }
+            [TokenTree::Ident(modifier), ..] if MODIFIERS.iter().any(|name| modifier == name) => {
+                modifiers.push(modifier.clone());
+                tokens = &tokens[1..];
+            }
+            _ => return (annotations, modifiers, tokens),
+        }
+    }
+}
+
+/// Convert a Java annotation to a generator one.
+///
+/// Common Java nullability annotations are converted to `@nullable` and `@nonnull`.
+fn convert_annotation(name: Ident, value: Option<Group>) -> Option<TokenStream> {
+    let java_name = name.to_string();
+    let generator_name = match java_name.as_str() {
+        "Nullable" => "nullable",
+        "NonNull" | "Nonnull" | "NotNull" => "nonnull",
+        name if GENERATOR_ANNOTATIONS.contains(&name) => name,
+        _ => return None,
+    };
+    let name = Ident::new(generator_name, name.span());
+    Some(quote! {@#name #value})
+}
+
+/// Parse type parameters, like `<T, U extends a.b.TestClass1<T>>`, into the erased types of
+/// the type variables.
+fn parse_type_parameters(
+    tokens: &[TokenTree],
+    type_variables: &TypeVariables,
+) -> (TypeVariables, usize) {
+    let mut type_variables = type_variables.clone();
+    let mut depth = 0;
+    let end = tokens
+        .iter()
+        .position(|token| {
+            if is_punctuation(token, '<') {
+                depth += 1;
+            } else if is_punctuation(token, '>') {
+                depth -= 1;
+            }
+            depth == 0
+        })
+        .unwrap_or_else(|| panic!("Expected \">\" after type parameters."));
+    for parameter in split_by_commas(&tokens[1..end]) {
+        let name = match parameter[0] {
+            TokenTree::Ident(ref name) => name.to_string(),
+            ref token => panic!("Expected a type parameter name, got {:?}.", token),
+        };
+        let bound = if parameter.len() > 2 && is_identifier(&parameter[1], "extends") {
+            // Type variables are erased to their first bound.
+            let bound = parameter[2..]
+                .split(|token| is_punctuation(token, '&'))
+                .next()
+                .unwrap();
+            erase_type(bound, &type_variables).unwrap_or_else(object_type)
+        } else {
+            object_type()
+        };
+        type_variables.insert(name, bound);
+    }
+    (type_variables, end + 1)
+}
+
+fn object_type() -> Vec<TokenTree> {
+    quote! {java.lang.Object}.into_iter().collect()
+}
+
+/// Erase generic type arguments of a type and replace type variables with their erased types.
+///
+/// Returns `None` for array and variable arity types, which are not supported.
+fn erase_type(tokens: &[TokenTree], type_variables: &TypeVariables) -> Option<Vec<TokenTree>> {
+    let mut result = vec![];
+    let mut depth = 0;
+    for token in tokens {
+        if is_punctuation(token, '<') {
+            depth += 1;
+        } else if is_punctuation(token, '>') {
+            depth -= 1;
+        } else if depth == 0 {
+            if is_group(token, Delimiter::Bracket) {
+                return None;
+            }
+            if is_punctuation(token, '.')
+                && result
+                    .last()
+                    .map_or(false, |token| is_punctuation(token, '.'))
+            {
+                return None;
+            }
+            result.push(token.clone());
+        }
+    }
+    if result.is_empty() {
+        return None;
+    }
+    if let [TokenTree::Ident(name)] = result.as_slice() {
+        if let Some(erased) = type_variables.get(&name.to_string()) {
+            return Some(erased.clone());
+        }
+    }
+    Some(result)
+}
+
+fn convert_type_declaration(tokens: &[TokenTree]) -> Option<TokenStream> {
+    let (_, modifiers, tokens) = take_annotations_and_modifiers(tokens);
+    let is_class = is_identifier(&tokens[0], "class");
+    let is_interface = is_identifier(&tokens[0], "interface");
+    // Enums and annotation types are not supported.
+    if !is_class && !is_interface {
+        return None;
+    }
+    let name = match tokens.get(1) {
+        Some(TokenTree::Ident(name)) => name.clone(),
+        token => panic!("Expected a class name, got {:?}.", token),
+    };
+    let body = match tokens.last() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => group.stream(),
+        token => panic!("Expected a class body, got {:?}.", token),
+    };
+    let mut header = &tokens[2..tokens.len() - 1];
+    let type_variables = if header
+        .first()
+        .map_or(false, |token| is_punctuation(token, '<'))
+    {
+        let (type_variables, length) = parse_type_parameters(header, &TypeVariables::new());
+        header = &header[length..];
+        type_variables
+    } else {
+        TypeVariables::new()
+    };
+    let supertypes = |keyword: &str| {
+        let tokens = header
+            .iter()
+            .skip_while(|token| !is_identifier(token, keyword))
+            .skip(1)
+            .take_while(|token| {
+                !is_identifier(token, "extends")
+                    && !is_identifier(token, "implements")
+                    && !is_identifier(token, "permits")
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        split_by_commas(&tokens)
+            .into_iter()
+            .map(|supertype| {
+                let supertype = erase_type(&supertype, &type_variables)
+                    .unwrap_or_else(|| panic!("Unsupported supertype in class {}.", name));
+                quote! {#(#supertype)*}
+            })
+            .collect::<Vec<_>>()
+    };
+    let extends = supertypes("extends");
+    let implements = supertypes("implements");
+    let body = strip_comments(&body.into_iter().collect::<Vec<_>>());
+    let members = split_declarations(&body)
+        .iter()
+        .filter_map(|member| convert_member(member, &name, is_interface, &type_variables))
+        .collect::<Vec<_>>();
+    let public = modifiers
+        .iter()
+        .find(|modifier| modifier.to_string() == "public");
+    let is_abstract = modifiers
+        .iter()
+        .find(|modifier| modifier.to_string() == "abstract");
+    let extends = if extends.is_empty() {
+        quote! {}
+    } else {
+        quote! {extends #(#extends),*}
+    };
+    Some(if is_interface {
+        quote! {
+            #public interface #name #extends {
+                #(#members)*
+            }
+        }
+    } else {
+        let implements = if implements.is_empty() {
+            quote! {}
+        } else {
+            quote! {implements #(#implements),*}
+        };
+        quote! {
+            #public #is_abstract class #name #extends #implements {
+                #(#members)*
+            }
+        }
+    })
+}
+
+/// Convert a class member to a generator method or constructor.
+///
+/// Returns `None` for members that are skipped: fields, initializers, nested types, private
+/// members and methods with unsupported types.
+fn convert_member(
+    tokens: &[TokenTree],
+    class_name: &Ident,
+    is_interface: bool,
+    type_variables: &TypeVariables,
+) -> Option<TokenStream> {
+    let (annotations, modifiers, tokens) = take_annotations_and_modifiers(tokens);
+    let has_modifier = |name: &str| modifiers.iter().any(|modifier| modifier == name);
+    if has_modifier("private") {
+        return None;
+    }
+    let arguments_index = tokens
+        .iter()
+        .position(|token| is_group(token, Delimiter::Parenthesis))?;
+    let (type_variables, header) = match tokens.first() {
+        Some(token) if is_punctuation(token, '<') => {
+            let (type_variables, length) = parse_type_parameters(tokens, type_variables);
+            (type_variables, &tokens[length..arguments_index])
+        }
+        _ => (type_variables.clone(), &tokens[..arguments_index]),
+    };
+    // Fields with initializers and nested types.
+    if header.iter().any(|token| {
+        is_punctuation(token, '=')
+            || is_identifier(token, "class")
+            || is_identifier(token, "interface")
+            || is_identifier(token, "enum")
+    }) {
+        return None;
+    }
+    let (name, return_type) = match header.split_last() {
+        Some((TokenTree::Ident(name), return_type)) => (name.clone(), return_type),
+        _ => return None,
+    };
+    let is_constructor = return_type.is_empty();
+    if is_constructor && (is_interface || name.to_string() != class_name.to_string()) {
+        return None;
+    }
+    // Old style array return types, like `int foo()[]`.
+    if tokens[arguments_index + 1..]
+        .iter()
+        .any(|token| is_group(token, Delimiter::Bracket))
+    {
+        return None;
+    }
+    let arguments = match tokens[arguments_index] {
+        TokenTree::Group(ref group) => group.stream().into_iter().collect::<Vec<_>>(),
+        _ => unreachable!(),
+    };
+    let arguments = split_by_commas(&arguments)
+        .iter()
+        .map(|argument| {
+            let (annotations, _, argument) = take_annotations_and_modifiers(argument);
+            let (name, data_type) = match argument.split_last() {
+                Some((TokenTree::Ident(name), data_type)) => (name.clone(), data_type),
+                _ => return None,
+            };
+            let data_type = erase_type(data_type, &type_variables)?;
+            Some(quote! {#(#annotations)* #(#data_type)* #name})
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let return_type = if is_constructor {
+        vec![]
+    } else {
+        erase_type(return_type, &type_variables)?
+    };
+    let modifiers = if is_interface {
+        // Interface methods are always public, only static ones are declared with modifiers.
+        if has_modifier("static") {
+            quote! {public static}
+        } else {
+            quote! {}
+        }
+    } else {
+        let mut result = quote! {};
+        for modifier in &["public", "static", "abstract", "synchronized"] {
+            if has_modifier(*modifier) {
+                let modifier = Ident::new(modifier, Span::call_site());
+                result.extend(quote! {#modifier});
+            }
+        }
+        result
+    };
+    Some(quote! {
+        #(#annotations)* #modifiers #(#return_type)* #name(#(#arguments),*);
+    })
+}
+
+fn is_identifier(token: &TokenTree, name: &str) -> bool {
+    match token {
+        TokenTree::Ident(identifier) => identifier == name,
+        _ => false,
+    }
+}
+
+fn is_punctuation(token: &TokenTree, value: char) -> bool {
+    match token {
+        TokenTree::Punct(punct) => punct.as_char() == value,
+        _ => false,
+    }
+}
+
+fn is_group(token: &TokenTree, delimiter: Delimiter) -> bool {
+    match token {
+        TokenTree::Group(group) => group.delimiter() == delimiter,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod java_source_to_definitions_tests {
+    use super::*;
+    use parse::*;
+
+    fn parse(input: TokenStream) -> (Vec<JavaDefinition>, Metadata) {
+        let JavaDefinitions {
+            definitions,
+            metadata,
+            ..
+        } = parse_java_definition(input);
+        (definitions, metadata)
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(parse(quote! {options { java_source }}), parse(quote! {}));
+    }
+
+    #[test]
+    fn class() {
+        let input = quote! {
+            options { java_source }
+            package a.b;
+
+            import c.d.TestClass2;
+            import static c.d.TestClass2.test;
+            import java.util.List;
+
+            /**
+             * Documentation.
+             */
+            @Deprecated
+            public final class TestClass1 extends TestClass2 implements TestInterface1, c.d.TestInterface2 {
+                private static final int FIELD = 1;
+                public String field2 = "value";
+                static {
+                    System.out.println();
+                }
+
+                /** Constructor documentation. */
+                public TestClass1(@Nullable String arg) {
+                    super();
+                }
+
+                protected TestClass1(final long arg) throws Exception {}
+
+                private TestClass1() {}
+
+                @Override
+                @RustName(test_one)
+                public static synchronized int test1(int arg1, List<String> arg2) {
+                    return 1;
+                }
+
+                public final @NonNull String test2() {
+                    return "";
+                }
+
+                private void test3() {}
+
+                public void test4(int[] arg) {}
+
+                public void test5(String... args) {}
+
+                class Inner {
+                    public void test6() {}
+                }
+            }
+
+            interface TestInterface1 {}
+        };
+        let expected = quote! {
+            package a.b;
+            import c.d.TestClass2;
+            import java.util.List;
+            import java.lang.*;
+
+            public class TestClass1 extends TestClass2 implements TestInterface1, c.d.TestInterface2 {
+                public TestClass1(@nullable String arg);
+                TestClass1(long arg);
+                @RustName(test_one)
+                public static synchronized int test1(int arg1, List arg2);
+                @nonnull public String test2();
+            }
+
+            interface TestInterface1 {}
+        };
+        assert_eq!(parse(input), parse(expected));
+    }
+
+    #[test]
+    fn abstract_class() {
+        let input = quote! {
+            options { java_source }
+            public abstract class TestClass1 {
+                public abstract void test1();
+            }
+        };
+        let expected = quote! {
+            public abstract class TestClass1 {
+                public abstract void test1();
+            }
+        };
+        assert_eq!(parse(input), parse(expected));
+    }
+
+    #[test]
+    fn generics() {
+        let input = quote! {
+            options { java_source }
+            package a.b;
+
+            public class TestClass1<T, U extends TestClass2<T> & TestInterface1> extends TestClass2<U> {
+                public T test1(U arg) {}
+                public <V extends Comparable<V>> V test2(java.util.Map<String, java.util.List<T>> arg) {}
+            }
+
+            public class TestClass2<T> {}
+            public interface TestInterface1 {}
+        };
+        let expected = quote! {
+            package a.b;
+
+            public class TestClass1 extends TestClass2 {
+                public java.lang.Object test1(TestClass2 arg);
+                public java.lang.Comparable test2(java.util.Map arg);
+            }
+
+            public class TestClass2 {}
+            public interface TestInterface1 {}
+
+            metadata {
+                interface java.lang.Comparable {}
+            }
+        };
+        let input = quote! {
+            #input
+            metadata {
+                interface java.lang.Comparable {}
+            }
+        };
+        assert_eq!(parse(input), parse(expected));
+    }
+
+    #[test]
+    fn interface() {
+        let input = quote! {
+            options { java_source }
+            package a.b;
+
+            @FunctionalInterface
+            public interface TestInterface1 extends TestInterface2 {
+                int CONSTANT = 1;
+
+                String test1(int arg);
+
+                default void test2() {}
+
+                static TestInterface1 test3() {
+                    return null;
+                }
+
+                private void test4() {}
+            }
+
+            interface TestInterface2 {}
+        };
+        let expected = quote! {
+            package a.b;
+
+            public interface TestInterface1 extends TestInterface2 {
+                java.lang.String test1(int arg);
+                void test2();
+                public static TestInterface1 test3();
+            }
+
+            interface TestInterface2 {}
+        };
+        assert_eq!(parse(input), parse(expected));
+    }
+
+    #[test]
+    fn skipped_declarations() {
+        let input = quote! {
+            options { java_source }
+            public enum TestEnum1 {
+                A, B;
+                public void test1() {}
+            }
+
+            public @interface TestAnnotation1 {}
+
+            class TestClass1 {}
+        };
+        let expected = quote! {
+            class TestClass1 {}
+        };
+        assert_eq!(parse(input), parse(expected));
+    }
+}
@@ -0,0 +1,230 @@
+//! Generation of Java sources for classes with native methods implemented in Rust.
+//!
+//! Used with the `java_stubs("library")` option, so that projects that own both the Java and
+//! the Rust side don't need to keep `native` method declarations in sync by hand.
+
+use java_name::*;
+use parse::*;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// A generated Java source file.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct JavaStub {
+    /// Path of the file relative to the sources root, like `a/b/TestClass1.java`.
+    pub path: PathBuf,
+    pub source: String,
+}
+
+/// Generate Java sources for classes that declare native methods.
+///
+/// The generated classes declare the native methods and load the native `library` in a static
+/// initializer. Other methods and constructors are not generated, as they are implemented
+/// in Java.
+pub fn generate_java_stubs(definitions: &JavaDefinitions, library: &str) -> Vec<JavaStub> {
+    definitions
+        .definitions
+        .iter()
+        .filter_map(|definition| match definition.definition {
+            JavaDefinitionKind::Class(ref class) if !class.native_methods.is_empty() => {
+                Some(generate_java_stub(definition, class, library))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn generate_java_stub(definition: &JavaDefinition, class: &JavaClass, library: &str) -> JavaStub {
+    let full_name = definition.name.clone().with_slashes();
+    let (package, name) = match full_name.rfind('/') {
+        Some(index) => (Some(&full_name[..index]), &full_name[index + 1..]),
+        None => (None, full_name.as_str()),
+    };
+    let mut source = String::from("// Generated by rust-jni-generator. Do not edit.\n");
+    if let Some(package) = package {
+        source.push_str(&format!("package {};\n", package.replace("/", ".")));
+    }
+    source.push('\n');
+    let mut header = vec![];
+    if definition.public {
+        header.push("public".to_owned());
+    }
+    if class.is_abstract {
+        header.push("abstract".to_owned());
+    }
+    header.push(format!("class {}", name));
+    if let Some(ref extends) = class.extends {
+        header.push(format!("extends {}", java_type(extends)));
+    }
+    if !class.implements.is_empty() {
+        let implements = class.implements.iter().map(java_type).collect::<Vec<_>>();
+        header.push(format!("implements {}", implements.join(", ")));
+    }
+    source.push_str(&format!("{} {{\n", header.join(" ")));
+    source.push_str(&format!(
+        "    static {{\n        System.loadLibrary(\"{}\");\n    }}\n",
+        library
+    ));
+    for method in class.native_methods.iter() {
+        let mut modifiers = vec![];
+        if method.public {
+            modifiers.push("public ");
+        }
+        if method.is_static {
+            modifiers.push("static ");
+        }
+        let arguments = method
+            .arguments
+            .iter()
+            .map(|argument| format!("{} {}", java_type(&argument.data_type), argument.name))
+            .collect::<Vec<_>>();
+        source.push_str(&format!(
+            "\n    {}native {} {}({});\n",
+            modifiers.concat(),
+            java_type(&method.return_type),
+            method.name,
+            arguments.join(", ")
+        ));
+    }
+    source.push_str("}\n");
+    JavaStub {
+        path: PathBuf::from(format!("{}.java", full_name)),
+        source,
+    }
+}
+
+fn java_type(name: &JavaName) -> String {
+    name.clone().with_slashes().replace("/", ".")
+}
+
+/// Write generated Java sources into the `java` directory of `OUT_DIR`.
+///
+/// Files are only rewritten when their content changes, so that Java builds that depend
+/// on them are not triggered needlessly.
+pub fn write_java_stubs(stubs: &[JavaStub]) {
+    let out_dir = env::var("OUT_DIR").unwrap_or_else(|_| {
+        panic!(
+            "The java_stubs option writes Java sources to OUT_DIR, \
+             which is only set for crates with a build script."
+        )
+    });
+    let root = PathBuf::from(out_dir).join("java");
+    for stub in stubs {
+        let path = root.join(&stub.path);
+        if fs::read_to_string(&path).ok().as_ref() == Some(&stub.source) {
+            continue;
+        }
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| fs::write(&path, &stub.source))
+            .unwrap_or_else(|error| panic!("Failed to write {}: {}.", path.display(), error));
+    }
+}
+
+#[cfg(test)]
+mod generate_java_stubs_tests {
+    use super::*;
+
+    #[test]
+    fn no_native_methods() {
+        let definitions = parse_java_definition(quote! {
+            public class a.b.TestClass1 {
+                public long test1(int arg);
+            }
+            public interface a.b.TestInterface1 {}
+        });
+        assert_eq!(generate_java_stubs(&definitions, "test"), vec![]);
+    }
+
+    #[test]
+    fn native_methods() {
+        let definitions = parse_java_definition(quote! {
+            public class a.b.TestClass1 extends a.b.TestClass2 implements a.b.TestInterface1, a.b.TestInterface2 {
+                public a.b.TestClass1(int arg);
+                public long test1(int arg);
+
+                @RustName(native_one)
+                public native long nativeOne(int arg1, a.b.TestClass2 arg2) {
+                    Ok(0)
+                };
+                static native a.b.TestClass2 nativeTwo() {
+                    Ok(None)
+                };
+                public static native void nativeThree(boolean arg) {
+                    Ok(())
+                };
+            }
+
+            abstract class c.TestClass3 {
+                native void nativeFour() {
+                    Ok(())
+                };
+            }
+
+            class TestClass4 {
+                native void nativeFive() {
+                    Ok(())
+                };
+            }
+
+            metadata {
+                class a.b.TestClass2;
+                interface a.b.TestInterface1 {}
+                interface a.b.TestInterface2 {}
+            }
+        });
+        assert_eq!(
+            generate_java_stubs(&definitions, "test_library"),
+            vec![
+                JavaStub {
+                    path: PathBuf::from("a/b/TestClass1.java"),
+                    source: "// Generated by rust-jni-generator. Do not edit.
+package a.b;
+
+public class TestClass1 extends a.b.TestClass2 implements a.b.TestInterface1, a.b.TestInterface2 {
+    static {
+        System.loadLibrary(\"test_library\");
+    }
+
+    public native long nativeOne(int arg1, a.b.TestClass2 arg2);
+
+    static native a.b.TestClass2 nativeTwo();
+
+    public static native void nativeThree(boolean arg);
+}
+"
+                    .to_owned(),
+                },
+                JavaStub {
+                    path: PathBuf::from("c/TestClass3.java"),
+                    source: "// Generated by rust-jni-generator. Do not edit.
+package c;
+
+abstract class TestClass3 {
+    static {
+        System.loadLibrary(\"test_library\");
+    }
+
+    native void nativeFour();
+}
+"
+                    .to_owned(),
+                },
+                JavaStub {
+                    path: PathBuf::from("TestClass4.java"),
+                    source: "// Generated by rust-jni-generator. Do not edit.
+
+class TestClass4 {
+    static {
+        System.loadLibrary(\"test_library\");
+    }
+
+    native void nativeFive();
+}
+"
+                    .to_owned(),
+                },
+            ]
+        );
+    }
+}
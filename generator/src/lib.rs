@@ -1,42 +1,248 @@
 #![recursion_limit = "1024"]
 
+extern crate indexmap;
 extern crate proc_macro;
 #[macro_use]
 extern crate quote;
 extern crate proc_macro2;
-extern crate rust_jni;
 
+mod derive;
 mod generate;
 mod java_name;
+mod java_source;
+mod java_stubs;
 mod parse;
 mod prepare;
+mod register_native;
 
+use derive::*;
 use generate::*;
 use java_name::*;
+use java_source::*;
+use java_stubs::*;
 use parse::*;
 use prepare::*;
 use proc_macro2::*;
+use register_native::*;
 
 /// Generate `rust-jni` wrappers for Java classes and interfaces.
 ///
+/// Generator options can be passed in an `options { ... }` block before the definitions:
+///  - `strict_extends` requires all classes to explicitly extend a class that is either
+///    `java.lang.Object` or is defined or declared in metadata, instead of silently extending
+///    `java.lang.Object`.
+///  - `java_source` allows to paste Java source code instead of writing definitions, see below.
+///  - `java_stubs("library")` writes Java sources of classes with native methods into the `java`
+///    directory of `OUT_DIR`, so the crate needs a build script. The generated classes declare
+///    the native methods and load the native `library` in a static initializer. Other methods
+///    and constructors are not generated, as they are implemented in Java.
+///  - `unchecked_methods("feature")` also generates an `unsafe` `method_unchecked` variant of
+///    every method, compiled only when the `feature` of the crate is enabled. The variants
+///    don't convert `null` results of `@NonNull` methods into `NullPointerException`-s and
+///    don't apply `@RustMapResult` functions, returning the result of the Java call as is.
+///    They are meant for performance-critical inner loops, and callers must ensure that
+///    results of `@NonNull` methods are not `null`.
+///  - `c_abi(a.b.Foo, a.b.Bar)` generates `extern "C"` functions for the public constructors and
+///    methods of the listed classes, so that C and C++ code in the same process can call them
+///    through the generated bindings, reusing their cached class and method ids and checks.
+///    The functions are named by the class and the Rust method, like `a_b_Foo_init` or
+///    `a_b_Foo_compute`, and take a `JNIEnv*`, the object for instance methods and the arguments
+///    as JNI values. Returned objects are new global references, which are deleted with
+///    `a_b_Foo_release(env, object)`. Exceptions are left pending, and the functions return zero
+///    or `NULL` in that case, like JNI functions do. Methods with `@since`, `@throws` or
+///    `@RustMapResult` are skipped.
+///
+/// Definitions can be preceded by a `package a.b;` statement, which puts definitions with
+/// simple names, like `class TestClass1 { ... }`, into the `a.b` package. Simple names in
+/// method signatures refer to the classes and interfaces of this package.
+///
+/// Definitions can also be preceded by `import` statements, which allow to refer to classes and
+/// interfaces by simple names:
+///  - `import a.b.TestClass1;` resolves `TestClass1` to `a.b.TestClass1`.
+///  - `import a.b.*;` resolves simple names of classes and interfaces from the `a.b` package
+///    that are defined or declared in metadata.
+///
+/// Method and argument names that are Rust keywords are escaped: `match` becomes `r#match` and
+/// `self`, `Self` and `crate`, which can't be raw identifiers, get an underscore suffix, like
+/// `self_`. Use `@RustName` to pick a different name for a method.
+///
+/// Object arguments are passed by reference and can't be `null`. Arguments marked with
+/// `@nullable`, like `void test(@nullable a.b.TestClass1 arg)`, are passed as
+/// `Option<&TestClass1>` instead, so `None` or `rust_jni::null::<TestClass1>()` passes `null`.
+/// Results marked with `@nullable` are returned as an `Option`.
+///
+/// Generated methods and constructors are `pub` if the Java ones are `public`. Use
+/// `@rust_visibility(pub(crate))` to pick a different Rust visibility, for example to keep a
+/// public Java method out of the public API of the crate. `@rust_visibility()` makes the method
+/// private.
+///
+/// Native methods of classes are implemented in Rust, either with a body written inline, like
+/// `native long compute(int x) { Ok(x as i64) }`, or declared without one, like
+/// `native long compute(int x);`. The JNI entry point converts the arguments and throws
+/// returned exceptions in both cases. For methods without a body a `<Class>Natives`
+/// trait is generated, like `FooNatives<'a>`, which the user implements for the class:
+/// `impl<'a> FooNatives<'a> for Foo<'a> { fn compute(&self, x: i32, token: ...) ... }`.
+/// Static native methods take the `env` instead of `&self`.
+///
+/// Methods and constructors of classes that were added in newer Java releases can be marked with
+/// the Java feature release that introduced them, like `@since(11)`. Their bindings check the
+/// release of the running Java VM with `rust_jni::jvm_feature_level` and return a
+/// `Result<JavaResult<'a, T>, UnsupportedOnThisJvm>`, so that calling them on older Java VMs
+/// returns an `UnsupportedOnThisJvm` error instead of throwing a `NoSuchMethodError`. `@since`
+/// is not supported on native, interface and abstract methods.
+///
+/// Methods of classes that throw an expected exception can be marked with its class, like
+/// `@throws(java.io.IOException) int read();`. The generated method catches exceptions of this
+/// class and returns them as the error of a `JavaResult<'a, Result<i32, IOException<'a>>>`, other
+/// exceptions are still returned as `JavaResult` errors. The exception class has to implement
+/// `rust_jni::JavaExceptionClass`. `@throws` can't be combined with `@RustMapResult`.
+///
+/// Java names with `$` or non-ASCII letters, like names of inner classes, are written as string
+/// literals: `class a.b."Outer$Inner" { ... }` or `public int "get$Value"();`. Rust names are
+/// derived from them by replacing `$` with `_` and other non-ASCII characters with `_u` followed
+/// by their hex code, so `"Outer$Inner"` becomes `Outer_Inner` and `"Café"` becomes `Caf_u00e9`.
+///
+/// Interfaces in `extends` and `implements` clauses can have generic type arguments, like
+/// `interface a.b.Foo extends Comparable<a.b.Foo> { ... }`. The arguments are erased, so the
+/// generated trait of `Foo` extends the trait of `Comparable`.
+///
+/// Generic type arguments of argument and result types are erased too, so a
+/// `java.lang.Class<? extends a.b.Foo>` argument takes a `&rust_jni::java::lang::Class`. Class
+/// objects of generated wrappers are passed with `Class::of::<a::b::Foo>(env, &token)?`.
+///
+/// Comments, including documentation comments, can be used anywhere in the definitions and in
+/// metadata. Empty statements, like a semicolon after a class body, are ignored.
+///
+/// With the `java_source` option the input is Java source code of a compilation unit, which is
+/// converted into definitions. Method and constructor bodies, fields, initializers, private
+/// members, nested types, enums and annotation types are ignored, generic types are erased and
+/// `java.lang` is imported implicitly. Methods with array or variable arity types are skipped.
+/// `@Nullable` and `@NonNull` annotations are recognized, other Java annotations are dropped.
+/// The source has to be valid Rust tokens, so character literals with more than one character
+/// are not supported. Overloaded methods still need distinct names via `@RustName`.
+///
+/// Generated classes implement `AsRef` and `Borrow` for `java.lang.Object` and every known
+/// superclass, so they can be passed to generic functions bounded on `AsRef<Object>`.
+///
+/// The generated code checks at compile time that the `rust-jni` version it's compiled with
+/// supports it. `rust_jni::java_generate!`, re-exported with the `macros` feature of `rust-jni`,
+/// always uses the matching generator version.
+///
 /// TODO(#76): examples.
 #[proc_macro]
 pub fn java_generate(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     java_generate_impl(input.into()).into()
 }
 
+/// Derive `rust-jni` class wrapper traits for a hand-written wrapper struct.
+///
+/// Generates the same trait implementations as [`java_generate!`](macro.java_generate.html)
+/// does for classes: `JniSignature`, `FromObject`, `AsRef` and `Borrow` for the super class
+/// and `java.lang.Object`, `Deref` to the super class, `Display`, `PartialEq` and `Eq`.
+///
+/// The struct must have an `object` field of the super class type and is configured with
+/// the `java` attribute:
+///  - `class = "path/to/Class"` is the JNI name of the Java class. Required.
+///  - `extends = path::to::SuperClass` is the Rust wrapper of the super class.
+///    Defaults to `java.lang.Object`.
+///
+/// ```ignore
+/// #[derive(Debug, JavaClassWrapper)]
+/// #[java(class = "a/b/Foo", extends = Bar)]
+/// pub struct Foo<'env> {
+///     object: Bar<'env>,
+/// }
+/// ```
+#[proc_macro_derive(JavaClassWrapper, attributes(java))]
+pub fn java_class_wrapper(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_java_class_wrapper(input.into()).into()
+}
+
+/// Register a native method implementation with
+/// [`rust_jni::register_all_natives`](../rust_jni/fn.register_all_natives.html).
+///
+/// Takes the Java class name, the method name and the JNI method signature. Registrations
+/// from all modules of all crates in the program are collected, so a single
+/// `register_all_natives` call, usually in `JNI_OnLoad`, registers them all instead of
+/// calling `RegisterNatives` by hand.
+///
+/// ```ignore
+/// #[register_native("a.b.Foo", "nativeMethod", "(I)J")]
+/// extern "system" fn native_method(env: *mut JNIEnv, this: jobject, value: jint) -> jlong {
+///     value as jlong
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn register_native(
+    attribute: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    generate_native_registration(attribute.into(), item.into()).into()
+}
+
 fn java_generate_impl(input: TokenStream) -> TokenStream {
-    generate(&to_generator_data(parse_java_definition(input)))
+    let definitions = parse_java_definition(input);
+    if let Some(ref library) = definitions.options.java_stubs {
+        write_java_stubs(&generate_java_stubs(&definitions, library));
+    }
+    let data = to_generator_data(definitions);
+    let mut tokens = generate(&data);
+    tokens.extend(generate_binding_info(&data));
+    tokens.extend(generate_compatibility_check());
+    tokens
 }
 
 #[cfg(test)]
 mod java_generate_tests {
     use super::*;
 
+    fn binding_info(classes: &[(&str, &str, &str, &[&str])], interfaces: &[&str]) -> TokenStream {
+        let class_names = classes.iter().map(|(name, _, _, _)| name);
+        let class_signatures = classes.iter().map(|(_, signature, _, _)| signature);
+        let class_super_classes = classes.iter().map(|(_, _, super_class, _)| super_class);
+        let class_interfaces = classes
+            .iter()
+            .map(|(_, _, _, interfaces)| interfaces.iter());
+        let generator_version = env!("CARGO_PKG_VERSION");
+        let compatible_with = concat!(
+            env!("CARGO_PKG_VERSION_MAJOR"),
+            ".",
+            env!("CARGO_PKG_VERSION_MINOR")
+        );
+        quote! {
+            #[doc(hidden)]
+            pub fn __rust_jni_binding_info() -> ::rust_jni::introspection::BindingInfo {
+                ::rust_jni::introspection::BindingInfo {
+                    crate_name: env!("CARGO_PKG_NAME"),
+                    crate_version: env!("CARGO_PKG_VERSION"),
+                    generator_version: #generator_version,
+                    classes: &[
+                        #(
+                            ::rust_jni::introspection::ClassBindingInfo {
+                                name: #class_names,
+                                signature: #class_signatures,
+                                super_class: #class_super_classes,
+                                interfaces: &[#(#class_interfaces,)*],
+                            },
+                        )*
+                    ],
+                    interfaces: &[#(#interfaces,)*],
+                }
+            }
+
+            ::rust_jni::introspection::__inventory::submit! {
+                ::rust_jni::introspection::BindingRegistration::new(__rust_jni_binding_info)
+            }
+
+            const _: () = ::rust_jni::introspection::__check_compatible_with(#compatible_with);
+        }
+    }
+
     #[test]
     fn empty() {
         let input = quote! {};
-        let expected = quote! {};
+        let mut expected = quote! {};
+        expected.extend(binding_info(&[], &[]));
         assert_tokens_equals(java_generate_impl(input), expected);
     }
 
@@ -45,93 +251,112 @@ mod java_generate_tests {
         let input = quote! {
             class TestClass1 extends TestClass2 {}
         };
-        let expected = quote! {
+        let mut expected = quote! {
             #[derive(Debug)]
             struct TestClass1<'env> {
-                object: ::TestClass2<'env>,
+                object: crate::TestClass2<'env>,
             }
 
-            impl<'a> ::rust_jni::JavaType for TestClass1<'a> {
-                #[doc(hidden)]
-                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-                #[doc(hidden)]
-                fn __signature() -> &'static str {
+            impl<'a> ::rust_jni::JniSignature for TestClass1<'a> {
+                fn signature() -> &'static str {
                     "LTestClass1;"
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::ToJni for TestClass1<'a> {
-                unsafe fn __to_jni(&self) -> Self::__JniType {
-                    self.raw_object()
+            impl<'a> ::rust_jni::FromObject<'a> for TestClass1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <crate::TestClass2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::FromJni<'a> for TestClass1<'a> {
-                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
-                    Self {
-                        object: <::TestClass2 as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
-                    }
+            impl<'a> ::rust_jni::__generator::ops::Deref for TestClass1<'a> {
+                type Target = crate::TestClass2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, TestClass1<'a>> for TestClass1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b TestClass1<'a> {
+            impl<'a> ::rust_jni::__generator::convert::From<TestClass1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: TestClass1<'a>) -> Self {
+                    value.object.into()
+                }
+            }
+
+            impl<'env> ::rust_jni::Detachable for TestClass1<'env> {
+                type Attached<'a> = TestClass1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<crate::TestClass2<'a>> for TestClass1<'a> {
+                fn as_ref(&self) -> &crate::TestClass2<'a> {
                     self
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, ::TestClass2<'a>> for TestClass1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b ::TestClass2<'a> {
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<crate::TestClass2<'a>> for TestClass1<'a> {
+                fn borrow(&self) -> &crate::TestClass2<'a> {
                     self
                 }
             }
 
-            impl<'a> ::std::ops::Deref for TestClass1<'a> {
-                type Target = ::TestClass2<'a>;
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for TestClass1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
 
-                fn deref(&self) -> &Self::Target {
-                    &self.object
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for TestClass1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
                 }
             }
 
             impl<'a> TestClass1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "TestClass1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "LTestClass1;";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "TestClass1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "TestClass1", token)
+                    ::rust_jni::java::lang::Class::find(env, token, "TestClass1")
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
                 {
-                    self.object
-                        .clone(token)
-                        .map(|object| Self { object })
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
                 }
 
                 pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                     self.object.to_string(token)
                 }
             }
 
-            impl<'a> ::std::fmt::Display for TestClass1<'a> {
-                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    self.object.fmt(formatter)
+            impl<'a> ::rust_jni::__generator::fmt::Display for TestClass1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for TestClass1<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for TestClass1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
-                    self.object.eq(other)
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
                 }
             }
 
             impl<'a> Eq for TestClass1<'a> {}
         };
+        expected.extend(binding_info(
+            &[("TestClass1", "LTestClass1;", "TestClass2", &[])],
+            &[],
+        ));
         assert_tokens_equals(java_generate_impl(input), expected);
     }
 
@@ -142,7 +367,7 @@ mod java_generate_tests {
             interface a.b.TestInterface2 {}
             class TestClass1 extends TestClass2 implements a.b.TestInterface1, a.b.TestInterface2 {}
         };
-        let expected = quote! {
+        let mut expected = quote! {
             trait TestInterface1<'a> {
             }
 
@@ -151,96 +376,120 @@ mod java_generate_tests {
 
             #[derive(Debug)]
             struct TestClass1<'env> {
-                object: ::TestClass2<'env>,
+                object: crate::TestClass2<'env>,
             }
 
-            impl<'a> ::rust_jni::JavaType for TestClass1<'a> {
-                #[doc(hidden)]
-                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-                #[doc(hidden)]
-                fn __signature() -> &'static str {
+            impl<'a> ::rust_jni::JniSignature for TestClass1<'a> {
+                fn signature() -> &'static str {
                     "LTestClass1;"
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::ToJni for TestClass1<'a> {
-                unsafe fn __to_jni(&self) -> Self::__JniType {
-                    self.raw_object()
+            impl<'a> ::rust_jni::FromObject<'a> for TestClass1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <crate::TestClass2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::FromJni<'a> for TestClass1<'a> {
-                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
-                    Self {
-                        object: <::TestClass2 as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
-                    }
+            impl<'a> ::rust_jni::__generator::ops::Deref for TestClass1<'a> {
+                type Target = crate::TestClass2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, TestClass1<'a>> for TestClass1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b TestClass1<'a> {
+            impl<'a> ::rust_jni::__generator::convert::From<TestClass1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: TestClass1<'a>) -> Self {
+                    value.object.into()
+                }
+            }
+
+            impl<'env> ::rust_jni::Detachable for TestClass1<'env> {
+                type Attached<'a> = TestClass1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<crate::TestClass2<'a>> for TestClass1<'a> {
+                fn as_ref(&self) -> &crate::TestClass2<'a> {
                     self
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, ::TestClass2<'a>> for TestClass1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b ::TestClass2<'a> {
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<crate::TestClass2<'a>> for TestClass1<'a> {
+                fn borrow(&self) -> &crate::TestClass2<'a> {
                     self
                 }
             }
 
-            impl<'a> ::std::ops::Deref for TestClass1<'a> {
-                type Target = ::TestClass2<'a>;
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for TestClass1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
 
-                fn deref(&self) -> &Self::Target {
-                    &self.object
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for TestClass1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
                 }
             }
 
             impl<'a> TestClass1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "TestClass1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "LTestClass1;";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "TestClass1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "TestClass1", token)
+                    ::rust_jni::java::lang::Class::find(env, token, "TestClass1")
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
                 {
-                    self.object
-                        .clone(token)
-                        .map(|object| Self { object })
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
                 }
 
                 pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                     self.object.to_string(token)
                 }
             }
 
-            impl<'a> ::std::fmt::Display for TestClass1<'a> {
-                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    self.object.fmt(formatter)
+            impl<'a> ::rust_jni::__generator::fmt::Display for TestClass1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for TestClass1<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for TestClass1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
-                    self.object.eq(other)
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
                 }
             }
 
             impl<'a> Eq for TestClass1<'a> {}
 
-            impl<'a> ::a::b::TestInterface1<'a> for TestClass1<'a> {
+            impl<'a> crate::a::b::TestInterface1<'a> for TestClass1<'a> {
             }
 
-            impl<'a> ::a::b::TestInterface2<'a> for TestClass1<'a> {
+            impl<'a> crate::a::b::TestInterface2<'a> for TestClass1<'a> {
             }
         };
+        expected.extend(binding_info(
+            &[(
+                "TestClass1",
+                "LTestClass1;",
+                "TestClass2",
+                &["a/b/TestInterface1", "a/b/TestInterface2"],
+            )],
+            &["TestInterface1", "TestInterface2"],
+        ));
         assert_tokens_equals(java_generate_impl(input), expected);
     }
 
@@ -249,93 +498,112 @@ mod java_generate_tests {
         let input = quote! {
             class a.b.TestClass1 extends c.d.TestClass2 {}
         };
-        let expected = quote! {
+        let mut expected = quote! {
             #[derive(Debug)]
             struct TestClass1<'env> {
-                object: ::c::d::TestClass2<'env>,
+                object: crate::c::d::TestClass2<'env>,
             }
 
-            impl<'a> ::rust_jni::JavaType for TestClass1<'a> {
-                #[doc(hidden)]
-                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-                #[doc(hidden)]
-                fn __signature() -> &'static str {
+            impl<'a> ::rust_jni::JniSignature for TestClass1<'a> {
+                fn signature() -> &'static str {
                     "La/b/TestClass1;"
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::ToJni for TestClass1<'a> {
-                unsafe fn __to_jni(&self) -> Self::__JniType {
-                    self.raw_object()
+            impl<'a> ::rust_jni::FromObject<'a> for TestClass1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <crate::c::d::TestClass2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::FromJni<'a> for TestClass1<'a> {
-                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
-                    Self {
-                        object: <::c::d::TestClass2 as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
-                    }
+            impl<'a> ::rust_jni::__generator::ops::Deref for TestClass1<'a> {
+                type Target = crate::c::d::TestClass2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::From<TestClass1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: TestClass1<'a>) -> Self {
+                    value.object.into()
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, TestClass1<'a>> for TestClass1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b TestClass1<'a> {
+            impl<'env> ::rust_jni::Detachable for TestClass1<'env> {
+                type Attached<'a> = TestClass1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<crate::c::d::TestClass2<'a>> for TestClass1<'a> {
+                fn as_ref(&self) -> &crate::c::d::TestClass2<'a> {
                     self
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, ::c::d::TestClass2<'a>> for TestClass1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b ::c::d::TestClass2<'a> {
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<crate::c::d::TestClass2<'a>> for TestClass1<'a> {
+                fn borrow(&self) -> &crate::c::d::TestClass2<'a> {
                     self
                 }
             }
 
-            impl<'a> ::std::ops::Deref for TestClass1<'a> {
-                type Target = ::c::d::TestClass2<'a>;
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for TestClass1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
 
-                fn deref(&self) -> &Self::Target {
-                    &self.object
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for TestClass1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
                 }
             }
 
             impl<'a> TestClass1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "a.b.TestClass1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "La/b/TestClass1;";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "a/b/TestClass1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "a/b/TestClass1", token)
+                    ::rust_jni::java::lang::Class::find(env, token, "a/b/TestClass1")
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
                 {
-                    self.object
-                        .clone(token)
-                        .map(|object| Self { object })
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
                 }
 
                 pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                     self.object.to_string(token)
                 }
             }
 
-            impl<'a> ::std::fmt::Display for TestClass1<'a> {
-                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    self.object.fmt(formatter)
+            impl<'a> ::rust_jni::__generator::fmt::Display for TestClass1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for TestClass1<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for TestClass1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
-                    self.object.eq(other)
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
                 }
             }
 
             impl<'a> Eq for TestClass1<'a> {}
         };
+        expected.extend(binding_info(
+            &[("TestClass1", "La/b/TestClass1;", "c/d/TestClass2", &[])],
+            &[],
+        ));
         assert_tokens_equals(java_generate_impl(input), expected);
     }
 
@@ -344,93 +612,112 @@ mod java_generate_tests {
         let input = quote! {
             public class TestClass1 extends TestClass2 {}
         };
-        let expected = quote! {
+        let mut expected = quote! {
             #[derive(Debug)]
             pub struct TestClass1<'env> {
-                object: ::TestClass2<'env>,
+                object: crate::TestClass2<'env>,
             }
 
-            impl<'a> ::rust_jni::JavaType for TestClass1<'a> {
-                #[doc(hidden)]
-                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-                #[doc(hidden)]
-                fn __signature() -> &'static str {
+            impl<'a> ::rust_jni::JniSignature for TestClass1<'a> {
+                fn signature() -> &'static str {
                     "LTestClass1;"
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::ToJni for TestClass1<'a> {
-                unsafe fn __to_jni(&self) -> Self::__JniType {
-                    self.raw_object()
+            impl<'a> ::rust_jni::FromObject<'a> for TestClass1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <crate::TestClass2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::FromJni<'a> for TestClass1<'a> {
-                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
-                    Self {
-                        object: <::TestClass2 as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
-                    }
+            impl<'a> ::rust_jni::__generator::ops::Deref for TestClass1<'a> {
+                type Target = crate::TestClass2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, TestClass1<'a>> for TestClass1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b TestClass1<'a> {
+            impl<'a> ::rust_jni::__generator::convert::From<TestClass1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: TestClass1<'a>) -> Self {
+                    value.object.into()
+                }
+            }
+
+            impl<'env> ::rust_jni::Detachable for TestClass1<'env> {
+                type Attached<'a> = TestClass1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<crate::TestClass2<'a>> for TestClass1<'a> {
+                fn as_ref(&self) -> &crate::TestClass2<'a> {
                     self
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, ::TestClass2<'a>> for TestClass1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b ::TestClass2<'a> {
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<crate::TestClass2<'a>> for TestClass1<'a> {
+                fn borrow(&self) -> &crate::TestClass2<'a> {
                     self
                 }
             }
 
-            impl<'a> ::std::ops::Deref for TestClass1<'a> {
-                type Target = ::TestClass2<'a>;
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for TestClass1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
 
-                fn deref(&self) -> &Self::Target {
-                    &self.object
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for TestClass1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
                 }
             }
 
             impl<'a> TestClass1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "TestClass1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "LTestClass1;";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "TestClass1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "TestClass1", token)
+                    ::rust_jni::java::lang::Class::find(env, token, "TestClass1")
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
                 {
-                    self.object
-                        .clone(token)
-                        .map(|object| Self { object })
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
                 }
 
                 pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                     self.object.to_string(token)
                 }
             }
 
-            impl<'a> ::std::fmt::Display for TestClass1<'a> {
-                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    self.object.fmt(formatter)
+            impl<'a> ::rust_jni::__generator::fmt::Display for TestClass1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for TestClass1<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for TestClass1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
-                    self.object.eq(other)
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
                 }
             }
 
             impl<'a> Eq for TestClass1<'a> {}
         };
+        expected.extend(binding_info(
+            &[("TestClass1", "LTestClass1;", "TestClass2", &[])],
+            &[],
+        ));
         assert_tokens_equals(java_generate_impl(input), expected);
     }
 
@@ -439,10 +726,11 @@ mod java_generate_tests {
         let input = quote! {
             interface TestInterface1 {}
         };
-        let expected = quote! {
+        let mut expected = quote! {
             trait TestInterface1<'a> {
             }
         };
+        expected.extend(binding_info(&[], &["TestInterface1"]));
         assert_tokens_equals(java_generate_impl(input), expected);
     }
 
@@ -451,10 +739,11 @@ mod java_generate_tests {
         let input = quote! {
             interface a.b.TestInterface1 {}
         };
-        let expected = quote! {
+        let mut expected = quote! {
             trait TestInterface1<'a> {
             }
         };
+        expected.extend(binding_info(&[], &["TestInterface1"]));
         assert_tokens_equals(java_generate_impl(input), expected);
     }
 
@@ -463,10 +752,11 @@ mod java_generate_tests {
         let input = quote! {
             public interface TestInterface1 {}
         };
-        let expected = quote! {
+        let mut expected = quote! {
             pub trait TestInterface1<'a> {
             }
         };
+        expected.extend(binding_info(&[], &["TestInterface1"]));
         assert_tokens_equals(java_generate_impl(input), expected);
     }
 
@@ -477,16 +767,20 @@ mod java_generate_tests {
             interface TestInterface3 {}
             interface TestInterface1 extends TestInterface2, TestInterface3 {}
         };
-        let expected = quote! {
+        let mut expected = quote! {
             trait TestInterface2<'a> {
             }
 
             trait TestInterface3<'a> {
             }
 
-            trait TestInterface1<'a>: ::TestInterface2<'a> + ::TestInterface3<'a> {
+            trait TestInterface1<'a>: crate::TestInterface2<'a> + crate::TestInterface3<'a> {
             }
         };
+        expected.extend(binding_info(
+            &[],
+            &["TestInterface2", "TestInterface3", "TestInterface1"],
+        ));
         assert_tokens_equals(java_generate_impl(input), expected);
     }
 
@@ -503,7 +797,7 @@ mod java_generate_tests {
                 class TestClass3;
             }
         };
-        let expected = quote! {
+        let mut expected = quote! {
             trait TestInterface1<'a> {
             }
 
@@ -512,85 +806,88 @@ mod java_generate_tests {
 
             #[derive(Debug)]
             struct TestClass1<'env> {
-                object: ::java::lang::Object<'env>,
+                object: ::rust_jni::java::lang::Object<'env>,
             }
 
-            impl<'a> ::rust_jni::JavaType for TestClass1<'a> {
-                #[doc(hidden)]
-                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-                #[doc(hidden)]
-                fn __signature() -> &'static str {
+            impl<'a> ::rust_jni::JniSignature for TestClass1<'a> {
+                fn signature() -> &'static str {
                     "LTestClass1;"
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::ToJni for TestClass1<'a> {
-                unsafe fn __to_jni(&self) -> Self::__JniType {
-                    self.raw_object()
+            impl<'a> ::rust_jni::FromObject<'a> for TestClass1<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <::rust_jni::java::lang::Object as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::FromJni<'a> for TestClass1<'a> {
-                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
-                    Self {
-                        object: <::java::lang::Object as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
-                    }
+            impl<'a> ::rust_jni::__generator::ops::Deref for TestClass1<'a> {
+                type Target = ::rust_jni::java::lang::Object<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, TestClass1<'a>> for TestClass1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b TestClass1<'a> {
-                    self
+            impl<'a> ::rust_jni::__generator::convert::From<TestClass1<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: TestClass1<'a>) -> Self {
+                    value.object.into()
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, ::java::lang::Object<'a>> for TestClass1<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b ::java::lang::Object<'a> {
+            impl<'env> ::rust_jni::Detachable for TestClass1<'env> {
+                type Attached<'a> = TestClass1<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for TestClass1<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
                     self
                 }
             }
 
-            impl<'a> ::std::ops::Deref for TestClass1<'a> {
-                type Target = ::java::lang::Object<'a>;
-
-                fn deref(&self) -> &Self::Target {
-                    &self.object
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for TestClass1<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
                 }
             }
 
             impl<'a> TestClass1<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "TestClass1";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "LTestClass1;";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "TestClass1";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "TestClass1", token)
+                    ::rust_jni::java::lang::Class::find(env, token, "TestClass1")
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
                 {
-                    self.object
-                        .clone(token)
-                        .map(|object| Self { object })
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
                 }
 
                 pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                     self.object.to_string(token)
                 }
             }
 
-            impl<'a> ::std::fmt::Display for TestClass1<'a> {
-                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    self.object.fmt(formatter)
+            impl<'a> ::rust_jni::__generator::fmt::Display for TestClass1<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for TestClass1<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for TestClass1<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
-                    self.object.eq(other)
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
                 }
             }
 
@@ -598,90 +895,100 @@ mod java_generate_tests {
 
             #[derive(Debug)]
             struct TestClass2<'env> {
-                object: ::java::lang::Object<'env>,
+                object: ::rust_jni::java::lang::Object<'env>,
             }
 
-            impl<'a> ::rust_jni::JavaType for TestClass2<'a> {
-                #[doc(hidden)]
-                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-                #[doc(hidden)]
-                fn __signature() -> &'static str {
+            impl<'a> ::rust_jni::JniSignature for TestClass2<'a> {
+                fn signature() -> &'static str {
                     "LTestClass2;"
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::ToJni for TestClass2<'a> {
-                unsafe fn __to_jni(&self) -> Self::__JniType {
-                    self.raw_object()
+            impl<'a> ::rust_jni::FromObject<'a> for TestClass2<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <::rust_jni::java::lang::Object as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::FromJni<'a> for TestClass2<'a> {
-                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
-                    Self {
-                        object: <::java::lang::Object as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
-                    }
+            impl<'a> ::rust_jni::__generator::ops::Deref for TestClass2<'a> {
+                type Target = ::rust_jni::java::lang::Object<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, TestClass2<'a>> for TestClass2<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b TestClass2<'a> {
-                    self
+            impl<'a> ::rust_jni::__generator::convert::From<TestClass2<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: TestClass2<'a>) -> Self {
+                    value.object.into()
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, ::java::lang::Object<'a>> for TestClass2<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b ::java::lang::Object<'a> {
+            impl<'env> ::rust_jni::Detachable for TestClass2<'env> {
+                type Attached<'a> = TestClass2<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for TestClass2<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
                     self
                 }
             }
 
-            impl<'a> ::std::ops::Deref for TestClass2<'a> {
-                type Target = ::java::lang::Object<'a>;
-
-                fn deref(&self) -> &Self::Target {
-                    &self.object
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for TestClass2<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
                 }
             }
 
             impl<'a> TestClass2<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "TestClass2";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "LTestClass2;";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "TestClass2";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "TestClass2", token)
+                    ::rust_jni::java::lang::Class::find(env, token, "TestClass2")
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
                 {
-                    self.object
-                        .clone(token)
-                        .map(|object| Self { object })
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
                 }
 
                 pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                     self.object.to_string(token)
                 }
             }
 
-            impl<'a> ::std::fmt::Display for TestClass2<'a> {
-                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    self.object.fmt(formatter)
+            impl<'a> ::rust_jni::__generator::fmt::Display for TestClass2<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for TestClass2<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for TestClass2<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
-                    self.object.eq(other)
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
                 }
             }
 
             impl<'a> Eq for TestClass2<'a> {}
         };
+        expected.extend(binding_info(
+            &[
+                ("TestClass1", "LTestClass1;", "java/lang/Object", &[]),
+                ("TestClass2", "LTestClass2;", "java/lang/Object", &[]),
+            ],
+            &["TestInterface1", "TestInterface2"],
+        ));
         assert_tokens_equals(java_generate_impl(input), expected);
     }
 
@@ -749,7 +1056,7 @@ mod java_generate_tests {
                 class c.d.TestClass2 extends c.d.TestClass1 implements e.f.TestInterface1;
             }
         };
-        let expected = quote! {
+        let mut expected = quote! {
             pub trait TestInterface3<'a> {
                 fn primitiveInterfaceFunc3(
                     &self,
@@ -760,12 +1067,12 @@ mod java_generate_tests {
 
                 fn objectInterfaceFunc3(
                     &self,
-                    arg: &::a::b::TestClass3<'a>,
+                    arg: &crate::a::b::TestClass3<'a>,
                     token: &::rust_jni::NoException<'a>,
-                ) -> ::rust_jni::JavaResult<'a, ::a::b::TestClass3<'a> >;
+                ) -> ::rust_jni::JavaResult<'a, crate::a::b::TestClass3<'a> >;
             }
 
-            pub trait TestInterface4<'a>: ::c::d::TestInterface2<'a> + ::a::b::TestInterface3<'a> {
+            pub trait TestInterface4<'a>: crate::c::d::TestInterface2<'a> + crate::a::b::TestInterface3<'a> {
                 fn primitive_func_3(
                     &self,
                     arg1: i32,
@@ -775,110 +1082,125 @@ mod java_generate_tests {
 
                 fn object_func_3(
                     &self,
-                    arg: &::a::b::TestClass3<'a>,
+                    arg: &crate::a::b::TestClass3<'a>,
                     token: &::rust_jni::NoException<'a>,
-                ) -> ::rust_jni::JavaResult<'a, ::c::d::TestClass2<'a> >;
+                ) -> ::rust_jni::JavaResult<'a, crate::c::d::TestClass2<'a> >;
             }
 
             #[derive(Debug)]
             pub struct TestClass3<'env> {
-                object: ::c::d::TestClass2<'env>,
+                object: crate::c::d::TestClass2<'env>,
             }
 
-            impl<'a> ::rust_jni::JavaType for TestClass3<'a> {
-                #[doc(hidden)]
-                type __JniType = <::rust_jni::java::lang::Object<'a> as ::rust_jni::JavaType>::__JniType;
-
-                #[doc(hidden)]
-                fn __signature() -> &'static str {
+            impl<'a> ::rust_jni::JniSignature for TestClass3<'a> {
+                fn signature() -> &'static str {
                     "La/b/TestClass3;"
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::ToJni for TestClass3<'a> {
-                unsafe fn __to_jni(&self) -> Self::__JniType {
-                    self.raw_object()
+            impl<'a> ::rust_jni::FromObject<'a> for TestClass3<'a> {
+                unsafe fn from_object(object: ::rust_jni::java::lang::Object<'a>) -> Self {
+                    Self {
+                        object: <crate::c::d::TestClass2 as ::rust_jni::FromObject<'a>>::from_object(object),
+                    }
+                }
+            }
+
+            impl<'a> ::rust_jni::__generator::ops::Deref for TestClass3<'a> {
+                type Target = crate::c::d::TestClass2<'a>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.object
                 }
             }
 
-            impl<'a> ::rust_jni::__generator::FromJni<'a> for TestClass3<'a> {
-                unsafe fn __from_jni(env: &'a ::rust_jni::JniEnv<'a>, value: Self::__JniType) -> Self {
-                    Self {
-                        object: <::c::d::TestClass2 as ::rust_jni::__generator::FromJni<'a>>::__from_jni(env, value),
-                    }
+            impl<'a> ::rust_jni::__generator::convert::From<TestClass3<'a>> for ::rust_jni::java::lang::Object<'a> {
+                fn from(value: TestClass3<'a>) -> Self {
+                    value.object.into()
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, TestClass3<'a>> for TestClass3<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b TestClass3<'a> {
+            impl<'env> ::rust_jni::Detachable for TestClass3<'env> {
+                type Attached<'a> = TestClass3<'a>;
+            }
+
+            impl<'a> ::rust_jni::__generator::convert::AsRef<crate::c::d::TestClass2<'a>> for TestClass3<'a> {
+                fn as_ref(&self) -> &crate::c::d::TestClass2<'a> {
                     self
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, ::c::d::TestClass2<'a>> for TestClass3<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b ::c::d::TestClass2<'a> {
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<crate::c::d::TestClass2<'a>> for TestClass3<'a> {
+                fn borrow(&self) -> &crate::c::d::TestClass2<'a> {
                     self
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, ::c::d::TestClass1<'a>> for TestClass3<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b ::c::d::TestClass1<'a> {
+            impl<'a> ::rust_jni::__generator::convert::AsRef<crate::c::d::TestClass1<'a>> for TestClass3<'a> {
+                fn as_ref(&self) -> &crate::c::d::TestClass1<'a> {
                     self
                 }
             }
 
-            impl<'a> ::rust_jni::Cast<'a, ::java::lang::Object<'a>> for TestClass3<'a> {
-                #[doc(hidden)]
-                fn cast<'b>(&'b self) -> &'b ::java::lang::Object<'a> {
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<crate::c::d::TestClass1<'a>> for TestClass3<'a> {
+                fn borrow(&self) -> &crate::c::d::TestClass1<'a> {
                     self
                 }
             }
 
-            impl<'a> ::std::ops::Deref for TestClass3<'a> {
-                type Target = ::c::d::TestClass2<'a>;
+            impl<'a> ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> for TestClass3<'a> {
+                fn as_ref(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
+                }
+            }
 
-                fn deref(&self) -> &Self::Target {
-                    &self.object
+            impl<'a> ::rust_jni::__generator::borrow::Borrow<::rust_jni::java::lang::Object<'a>> for TestClass3<'a> {
+                fn borrow(&self) -> &::rust_jni::java::lang::Object<'a> {
+                    self
                 }
             }
 
             impl<'a> TestClass3<'a> {
+                /// Binary name of the Java class, as returned by `Class.getName()`.
+                pub const BINARY_NAME: &'static str = "a.b.TestClass3";
+                /// JNI type descriptor of the Java class.
+                pub const DESCRIPTOR: &'static str = "La/b/TestClass3;";
+                /// Internal name of the Java class, as accepted by `FindClass`.
+                pub const SLASH_NAME: &'static str = "a/b/TestClass3";
+
                 pub fn get_class(env: &'a ::rust_jni::JniEnv<'a>, token: &::rust_jni::NoException<'a>)
                     -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::Class<'a>> {
-                    ::rust_jni::java::lang::Class::find(env, "a/b/TestClass3", token)
+                    ::rust_jni::java::lang::Class::find(env, token, "a/b/TestClass3")
                 }
 
                 pub fn clone(&self, token: &::rust_jni::NoException<'a>) -> ::rust_jni::JavaResult<'a, Self>
                 where
                     Self: Sized,
                 {
-                    self.object
-                        .clone(token)
-                        .map(|object| Self { object })
+                    ::rust_jni::JavaClassExt::clone_object(self, token)
                 }
 
                 pub fn to_string(&self, token: &::rust_jni::NoException<'a>)
-                    -> ::rust_jni::JavaResult<'a, ::rust_jni::java::lang::String<'a>> {
+                    -> ::rust_jni::JavaResult<'a, ::rust_jni::__generator::option::Option<::rust_jni::java::lang::String<'a>>> {
                     self.object.to_string(token)
                 }
 
                 pub fn init(
                     env: &'a ::rust_jni::JniEnv<'a>,
                     arg1: i32,
-                    arg2: &::a::b::TestClass3<'a>,
+                    arg2: &crate::a::b::TestClass3<'a>,
                     token: &::rust_jni::NoException<'a>,
                 ) -> ::rust_jni::JavaResult<'a, Self> {
                     // Safe because the method name and arguments are correct.
                     unsafe {
-                        ::rust_jni::__generator::call_constructor::<Self, _, fn(i32, &::a::b::TestClass3<'a>,)>
-                        (
-                            env,
-                            (arg1, arg2,),
-                            token,
-                        )
+                        env.with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_constructor::<Self, _, fn(i32, &crate::a::b::TestClass3<'a>,)>
+                            (
+                                env,
+                                token,
+                                (arg1, arg2,),
+                            )
+                        })
                     }
                 }
 
@@ -890,35 +1212,40 @@ mod java_generate_tests {
                 ) -> ::rust_jni::JavaResult<'a, i64> {
                     // Safe because the method name and arguments are correct.
                     unsafe {
-                        ::rust_jni::__generator::call_method::<_, _, _,
-                            fn(i32, char,) -> i64
-                        >
-                        (
-                            self,
-                            "primitiveFunc3",
-                            (arg1, arg2,),
-                            token,
-                        )
+                        self.env().with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_method::<_, _, _,
+                                fn(i32, char,) -> i64
+                            >
+                            (
+                                self,
+                                token,
+                                concat!("primitiveFunc3", "\0"),
+                                (arg1, arg2,),
+                            )
+                        })
                     }
                 }
 
                 pub fn object_func_3(
                     &self,
-                    arg: &::a::b::TestClass3<'a>,
+                    arg: &crate::a::b::TestClass3<'a>,
                     token: &::rust_jni::NoException<'a>,
-                ) -> ::rust_jni::JavaResult<'a, ::c::d::TestClass2<'a> > {
+                ) -> ::rust_jni::JavaResult<'a, crate::c::d::TestClass2<'a> > {
                     // Safe because the method name and arguments are correct.
-                    unsafe {
-                        ::rust_jni::__generator::call_method::<_, _, _,
-                            fn(&::a::b::TestClass3<'a>,) -> ::c::d::TestClass2<'a>
-                        >
-                        (
-                            self,
-                            "objectFunc3",
-                            (arg,),
-                            token,
-                        )
-                    }
+                    (unsafe {
+                        self.env().with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_method::<_, _, _,
+                                fn(&crate::a::b::TestClass3<'a>,) -> crate::c::d::TestClass2<'a>
+                            >
+                            (
+                                self,
+                                token,
+                                concat!("objectFunc3", "\0"),
+                                (arg,),
+                            )
+                        })
+                    })
+                    .and_then(|result| ::rust_jni::NullableJavaClassExt::or_npe(result, self.env(), token))
                 }
 
                 fn primitiveInterfaceFunc3(
@@ -929,35 +1256,40 @@ mod java_generate_tests {
                 ) -> ::rust_jni::JavaResult<'a, i64> {
                     // Safe because the method name and arguments are correct.
                     unsafe {
-                        ::rust_jni::__generator::call_method::<_, _, _,
-                            fn(i32, char,) -> i64
-                        >
-                        (
-                            self,
-                            "primitiveInterfaceFunc3",
-                            (arg1, arg2,),
-                            token,
-                        )
+                        self.env().with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_method::<_, _, _,
+                                fn(i32, char,) -> i64
+                            >
+                            (
+                                self,
+                                token,
+                                concat!("primitiveInterfaceFunc3", "\0"),
+                                (arg1, arg2,),
+                            )
+                        })
                     }
                 }
 
                 fn objectInterfaceFunc3(
                     &self,
-                    arg: &::a::b::TestClass3<'a>,
+                    arg: &crate::a::b::TestClass3<'a>,
                     token: &::rust_jni::NoException<'a>,
-                ) -> ::rust_jni::JavaResult<'a, ::a::b::TestClass3<'a> > {
+                ) -> ::rust_jni::JavaResult<'a, crate::a::b::TestClass3<'a> > {
                     // Safe because the method name and arguments are correct.
-                    unsafe {
-                        ::rust_jni::__generator::call_method::<_, _, _,
-                            fn(&::a::b::TestClass3<'a>,) -> ::a::b::TestClass3<'a>
-                        >
-                        (
-                            self,
-                            "objectInterfaceFunc3",
-                            (arg,),
-                            token,
-                        )
-                    }
+                    (unsafe {
+                        self.env().with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_method::<_, _, _,
+                                fn(&crate::a::b::TestClass3<'a>,) -> crate::a::b::TestClass3<'a>
+                            >
+                            (
+                                self,
+                                token,
+                                concat!("objectInterfaceFunc3", "\0"),
+                                (arg,),
+                            )
+                        })
+                    })
+                    .and_then(|result| ::rust_jni::NullableJavaClassExt::or_npe(result, self.env(), token))
                 }
 
                 fn primitive_static_func_3(
@@ -968,35 +1300,40 @@ mod java_generate_tests {
                 ) -> ::rust_jni::JavaResult<'a, i64> {
                     // Safe because the method name and arguments are correct.
                     unsafe {
-                        ::rust_jni::__generator::call_static_method::<Self, _, _,
-                            fn(i32, char,) -> i64
-                        >
-                        (
-                            env,
-                            "primitiveStaticFunc3",
-                            (arg1, arg2,),
-                            token,
-                        )
+                        env.with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_static_method::<Self, _, _,
+                                fn(i32, char,) -> i64
+                            >
+                            (
+                                env,
+                                token,
+                                concat!("primitiveStaticFunc3", "\0"),
+                                (arg1, arg2,),
+                            )
+                        })
                     }
                 }
 
                 pub fn object_static_func_3(
                     env: &'a ::rust_jni::JniEnv<'a>,
-                    arg: &::a::b::TestClass3<'a>,
+                    arg: &crate::a::b::TestClass3<'a>,
                     token: &::rust_jni::NoException<'a>,
-                ) -> ::rust_jni::JavaResult<'a, ::c::d::TestClass2<'a> > {
+                ) -> ::rust_jni::JavaResult<'a, crate::c::d::TestClass2<'a> > {
                     // Safe because the method name and arguments are correct.
-                    unsafe {
-                        ::rust_jni::__generator::call_static_method::<Self, _, _,
-                            fn(&::a::b::TestClass3<'a>,) -> ::c::d::TestClass2<'a>
-                        >
-                        (
-                            env,
-                            "objectStaticFunc3",
-                            (arg,),
-                            token,
-                        )
-                    }
+                    (unsafe {
+                        env.with_local_frame(token, 4, |token| {
+                            ::rust_jni::call_static_method::<Self, _, _,
+                                fn(&crate::a::b::TestClass3<'a>,) -> crate::c::d::TestClass2<'a>
+                            >
+                            (
+                                env,
+                                token,
+                                concat!("objectStaticFunc3", "\0"),
+                                (arg,),
+                            )
+                        })
+                    })
+                    .and_then(|result| ::rust_jni::NullableJavaClassExt::or_npe(result, env, token))
                 }
 
                 pub fn primitive_native_func_3(
@@ -1011,9 +1348,9 @@ mod java_generate_tests {
 
                 fn objectNativeFunc3(
                     &self,
-                    arg: ::a::b::TestClass3<'a>,
+                    arg: crate::a::b::TestClass3<'a>,
                     token: &::rust_jni::NoException<'a>,
-                ) -> ::rust_jni::JavaResult<'a, ::a::b::TestClass3<'a> > {
+                ) -> ::rust_jni::JavaResult<'a, crate::a::b::TestClass3<'a> > {
                     println!("{:?} {:?} {:?}", arg, token, self);
                     Ok(arg)
                 }
@@ -1030,9 +1367,9 @@ mod java_generate_tests {
 
                 pub fn objectStaticNativeFunc3(
                     env: &'a ::rust_jni::JniEnv<'a>,
-                    arg: ::a::b::TestClass3<'a>,
+                    arg: crate::a::b::TestClass3<'a>,
                     token: &::rust_jni::NoException<'a>,
-                ) -> ::rust_jni::JavaResult<'a, ::a::b::TestClass3<'a> > {
+                ) -> ::rust_jni::JavaResult<'a, crate::a::b::TestClass3<'a> > {
                     println!("{:?} {:?} {:?}", arg, token, env);
                     Ok(arg)
                 }
@@ -1043,40 +1380,18 @@ mod java_generate_tests {
             pub unsafe extern "C" fn Java_a_b_TestClass3_primitiveNativeFunc3__IC<'a>(
                 raw_env: *mut ::jni_sys::JNIEnv,
                 object: ::jni_sys::jobject,
-                arg1: <i32 as ::rust_jni::JavaType>::__JniType,
-                arg2: <char as ::rust_jni::JavaType>::__JniType,
-            ) -> <i64 as ::rust_jni::JavaType>::__JniType {
-                ::rust_jni::__generator::test_jni_argument_type(arg1);
-                ::rust_jni::__generator::test_jni_argument_type(arg2);
+                arg1: <i32 as ::rust_jni::__generator::NativeMethodArgument<'a>>::JniType,
+                arg2: <char as ::rust_jni::__generator::NativeMethodArgument<'a>>::JniType,
+            ) -> <i64 as ::rust_jni::__generator::ToJavaNativeResult>::JniType {
                 ::rust_jni::__generator::native_method_wrapper(raw_env, |env, token| {
-                    {
-                        let value =
-                            <i32 as ::rust_jni::__generator::FromJni>
-                                ::__from_jni(env, arg1);
-                        ::rust_jni::__generator::test_from_jni_type(&value);
-                        ::std::mem::forget(value);
-                    }
-                    {
-                        let value =
-                            <char as ::rust_jni::__generator::FromJni>
-                                ::__from_jni(env, arg2);
-                        ::rust_jni::__generator::test_from_jni_type(&value);
-                        ::std::mem::forget(value);
-                    }
-
-                    let object = <TestClass3 as ::rust_jni::__generator::FromJni>::__from_jni(env, object);
+                    let object = <TestClass3 as ::rust_jni::__generator::NativeMethodArgument>::from_jni(env, token, object)?;
+                    let arg1 =
+                        ::rust_jni::__generator::NativeMethodArgument::from_jni(env, token, arg1)?;
+                    let arg2 =
+                        ::rust_jni::__generator::NativeMethodArgument::from_jni(env, token, arg2)?;
                     object
-                        .primitive_native_func_3(
-                            ::rust_jni::__generator::FromJni::__from_jni(env, arg1),
-                            ::rust_jni::__generator::FromJni::__from_jni(env, arg2),
-                            &token,
-                        )
-                        .map(|value| {
-                            let result = ::rust_jni::__generator::ToJni::__to_jni(&value);
-                            // We don't want to delete the reference to result for object results.
-                            ::std::mem::forget(value);
-                            result
-                        })
+                        .primitive_native_func_3(arg1, arg2, token)
+                        .map(::rust_jni::__generator::to_native_result)
                 })
             }
 
@@ -1085,30 +1400,15 @@ mod java_generate_tests {
             pub unsafe extern "C" fn Java_a_b_TestClass3_objectNativeFunc3__La_b_TestClass3_2<'a>(
                 raw_env: *mut ::jni_sys::JNIEnv,
                 object: ::jni_sys::jobject,
-                arg: <::a::b::TestClass3 as ::rust_jni::JavaType>::__JniType,
-            ) -> <::a::b::TestClass3<'a> as ::rust_jni::JavaType>::__JniType {
-                ::rust_jni::__generator::test_jni_argument_type(arg);
+                arg: <crate::a::b::TestClass3<'a> as ::rust_jni::__generator::NativeMethodArgument<'a>>::JniType,
+            ) -> <crate::a::b::TestClass3<'a> as ::rust_jni::__generator::ToJavaNativeResult>::JniType {
                 ::rust_jni::__generator::native_method_wrapper(raw_env, |env, token| {
-                    {
-                        let value =
-                            <::a::b::TestClass3 as ::rust_jni::__generator::FromJni>
-                                ::__from_jni(env, arg);
-                        ::rust_jni::__generator::test_from_jni_type(&value);
-                        ::std::mem::forget(value);
-                    }
-
-                    let object = <TestClass3 as ::rust_jni::__generator::FromJni>::__from_jni(env, object);
+                    let object = <TestClass3 as ::rust_jni::__generator::NativeMethodArgument>::from_jni(env, token, object)?;
+                    let arg =
+                        ::rust_jni::__generator::NativeMethodArgument::from_jni(env, token, arg)?;
                     object
-                        .objectNativeFunc3(
-                            ::rust_jni::__generator::FromJni::__from_jni(env, arg),
-                            &token,
-                        )
-                        .map(|value| {
-                            let result = ::rust_jni::__generator::ToJni::__to_jni(&value);
-                            // We don't want to delete the reference to result for object results.
-                            ::std::mem::forget(value);
-                            result
-                        })
+                        .objectNativeFunc3(arg, token)
+                        .map(::rust_jni::__generator::to_native_result)
                 })
             }
 
@@ -1117,44 +1417,22 @@ mod java_generate_tests {
             pub unsafe extern "C" fn Java_a_b_TestClass3_primitiveStaticNativeFunc3__IC<'a>(
                 raw_env: *mut ::jni_sys::JNIEnv,
                 raw_class: ::jni_sys::jclass,
-                arg1: <i32 as ::rust_jni::JavaType>::__JniType,
-                arg2: <char as ::rust_jni::JavaType>::__JniType,
-            ) -> <i64 as ::rust_jni::JavaType>::__JniType {
-                ::rust_jni::__generator::test_jni_argument_type(arg1);
-                ::rust_jni::__generator::test_jni_argument_type(arg2);
+                arg1: <i32 as ::rust_jni::__generator::NativeMethodArgument<'a>>::JniType,
+                arg2: <char as ::rust_jni::__generator::NativeMethodArgument<'a>>::JniType,
+            ) -> <i64 as ::rust_jni::__generator::ToJavaNativeResult>::JniType {
                 ::rust_jni::__generator::native_method_wrapper(raw_env, |env, token| {
-                    {
-                        let value =
-                            <i32 as ::rust_jni::__generator::FromJni>
-                                ::__from_jni(env, arg1);
-                        ::rust_jni::__generator::test_from_jni_type(&value);
-                        ::std::mem::forget(value);
-                    }
-                    {
-                        let value =
-                            <char as ::rust_jni::__generator::FromJni>
-                                ::__from_jni(env, arg2);
-                        ::rust_jni::__generator::test_from_jni_type(&value);
-                        ::std::mem::forget(value);
-                    }
-
-                    let class = TestClass3::get_class(env, &token)?;
-                    let raw_class = <::rust_jni::java::lang::Class as ::rust_jni::__generator::FromJni>::__from_jni(env, raw_class);
-                    if !class.is_same_as(&raw_class, &token) {
+                    let class = TestClass3::get_class(env, token)?;
+                    let raw_class = <::rust_jni::java::lang::Class as ::rust_jni::__generator::NativeMethodArgument>::from_jni(env, token, raw_class)?;
+                    if !class.is_same_as(token, &raw_class) {
                         panic!("Native method primitiveStaticNativeFunc3 does not belong to class TestClass3");
                     }
 
-                    TestClass3::primitive_static_native_func_3(
-                        env,
-                        ::rust_jni::__generator::FromJni::__from_jni(env, arg1),
-                        ::rust_jni::__generator::FromJni::__from_jni(env, arg2),
-                        &token,
-                    )
-                    .map(|value| {
-                        let result = ::rust_jni::__generator::ToJni::__to_jni(&value);
-                        ::std::mem::forget(value);
-                        result
-                    })
+                    let arg1 =
+                        ::rust_jni::__generator::NativeMethodArgument::from_jni(env, token, arg1)?;
+                    let arg2 =
+                        ::rust_jni::__generator::NativeMethodArgument::from_jni(env, token, arg2)?;
+                    TestClass3::primitive_static_native_func_3(env, arg1, arg2, token)
+                        .map(::rust_jni::__generator::to_native_result)
                 })
             }
 
@@ -1163,72 +1441,50 @@ mod java_generate_tests {
             pub unsafe extern "C" fn Java_a_b_TestClass3_objectStaticNativeFunc3__La_b_TestClass3_2<'a>(
                 raw_env: *mut ::jni_sys::JNIEnv,
                 raw_class: ::jni_sys::jclass,
-                arg: <::a::b::TestClass3 as ::rust_jni::JavaType>::__JniType,
-            ) -> <::a::b::TestClass3<'a> as ::rust_jni::JavaType>::__JniType {
-                ::rust_jni::__generator::test_jni_argument_type(arg);
+                arg: <crate::a::b::TestClass3<'a> as ::rust_jni::__generator::NativeMethodArgument<'a>>::JniType,
+            ) -> <crate::a::b::TestClass3<'a> as ::rust_jni::__generator::ToJavaNativeResult>::JniType {
                 ::rust_jni::__generator::native_method_wrapper(raw_env, |env, token| {
-                    {
-                        let value =
-                            <::a::b::TestClass3 as ::rust_jni::__generator::FromJni>
-                                ::__from_jni(env, arg);
-                        ::rust_jni::__generator::test_from_jni_type(&value);
-                        ::std::mem::forget(value);
-                    }
-
-                    let class = TestClass3::get_class(env, &token)?;
-                    let raw_class = <::rust_jni::java::lang::Class as ::rust_jni::__generator::FromJni>::__from_jni(env, raw_class);
-                    if !class.is_same_as(&raw_class, &token) {
+                    let class = TestClass3::get_class(env, token)?;
+                    let raw_class = <::rust_jni::java::lang::Class as ::rust_jni::__generator::NativeMethodArgument>::from_jni(env, token, raw_class)?;
+                    if !class.is_same_as(token, &raw_class) {
                         panic!("Native method objectStaticNativeFunc3 does not belong to class TestClass3");
                     }
 
-                    TestClass3::objectStaticNativeFunc3(
-                        env,
-                        ::rust_jni::__generator::FromJni::__from_jni(env, arg),
-                        &token,
-                    )
-                    .map(|value| {
-                        let result = ::rust_jni::__generator::ToJni::__to_jni(&value);
-                        ::std::mem::forget(value);
-                        result
-                    })
+                    let arg =
+                        ::rust_jni::__generator::NativeMethodArgument::from_jni(env, token, arg)?;
+                    TestClass3::objectStaticNativeFunc3(env, arg, token)
+                        .map(::rust_jni::__generator::to_native_result)
                 })
             }
 
-            impl<'a> ::std::fmt::Display for TestClass3<'a> {
-                fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    self.object.fmt(formatter)
+            impl<'a> ::rust_jni::__generator::fmt::Display for TestClass3<'a> {
+                fn fmt(&self, formatter: &mut ::rust_jni::__generator::fmt::Formatter) -> ::rust_jni::__generator::fmt::Result {
+                    ::rust_jni::__generator::fmt::Display::fmt(&self.object, formatter)
                 }
             }
 
-            impl<'a, T> PartialEq<T> for TestClass3<'a> where T: ::rust_jni::Cast<'a, ::rust_jni::java::lang::Object<'a>> {
+            impl<'a, T> PartialEq<T> for TestClass3<'a> where T: ::rust_jni::__generator::convert::AsRef<::rust_jni::java::lang::Object<'a>> {
                 fn eq(&self, other: &T) -> bool {
-                    self.object.eq(other)
+                    ::rust_jni::__generator::convert::AsRef::<::rust_jni::java::lang::Object<'a>>::as_ref(self).eq(other.as_ref())
                 }
             }
 
             impl<'a> Eq for TestClass3<'a> {}
 
 
-            impl<'a> ::a::b::TestInterface3<'a> for TestClass3<'a> {
-                fn primitiveInterfaceFunc3(
+            impl<'a> crate::e::f::TestInterface1<'a> for TestClass3<'a> {
+                fn primitive_interface_func_1(
                     &self,
                     arg1: i32,
                     arg2: char,
                     token: &::rust_jni::NoException<'a>,
                 ) -> ::rust_jni::JavaResult<'a, i64> {
-                    Self::primitiveInterfaceFunc3(self, arg1, arg2, token)
-                }
-
-                fn objectInterfaceFunc3(
-                    &self,
-                    arg: &::a::b::TestClass3<'a>,
-                    token: &::rust_jni::NoException<'a>,
-                ) -> ::rust_jni::JavaResult<'a, ::a::b::TestClass3<'a> > {
-                    Self::objectInterfaceFunc3(self, arg, token)
+                    < crate::c::d::TestClass2 as crate::e::f::TestInterface1 >
+                        ::primitive_interface_func_1(self, arg1, arg2, token)
                 }
             }
 
-            impl<'a> ::a::b::TestInterface4<'a> for TestClass3<'a> {
+            impl<'a> crate::a::b::TestInterface4<'a> for TestClass3<'a> {
                 fn primitive_func_3(
                     &self,
                     arg1: i32,
@@ -1240,30 +1496,90 @@ mod java_generate_tests {
 
                 fn object_func_3(
                     &self,
-                    arg: &::a::b::TestClass3<'a>,
+                    arg: &crate::a::b::TestClass3<'a>,
                     token: &::rust_jni::NoException<'a>,
-                ) -> ::rust_jni::JavaResult<'a, ::c::d::TestClass2<'a> > {
+                ) -> ::rust_jni::JavaResult<'a, crate::c::d::TestClass2<'a> > {
                     Self::object_func_3(self, arg, token)
                 }
             }
 
-            impl<'a> ::c::d::TestInterface2<'a> for TestClass3<'a> {
+            impl<'a> crate::c::d::TestInterface2<'a> for TestClass3<'a> {
             }
 
-            impl<'a> ::e::f::TestInterface1<'a> for TestClass3<'a> {
-                fn primitive_interface_func_1(
+            impl<'a> crate::a::b::TestInterface3<'a> for TestClass3<'a> {
+                fn primitiveInterfaceFunc3(
                     &self,
                     arg1: i32,
                     arg2: char,
                     token: &::rust_jni::NoException<'a>,
                 ) -> ::rust_jni::JavaResult<'a, i64> {
-                    < ::c::d::TestClass2 as ::e::f::TestInterface1 >
-                        ::primitive_interface_func_1(self, arg1, arg2, token)
+                    Self::primitiveInterfaceFunc3(self, arg1, arg2, token)
+                }
+
+                fn objectInterfaceFunc3(
+                    &self,
+                    arg: &crate::a::b::TestClass3<'a>,
+                    token: &::rust_jni::NoException<'a>,
+                ) -> ::rust_jni::JavaResult<'a, crate::a::b::TestClass3<'a> > {
+                    Self::objectInterfaceFunc3(self, arg, token)
                 }
             }
         };
+        expected.extend(binding_info(
+            &[(
+                "TestClass3",
+                "La/b/TestClass3;",
+                "c/d/TestClass2",
+                &["e/f/TestInterface1", "a/b/TestInterface4"],
+            )],
+            &["TestInterface3", "TestInterface4"],
+        ));
         assert_tokens_equals(java_generate_impl(input), expected);
     }
+
+    fn assert_no_std(tokens: TokenStream) {
+        for token in tokens {
+            match token {
+                proc_macro2::TokenTree::Group(group) => assert_no_std(group.stream()),
+                proc_macro2::TokenTree::Ident(ident) => {
+                    assert_ne!(ident, "std", "Generated code refers to `std`")
+                }
+                _ => {}
+            }
+        }
+    }
+
+    #[test]
+    fn no_std() {
+        // Generated code must compile in `no_std` crates too.
+        let input = quote! {
+            options { c_abi(a.b.TestClass2) }
+
+            public interface a.b.TestInterface1 {
+                long primitiveInterfaceFunc1(int arg1, char arg2);
+            }
+
+            public class a.b.TestClass2 implements a.b.TestInterface1 {
+                public a.b.TestClass2(int arg);
+
+                public long primitiveInterfaceFunc1(int arg1, char arg2);
+                @nullable
+                public a.b.TestClass2 nullableFunc2(@nullable a.b.TestClass2 arg);
+                public static a.b.TestClass2 objectStaticFunc2(a.b.TestClass2 arg);
+
+                public native long primitiveNativeFunc2(int arg1, char arg2) {
+                    Ok(0)
+                };
+                native a.b.TestClass2 objectNativeFunc2(a.b.TestClass2 arg) {
+                    Ok(arg.clone())
+                };
+                public static native a.b.TestClass2 objectStaticNativeFunc2(a.b.TestClass2 arg) {
+                    Ok(arg.clone())
+                };
+            }
+        };
+        assert_no_std(java_generate_impl(input));
+    }
 }
 
 #[cfg(test)]
@@ -20,6 +20,7 @@ impl Eq for Annotation {}
 pub struct MethodArgument {
     pub name: Ident,
     pub data_type: JavaName,
+    pub annotations: Vec<Annotation>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -37,6 +38,8 @@ pub struct JavaClassMethod {
     pub arguments: Vec<MethodArgument>,
     pub public: bool,
     pub is_static: bool,
+    pub is_synchronized: bool,
+    pub is_abstract: bool,
     pub annotations: Vec<Annotation>,
 }
 
@@ -47,7 +50,8 @@ pub struct JavaNativeMethod {
     pub arguments: Vec<MethodArgument>,
     pub public: bool,
     pub is_static: bool,
-    pub code: TokenStream,
+    /// The Rust body, `None` for methods declared without one.
+    pub code: Option<TokenStream>,
     pub annotations: Vec<Annotation>,
 }
 
@@ -68,6 +72,7 @@ pub struct JavaConstructor {
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct JavaClass {
+    pub is_abstract: bool,
     pub extends: Option<JavaName>,
     pub implements: Vec<JavaName>,
     pub methods: Vec<JavaClassMethod>,
@@ -75,12 +80,26 @@ pub struct JavaClass {
     pub constructors: Vec<JavaConstructor>,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, Clone)]
 pub struct JavaInterface {
     pub methods: Vec<JavaInterfaceMethod>,
+    pub static_methods: Vec<JavaClassMethod>,
     pub extends: Vec<JavaName>,
+    /// Generic type arguments of extended interfaces, like `Foo` in `extends Comparable<Foo>`.
+    ///
+    /// Generated traits use the erased interfaces from `extends`, the arguments are only
+    /// recorded for typed support in the future.
+    pub extends_generic_arguments: Vec<(JavaName, TokenStream)>,
+}
+
+impl PartialEq for JavaInterface {
+    fn eq(&self, other: &Self) -> bool {
+        format!("{:?}", self) == format!("{:?}", other)
+    }
 }
 
+impl Eq for JavaInterface {}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum JavaDefinitionKind {
     Class(JavaClass),
@@ -123,55 +142,137 @@ pub struct Metadata {
     pub definitions: Vec<JavaDefinitionMetadata>,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Options {
+    pub strict_extends: bool,
+    pub java_source: bool,
+    /// The native library to load in generated Java sources, see `java_stubs`.
+    pub java_stubs: Option<String>,
+    /// The feature that enables generated `_unchecked` method variants, see `unchecked_methods`.
+    pub unchecked_methods: Option<String>,
+    /// Classes to generate C ABI shims for, see `c_abi`.
+    pub c_abi: Vec<JavaName>,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct JavaDefinitions {
+    pub options: Options,
     pub definitions: Vec<JavaDefinition>,
     pub metadata: Metadata,
 }
 
-fn parse_annotation(tokens: &[TokenTree]) -> Annotation {
-    let name = match tokens[0] {
-        TokenTree::Ident(ref identifier) => identifier.clone(),
-        _ => unreachable!(),
-    };
-    let value = match tokens[1] {
-        TokenTree::Group(ref group) => group.stream(),
-        _ => unreachable!(),
-    };
-    Annotation {
-        name,
-        value: TokenStream::from_iter(value.into_iter()),
+/// Parse leading annotations, returning them and the rest of the tokens.
+///
+/// Annotations are either `@Name(value)` or `@Name` without a value.
+fn parse_annotations(tokens: &[TokenTree]) -> (Vec<Annotation>, &[TokenTree]) {
+    let mut annotations = vec![];
+    let mut tokens = tokens;
+    while tokens.len() > 1 && is_punctuation(&tokens[0], '@') {
+        let name = match tokens[1] {
+            TokenTree::Ident(ref identifier) => identifier.clone(),
+            ref token => panic!("Expected annotation name, got {:?}.", token),
+        };
+        match tokens.get(2) {
+            Some(TokenTree::Group(ref group)) if group.delimiter() == Delimiter::Parenthesis => {
+                annotations.push(Annotation {
+                    name,
+                    value: group.stream(),
+                });
+                tokens = &tokens[3..];
+            }
+            _ => {
+                annotations.push(Annotation {
+                    name,
+                    value: TokenStream::new(),
+                });
+                tokens = &tokens[2..];
+            }
+        }
     }
+    (annotations, tokens)
 }
 
-fn parse_annotations(tokens: &[TokenTree]) -> Vec<Annotation> {
-    if tokens.len() == 0 {
-        vec![]
-    } else {
-        match tokens[0] {
-            TokenTree::Punct(ref punct) => {
-                if punct.spacing() == Spacing::Alone && punct.as_char() == '@' {
-                    tokens
-                        .split(|token| is_punctuation(token, '@'))
-                        .filter(|slice| !slice.is_empty())
-                        .map(parse_annotation)
-                        .collect()
-                } else {
-                    vec![]
-                }
+/// Parse a comma separated list of names, like the interfaces in an `extends` clause.
+///
+/// Names can have generic type arguments, like `Comparable<Foo>`. The names are returned
+/// erased, together with the arguments, which are empty for non-generic names.
+fn comma_separated_names(tokens: impl Iterator<Item = TokenTree>) -> Vec<(JavaName, TokenStream)> {
+    let tokens = tokens.collect::<Vec<_>>();
+    split_by_commas(&tokens)
+        .into_iter()
+        .map(|tokens| {
+            let (name, arguments) = split_generic_arguments(tokens);
+            (JavaName::from_tokens(name.iter()), arguments)
+        })
+        .collect()
+}
+
+/// Parse a type of a method argument or result, erasing generic type arguments, so that
+/// `java.lang.Class<?>` becomes `java.lang.Class`.
+fn parse_type(tokens: &[TokenTree]) -> JavaName {
+    JavaName::from_tokens(split_generic_arguments(tokens).0.iter())
+}
+
+/// Split tokens by commas that are not inside of generic type arguments.
+fn split_by_commas(tokens: &[TokenTree]) -> Vec<&[TokenTree]> {
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut depth = 0;
+    for (index, token) in tokens.iter().enumerate() {
+        if is_angle_bracket(token, '<') {
+            depth += 1;
+        } else if is_angle_bracket(token, '>') {
+            depth -= 1;
+        } else if depth == 0 && is_punctuation(token, ',') {
+            parts.push(&tokens[start..index]);
+            start = index + 1;
+        }
+    }
+    parts.push(&tokens[start..]);
+    parts.into_iter().filter(|part| !part.is_empty()).collect()
+}
+
+/// Split a type into the erased type and it's generic type arguments, like `Comparable<Foo>`
+/// into `Comparable` and `Foo`. The arguments are empty for non-generic types.
+fn split_generic_arguments(tokens: &[TokenTree]) -> (Vec<TokenTree>, TokenStream) {
+    let mut erased = vec![];
+    let mut arguments = vec![];
+    let mut depth = 0;
+    for token in tokens {
+        if is_angle_bracket(token, '<') {
+            depth += 1;
+            if depth == 1 {
+                continue;
+            }
+        } else if is_angle_bracket(token, '>') {
+            if depth == 0 {
+                panic!("Unexpected \">\" in {:?}.", erased);
             }
-            _ => vec![],
+            depth -= 1;
+            if depth == 0 {
+                continue;
+            }
+        }
+        if depth == 0 {
+            erased.push(token.clone());
+        } else {
+            arguments.push(token.clone());
         }
     }
+    if depth != 0 {
+        panic!("Unclosed generic type arguments in {:?}.", erased);
+    }
+    (erased, TokenStream::from_iter(arguments))
 }
 
-fn comma_separated_names(tokens: impl Iterator<Item = TokenTree>) -> Vec<JavaName> {
-    let tokens = tokens.collect::<Vec<_>>();
-    tokens
-        .split(|token| is_punctuation(token, ','))
-        .filter(|slice| !slice.is_empty())
-        .map(|slice| JavaName::from_tokens(slice.iter()))
-        .collect()
+/// Check for an angle bracket of generic type arguments.
+///
+/// `>>` is tokenized as two joint punctuation characters, so spacing is ignored.
+fn is_angle_bracket(token: &TokenTree, value: char) -> bool {
+    match token {
+        TokenTree::Punct(punct) => punct.as_char() == value,
+        _ => false,
+    }
 }
 
 fn is_punctuation(token: &TokenTree, value: char) -> bool {
@@ -181,7 +282,9 @@ fn is_punctuation(token: &TokenTree, value: char) -> bool {
     }
 }
 
-fn parse_interface_header(header: &[TokenTree]) -> (JavaName, Vec<JavaName>) {
+fn parse_interface_header(
+    header: &[TokenTree],
+) -> (JavaName, Vec<JavaName>, Vec<(JavaName, TokenStream)>) {
     let name = JavaName::from_tokens(
         header
             .iter()
@@ -194,20 +297,30 @@ fn parse_interface_header(header: &[TokenTree]) -> (JavaName, Vec<JavaName>) {
             .skip(1)
             .cloned(),
     );
-    (name, extends)
+    let generic_arguments = extends
+        .iter()
+        .filter(|(_, arguments)| !arguments.is_empty())
+        .cloned()
+        .collect();
+    let extends = extends.into_iter().map(|(name, _)| name).collect();
+    (name, extends, generic_arguments)
 }
 
 fn parse_class_header(header: &[TokenTree]) -> (JavaName, Option<JavaName>, Vec<JavaName>) {
     let name = JavaName::from_tokens(header.iter().take_while(|token| {
         !is_identifier(&token, "extends") && !is_identifier(&token, "implements")
     }));
+    // Generic type arguments of implemented interfaces are erased.
     let implements = comma_separated_names(
         header
             .iter()
             .skip_while(|token| !is_identifier(&token, "implements"))
             .skip(1)
             .cloned(),
-    );
+    )
+    .into_iter()
+    .map(|(name, _)| name)
+    .collect();
     let has_extends = header
         .iter()
         .filter(|token| is_identifier(&token, "extends"))
@@ -228,7 +341,8 @@ fn parse_class_header(header: &[TokenTree]) -> (JavaName, Option<JavaName>, Vec<
 }
 
 fn parse_metadata(tokens: TokenStream) -> Metadata {
-    let definitions = tokens.clone().into_iter().collect::<Vec<_>>();
+    let definitions =
+        strip_empty_statements(strip_comments(&tokens.into_iter().collect::<Vec<_>>()));
     let definitions = definitions
         .split(is_metadata_definition)
         .filter(|tokens| !tokens.is_empty())
@@ -241,7 +355,7 @@ fn parse_metadata(tokens: TokenStream) -> Metadata {
             }
 
             if is_interface {
-                let (name, extends) = parse_interface_header(header);
+                let (name, extends, _) = parse_interface_header(header);
                 JavaDefinitionMetadata {
                     name,
                     definition: JavaDefinitionMetadataKind::Interface(JavaInterfaceMetadata {
@@ -269,10 +383,12 @@ fn parse_metadata(tokens: TokenStream) -> Metadata {
         .map(|(definition, tokens)| {
             let java_definition = match definition.definition.clone() {
                 JavaDefinitionMetadataKind::Interface(interface) => {
-                    let methods = tokens.into_iter().collect::<Vec<_>>();
+                    let methods = strip_comments(&tokens.into_iter().collect::<Vec<_>>());
                     let methods = methods
                         .split(|token| is_punctuation(token, ';'))
                         .filter(|tokens| !tokens.is_empty())
+                        // Static methods are not inherited, so they are not needed in metadata.
+                        .filter(|tokens| !is_static_method(tokens))
                         .map(parse_interface_method)
                         .collect::<Vec<_>>();
                     JavaDefinitionMetadataKind::Interface(JavaInterfaceMetadata {
@@ -291,6 +407,80 @@ fn parse_metadata(tokens: TokenStream) -> Metadata {
     Metadata { definitions }
 }
 
+fn parse_options(tokens: TokenStream) -> Options {
+    let mut options = Options::default();
+    let mut tokens = strip_comments(&tokens.into_iter().collect::<Vec<_>>()).into_iter();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(ref identifier) if identifier.to_string() == "strict_extends" => {
+                options.strict_extends = true
+            }
+            TokenTree::Ident(ref identifier) if identifier.to_string() == "java_source" => {
+                options.java_source = true
+            }
+            TokenTree::Ident(ref identifier) if identifier.to_string() == "java_stubs" => {
+                options.java_stubs =
+                    Some(parse_string_option("java_stubs", "library", tokens.next()))
+            }
+            TokenTree::Ident(ref identifier) if identifier.to_string() == "unchecked_methods" => {
+                options.unchecked_methods = Some(parse_string_option(
+                    "unchecked_methods",
+                    "feature",
+                    tokens.next(),
+                ))
+            }
+            TokenTree::Ident(ref identifier) if identifier.to_string() == "c_abi" => {
+                options.c_abi = parse_class_names_option("c_abi", tokens.next())
+            }
+            TokenTree::Punct(ref punctuation) if punctuation.as_char() == ',' => {}
+            token => panic!("Unknown option {}.", token),
+        }
+    }
+    options
+}
+
+/// Parse the string argument of an option, like the library name of `java_stubs("library")`.
+fn parse_string_option(option: &str, kind: &str, token: Option<TokenTree>) -> String {
+    let value = match token {
+        Some(TokenTree::Group(ref group)) if group.delimiter() == Delimiter::Parenthesis => {
+            match group.stream().into_iter().collect::<Vec<_>>().as_slice() {
+                [TokenTree::Literal(literal)] => literal.to_string(),
+                _ => String::new(),
+            }
+        }
+        _ => String::new(),
+    };
+    if value.len() < 3 || !value.starts_with('"') || !value.ends_with('"') {
+        panic!(
+            "Expected a {} name in parenthesis after {}, like {}(\"name\").",
+            kind, option, option
+        );
+    }
+    value[1..value.len() - 1].to_owned()
+}
+
+/// Parse the class names argument of an option, like `c_abi(a.b.Foo, a.b.Bar)`.
+fn parse_class_names_option(option: &str, token: Option<TokenTree>) -> Vec<JavaName> {
+    let names = match token {
+        Some(TokenTree::Group(ref group)) if group.delimiter() == Delimiter::Parenthesis => group
+            .stream()
+            .into_iter()
+            .collect::<Vec<_>>()
+            .split(|token| is_punctuation(token, ','))
+            .filter(|tokens| !tokens.is_empty())
+            .map(|tokens| JavaName::from_tokens(tokens.iter()))
+            .collect::<Vec<_>>(),
+        _ => vec![],
+    };
+    if names.is_empty() {
+        panic!(
+            "Expected class names in parenthesis after {}, like {}(a.b.Foo).",
+            option, option
+        );
+    }
+    names
+}
+
 fn is_constructor(tokens: &[TokenTree], class_name: &JavaName) -> bool {
     let class_name_len = class_name
         .clone()
@@ -298,12 +488,27 @@ fn is_constructor(tokens: &[TokenTree], class_name: &JavaName) -> bool {
         .into_iter()
         .collect::<Vec<_>>()
         .len();
-    if tokens.len() <= class_name_len {
+    if tokens.len() <= 1 {
         return false;
     }
-    let tokens = &tokens[tokens.len() - class_name_len - 1..tokens.len() - 1];
-    TokenStream::from_iter(tokens.iter().cloned()).to_string()
-        == class_name.clone().with_dots().to_string()
+    if tokens.len() > class_name_len {
+        let name_tokens = &tokens[tokens.len() - class_name_len - 1..tokens.len() - 1];
+        if TokenStream::from_iter(name_tokens.iter().cloned()).to_string()
+            == class_name.clone().with_dots().to_string()
+        {
+            return true;
+        }
+    }
+    // Constructors can also be named by the simple class name, like in Java.
+    let tokens = tokens[..tokens.len() - 1]
+        .iter()
+        .filter(|token| !is_identifier(token, "public"))
+        .cloned()
+        .collect::<Vec<_>>();
+    match parse_annotations(&tokens).1 {
+        [name] => name.to_string() == class_name.0.clone().into_iter().last().unwrap().to_string(),
+        _ => false,
+    }
 }
 
 fn parse_method_arguments(token: TokenTree) -> Vec<MethodArgument> {
@@ -312,19 +517,20 @@ fn parse_method_arguments(token: TokenTree) -> Vec<MethodArgument> {
             if group.delimiter() != Delimiter::Parenthesis {
                 panic!("Expected method arguments in parenthesis, got {:?}.", group);
             }
-            let arguments = group.stream().into_iter().collect::<Vec<_>>();
-            arguments
-                .split(|token| is_punctuation(token, ','))
-                .filter(|tokens| !tokens.is_empty())
+            let arguments = strip_comments(&group.stream().into_iter().collect::<Vec<_>>());
+            split_by_commas(&arguments)
+                .into_iter()
                 .map(|tokens| tokens.split_last().unwrap())
                 .map(|(last, others)| {
                     let name = match last {
                         TokenTree::Ident(ident) => ident.clone(),
                         token => panic!("Expected argument name, got {:?}.", token),
                     };
+                    let (annotations, data_type) = parse_annotations(others);
                     MethodArgument {
                         name,
-                        data_type: JavaName::from_tokens(others.iter()),
+                        data_type: parse_type(data_type),
+                        annotations,
                     }
                 })
                 .collect::<Vec<_>>()
@@ -333,24 +539,45 @@ fn parse_method_arguments(token: TokenTree) -> Vec<MethodArgument> {
     }
 }
 
+/// Parse a method name.
+///
+/// Names that are not Rust identifiers can be written as string literals, like `"get$Value"`.
+/// Such names are mapped to Rust identifiers and the Java name is kept in a `JavaName` annotation.
+fn parse_method_name(token: TokenTree, annotations: &mut Vec<Annotation>) -> Ident {
+    match token {
+        TokenTree::Ident(ident) => ident,
+        TokenTree::Literal(literal) => {
+            let name = java_identifier(&literal);
+            annotations.push(Annotation {
+                name: Ident::new("JavaName", Span::call_site()),
+                value: quote! {#literal},
+            });
+            Ident::new(&to_rust_identifier(&name), literal.span())
+        }
+        token => panic!("Expected method name, got {:?}.", token),
+    }
+}
+
 fn parse_method(tokens: &[TokenTree]) -> JavaClassMethod {
     let public = tokens.iter().any(|token| is_identifier(token, "public"));
     let is_static = tokens.iter().any(|token| is_identifier(token, "static"));
+    let is_synchronized = tokens
+        .iter()
+        .any(|token| is_identifier(token, "synchronized"));
+    let is_abstract = tokens.iter().any(|token| is_identifier(token, "abstract"));
     let tokens = tokens
         .iter()
-        .filter(|token| !is_identifier(token, "public") && !is_identifier(token, "static"))
+        .filter(|token| {
+            !is_identifier(token, "public")
+                && !is_identifier(token, "static")
+                && !is_identifier(token, "synchronized")
+                && !is_identifier(token, "abstract")
+        })
         .cloned()
         .collect::<Vec<_>>();
-    let name = match tokens[tokens.len() - 2].clone() {
-        TokenTree::Ident(ident) => ident,
-        token => panic!("Expected method name, got {:?}.", token),
-    };
-    let annotations = parse_annotations(&tokens[0..tokens.len() - 2]);
-    let return_type = JavaName::from_tokens(
-        tokens[0..tokens.len() - 2]
-            .iter()
-            .skip(3 * annotations.len()),
-    );
+    let (mut annotations, return_type) = parse_annotations(&tokens[0..tokens.len() - 2]);
+    let name = parse_method_name(tokens[tokens.len() - 2].clone(), &mut annotations);
+    let return_type = parse_type(return_type);
     let arguments = parse_method_arguments(tokens[tokens.len() - 1].clone());
     JavaClassMethod {
         public,
@@ -358,22 +585,17 @@ fn parse_method(tokens: &[TokenTree]) -> JavaClassMethod {
         return_type,
         arguments,
         is_static,
+        is_synchronized,
+        is_abstract,
         annotations,
     }
 }
 
 fn parse_interface_method(tokens: &[TokenTree]) -> JavaInterfaceMethod {
     let tokens = tokens.iter().cloned().collect::<Vec<_>>();
-    let name = match tokens[tokens.len() - 2].clone() {
-        TokenTree::Ident(ident) => ident,
-        token => panic!("Expected method name, got {:?}.", token),
-    };
-    let annotations = parse_annotations(&tokens[0..tokens.len() - 2]);
-    let return_type = JavaName::from_tokens(
-        tokens[0..tokens.len() - 2]
-            .iter()
-            .skip(3 * annotations.len()),
-    );
+    let (mut annotations, return_type) = parse_annotations(&tokens[0..tokens.len() - 2]);
+    let name = parse_method_name(tokens[tokens.len() - 2].clone(), &mut annotations);
+    let return_type = parse_type(return_type);
     let arguments = parse_method_arguments(tokens[tokens.len() - 1].clone());
     JavaInterfaceMethod {
         name,
@@ -389,33 +611,27 @@ fn parse_native_method(tokens: &[TokenTree]) -> JavaNativeMethod {
     let tokens = tokens
         .iter()
         .filter(|token| {
+            // Java enters the monitor of `synchronized` native methods before calling them.
             !is_identifier(token, "public")
                 && !is_identifier(token, "static")
                 && !is_identifier(token, "native")
+                && !is_identifier(token, "synchronized")
         })
         .cloned()
         .collect::<Vec<_>>();
-    let code = match tokens[tokens.len() - 1].clone() {
-        TokenTree::Group(group) => {
-            if group.delimiter() == Delimiter::Brace {
-                group.stream()
-            } else {
-                panic!("Expected method code in braces, got {:?}.", group)
-            }
-        }
+    // Methods without a body, like `native long compute(int x);`, end with the arguments.
+    let (code, tokens) = match tokens[tokens.len() - 1].clone() {
+        TokenTree::Group(group) => match group.delimiter() {
+            Delimiter::Brace => (Some(group.stream()), &tokens[..tokens.len() - 1]),
+            Delimiter::Parenthesis => (None, &tokens[..]),
+            _ => panic!("Expected method code in braces, got {:?}.", group),
+        },
         token => panic!("Expected method code, got {:?}.", token),
     };
-    let name = match tokens[tokens.len() - 3].clone() {
-        TokenTree::Ident(ident) => ident,
-        token => panic!("Expected method name, got {:?}.", token),
-    };
-    let annotations = parse_annotations(&tokens[0..tokens.len() - 3]);
-    let return_type = JavaName::from_tokens(
-        tokens[0..tokens.len() - 3]
-            .iter()
-            .skip(3 * annotations.len()),
-    );
-    let arguments = parse_method_arguments(tokens[tokens.len() - 2].clone());
+    let (mut annotations, return_type) = parse_annotations(&tokens[0..tokens.len() - 2]);
+    let name = parse_method_name(tokens[tokens.len() - 2].clone(), &mut annotations);
+    let return_type = parse_type(return_type);
+    let arguments = parse_method_arguments(tokens[tokens.len() - 1].clone());
     JavaNativeMethod {
         public,
         name,
@@ -434,7 +650,7 @@ fn parse_constructor(tokens: &[TokenTree]) -> JavaConstructor {
         .filter(|token| !is_identifier(token, "public"))
         .cloned()
         .collect::<Vec<_>>();
-    let annotations = parse_annotations(&tokens[0..tokens.len() - 1]);
+    let (annotations, _) = parse_annotations(&tokens[0..tokens.len() - 1]);
     let arguments = parse_method_arguments(tokens[tokens.len() - 1].clone());
     JavaConstructor {
         public,
@@ -443,8 +659,292 @@ fn parse_constructor(tokens: &[TokenTree]) -> JavaConstructor {
     }
 }
 
+/// Classes that `rust-jni` provides, so that they can be imported on demand.
+pub const BUILTIN_CLASSES: &[&str] = &[
+    "java.lang.Class",
+    "java.lang.Exception",
+    "java.lang.NullPointerException",
+    "java.lang.Object",
+    "java.lang.Runtime",
+    "java.lang.String",
+    "java.lang.Throwable",
+];
+
+/// The package and imports of a `java_generate!` input, used to resolve simple class names.
+#[derive(Debug, Default)]
+struct Imports {
+    /// The package of definitions with simple names, like `package a.b;`.
+    package: Option<JavaName>,
+    /// Single-type imports, like `import a.b.TestClass1;`.
+    classes: Vec<JavaName>,
+    /// Type-import-on-demand imports, like `import a.b.*;`.
+    packages: Vec<JavaName>,
+    /// Classes and interfaces that are defined or declared in metadata.
+    known: Vec<JavaName>,
+}
+
+impl Imports {
+    /// Put a definition with a simple name into the package of the input.
+    fn qualify(&self, name: JavaName) -> JavaName {
+        match self.package {
+            Some(ref package) if name.0.clone().into_iter().count() == 1 => {
+                in_package(package, name.name())
+            }
+            _ => name,
+        }
+    }
+
+    /// Resolve a simple class name the same way Java does: a single-type import takes precedence
+    /// over a class in the same package, which takes precedence over on-demand imports.
+    fn resolve(&self, name: &mut JavaName) {
+        if name.0.clone().into_iter().count() != 1 || name.as_primitive_type().is_some() {
+            return;
+        }
+        let simple_name = name.clone().name();
+        if let Some(class) = self
+            .classes
+            .iter()
+            .find(|class| (*class).clone().name() == simple_name)
+        {
+            *name = class.clone();
+            return;
+        }
+        let same_package = self.qualify(name.clone());
+        if self.known.contains(&same_package) {
+            *name = same_package;
+            return;
+        }
+        let candidates = self
+            .packages
+            .iter()
+            .map(|package| in_package(package, simple_name.clone()))
+            .filter(|candidate| self.known.contains(candidate))
+            .collect::<Vec<_>>();
+        match candidates.len() {
+            0 => {}
+            1 => *name = candidates[0].clone(),
+            _ => panic!(
+                "Name {} is ambiguous, it matches imports {}.",
+                simple_name,
+                candidates
+                    .into_iter()
+                    .map(|candidate| candidate.with_slashes().replace("/", "."))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+
+    fn resolve_arguments(&self, arguments: &mut Vec<MethodArgument>) {
+        for argument in arguments {
+            self.resolve(&mut argument.data_type);
+        }
+    }
+
+    fn resolve_interface_methods(&self, methods: &mut Vec<JavaInterfaceMethod>) {
+        for method in methods {
+            self.resolve(&mut method.return_type);
+            self.resolve_arguments(&mut method.arguments);
+        }
+    }
+
+    fn resolve_class_methods(&self, methods: &mut Vec<JavaClassMethod>) {
+        for method in methods {
+            self.resolve(&mut method.return_type);
+            self.resolve_arguments(&mut method.arguments);
+            // The exception class of `@throws(Exception)`.
+            for annotation in &mut method.annotations {
+                if annotation.name == "throws" {
+                    let tokens = annotation.value.clone().into_iter().collect::<Vec<_>>();
+                    let mut name = parse_type(&tokens);
+                    self.resolve(&mut name);
+                    annotation.value = name.0;
+                }
+            }
+        }
+    }
+
+    fn resolve_definitions(&self, definitions: &mut JavaDefinitions) {
+        definitions
+            .options
+            .c_abi
+            .iter_mut()
+            .for_each(|name| self.resolve(name));
+        for definition in &mut definitions.definitions {
+            match definition.definition {
+                JavaDefinitionKind::Class(ref mut class) => {
+                    class.extends.iter_mut().for_each(|name| self.resolve(name));
+                    class
+                        .implements
+                        .iter_mut()
+                        .for_each(|name| self.resolve(name));
+                    self.resolve_class_methods(&mut class.methods);
+                    for method in &mut class.native_methods {
+                        self.resolve(&mut method.return_type);
+                        self.resolve_arguments(&mut method.arguments);
+                    }
+                    for constructor in &mut class.constructors {
+                        self.resolve_arguments(&mut constructor.arguments);
+                    }
+                }
+                JavaDefinitionKind::Interface(ref mut interface) => {
+                    interface
+                        .extends
+                        .iter_mut()
+                        .for_each(|name| self.resolve(name));
+                    interface
+                        .extends_generic_arguments
+                        .iter_mut()
+                        .for_each(|(name, _)| self.resolve(name));
+                    self.resolve_interface_methods(&mut interface.methods);
+                    self.resolve_class_methods(&mut interface.static_methods);
+                }
+            }
+        }
+        for definition in &mut definitions.metadata.definitions {
+            match definition.definition {
+                JavaDefinitionMetadataKind::Class(ref mut class) => {
+                    class.extends.iter_mut().for_each(|name| self.resolve(name));
+                    class
+                        .implements
+                        .iter_mut()
+                        .for_each(|name| self.resolve(name));
+                }
+                JavaDefinitionMetadataKind::Interface(ref mut interface) => {
+                    interface
+                        .extends
+                        .iter_mut()
+                        .for_each(|name| self.resolve(name));
+                    self.resolve_interface_methods(&mut interface.methods);
+                }
+            }
+        }
+    }
+}
+
+fn in_package(package: &JavaName, name: Ident) -> JavaName {
+    let mut tokens = package.clone().0;
+    tokens.extend(Some(TokenTree::Ident(name)));
+    JavaName(tokens)
+}
+
+/// Remove comments from the tokens of a DSL block.
+///
+/// The Rust tokenizer drops line and block comments, but turns documentation comments, like
+/// `/// ...` and `/** ... */`, into `#[doc = "..."]` and `#![doc = "..."]` attributes. Nested
+/// groups are not changed, so that the code of native methods keeps it's attributes.
+pub fn strip_comments(tokens: &[TokenTree]) -> Vec<TokenTree> {
+    let is_attribute_start = |token: Option<&TokenTree>, value| match token {
+        Some(TokenTree::Punct(punctuation)) => punctuation.as_char() == value,
+        _ => false,
+    };
+    let mut result = vec![];
+    let mut index = 0;
+    while index < tokens.len() {
+        if is_attribute_start(tokens.get(index), '#') {
+            let attribute_index = if is_attribute_start(tokens.get(index + 1), '!') {
+                index + 2
+            } else {
+                index + 1
+            };
+            match tokens.get(attribute_index) {
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => {
+                    index = attribute_index + 1;
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        result.push(tokens[index].clone());
+        index += 1;
+    }
+    result
+}
+
+/// Remove empty statements, like a semicolon after a definition body.
+fn strip_empty_statements(tokens: Vec<TokenTree>) -> Vec<TokenTree> {
+    let mut result: Vec<TokenTree> = vec![];
+    for token in tokens {
+        if is_punctuation(&token, ';')
+            && result.last().map_or(true, |last| {
+                is_punctuation(last, ';') || is_definition(last)
+            })
+        {
+            continue;
+        }
+        result.push(token);
+    }
+    result
+}
+
+/// Remove a leading statement, like `import a.b.*;`, from the tokens and return it's tokens
+/// between the keyword and the semicolon.
+fn take_statement(definitions: &mut Vec<TokenTree>, keyword: &str) -> Vec<TokenTree> {
+    let end = definitions
+        .iter()
+        .position(|token| is_punctuation(token, ';'))
+        .unwrap_or_else(|| panic!("Expected \";\" after {}.", keyword));
+    let mut tokens = definitions.drain(0..end + 1).collect::<Vec<_>>();
+    tokens.pop();
+    tokens.remove(0);
+    tokens
+}
+
+/// Parse the leading `package` and `import` statements, removing them from the tokens.
+fn parse_imports(definitions: &mut Vec<TokenTree>) -> Imports {
+    let mut imports = Imports::default();
+    if !definitions.is_empty() && is_identifier(&definitions[0], "package") {
+        let tokens = take_statement(definitions, "package");
+        imports.package = Some(JavaName::from_tokens(tokens.iter()));
+    }
+    while !definitions.is_empty() && is_identifier(&definitions[0], "import") {
+        let tokens = take_statement(definitions, "import");
+        let is_wildcard = tokens.len() > 2
+            && match (&tokens[tokens.len() - 2], &tokens[tokens.len() - 1]) {
+                (TokenTree::Punct(dot), TokenTree::Punct(star)) => {
+                    dot.as_char() == '.' && star.as_char() == '*'
+                }
+                _ => false,
+            };
+        let (imported, name) = if is_wildcard {
+            (
+                &mut imports.packages,
+                JavaName::from_tokens(tokens[..tokens.len() - 2].iter()),
+            )
+        } else {
+            (&mut imports.classes, JavaName::from_tokens(tokens.iter()))
+        };
+        // Java allows to import the same class or package more than once.
+        if !imported.contains(&name) {
+            imported.push(name);
+        }
+    }
+    imports
+}
+
 pub fn parse_java_definition(input: TokenStream) -> JavaDefinitions {
-    let mut definitions = input.clone().into_iter().collect::<Vec<_>>();
+    let mut definitions =
+        strip_empty_statements(strip_comments(&input.into_iter().collect::<Vec<_>>()));
+    let options = if definitions.len() > 1 && is_identifier(&definitions[0], "options") {
+        let options = match definitions[1].clone() {
+            TokenTree::Group(group) => {
+                if group.delimiter() == Delimiter::Brace {
+                    parse_options(group.stream())
+                } else {
+                    panic!("Expected braces, got {:?}.", group)
+                }
+            }
+            token => panic!("Expected braces, got {:?}.", token),
+        };
+        definitions.drain(0..2);
+        options
+    } else {
+        Options::default()
+    };
+    if options.java_source {
+        definitions = java_source_to_definitions(definitions);
+    }
+    let mut imports = parse_imports(&mut definitions);
     let metadata = if definitions.len() > 1
         && is_identifier(&definitions[definitions.len() - 2], "metadata")
     {
@@ -476,28 +976,42 @@ pub fn parse_java_definition(input: TokenStream) -> JavaDefinitions {
             } else {
                 (token, header)
             };
+            let is_abstract = is_identifier(&token, "abstract");
+            let (token, header) = if is_abstract {
+                header.split_first().unwrap()
+            } else {
+                (token, header)
+            };
             let is_class = is_identifier(&token, "class");
             let is_interface = is_identifier(&token, "interface");
             if !is_class && !is_interface {
                 panic!("Expected \"class\" or \"interface\", got {:?}.", token);
             }
+            if is_abstract && !is_class {
+                panic!("Expected \"class\" after \"abstract\", got {:?}.", token);
+            }
 
             if is_interface {
-                let (name, extends) = parse_interface_header(header);
+                let (name, extends, extends_generic_arguments) = parse_interface_header(header);
+                let name = imports.qualify(name);
                 JavaDefinition {
                     name,
                     public,
                     definition: JavaDefinitionKind::Interface(JavaInterface {
                         methods: vec![],
+                        static_methods: vec![],
                         extends,
+                        extends_generic_arguments,
                     }),
                 }
             } else {
                 let (name, extends, implements) = parse_class_header(header);
+                let name = imports.qualify(name);
                 JavaDefinition {
                     name,
                     public,
                     definition: JavaDefinitionKind::Class(JavaClass {
+                        is_abstract,
                         extends,
                         implements,
                         methods: vec![],
@@ -513,7 +1027,7 @@ pub fn parse_java_definition(input: TokenStream) -> JavaDefinitions {
             _ => unreachable!(),
         })
         .map(|(definition, tokens)| {
-            let methods = tokens.into_iter().collect::<Vec<_>>();
+            let methods = strip_comments(&tokens.into_iter().collect::<Vec<_>>());
             let java_definition = match definition.definition.clone() {
                 JavaDefinitionKind::Class(class) => {
                     let constructors = methods
@@ -544,13 +1058,21 @@ pub fn parse_java_definition(input: TokenStream) -> JavaDefinitions {
                     })
                 }
                 JavaDefinitionKind::Interface(interface) => {
+                    let static_methods = methods
+                        .split(|token| is_punctuation(token, ';'))
+                        .filter(|tokens| !tokens.is_empty())
+                        .filter(|tokens| is_static_method(tokens))
+                        .map(parse_method)
+                        .collect::<Vec<_>>();
                     let methods = methods
                         .split(|token| is_punctuation(token, ';'))
                         .filter(|tokens| !tokens.is_empty())
+                        .filter(|tokens| !is_static_method(tokens))
                         .map(parse_interface_method)
                         .collect::<Vec<_>>();
                     JavaDefinitionKind::Interface(JavaInterface {
                         methods,
+                        static_methods,
                         ..interface
                     })
                 }
@@ -560,11 +1082,28 @@ pub fn parse_java_definition(input: TokenStream) -> JavaDefinitions {
                 ..definition
             }
         })
+        .collect::<Vec<_>>();
+    imports.known = definitions
+        .iter()
+        .map(|definition| definition.name.clone())
+        .chain(
+            metadata
+                .definitions
+                .iter()
+                .map(|definition| definition.name.clone()),
+        )
+        .chain(BUILTIN_CLASSES.iter().map(|name| {
+            let tokens = name.parse::<TokenStream>().unwrap();
+            JavaName::from_tokens(tokens.into_iter().collect::<Vec<_>>().iter())
+        }))
         .collect();
-    JavaDefinitions {
+    let mut definitions = JavaDefinitions {
+        options,
         definitions,
         metadata,
-    }
+    };
+    imports.resolve_definitions(&mut definitions);
+    definitions
 }
 
 fn is_identifier(token: &TokenTree, name: &str) -> bool {
@@ -574,6 +1113,10 @@ fn is_identifier(token: &TokenTree, name: &str) -> bool {
     }
 }
 
+fn is_static_method(tokens: &[TokenTree]) -> bool {
+    tokens.iter().any(|token| is_identifier(token, "static"))
+}
+
 fn is_definition(token: &TokenTree) -> bool {
     match token {
         TokenTree::Group(group) => group.delimiter() == Delimiter::Brace,
@@ -599,6 +1142,7 @@ mod parse_tests {
         assert_eq!(
             parse_java_definition(input),
             JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![],
                 metadata: Metadata {
                     definitions: vec![],
@@ -615,10 +1159,12 @@ mod parse_tests {
         assert_eq!(
             parse_java_definition(input),
             JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![JavaDefinition {
                     name: JavaName(quote! {TestClass1}),
                     public: false,
                     definition: JavaDefinitionKind::Class(JavaClass {
+                        is_abstract: false,
                         extends: None,
                         implements: vec![],
                         methods: vec![],
@@ -641,10 +1187,12 @@ mod parse_tests {
         assert_eq!(
             parse_java_definition(input),
             JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![JavaDefinition {
                     name: JavaName(quote! {TestClass1}),
                     public: false,
                     definition: JavaDefinitionKind::Class(JavaClass {
+                        is_abstract: false,
                         extends: Some(JavaName(quote! {test1})),
                         implements: vec![],
                         methods: vec![],
@@ -667,10 +1215,12 @@ mod parse_tests {
         assert_eq!(
             parse_java_definition(input),
             JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![JavaDefinition {
                     name: JavaName(quote! {TestClass1}),
                     public: true,
                     definition: JavaDefinitionKind::Class(JavaClass {
+                        is_abstract: false,
                         extends: None,
                         implements: vec![],
                         methods: vec![],
@@ -685,6 +1235,118 @@ mod parse_tests {
         );
     }
 
+    #[test]
+    fn one_class_abstract() {
+        let input = quote! {
+            public abstract class TestClass1 {
+                abstract long test1(int arg1);
+                public long test2();
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                options: Options::default(),
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {TestClass1}),
+                    public: true,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        is_abstract: true,
+                        extends: None,
+                        implements: vec![],
+                        methods: vec![
+                            JavaClassMethod {
+                                name: Ident::new("test1", Span::call_site()),
+                                return_type: JavaName(quote! {long}),
+                                arguments: vec![MethodArgument {
+                                    name: Ident::new("arg1", Span::call_site()),
+                                    data_type: JavaName(quote! {int}),
+                                    annotations: vec![],
+                                }],
+                                public: false,
+                                is_static: false,
+                                is_synchronized: false,
+                                is_abstract: true,
+                                annotations: vec![],
+                            },
+                            JavaClassMethod {
+                                name: Ident::new("test2", Span::call_site()),
+                                return_type: JavaName(quote! {long}),
+                                arguments: vec![],
+                                public: true,
+                                is_static: false,
+                                is_synchronized: false,
+                                is_abstract: false,
+                                annotations: vec![],
+                            },
+                        ],
+                        native_methods: vec![],
+                        constructors: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn one_class_native_methods() {
+        let input = quote! {
+            class TestClass1 {
+                public native long test1(int arg1);
+                static native void test2() {
+                    Ok(())
+                };
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                options: Options::default(),
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {TestClass1}),
+                    public: false,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        is_abstract: false,
+                        extends: None,
+                        implements: vec![],
+                        methods: vec![],
+                        native_methods: vec![
+                            JavaNativeMethod {
+                                name: Ident::new("test1", Span::call_site()),
+                                return_type: JavaName(quote! {long}),
+                                arguments: vec![MethodArgument {
+                                    name: Ident::new("arg1", Span::call_site()),
+                                    data_type: JavaName(quote! {int}),
+                                    annotations: vec![],
+                                }],
+                                public: true,
+                                is_static: false,
+                                code: None,
+                                annotations: vec![],
+                            },
+                            JavaNativeMethod {
+                                name: Ident::new("test2", Span::call_site()),
+                                return_type: JavaName(quote! {void}),
+                                arguments: vec![],
+                                public: false,
+                                is_static: true,
+                                code: Some(quote! {Ok(())}),
+                                annotations: vec![],
+                            },
+                        ],
+                        constructors: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
     #[test]
     fn one_class_packaged() {
         let input = quote! {
@@ -693,10 +1355,12 @@ mod parse_tests {
         assert_eq!(
             parse_java_definition(input),
             JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![JavaDefinition {
                     name: JavaName(quote! {a b TestClass1}),
                     public: false,
                     definition: JavaDefinitionKind::Class(JavaClass {
+                        is_abstract: false,
                         extends: None,
                         implements: vec![],
                         methods: vec![],
@@ -719,10 +1383,12 @@ mod parse_tests {
         assert_eq!(
             parse_java_definition(input),
             JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![JavaDefinition {
                     name: JavaName(quote! {TestClass1}),
                     public: false,
                     definition: JavaDefinitionKind::Class(JavaClass {
+                        is_abstract: false,
                         extends: None,
                         implements: vec![JavaName(quote! {test2}), JavaName(quote! {a b test3})],
                         methods: vec![],
@@ -745,12 +1411,15 @@ mod parse_tests {
         assert_eq!(
             parse_java_definition(input),
             JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![JavaDefinition {
                     name: JavaName(quote! {TestInterface1}),
                     public: false,
                     definition: JavaDefinitionKind::Interface(JavaInterface {
                         methods: vec![],
+                        static_methods: vec![],
                         extends: vec![],
+                        extends_generic_arguments: vec![],
                     }),
                 }],
                 metadata: Metadata {
@@ -768,12 +1437,15 @@ mod parse_tests {
         assert_eq!(
             parse_java_definition(input),
             JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![JavaDefinition {
                     name: JavaName(quote! {TestInterface1}),
                     public: true,
                     definition: JavaDefinitionKind::Interface(JavaInterface {
                         methods: vec![],
+                        static_methods: vec![],
                         extends: vec![],
+                        extends_generic_arguments: vec![],
                     }),
                 }],
                 metadata: Metadata {
@@ -791,12 +1463,15 @@ mod parse_tests {
         assert_eq!(
             parse_java_definition(input),
             JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![JavaDefinition {
                     name: JavaName(quote! {a b TestInterface1}),
                     public: false,
                     definition: JavaDefinitionKind::Interface(JavaInterface {
                         methods: vec![],
+                        static_methods: vec![],
                         extends: vec![],
+                        extends_generic_arguments: vec![],
                     }),
                 }],
                 metadata: Metadata {
@@ -814,15 +1489,18 @@ mod parse_tests {
         assert_eq!(
             parse_java_definition(input),
             JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![JavaDefinition {
                     name: JavaName(quote! {TestInterface1}),
                     public: false,
                     definition: JavaDefinitionKind::Interface(JavaInterface {
                         methods: vec![],
+                        static_methods: vec![],
                         extends: vec![
                             JavaName(quote! {TestInterface2}),
                             JavaName(quote! {a b TestInterface3}),
                         ],
+                        extends_generic_arguments: vec![],
                     }),
                 }],
                 metadata: Metadata {
@@ -833,9 +1511,460 @@ mod parse_tests {
     }
 
     #[test]
-    fn multiple() {
+    fn one_interface_extends_generic() {
         let input = quote! {
-            interface TestInterface1 {}
+            interface TestInterface1 extends TestInterface2<TestInterface1>, a.b.TestInterface3<c.d.TestClass1<TestInterface1>, TestClass2>, TestInterface4 {}
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                options: Options::default(),
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {TestInterface1}),
+                    public: false,
+                    definition: JavaDefinitionKind::Interface(JavaInterface {
+                        methods: vec![],
+                        static_methods: vec![],
+                        extends: vec![
+                            JavaName(quote! {TestInterface2}),
+                            JavaName(quote! {a b TestInterface3}),
+                            JavaName(quote! {TestInterface4}),
+                        ],
+                        extends_generic_arguments: vec![
+                            (JavaName(quote! {TestInterface2}), quote! {TestInterface1}),
+                            (
+                                JavaName(quote! {a b TestInterface3}),
+                                quote! {c.d.TestClass1<TestInterface1>, TestClass2},
+                            ),
+                        ],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Unclosed generic type arguments")]
+    fn one_interface_extends_unclosed_generic() {
+        let input = quote! {
+            interface TestInterface1 extends TestInterface2<TestInterface1 {}
+        };
+        parse_java_definition(input);
+    }
+
+    #[test]
+    fn one_class_implements_generic() {
+        let input = quote! {
+            class TestClass1 implements TestInterface1<TestClass1>, TestInterface2 {}
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                options: Options::default(),
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {TestClass1}),
+                    public: false,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        is_abstract: false,
+                        extends: None,
+                        implements: vec![
+                            JavaName(quote! {TestInterface1}),
+                            JavaName(quote! {TestInterface2}),
+                        ],
+                        methods: vec![],
+                        native_methods: vec![],
+                        constructors: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn annotations() {
+        let input = quote! {
+            interface TestInterface1 {
+                @RustName(test_1) @nullable a.b.TestClass1 test1(@nonnull a.b.TestClass2 arg1, int arg2);
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                options: Options::default(),
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {TestInterface1}),
+                    public: false,
+                    definition: JavaDefinitionKind::Interface(JavaInterface {
+                        methods: vec![JavaInterfaceMethod {
+                            name: Ident::new("test1", Span::call_site()),
+                            return_type: JavaName(quote! {a b TestClass1}),
+                            arguments: vec![
+                                MethodArgument {
+                                    name: Ident::new("arg1", Span::call_site()),
+                                    data_type: JavaName(quote! {a b TestClass2}),
+                                    annotations: vec![Annotation {
+                                        name: Ident::new("nonnull", Span::call_site()),
+                                        value: TokenStream::new(),
+                                    }],
+                                },
+                                MethodArgument {
+                                    name: Ident::new("arg2", Span::call_site()),
+                                    data_type: JavaName(quote! {int}),
+                                    annotations: vec![],
+                                },
+                            ],
+                            annotations: vec![
+                                Annotation {
+                                    name: Ident::new("RustName", Span::call_site()),
+                                    value: quote! {test_1},
+                                },
+                                Annotation {
+                                    name: Ident::new("nullable", Span::call_site()),
+                                    value: TokenStream::new(),
+                                },
+                            ],
+                        }],
+                        static_methods: vec![],
+                        extends: vec![],
+                        extends_generic_arguments: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn generic_types() {
+        let input = quote! {
+            interface TestInterface1 {
+                java.lang.Class<?> test1(java.lang.Class<? extends a.b.TestClass1> arg1, a.b.TestClass2<java.lang.String, java.lang.Class<?>> arg2);
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                options: Options::default(),
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {TestInterface1}),
+                    public: false,
+                    definition: JavaDefinitionKind::Interface(JavaInterface {
+                        methods: vec![JavaInterfaceMethod {
+                            name: Ident::new("test1", Span::call_site()),
+                            return_type: JavaName(quote! {java lang Class}),
+                            arguments: vec![
+                                MethodArgument {
+                                    name: Ident::new("arg1", Span::call_site()),
+                                    data_type: JavaName(quote! {java lang Class}),
+                                    annotations: vec![],
+                                },
+                                MethodArgument {
+                                    name: Ident::new("arg2", Span::call_site()),
+                                    data_type: JavaName(quote! {a b TestClass2}),
+                                    annotations: vec![],
+                                },
+                            ],
+                            annotations: vec![],
+                        }],
+                        static_methods: vec![],
+                        extends: vec![],
+                        extends_generic_arguments: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn imports() {
+        let input = quote! {
+            import c.d.TestClass2;
+            import e.f.*;
+            class a.b.TestClass1 extends TestClass2 implements TestInterface1 {
+                TestClass2 test1(TestInterface1 arg1, TestClass3 arg2, int arg3);
+            }
+            metadata {
+                interface e.f.TestInterface1;
+                class c.d.TestClass2;
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                options: Options::default(),
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {a b TestClass1}),
+                    public: false,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        is_abstract: false,
+                        extends: Some(JavaName(quote! {c d TestClass2})),
+                        implements: vec![JavaName(quote! {e f TestInterface1})],
+                        methods: vec![JavaClassMethod {
+                            name: Ident::new("test1", Span::call_site()),
+                            return_type: JavaName(quote! {c d TestClass2}),
+                            arguments: vec![
+                                MethodArgument {
+                                    name: Ident::new("arg1", Span::call_site()),
+                                    data_type: JavaName(quote! {e f TestInterface1}),
+                                    annotations: vec![],
+                                },
+                                MethodArgument {
+                                    name: Ident::new("arg2", Span::call_site()),
+                                    data_type: JavaName(quote! {TestClass3}),
+                                    annotations: vec![],
+                                },
+                                MethodArgument {
+                                    name: Ident::new("arg3", Span::call_site()),
+                                    data_type: JavaName(quote! {int}),
+                                    annotations: vec![],
+                                },
+                            ],
+                            public: false,
+                            is_static: false,
+                            is_synchronized: false,
+                            is_abstract: false,
+                            annotations: vec![],
+                        }],
+                        native_methods: vec![],
+                        constructors: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![
+                        JavaDefinitionMetadata {
+                            name: JavaName(quote! {e f TestInterface1}),
+                            definition: JavaDefinitionMetadataKind::Interface(
+                                JavaInterfaceMetadata {
+                                    extends: vec![],
+                                    methods: vec![],
+                                }
+                            ),
+                        },
+                        JavaDefinitionMetadata {
+                            name: JavaName(quote! {c d TestClass2}),
+                            definition: JavaDefinitionMetadataKind::Class(JavaClassMetadata {
+                                extends: None,
+                                implements: vec![],
+                            }),
+                        },
+                    ],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn package() {
+        let input = quote! {
+            package a.b;
+            import c.d.TestClass2;
+            public class TestClass1 extends TestClass2 {
+                public TestClass1(TestClass3 arg);
+                TestClass1 test1(int arg1);
+            }
+            class TestClass3 {}
+            class e.f.TestClass4 {}
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                options: Options::default(),
+                definitions: vec![
+                    JavaDefinition {
+                        name: JavaName(quote! {a b TestClass1}),
+                        public: true,
+                        definition: JavaDefinitionKind::Class(JavaClass {
+                            is_abstract: false,
+                            extends: Some(JavaName(quote! {c d TestClass2})),
+                            implements: vec![],
+                            methods: vec![JavaClassMethod {
+                                name: Ident::new("test1", Span::call_site()),
+                                return_type: JavaName(quote! {a b TestClass1}),
+                                arguments: vec![MethodArgument {
+                                    name: Ident::new("arg1", Span::call_site()),
+                                    data_type: JavaName(quote! {int}),
+                                    annotations: vec![],
+                                }],
+                                public: false,
+                                is_static: false,
+                                is_synchronized: false,
+                                is_abstract: false,
+                                annotations: vec![],
+                            }],
+                            native_methods: vec![],
+                            constructors: vec![JavaConstructor {
+                                arguments: vec![MethodArgument {
+                                    name: Ident::new("arg", Span::call_site()),
+                                    data_type: JavaName(quote! {a b TestClass3}),
+                                    annotations: vec![],
+                                }],
+                                public: true,
+                                annotations: vec![],
+                            }],
+                        }),
+                    },
+                    JavaDefinition {
+                        name: JavaName(quote! {a b TestClass3}),
+                        public: false,
+                        definition: JavaDefinitionKind::Class(JavaClass {
+                            is_abstract: false,
+                            extends: None,
+                            implements: vec![],
+                            methods: vec![],
+                            native_methods: vec![],
+                            constructors: vec![],
+                        }),
+                    },
+                    JavaDefinition {
+                        name: JavaName(quote! {e f TestClass4}),
+                        public: false,
+                        definition: JavaDefinitionKind::Class(JavaClass {
+                            is_abstract: false,
+                            extends: None,
+                            implements: vec![],
+                            methods: vec![],
+                            native_methods: vec![],
+                            constructors: vec![],
+                        }),
+                    },
+                ],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn imports_default_package() {
+        let input = quote! {
+            import a.b.*;
+            class TestClass1 {}
+            class TestClass2 extends TestClass1 {}
+            metadata {
+                class a.b.TestClass1;
+            }
+        };
+        let definitions = parse_java_definition(input).definitions;
+        match definitions[1].definition {
+            JavaDefinitionKind::Class(ref class) => {
+                assert_eq!(class.extends, Some(JavaName(quote! {TestClass1})))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn imports_throws() {
+        let input = quote! {
+            import c.d.*;
+            class a.b.TestClass1 {
+                @throws(TestException1) long test1();
+                @throws(e.f.TestException2) long test2();
+            }
+            metadata {
+                class c.d.TestException1;
+            }
+        };
+        let definitions = parse_java_definition(input).definitions;
+        match definitions[0].definition {
+            JavaDefinitionKind::Class(ref class) => {
+                assert_eq!(
+                    class.methods[0].annotations[0].value.to_string(),
+                    quote! {c d TestException1}.to_string()
+                );
+                assert_eq!(
+                    class.methods[1].annotations[0].value.to_string(),
+                    quote! {e f TestException2}.to_string()
+                );
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Name TestClass1 is ambiguous, it matches imports a.b.TestClass1, c.d.TestClass1"
+    )]
+    fn imports_ambiguous() {
+        let input = quote! {
+            import a.b.*;
+            import c.d.*;
+            class e.f.TestClass2 extends TestClass1 {}
+            metadata {
+                class a.b.TestClass1;
+                class c.d.TestClass1;
+            }
+        };
+        parse_java_definition(input);
+    }
+
+    #[test]
+    fn one_interface_methods() {
+        let input = quote! {
+            interface TestInterface1 {
+                long test1(int arg1);
+                public static long test2(int arg1);
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                options: Options::default(),
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {TestInterface1}),
+                    public: false,
+                    definition: JavaDefinitionKind::Interface(JavaInterface {
+                        methods: vec![JavaInterfaceMethod {
+                            name: Ident::new("test1", Span::call_site()),
+                            return_type: JavaName(quote! {long}),
+                            arguments: vec![MethodArgument {
+                                name: Ident::new("arg1", Span::call_site()),
+                                data_type: JavaName(quote! {int}),
+                                annotations: vec![],
+                            }],
+                            annotations: vec![],
+                        }],
+                        static_methods: vec![JavaClassMethod {
+                            name: Ident::new("test2", Span::call_site()),
+                            return_type: JavaName(quote! {long}),
+                            arguments: vec![MethodArgument {
+                                name: Ident::new("arg1", Span::call_site()),
+                                data_type: JavaName(quote! {int}),
+                                annotations: vec![],
+                            }],
+                            public: true,
+                            is_static: true,
+                            is_synchronized: false,
+                            is_abstract: false,
+                            annotations: vec![],
+                        }],
+                        extends: vec![],
+                        extends_generic_arguments: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn multiple() {
+        let input = quote! {
+            interface TestInterface1 {}
             interface TestInterface2 {}
             class TestClass1 {}
             class TestClass2 {}
@@ -843,13 +1972,16 @@ mod parse_tests {
         assert_eq!(
             parse_java_definition(input),
             JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![
                     JavaDefinition {
                         name: JavaName(quote! {TestInterface1}),
                         public: false,
                         definition: JavaDefinitionKind::Interface(JavaInterface {
                             methods: vec![],
+                            static_methods: vec![],
                             extends: vec![],
+                            extends_generic_arguments: vec![],
                         }),
                     },
                     JavaDefinition {
@@ -857,13 +1989,16 @@ mod parse_tests {
                         public: false,
                         definition: JavaDefinitionKind::Interface(JavaInterface {
                             methods: vec![],
+                            static_methods: vec![],
                             extends: vec![],
+                            extends_generic_arguments: vec![],
                         }),
                     },
                     JavaDefinition {
                         name: JavaName(quote! {TestClass1}),
                         public: false,
                         definition: JavaDefinitionKind::Class(JavaClass {
+                            is_abstract: false,
                             extends: None,
                             implements: vec![],
                             methods: vec![],
@@ -875,6 +2010,7 @@ mod parse_tests {
                         name: JavaName(quote! {TestClass2}),
                         public: false,
                         definition: JavaDefinitionKind::Class(JavaClass {
+                            is_abstract: false,
                             extends: None,
                             implements: vec![],
                             methods: vec![],
@@ -898,6 +2034,7 @@ mod parse_tests {
         assert_eq!(
             parse_java_definition(input),
             JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![],
                 metadata: Metadata {
                     definitions: vec![],
@@ -919,6 +2056,7 @@ mod parse_tests {
         assert_eq!(
             parse_java_definition(input),
             JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![],
                 metadata: Metadata {
                     definitions: vec![
@@ -963,6 +2101,168 @@ mod parse_tests {
         );
     }
 
+    #[test]
+    fn options() {
+        let input = quote! {
+            options {
+                strict_extends
+            }
+            class a.b.TestClass1 extends java.lang.Object {}
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            JavaDefinitions {
+                options: Options {
+                    strict_extends: true,
+                    java_source: false,
+                    java_stubs: None,
+                    unchecked_methods: None,
+                    c_abi: vec![],
+                },
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {a b TestClass1}),
+                    public: false,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        is_abstract: false,
+                        extends: Some(JavaName(quote! {java lang Object})),
+                        implements: vec![],
+                        methods: vec![],
+                        native_methods: vec![],
+                        constructors: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn option_java_stubs() {
+        let input = quote! {
+            options {
+                strict_extends,
+                java_stubs("test_library")
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input).options,
+            Options {
+                strict_extends: true,
+                java_source: false,
+                java_stubs: Some("test_library".to_owned()),
+                unchecked_methods: None,
+                c_abi: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn option_unchecked_methods() {
+        let input = quote! {
+            options {
+                unchecked_methods("unchecked")
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input).options,
+            Options {
+                strict_extends: false,
+                java_source: false,
+                java_stubs: None,
+                unchecked_methods: Some("unchecked".to_owned()),
+                c_abi: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn option_c_abi() {
+        let input = quote! {
+            options {
+                c_abi(a.b.TestClass1, TestClass2)
+            }
+            package a.c;
+            import a.b.*;
+            class TestClass2 {}
+            metadata {
+                class a.b.TestClass1;
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input).options.c_abi,
+            vec![
+                JavaName(quote! {a b TestClass1}),
+                JavaName(quote! {a c TestClass2}),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected class names in parenthesis after c_abi")]
+    fn option_c_abi_no_classes() {
+        let input = quote! {
+            options {
+                c_abi()
+            }
+        };
+        parse_java_definition(input);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected a feature name in parenthesis after unchecked_methods")]
+    fn option_unchecked_methods_no_feature() {
+        let input = quote! {
+            options {
+                unchecked_methods
+            }
+        };
+        parse_java_definition(input);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected a library name in parenthesis after java_stubs")]
+    fn option_java_stubs_no_library() {
+        let input = quote! {
+            options {
+                java_stubs
+            }
+        };
+        parse_java_definition(input);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected a library name in parenthesis after java_stubs")]
+    fn option_java_stubs_not_string() {
+        let input = quote! {
+            options {
+                java_stubs(test_library)
+            }
+        };
+        parse_java_definition(input);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown option")]
+    fn invalid_option() {
+        let input = quote! {
+            options {
+                invalid_option
+            }
+        };
+        parse_java_definition(input);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected \"class\" after \"abstract\"")]
+    fn abstract_interface() {
+        let input = quote! {
+            abstract interface a.b {}
+        };
+        parse_java_definition(input);
+    }
+
     #[test]
     #[should_panic(expected = "Expected \"class\" or \"interface\"")]
     fn invalid_definition_kind() {
@@ -1036,4 +2336,136 @@ mod parse_tests {
         };
         parse_java_definition(input);
     }
+
+    #[test]
+    fn comments() {
+        let input = quote! {
+            //! Module documentation.
+            options {
+                /// An option.
+                strict_extends
+            }
+            // A comment.
+            package a.b;
+            /* A block comment. */
+            import c.d.*;
+
+            /// A class.
+            /** More documentation. */
+            public class TestClass1 extends TestClass2 {
+                /// A constructor.
+                public TestClass1(/** An argument. */ int arg);
+                /// A method.
+                @RustName(test_one)
+                /// More documentation.
+                TestInterface1 test1(
+                    /// An argument.
+                    @nullable
+                    /// More documentation.
+                    TestClass2 arg1,
+                );
+                /// A native method.
+                native long test2() {
+                    /// Code keeps it's attributes.
+                    #[allow(unused_variables)]
+                    let value = 1;
+                    Ok(0)
+                };
+            }
+
+            /// An interface.
+            interface TestInterface1 {
+                /// A method.
+                void test3();
+            }
+
+            metadata {
+                /// A class.
+                class c.d.TestClass2 extends java.lang.Object;
+                /// An interface.
+                interface c.d.TestInterface2 {
+                    /// A method.
+                    long test4(/// An argument.
+                               int arg);
+                }
+            }
+        };
+        let expected = quote! {
+            options {
+                strict_extends
+            }
+            package a.b;
+            import c.d.*;
+
+            public class TestClass1 extends TestClass2 {
+                public TestClass1(int arg);
+                @RustName(test_one)
+                TestInterface1 test1(@nullable TestClass2 arg1,);
+                native long test2() {
+                    /// Code keeps it's attributes.
+                    #[allow(unused_variables)]
+                    let value = 1;
+                    Ok(0)
+                };
+            }
+
+            interface TestInterface1 {
+                void test3();
+            }
+
+            metadata {
+                class c.d.TestClass2 extends java.lang.Object;
+                interface c.d.TestInterface2 {
+                    long test4(int arg);
+                }
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            parse_java_definition(expected)
+        );
+    }
+
+    #[test]
+    fn empty_statements() {
+        let input = quote! {
+            package a.b;;
+            ;
+            class TestClass1 {
+                ;
+                void test1();;
+            };
+            interface TestInterface1 {};;
+
+            metadata {
+                ;
+                class c.d.TestClass2 {};
+                class c.d.TestClass3;;
+                interface c.d.TestInterface2 {
+                    void test2();;
+                };
+                interface c.d.TestInterface3 {}
+            };
+        };
+        let expected = quote! {
+            package a.b;
+            class TestClass1 {
+                void test1();
+            }
+            interface TestInterface1 {}
+
+            metadata {
+                class c.d.TestClass2 {}
+                class c.d.TestClass3;
+                interface c.d.TestInterface2 {
+                    void test2();
+                }
+                interface c.d.TestInterface3 {}
+            }
+        };
+        assert_eq!(
+            parse_java_definition(input),
+            parse_java_definition(expected)
+        );
+    }
 }
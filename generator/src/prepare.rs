@@ -1,11 +1,12 @@
 use generate::{self, GeneratorData, GeneratorDefinition};
+use indexmap::{IndexMap, IndexSet};
 use java_name::*;
 use parse::*;
 use proc_macro2::*;
-use std::collections::{HashMap, HashSet};
+use std::iter;
 
 fn populate_interface_extends_rec(
-    interface_extends: &mut HashMap<JavaName, HashSet<JavaName>>,
+    interface_extends: &mut IndexMap<JavaName, IndexSet<JavaName>>,
     key: &JavaName,
 ) {
     let mut interfaces = interface_extends.get(key).unwrap().clone();
@@ -19,7 +20,7 @@ fn populate_interface_extends_rec(
     *interface_extends.get_mut(key).unwrap() = interfaces;
 }
 
-fn populate_interface_extends(interface_extends: &mut HashMap<JavaName, HashSet<JavaName>>) {
+fn populate_interface_extends(interface_extends: &mut IndexMap<JavaName, IndexSet<JavaName>>) {
     for key in interface_extends.keys().cloned().collect::<Vec<_>>() {
         populate_interface_extends_rec(interface_extends, &key);
     }
@@ -56,30 +57,374 @@ fn annotation_value_ident(annotations: &[Annotation], name: &str) -> Option<Iden
     })
 }
 
-fn to_generator_method(method: JavaClassMethod) -> generate::ClassMethod {
+fn annotation_value_bool(annotations: &[Annotation], name: &str) -> Option<bool> {
+    annotation_value_ident(annotations, name).map(|value| match value.to_string().as_str() {
+        "true" => true,
+        "false" => false,
+        _ => panic!("Expected true or false in @{}, got {}.", name, value),
+    })
+}
+
+/// The Java name of a method, kept in a `JavaName` annotation for names written as string literals.
+fn method_java_name(name: &Ident, annotations: &[Annotation]) -> String {
+    annotation_value(annotations, "JavaName")
+        .map(|value| match value.into_iter().next().unwrap() {
+            TokenTree::Literal(literal) => java_identifier(&literal),
+            _ => unreachable!(),
+        })
+        .unwrap_or_else(|| name.to_string())
+}
+
+/// Parse a `@RustMapResult(path::to::function -> ResultType)` annotation.
+fn annotation_value_map_result(annotations: &[Annotation]) -> Option<generate::MapResult> {
+    annotation_value(annotations, "RustMapResult").map(|value| {
+        let tokens = value.into_iter().collect::<Vec<_>>();
+        let arrow = tokens
+            .windows(2)
+            .position(|pair| match (&pair[0], &pair[1]) {
+                (TokenTree::Punct(minus), TokenTree::Punct(greater)) => {
+                    minus.as_char() == '-'
+                        && minus.spacing() == Spacing::Joint
+                        && greater.as_char() == '>'
+                }
+                _ => false,
+            });
+        match arrow {
+            Some(arrow) if arrow > 0 && arrow + 2 < tokens.len() => generate::MapResult {
+                function: tokens[..arrow].iter().cloned().collect(),
+                result_type: tokens[arrow + 2..].iter().cloned().collect(),
+            },
+            _ => panic!(
+                "Expected @RustMapResult(path::to::function -> ResultType), got @RustMapResult({}).",
+                tokens.iter().cloned().collect::<TokenStream>()
+            ),
+        }
+    })
+}
+
+/// Parse a `@since(11)` annotation: the Java feature release that introduced a method.
+fn annotation_value_since(annotations: &[Annotation]) -> Option<Literal> {
+    annotation_value(annotations, "since").map(|value| {
+        let tokens = value.into_iter().collect::<Vec<_>>();
+        let release = match tokens.as_slice() {
+            [TokenTree::Literal(literal)] => literal.to_string().parse::<u32>().ok(),
+            _ => None,
+        };
+        match release {
+            Some(release) => Literal::u32_unsuffixed(release),
+            None => panic!(
+                "Expected a Java feature release, like @since(11), got @since({}).",
+                tokens.iter().cloned().collect::<TokenStream>()
+            ),
+        }
+    })
+}
+
+/// Parse a `@rust_visibility(pub(crate))` annotation: the Rust visibility of a method,
+/// independent of it's Java visibility. `@rust_visibility()` makes the method private.
+fn annotation_value_visibility(annotations: &[Annotation]) -> Option<TokenStream> {
+    annotation_value(annotations, "rust_visibility").map(|value| {
+        let tokens = value.clone().into_iter().collect::<Vec<_>>();
+        let valid = match tokens.as_slice() {
+            [] => true,
+            [TokenTree::Ident(public)] => public.to_string() == "pub",
+            [TokenTree::Ident(public), TokenTree::Group(group)] => {
+                public.to_string() == "pub" && group.delimiter() == Delimiter::Parenthesis
+            }
+            _ => false,
+        };
+        if !valid {
+            panic!(
+                "Expected a Rust visibility, like @rust_visibility(pub(crate)), got @rust_visibility({}).",
+                value
+            );
+        }
+        value
+    })
+}
+
+/// Check that a method without a runtime availability check is not marked with `@since`.
+fn check_no_since(annotations: &[Annotation], kind: &str, name: &Ident) {
+    if annotation_value(annotations, "since").is_some() {
+        panic!("@since is not supported on {}, got {}.", kind, name);
+    }
+}
+
+/// Nullability of an object type, set with a `@nullable` or a `@nonnull` annotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Nullability {
+    /// No annotation, the value is assumed to never be `null`.
+    Implicit,
+    Nullable,
+    NonNull,
+}
+
+fn annotation_nullability(annotations: &[Annotation], data_type: &JavaName) -> Nullability {
+    let nullable = annotation_value(annotations, "nullable").is_some();
+    let nonnull = annotation_value(annotations, "nonnull").is_some();
+    let nullability = match (nullable, nonnull) {
+        (false, false) => return Nullability::Implicit,
+        (true, false) => Nullability::Nullable,
+        (false, true) => Nullability::NonNull,
+        (true, true) => panic!("@nullable and @nonnull can't be used together."),
+    };
+    if data_type.as_primitive_type().is_some() {
+        panic!(
+            "Only object types can be @nullable or @nonnull, got {}.",
+            data_type.clone().with_slashes().replace("/", ".")
+        );
+    }
+    nullability
+}
+
+/// Rust type of a method result: `@nullable` results are optional.
+fn to_rust_result_type(return_type: JavaName, annotations: &[Annotation]) -> TokenStream {
+    let nullability = annotation_nullability(annotations, &return_type);
+    let return_type = return_type.as_rust_type();
+    match nullability {
+        Nullability::Nullable => quote! {::rust_jni::__generator::option::Option<#return_type>},
+        _ => return_type,
+    }
+}
+
+/// Rust keywords, including reserved ones, that can be valid Java identifiers.
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "become", "box", "crate", "dyn", "extern", "fn", "gen", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "self", "Self", "struct", "trait", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+    "where", "yield",
+];
+
+/// Keywords that can't be raw identifiers.
+const NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "Self"];
+
+/// Escape a Java identifier that is a Rust keyword: use a raw identifier, like `r#match`,
+/// or add an underscore suffix, like `self_`, for keywords that can't be raw identifiers.
+fn escape_rust_keyword(name: Ident) -> Ident {
+    let string = name.to_string();
+    if NON_RAW_KEYWORDS.contains(&string.as_str()) {
+        return Ident::new(&format!("{}_", string), name.span());
+    }
+    if !RUST_KEYWORDS.contains(&string.as_str()) {
+        return name;
+    }
+    // `Ident::new_raw` is not available in a stable `proc_macro2`, but raw identifiers
+    // can be parsed.
+    match format!("r#{}", string)
+        .parse::<TokenStream>()
+        .unwrap()
+        .into_iter()
+        .next()
+    {
+        Some(TokenTree::Ident(mut identifier)) => {
+            identifier.set_span(name.span());
+            identifier
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Rust type of a method argument: `@nullable` arguments are optional references.
+fn to_rust_argument_type(argument: &MethodArgument) -> TokenStream {
+    let argument_type = argument.data_type.clone().as_rust_type_reference();
+    match annotation_nullability(&argument.annotations, &argument.data_type) {
+        Nullability::Nullable => quote! {::rust_jni::__generator::option::Option<#argument_type>},
+        _ => argument_type,
+    }
+}
+
+/// Rust type of a method result with a `@returns_self` annotation: the object of the class itself.
+fn to_rust_self_result_type(
+    name: &Ident,
+    return_type: &JavaName,
+    is_static: bool,
+    class_name: &JavaName,
+    annotations: &[Annotation],
+) -> Option<TokenStream> {
+    if annotation_value(annotations, "returns_self").is_none() {
+        return None;
+    }
+    if is_static {
+        panic!(
+            "@returns_self can only be used on non-static methods, got {}.",
+            name
+        );
+    }
+    if return_type != class_name {
+        panic!(
+            "@returns_self method {} must return {}, got {}.",
+            name,
+            class_name.clone().with_slashes().replace("/", "."),
+            return_type.clone().with_slashes().replace("/", ".")
+        );
+    }
+    Some(match annotation_nullability(annotations, return_type) {
+        Nullability::Nullable => quote! {::rust_jni::__generator::option::Option<Self>},
+        _ => quote! {Self},
+    })
+}
+
+fn to_generator_method(
+    method: JavaClassMethod,
+    class_name: &JavaName,
+    unchecked_feature: &Option<String>,
+) -> generate::ClassMethod {
     let JavaClassMethod {
         name,
         public,
         return_type,
         arguments,
+        is_synchronized,
+        is_static,
         annotations,
         ..
     } = method;
-    let java_name = Literal::string(&name.to_string());
+    let java_name = Literal::string(&method_java_name(&name, &annotations));
+    let monitor_guard = annotation_value_bool(&annotations, "RustSynchronized").unwrap_or(false);
+    if monitor_guard && !is_synchronized {
+        panic!(
+            "@RustSynchronized can only be used on synchronized methods, got {}.",
+            name
+        );
+    }
+    if annotation_value(&annotations, "throws").is_some()
+        && annotation_value(&annotations, "RustMapResult").is_some()
+    {
+        panic!(
+            "@throws can't be used together with @RustMapResult, got {}.",
+            name
+        );
+    }
+    // Java calls return objects as `Option`-s, so results that are not `@nullable` are checked
+    // for `null`.
+    let nonnull_result = return_type.as_primitive_type().is_none()
+        && annotation_nullability(&annotations, &return_type) != Nullability::Nullable;
+    let return_type =
+        to_rust_self_result_type(&name, &return_type, is_static, class_name, &annotations)
+            .unwrap_or_else(|| to_rust_result_type(return_type, &annotations));
     generate::ClassMethod {
-        name: annotation_value_ident(&annotations, "RustName").unwrap_or(name),
+        name: method_rust_name(name, &annotations),
         java_name,
         public,
-        return_type: return_type.as_rust_type(),
+        monitor_guard,
+        map_result: annotation_value_map_result(&annotations),
+        nonnull_result,
+        unchecked_feature: unchecked_feature.clone(),
+        since: annotation_value_since(&annotations),
+        visibility: annotation_value_visibility(&annotations),
+        throws: annotation_value(&annotations, "throws")
+            .map(|value| JavaName(value).as_rust_type()),
+        return_type,
+        argument_names: arguments
+            .iter()
+            .map(|argument| escape_rust_keyword(argument.name.clone()))
+            .collect(),
+        argument_types: arguments.iter().map(to_rust_argument_type).collect(),
+    }
+}
+
+/// The Rust name of a method: the `@RustName` or the escaped Java name.
+fn method_rust_name(name: Ident, annotations: &[Annotation]) -> Ident {
+    annotation_value_ident(annotations, "RustName").unwrap_or_else(|| escape_rust_keyword(name))
+}
+
+/// The Rust name of a constructor: the `@RustName` or `init`.
+fn constructor_rust_name(annotations: &[Annotation]) -> Ident {
+    annotation_value_ident(annotations, "RustName")
+        .unwrap_or_else(|| Ident::new("init", Span::call_site()))
+}
+
+/// How a value of a Java type is passed through C ABI shims.
+fn to_c_abi_type(data_type: &JavaName, annotations: &[Annotation]) -> generate::CAbiType {
+    match data_type.as_primitive_type() {
+        Some(primitive) => generate::CAbiType::Primitive(primitive),
+        None => generate::CAbiType::Object {
+            class: data_type.clone().as_rust_type_no_lifetime(),
+            nullable: annotation_nullability(annotations, data_type) == Nullability::Nullable,
+        },
+    }
+}
+
+fn to_c_abi_shim(
+    prefix: &str,
+    method: Ident,
+    kind: generate::CAbiShimKind,
+    arguments: &[MethodArgument],
+    return_type: generate::CAbiType,
+) -> generate::CAbiShim {
+    let method_name = method.to_string();
+    // Raw identifiers, like `r#match`, are not keywords with a prefix.
+    let method_name = method_name.trim_start_matches("r#");
+    generate::CAbiShim {
+        name: Ident::new(&format!("{}_{}", prefix, method_name), Span::call_site()),
+        method,
+        kind,
         argument_names: arguments
             .iter()
-            .map(|argument| argument.name.clone())
+            .map(|argument| escape_rust_keyword(argument.name.clone()))
             .collect(),
         argument_types: arguments
             .iter()
-            .map(|argument| argument.data_type.clone().as_rust_type_reference())
+            .map(|argument| to_c_abi_type(&argument.data_type, &argument.annotations))
             .collect(),
+        return_type,
+    }
+}
+
+/// Generate C ABI shims for public constructors and methods of a class selected with the
+/// `c_abi` option. Methods with `@since`, `@throws` or `@RustMapResult`, which return
+/// richer results, are skipped.
+fn to_c_abi(
+    class_name: &JavaName,
+    constructors: &[JavaConstructor],
+    methods: &[JavaClassMethod],
+) -> generate::CAbi {
+    let prefix = class_name.clone().with_underscores();
+    let has_rich_result = |annotations: &[Annotation]| {
+        annotation_value(annotations, "since").is_some()
+            || annotation_value(annotations, "throws").is_some()
+            || annotation_value(annotations, "RustMapResult").is_some()
+    };
+    let constructor_shims = constructors
+        .iter()
+        .filter(|constructor| constructor.public && !has_rich_result(&constructor.annotations))
+        .map(|constructor| {
+            to_c_abi_shim(
+                &prefix,
+                constructor_rust_name(&constructor.annotations),
+                generate::CAbiShimKind::Constructor,
+                &constructor.arguments,
+                generate::CAbiType::Object {
+                    class: class_name.clone().as_rust_type_no_lifetime(),
+                    nullable: false,
+                },
+            )
+        });
+    let method_shims = methods
+        .iter()
+        .filter(|method| method.public && !has_rich_result(&method.annotations))
+        .map(|method| {
+            to_c_abi_shim(
+                &prefix,
+                method_rust_name(method.name.clone(), &method.annotations),
+                if method.is_static {
+                    generate::CAbiShimKind::StaticMethod
+                } else {
+                    generate::CAbiShimKind::Method
+                },
+                &method.arguments,
+                to_c_abi_type(&method.return_type, &method.annotations),
+            )
+        });
+    let shims = constructor_shims.chain(method_shims).collect::<Vec<_>>();
+    let release = Ident::new(&format!("{}_release", prefix), Span::call_site());
+    if let Some(shim) = shims.iter().find(|shim| shim.name == release) {
+        panic!(
+            "C ABI shim of {} conflicts with the release function, rename it with @RustName.",
+            shim.method
+        );
     }
+    generate::CAbi { release, shims }
 }
 
 fn to_generator_interface_method(method: JavaInterfaceMethod) -> generate::InterfaceMethod {
@@ -90,17 +435,16 @@ fn to_generator_interface_method(method: JavaInterfaceMethod) -> generate::Inter
         annotations,
         ..
     } = method;
+    check_no_since(&annotations, "interface and abstract methods", &name);
     generate::InterfaceMethod {
-        name: annotation_value_ident(&annotations, "RustName").unwrap_or(name),
-        return_type: return_type.as_rust_type(),
+        name: annotation_value_ident(&annotations, "RustName")
+            .unwrap_or_else(|| escape_rust_keyword(name)),
+        return_type: to_rust_result_type(return_type, &annotations),
         argument_names: arguments
             .iter()
-            .map(|argument| argument.name.clone())
-            .collect(),
-        argument_types: arguments
-            .iter()
-            .map(|argument| argument.data_type.clone().as_rust_type_reference())
+            .map(|argument| escape_rust_keyword(argument.name.clone()))
             .collect(),
+        argument_types: arguments.iter().map(to_rust_argument_type).collect(),
     }
 }
 
@@ -115,22 +459,28 @@ fn to_generator_interface_method_implementation(
         annotations,
         ..
     } = method;
-    let class_has_method = class_methods.iter().any(|class_method| {
+    let class_method = class_methods.iter().find(|class_method| {
         class_method.name == name
             && class_method.return_type == return_type
             && class_method.arguments == arguments
     });
+    if let Some(class_method) = class_method {
+        check_no_since(
+            &class_method.annotations,
+            "methods implementing interface methods",
+            &name,
+        );
+    }
+    let class_has_method = class_method.is_some();
     generate::InterfaceMethodImplementation {
-        name: annotation_value_ident(&annotations, "RustName").unwrap_or(name),
-        return_type: return_type.as_rust_type(),
+        name: annotation_value_ident(&annotations, "RustName")
+            .unwrap_or_else(|| escape_rust_keyword(name)),
+        return_type: to_rust_result_type(return_type, &annotations),
         argument_names: arguments
             .iter()
-            .map(|argument| argument.name.clone())
-            .collect(),
-        argument_types: arguments
-            .iter()
-            .map(|argument| argument.data_type.clone().as_rust_type_reference())
+            .map(|argument| escape_rust_keyword(argument.name.clone()))
             .collect(),
+        argument_types: arguments.iter().map(to_rust_argument_type).collect(),
         class_has_method,
     }
 }
@@ -157,31 +507,44 @@ fn to_generator_native_method(
         &format!(
             "Java_{}_{}__{}",
             class_name.clone().with_underscores(),
-            name.to_string(),
+            mangle_jni_name(&method_java_name(&name, &annotations)),
             signatures.join("")
         ),
         Span::call_site(),
     );
-    let rust_name = annotation_value_ident(&annotations, "RustName").unwrap_or(name.clone());
+    let has_nullability = |annotations: &[Annotation]| {
+        annotation_value(annotations, "nullable").is_some()
+            || annotation_value(annotations, "nonnull").is_some()
+    };
+    check_no_since(&annotations, "native methods", &name);
+    if has_nullability(&annotations)
+        || arguments
+            .iter()
+            .any(|argument| has_nullability(&argument.annotations))
+    {
+        panic!(
+            "@nullable and @nonnull are not supported on native methods, got {}.",
+            name
+        );
+    }
+    let rust_name = annotation_value_ident(&annotations, "RustName")
+        .unwrap_or_else(|| escape_rust_keyword(name.clone()));
     generate::NativeMethod {
         name,
         rust_name,
         java_name,
         public,
         code,
+        visibility: annotation_value_visibility(&annotations),
         return_type: return_type.as_rust_type(),
         argument_names: arguments
             .iter()
-            .map(|argument| argument.name.clone())
+            .map(|argument| escape_rust_keyword(argument.name.clone()))
             .collect(),
         argument_types: arguments
             .iter()
             .map(|argument| argument.data_type.clone().as_rust_type())
             .collect(),
-        argument_types_no_lifetime: arguments
-            .iter()
-            .map(|argument| argument.data_type.clone().as_rust_type_no_lifetime())
-            .collect(),
     }
 }
 
@@ -192,18 +555,52 @@ fn to_generator_constructor(constructor: JavaConstructor) -> generate::Construct
         annotations,
         ..
     } = constructor;
-    let name = Ident::new("init", Span::call_site());
     generate::Constructor {
-        name: annotation_value_ident(&annotations, "RustName").unwrap_or(name),
+        name: constructor_rust_name(&annotations),
         public,
+        since: annotation_value_since(&annotations),
+        visibility: annotation_value_visibility(&annotations),
         argument_names: arguments
             .iter()
-            .map(|argument| argument.name.clone())
-            .collect(),
-        argument_types: arguments
-            .iter()
-            .map(|argument| argument.data_type.clone().as_rust_type_reference())
+            .map(|argument| escape_rust_keyword(argument.name.clone()))
             .collect(),
+        argument_types: arguments.iter().map(to_rust_argument_type).collect(),
+    }
+}
+
+fn to_abstract_method(method: &JavaClassMethod) -> JavaInterfaceMethod {
+    JavaInterfaceMethod {
+        name: method.name.clone(),
+        return_type: method.return_type.clone(),
+        arguments: method.arguments.clone(),
+        annotations: method.annotations.clone(),
+    }
+}
+
+/// Check that abstract methods are only declared in abstract classes and
+/// that abstract classes are never constructed.
+fn check_abstract(name: &JavaName, class: &JavaClass) {
+    for method in class.methods.iter().filter(|method| method.is_abstract) {
+        if !class.is_abstract {
+            panic!(
+                "Class {} declares abstract method {}, but is not abstract.",
+                name.clone().with_slashes().replace("/", "."),
+                method.name
+            );
+        }
+        if method.is_static {
+            panic!(
+                "Abstract method {} of class {} can't be static.",
+                method.name,
+                name.clone().with_slashes().replace("/", ".")
+            );
+        }
+    }
+    if class.is_abstract && !class.constructors.is_empty() {
+        panic!(
+            "Abstract class {} can't declare constructors as it can't be instantiated.",
+            name.clone().with_slashes().replace("/", ".")
+        );
     }
 }
 
@@ -230,8 +627,86 @@ fn get_interfaces(name: &Option<JavaName>, definitions: &Vec<JavaDefinition>) ->
     }
 }
 
+/// Check that all classes explicitly extend a class that is either `java.lang.Object`
+/// or is defined or declared in metadata.
+fn check_strict_extends(definitions: &JavaDefinitions) {
+    let classes = definitions
+        .definitions
+        .iter()
+        .filter_map(|definition| match definition.definition {
+            JavaDefinitionKind::Class(ref class) => Some((&definition.name, &class.extends)),
+            _ => None,
+        })
+        .chain(
+            definitions
+                .metadata
+                .definitions
+                .iter()
+                .filter_map(|definition| match definition.definition {
+                    JavaDefinitionMetadataKind::Class(ref class) => {
+                        Some((&definition.name, &class.extends))
+                    }
+                    _ => None,
+                }),
+        )
+        .map(|(name, extends)| {
+            (
+                name.clone().with_slashes(),
+                extends.clone().map(|extends| extends.with_slashes()),
+            )
+        })
+        .collect::<Vec<_>>();
+    for (name, extends) in classes.iter() {
+        if name == "java/lang/Object" {
+            continue;
+        }
+        match extends {
+            None => panic!(
+                "Class {} doesn't specify a superclass. With the strict_extends option \
+                 classes need to extend java.lang.Object explicitly.",
+                name.replace("/", ".")
+            ),
+            Some(extends) => {
+                if extends != "java/lang/Object"
+                    && !classes.iter().any(|(class_name, _)| class_name == extends)
+                {
+                    panic!(
+                        "Class {} extends {} which is neither defined nor declared in metadata.",
+                        name.replace("/", "."),
+                        extends.replace("/", ".")
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Check that classes selected with the `c_abi` option are defined.
+fn check_c_abi(definitions: &JavaDefinitions) {
+    for name in &definitions.options.c_abi {
+        let is_defined =
+            definitions
+                .definitions
+                .iter()
+                .any(|definition| match definition.definition {
+                    JavaDefinitionKind::Class(_) => definition.name == *name,
+                    _ => false,
+                });
+        if !is_defined {
+            panic!(
+                "Class {} of the c_abi option is not defined.",
+                name.clone().with_slashes().replace("/", ".")
+            );
+        }
+    }
+}
+
 pub fn to_generator_data(definitions: JavaDefinitions) -> GeneratorData {
-    let mut extends_map = HashMap::new();
+    if definitions.options.strict_extends {
+        check_strict_extends(&definitions);
+    }
+    check_c_abi(&definitions);
+    let mut extends_map = IndexMap::new();
     definitions
         .definitions
         .clone()
@@ -275,7 +750,7 @@ pub fn to_generator_data(definitions: JavaDefinitions) -> GeneratorData {
                 _ => unreachable!(),
             }
         });
-    let mut interface_extends = HashMap::new();
+    let mut interface_extends = IndexMap::new();
     definitions
         .definitions
         .clone()
@@ -291,7 +766,7 @@ pub fn to_generator_data(definitions: JavaDefinitions) -> GeneratorData {
             match definition {
                 JavaDefinitionKind::Interface(interface) => {
                     let JavaInterface { extends, .. } = interface;
-                    let all_extends = interface_extends.entry(name).or_insert(HashSet::new());
+                    let all_extends = interface_extends.entry(name).or_insert(IndexSet::new());
                     extends.into_iter().for_each(|extends_name| {
                         all_extends.insert(extends_name);
                     });
@@ -315,7 +790,7 @@ pub fn to_generator_data(definitions: JavaDefinitions) -> GeneratorData {
             match definition {
                 JavaDefinitionMetadataKind::Interface(interface) => {
                     let JavaInterfaceMetadata { extends, .. } = interface;
-                    let all_extends = interface_extends.entry(name).or_insert(HashSet::new());
+                    let all_extends = interface_extends.entry(name).or_insert(IndexSet::new());
                     extends.into_iter().for_each(|extends_name| {
                         all_extends.insert(extends_name);
                     });
@@ -324,12 +799,34 @@ pub fn to_generator_data(definitions: JavaDefinitions) -> GeneratorData {
             }
         });
     populate_interface_extends(&mut interface_extends);
-    GeneratorData {
+    let mut abstract_methods = IndexMap::new();
+    definitions
+        .definitions
+        .iter()
+        .for_each(|definition| match definition.definition {
+            JavaDefinitionKind::Class(ref class) => {
+                check_abstract(&definition.name, class);
+                if class.is_abstract {
+                    abstract_methods.insert(
+                        definition.name.clone(),
+                        class
+                            .methods
+                            .iter()
+                            .filter(|method| method.is_abstract)
+                            .map(to_abstract_method)
+                            .collect::<Vec<_>>(),
+                    );
+                }
+            }
+            _ => {}
+        });
+    let unchecked_feature = definitions.options.unchecked_methods.clone();
+    let data = GeneratorData {
         definitions: definitions
             .definitions
             .clone()
             .into_iter()
-            .map(|definition| {
+            .flat_map(|definition| {
                 let JavaDefinition {
                     name,
                     public,
@@ -340,13 +837,15 @@ pub fn to_generator_data(definitions: JavaDefinitions) -> GeneratorData {
                 match definition {
                     JavaDefinitionKind::Class(class) => {
                         let JavaClass {
+                            is_abstract,
                             extends,
+                            implements: declared_interfaces,
                             constructors,
                             methods,
                             native_methods,
-                            ..
                         } = class;
                         let mut transitive_extends = vec![];
+                        let mut abstract_classes = vec![name.clone()];
                         let mut current = name.clone();
                         loop {
                             let super_class = extends_map.get(&current);
@@ -354,30 +853,42 @@ pub fn to_generator_data(definitions: JavaDefinitions) -> GeneratorData {
                                 break;
                             }
                             let super_class = super_class.unwrap();
-                            transitive_extends.push(super_class.clone().with_double_colons());
+                            transitive_extends.push(super_class.clone().as_rust_path());
+                            abstract_classes.push(super_class.clone());
                             current = super_class.clone();
                         }
                         let string_signature = name.clone().with_slashes();
                         let signature = Literal::string(&string_signature);
                         let full_signature = Literal::string(&format!("L{};", string_signature));
+                        let binary_name = Literal::string(&string_signature.replace("/", "."));
+                        let super_signature = Literal::string(
+                            &extends
+                                .clone()
+                                .map(|name| name.with_slashes())
+                                .unwrap_or("java/lang/Object".to_owned()),
+                        );
+                        let interface_signatures = declared_interfaces
+                            .into_iter()
+                            .map(|name| Literal::string(&name.with_slashes()))
+                            .collect();
                         let super_class = extends
-                            .map(|name| name.with_double_colons())
-                            .unwrap_or(quote! {::java::lang::Object});
+                            .map(|name| name.as_rust_path())
+                            .unwrap_or(quote! {::rust_jni::java::lang::Object});
                         let implements =
                             get_interfaces(&Some(name.clone()), &definitions.definitions);
-                        let mut implements = implements
+                        // Each interface is followed by the interfaces it extends, in declaration
+                        // order, so that the generated code doesn't depend on hashing.
+                        let implements = implements
                             .iter()
-                            .flat_map(|name| interface_extends.get(&name).unwrap().iter())
-                            .chain(implements.iter())
+                            .flat_map(|name| {
+                                iter::once(name).chain(interface_extends.get(name).unwrap().iter())
+                            })
                             .cloned()
-                            .collect::<HashSet<_>>()
-                            .into_iter()
-                            .collect::<Vec<_>>();
-                        implements.sort_by(|left, right| left.to_string().cmp(&right.to_string()));
+                            .collect::<IndexSet<_>>();
                         let mut implements = implements
                             .into_iter()
                             .map(|name| generate::InterfaceImplementation {
-                                interface: name.clone().with_double_colons(),
+                                interface: name.clone().as_rust_path(),
                                 methods: definitions
                                     .definitions
                                     .iter()
@@ -412,17 +923,42 @@ pub fn to_generator_data(definitions: JavaDefinitions) -> GeneratorData {
                                     .collect(),
                             })
                             .collect::<Vec<_>>();
+                        // Abstract methods of the class and it's superclasses are
+                        // implemented the same way as interface methods.
+                        implements.extend(abstract_classes.into_iter().filter_map(|class_name| {
+                            abstract_methods.get(&class_name).map(|class_methods| {
+                                generate::InterfaceImplementation {
+                                    interface: class_name
+                                        .with_name_suffix("Abstract")
+                                        .as_rust_path(),
+                                    methods: class_methods
+                                        .iter()
+                                        .cloned()
+                                        .map(|method| {
+                                            to_generator_interface_method_implementation(
+                                                method, &methods,
+                                            )
+                                        })
+                                        .collect(),
+                                }
+                            })
+                        }));
+                        let c_abi = if definitions.options.c_abi.contains(&name) {
+                            Some(to_c_abi(&name, &constructors, &methods))
+                        } else {
+                            None
+                        };
                         let static_methods = methods
                             .iter()
                             .filter(|method| method.is_static)
                             .cloned()
-                            .map(to_generator_method)
+                            .map(|method| to_generator_method(method, &name, &unchecked_feature))
                             .collect();
                         let methods = methods
                             .iter()
                             .filter(|method| !method.is_static)
                             .cloned()
-                            .map(to_generator_method)
+                            .map(|method| to_generator_method(method, &name, &unchecked_feature))
                             .collect();
                         let constructors = constructors
                             .into_iter()
@@ -440,7 +976,7 @@ pub fn to_generator_data(definitions: JavaDefinitions) -> GeneratorData {
                             .cloned()
                             .map(|method| to_generator_native_method(method, &name))
                             .collect();
-                        GeneratorDefinition::Class(generate::Class {
+                        let class = GeneratorDefinition::Class(generate::Class {
                             class: definition_name,
                             public,
                             super_class,
@@ -448,104 +984,571 @@ pub fn to_generator_data(definitions: JavaDefinitions) -> GeneratorData {
                             implements,
                             signature,
                             full_signature,
+                            binary_name,
+                            super_signature,
+                            interface_signatures,
                             constructors,
                             methods,
                             static_methods,
                             native_methods,
                             static_native_methods,
-                        })
+                            c_abi,
+                        });
+                        if is_abstract {
+                            let abstract_methods = generate::Interface {
+                                interface: name.clone().with_name_suffix("Abstract").name(),
+                                public,
+                                extends: vec![],
+                                methods: abstract_methods
+                                    .get(&name)
+                                    .unwrap()
+                                    .iter()
+                                    .cloned()
+                                    .map(to_generator_interface_method)
+                                    .collect(),
+                            };
+                            vec![
+                                class,
+                                GeneratorDefinition::AbstractMethods(abstract_methods),
+                            ]
+                        } else {
+                            vec![class]
+                        }
                     }
                     JavaDefinitionKind::Interface(interface) => {
                         let JavaInterface {
-                            methods, extends, ..
+                            methods,
+                            static_methods,
+                            extends,
+                            ..
                         } = interface;
                         let methods = methods
                             .iter()
                             .cloned()
                             .map(to_generator_interface_method)
                             .collect();
-                        GeneratorDefinition::Interface(generate::Interface {
+                        let interface = GeneratorDefinition::Interface(generate::Interface {
                             interface: definition_name,
                             public,
                             methods,
                             extends: extends
                                 .into_iter()
-                                .map(|name| name.with_double_colons())
+                                .map(|name| name.as_rust_path())
                                 .collect(),
-                        })
+                        });
+                        if static_methods.is_empty() {
+                            vec![interface]
+                        } else {
+                            let string_signature = name.clone().with_slashes();
+                            let statics = generate::InterfaceStatics {
+                                name: name.clone().with_name_suffix("Statics").name(),
+                                public,
+                                signature: Literal::string(&string_signature),
+                                full_signature: Literal::string(&format!("L{};", string_signature)),
+                                static_methods: static_methods
+                                    .into_iter()
+                                    .map(|method| {
+                                        to_generator_method(method, &name, &unchecked_feature)
+                                    })
+                                    .collect(),
+                            };
+                            vec![interface, GeneratorDefinition::InterfaceStatics(statics)]
+                        }
                     }
                 }
             })
             .collect(),
+    };
+    check_c_abi_symbols(&data);
+    data
+}
+
+/// Check that C ABI shims don't define the same `#[no_mangle]` symbol twice, which happens
+/// with overloaded methods and with method names that continue the name of another class,
+/// like `test1_init` of `a.b` and `init` of `a.b.test1`.
+fn check_c_abi_symbols(data: &GeneratorData) {
+    let mut symbols = IndexSet::new();
+    for definition in &data.definitions {
+        let c_abi = match definition {
+            GeneratorDefinition::Class(generate::Class {
+                c_abi: Some(c_abi), ..
+            }) => c_abi,
+            _ => continue,
+        };
+        let names = iter::once(&c_abi.release).chain(c_abi.shims.iter().map(|shim| &shim.name));
+        for name in names {
+            if !symbols.insert(name.to_string()) {
+                panic!(
+                    "C ABI symbol {} is defined more than once, rename the method with @RustName.",
+                    name
+                );
+            }
+        }
     }
 }
 
 #[cfg(test)]
-mod to_generator_data_tests {
+mod annotation_value_map_result_tests {
     use super::*;
+    use assert_tokens_equals;
+
+    fn map_result_annotation(value: TokenStream) -> Vec<Annotation> {
+        vec![Annotation {
+            name: Ident::new("RustMapResult", Span::call_site()),
+            value,
+        }]
+    }
 
     #[test]
-    fn empty() {
-        assert_generator_data_equals(
-            to_generator_data(JavaDefinitions {
-                definitions: vec![],
-                metadata: Metadata {
-                    definitions: vec![],
-                },
-            }),
-            GeneratorData {
-                definitions: vec![],
-            },
-        );
+    fn none() {
+        assert!(annotation_value_map_result(&[]).is_none());
     }
 
     #[test]
-    fn metadata_only() {
-        assert_generator_data_equals(
-            to_generator_data(JavaDefinitions {
-                definitions: vec![],
-                metadata: Metadata {
-                    definitions: vec![
-                        JavaDefinitionMetadata {
-                            name: JavaName(quote! {c d test1}),
-                            definition: JavaDefinitionMetadataKind::Interface(
-                                JavaInterfaceMetadata {
-                                    methods: vec![],
-                                    extends: vec![],
-                                },
-                            ),
-                        },
-                        JavaDefinitionMetadata {
-                            name: JavaName(quote! {a b test2}),
-                            definition: JavaDefinitionMetadataKind::Class(JavaClassMetadata {
-                                extends: None,
-                                implements: vec![JavaName(quote! {c d test1})],
-                            }),
-                        },
-                    ],
-                },
-            }),
-            GeneratorData {
-                definitions: vec![],
-            },
+    fn map_result() {
+        let map_result =
+            annotation_value_map_result(&map_result_annotation(quote! {a::b::map -> c::D<'a>}))
+                .unwrap();
+        assert_tokens_equals(map_result.function, quote! {a::b::map});
+        assert_tokens_equals(map_result.result_type, quote! {c::D<'a>});
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected @RustMapResult(path::to::function -> ResultType)")]
+    fn no_result_type() {
+        annotation_value_map_result(&map_result_annotation(quote! {a::b::map}));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected @RustMapResult(path::to::function -> ResultType)")]
+    fn no_function() {
+        annotation_value_map_result(&map_result_annotation(quote! {-> c::D}));
+    }
+}
+
+#[cfg(test)]
+mod annotation_value_since_tests {
+    use super::*;
+
+    fn since_annotation(value: TokenStream) -> Vec<Annotation> {
+        vec![Annotation {
+            name: Ident::new("since", Span::call_site()),
+            value,
+        }]
+    }
+
+    #[test]
+    fn none() {
+        assert!(annotation_value_since(&[]).is_none());
+    }
+
+    #[test]
+    fn since() {
+        assert_eq!(
+            annotation_value_since(&since_annotation(quote! {11}))
+                .unwrap()
+                .to_string(),
+            "11"
         );
     }
 
     #[test]
-    fn one_class() {
-        assert_generator_data_equals(
-            to_generator_data(JavaDefinitions {
-                definitions: vec![JavaDefinition {
-                    name: JavaName(quote! {a b test1}),
-                    public: false,
-                    definition: JavaDefinitionKind::Class(JavaClass {
-                        extends: Some(JavaName(quote! {c d test2})),
-                        implements: vec![],
-                        methods: vec![],
-                        native_methods: vec![],
-                        constructors: vec![],
-                    }),
-                }],
+    #[should_panic(expected = "Expected a Java feature release, like @since(11), got @since(1.8).")]
+    fn not_integer() {
+        annotation_value_since(&since_annotation(quote! {1.8}));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected a Java feature release, like @since(11), got @since().")]
+    fn empty() {
+        annotation_value_since(&since_annotation(quote! {}));
+    }
+}
+
+#[cfg(test)]
+mod annotation_value_visibility_tests {
+    use super::*;
+    use assert_tokens_equals;
+
+    fn visibility_annotation(value: TokenStream) -> Vec<Annotation> {
+        vec![Annotation {
+            name: Ident::new("rust_visibility", Span::call_site()),
+            value,
+        }]
+    }
+
+    #[test]
+    fn none() {
+        assert!(annotation_value_visibility(&[]).is_none());
+    }
+
+    #[test]
+    fn visibility() {
+        for visibility in vec![
+            quote! {},
+            quote! {pub},
+            quote! {pub(crate)},
+            quote! {pub(in a::b)},
+        ] {
+            assert_tokens_equals(
+                annotation_value_visibility(&visibility_annotation(visibility.clone())).unwrap(),
+                visibility,
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected a Rust visibility, like @rust_visibility(pub(crate)), got @rust_visibility(crate)."
+    )]
+    fn invalid() {
+        annotation_value_visibility(&visibility_annotation(quote! {crate}));
+    }
+}
+
+#[cfg(test)]
+mod annotation_nullability_tests {
+    use super::*;
+    use assert_tokens_equals;
+
+    fn annotations(names: &[&str]) -> Vec<Annotation> {
+        names
+            .iter()
+            .map(|name| Annotation {
+                name: Ident::new(name, Span::call_site()),
+                value: TokenStream::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn implicit() {
+        assert_eq!(
+            annotation_nullability(&[], &JavaName(quote! {a b test1})),
+            Nullability::Implicit
+        );
+        assert_tokens_equals(
+            to_rust_result_type(JavaName(quote! {a b test1}), &[]),
+            quote! {crate::a::b::test1<'a>},
+        );
+    }
+
+    #[test]
+    fn nullable() {
+        assert_eq!(
+            annotation_nullability(&annotations(&["nullable"]), &JavaName(quote! {a b test1})),
+            Nullability::Nullable
+        );
+        assert_tokens_equals(
+            to_rust_result_type(JavaName(quote! {a b test1}), &annotations(&["nullable"])),
+            quote! {::rust_jni::__generator::option::Option<crate::a::b::test1<'a> >},
+        );
+        assert_tokens_equals(
+            to_rust_argument_type(&MethodArgument {
+                name: Ident::new("arg1", Span::call_site()),
+                data_type: JavaName(quote! {a b test1}),
+                annotations: annotations(&["nullable"]),
+            }),
+            quote! {::rust_jni::__generator::option::Option<& crate::a::b::test1<'a> >},
+        );
+    }
+
+    #[test]
+    fn nonnull() {
+        assert_eq!(
+            annotation_nullability(&annotations(&["nonnull"]), &JavaName(quote! {a b test1})),
+            Nullability::NonNull
+        );
+        assert_tokens_equals(
+            to_rust_result_type(JavaName(quote! {a b test1}), &annotations(&["nonnull"])),
+            quote! {crate::a::b::test1<'a>},
+        );
+        assert_tokens_equals(
+            to_rust_argument_type(&MethodArgument {
+                name: Ident::new("arg1", Span::call_site()),
+                data_type: JavaName(quote! {a b test1}),
+                annotations: annotations(&["nonnull"]),
+            }),
+            quote! {& crate::a::b::test1<'a>},
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "@nullable and @nonnull can't be used together")]
+    fn nullable_nonnull() {
+        annotation_nullability(
+            &annotations(&["nullable", "nonnull"]),
+            &JavaName(quote! {a b test1}),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Only object types can be @nullable or @nonnull, got long")]
+    fn primitive() {
+        annotation_nullability(&annotations(&["nullable"]), &JavaName(quote! {long}));
+    }
+}
+
+#[cfg(test)]
+mod to_rust_self_result_type_tests {
+    use super::*;
+    use assert_tokens_equals;
+
+    fn annotations(names: &[&str]) -> Vec<Annotation> {
+        names
+            .iter()
+            .map(|name| Annotation {
+                name: Ident::new(name, Span::call_site()),
+                value: TokenStream::new(),
+            })
+            .collect()
+    }
+
+    fn self_result_type(
+        return_type: TokenStream,
+        is_static: bool,
+        annotations: &[Annotation],
+    ) -> Option<TokenStream> {
+        to_rust_self_result_type(
+            &Ident::new("test_method", Span::call_site()),
+            &JavaName(return_type),
+            is_static,
+            &JavaName(quote! {a b test1}),
+            annotations,
+        )
+    }
+
+    #[test]
+    fn no_annotation() {
+        assert!(self_result_type(quote! {a b test1}, false, &[]).is_none());
+    }
+
+    #[test]
+    fn returns_self() {
+        assert_tokens_equals(
+            self_result_type(quote! {a b test1}, false, &annotations(&["returns_self"])).unwrap(),
+            quote! {Self},
+        );
+    }
+
+    #[test]
+    fn returns_self_nullable() {
+        assert_tokens_equals(
+            self_result_type(
+                quote! {a b test1},
+                false,
+                &annotations(&["returns_self", "nullable"]),
+            )
+            .unwrap(),
+            quote! {::rust_jni::__generator::option::Option<Self>},
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "@returns_self method test_method must return a.b.test1, got c.d.test2"
+    )]
+    fn returns_other_class() {
+        self_result_type(quote! {c d test2}, false, &annotations(&["returns_self"]));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "@returns_self can only be used on non-static methods, got test_method"
+    )]
+    fn returns_self_static() {
+        self_result_type(quote! {a b test1}, true, &annotations(&["returns_self"]));
+    }
+}
+
+#[cfg(test)]
+mod escape_rust_keyword_tests {
+    use super::*;
+
+    fn escape(name: &str) -> String {
+        escape_rust_keyword(Ident::new(name, Span::call_site())).to_string()
+    }
+
+    #[test]
+    fn not_keyword() {
+        assert_eq!(escape("method"), "method");
+    }
+
+    #[test]
+    fn keyword() {
+        assert_eq!(escape("match"), "r#match");
+        assert_eq!(escape("fn"), "r#fn");
+        assert_eq!(escape("type"), "r#type");
+    }
+
+    #[test]
+    fn non_raw_keyword() {
+        assert_eq!(escape("self"), "self_");
+        assert_eq!(escape("Self"), "Self_");
+        assert_eq!(escape("crate"), "crate_");
+    }
+
+    #[test]
+    fn keyword_methods() {
+        let mut data = to_generator_data(parse_java_definition(quote! {
+            class a.b.TestClass {
+                public long match(int fn, int self);
+                public static long type(int value);
+                @RustName(type_2) public long type(long value);
+            }
+        }));
+        let generated = generate::generate(&data).to_string();
+        assert!(generated.contains("fn r#match ( & self , r#fn : i32 , self_ : i32 ,"));
+        let class = match data.definitions.remove(0) {
+            GeneratorDefinition::Class(class) => class,
+            _ => unreachable!(),
+        };
+        let names = |methods: &[generate::ClassMethod]| {
+            methods
+                .iter()
+                .map(|method| {
+                    (
+                        method.name.to_string(),
+                        method.java_name.to_string(),
+                        method
+                            .argument_names
+                            .iter()
+                            .map(|name| name.to_string())
+                            .collect::<Vec<_>>(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            names(&class.methods),
+            vec![
+                (
+                    "r#match".to_owned(),
+                    "\"match\"".to_owned(),
+                    vec!["r#fn".to_owned(), "self_".to_owned()]
+                ),
+                (
+                    "type_2".to_owned(),
+                    "\"type\"".to_owned(),
+                    vec!["value".to_owned()]
+                ),
+            ]
+        );
+        assert_eq!(
+            names(&class.static_methods),
+            vec![(
+                "r#type".to_owned(),
+                "\"type\"".to_owned(),
+                vec!["value".to_owned()]
+            )]
+        );
+    }
+}
+
+#[cfg(test)]
+mod literal_name_tests {
+    use super::*;
+
+    #[test]
+    fn literal_names() {
+        let mut data = to_generator_data(parse_java_definition(quote! {
+            class a.b."Outer$Inner" {
+                public long "get$Value"(int value);
+                public native int "native$Method"(int value) {
+                    Ok(value)
+                }
+            }
+        }));
+        let class = match data.definitions.remove(0) {
+            GeneratorDefinition::Class(class) => class,
+            _ => unreachable!(),
+        };
+        assert_eq!(class.class.to_string(), "Outer_Inner");
+        assert_eq!(class.signature.to_string(), "\"a/b/Outer$Inner\"");
+        assert_eq!(class.binary_name.to_string(), "\"a.b.Outer$Inner\"");
+        assert_eq!(class.methods[0].name.to_string(), "get_Value");
+        assert_eq!(class.methods[0].java_name.to_string(), "\"get$Value\"");
+        assert_eq!(
+            class.native_methods[0].rust_name.to_string(),
+            "native_Method"
+        );
+        assert_eq!(
+            class.native_methods[0].java_name.to_string(),
+            "Java_a_b_Outer_00024Inner_native_00024Method__I"
+        );
+    }
+}
+
+#[cfg(test)]
+mod to_generator_data_tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_generator_data_equals(
+            to_generator_data(JavaDefinitions {
+                options: Options::default(),
+                definitions: vec![],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }),
+            GeneratorData {
+                definitions: vec![],
+            },
+        );
+    }
+
+    #[test]
+    fn metadata_only() {
+        assert_generator_data_equals(
+            to_generator_data(JavaDefinitions {
+                options: Options::default(),
+                definitions: vec![],
+                metadata: Metadata {
+                    definitions: vec![
+                        JavaDefinitionMetadata {
+                            name: JavaName(quote! {c d test1}),
+                            definition: JavaDefinitionMetadataKind::Interface(
+                                JavaInterfaceMetadata {
+                                    methods: vec![],
+                                    extends: vec![],
+                                },
+                            ),
+                        },
+                        JavaDefinitionMetadata {
+                            name: JavaName(quote! {a b test2}),
+                            definition: JavaDefinitionMetadataKind::Class(JavaClassMetadata {
+                                extends: None,
+                                implements: vec![JavaName(quote! {c d test1})],
+                            }),
+                        },
+                    ],
+                },
+            }),
+            GeneratorData {
+                definitions: vec![],
+            },
+        );
+    }
+
+    #[test]
+    fn one_class() {
+        assert_generator_data_equals(
+            to_generator_data(JavaDefinitions {
+                options: Options::default(),
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {a b test1}),
+                    public: false,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        is_abstract: false,
+                        extends: Some(JavaName(quote! {c d test2})),
+                        implements: vec![],
+                        methods: vec![],
+                        native_methods: vec![],
+                        constructors: vec![],
+                    }),
+                }],
                 metadata: Metadata {
                     definitions: vec![],
                 },
@@ -554,29 +1557,160 @@ mod to_generator_data_tests {
                 definitions: vec![GeneratorDefinition::Class(generate::Class {
                     class: Ident::new("test1", Span::call_site()),
                     public: false,
-                    super_class: quote! {::c::d::test2},
-                    transitive_extends: vec![quote! {::c::d::test2}],
+                    super_class: quote! {crate::c::d::test2},
+                    transitive_extends: vec![quote! {crate::c::d::test2}],
+                    implements: vec![],
+                    signature: Literal::string("a/b/test1"),
+                    full_signature: Literal::string("La/b/test1;"),
+                    binary_name: Literal::string("a.b.test1"),
+                    super_signature: Literal::string("c/d/test2"),
+                    interface_signatures: vec![],
+                    methods: vec![],
+                    static_methods: vec![],
+                    native_methods: vec![],
+                    static_native_methods: vec![],
+                    c_abi: None,
+                    constructors: vec![],
+                })],
+            },
+        );
+    }
+
+    #[test]
+    fn strict_extends() {
+        assert_generator_data_equals(
+            to_generator_data(JavaDefinitions {
+                options: Options {
+                    strict_extends: true,
+                    java_source: false,
+                    java_stubs: None,
+                    unchecked_methods: None,
+                    c_abi: vec![],
+                },
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {a b test1}),
+                    public: false,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        is_abstract: false,
+                        extends: Some(JavaName(quote! {c d test2})),
+                        implements: vec![],
+                        methods: vec![],
+                        native_methods: vec![],
+                        constructors: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![JavaDefinitionMetadata {
+                        name: JavaName(quote! {c d test2}),
+                        definition: JavaDefinitionMetadataKind::Class(JavaClassMetadata {
+                            extends: Some(JavaName(quote! {java lang Object})),
+                            implements: vec![],
+                        }),
+                    }],
+                },
+            }),
+            GeneratorData {
+                definitions: vec![GeneratorDefinition::Class(generate::Class {
+                    class: Ident::new("test1", Span::call_site()),
+                    public: false,
+                    super_class: quote! {crate::c::d::test2},
+                    transitive_extends: vec![
+                        quote! {crate::c::d::test2},
+                        quote! {::rust_jni::java::lang::Object},
+                    ],
                     implements: vec![],
                     signature: Literal::string("a/b/test1"),
                     full_signature: Literal::string("La/b/test1;"),
+                    binary_name: Literal::string("a.b.test1"),
+                    super_signature: Literal::string("c/d/test2"),
+                    interface_signatures: vec![],
                     methods: vec![],
                     static_methods: vec![],
                     native_methods: vec![],
                     static_native_methods: vec![],
+                    c_abi: None,
                     constructors: vec![],
                 })],
             },
         );
     }
 
+    #[test]
+    #[should_panic(expected = "Class a.b.test1 doesn't specify a superclass")]
+    fn strict_extends_no_extends() {
+        to_generator_data(JavaDefinitions {
+            options: Options {
+                strict_extends: true,
+                java_source: false,
+                java_stubs: None,
+                unchecked_methods: None,
+                c_abi: vec![],
+            },
+            definitions: vec![JavaDefinition {
+                name: JavaName(quote! {a b test1}),
+                public: false,
+                definition: JavaDefinitionKind::Class(JavaClass {
+                    is_abstract: false,
+                    extends: None,
+                    implements: vec![],
+                    methods: vec![],
+                    native_methods: vec![],
+                    constructors: vec![],
+                }),
+            }],
+            metadata: Metadata {
+                definitions: vec![],
+            },
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Class a.b.test1 extends c.d.test2 which is neither defined nor declared"
+    )]
+    fn strict_extends_unknown_superclass() {
+        to_generator_data(JavaDefinitions {
+            options: Options {
+                strict_extends: true,
+                java_source: false,
+                java_stubs: None,
+                unchecked_methods: None,
+                c_abi: vec![],
+            },
+            definitions: vec![JavaDefinition {
+                name: JavaName(quote! {a b test1}),
+                public: false,
+                definition: JavaDefinitionKind::Class(JavaClass {
+                    is_abstract: false,
+                    extends: Some(JavaName(quote! {c d test2})),
+                    implements: vec![],
+                    methods: vec![],
+                    native_methods: vec![],
+                    constructors: vec![],
+                }),
+            }],
+            metadata: Metadata {
+                definitions: vec![JavaDefinitionMetadata {
+                    name: JavaName(quote! {c d test3}),
+                    definition: JavaDefinitionMetadataKind::Class(JavaClassMetadata {
+                        extends: Some(JavaName(quote! {java lang Object})),
+                        implements: vec![],
+                    }),
+                }],
+            },
+        });
+    }
+
     #[test]
     fn one_class_no_extends() {
         assert_generator_data_equals(
             to_generator_data(JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![JavaDefinition {
                     name: JavaName(quote! {a b test1}),
                     public: false,
                     definition: JavaDefinitionKind::Class(JavaClass {
+                        is_abstract: false,
                         extends: None,
                         implements: vec![],
                         methods: vec![],
@@ -592,15 +1726,19 @@ mod to_generator_data_tests {
                 definitions: vec![GeneratorDefinition::Class(generate::Class {
                     class: Ident::new("test1", Span::call_site()),
                     public: false,
-                    super_class: quote! {::java::lang::Object},
-                    transitive_extends: vec![quote! {::java::lang::Object}],
+                    super_class: quote! {::rust_jni::java::lang::Object},
+                    transitive_extends: vec![quote! {::rust_jni::java::lang::Object}],
                     implements: vec![],
                     signature: Literal::string("a/b/test1"),
                     full_signature: Literal::string("La/b/test1;"),
+                    binary_name: Literal::string("a.b.test1"),
+                    super_signature: Literal::string("java/lang/Object"),
+                    interface_signatures: vec![],
                     methods: vec![],
                     static_methods: vec![],
                     native_methods: vec![],
                     static_native_methods: vec![],
+                    c_abi: None,
                     constructors: vec![],
                 })],
             },
@@ -611,11 +1749,13 @@ mod to_generator_data_tests {
     fn one_class_extends_recursive() {
         assert_generator_data_equals(
             to_generator_data(JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![
                     JavaDefinition {
                         name: JavaName(quote! {c d test2}),
                         public: false,
                         definition: JavaDefinitionKind::Class(JavaClass {
+                            is_abstract: false,
                             extends: Some(JavaName(quote! {e f test3})),
                             implements: vec![],
                             methods: vec![],
@@ -627,6 +1767,7 @@ mod to_generator_data_tests {
                         name: JavaName(quote! {a b test1}),
                         public: false,
                         definition: JavaDefinitionKind::Class(JavaClass {
+                            is_abstract: false,
                             extends: Some(JavaName(quote! {c d test2})),
                             implements: vec![],
                             methods: vec![],
@@ -659,38 +1800,46 @@ mod to_generator_data_tests {
                     GeneratorDefinition::Class(generate::Class {
                         class: Ident::new("test2", Span::call_site()),
                         public: false,
-                        super_class: quote! {::e::f::test3},
+                        super_class: quote! {crate::e::f::test3},
                         transitive_extends: vec![
-                            quote! {::e::f::test3},
-                            quote! {::e::f::test4},
-                            quote! {::java::lang::Object},
+                            quote! {crate::e::f::test3},
+                            quote! {crate::e::f::test4},
+                            quote! {::rust_jni::java::lang::Object},
                         ],
                         implements: vec![],
                         signature: Literal::string("c/d/test2"),
                         full_signature: Literal::string("Lc/d/test2;"),
+                        binary_name: Literal::string("c.d.test2"),
+                        super_signature: Literal::string("e/f/test3"),
+                        interface_signatures: vec![],
                         methods: vec![],
                         static_methods: vec![],
                         native_methods: vec![],
                         static_native_methods: vec![],
+                        c_abi: None,
                         constructors: vec![],
                     }),
                     GeneratorDefinition::Class(generate::Class {
                         class: Ident::new("test1", Span::call_site()),
                         public: false,
-                        super_class: quote! {::c::d::test2},
+                        super_class: quote! {crate::c::d::test2},
                         transitive_extends: vec![
-                            quote! {::c::d::test2},
-                            quote! {::e::f::test3},
-                            quote! {::e::f::test4},
-                            quote! {::java::lang::Object},
+                            quote! {crate::c::d::test2},
+                            quote! {crate::e::f::test3},
+                            quote! {crate::e::f::test4},
+                            quote! {::rust_jni::java::lang::Object},
                         ],
                         implements: vec![],
                         signature: Literal::string("a/b/test1"),
                         full_signature: Literal::string("La/b/test1;"),
+                        binary_name: Literal::string("a.b.test1"),
+                        super_signature: Literal::string("c/d/test2"),
+                        interface_signatures: vec![],
                         methods: vec![],
                         static_methods: vec![],
                         native_methods: vec![],
                         static_native_methods: vec![],
+                        c_abi: None,
                         constructors: vec![],
                     }),
                 ],
@@ -702,19 +1851,23 @@ mod to_generator_data_tests {
     fn one_class_implements() {
         assert_generator_data_equals(
             to_generator_data(JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![
                     JavaDefinition {
                         name: JavaName(quote! {e f test4}),
                         public: false,
                         definition: JavaDefinitionKind::Interface(JavaInterface {
                             methods: vec![],
+                            static_methods: vec![],
                             extends: vec![],
+                            extends_generic_arguments: vec![],
                         }),
                     },
                     JavaDefinition {
                         name: JavaName(quote! {a b test1}),
                         public: false,
                         definition: JavaDefinitionKind::Class(JavaClass {
+                            is_abstract: false,
                             extends: None,
                             implements: vec![
                                 JavaName(quote! {e f test3}),
@@ -747,24 +1900,31 @@ mod to_generator_data_tests {
                     GeneratorDefinition::Class(generate::Class {
                         class: Ident::new("test1", Span::call_site()),
                         public: false,
-                        super_class: quote! {::java::lang::Object},
-                        transitive_extends: vec![quote! {::java::lang::Object}],
+                        super_class: quote! {::rust_jni::java::lang::Object},
+                        transitive_extends: vec![quote! {::rust_jni::java::lang::Object}],
                         implements: vec![
                             generate::InterfaceImplementation {
-                                interface: quote! {::e::f::test3},
+                                interface: quote! {crate::e::f::test3},
                                 methods: vec![],
                             },
                             generate::InterfaceImplementation {
-                                interface: quote! {::e::f::test4},
+                                interface: quote! {crate::e::f::test4},
                                 methods: vec![],
                             },
                         ],
                         signature: Literal::string("a/b/test1"),
                         full_signature: Literal::string("La/b/test1;"),
+                        binary_name: Literal::string("a.b.test1"),
+                        super_signature: Literal::string("java/lang/Object"),
+                        interface_signatures: vec![
+                            Literal::string("e/f/test3"),
+                            Literal::string("e/f/test4"),
+                        ],
                         methods: vec![],
                         static_methods: vec![],
                         native_methods: vec![],
                         static_native_methods: vec![],
+                        c_abi: None,
                         constructors: vec![],
                     }),
                 ],
@@ -776,19 +1936,23 @@ mod to_generator_data_tests {
     fn one_class_implements_recursive() {
         assert_generator_data_equals(
             to_generator_data(JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![
                     JavaDefinition {
                         name: JavaName(quote! {e f test3}),
                         public: false,
                         definition: JavaDefinitionKind::Interface(JavaInterface {
                             methods: vec![],
+                            static_methods: vec![],
                             extends: vec![JavaName(quote! {e f test4})],
+                            extends_generic_arguments: vec![],
                         }),
                     },
                     JavaDefinition {
                         name: JavaName(quote! {a b test1}),
                         public: false,
                         definition: JavaDefinitionKind::Class(JavaClass {
+                            is_abstract: false,
                             extends: None,
                             implements: vec![JavaName(quote! {e f test3})],
                             methods: vec![],
@@ -825,34 +1989,38 @@ mod to_generator_data_tests {
                     GeneratorDefinition::Interface(generate::Interface {
                         interface: Ident::new("test3", Span::call_site()),
                         public: false,
-                        extends: vec![quote! {::e::f::test4}],
+                        extends: vec![quote! {crate::e::f::test4}],
                         methods: vec![],
                     }),
                     GeneratorDefinition::Class(generate::Class {
                         class: Ident::new("test1", Span::call_site()),
                         public: false,
-                        super_class: quote! {::java::lang::Object},
-                        transitive_extends: vec![quote! {::java::lang::Object}],
+                        super_class: quote! {::rust_jni::java::lang::Object},
+                        transitive_extends: vec![quote! {::rust_jni::java::lang::Object}],
                         implements: vec![
                             generate::InterfaceImplementation {
-                                interface: quote! {::e::f::test3},
+                                interface: quote! {crate::e::f::test3},
                                 methods: vec![],
                             },
                             generate::InterfaceImplementation {
-                                interface: quote! {::e::f::test4},
+                                interface: quote! {crate::e::f::test4},
                                 methods: vec![],
                             },
                             generate::InterfaceImplementation {
-                                interface: quote! {::g::h::test5},
+                                interface: quote! {crate::g::h::test5},
                                 methods: vec![],
                             },
                         ],
                         signature: Literal::string("a/b/test1"),
                         full_signature: Literal::string("La/b/test1;"),
+                        binary_name: Literal::string("a.b.test1"),
+                        super_signature: Literal::string("java/lang/Object"),
+                        interface_signatures: vec![Literal::string("e/f/test3")],
                         methods: vec![],
                         static_methods: vec![],
                         native_methods: vec![],
                         static_native_methods: vec![],
+                        c_abi: None,
                         constructors: vec![],
                     }),
                 ],
@@ -864,13 +2032,16 @@ mod to_generator_data_tests {
     fn one_class_implements_recursive_duplicated() {
         assert_generator_data_equals(
             to_generator_data(JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![
                     JavaDefinition {
                         name: JavaName(quote! {g h test4}),
                         public: false,
                         definition: JavaDefinitionKind::Interface(JavaInterface {
                             methods: vec![],
+                            static_methods: vec![],
                             extends: vec![],
+                            extends_generic_arguments: vec![],
                         }),
                     },
                     JavaDefinition {
@@ -878,13 +2049,16 @@ mod to_generator_data_tests {
                         public: false,
                         definition: JavaDefinitionKind::Interface(JavaInterface {
                             methods: vec![],
+                            static_methods: vec![],
                             extends: vec![JavaName(quote! {g h test4})],
+                            extends_generic_arguments: vec![],
                         }),
                     },
                     JavaDefinition {
                         name: JavaName(quote! {a b test1}),
                         public: false,
                         definition: JavaDefinitionKind::Class(JavaClass {
+                            is_abstract: false,
                             extends: None,
                             implements: vec![
                                 JavaName(quote! {e f test3}),
@@ -911,30 +2085,37 @@ mod to_generator_data_tests {
                     GeneratorDefinition::Interface(generate::Interface {
                         interface: Ident::new("test3", Span::call_site()),
                         public: false,
-                        extends: vec![quote! {::g::h::test4}],
+                        extends: vec![quote! {crate::g::h::test4}],
                         methods: vec![],
                     }),
                     GeneratorDefinition::Class(generate::Class {
                         class: Ident::new("test1", Span::call_site()),
                         public: false,
-                        super_class: quote! {::java::lang::Object},
-                        transitive_extends: vec![quote! {::java::lang::Object}],
+                        super_class: quote! {::rust_jni::java::lang::Object},
+                        transitive_extends: vec![quote! {::rust_jni::java::lang::Object}],
                         implements: vec![
                             generate::InterfaceImplementation {
-                                interface: quote! {::e::f::test3},
+                                interface: quote! {crate::e::f::test3},
                                 methods: vec![],
                             },
                             generate::InterfaceImplementation {
-                                interface: quote! {::g::h::test4},
+                                interface: quote! {crate::g::h::test4},
                                 methods: vec![],
                             },
                         ],
                         signature: Literal::string("a/b/test1"),
                         full_signature: Literal::string("La/b/test1;"),
+                        binary_name: Literal::string("a.b.test1"),
+                        super_signature: Literal::string("java/lang/Object"),
+                        interface_signatures: vec![
+                            Literal::string("e/f/test3"),
+                            Literal::string("g/h/test4"),
+                        ],
                         methods: vec![],
                         static_methods: vec![],
                         native_methods: vec![],
                         static_native_methods: vec![],
+                        c_abi: None,
                         constructors: vec![],
                     }),
                 ],
@@ -943,13 +2124,110 @@ mod to_generator_data_tests {
     }
 
     #[test]
-    fn one_class_public() {
+    fn one_class_implements_declaration_order() {
         assert_generator_data_equals(
             to_generator_data(JavaDefinitions {
-                definitions: vec![JavaDefinition {
-                    name: JavaName(quote! {a b test1}),
-                    public: true,
-                    definition: JavaDefinitionKind::Class(JavaClass {
+                options: Options::default(),
+                definitions: vec![
+                    JavaDefinition {
+                        name: JavaName(quote! {g h test4}),
+                        public: false,
+                        definition: JavaDefinitionKind::Interface(JavaInterface {
+                            methods: vec![],
+                            static_methods: vec![],
+                            extends: vec![],
+                            extends_generic_arguments: vec![],
+                        }),
+                    },
+                    JavaDefinition {
+                        name: JavaName(quote! {e f test3}),
+                        public: false,
+                        definition: JavaDefinitionKind::Interface(JavaInterface {
+                            methods: vec![],
+                            static_methods: vec![],
+                            extends: vec![],
+                            extends_generic_arguments: vec![],
+                        }),
+                    },
+                    JavaDefinition {
+                        name: JavaName(quote! {a b test1}),
+                        public: false,
+                        definition: JavaDefinitionKind::Class(JavaClass {
+                            is_abstract: false,
+                            extends: None,
+                            implements: vec![
+                                JavaName(quote! {g h test4}),
+                                JavaName(quote! {e f test3}),
+                            ],
+                            methods: vec![],
+                            native_methods: vec![],
+                            constructors: vec![],
+                        }),
+                    },
+                ],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }),
+            GeneratorData {
+                definitions: vec![
+                    GeneratorDefinition::Interface(generate::Interface {
+                        interface: Ident::new("test4", Span::call_site()),
+                        public: false,
+                        extends: vec![],
+                        methods: vec![],
+                    }),
+                    GeneratorDefinition::Interface(generate::Interface {
+                        interface: Ident::new("test3", Span::call_site()),
+                        public: false,
+                        extends: vec![],
+                        methods: vec![],
+                    }),
+                    GeneratorDefinition::Class(generate::Class {
+                        class: Ident::new("test1", Span::call_site()),
+                        public: false,
+                        super_class: quote! {::rust_jni::java::lang::Object},
+                        transitive_extends: vec![quote! {::rust_jni::java::lang::Object}],
+                        implements: vec![
+                            generate::InterfaceImplementation {
+                                interface: quote! {crate::g::h::test4},
+                                methods: vec![],
+                            },
+                            generate::InterfaceImplementation {
+                                interface: quote! {crate::e::f::test3},
+                                methods: vec![],
+                            },
+                        ],
+                        signature: Literal::string("a/b/test1"),
+                        full_signature: Literal::string("La/b/test1;"),
+                        binary_name: Literal::string("a.b.test1"),
+                        super_signature: Literal::string("java/lang/Object"),
+                        interface_signatures: vec![
+                            Literal::string("g/h/test4"),
+                            Literal::string("e/f/test3"),
+                        ],
+                        methods: vec![],
+                        static_methods: vec![],
+                        native_methods: vec![],
+                        static_native_methods: vec![],
+                        c_abi: None,
+                        constructors: vec![],
+                    }),
+                ],
+            },
+        );
+    }
+
+    #[test]
+    fn one_class_public() {
+        assert_generator_data_equals(
+            to_generator_data(JavaDefinitions {
+                options: Options::default(),
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {a b test1}),
+                    public: true,
+                    definition: JavaDefinitionKind::Class(JavaClass {
+                        is_abstract: false,
                         extends: None,
                         implements: vec![],
                         methods: vec![],
@@ -965,31 +2243,383 @@ mod to_generator_data_tests {
                 definitions: vec![GeneratorDefinition::Class(generate::Class {
                     class: Ident::new("test1", Span::call_site()),
                     public: true,
-                    super_class: quote! {::java::lang::Object},
-                    transitive_extends: vec![quote! {::java::lang::Object}],
+                    super_class: quote! {::rust_jni::java::lang::Object},
+                    transitive_extends: vec![quote! {::rust_jni::java::lang::Object}],
                     implements: vec![],
                     signature: Literal::string("a/b/test1"),
                     full_signature: Literal::string("La/b/test1;"),
+                    binary_name: Literal::string("a.b.test1"),
+                    super_signature: Literal::string("java/lang/Object"),
+                    interface_signatures: vec![],
                     methods: vec![],
                     static_methods: vec![],
                     native_methods: vec![],
                     static_native_methods: vec![],
+                    c_abi: None,
                     constructors: vec![],
                 })],
             },
         );
     }
 
+    #[test]
+    fn one_class_abstract() {
+        assert_generator_data_equals(
+            to_generator_data(JavaDefinitions {
+                options: Options::default(),
+                definitions: vec![
+                    JavaDefinition {
+                        name: JavaName(quote! {a b test1}),
+                        public: true,
+                        definition: JavaDefinitionKind::Class(JavaClass {
+                            is_abstract: true,
+                            extends: None,
+                            implements: vec![],
+                            methods: vec![JavaClassMethod {
+                                name: Ident::new("test_method", Span::call_site()),
+                                return_type: JavaName(quote! {long}),
+                                arguments: vec![],
+                                public: false,
+                                is_static: false,
+                                is_synchronized: false,
+                                is_abstract: true,
+                                annotations: vec![],
+                            }],
+                            native_methods: vec![],
+                            constructors: vec![],
+                        }),
+                    },
+                    JavaDefinition {
+                        name: JavaName(quote! {a b test2}),
+                        public: false,
+                        definition: JavaDefinitionKind::Class(JavaClass {
+                            is_abstract: false,
+                            extends: Some(JavaName(quote! {a b test1})),
+                            implements: vec![],
+                            methods: vec![],
+                            native_methods: vec![],
+                            constructors: vec![],
+                        }),
+                    },
+                ],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }),
+            GeneratorData {
+                definitions: vec![
+                    GeneratorDefinition::Class(generate::Class {
+                        class: Ident::new("test1", Span::call_site()),
+                        public: true,
+                        super_class: quote! {::rust_jni::java::lang::Object},
+                        transitive_extends: vec![quote! {::rust_jni::java::lang::Object}],
+                        implements: vec![generate::InterfaceImplementation {
+                            interface: quote! {crate::a::b::test1Abstract},
+                            methods: vec![generate::InterfaceMethodImplementation {
+                                name: Ident::new("test_method", Span::call_site()),
+                                return_type: quote! {i64},
+                                argument_names: vec![],
+                                argument_types: vec![],
+                                class_has_method: true,
+                            }],
+                        }],
+                        signature: Literal::string("a/b/test1"),
+                        full_signature: Literal::string("La/b/test1;"),
+                        binary_name: Literal::string("a.b.test1"),
+                        super_signature: Literal::string("java/lang/Object"),
+                        interface_signatures: vec![],
+                        methods: vec![generate::ClassMethod {
+                            name: Ident::new("test_method", Span::call_site()),
+                            java_name: Literal::string("test_method"),
+                            return_type: quote! {i64},
+                            argument_names: vec![],
+                            argument_types: vec![],
+                            public: false,
+                            monitor_guard: false,
+                            map_result: None,
+                            nonnull_result: false,
+                            unchecked_feature: None,
+                            since: None,
+                            visibility: None,
+                            throws: None,
+                        }],
+                        static_methods: vec![],
+                        native_methods: vec![],
+                        static_native_methods: vec![],
+                        c_abi: None,
+                        constructors: vec![],
+                    }),
+                    GeneratorDefinition::AbstractMethods(generate::Interface {
+                        interface: Ident::new("test1Abstract", Span::call_site()),
+                        public: true,
+                        extends: vec![],
+                        methods: vec![generate::InterfaceMethod {
+                            name: Ident::new("test_method", Span::call_site()),
+                            return_type: quote! {i64},
+                            argument_names: vec![],
+                            argument_types: vec![],
+                        }],
+                    }),
+                    GeneratorDefinition::Class(generate::Class {
+                        class: Ident::new("test2", Span::call_site()),
+                        public: false,
+                        super_class: quote! {crate::a::b::test1},
+                        transitive_extends: vec![
+                            quote! {crate::a::b::test1},
+                            quote! {::rust_jni::java::lang::Object},
+                        ],
+                        implements: vec![generate::InterfaceImplementation {
+                            interface: quote! {crate::a::b::test1Abstract},
+                            methods: vec![generate::InterfaceMethodImplementation {
+                                name: Ident::new("test_method", Span::call_site()),
+                                return_type: quote! {i64},
+                                argument_names: vec![],
+                                argument_types: vec![],
+                                class_has_method: false,
+                            }],
+                        }],
+                        signature: Literal::string("a/b/test2"),
+                        full_signature: Literal::string("La/b/test2;"),
+                        binary_name: Literal::string("a.b.test2"),
+                        super_signature: Literal::string("a/b/test1"),
+                        interface_signatures: vec![],
+                        methods: vec![],
+                        static_methods: vec![],
+                        native_methods: vec![],
+                        static_native_methods: vec![],
+                        c_abi: None,
+                        constructors: vec![],
+                    }),
+                ],
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Class a.b.test1 declares abstract method test_method, but is not abstract"
+    )]
+    fn abstract_method_in_concrete_class() {
+        to_generator_data(JavaDefinitions {
+            options: Options::default(),
+            definitions: vec![JavaDefinition {
+                name: JavaName(quote! {a b test1}),
+                public: false,
+                definition: JavaDefinitionKind::Class(JavaClass {
+                    is_abstract: false,
+                    extends: None,
+                    implements: vec![],
+                    methods: vec![JavaClassMethod {
+                        name: Ident::new("test_method", Span::call_site()),
+                        return_type: JavaName(quote! {long}),
+                        arguments: vec![],
+                        public: false,
+                        is_static: false,
+                        is_synchronized: false,
+                        is_abstract: true,
+                        annotations: vec![],
+                    }],
+                    native_methods: vec![],
+                    constructors: vec![],
+                }),
+            }],
+            metadata: Metadata {
+                definitions: vec![],
+            },
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Abstract method test_method of class a.b.test1 can't be static")]
+    fn abstract_static_method() {
+        to_generator_data(JavaDefinitions {
+            options: Options::default(),
+            definitions: vec![JavaDefinition {
+                name: JavaName(quote! {a b test1}),
+                public: false,
+                definition: JavaDefinitionKind::Class(JavaClass {
+                    is_abstract: true,
+                    extends: None,
+                    implements: vec![],
+                    methods: vec![JavaClassMethod {
+                        name: Ident::new("test_method", Span::call_site()),
+                        return_type: JavaName(quote! {long}),
+                        arguments: vec![],
+                        public: false,
+                        is_static: true,
+                        is_synchronized: false,
+                        is_abstract: true,
+                        annotations: vec![],
+                    }],
+                    native_methods: vec![],
+                    constructors: vec![],
+                }),
+            }],
+            metadata: Metadata {
+                definitions: vec![],
+            },
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Abstract class a.b.test1 can't declare constructors")]
+    fn abstract_class_constructor() {
+        to_generator_data(JavaDefinitions {
+            options: Options::default(),
+            definitions: vec![JavaDefinition {
+                name: JavaName(quote! {a b test1}),
+                public: false,
+                definition: JavaDefinitionKind::Class(JavaClass {
+                    is_abstract: true,
+                    extends: None,
+                    implements: vec![],
+                    methods: vec![],
+                    native_methods: vec![],
+                    constructors: vec![JavaConstructor {
+                        arguments: vec![],
+                        public: true,
+                        annotations: vec![],
+                    }],
+                }),
+            }],
+            metadata: Metadata {
+                definitions: vec![],
+            },
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "@nullable and @nonnull are not supported on native methods")]
+    fn nullable_native_method() {
+        to_generator_data(JavaDefinitions {
+            options: Options::default(),
+            definitions: vec![JavaDefinition {
+                name: JavaName(quote! {a b test1}),
+                public: false,
+                definition: JavaDefinitionKind::Class(JavaClass {
+                    is_abstract: false,
+                    extends: None,
+                    implements: vec![],
+                    methods: vec![],
+                    native_methods: vec![JavaNativeMethod {
+                        name: Ident::new("test_method", Span::call_site()),
+                        return_type: JavaName(quote! {long}),
+                        arguments: vec![MethodArgument {
+                            name: Ident::new("arg1", Span::call_site()),
+                            data_type: JavaName(quote! {a b test1}),
+                            annotations: vec![Annotation {
+                                name: Ident::new("nullable", Span::call_site()),
+                                value: TokenStream::new(),
+                            }],
+                        }],
+                        public: false,
+                        is_static: false,
+                        code: Some(quote! {}),
+                        annotations: vec![],
+                    }],
+                    constructors: vec![],
+                }),
+            }],
+            metadata: Metadata {
+                definitions: vec![],
+            },
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "@since is not supported on native methods, got test_method.")]
+    fn since_native_method() {
+        to_generator_data(JavaDefinitions {
+            options: Options::default(),
+            definitions: vec![JavaDefinition {
+                name: JavaName(quote! {a b test1}),
+                public: false,
+                definition: JavaDefinitionKind::Class(JavaClass {
+                    is_abstract: false,
+                    extends: None,
+                    implements: vec![],
+                    methods: vec![],
+                    native_methods: vec![JavaNativeMethod {
+                        name: Ident::new("test_method", Span::call_site()),
+                        return_type: JavaName(quote! {long}),
+                        arguments: vec![MethodArgument {
+                            name: Ident::new("arg1", Span::call_site()),
+                            data_type: JavaName(quote! {a b test1}),
+                            annotations: vec![],
+                        }],
+                        public: false,
+                        is_static: false,
+                        code: Some(quote! {}),
+                        annotations: vec![Annotation {
+                            name: Ident::new("since", Span::call_site()),
+                            value: quote! {11},
+                        }],
+                    }],
+                    constructors: vec![],
+                }),
+            }],
+            metadata: Metadata {
+                definitions: vec![],
+            },
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "@throws can't be used together with @RustMapResult, got test_method."
+    )]
+    fn throws_map_result() {
+        to_generator_data(JavaDefinitions {
+            options: Options::default(),
+            definitions: vec![JavaDefinition {
+                name: JavaName(quote! {a b test1}),
+                public: false,
+                definition: JavaDefinitionKind::Class(JavaClass {
+                    is_abstract: false,
+                    extends: None,
+                    implements: vec![],
+                    methods: vec![JavaClassMethod {
+                        name: Ident::new("test_method", Span::call_site()),
+                        return_type: JavaName(quote! {long}),
+                        arguments: vec![],
+                        public: false,
+                        is_static: false,
+                        is_synchronized: false,
+                        is_abstract: false,
+                        annotations: vec![
+                            Annotation {
+                                name: Ident::new("throws", Span::call_site()),
+                                value: quote! {c d Exception1},
+                            },
+                            Annotation {
+                                name: Ident::new("RustMapResult", Span::call_site()),
+                                value: quote! {a::b::map -> c::D<'a>},
+                            },
+                        ],
+                    }],
+                    native_methods: vec![],
+                    constructors: vec![],
+                }),
+            }],
+            metadata: Metadata {
+                definitions: vec![],
+            },
+        });
+    }
+
     #[test]
     fn one_interface() {
         assert_generator_data_equals(
             to_generator_data(JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![JavaDefinition {
                     name: JavaName(quote! {a b test1}),
                     public: false,
                     definition: JavaDefinitionKind::Interface(JavaInterface {
                         methods: vec![],
+                        static_methods: vec![],
                         extends: vec![],
+                        extends_generic_arguments: vec![],
                     }),
                 }],
                 metadata: Metadata {
@@ -1011,13 +2641,16 @@ mod to_generator_data_tests {
     fn one_interface_extends() {
         assert_generator_data_equals(
             to_generator_data(JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![
                     JavaDefinition {
                         name: JavaName(quote! {e f test3}),
                         public: false,
                         definition: JavaDefinitionKind::Interface(JavaInterface {
                             methods: vec![],
+                            static_methods: vec![],
                             extends: vec![],
+                            extends_generic_arguments: vec![],
                         }),
                     },
                     JavaDefinition {
@@ -1025,10 +2658,12 @@ mod to_generator_data_tests {
                         public: false,
                         definition: JavaDefinitionKind::Interface(JavaInterface {
                             methods: vec![],
+                            static_methods: vec![],
                             extends: vec![
                                 JavaName(quote! {c d test2}),
                                 JavaName(quote! {e f test3}),
                             ],
+                            extends_generic_arguments: vec![],
                         }),
                     },
                 ],
@@ -1066,9 +2701,144 @@ mod to_generator_data_tests {
                     GeneratorDefinition::Interface(generate::Interface {
                         interface: Ident::new("test1", Span::call_site()),
                         public: false,
-                        extends: vec![quote! {::c::d::test2}, quote! {::e::f::test3}],
+                        extends: vec![quote! {crate::c::d::test2}, quote! {crate::e::f::test3}],
+                        methods: vec![],
+                    }),
+                ],
+            },
+        );
+    }
+
+    #[test]
+    fn one_interface_static_methods() {
+        assert_generator_data_equals(
+            to_generator_data(JavaDefinitions {
+                options: Options::default(),
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {a b test1}),
+                    public: true,
+                    definition: JavaDefinitionKind::Interface(JavaInterface {
+                        methods: vec![],
+                        static_methods: vec![JavaClassMethod {
+                            name: Ident::new("test_method", Span::call_site()),
+                            return_type: JavaName(quote! {long}),
+                            arguments: vec![MethodArgument {
+                                name: Ident::new("arg1", Span::call_site()),
+                                data_type: JavaName(quote! {int}),
+                                annotations: vec![],
+                            }],
+                            public: true,
+                            is_static: true,
+                            is_synchronized: false,
+                            is_abstract: false,
+                            annotations: vec![],
+                        }],
+                        extends: vec![],
+                        extends_generic_arguments: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }),
+            GeneratorData {
+                definitions: vec![
+                    GeneratorDefinition::Interface(generate::Interface {
+                        interface: Ident::new("test1", Span::call_site()),
+                        public: true,
+                        extends: vec![],
                         methods: vec![],
                     }),
+                    GeneratorDefinition::InterfaceStatics(generate::InterfaceStatics {
+                        name: Ident::new("test1Statics", Span::call_site()),
+                        public: true,
+                        signature: Literal::string("a/b/test1"),
+                        full_signature: Literal::string("La/b/test1;"),
+                        static_methods: vec![generate::ClassMethod {
+                            name: Ident::new("test_method", Span::call_site()),
+                            java_name: Literal::string("test_method"),
+                            return_type: quote! {i64},
+                            public: true,
+                            argument_names: vec![Ident::new("arg1", Span::call_site())],
+                            argument_types: vec![quote! {i32}],
+                            monitor_guard: false,
+                            map_result: None,
+                            nonnull_result: false,
+                            unchecked_feature: None,
+                            since: None,
+                            visibility: None,
+                            throws: None,
+                        }],
+                    }),
+                ],
+            },
+        );
+    }
+
+    #[test]
+    fn one_interface_static_methods_unchecked() {
+        assert_generator_data_equals(
+            to_generator_data(JavaDefinitions {
+                options: Options {
+                    unchecked_methods: Some("unchecked".to_owned()),
+                    ..Options::default()
+                },
+                definitions: vec![JavaDefinition {
+                    name: JavaName(quote! {a b test1}),
+                    public: true,
+                    definition: JavaDefinitionKind::Interface(JavaInterface {
+                        methods: vec![],
+                        static_methods: vec![JavaClassMethod {
+                            name: Ident::new("test_method", Span::call_site()),
+                            return_type: JavaName(quote! {long}),
+                            arguments: vec![MethodArgument {
+                                name: Ident::new("arg1", Span::call_site()),
+                                data_type: JavaName(quote! {int}),
+                                annotations: vec![],
+                            }],
+                            public: true,
+                            is_static: true,
+                            is_synchronized: false,
+                            is_abstract: false,
+                            annotations: vec![],
+                        }],
+                        extends: vec![],
+                        extends_generic_arguments: vec![],
+                    }),
+                }],
+                metadata: Metadata {
+                    definitions: vec![],
+                },
+            }),
+            GeneratorData {
+                definitions: vec![
+                    GeneratorDefinition::Interface(generate::Interface {
+                        interface: Ident::new("test1", Span::call_site()),
+                        public: true,
+                        extends: vec![],
+                        methods: vec![],
+                    }),
+                    GeneratorDefinition::InterfaceStatics(generate::InterfaceStatics {
+                        name: Ident::new("test1Statics", Span::call_site()),
+                        public: true,
+                        signature: Literal::string("a/b/test1"),
+                        full_signature: Literal::string("La/b/test1;"),
+                        static_methods: vec![generate::ClassMethod {
+                            name: Ident::new("test_method", Span::call_site()),
+                            java_name: Literal::string("test_method"),
+                            return_type: quote! {i64},
+                            public: true,
+                            argument_names: vec![Ident::new("arg1", Span::call_site())],
+                            argument_types: vec![quote! {i32}],
+                            monitor_guard: false,
+                            map_result: None,
+                            nonnull_result: false,
+                            unchecked_feature: Some("unchecked".to_owned()),
+                            since: None,
+                            visibility: None,
+                            throws: None,
+                        }],
+                    }),
                 ],
             },
         );
@@ -1078,12 +2848,15 @@ mod to_generator_data_tests {
     fn one_interface_public() {
         assert_generator_data_equals(
             to_generator_data(JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![JavaDefinition {
                     name: JavaName(quote! {a b test1}),
                     public: true,
                     definition: JavaDefinitionKind::Interface(JavaInterface {
                         methods: vec![],
+                        static_methods: vec![],
                         extends: vec![],
+                        extends_generic_arguments: vec![],
                     }),
                 }],
                 metadata: Metadata {
@@ -1101,17 +2874,189 @@ mod to_generator_data_tests {
         );
     }
 
+    fn c_abi_definitions(methods: Vec<JavaClassMethod>) -> JavaDefinitions {
+        JavaDefinitions {
+            options: Options {
+                c_abi: vec![JavaName(quote! {a b test1})],
+                ..Options::default()
+            },
+            definitions: vec![JavaDefinition {
+                name: JavaName(quote! {a b test1}),
+                public: true,
+                definition: JavaDefinitionKind::Class(JavaClass {
+                    is_abstract: false,
+                    extends: None,
+                    implements: vec![],
+                    methods,
+                    native_methods: vec![],
+                    constructors: vec![JavaConstructor {
+                        arguments: vec![MethodArgument {
+                            name: Ident::new("arg1", Span::call_site()),
+                            data_type: JavaName(quote! {int}),
+                            annotations: vec![],
+                        }],
+                        public: true,
+                        annotations: vec![],
+                    }],
+                }),
+            }],
+            metadata: Metadata {
+                definitions: vec![],
+            },
+        }
+    }
+
+    fn c_abi_method(name: &str, public: bool, annotations: Vec<Annotation>) -> JavaClassMethod {
+        JavaClassMethod {
+            name: Ident::new(name, Span::call_site()),
+            return_type: JavaName(quote! {long}),
+            arguments: vec![MethodArgument {
+                name: Ident::new("arg1", Span::call_site()),
+                data_type: JavaName(quote! {a b test1}),
+                annotations: vec![Annotation {
+                    name: Ident::new("nullable", Span::call_site()),
+                    value: quote! {},
+                }],
+            }],
+            public,
+            is_static: false,
+            is_synchronized: false,
+            is_abstract: false,
+            annotations,
+        }
+    }
+
+    #[test]
+    fn c_abi() {
+        let data = to_generator_data(c_abi_definitions(vec![
+            c_abi_method("test_method_1", true, vec![]),
+            c_abi_method("test_method_2", false, vec![]),
+            c_abi_method(
+                "test_method_3",
+                true,
+                vec![Annotation {
+                    name: Ident::new("since", Span::call_site()),
+                    value: quote! {11},
+                }],
+            ),
+            JavaClassMethod {
+                name: Ident::new("match", Span::call_site()),
+                return_type: JavaName(quote! {a b test1}),
+                arguments: vec![],
+                public: true,
+                is_static: true,
+                is_synchronized: false,
+                is_abstract: false,
+                annotations: vec![],
+            },
+        ]));
+        let raw_match = escape_rust_keyword(Ident::new("match", Span::call_site()));
+        let c_abi = match data.definitions[0] {
+            GeneratorDefinition::Class(ref class) => &class.c_abi,
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            format!("{:?}", c_abi),
+            format!(
+                "{:?}",
+                Some(generate::CAbi {
+                    release: Ident::new("a_b_test1_release", Span::call_site()),
+                    shims: vec![
+                        generate::CAbiShim {
+                            name: Ident::new("a_b_test1_init", Span::call_site()),
+                            method: Ident::new("init", Span::call_site()),
+                            kind: generate::CAbiShimKind::Constructor,
+                            argument_names: vec![Ident::new("arg1", Span::call_site())],
+                            argument_types: vec![generate::CAbiType::Primitive(quote! {i32})],
+                            return_type: generate::CAbiType::Object {
+                                class: quote! {crate::a::b::test1},
+                                nullable: false,
+                            },
+                        },
+                        generate::CAbiShim {
+                            name: Ident::new("a_b_test1_test_method_1", Span::call_site()),
+                            method: Ident::new("test_method_1", Span::call_site()),
+                            kind: generate::CAbiShimKind::Method,
+                            argument_names: vec![Ident::new("arg1", Span::call_site())],
+                            argument_types: vec![generate::CAbiType::Object {
+                                class: quote! {crate::a::b::test1},
+                                nullable: true,
+                            }],
+                            return_type: generate::CAbiType::Primitive(quote! {i64}),
+                        },
+                        generate::CAbiShim {
+                            name: Ident::new("a_b_test1_match", Span::call_site()),
+                            method: raw_match,
+                            kind: generate::CAbiShimKind::StaticMethod,
+                            argument_names: vec![],
+                            argument_types: vec![],
+                            return_type: generate::CAbiType::Object {
+                                class: quote! {crate::a::b::test1},
+                                nullable: false,
+                            },
+                        },
+                    ],
+                })
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "C ABI shim of release conflicts with the release function")]
+    fn c_abi_release_conflict() {
+        to_generator_data(c_abi_definitions(vec![c_abi_method(
+            "release",
+            true,
+            vec![],
+        )]));
+    }
+
+    #[test]
+    #[should_panic(expected = "C ABI symbol a_b_test1_test_method_1 is defined more than once")]
+    fn c_abi_overloaded_method() {
+        let mut overload = c_abi_method("test_method_1", true, vec![]);
+        overload.arguments = vec![];
+        to_generator_data(c_abi_definitions(vec![
+            c_abi_method("test_method_1", true, vec![]),
+            overload,
+        ]));
+    }
+
+    #[test]
+    #[should_panic(expected = "C ABI symbol a_b_test1_init is defined more than once")]
+    fn c_abi_same_symbol_in_two_classes() {
+        let mut definitions = c_abi_definitions(vec![]);
+        let mut other = c_abi_definitions(vec![c_abi_method("test1_init", true, vec![])])
+            .definitions
+            .remove(0);
+        other.name = JavaName(quote! {a b});
+        definitions.definitions.push(other);
+        definitions.options.c_abi.push(JavaName(quote! {a b}));
+        to_generator_data(definitions);
+    }
+
+    #[test]
+    #[should_panic(expected = "Class a.b.test2 of the c_abi option is not defined.")]
+    fn c_abi_not_defined() {
+        let mut definitions = c_abi_definitions(vec![]);
+        definitions.options.c_abi = vec![JavaName(quote! {a b test2})];
+        to_generator_data(definitions);
+    }
+
     #[test]
     fn multiple() {
         assert_generator_data_equals(
             to_generator_data(JavaDefinitions {
+                options: Options::default(),
                 definitions: vec![
                     JavaDefinition {
                         name: JavaName(quote! {e f test_if1}),
                         public: false,
                         definition: JavaDefinitionKind::Interface(JavaInterface {
                             methods: vec![],
+                            static_methods: vec![],
                             extends: vec![],
+                            extends_generic_arguments: vec![],
                         }),
                     },
                     JavaDefinition {
@@ -1119,13 +3064,16 @@ mod to_generator_data_tests {
                         public: false,
                         definition: JavaDefinitionKind::Interface(JavaInterface {
                             methods: vec![],
+                            static_methods: vec![],
                             extends: vec![],
+                            extends_generic_arguments: vec![],
                         }),
                     },
                     JavaDefinition {
                         name: JavaName(quote! {a b test1}),
                         public: false,
                         definition: JavaDefinitionKind::Class(JavaClass {
+                            is_abstract: false,
                             extends: None,
                             implements: vec![],
                             methods: vec![],
@@ -1137,6 +3085,7 @@ mod to_generator_data_tests {
                         name: JavaName(quote! {test2}),
                         public: false,
                         definition: JavaDefinitionKind::Class(JavaClass {
+                            is_abstract: false,
                             extends: None,
                             implements: vec![],
                             methods: vec![],
@@ -1166,29 +3115,37 @@ mod to_generator_data_tests {
                     GeneratorDefinition::Class(generate::Class {
                         class: Ident::new("test1", Span::call_site()),
                         public: false,
-                        super_class: quote! {::java::lang::Object},
-                        transitive_extends: vec![quote! {::java::lang::Object}],
+                        super_class: quote! {::rust_jni::java::lang::Object},
+                        transitive_extends: vec![quote! {::rust_jni::java::lang::Object}],
                         implements: vec![],
                         signature: Literal::string("a/b/test1"),
                         full_signature: Literal::string("La/b/test1;"),
+                        binary_name: Literal::string("a.b.test1"),
+                        super_signature: Literal::string("java/lang/Object"),
+                        interface_signatures: vec![],
                         methods: vec![],
                         static_methods: vec![],
                         native_methods: vec![],
                         static_native_methods: vec![],
+                        c_abi: None,
                         constructors: vec![],
                     }),
                     GeneratorDefinition::Class(generate::Class {
                         class: Ident::new("test2", Span::call_site()),
                         public: false,
-                        super_class: quote! {::java::lang::Object},
-                        transitive_extends: vec![quote! {::java::lang::Object}],
+                        super_class: quote! {::rust_jni::java::lang::Object},
+                        transitive_extends: vec![quote! {::rust_jni::java::lang::Object}],
                         implements: vec![],
                         signature: Literal::string("test2"),
                         full_signature: Literal::string("Ltest2;"),
+                        binary_name: Literal::string("test2"),
+                        super_signature: Literal::string("java/lang/Object"),
+                        interface_signatures: vec![],
                         methods: vec![],
                         static_methods: vec![],
                         native_methods: vec![],
                         static_native_methods: vec![],
+                        c_abi: None,
                         constructors: vec![],
                     }),
                 ],
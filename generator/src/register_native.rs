@@ -0,0 +1,158 @@
+#[cfg(test)]
+use super::assert_tokens_equals;
+use proc_macro2::*;
+
+/// Arguments of a `#[register_native("a.b.Class", "method", "(I)J")]` attribute.
+#[derive(Debug, PartialEq)]
+struct NativeRegistration {
+    class: String,
+    name: String,
+    signature: String,
+}
+
+pub fn generate_native_registration(attribute: TokenStream, item: TokenStream) -> TokenStream {
+    let NativeRegistration {
+        class,
+        name,
+        signature,
+    } = parse_native_registration(attribute);
+    let function = function_name(&item);
+    let class = Literal::string(&class.replace(".", "/"));
+    let name = Literal::string(&format!("{}\0", name));
+    let signature = Literal::string(&format!("{}\0", signature));
+    quote! {
+        #item
+
+        ::rust_jni::introspection::__inventory::submit! {
+            ::rust_jni::NativeMethodRegistration::new(
+                #class,
+                #name,
+                #signature,
+                #function as *mut ::rust_jni::__generator::ffi::c_void,
+            )
+        }
+    }
+}
+
+fn parse_native_registration(attribute: TokenStream) -> NativeRegistration {
+    let tokens = attribute.into_iter().collect::<Vec<_>>();
+    let arguments = tokens
+        .split(|token| match token {
+            TokenTree::Punct(punct) => punct.as_char() == ',',
+            _ => false,
+        })
+        .filter(|argument| !argument.is_empty())
+        .map(|argument| match argument {
+            [TokenTree::Literal(literal)] => {
+                let value = literal.to_string();
+                if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+                    Some(value[1..value.len() - 1].to_owned())
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>();
+    match arguments {
+        Some(ref arguments) if arguments.len() == 3 => NativeRegistration {
+            class: arguments[0].clone(),
+            name: arguments[1].clone(),
+            signature: arguments[2].clone(),
+        },
+        _ => panic!(
+            "Expected a class name, a method name and a signature, \
+             like #[register_native(\"a.b.Class\", \"method\", \"(I)J\")]."
+        ),
+    }
+}
+
+/// Find the name of the function the attribute is applied to.
+fn function_name(item: &TokenStream) -> Ident {
+    let tokens = item.clone().into_iter().collect::<Vec<_>>();
+    tokens
+        .windows(2)
+        .filter_map(|pair| match (&pair[0], &pair[1]) {
+            (TokenTree::Ident(keyword), TokenTree::Ident(name)) if keyword == "fn" => {
+                Some(name.clone())
+            }
+            _ => None,
+        })
+        .next()
+        .unwrap_or_else(|| panic!("#[register_native] can only be applied to functions."))
+}
+
+#[cfg(test)]
+mod generate_native_registration_tests {
+    use super::*;
+
+    #[test]
+    fn function() {
+        let item = quote! {
+            extern "system" fn native_method(
+                _env: *mut ::jni_sys::JNIEnv,
+                _this: ::jni_sys::jobject,
+                value: ::jni_sys::jint,
+            ) -> ::jni_sys::jlong {
+                value as ::jni_sys::jlong
+            }
+        };
+        let name = Literal::string("nativeMethod\0");
+        let signature = Literal::string("(I)J\0");
+        let expected = quote! {
+            extern "system" fn native_method(
+                _env: *mut ::jni_sys::JNIEnv,
+                _this: ::jni_sys::jobject,
+                value: ::jni_sys::jint,
+            ) -> ::jni_sys::jlong {
+                value as ::jni_sys::jlong
+            }
+
+            ::rust_jni::introspection::__inventory::submit! {
+                ::rust_jni::NativeMethodRegistration::new(
+                    "a/b/TestClass",
+                    #name,
+                    #signature,
+                    native_method as *mut ::rust_jni::__generator::ffi::c_void,
+                )
+            }
+        };
+        assert_tokens_equals(
+            generate_native_registration(quote! {"a.b.TestClass", "nativeMethod", "(I)J"}, item),
+            expected,
+        );
+    }
+
+    #[test]
+    fn trailing_comma() {
+        assert_eq!(
+            parse_native_registration(quote! {"a.b.TestClass", "nativeMethod", "()V",}),
+            NativeRegistration {
+                class: "a.b.TestClass".to_owned(),
+                name: "nativeMethod".to_owned(),
+                signature: "()V".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected a class name, a method name and a signature")]
+    fn too_few_arguments() {
+        parse_native_registration(quote! {"a.b.TestClass", "nativeMethod"});
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected a class name, a method name and a signature")]
+    fn not_a_string() {
+        parse_native_registration(quote! {"a.b.TestClass", nativeMethod, "()V"});
+    }
+
+    #[test]
+    #[should_panic(expected = "#[register_native] can only be applied to functions")]
+    fn not_a_function() {
+        generate_native_registration(
+            quote! {"a.b.TestClass", "nativeMethod", "()V"},
+            quote! {struct Test;},
+        );
+    }
+}
@@ -3,13 +3,6 @@ extern crate jni_sys;
 extern crate rust_jni;
 extern crate rust_jni_generator;
 
-#[cfg(test)]
-mod java {
-    pub mod lang {
-        pub use rust_jni::java::lang::*;
-    }
-}
-
 #[cfg(test)]
 mod e {
     pub mod f {
@@ -17,11 +10,58 @@ mod e {
         use rust_jni_generator::*;
 
         java_generate! {
-            public interface e.f.TestInterface1 {
+            package e.f;
+
+            /// An interface with documentation comments.
+            public interface TestInterface1 {
+                /// A method.
                 @RustName(primitive_interface_func_1)
                 long primitiveInterfaceFunc1(int arg1, char arg2);
+                @RustName(static_interface_func_1)
+                public static long staticInterfaceFunc1(int arg1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod g {
+    pub mod h {
+        #[allow(unused_imports)]
+        use rust_jni_generator::*;
+
+        java_generate! {
+            options { java_source }
+            package g.h;
+
+            /**
+             * A class copied from Java sources.
+             */
+            public class TestClass3<T extends TestClass3<T>> {
+                private final long value;
+
+                public TestClass3(long value) {
+                    this.value = value;
+                }
+
+                @RustName(get_value)
+                public long getValue() {
+                    return value;
+                }
+
+                @Nullable
+                @RustName(get_name)
+                public String getName(T other) {
+                    return null;
+                }
             }
         }
+
+        #[derive(Debug, JavaClassWrapper)]
+        #[java(class = "g/h/TestClass5", extends = ::c::d::TestClass1)]
+        pub struct TestClass5<'env> {
+            object: ::c::d::TestClass1<'env>,
+        }
     }
 }
 
@@ -31,17 +71,57 @@ mod c {
         #[allow(unused_imports)]
         use rust_jni_generator::*;
 
+        fn long_to_usize<'a>(
+            value: i64,
+            _token: &::rust_jni::NoException<'a>,
+        ) -> ::rust_jni::JavaResult<'a, usize> {
+            Ok(value as usize)
+        }
+
         java_generate! {
+            options { c_abi(c.d.TestClass1) }
+
             public interface c.d.TestInterface2 extends e.f.TestInterface1 {}
 
             public class c.d.TestClass1 {
                 public c.d.TestClass1(int arg);
 
                 public long primitiveFunc1(int arg1, char arg2);
-                c.d.TestClass1 objectFunc1(c.d.TestClass1 arg);
+                public short shortFunc1(short arg1);
+                public c.d.TestClass1 objectFunc1(c.d.TestClass1 arg);
+                @nullable
+                public c.d.TestClass1 nullableFunc1(@nullable c.d.TestClass1 arg);
+                @nonnull
+                c.d.TestClass1 nonnullFunc1(@nonnull c.d.TestClass1 arg);
+                @returns_self
+                public c.d.TestClass1 fluentFunc1(int arg1);
 
                 public static long primitiveStaticFunc1(int arg1, char arg2);
-                static c.d.TestClass1 objectStaticFunc1(c.d.TestClass1 arg);
+                public static short shortStaticFunc1(short arg1);
+                public static c.d.TestClass1 objectStaticFunc1(c.d.TestClass1 arg);
+
+                public synchronized long synchronizedFunc1(int arg1);
+                @RustSynchronized(true)
+                public synchronized long guardedFunc1(int arg1);
+                @RustSynchronized(true)
+                public static synchronized long guardedStaticFunc1(int arg1);
+
+                @RustMapResult(long_to_usize -> usize)
+                public long mappedFunc1(int arg1);
+                @RustMapResult(self::long_to_usize -> usize)
+                public static long mappedStaticFunc1(int arg1);
+            }
+            public abstract class c.d.TestAbstractClass1 {
+                public abstract long abstractFunc1(int arg1);
+                public long concreteFunc1(int arg1);
+            }
+            public class c.d.TestClass3 extends c.d.TestAbstractClass1 {
+                public c.d.TestClass3(int arg);
+
+                public long abstractFunc1(int arg1);
+            }
+            public class c.d.TestClass4 extends c.d.TestClass3 {
+                public c.d.TestClass4(int arg);
             }
             public class c.d.TestClass2 extends c.d.TestClass1 implements e.f.TestInterface1 {
                 public c.d.TestClass2(c.d.TestClass1 arg);
@@ -70,6 +150,10 @@ mod a {
         use rust_jni_generator::*;
 
         java_generate! {
+            import a.b.*;
+            import c.d.*;
+            import e.f.TestInterface1;
+
             public interface a.b.TestInterface3 {
                 long primitiveInterfaceFunc3(int arg1, char arg2);
                 a.b.TestClass3 objectInterfaceFunc3(a.b.TestClass3 arg);
@@ -79,10 +163,10 @@ mod a {
                 @RustName(primitive_func_3)
                 long primitiveFunc3(int arg1, char arg2);
                 @RustName(object_func_3)
-                c.d.TestClass2 objectFunc3(a.b.TestClass3 arg);
+                TestClass2 objectFunc3(TestClass3 arg);
             }
 
-            public class a.b.TestClass3 extends c.d.TestClass2 implements e.f.TestInterface1, a.b.TestInterface4 {
+            public class a.b.TestClass3 extends TestClass2 implements TestInterface1, TestInterface4 {
                 @RustName(init)
                 public a.b.TestClass3(int arg1, a.b.TestClass3 arg2);
 
@@ -138,4 +222,77 @@ mod a {
 mod tests {
     #[test]
     fn test() {}
+
+    #[test]
+    fn c_abi() {
+        use jni_sys::{jchar, jint, jlong, jobject, JNIEnv};
+
+        let _: unsafe extern "C" fn(*mut JNIEnv, jint) -> jobject = ::c::d::c_d_TestClass1_init;
+        let _: unsafe extern "C" fn(*mut JNIEnv, jobject, jint, jchar) -> jlong =
+            ::c::d::c_d_TestClass1_primitiveFunc1;
+        let _: unsafe extern "C" fn(*mut JNIEnv, jobject, jobject) -> jobject =
+            ::c::d::c_d_TestClass1_nullableFunc1;
+        let _: unsafe extern "C" fn(*mut JNIEnv, jobject) -> jobject =
+            ::c::d::c_d_TestClass1_objectStaticFunc1;
+        let _: unsafe extern "C" fn(*mut JNIEnv, jobject) = ::c::d::c_d_TestClass1_release;
+    }
+
+    #[test]
+    fn compatible_with() {
+        // Code generated by this version of the generator must be accepted by `rust-jni`.
+        assert_eq!(
+            ::rust_jni::introspection::COMPATIBLE_WITH,
+            concat!(
+                env!("CARGO_PKG_VERSION_MAJOR"),
+                ".",
+                env!("CARGO_PKG_VERSION_MINOR")
+            )
+        );
+    }
+
+    #[test]
+    fn owned_object() {
+        use rust_jni::{JavaClassExt, JavaResult, JniEnv, NoException, OwnedObject};
+
+        // Generated and derived class wrappers can be detached and attached again. Only checks
+        // that the code compiles, calling it needs a Java VM with the test classes.
+        fn round_trip<'a>(
+            value: ::c::d::TestClass1<'a>,
+            env: &'a JniEnv<'a>,
+            token: &NoException<'a>,
+        ) -> JavaResult<'a, ::c::d::TestClass1<'a>> {
+            let owned: OwnedObject<::c::d::TestClass1<'static>> = value.detach_from_env(token)?;
+            owned.attach_to_env(env, token)
+        }
+
+        fn detach_derived<'a>(
+            value: ::g::h::TestClass5<'a>,
+            token: &NoException<'a>,
+        ) -> JavaResult<'a, OwnedObject<::g::h::TestClass5<'static>>> {
+            value.detach_from_env(token)
+        }
+
+        let _ = (round_trip, detach_derived);
+    }
+
+    #[test]
+    fn binding_info() {
+        let mut classes = rust_jni::introspection::all_bindings()
+            .into_iter()
+            .flat_map(|binding| binding.classes.iter().map(|class| class.signature))
+            .collect::<Vec<_>>();
+        classes.sort();
+        assert_eq!(
+            classes,
+            vec![
+                "La/b/TestClass3;",
+                "Lc/d/TestAbstractClass1;",
+                "Lc/d/TestClass1;",
+                "Lc/d/TestClass2;",
+                "Lc/d/TestClass3;",
+                "Lc/d/TestClass4;",
+                "Lg/h/TestClass3;",
+            ]
+        );
+    }
 }
@@ -6,3 +6,12 @@ pub mod lang {
     //! [`java.lang` javadoc](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/lang/package-summary.html)
     pub use rust_jni::java::lang::*;
 }
+
+pub mod util {
+    //! Package java.util.
+    //!
+    //! Contains the collections framework and miscellaneous utility classes.
+    //!
+    //! [`java.util` javadoc](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/util/package-summary.html)
+    pub use rust_jni::java::util::*;
+}
@@ -39,6 +39,11 @@ mod rust_jni_aliases {
         value
     }
 
+    #[allow(dead_code)]
+    fn test_map<'a>(value: ::rust_jni::java::util::Map<'a>) -> ::java::util::Map<'a> {
+        value
+    }
+
     #[test]
     fn test() {}
 }
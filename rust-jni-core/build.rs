@@ -0,0 +1,67 @@
+extern crate walkdir;
+
+use std::env;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    if cfg!(feature = "graalvm") {
+        println!("cargo:rerun-if-env-changed=RUST_JNI_GRAALVM_LIBRARY");
+        if cfg!(feature = "libjvm") {
+            panic!("The `graalvm` and `libjvm` features link different Java VMs and can't be enabled together.");
+        }
+        let library = env::var("RUST_JNI_GRAALVM_LIBRARY").expect(
+            "Set RUST_JNI_GRAALVM_LIBRARY to the path of a GraalVM native-image shared library.",
+        );
+        let library = Path::new(&library);
+        match (library.parent(), library_name(library)) {
+            (Some(path), Some(name)) => {
+                println!("cargo:rustc-link-search=native={}", path.display());
+                println!("cargo:rustc-link-lib=dylib={}", name);
+            }
+            _ => panic!(
+                "RUST_JNI_GRAALVM_LIBRARY is not a path to a shared library: {}",
+                library.display()
+            ),
+        }
+    }
+    if cfg!(feature = "libjvm") {
+        let libjvm_path = env::var("JAVA_HOME").ok().and_then(find_libjvm);
+        match libjvm_path {
+            Some(path) => println!("cargo:rustc-link-search=native={}", path.display()),
+            None => panic!("Failed to find libjvm.so. Try setting JAVA_HOME"),
+        }
+        println!("cargo:rustc-link-lib=dylib=jvm");
+    }
+}
+
+fn find_libjvm(path: impl AsRef<Path>) -> Option<PathBuf> {
+    walkdir::WalkDir::new(path)
+        .follow_links(true)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name().to_str().unwrap_or("") == java_lib_name())
+        .filter_map(|entry| entry.path().parent().map(Into::into))
+        .next()
+}
+
+/// Get the name to link a shared library with from it's file name,
+/// for example `app` for `libapp.so`.
+fn library_name(path: &Path) -> Option<&str> {
+    let name = path.file_stem()?.to_str()?;
+    if cfg!(target_os = "windows") {
+        Some(name)
+    } else {
+        name.strip_prefix("lib")
+    }
+}
+
+// TODO(#15): support Android.
+fn java_lib_name() -> &'static str {
+    if cfg!(target_os = "linux") {
+        "libjvm.so"
+    } else if cfg!(target_os = "windows") {
+        "jvm.dll"
+    } else {
+        "libjvm.dylib"
+    }
+}
@@ -0,0 +1,137 @@
+//! Assertion macros for testing Java bindings.
+
+use crate::java_class::{JavaClass, JavaClassExt};
+use crate::object::Object;
+use crate::throwable::Throwable;
+use crate::token::NoException;
+
+/// Assert that two Java objects are equal according to
+/// [`Object::equals`](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#equals(java.lang.Object)).
+///
+/// Takes the two objects and the [`NoException`](struct.NoException.html) token. Panics with
+/// the string representations of the objects if they are not equal or if `equals` throws.
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::String;
+/// #
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let first = String::new(env, &token, "string")?;
+/// let second = String::new(env, &token, "string")?;
+/// assert_java_eq!(first, second, &token);
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_java_eq {
+    ($left:expr, $right:expr, $token:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => $crate::__assert_java_eq(
+                ::core::convert::AsRef::as_ref(left),
+                ::core::convert::AsRef::as_ref(right),
+                $token,
+            ),
+        }
+    };
+}
+
+/// Assert that an expression returning a [`JavaResult`](type.JavaResult.html) threw an exception
+/// of the given type and return the exception as that type.
+///
+/// Takes the exception type, the expression and the [`NoException`](struct.NoException.html)
+/// token. Panics if the expression didn't throw or threw an exception of a different type.
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::{NullPointerException, String, Throwable};
+/// #
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let exception: NullPointerException = assert_throws!(
+///     NullPointerException,
+///     None::<String>.or_npe(env, &token),
+///     &token
+/// );
+/// let exception: Throwable = exception.into();
+/// assert!(exception.get_message(&token)?.is_none());
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_throws {
+    ($type:ty, $expression:expr, $token:expr $(,)?) => {
+        match $expression {
+            ::core::result::Result::Ok(_) => panic!(
+                "assertion failed: `{}` didn't throw `{}`",
+                stringify!($expression),
+                stringify!($type)
+            ),
+            ::core::result::Result::Err(exception) => {
+                $crate::__downcast_exception::<$type>(exception, $token)
+            }
+        }
+    };
+}
+
+/// Implementation of [`assert_java_eq!`](macro.assert_java_eq.html).
+#[doc(hidden)]
+pub fn __assert_java_eq<'a>(left: &Object<'a>, right: &Object<'a>, token: &NoException<'a>) {
+    match left.value_eq(token, right) {
+        Ok(true) => {}
+        Ok(false) => panic!(
+            "assertion failed: `left.equals(right)`\n  left: `{}`,\n right: `{}`",
+            left, right
+        ),
+        Err(exception) => panic!(
+            "assertion failed: `left.equals(right)` threw `{}`",
+            exception.as_ref()
+        ),
+    }
+}
+
+/// Implementation of [`assert_throws!`](macro.assert_throws.html).
+#[doc(hidden)]
+pub fn __downcast_exception<'a, T>(exception: Throwable<'a>, token: &NoException<'a>) -> T
+where
+    T: JavaClass<'a>,
+{
+    let class = T::class(exception.env(), token).unwrap_or_else(|error| {
+        panic!(
+            "Could not find the class of `{}`: `{}`",
+            T::signature(),
+            error.as_ref()
+        )
+    });
+    if !exception.is_instance_of(token, &class) {
+        panic!(
+            "assertion failed: expected an exception of type `{}`, got `{}`",
+            T::signature(),
+            exception.as_ref()
+        );
+    }
+    // Safe because the exception is an instance of `T`.
+    unsafe { T::from_object(exception.into()) }
+}
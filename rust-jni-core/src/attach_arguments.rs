@@ -1,9 +1,15 @@
+use crate::classes::class_loader::ClassLoader;
 use crate::java_string::*;
+use crate::object_key::ObjectKey;
+use crate::result::JavaResult;
+use crate::token::NoException;
 use crate::version::JniVersion;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi::c_char;
+use core::marker::PhantomData;
+use core::ptr;
 use jni_sys;
-use std::marker::PhantomData;
-use std::os::raw::c_char;
-use std::ptr;
 
 /// Arguments for attaching a thread to the JVM.
 ///
@@ -21,6 +27,7 @@ use std::ptr;
 pub struct AttachArguments {
     version: JniVersion,
     thread_name: Option<String>,
+    context_class_loader: Option<ObjectKey>,
     // TODO(#7): support thread groups.
 }
 
@@ -32,6 +39,7 @@ impl AttachArguments {
         AttachArguments {
             thread_name: None,
             version: version,
+            context_class_loader: None,
         }
     }
 
@@ -42,6 +50,7 @@ impl AttachArguments {
         AttachArguments {
             thread_name: Some(thread_name.into()),
             version: version,
+            context_class_loader: None,
         }
     }
 
@@ -58,6 +67,30 @@ impl AttachArguments {
     pub fn thread_name(&self) -> &Option<String> {
         &self.thread_name
     }
+
+    /// Set the context class loader of the attached thread.
+    ///
+    /// Threads attached from native code don't have an application class loader as their
+    /// context class loader, so application classes can't be found on Android or in application
+    /// servers. A class loader from a thread that can load them can be passed to worker
+    /// threads instead.
+    ///
+    /// [`Thread::setContextClassLoader` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Thread.html#setContextClassLoader(java.lang.ClassLoader))
+    pub fn with_context_class_loader<'a>(
+        self,
+        token: &NoException<'a>,
+        class_loader: &ClassLoader<'a>,
+    ) -> JavaResult<'a, Self> {
+        Ok(AttachArguments {
+            context_class_loader: Some(ObjectKey::new(class_loader, token)?),
+            ..self
+        })
+    }
+
+    /// Return the context class loader to set when attaching a thread to a Java VM.
+    pub fn context_class_loader(&self) -> Option<&ObjectKey> {
+        self.context_class_loader.as_ref()
+    }
 }
 
 #[cfg(test)]
@@ -70,7 +103,8 @@ mod tests {
             AttachArguments::new(JniVersion::V4),
             AttachArguments {
                 thread_name: None,
-                version: JniVersion::V4
+                version: JniVersion::V4,
+                context_class_loader: None,
             }
         );
     }
@@ -82,6 +116,7 @@ mod tests {
             AttachArguments {
                 thread_name: Some("test-name".into()),
                 version: JniVersion::V4,
+                context_class_loader: None,
             }
         );
     }
@@ -91,6 +126,7 @@ mod tests {
         let arguments = AttachArguments {
             version: JniVersion::V4,
             thread_name: None,
+            context_class_loader: None,
         };
         assert_eq!(arguments.version(), JniVersion::V4);
     }
@@ -100,6 +136,7 @@ mod tests {
         let arguments = AttachArguments {
             version: JniVersion::V4,
             thread_name: Some("test-name".into()),
+            context_class_loader: None,
         };
         assert_eq!(arguments.thread_name(), &Some("test-name".to_owned()));
     }
@@ -109,6 +146,7 @@ mod tests {
         let arguments = AttachArguments {
             version: JniVersion::V4,
             thread_name: None,
+            context_class_loader: None,
         };
         assert_eq!(arguments.thread_name(), &None);
     }
@@ -152,7 +190,7 @@ impl AttachArguments {
 #[cfg(test)]
 mod to_raw_tests {
     use super::*;
-    use std::slice;
+    use core::slice;
 
     #[test]
     fn to_raw() {
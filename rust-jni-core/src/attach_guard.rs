@@ -0,0 +1,115 @@
+use crate::env::JniEnv;
+use core::mem::ManuallyDrop;
+use core::ops::Deref;
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// How [`JavaVM::attach_or_current`](struct.JavaVM.html#method.attach_or_current) attaches
+/// threads that are not attached to the Java VM yet.
+///
+/// The policy is set for the whole process with
+/// [`set_attach_policy`](fn.set_attach_policy.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttachPolicy {
+    /// Attach threads with
+    /// [`AttachCurrentThread`](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#attachcurrentthread).
+    ///
+    /// This is the default.
+    Normal,
+    /// Attach threads as daemons with
+    /// [`AttachCurrentThreadAsDaemon`](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#attachcurrentthreadasdaemon),
+    /// so that they don't prevent the Java VM from shutting down.
+    Daemon,
+}
+
+impl AttachPolicy {
+    fn from_raw(value: u8) -> Self {
+        match value {
+            0 => AttachPolicy::Normal,
+            1 => AttachPolicy::Daemon,
+            value => panic!("Unexpected attach policy value {}.", value),
+        }
+    }
+
+    fn to_raw(self) -> u8 {
+        match self {
+            AttachPolicy::Normal => 0,
+            AttachPolicy::Daemon => 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod attach_policy_tests {
+    use super::*;
+
+    #[test]
+    fn to_from_raw() {
+        for policy in &[AttachPolicy::Normal, AttachPolicy::Daemon] {
+            assert_eq!(AttachPolicy::from_raw(policy.to_raw()), *policy);
+        }
+    }
+}
+
+static ATTACH_POLICY: AtomicU8 = AtomicU8::new(0);
+
+/// Set the [`AttachPolicy`](enum.AttachPolicy.html) for the whole process.
+pub fn set_attach_policy(policy: AttachPolicy) {
+    ATTACH_POLICY.store(policy.to_raw(), Ordering::SeqCst);
+}
+
+/// Get the current [`AttachPolicy`](enum.AttachPolicy.html).
+pub fn attach_policy() -> AttachPolicy {
+    AttachPolicy::from_raw(ATTACH_POLICY.load(Ordering::SeqCst))
+}
+
+/// A [`JniEnv`](struct.JniEnv.html) returned by
+/// [`JavaVM::attach_or_current`](struct.JavaVM.html#method.attach_or_current).
+///
+/// Detaches the current thread when
+/// [`drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html#tymethod.drop)-ed only if
+/// the thread was attached by [`attach_or_current`](struct.JavaVM.html#method.attach_or_current).
+/// A thread that was already attached stays attached, so that the code that attached it
+/// keeps working.
+#[derive(Debug)]
+pub struct AttachGuard<'env> {
+    env: ManuallyDrop<JniEnv<'env>>,
+    attached: bool,
+}
+
+impl<'env> AttachGuard<'env> {
+    pub(crate) fn new(env: JniEnv<'env>, attached: bool) -> Self {
+        Self {
+            env: ManuallyDrop::new(env),
+            attached,
+        }
+    }
+
+    /// Check if the thread was attached by
+    /// [`attach_or_current`](struct.JavaVM.html#method.attach_or_current) and will be detached
+    /// when the guard is dropped.
+    #[inline(always)]
+    pub fn attached(&self) -> bool {
+        self.attached
+    }
+}
+
+impl<'env> Deref for AttachGuard<'env> {
+    type Target = JniEnv<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.env
+    }
+}
+
+/// Detach the current thread if it was attached by
+/// [`attach_or_current`](struct.JavaVM.html#method.attach_or_current).
+/// See [`JniEnv`](struct.JniEnv.html#impl-Drop) for details.
+impl Drop for AttachGuard<'_> {
+    fn drop(&mut self) {
+        if self.attached {
+            // Safe because the env is not used after this.
+            unsafe { ManuallyDrop::drop(&mut self.env) };
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! The library providing the JNI invocation API, which creates and looks up Java VMs.
+//!
+//! By default that's `libjvm` of a JDK, linked with the `libjvm` feature.
+//!
+//! With the `graalvm` feature it's a shared library built by GraalVM `native-image`, which starts
+//! an embedded Substrate VM isolate from `JNI_CreateJavaVM` and exposes the same invocation API.
+//! Both backends return regular `JavaVM` and `JNIEnv` pointers, so
+//! [`JavaVM`](../struct.JavaVM.html) and [`JniEnv`](../struct.JniEnv.html) work the same way
+//! on top of either. The build script links the library set in the `RUST_JNI_GRAALVM_LIBRARY`
+//! environment variable.
+//!
+//! Unit tests replace the entry points with mocks generated by `generate_jni_functions_mock!`.
+
+// Both backends export the invocation API under the standard names and signatures, so only
+// the linked library differs.
+pub use jni_sys::{JNI_CreateJavaVM, JNI_GetCreatedJavaVMs, JNI_GetDefaultJavaVMInitArgs};
@@ -0,0 +1,21 @@
+//! `tracing` spans for Java calls.
+//!
+//! Only compiled with the `tracing` feature.
+
+use tracing::span::EnteredSpan;
+
+/// Enter a span for a Java call. The span is exited when the returned guard is dropped.
+///
+/// Spans are named `java_call` and have the `debug` level. The `class` field is the JNI
+/// signature of the class, the `method` and `signature` fields are the name and the JNI
+/// signature of the method.
+#[inline(always)]
+pub(crate) fn enter_call(class: &str, method: &str, signature: &str) -> EnteredSpan {
+    tracing::debug_span!(
+        "java_call",
+        class = class,
+        method = method.trim_end_matches('\0'),
+        signature = signature.trim_end_matches('\0'),
+    )
+    .entered()
+}
@@ -0,0 +1,436 @@
+#[cfg(feature = "std")]
+use crate::class_cache;
+use crate::classes::class_loader;
+use crate::env::JniEnv;
+use crate::java_class::{find_class, JavaClassRef};
+use crate::java_methods::call_method;
+use crate::java_methods::FromObject;
+use crate::java_methods::JniSignature;
+use crate::java_primitives::JavaPrimitiveResultType;
+use crate::java_string::*;
+use crate::jni_bool;
+use crate::jni_methods;
+use crate::object::Object;
+use crate::result::JavaResult;
+use crate::string::String;
+use crate::token::{CallOutcome, NoException};
+use alloc::format;
+use core::ffi::c_char;
+use core::ptr::NonNull;
+use jni_sys;
+
+include!("call_jni_method.rs");
+
+/// A type representing a Java
+/// [`Class`](https://docs.oracle.com/javase/10/docs/api/java/lang/Class.html).
+// TODO: examples.
+#[derive(Debug, Clone)]
+pub struct Class<'env> {
+    object: Object<'env>,
+}
+
+impl<'env> Class<'env> {
+    /// Find an existing Java class by it's name. The name is a fully qualified class or array
+    /// type name.
+    ///
+    /// With the `std` feature found classes are cached by name, so only the first lookup calls
+    /// `FindClass`. See [`set_class_cache_enabled`](../../fn.set_class_cache_enabled.html).
+    ///
+    /// Classes not found by `FindClass` can be loaded with the context class loader of
+    /// the current thread, see
+    /// [`set_context_class_loader_fallback_enabled`](../../fn.set_context_class_loader_fallback_enabled.html).
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#findclass)
+    pub fn find<'a>(
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+        class_name: &str,
+    ) -> JavaResult<'a, Class<'a>> {
+        #[cfg(feature = "std")]
+        {
+            if let Some(class) = class_cache::get(env, token, class_name)? {
+                return Ok(class);
+            }
+        }
+        let java_class_name = to_java_string(class_name);
+        // Safe because the arguments are correct and because `FindClass` throws an exception
+        // before returning `null`.
+        let raw_class = unsafe {
+            call_nullable_jni_method!(
+                env,
+                token,
+                FindClass,
+                java_class_name.as_ptr() as *const c_char
+            )
+        };
+        let class = match raw_class {
+            // Safe because the argument is a valid class reference.
+            Ok(raw_class) => unsafe { Self::from_raw(env, raw_class) },
+            Err(exception) => {
+                class_loader::find_with_context_class_loader(env, token, class_name, exception)?
+            }
+        };
+        #[cfg(feature = "std")]
+        class_cache::insert(token, class_name, &class)?;
+        Ok(class)
+    }
+
+    /// Get the class of a primitive type, like `int.class` in Java.
+    ///
+    /// The class is read from the `TYPE` field of the corresponding wrapper class, for example
+    /// [`Integer.TYPE`](https://docs.oracle.com/javase/10/docs/api/java/lang/Integer.html#TYPE)
+    /// for [`i32`](https://doc.rust-lang.org/std/primitive.i32.html) and
+    /// [`Void.TYPE`](https://docs.oracle.com/javase/10/docs/api/java/lang/Void.html#TYPE)
+    /// for [`()`](https://doc.rust-lang.org/std/primitive.unit.html).
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getstaticobjectfield)
+    pub fn primitive<'a, T>(
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+    ) -> JavaResult<'a, Class<'a>>
+    where
+        T: JavaPrimitiveResultType,
+    {
+        let wrapper_name = match T::signature() {
+            "Z" => "java/lang/Boolean",
+            "B" => "java/lang/Byte",
+            "C" => "java/lang/Character",
+            "S" => "java/lang/Short",
+            "I" => "java/lang/Integer",
+            "J" => "java/lang/Long",
+            "F" => "java/lang/Float",
+            "D" => "java/lang/Double",
+            "V" => "java/lang/Void",
+            signature => unreachable!("Unexpected primitive type signature {}.", signature),
+        };
+        let wrapper_class = Self::find(env, token, wrapper_name)?;
+        // Safe because the arguments are correct and because `GetStaticFieldID` throws
+        // an exception before returning `null`.
+        let field_id = unsafe {
+            call_nullable_jni_method!(
+                env,
+                token,
+                GetStaticFieldID,
+                wrapper_class.raw_object().as_ptr(),
+                "TYPE\0".as_ptr() as *const c_char,
+                "Ljava/lang/Class;\0".as_ptr() as *const c_char
+            )
+        }?;
+        // Safe because the arguments are correct.
+        let raw_class = unsafe {
+            call_jni_method!(
+                env,
+                GetStaticObjectField,
+                wrapper_class.raw_object().as_ptr(),
+                field_id.as_ptr()
+            )
+        };
+        // Should not panic: the `TYPE` fields of wrapper classes are never `null`.
+        // Safe because the argument is a valid class reference.
+        Ok(unsafe { Self::from_raw(env, NonNull::new(raw_class).unwrap()) })
+    }
+
+    /// Get the class of arrays with elements of the given type, like `int[].class` or
+    /// `String[].class` in Java.
+    ///
+    /// The class is found by the array type name built from the JNI signature of the element type,
+    /// for example `[I` for [`i32`](https://doc.rust-lang.org/std/primitive.i32.html) and
+    /// `[Ljava/lang/String;` for [`String`](struct.String.html).
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#findclass)
+    pub fn array_of<'a, T>(
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+    ) -> JavaResult<'a, Class<'a>>
+    where
+        T: JniSignature,
+    {
+        Self::find(env, token, &format!("[{}", T::signature()))
+    }
+
+    /// Get the class of a Java class wrapper, like `String.class` in Java.
+    ///
+    /// Class objects are passed to Java methods that take `Class<?>` parameters, like
+    /// [`Class::isAssignableFrom`](https://docs.oracle.com/javase/10/docs/api/java/lang/Class.html#isAssignableFrom(java.lang.Class)):
+    /// ```
+    /// # use rust_jni::*;
+    /// # use rust_jni::java::lang::{Class, Object, String};
+    /// #
+    /// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+    /// let object_class = Class::of::<Object>(env, &token)?;
+    /// let string_class = Class::of::<String>(env, &token)?;
+    /// // Safe because we ensure correct arguments and return type.
+    /// let assignable = unsafe {
+    ///     call_method::<_, _, _, fn(&Class<'a>) -> bool>(
+    ///         &object_class,
+    ///         &token,
+    ///         "isAssignableFrom\0",
+    ///         (&string_class,),
+    ///     )
+    /// }?;
+    /// assert!(assignable);
+    /// # Ok(token)
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     let init_arguments = InitArguments::default();
+    /// #     let vm = JavaVM::create(&init_arguments).unwrap();
+    /// #     let _ = vm.with_attached(
+    /// #        &AttachArguments::new(init_arguments.version()),
+    /// #        |env: &JniEnv, token: NoException| {
+    /// #            ((), jni_main(env, token).unwrap())
+    /// #        },
+    /// #     );
+    /// # }
+    /// ```
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#findclass)
+    pub fn of<'a, T>(env: &'a JniEnv<'a>, token: &NoException<'a>) -> JavaResult<'a, Class<'a>>
+    where
+        T: JavaClassRef<'a>,
+    {
+        find_class::<T>(env, token)
+    }
+
+    /// Get the parent class of this class. Will return
+    /// [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None) for the
+    /// [`Object`](struct.Object.html) class or any interface.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getsuperclass)
+    pub fn parent(&self, _token: &NoException) -> Option<Class<'env>> {
+        // Safe because the argument is ensured to be correct references by construction.
+        let raw_java_class = unsafe { call_jni_object_method!(self, GetSuperclass) };
+        NonNull::new(raw_java_class).map(|raw_java_class| {
+            // Safe because the argument is ensured to be a correct reference.
+            unsafe { Self::from_raw(self.env(), raw_java_class) }
+        })
+    }
+
+    /// Check if this class is a subtype of the other class.
+    ///
+    /// In Java a class is a subtype of the other class if that other class is a direct or
+    /// an indirect parent of this class or an interface this class or any it's parent is
+    /// implementing.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#isassignablefrom)
+    pub fn is_subtype_of(&self, _token: &NoException, class: &Class) -> bool {
+        // Safe because arguments are ensured to be the correct by construction.
+        let assignable = unsafe {
+            call_jni_object_method!(
+                self,
+                IsAssignableFrom,
+                class.raw_object().as_ptr() as jni_sys::jclass
+            )
+        };
+        jni_bool::to_rust(assignable)
+    }
+
+    /// Get class name
+    ///
+    /// [`Class::getName` javadoc](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/lang/Class.html#getName())
+    pub fn get_name(&self, token: &NoException<'env>) -> JavaResult<'env, Option<String<'env>>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { call_method::<Self, _, _, fn() -> String<'env>>(self, token, "getName\0", ()) }
+    }
+
+    /// Get the Java release the class file of this class was compiled for, like `8` for
+    /// class files of version 52.
+    ///
+    /// The version is read from the class file, which is looked up with
+    /// [`Class::getResourceAsStream`](https://docs.oracle.com/javase/10/docs/api/java/lang/Class.html#getResourceAsStream(java.lang.String)).
+    /// Returns `None` for primitive and array classes and for classes without a class file
+    /// resource, like classes defined at runtime.
+    ///
+    /// Use [`jvm_feature_level`](../../fn.jvm_feature_level.html) to get the release of
+    /// the Java VM itself.
+    pub fn java_version(&self, token: &NoException<'env>) -> JavaResult<'env, Option<u32>> {
+        let env = self.env();
+        // Safe because we ensure correct arguments and return type.
+        let is_array_or_primitive = unsafe {
+            jni_methods::call_primitive_method::<jni_sys::jboolean>(
+                &self.object,
+                token,
+                "isArray\0",
+                "()Z\0",
+                (),
+            )
+        }? == jni_sys::JNI_TRUE
+            || unsafe {
+                jni_methods::call_primitive_method::<jni_sys::jboolean>(
+                    &self.object,
+                    token,
+                    "isPrimitive\0",
+                    "()Z\0",
+                    (),
+                )
+            }? == jni_sys::JNI_TRUE;
+        if is_array_or_primitive {
+            return Ok(None);
+        }
+        let name = match self.get_name(token)? {
+            Some(name) => name.as_string(token),
+            None => return Ok(None),
+        };
+        let resource = String::new(env, token, &format!("/{}.class", name.replace('.', "/")))?;
+        // Safe because we ensure correct arguments and return type.
+        let stream = unsafe {
+            jni_methods::call_object_method(
+                &self.object,
+                token,
+                "getResourceAsStream\0",
+                "(Ljava/lang/String;)Ljava/io/InputStream;\0",
+                (resource.raw_object().as_ptr(),),
+            )
+        }?;
+        let stream = match stream {
+            // Safe because the argument is a valid object reference.
+            Some(stream) => unsafe { Object::from_raw(env, stream) },
+            None => return Ok(None),
+        };
+        let data_stream_class = Class::find(env, token, "java/io/DataInputStream")?;
+        // Safe because we ensure correct arguments.
+        let data_stream = unsafe {
+            jni_methods::call_constructor(
+                &data_stream_class,
+                token,
+                "(Ljava/io/InputStream;)V\0",
+                (stream.raw_object().as_ptr(),),
+            )
+        }?;
+        // Safe because the argument is a valid object reference.
+        let data_stream = unsafe { Object::from_raw(env, data_stream) };
+        let version = read_class_file_version(&data_stream, token);
+        // Safe because we ensure correct arguments and return type.
+        let closed = unsafe {
+            jni_methods::call_primitive_method::<()>(&data_stream, token, "close\0", "()V\0", ())
+        };
+        let version = version?;
+        closed?;
+        // Class file versions start at 45 for Java 1.1.
+        Ok(version.map(|major| major.saturating_sub(44)))
+    }
+
+    /// Unregister native methods of this class, so that they have to be linked or registered
+    /// again before they can be called.
+    ///
+    /// This allows reloading the native library that implements them during development.
+    /// Normal native code should not call this method.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#unregisternatives)
+    pub fn unregister_natives(&self, token: &NoException<'env>) -> JavaResult<'env, ()> {
+        let env = self.env();
+        // Safe because the argument is ensured to be a correct reference by construction.
+        let status = token.with_owned(
+            env,
+            #[inline(always)]
+            |_token| unsafe {
+                CallOutcome::Unknown(call_jni_method!(
+                    env,
+                    UnregisterNatives,
+                    self.raw_object().as_ptr()
+                ))
+            },
+        )?;
+        if status != jni_sys::JNI_OK {
+            panic!(
+                "UnregisterNatives failed with status {} without throwing an exception.",
+                status
+            );
+        }
+        Ok(())
+    }
+
+    /// Unsafe because the argument mught not be a valid class reference.
+    #[inline(always)]
+    pub(crate) unsafe fn from_raw<'a>(
+        env: &'a JniEnv<'a>,
+        raw_class: NonNull<jni_sys::_jobject>,
+    ) -> Class<'a> {
+        Class {
+            object: Object::from_raw(env, raw_class.cast()),
+        }
+    }
+}
+
+/// Read the major version of a class file from a `DataInputStream`.
+///
+/// Returns `None` if the stream doesn't start with the class file magic number.
+fn read_class_file_version<'a>(
+    stream: &Object<'a>,
+    token: &NoException<'a>,
+) -> JavaResult<'a, Option<u32>> {
+    // Safe because we ensure correct arguments and return type.
+    let read_unsigned_short = || unsafe {
+        jni_methods::call_primitive_method::<jni_sys::jint>(
+            stream,
+            token,
+            "readUnsignedShort\0",
+            "()I\0",
+            (),
+        )
+    };
+    // The magic number is `0xCAFEBABE`.
+    if read_unsigned_short()? != 0xCAFE || read_unsigned_short()? != 0xBABE {
+        return Ok(None);
+    }
+    let _minor = read_unsigned_short()?;
+    let major = read_unsigned_short()?;
+    Ok(Some(major as u32))
+}
+
+/// Allow [`Class`](struct.Class.html) to be used in place of an [`Object`](struct.Object.html).
+impl<'env> ::core::ops::Deref for Class<'env> {
+    type Target = Object<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Object<'env>> for Class<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        &self.object
+    }
+}
+
+impl<'a> Into<Object<'a>> for Class<'a> {
+    fn into(self) -> Object<'a> {
+        self.object
+    }
+}
+
+impl<'env> FromObject<'env> for Class<'env> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self { object }
+    }
+}
+
+impl JniSignature for Class<'_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "Ljava/lang/Class;"
+    }
+}
+
+/// Allow comparing [`Class`](struct.Class.html)
+/// to Java objects. Java objects are compared by-reference to preserve
+/// original Java semantics. To compare objects by value, call the
+/// [`value_eq`](struct.Object.html#method.value_eq) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`ref_eq`](struct.Object.html#method.ref_eq) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for Class<'env>
+where
+    T: AsRef<Object<'env>>,
+{
+    #[inline(always)]
+    fn eq(&self, other: &T) -> bool {
+        self.as_ref().eq(other.as_ref())
+    }
+}
@@ -0,0 +1,145 @@
+//! A cache of classes found with [`Class::find`](java/lang/struct.Class.html#method.find).
+//!
+//! Only compiled with the `std` feature.
+
+use crate::class::Class;
+use crate::env::JniEnv;
+use crate::result::JavaResult;
+use crate::token::{CallOutcome, NoException};
+use core::ptr::NonNull;
+use core::sync::atomic::{AtomicBool, Ordering};
+use jni_sys;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+include!("call_jni_method.rs");
+
+/// A weak global reference to a class.
+struct WeakClass(NonNull<jni_sys::_jobject>);
+
+/// Weak global references can be used from any thread.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/design.html#global-and-local-references)
+unsafe impl Send for WeakClass {}
+
+static CLASS_CACHE_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Classes keyed by their names.
+static CLASS_CACHE: Mutex<BTreeMap<String, WeakClass>> = Mutex::new(BTreeMap::new());
+
+/// Get a cached class, if it's cached and wasn't unloaded.
+pub(crate) fn get<'a>(
+    env: &'a JniEnv<'a>,
+    token: &NoException<'a>,
+    class_name: &str,
+) -> JavaResult<'a, Option<Class<'a>>> {
+    if !class_cache_enabled() {
+        return Ok(None);
+    }
+    let mut cache = CLASS_CACHE.lock().unwrap();
+    let weak_class = match cache.get(class_name) {
+        None => return Ok(None),
+        Some(weak_class) => weak_class.0,
+    };
+    // Safe because the argument is a valid weak global reference.
+    // `NewLocalRef` returns `null` for unloaded classes, but can also throw an exception,
+    // so a runtime exception check is needed.
+    let raw_class = token.with_owned(
+        env,
+        #[inline(always)]
+        |_token| unsafe {
+            CallOutcome::Unknown(call_jni_method!(env, NewLocalRef, weak_class.as_ptr()))
+        },
+    )?;
+    match NonNull::new(raw_class) {
+        // Safe because the argument is a valid class reference.
+        Some(raw_class) => Ok(Some(unsafe { Class::from_raw(env, raw_class) })),
+        None => {
+            cache.remove(class_name);
+            // Safe because the argument is a valid weak global reference.
+            unsafe { call_jni_method!(env, DeleteWeakGlobalRef, weak_class.as_ptr()) };
+            #[cfg(feature = "stats")]
+            crate::stats::weak_ref_deleted();
+            Ok(None)
+        }
+    }
+}
+
+/// Cache a class found by it's name.
+pub(crate) fn insert<'a>(
+    token: &NoException<'a>,
+    class_name: &str,
+    class: &Class<'a>,
+) -> JavaResult<'a, ()> {
+    if !class_cache_enabled() {
+        return Ok(());
+    }
+    let env = class.env();
+    // Safe because the argument is ensured to be the correct by construction.
+    // `NewWeakGlobalRef` is supposed to throw an exception before returning `null`,
+    // but not all Java VMs do, so a runtime exception check is needed.
+    let weak_class = token.with_owned(
+        env,
+        #[inline(always)]
+        |_token| unsafe {
+            CallOutcome::Unknown(call_jni_method!(
+                env,
+                NewWeakGlobalRef,
+                class.raw_object().as_ptr()
+            ))
+        },
+    )?;
+    let weak_class = match NonNull::new(weak_class) {
+        None => return Ok(()),
+        Some(weak_class) => weak_class,
+    };
+    #[cfg(feature = "stats")]
+    crate::stats::weak_ref_created();
+    let mut cache = CLASS_CACHE.lock().unwrap();
+    if cache.contains_key(class_name) {
+        // Safe because the argument is a valid weak global reference.
+        unsafe { call_jni_method!(env, DeleteWeakGlobalRef, weak_class.as_ptr()) };
+        #[cfg(feature = "stats")]
+        crate::stats::weak_ref_deleted();
+    } else {
+        cache.insert(class_name.to_owned(), WeakClass(weak_class));
+    }
+    Ok(())
+}
+
+/// Enable or disable caching of classes found with
+/// [`Class::find`](java/lang/struct.Class.html#method.find) for the whole process.
+///
+/// Classes are cached by name, so the cache should be disabled when classes with the same name
+/// are loaded by different class loaders. Disabling the cache doesn't free cached classes, call
+/// [`clear_class_cache`](fn.clear_class_cache.html) for that.
+///
+/// The cache is enabled by default. Only available with the `std` feature.
+pub fn set_class_cache_enabled(enabled: bool) {
+    CLASS_CACHE_ENABLED.store(enabled, Ordering::SeqCst);
+}
+
+/// Check if caching of classes found with
+/// [`Class::find`](java/lang/struct.Class.html#method.find) is enabled.
+///
+/// Only available with the `std` feature.
+pub fn class_cache_enabled() -> bool {
+    CLASS_CACHE_ENABLED.load(Ordering::SeqCst)
+}
+
+/// Forget all cached classes.
+///
+/// Only available with the `std` feature.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#deleteweakglobalref)
+pub fn clear_class_cache(env: &JniEnv) {
+    let mut cache = CLASS_CACHE.lock().unwrap();
+    for (_, weak_class) in cache.iter() {
+        // Safe because the argument is a valid weak global reference.
+        // `DeleteWeakGlobalRef` can be called with a pending exception.
+        unsafe { call_jni_method!(env, DeleteWeakGlobalRef, weak_class.0.as_ptr()) };
+        #[cfg(feature = "stats")]
+        crate::stats::weak_ref_deleted();
+    }
+    cache.clear();
+}
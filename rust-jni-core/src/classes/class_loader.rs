@@ -0,0 +1,244 @@
+use crate::class::Class;
+use crate::env::JniEnv;
+use crate::java_methods::FromObject;
+use crate::java_methods::JniSignature;
+use crate::jni_methods;
+use crate::object::Object;
+use crate::result::JavaResult;
+use crate::string::String;
+use crate::throwable::Throwable;
+use crate::token::NoException;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// A type representing a Java
+/// [`ClassLoader`](https://docs.oracle.com/javase/10/docs/api/java/lang/ClassLoader.html).
+#[derive(Debug, Clone)]
+pub struct ClassLoader<'env> {
+    object: Object<'env>,
+}
+
+impl<'env> ClassLoader<'env> {
+    /// Get the system class loader.
+    ///
+    /// [`ClassLoader::getSystemClassLoader` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/ClassLoader.html#getSystemClassLoader())
+    pub fn system(
+        env: &'env JniEnv<'env>,
+        token: &NoException<'env>,
+    ) -> JavaResult<'env, ClassLoader<'env>> {
+        let class = Class::find(env, token, "java/lang/ClassLoader")?;
+        // Safe because we ensure correct arguments and return type.
+        let class_loader = unsafe {
+            jni_methods::call_static_object_method(
+                &class,
+                token,
+                "getSystemClassLoader\0",
+                "()Ljava/lang/ClassLoader;\0",
+                (),
+            )
+        }?;
+        // `getSystemClassLoader` never returns `null` once the Java VM is started.
+        let class_loader = class_loader.expect("ClassLoader.getSystemClassLoader() returned null.");
+        // Safe because the argument is a valid class loader reference.
+        Ok(unsafe { Self::from_object(Object::from_raw(env, class_loader)) })
+    }
+
+    /// Get the context class loader of the current thread.
+    ///
+    /// Returns [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None)
+    /// if the context class loader is not set.
+    ///
+    /// [`Thread::getContextClassLoader` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Thread.html#getContextClassLoader())
+    pub fn context(
+        env: &'env JniEnv<'env>,
+        token: &NoException<'env>,
+    ) -> JavaResult<'env, Option<ClassLoader<'env>>> {
+        let thread = current_thread(env, token)?;
+        // Safe because we ensure correct arguments and return type.
+        let class_loader = unsafe {
+            jni_methods::call_object_method(
+                &thread,
+                token,
+                "getContextClassLoader\0",
+                "()Ljava/lang/ClassLoader;\0",
+                (),
+            )
+        }?;
+        // Safe because the argument is a valid class loader reference.
+        Ok(class_loader
+            .map(|class_loader| unsafe { Self::from_object(Object::from_raw(env, class_loader)) }))
+    }
+
+    /// Load a class by it's name. Unlike [`Class::find`](struct.Class.html#method.find),
+    /// takes a binary class name, like `java.lang.String`.
+    ///
+    /// [`ClassLoader::loadClass` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/ClassLoader.html#loadClass(java.lang.String))
+    pub fn load_class(
+        &self,
+        token: &NoException<'env>,
+        class_name: &str,
+    ) -> JavaResult<'env, Class<'env>> {
+        let env = self.object.env();
+        let class_name = String::new(env, token, class_name)?;
+        // Safe because we ensure correct arguments and return type.
+        let class = unsafe {
+            jni_methods::call_object_method(
+                &self.object,
+                token,
+                "loadClass\0",
+                "(Ljava/lang/String;)Ljava/lang/Class;\0",
+                (class_name.raw_object().as_ptr(),),
+            )
+        }?;
+        // `loadClass` throws an exception instead of returning `null`.
+        let class = class.expect("ClassLoader.loadClass() returned null.");
+        // Safe because the argument is a valid class reference.
+        Ok(unsafe { Class::from_raw(env, class) })
+    }
+}
+
+/// Get the current Java thread object.
+///
+/// [`Thread::currentThread` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Thread.html#currentThread())
+fn current_thread<'a>(env: &'a JniEnv<'a>, token: &NoException<'a>) -> JavaResult<'a, Object<'a>> {
+    let class = Class::find(env, token, "java/lang/Thread")?;
+    // Safe because we ensure correct arguments and return type.
+    let thread = unsafe {
+        jni_methods::call_static_object_method(
+            &class,
+            token,
+            "currentThread\0",
+            "()Ljava/lang/Thread;\0",
+            (),
+        )
+    }?;
+    // `currentThread` never returns `null` in an attached thread.
+    let thread = thread.expect("Thread.currentThread() returned null.");
+    // Safe because the argument is a valid thread reference.
+    Ok(unsafe { Object::from_raw(env, thread) })
+}
+
+/// Set the context class loader of the current thread.
+///
+/// [`Thread::setContextClassLoader` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Thread.html#setContextClassLoader(java.lang.ClassLoader))
+pub(crate) fn set_context_class_loader<'a>(
+    env: &'a JniEnv<'a>,
+    token: &NoException<'a>,
+    class_loader: &ClassLoader<'a>,
+) -> JavaResult<'a, ()> {
+    let thread = current_thread(env, token)?;
+    // Safe because we ensure correct arguments and return type.
+    unsafe {
+        jni_methods::call_primitive_method(
+            &thread,
+            token,
+            "setContextClassLoader\0",
+            "(Ljava/lang/ClassLoader;)V\0",
+            (class_loader.raw_object().as_ptr(),),
+        )
+    }
+}
+
+static CONTEXT_CLASS_LOADER_FALLBACK_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable loading classes with the context class loader of the current thread when
+/// [`Class::find`](java/lang/struct.Class.html#method.find) can't find them.
+///
+/// `FindClass` looks up classes in the class loader of the native method that called it or in
+/// the system class loader for threads attached from native code. The latter can't find
+/// application classes on Android or in application servers. With the fallback enabled such
+/// classes are loaded with
+/// `Thread.currentThread().getContextClassLoader().loadClass()`, see
+/// [`AttachArguments::with_context_class_loader`](struct.AttachArguments.html#method.with_context_class_loader).
+///
+/// The fallback is disabled by default.
+pub fn set_context_class_loader_fallback_enabled(enabled: bool) {
+    CONTEXT_CLASS_LOADER_FALLBACK_ENABLED.store(enabled, Ordering::SeqCst);
+}
+
+/// Check if [`Class::find`](java/lang/struct.Class.html#method.find) falls back to the context
+/// class loader of the current thread.
+/// See [`set_context_class_loader_fallback_enabled`](fn.set_context_class_loader_fallback_enabled.html).
+pub fn context_class_loader_fallback_enabled() -> bool {
+    CONTEXT_CLASS_LOADER_FALLBACK_ENABLED.load(Ordering::SeqCst)
+}
+
+/// Load a class that `FindClass` couldn't find with the context class loader of
+/// the current thread.
+///
+/// Returns the original exception if the fallback is disabled, the class name is an array type
+/// name or the class can't be loaded.
+pub(crate) fn find_with_context_class_loader<'a>(
+    env: &'a JniEnv<'a>,
+    token: &NoException<'a>,
+    class_name: &str,
+    exception: Throwable<'a>,
+) -> JavaResult<'a, Class<'a>> {
+    if !context_class_loader_fallback_enabled() || class_name.starts_with('[') {
+        return Err(exception);
+    }
+    let not_found = Class::find(env, token, "java/lang/NoClassDefFoundError")?;
+    if !exception.is_instance_of(token, &not_found) {
+        return Err(exception);
+    }
+    match ClassLoader::context(env, token)? {
+        None => Err(exception),
+        Some(class_loader) => class_loader
+            .load_class(token, &class_name.replace('/', "."))
+            .or(Err(exception)),
+    }
+}
+
+/// Allow [`ClassLoader`](struct.ClassLoader.html) to be used in place of an [`Object`](struct.Object.html).
+impl<'env> ::core::ops::Deref for ClassLoader<'env> {
+    type Target = Object<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Object<'env>> for ClassLoader<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        &self.object
+    }
+}
+
+impl<'a> Into<Object<'a>> for ClassLoader<'a> {
+    fn into(self) -> Object<'a> {
+        self.object
+    }
+}
+
+impl<'env> FromObject<'env> for ClassLoader<'env> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self { object }
+    }
+}
+
+impl JniSignature for ClassLoader<'_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "Ljava/lang/ClassLoader;"
+    }
+}
+
+/// Allow comparing [`ClassLoader`](struct.ClassLoader.html) to Java objects. Java objects are
+/// compared by-reference to preserve original Java semantics. To compare objects by value, call
+/// the [`value_eq`](struct.Object.html#method.value_eq) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`ref_eq`](struct.Object.html#method.ref_eq) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for ClassLoader<'env>
+where
+    T: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &T) -> bool {
+        self.as_ref().eq(other.as_ref())
+    }
+}
@@ -1,7 +1,13 @@
+use crate::env::JniEnv;
+use crate::java_class::JavaExceptionClass;
+use crate::java_methods::call_constructor;
 use crate::java_methods::FromObject;
 use crate::java_methods::JniSignature;
 use crate::object::Object;
+use crate::result::JavaResult;
+use crate::string::String;
 use crate::throwable::Throwable;
+use crate::token::NoException;
 
 /// A type representing a Java
 /// [`Exception`](https://docs.oracle.com/javase/10/docs/api/java/lang/Exception.html).
@@ -10,9 +16,35 @@ pub struct Exception<'env> {
     pub(crate) object: Throwable<'env>,
 }
 
-/// Allow [`Exception`](struct.Exception.html) to be used in place of an [`Object`](struct.Object.html).
-impl<'env> ::std::ops::Deref for Exception<'env> {
-    type Target = Object<'env>;
+impl<'this> Exception<'this> {
+    /// Create a new [`Exception`](struct.Exception.html).
+    ///
+    /// [`Exception()` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Exception.html#<init>())
+    pub fn new(
+        env: &'this JniEnv<'this>,
+        token: &NoException<'this>,
+    ) -> JavaResult<'this, Exception<'this>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { call_constructor::<Self, _, fn()>(env, token, ()) }
+    }
+
+    /// Create a new [`Exception`](struct.Exception.html) with a message.
+    ///
+    /// [`Exception(String)` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Exception.html#<init>(java.lang.String))
+    pub fn new_with_message(
+        env: &'this JniEnv<'this>,
+        token: &NoException<'this>,
+        message: &String<'this>,
+    ) -> JavaResult<'this, Exception<'this>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { call_constructor::<Self, _, fn(&String<'this>)>(env, token, (message,)) }
+    }
+}
+
+/// Allow [`Exception`](struct.Exception.html) to be used in place of a
+/// [`Throwable`](struct.Throwable.html).
+impl<'env> ::core::ops::Deref for Exception<'env> {
+    type Target = Throwable<'env>;
 
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
@@ -23,7 +55,7 @@ impl<'env> ::std::ops::Deref for Exception<'env> {
 impl<'env> AsRef<Object<'env>> for Exception<'env> {
     #[inline(always)]
     fn as_ref(&self) -> &Object<'env> {
-        &self.object
+        self.object.as_ref()
     }
 }
 
@@ -55,15 +87,17 @@ impl JniSignature for Exception<'_> {
     }
 }
 
+impl<'env> JavaExceptionClass<'env> for Exception<'env> {}
+
 /// Allow comparing [`Exception`](struct.Exception.html)
 /// to Java objects. Java objects are compared by-reference to preserve
 /// original Java semantics. To compare objects by value, call the
-/// [`equals`](struct.Object.html#method.equals) method.
+/// [`value_eq`](struct.Object.html#method.value_eq) method.
 ///
 /// Will panic if there is a pending exception in the current thread.
 ///
 /// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
-/// [`is_same_as`](struct.Object.html#methods.is_same_as) to comparing with `==`, because
+/// [`ref_eq`](struct.Object.html#method.ref_eq) to comparing with `==`, because
 /// the former checks for a pending exception in compile-time rather than the run-time.
 impl<'env, T> PartialEq<T> for Exception<'env>
 where
@@ -1,2 +1,4 @@
+pub mod class_loader;
 pub mod exception;
 pub mod null_pointer_exception;
+pub mod runtime_exception;
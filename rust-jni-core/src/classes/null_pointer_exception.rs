@@ -1,10 +1,13 @@
 use crate::classes::exception::Exception;
+use crate::classes::runtime_exception::RuntimeException;
 use crate::env::JniEnv;
+use crate::java_class::JavaExceptionClass;
 use crate::java_methods::call_constructor;
 use crate::java_methods::FromObject;
 use crate::java_methods::JniSignature;
 use crate::object::Object;
 use crate::result::JavaResult;
+use crate::string::String;
 use crate::throwable::Throwable;
 use crate::token::NoException;
 
@@ -12,7 +15,7 @@ use crate::token::NoException;
 /// [`NullPointerException`](https://docs.oracle.com/javase/10/docs/api/java/lang/NullPointerException.html).
 #[derive(Debug, Clone)]
 pub struct NullPointerException<'env> {
-    pub(crate) object: Exception<'env>,
+    pub(crate) object: RuntimeException<'env>,
 }
 
 impl<'this> NullPointerException<'this> {
@@ -26,12 +29,24 @@ impl<'this> NullPointerException<'this> {
         // Safe because we ensure correct arguments and return type.
         unsafe { call_constructor::<Self, _, fn()>(&env, token, ()) }
     }
+
+    /// Create a new [`NullPointerException`](struct.NullPointerException.html) with a message.
+    ///
+    /// [`NullPointerException(String)` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/NullPointerException.html#<init>(java.lang.String))
+    pub fn new_with_message(
+        env: &'this JniEnv<'this>,
+        token: &NoException<'this>,
+        message: &String<'this>,
+    ) -> JavaResult<'this, NullPointerException<'this>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { call_constructor::<Self, _, fn(&String<'this>)>(env, token, (message,)) }
+    }
 }
 
-/// Allow [`NullPointerException`](struct.NullPointerException.html) to be used in place of an
-/// [`Object`](struct.Object.html).
-impl<'env> ::std::ops::Deref for NullPointerException<'env> {
-    type Target = Object<'env>;
+/// Allow [`NullPointerException`](struct.NullPointerException.html) to be used in place of a
+/// [`RuntimeException`](struct.RuntimeException.html).
+impl<'env> ::core::ops::Deref for NullPointerException<'env> {
+    type Target = RuntimeException<'env>;
 
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
@@ -42,13 +57,19 @@ impl<'env> ::std::ops::Deref for NullPointerException<'env> {
 impl<'env> AsRef<Object<'env>> for NullPointerException<'env> {
     #[inline(always)]
     fn as_ref(&self) -> &Object<'env> {
-        &self.object
+        self.object.as_ref()
+    }
+}
+
+impl<'a> Into<RuntimeException<'a>> for NullPointerException<'a> {
+    fn into(self) -> RuntimeException<'a> {
+        self.object
     }
 }
 
 impl<'a> Into<Exception<'a>> for NullPointerException<'a> {
     fn into(self) -> Exception<'a> {
-        self.object
+        self.object.into()
     }
 }
 
@@ -68,7 +89,7 @@ impl<'env> FromObject<'env> for NullPointerException<'env> {
     #[inline(always)]
     unsafe fn from_object(object: Object<'env>) -> Self {
         Self {
-            object: Exception::from_object(object),
+            object: RuntimeException::from_object(object),
         }
     }
 }
@@ -80,15 +101,17 @@ impl JniSignature for NullPointerException<'_> {
     }
 }
 
+impl<'env> JavaExceptionClass<'env> for NullPointerException<'env> {}
+
 /// Allow comparing [`NullPointerException`](struct.NullPointerException.html)
 /// to Java objects. Java objects are compared by-reference to preserve
 /// original Java semantics. To compare objects by value, call the
-/// [`equals`](struct.Object.html#method.equals) method.
+/// [`value_eq`](struct.Object.html#method.value_eq) method.
 ///
 /// Will panic if there is a pending exception in the current thread.
 ///
 /// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
-/// [`is_same_as`](struct.Object.html#methods.is_same_as) to comparing with `==`, because
+/// [`ref_eq`](struct.Object.html#method.ref_eq) to comparing with `==`, because
 /// the former checks for a pending exception in compile-time rather than the run-time.
 impl<'env, T> PartialEq<T> for NullPointerException<'env>
 where
@@ -0,0 +1,116 @@
+use crate::classes::exception::Exception;
+use crate::env::JniEnv;
+use crate::java_class::JavaExceptionClass;
+use crate::java_methods::call_constructor;
+use crate::java_methods::FromObject;
+use crate::java_methods::JniSignature;
+use crate::object::Object;
+use crate::result::JavaResult;
+use crate::string::String;
+use crate::throwable::Throwable;
+use crate::token::NoException;
+
+/// A type representing a Java
+/// [`RuntimeException`](https://docs.oracle.com/javase/10/docs/api/java/lang/RuntimeException.html).
+#[derive(Debug, Clone)]
+pub struct RuntimeException<'env> {
+    pub(crate) object: Exception<'env>,
+}
+
+impl<'this> RuntimeException<'this> {
+    /// Create a new [`RuntimeException`](struct.RuntimeException.html).
+    ///
+    /// [`RuntimeException()` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/RuntimeException.html#<init>())
+    pub fn new(
+        env: &'this JniEnv<'this>,
+        token: &NoException<'this>,
+    ) -> JavaResult<'this, RuntimeException<'this>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { call_constructor::<Self, _, fn()>(env, token, ()) }
+    }
+
+    /// Create a new [`RuntimeException`](struct.RuntimeException.html) with a message.
+    ///
+    /// [`RuntimeException(String)` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/RuntimeException.html#<init>(java.lang.String))
+    pub fn new_with_message(
+        env: &'this JniEnv<'this>,
+        token: &NoException<'this>,
+        message: &String<'this>,
+    ) -> JavaResult<'this, RuntimeException<'this>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { call_constructor::<Self, _, fn(&String<'this>)>(env, token, (message,)) }
+    }
+}
+
+/// Allow [`RuntimeException`](struct.RuntimeException.html) to be used in place of an
+/// [`Exception`](struct.Exception.html).
+impl<'env> ::core::ops::Deref for RuntimeException<'env> {
+    type Target = Exception<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Object<'env>> for RuntimeException<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        self.object.as_ref()
+    }
+}
+
+impl<'a> Into<Exception<'a>> for RuntimeException<'a> {
+    fn into(self) -> Exception<'a> {
+        self.object
+    }
+}
+
+impl<'a> Into<Throwable<'a>> for RuntimeException<'a> {
+    fn into(self) -> Throwable<'a> {
+        self.object.into()
+    }
+}
+
+impl<'a> Into<Object<'a>> for RuntimeException<'a> {
+    fn into(self) -> Object<'a> {
+        self.object.into()
+    }
+}
+
+impl<'env> FromObject<'env> for RuntimeException<'env> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self {
+            object: Exception::from_object(object),
+        }
+    }
+}
+
+impl JniSignature for RuntimeException<'_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "Ljava/lang/RuntimeException;"
+    }
+}
+
+impl<'env> JavaExceptionClass<'env> for RuntimeException<'env> {}
+
+/// Allow comparing [`RuntimeException`](struct.RuntimeException.html)
+/// to Java objects. Java objects are compared by-reference to preserve
+/// original Java semantics. To compare objects by value, call the
+/// [`value_eq`](struct.Object.html#method.value_eq) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`ref_eq`](struct.Object.html#method.ref_eq) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for RuntimeException<'env>
+where
+    T: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &T) -> bool {
+        self.as_ref().eq(other.as_ref())
+    }
+}
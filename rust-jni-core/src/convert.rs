@@ -0,0 +1,149 @@
+//! Checked conversions between Rust and JNI sizes and booleans.
+//!
+//! JNI sizes and indices are `jsize`-s, which are 32-bit signed integers, so a `usize` can
+//! be silently truncated by an `as` cast. These functions check the conversions instead.
+
+use crate::jni_bool;
+use core::fmt;
+use jni_sys;
+
+/// The JNI `true` value.
+pub const JNI_TRUE: jni_sys::jboolean = jni_sys::JNI_TRUE;
+/// The JNI `false` value.
+pub const JNI_FALSE: jni_sys::jboolean = jni_sys::JNI_FALSE;
+
+/// Error returned when converting between `usize` and `jsize` fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsizeError {
+    /// The size is larger than the maximum `jsize`.
+    TooLarge(usize),
+    /// The `jsize` is negative.
+    Negative(jni_sys::jsize),
+}
+
+impl fmt::Display for JsizeError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JsizeError::TooLarge(size) => write!(
+                formatter,
+                "Size {} is larger than the maximum JNI size {}.",
+                size,
+                jni_sys::jsize::MAX
+            ),
+            JsizeError::Negative(size) => write!(formatter, "JNI size {} is negative.", size),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for JsizeError {}
+
+/// Convert a Rust size or index into a JNI `jsize`.
+///
+/// Returns an error if the value is larger than the maximum `jsize`.
+///
+/// Example:
+/// ```
+/// # use rust_jni::convert::*;
+/// assert_eq!(to_jsize(17), Ok(17));
+/// assert_eq!(to_jsize(usize::MAX), Err(JsizeError::TooLarge(usize::MAX)));
+/// ```
+pub fn to_jsize(size: usize) -> Result<jni_sys::jsize, JsizeError> {
+    if size > jni_sys::jsize::MAX as usize {
+        Err(JsizeError::TooLarge(size))
+    } else {
+        Ok(size as jni_sys::jsize)
+    }
+}
+
+/// Convert a JNI `jsize` into a Rust size or index.
+///
+/// Returns an error if the value is negative.
+///
+/// Example:
+/// ```
+/// # use rust_jni::convert::*;
+/// assert_eq!(from_jsize(17), Ok(17));
+/// assert_eq!(from_jsize(-1), Err(JsizeError::Negative(-1)));
+/// ```
+pub fn from_jsize(size: jni_sys::jsize) -> Result<usize, JsizeError> {
+    if size < 0 {
+        Err(JsizeError::Negative(size))
+    } else {
+        Ok(size as usize)
+    }
+}
+
+/// Convert a Rust `bool` into a JNI `jboolean`.
+pub fn to_jboolean(value: bool) -> jni_sys::jboolean {
+    jni_bool::to_jni(value)
+}
+
+/// Convert a JNI `jboolean` into a Rust `bool`.
+///
+/// Returns `None` for values other than [`JNI_TRUE`](constant.JNI_TRUE.html) and
+/// [`JNI_FALSE`](constant.JNI_FALSE.html).
+pub fn from_jboolean(value: jni_sys::jboolean) -> Option<bool> {
+    match value {
+        JNI_TRUE => Some(true),
+        JNI_FALSE => Some(false),
+        _ => None,
+    }
+}
+
+/// Convert a size passed to JNI, panicking if it doesn't fit into a `jsize`.
+#[doc(hidden)]
+pub fn size_to_jni(size: usize) -> jni_sys::jsize {
+    to_jsize(size).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Convert a size returned by JNI, panicking if it is negative.
+#[doc(hidden)]
+pub fn size_from_jni(size: jni_sys::jsize) -> usize {
+    from_jsize(size).unwrap_or_else(|error| panic!("{}", error))
+}
+
+#[cfg(test)]
+mod convert_tests {
+    use super::*;
+
+    #[test]
+    fn to_jsize_max() {
+        assert_eq!(
+            to_jsize(jni_sys::jsize::MAX as usize),
+            Ok(jni_sys::jsize::MAX)
+        );
+    }
+
+    #[test]
+    fn to_jsize_too_large() {
+        let size = jni_sys::jsize::MAX as usize + 1;
+        assert_eq!(to_jsize(size), Err(JsizeError::TooLarge(size)));
+    }
+
+    #[test]
+    fn from_jsize_zero() {
+        assert_eq!(from_jsize(0), Ok(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Size 2147483648 is larger than the maximum JNI size 2147483647.")]
+    fn size_to_jni_too_large() {
+        size_to_jni(jni_sys::jsize::MAX as usize + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "JNI size -1 is negative.")]
+    fn size_from_jni_negative() {
+        size_from_jni(-1);
+    }
+
+    #[test]
+    fn jboolean() {
+        assert_eq!(to_jboolean(true), JNI_TRUE);
+        assert_eq!(to_jboolean(false), JNI_FALSE);
+        assert_eq!(from_jboolean(JNI_TRUE), Some(true));
+        assert_eq!(from_jboolean(JNI_FALSE), Some(false));
+        assert_eq!(from_jboolean(10), None);
+    }
+}
@@ -0,0 +1,354 @@
+//! Debugging helpers for inspecting Java objects.
+//!
+//! [`dump`](fn.dump.html) reflectively walks the fields of an object and formats them as a tree,
+//! which helps inspecting opaque objects returned from third-party APIs.
+
+use crate::class::Class;
+use crate::convert;
+use crate::env::JniEnv;
+use crate::java_methods::FromObject;
+use crate::jni_bool;
+use crate::jni_methods;
+use crate::object::Object;
+use crate::object_array::ObjectArray;
+use crate::result::JavaResult;
+use crate::signature::JavaType;
+use crate::string::String;
+use crate::token::NoException;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use core::ptr::NonNull;
+use jni_sys;
+
+include!("call_jni_method.rs");
+
+/// Maximum number of characters of Java strings shown by [`dump`](fn.dump.html).
+const MAX_STRING_LENGTH: usize = 64;
+
+/// The `static` bit of
+/// [`Field::getModifiers`](https://docs.oracle.com/javase/10/docs/api/java/lang/reflect/Modifier.html#STATIC).
+const STATIC_MODIFIER: i32 = 0x0008;
+
+/// Format the object and it's fields, walking fields of referenced objects up to `depth` levels.
+///
+/// Every object is printed as it's class name and identity hash code, like
+/// `a.b.Point@1b6d3586`, followed by it's instance fields, including the inherited ones, one per
+/// line and indented by nesting level. Primitive fields are printed with their values, strings
+/// are quoted and truncated, arrays are printed with their length and objects are expanded
+/// recursively. A `depth` of `0` only prints the object itself. Objects that reference one of
+/// their ancestors in the tree are marked with `(cycle)` instead of being expanded again.
+///
+/// Field values are read with JNI, which bypasses Java access checks, so private fields and
+/// fields of classes in modules that are not open are included.
+///
+/// Meant for debugging only: the output format is not stable.
+///
+/// # Example
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::Throwable;
+/// #
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let message = java::lang::String::new(env, &token, "message")?;
+/// let exception = Throwable::new_with_message(env, &token, &message)?;
+/// let dump = debug::dump(&exception, 1, &token)?;
+/// assert!(dump.starts_with("java.lang.Throwable@"));
+/// assert!(dump.contains("\n  detailMessage: java.lang.String = \"message\""));
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+pub fn dump<'a>(
+    object: &Object<'a>,
+    depth: usize,
+    token: &NoException<'a>,
+) -> JavaResult<'a, alloc::string::String> {
+    let mut output = alloc::string::String::new();
+    dump_object(&mut output, object, depth, 1, &[], token)?;
+    Ok(output)
+}
+
+/// Write the object header and, if it's not a string or an array, it's fields.
+fn dump_object<'a>(
+    output: &mut alloc::string::String,
+    object: &Object<'a>,
+    depth: usize,
+    indent: usize,
+    ancestors: &[&Object<'a>],
+    token: &NoException<'a>,
+) -> JavaResult<'a, ()> {
+    let class = object.class(token);
+    let class_name = type_name(&class, token)?;
+    if class_name == "java.lang.String" {
+        // Safe because the object is a string.
+        let string = unsafe { String::from_object(object.clone_object(token)?) };
+        write_string(output, &string.as_string_lossy(token));
+        return Ok(());
+    }
+    if class_name.ends_with("[]") {
+        // Safe because the object is an array.
+        let length =
+            unsafe { call_jni_method!(object.env(), GetArrayLength, object.raw_object().as_ptr()) };
+        let length = convert::size_from_jni(length);
+        let element_name = &class_name[..class_name.len() - 2];
+        match element_name.find('[') {
+            // Arrays of arrays, like `int[3][]`.
+            Some(index) => write!(
+                output,
+                "{}[{}]{}",
+                &element_name[..index],
+                length,
+                &element_name[index..]
+            ),
+            None => write!(output, "{}[{}]", element_name, length),
+        }
+        .unwrap();
+        return Ok(());
+    }
+    write!(
+        output,
+        "{}@{:x}",
+        class_name,
+        object.identity_hash(token)? as u32
+    )
+    .unwrap();
+    if ancestors
+        .iter()
+        .any(|ancestor| ancestor.is_same_as(token, object))
+    {
+        output.push_str(" (cycle)");
+        return Ok(());
+    }
+    if depth == 0 {
+        return Ok(());
+    }
+    let mut ancestors = ancestors.to_vec();
+    ancestors.push(object);
+    let mut classes = Vec::new();
+    let mut current = Some(class);
+    while let Some(class) = current {
+        current = class.parent(token);
+        classes.push(class);
+    }
+    // Fields of superclasses go first, like in memory.
+    for class in classes.iter().rev() {
+        dump_fields(output, object, class, depth, indent, &ancestors, token)?;
+    }
+    Ok(())
+}
+
+/// Write instance fields declared in the class.
+fn dump_fields<'a>(
+    output: &mut alloc::string::String,
+    object: &Object<'a>,
+    class: &Class<'a>,
+    depth: usize,
+    indent: usize,
+    ancestors: &[&Object<'a>],
+    token: &NoException<'a>,
+) -> JavaResult<'a, ()> {
+    let env = object.env();
+    // Safe because we ensure correct arguments and return type.
+    let fields = unsafe {
+        jni_methods::call_object_method(
+            class,
+            token,
+            "getDeclaredFields\0",
+            "()[Ljava/lang/reflect/Field;\0",
+            (),
+        )
+    }?;
+    // Should not panic: `getDeclaredFields` never returns `null`.
+    // Safe because the argument is a valid array reference.
+    let fields = unsafe { ObjectArray::from_object(Object::from_raw(env, fields.unwrap())) };
+    for index in 0..fields.len(token) {
+        // Should not panic: the array doesn't contain `null`-s.
+        let field = fields.get(token, index)?.unwrap();
+        // Safe because we ensure correct arguments and return type.
+        let modifiers = unsafe {
+            jni_methods::call_primitive_method::<i32>(&field, token, "getModifiers\0", "()I\0", ())
+        }?;
+        if modifiers & STATIC_MODIFIER != 0 {
+            continue;
+        }
+        // Safe because we ensure correct arguments and return type.
+        let name = unsafe {
+            jni_methods::call_object_method(
+                &field,
+                token,
+                "getName\0",
+                "()Ljava/lang/String;\0",
+                (),
+            )
+        }?;
+        // Should not panic: fields always have a name.
+        // Safe because the argument is a valid string reference.
+        let name = unsafe { String::from_object(Object::from_raw(env, name.unwrap())) };
+        // Safe because we ensure correct arguments and return type.
+        let field_type = unsafe {
+            jni_methods::call_object_method(&field, token, "getType\0", "()Ljava/lang/Class;\0", ())
+        }?;
+        // Should not panic: fields always have a type.
+        // Safe because the argument is a valid class reference.
+        let field_type = unsafe { Class::from_object(Object::from_raw(env, field_type.unwrap())) };
+        let type_name = type_name(&field_type, token)?;
+        // Safe because the argument is a valid field reference.
+        let field_id =
+            unsafe { call_jni_method!(env, FromReflectedField, field.raw_object().as_ptr()) };
+        // Should not panic: the field is a valid reflected field.
+        let field_id = NonNull::new(field_id).expect("FromReflectedField returned null.");
+
+        write!(
+            output,
+            "\n{:indent$}{}: {} = ",
+            "",
+            name.as_string_lossy(token),
+            type_name,
+            indent = indent * 2
+        )
+        .unwrap();
+        // Safe because the field belongs to the class of the object and the getter
+        // matches the field type.
+        unsafe {
+            dump_field(
+                output, env, object, field_id, &type_name, depth, indent, ancestors, token,
+            )
+        }?;
+    }
+    Ok(())
+}
+
+/// Write the value of the field.
+///
+/// Unsafe because the field must belong to the class of the object and have the type `type_name`.
+#[allow(clippy::too_many_arguments)]
+unsafe fn dump_field<'a>(
+    output: &mut alloc::string::String,
+    env: &'a JniEnv<'a>,
+    object: &Object<'a>,
+    field_id: NonNull<jni_sys::_jfieldID>,
+    type_name: &str,
+    depth: usize,
+    indent: usize,
+    ancestors: &[&Object<'a>],
+    token: &NoException<'a>,
+) -> JavaResult<'a, ()> {
+    let raw_object = object.raw_object().as_ptr();
+    let field_id = field_id.as_ptr();
+    match type_name {
+        "boolean" => {
+            let value = call_jni_method!(env, GetBooleanField, raw_object, field_id);
+            write!(output, "{}", jni_bool::to_rust(value))
+        }
+        "byte" => write!(
+            output,
+            "{}",
+            call_jni_method!(env, GetByteField, raw_object, field_id)
+        ),
+        "char" => {
+            let value = call_jni_method!(env, GetCharField, raw_object, field_id);
+            match char::from_u32(u32::from(value)) {
+                Some(value) => write!(output, "{:?}", value),
+                None => write!(output, "'\\u{{{:x}}}'", value),
+            }
+        }
+        "short" => write!(
+            output,
+            "{}",
+            call_jni_method!(env, GetShortField, raw_object, field_id)
+        ),
+        "int" => write!(
+            output,
+            "{}",
+            call_jni_method!(env, GetIntField, raw_object, field_id)
+        ),
+        "long" => write!(
+            output,
+            "{}",
+            call_jni_method!(env, GetLongField, raw_object, field_id)
+        ),
+        "float" => write!(
+            output,
+            "{:?}",
+            call_jni_method!(env, GetFloatField, raw_object, field_id)
+        ),
+        "double" => write!(
+            output,
+            "{:?}",
+            call_jni_method!(env, GetDoubleField, raw_object, field_id)
+        ),
+        _ => {
+            let value = call_jni_method!(env, GetObjectField, raw_object, field_id);
+            match NonNull::new(value) {
+                None => write!(output, "null"),
+                Some(value) => {
+                    // Safe because the argument is a valid object reference.
+                    let value = Object::from_raw(env, value);
+                    return dump_object(output, &value, depth - 1, indent + 1, ancestors, token);
+                }
+            }
+        }
+    }
+    .unwrap();
+    Ok(())
+}
+
+/// Get the name of the class as it is written in Java source code, like `java.lang.String`
+/// or `int[]`.
+fn type_name<'a>(
+    class: &Class<'a>,
+    token: &NoException<'a>,
+) -> JavaResult<'a, alloc::string::String> {
+    // Should not panic: classes always have a name.
+    let name = class.get_name(token)?.unwrap().as_string_lossy(token);
+    if !name.starts_with('[') {
+        return Ok(name);
+    }
+    // Array classes are named by their signatures, like `[Ljava.lang.String;`.
+    Ok(JavaType::parse(&name.replace('.', "/"))
+        .map(|java_type| java_type.java_name())
+        .unwrap_or(name))
+}
+
+/// Write a quoted string, truncated to `MAX_STRING_LENGTH` characters.
+fn write_string(output: &mut alloc::string::String, string: &str) {
+    let mut characters = string.chars();
+    let truncated = characters
+        .by_ref()
+        .take(MAX_STRING_LENGTH)
+        .collect::<alloc::string::String>();
+    if characters.next().is_some() {
+        write!(output, "{:?}...", truncated).unwrap();
+    } else {
+        write!(output, "{:?}", truncated).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod write_string_tests {
+    use super::*;
+
+    #[test]
+    fn short() {
+        let mut output = alloc::string::String::new();
+        write_string(&mut output, "a\"b");
+        assert_eq!(output, "\"a\\\"b\"");
+    }
+
+    #[test]
+    fn truncated() {
+        let mut output = alloc::string::String::new();
+        write_string(&mut output, &"ä".repeat(MAX_STRING_LENGTH + 1));
+        assert_eq!(output, format!("{:?}...", "ä".repeat(MAX_STRING_LENGTH)));
+    }
+}
@@ -0,0 +1,50 @@
+//! A side table of debug names for Java objects.
+//!
+//! Only compiled with the `debug-names` feature.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// Debug names keyed by identity hashes of Java objects.
+static DEBUG_NAMES: Mutex<BTreeMap<i32, String>> = Mutex::new(BTreeMap::new());
+
+pub(crate) fn set(identity_hash: i32, name: &str) {
+    DEBUG_NAMES
+        .lock()
+        .unwrap()
+        .insert(identity_hash, name.to_owned());
+}
+
+pub(crate) fn get(identity_hash: i32) -> Option<String> {
+    DEBUG_NAMES.lock().unwrap().get(&identity_hash).cloned()
+}
+
+/// Check if no names are set, so that looking up identity hashes can be skipped.
+pub(crate) fn is_empty() -> bool {
+    DEBUG_NAMES.lock().unwrap().is_empty()
+}
+
+/// Forget all names set with [`Object::set_debug_name`](java/lang/struct.Object.html#method.set_debug_name).
+///
+/// Names are not removed when objects are garbage collected, so long-running programs should
+/// clear them periodically.
+///
+/// Only available with the `debug-names` feature.
+pub fn clear_debug_names() {
+    DEBUG_NAMES.lock().unwrap().clear();
+}
+
+#[cfg(test)]
+mod debug_name_tests {
+    use super::*;
+
+    #[test]
+    fn set_get() {
+        set(-17, "first");
+        assert_eq!(get(-17), Some("first".to_owned()));
+        set(-17, "second");
+        assert_eq!(get(-17), Some("second".to_owned()));
+        assert_eq!(get(-18), None);
+        assert!(!is_empty());
+    }
+}
@@ -1,12 +1,16 @@
+use crate::classes::class_loader::{self, ClassLoader};
 use crate::error::JniError;
-use crate::token::{ConsumedNoException, NoException};
+use crate::fatal_error;
+use crate::local_frame::LocalFrameResult;
+use crate::result::JavaResult;
+use crate::throwable::Throwable;
+use crate::token::{CallOutcome, ConsumedNoException, NoException};
 use crate::version::JniVersion;
 use crate::vm::{JavaVM, JavaVMRef};
+use core::cell::RefCell;
+use core::mem;
 use core::ptr::NonNull;
 use jni_sys;
-use std;
-use std::cell::RefCell;
-use std::mem;
 
 include!("call_jni_method.rs");
 
@@ -175,20 +179,73 @@ pub struct JniEnv<'this> {
 // impl<'vm> !Sync for JniEnv<'vm> {}
 
 impl<'this> JniEnv<'this> {
-    /// Get the raw Java VM pointer.
-    ///
-    /// This function provides low-level access to all of JNI and thus is unsafe.
-    #[inline(always)]
-    pub unsafe fn raw_jvm(&self) -> NonNull<jni_sys::JavaVM> {
-        self.vm.raw_jvm()
+    raw_passthrough! {
+        /// Get the raw Java VM pointer.
+        ///
+        /// This function provides low-level access to all of JNI and thus is unsafe.
+        ///
+        /// Not public with the `deny-unsafe-passthrough` feature.
+        #[inline(always)]
+        unsafe fn raw_jvm(&self) -> NonNull<jni_sys::JavaVM> {
+            self.vm.raw_jvm()
+        }
+    }
+
+    raw_passthrough! {
+        /// Get the raw JNI environment pointer.
+        ///
+        /// This function provides low-level access to all of JNI and thus is unsafe.
+        ///
+        /// Not public with the `deny-unsafe-passthrough` feature, use
+        /// [`with_raw_env`](#method.with_raw_env) instead.
+        #[inline(always)]
+        unsafe fn raw_env(&self) -> NonNull<jni_sys::JNIEnv> {
+            self.jni_env
+        }
     }
 
-    /// Get the raw JNI environment pointer.
+    /// Call `callback` with the raw JNI environment pointer.
+    ///
+    /// Unlike [`raw_env`](#method.raw_env), this is available with the
+    /// `deny-unsafe-passthrough` feature, as it can only be called when there is no pending
+    /// exception. The pointer must not be used after `callback` returns.
     ///
     /// This function provides low-level access to all of JNI and thus is unsafe.
+    ///
+    /// # Example
+    /// ```
+    /// # use rust_jni::*;
+    /// #
+    /// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+    /// let version = unsafe {
+    ///     env.with_raw_env(&token, |raw_env| {
+    ///         let raw_env = raw_env.as_ptr();
+    ///         ((**raw_env).GetVersion.unwrap())(raw_env)
+    ///     })
+    /// };
+    /// // JNI version 1.8 or newer.
+    /// assert!(version >= 0x0001_0008);
+    /// # Ok(token)
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     let init_arguments = InitArguments::default();
+    /// #     let vm = JavaVM::create(&init_arguments).unwrap();
+    /// #     let _ = vm.with_attached(
+    /// #        &AttachArguments::new(init_arguments.version()),
+    /// #        |env: &JniEnv, token: NoException| {
+    /// #            ((), jni_main(env, token).unwrap())
+    /// #        },
+    /// #     );
+    /// # }
+    /// ```
     #[inline(always)]
-    pub unsafe fn raw_env(&self) -> NonNull<jni_sys::JNIEnv> {
-        self.jni_env
+    pub unsafe fn with_raw_env<'a, R>(
+        &'a self,
+        _token: &NoException<'a>,
+        callback: impl FnOnce(NonNull<jni_sys::JNIEnv>) -> R,
+    ) -> R {
+        callback(self.jni_env)
     }
 
     fn verify_token_not_borrowed(&self) {
@@ -205,8 +262,9 @@ impl<'this> JniEnv<'this> {
     /// Panics when trying to obtain the token for the second time.
     ///
     /// Read more about tokens in [`NoException`](struct.NoException.html) documentation.
-    // TODO(#22): Return a token with the env if possible:
-    // https://stackoverflow.com/questions/50891977/can-i-return-a-value-and-a-reference-to-it-from-a-function.
+    ///
+    /// Use [`JavaVM::attach_with_token`](struct.JavaVM.html#method.attach_with_token) to attach
+    /// the thread and get the token in one step without panics.
     pub fn token<'a>(&'a self) -> NoException<'a> {
         self.verify_token_not_borrowed();
 
@@ -260,6 +318,230 @@ impl<'this> JniEnv<'this> {
         JniVersion::from_raw(unsafe { call_jni_method!(self, GetVersion) })
     }
 
+    /// Ensure that at least `capacity` local references can be created before running `callback`.
+    ///
+    /// Every Java object returned to Rust holds a local reference until it's dropped. Java VMs
+    /// only guarantee 16 local references per native method call, so code that creates thousands
+    /// of objects at once, like converting a large Java collection into a
+    /// [`Vec`](https://doc.rust-lang.org/std/vec/struct.Vec.html), should reserve them up front
+    /// instead of relying on the Java VM growing the local reference table on demand.
+    ///
+    /// This doesn't free the local references created by `callback` when it returns and the
+    /// reserved capacity stays in effect afterwards. Some Java VMs ignore the hint if it is too
+    /// large without throwing an exception, in which case `callback` is still run.
+    ///
+    /// Returns an
+    /// [`OutOfMemoryError`](https://docs.oracle.com/javase/10/docs/api/java/lang/OutOfMemoryError.html)
+    /// if the Java VM can't reserve the local references.
+    /// Will panic if `capacity` doesn't fit into a `jint`.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#ensurelocalcapacity)
+    ///
+    /// # Example
+    /// ```
+    /// # use rust_jni::*;
+    /// # use rust_jni::java::lang::String;
+    /// #
+    /// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+    /// let strings = env.with_capacity_hint(&token, 1000, |token| {
+    ///     (0..1000)
+    ///         .map(|index| String::new(env, token, &index.to_string()))
+    ///         .collect::<JavaResult<Vec<_>>>()
+    /// })?;
+    /// assert_eq!(strings.len(), 1000);
+    /// # Ok(token)
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     let init_arguments = InitArguments::default();
+    /// #     let vm = JavaVM::create(&init_arguments).unwrap();
+    /// #     let _ = vm.with_attached(
+    /// #        &AttachArguments::new(init_arguments.version()),
+    /// #        |env: &JniEnv, token: NoException| {
+    /// #            ((), jni_main(env, token).unwrap())
+    /// #        },
+    /// #     );
+    /// # }
+    /// ```
+    pub fn with_capacity_hint<'a, T>(
+        &'a self,
+        token: &NoException<'a>,
+        capacity: usize,
+        callback: impl FnOnce(&NoException<'a>) -> JavaResult<'a, T>,
+    ) -> JavaResult<'a, T> {
+        if capacity > jni_sys::jint::MAX as usize {
+            panic!("Local reference capacity {} is too large.", capacity);
+        }
+        // Safe because the argument is ensured to be the correct by construction.
+        // `EnsureLocalCapacity` is supposed to throw an exception when it fails, but not all
+        // Java VMs do, so a runtime exception check is needed.
+        token.with_owned(
+            self,
+            #[inline(always)]
+            |_token| unsafe {
+                CallOutcome::Unknown(call_jni_method!(
+                    self,
+                    EnsureLocalCapacity,
+                    capacity as jni_sys::jint
+                ))
+            },
+        )?;
+        callback(token)
+    }
+
+    /// Run `callback` in a new local reference frame with space for at least `capacity` local
+    /// references.
+    ///
+    /// All local references created by `callback` are freed when it returns, except for the
+    /// result or the thrown exception, which are promoted to the previous frame. This keeps the
+    /// number of local references constant in loops that call Java methods.
+    ///
+    /// If the Java VM fails to push a frame without throwing an exception, `callback` is run
+    /// in the current frame instead.
+    ///
+    /// Returns an
+    /// [`OutOfMemoryError`](https://docs.oracle.com/javase/10/docs/api/java/lang/OutOfMemoryError.html)
+    /// if the Java VM can't reserve the local references.
+    /// Will panic if `capacity` doesn't fit into a `jint`.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#pushlocalframe)
+    ///
+    /// # Example
+    /// ```
+    /// # use rust_jni::*;
+    /// # use rust_jni::java::lang::String;
+    /// #
+    /// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+    /// let length = unsafe {
+    ///     env.with_local_frame(&token, 2, |token| {
+    ///         let string = String::new(env, token, "string")?;
+    ///         Ok(string.len(token) as i32)
+    ///     })
+    /// }?;
+    /// assert_eq!(length, 6);
+    /// # Ok(token)
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     let init_arguments = InitArguments::default();
+    /// #     let vm = JavaVM::create(&init_arguments).unwrap();
+    /// #     let _ = vm.with_attached(
+    /// #        &AttachArguments::new(init_arguments.version()),
+    /// #        |env: &JniEnv, token: NoException| {
+    /// #            ((), jni_main(env, token).unwrap())
+    /// #        },
+    /// #     );
+    /// # }
+    /// ```
+    ///
+    /// Unsafe because Java objects created by `callback` must not outlive it other than by
+    /// being returned from it.
+    pub unsafe fn with_local_frame<'a, T>(
+        &'a self,
+        token: &NoException<'a>,
+        capacity: usize,
+        callback: impl FnOnce(&NoException<'a>) -> JavaResult<'a, T>,
+    ) -> JavaResult<'a, T>
+    where
+        T: LocalFrameResult<'a>,
+    {
+        if capacity > jni_sys::jint::MAX as usize {
+            panic!("Local reference capacity {} is too large.", capacity);
+        }
+        // Safe because the argument is ensured to be the correct by construction.
+        // Not all Java VMs throw an exception when `PushLocalFrame` fails, so a runtime
+        // exception check is needed.
+        let status = token.with_owned(
+            self,
+            #[inline(always)]
+            |_token| {
+                CallOutcome::Unknown(call_jni_method!(
+                    self,
+                    PushLocalFrame,
+                    capacity as jni_sys::jint
+                ))
+            },
+        )?;
+        let result = callback(token);
+        if status != jni_sys::JNI_OK {
+            return result;
+        }
+        match result {
+            Ok(value) => Ok(value.__pop_local_frame(self)),
+            Err(throwable) => Err(throwable.__pop_local_frame(self)),
+        }
+    }
+
+    /// Get and clear the pending exception, if there is one.
+    ///
+    /// This is useful in native method glue and other low-level code that calls JNI directly and
+    /// doesn't have an [`Exception`](struct.Exception.html) token. Code that does should call
+    /// [`Exception::unwrap`](struct.Exception.html#method.unwrap) instead.
+    ///
+    /// The cleared exception is subject to the [`FatalErrorPolicy`](enum.FatalErrorPolicy.html).
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#exceptionoccurred)
+    ///
+    /// # Example
+    /// ```
+    /// # use rust_jni::*;
+    /// # use rust_jni::java::lang::Throwable;
+    /// #
+    /// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+    /// assert!(env.take_pending_exception().is_none());
+    ///
+    /// let exception = Throwable::new(env, &token)?;
+    /// unsafe {
+    ///     let raw_env = env.raw_env().as_ptr();
+    ///     let jni_fn = ((**raw_env).Throw).unwrap();
+    ///     jni_fn(raw_env, exception.raw_object().as_ptr());
+    /// }
+    /// let thrown = env.take_pending_exception().unwrap();
+    /// assert!(thrown.is_same_as(&token, &exception));
+    /// # Ok(token)
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     let init_arguments = InitArguments::default();
+    /// #     let vm = JavaVM::create(&init_arguments).unwrap();
+    /// #     let _ = vm.with_attached(
+    /// #        &AttachArguments::new(init_arguments.version()),
+    /// #        |env: &JniEnv, token: NoException| {
+    /// #            ((), jni_main(env, token).unwrap())
+    /// #        },
+    /// #     );
+    /// # }
+    /// ```
+    pub fn take_pending_exception<'a>(&'a self) -> Option<Throwable<'a>> {
+        // Safe because the argument is ensured to be the correct by construction.
+        let raw_java_throwable =
+            NonNull::new(unsafe { call_jni_method!(self, ExceptionOccurred) })?;
+        // Safe because the argument is ensured to be the correct by construction.
+        unsafe { call_jni_method!(self, ExceptionClear) };
+        // Safe because the argument is a valid throwable reference.
+        unsafe { fatal_error::handle_cleared_exception(self, raw_java_throwable) };
+        // Safe because the argument is a valid throwable reference.
+        Some(unsafe { Throwable::from_raw(self, raw_java_throwable) })
+    }
+
+    /// Set the context class loader of the current thread.
+    ///
+    /// Threads attached from native code don't have an application class loader as their
+    /// context class loader. Use this method or
+    /// [`AttachArguments::with_context_class_loader`](struct.AttachArguments.html#method.with_context_class_loader)
+    /// to make application classes available to
+    /// [`Class::find`](java/lang/struct.Class.html#method.find), see
+    /// [`set_context_class_loader_fallback_enabled`](fn.set_context_class_loader_fallback_enabled.html).
+    ///
+    /// [`Thread::setContextClassLoader` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Thread.html#setContextClassLoader(java.lang.ClassLoader))
+    pub fn set_context_class_loader<'a>(
+        &'a self,
+        token: &NoException<'a>,
+        class_loader: &ClassLoader<'a>,
+    ) -> JavaResult<'a, ()> {
+        class_loader::set_context_class_loader(self, token, class_loader)
+    }
+
     /// Detach current thread.
     ///
     /// Calling this method consumes [`JniEnv`](struct.JniEnv.html). Detaching the thread is not allowed
@@ -280,6 +562,8 @@ impl<'this> JniEnv<'this> {
         vm: &'vm JavaVMRef,
         jni_env: NonNull<jni_sys::JNIEnv>,
     ) -> JniEnv<'env> {
+        #[cfg(feature = "stats")]
+        crate::stats::env_created();
         JniEnv {
             vm,
             jni_env,
@@ -293,6 +577,8 @@ impl<'this> JniEnv<'this> {
         vm: &'vm JavaVMRef,
         jni_env: NonNull<jni_sys::JNIEnv>,
     ) -> JniEnv<'env> {
+        #[cfg(feature = "stats")]
+        crate::stats::env_created();
         let env = JniEnv {
             vm,
             jni_env,
@@ -358,14 +644,20 @@ impl<'vm> Drop for JniEnv<'vm> {
         // Safe because the current thread is guaranteed to be attached and the argument is correct.
         unsafe {
             let error = JavaVM::detach(self.raw_jvm());
-            if error.is_some() {
-                // No meaningful way to handle the error except for logging it.
-                println!(
-                    "Error {:?} when calling `DetachCurrentThread` on {:?}",
-                    error.unwrap(),
-                    self
-                );
+            // No meaningful way to handle the error except for logging it,
+            // which needs the standard library.
+            #[cfg(feature = "std")]
+            {
+                if error.is_some() {
+                    println!(
+                        "Error {:?} when calling `DetachCurrentThread` on {:?}",
+                        error.unwrap(),
+                        self
+                    );
+                }
             }
+            #[cfg(not(feature = "std"))]
+            let _ = error;
         }
     }
 }
@@ -373,9 +665,9 @@ impl<'vm> Drop for JniEnv<'vm> {
 #[cfg(test)]
 mod jni_env_tests {
     use super::*;
+    use core::ptr;
     use mockall::*;
     use serial_test_derive::serial;
-    use std::ptr;
 
     generate_java_vm_mock!(mock);
     generate_jni_env_mock!(jni_mock);
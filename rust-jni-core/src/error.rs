@@ -0,0 +1,341 @@
+use crate::init_arguments::JvmOption;
+use crate::version::JniVersion;
+use alloc::string::String;
+use core::fmt;
+
+/// Errors returned by JNI function.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#return-codes)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JniError {
+    /// Unknown error with the raw status code, including the generic `JNI_ERR`.
+    /// Needed for forward compability.
+    Unknown(i32),
+    /// `JNI_EDETACHED`: returned when the currect thread is not attached to a Java VM.
+    ThreadDetached,
+    /// `JNI_EVERSION`: returned when requesting a VM with an unsupported version.
+    UnsupportedVersion,
+    /// `JNI_ENOMEM`: returned when there isn't enough memory for the operation.
+    NotEnoughMemory,
+    /// `JNI_EEXIST`: returned when trying to create a new Java VM when
+    /// one already exists in the current process.
+    /// Creating multiple Java VMs in a single process is not supported.
+    /// See [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
+    /// for more details.
+    VmExists,
+    /// `JNI_EINVAL`: returned when passing invalid arguments to JNI calls.
+    InvalidArguments,
+}
+
+impl JniError {
+    /// Convert from a raw `jint` error.
+    pub(crate) fn from_raw(error: jni_sys::jint) -> Option<JniError> {
+        match error {
+            jni_sys::JNI_OK => None,
+            jni_sys::JNI_EDETACHED => Some(JniError::ThreadDetached),
+            jni_sys::JNI_EVERSION => Some(JniError::UnsupportedVersion),
+            jni_sys::JNI_ENOMEM => Some(JniError::NotEnoughMemory),
+            jni_sys::JNI_EEXIST => Some(JniError::VmExists),
+            jni_sys::JNI_EINVAL => Some(JniError::InvalidArguments),
+            error => Some(JniError::Unknown(error)),
+        }
+    }
+
+    /// Get the raw JNI status code of the error, like `JNI_EDETACHED`.
+    ///
+    /// Generic JNI failures are reported as `JniError::Unknown(JNI_ERR)`.
+    pub fn code(&self) -> i32 {
+        match self {
+            JniError::Unknown(error) => *error,
+            JniError::ThreadDetached => jni_sys::JNI_EDETACHED,
+            JniError::UnsupportedVersion => jni_sys::JNI_EVERSION,
+            JniError::NotEnoughMemory => jni_sys::JNI_ENOMEM,
+            JniError::VmExists => jni_sys::JNI_EEXIST,
+            JniError::InvalidArguments => jni_sys::JNI_EINVAL,
+        }
+    }
+}
+
+impl fmt::Display for JniError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JniError::Unknown(error) => write!(formatter, "JNI call failed with status {}.", error),
+            JniError::ThreadDetached => {
+                write!(formatter, "The current thread is not attached to a Java VM.")
+            }
+            JniError::UnsupportedVersion => {
+                write!(formatter, "The requested JNI version is not supported.")
+            }
+            JniError::NotEnoughMemory => write!(formatter, "Not enough memory."),
+            JniError::VmExists => write!(
+                formatter,
+                "A Java VM already exists in this process. Only one Java VM per process is supported."
+            ),
+            JniError::InvalidArguments => write!(formatter, "Invalid arguments to a JNI call."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for JniError {}
+
+#[cfg(test)]
+mod from_raw_tests {
+    use super::*;
+
+    #[test]
+    fn from_raw_ok() {
+        assert_eq!(JniError::from_raw(jni_sys::JNI_OK), None);
+    }
+
+    #[test]
+    fn from_raw_error() {
+        assert_eq!(
+            JniError::from_raw(jni_sys::JNI_EDETACHED),
+            Some(JniError::ThreadDetached)
+        );
+        assert_eq!(
+            JniError::from_raw(jni_sys::JNI_EVERSION),
+            Some(JniError::UnsupportedVersion)
+        );
+        assert_eq!(
+            JniError::from_raw(jni_sys::JNI_ENOMEM),
+            Some(JniError::NotEnoughMemory)
+        );
+        assert_eq!(
+            JniError::from_raw(jni_sys::JNI_EEXIST),
+            Some(JniError::VmExists)
+        );
+        assert_eq!(
+            JniError::from_raw(jni_sys::JNI_EINVAL),
+            Some(JniError::InvalidArguments)
+        );
+    }
+
+    #[test]
+    fn from_raw_unknown_error() {
+        assert_eq!(JniError::from_raw(7), Some(JniError::Unknown(7)));
+    }
+
+    #[test]
+    fn code() {
+        for code in &[
+            jni_sys::JNI_ERR,
+            jni_sys::JNI_EDETACHED,
+            jni_sys::JNI_EVERSION,
+            jni_sys::JNI_ENOMEM,
+            jni_sys::JNI_EEXIST,
+            jni_sys::JNI_EINVAL,
+            7,
+        ] {
+            assert_eq!(JniError::from_raw(*code).unwrap().code(), *code);
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", JniError::Unknown(jni_sys::JNI_ERR)),
+            "JNI call failed with status -1."
+        );
+        assert_eq!(
+            format!("{}", JniError::ThreadDetached),
+            "The current thread is not attached to a Java VM."
+        );
+    }
+}
+
+/// Errors returned when creating a Java VM.
+///
+/// See [`JavaVM::create`](struct.JavaVM.html#method.create).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VmCreationError {
+    /// A Java VM init option can't be passed to the Java VM,
+    /// for example because it has an empty name, a zero memory size
+    /// or sets a system property that is already set.
+    InvalidOption {
+        /// The invalid option.
+        option: JvmOption,
+        /// The invalid option as it would be passed to the Java VM, for example `-Xmx0m`.
+        option_string: String,
+        /// Description of the problem.
+        reason: String,
+    },
+    /// The Java VM doesn't support the requested JNI version.
+    UnsupportedVersion(JniVersion),
+    /// A Java VM already exists in the current process.
+    /// Creating multiple Java VMs in a single process is not supported.
+    /// See [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
+    /// for more details.
+    VmExists,
+    /// The Java VM failed to start with the JNI status code.
+    ///
+    /// Java VMs report unrecognized options and most other startup failures
+    /// with a generic [`JniError::Unknown`](enum.JniError.html#variant.Unknown) error
+    /// and print the details to the standard error.
+    Jni(JniError),
+}
+
+impl VmCreationError {
+    /// Convert from a JNI error returned by `JNI_CreateJavaVM`.
+    #[cfg_attr(feature = "no-invocation", allow(dead_code))]
+    pub(crate) fn from_jni(error: JniError, version: JniVersion) -> VmCreationError {
+        match error {
+            JniError::UnsupportedVersion => VmCreationError::UnsupportedVersion(version),
+            JniError::VmExists => VmCreationError::VmExists,
+            error => VmCreationError::Jni(error),
+        }
+    }
+}
+
+impl fmt::Display for VmCreationError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VmCreationError::InvalidOption {
+                option_string,
+                reason,
+                ..
+            } => write!(
+                formatter,
+                "Invalid Java VM option \"{}\": {}",
+                option_string, reason
+            ),
+            VmCreationError::UnsupportedVersion(version) => write!(
+                formatter,
+                "The Java VM doesn't support JNI version {:?}.",
+                version
+            ),
+            VmCreationError::VmExists => write!(
+                formatter,
+                "A Java VM already exists in this process. Only one Java VM per process is supported."
+            ),
+            VmCreationError::Jni(JniError::NotEnoughMemory) => {
+                write!(formatter, "Not enough memory to create a Java VM.")
+            }
+            VmCreationError::Jni(JniError::InvalidArguments) => {
+                write!(formatter, "Invalid Java VM init arguments.")
+            }
+            VmCreationError::Jni(error) => write!(
+                formatter,
+                "Failed to create a Java VM: {:?}. Unrecognized options and other startup \
+                 failures are reported by the Java VM in the standard error.",
+                error
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VmCreationError {}
+
+/// Error returned when a Java string can't be converted into a Rust string.
+///
+/// Java strings are sequences of UTF-16 code units and can contain unpaired surrogates,
+/// which are not valid Unicode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StringDecodingError;
+
+impl fmt::Display for StringDecodingError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "The Java string is not valid Unicode: it contains unpaired surrogates."
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StringDecodingError {}
+
+/// Error returned by generated bindings of Java methods that are not available in the
+/// running Java VM, like methods marked with `@since(11)` called on Java 8.
+///
+/// Returned instead of throwing a `NoSuchMethodError`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnsupportedOnThisJvm {
+    /// The Java feature release that introduced the method.
+    pub required: u32,
+    /// The feature release of the running Java VM.
+    pub actual: u32,
+}
+
+impl fmt::Display for UnsupportedOnThisJvm {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "The method requires Java {}, but the Java VM is Java {}.",
+            self.required, self.actual
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnsupportedOnThisJvm {}
+
+/// Error returned when a JNI type signature or method descriptor can't be parsed.
+///
+/// See the [`signature`](signature/index.html) module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureParseError {
+    /// The signature that failed to parse.
+    pub signature: String,
+    /// Byte offset of the first invalid character in the signature.
+    pub position: usize,
+}
+
+impl fmt::Display for SignatureParseError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Invalid JNI signature {:?}: unexpected input at position {}.",
+            self.signature, self.position
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SignatureParseError {}
+
+#[cfg(test)]
+mod vm_creation_error_tests {
+    use super::*;
+
+    #[test]
+    fn from_jni() {
+        assert_eq!(
+            VmCreationError::from_jni(JniError::UnsupportedVersion, JniVersion::V10),
+            VmCreationError::UnsupportedVersion(JniVersion::V10)
+        );
+        assert_eq!(
+            VmCreationError::from_jni(JniError::VmExists, JniVersion::V10),
+            VmCreationError::VmExists
+        );
+        assert_eq!(
+            VmCreationError::from_jni(JniError::NotEnoughMemory, JniVersion::V10),
+            VmCreationError::Jni(JniError::NotEnoughMemory)
+        );
+        assert_eq!(
+            VmCreationError::from_jni(JniError::Unknown(-1), JniVersion::V10),
+            VmCreationError::Jni(JniError::Unknown(-1))
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!(
+                "{}",
+                VmCreationError::InvalidOption {
+                    option: JvmOption::Unknown("-Xmx0".to_owned()),
+                    option_string: "-Xmx0".to_owned(),
+                    reason: "Invalid memory size in option \"-Xmx0\".".to_owned(),
+                }
+            ),
+            "Invalid Java VM option \"-Xmx0\": Invalid memory size in option \"-Xmx0\"."
+        );
+        assert_eq!(
+            format!("{}", VmCreationError::UnsupportedVersion(JniVersion::V10)),
+            "The Java VM doesn't support JNI version V10."
+        );
+    }
+}
@@ -0,0 +1,311 @@
+//! A pool of threads attached to a Java VM.
+//!
+//! Attaching and detaching threads is expensive. [`JvmExecutor`](struct.JvmExecutor.html)
+//! keeps a fixed number of attached threads and runs submitted tasks on them, which also caps
+//! the number of threads calling JNI concurrently.
+
+use crate::attach_arguments::AttachArguments;
+use crate::env::JniEnv;
+use crate::error::JniError;
+use crate::result::JavaResult;
+use crate::token::{CallOutcome, NoException};
+use crate::version::JniVersion;
+use crate::virtual_thread::is_virtual_thread;
+use crate::vm::JavaVM;
+use std::collections::VecDeque;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+type Job = Box<
+    dyn for<'token> FnOnce(&'token JniEnv<'token>, NoException<'token>) -> NoException<'token>
+        + Send,
+>;
+
+/// Tasks submitted with [`submit_ordered`](struct.JvmExecutor.html#method.submit_ordered).
+///
+/// At most one job draining this queue is in the shared job queue at any time,
+/// which guarantees that ordered tasks never run concurrently.
+struct OrderedJobs {
+    jobs: VecDeque<Job>,
+    draining: bool,
+}
+
+/// A pool of threads attached to a Java VM as daemons.
+///
+/// Tasks can be submitted from any thread, including threads that are not attached to the
+/// Java VM. Each task gets a [`JniEnv`](../struct.JniEnv.html) and a
+/// [`NoException`](../struct.NoException.html) token, same as in
+/// [`JavaVM::with_attached`](../struct.JavaVM.html#method.with_attached).
+///
+/// Task results are sent as a [`thread::Result`](https://doc.rust-lang.org/std/thread/type.Result.html),
+/// like [`JoinHandle::join`](https://doc.rust-lang.org/std/thread/struct.JoinHandle.html#method.join)
+/// returns. If a task panics, the panic is caught and sent to the receiver of the task result
+/// as an error, an exception left pending by the task is cleared and the thread continues
+/// running tasks, including ordered tasks that were waiting for it.
+///
+/// Dropping the executor waits for all submitted tasks to finish and detaches the threads.
+///
+/// # Example
+/// ```
+/// use rust_jni::executor::JvmExecutor;
+/// use rust_jni::java::lang::String;
+/// use rust_jni::*;
+/// use std::sync::Arc;
+///
+/// let init_arguments = InitArguments::default();
+/// let vm = Arc::new(JavaVM::create(&init_arguments).unwrap());
+/// let executor = JvmExecutor::new(vm, init_arguments.version(), 2).unwrap();
+/// let result = executor.submit(|env, token| {
+///     let string = String::new(env, &token, "Hello").unwrap();
+///     (string.len(&token), token)
+/// });
+/// assert_eq!(result.recv().unwrap().unwrap(), 5);
+/// ```
+pub struct JvmExecutor {
+    sender: Option<Sender<Job>>,
+    ordered_jobs: Arc<Mutex<OrderedJobs>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl JvmExecutor {
+    /// Create an executor with `thread_count` threads attached to the Java VM.
+    ///
+    /// Returns an error if any of the threads could not be attached.
+    /// Will panic if `thread_count` is zero.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#attachcurrentthreadasdaemon)
+    pub fn new(
+        vm: Arc<JavaVM>,
+        version: JniVersion,
+        thread_count: usize,
+    ) -> Result<Self, JniError> {
+        if thread_count == 0 {
+            panic!("JvmExecutor must have at least one thread.");
+        }
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let (started_sender, started_receiver) = mpsc::channel();
+        let workers = (0..thread_count)
+            .map(|index| {
+                let vm = vm.clone();
+                let receiver = receiver.clone();
+                let started_sender = started_sender.clone();
+                let name = format!("rust-jni-executor-{}", index);
+                let arguments = AttachArguments::named(version, name.clone());
+                thread::Builder::new()
+                    .name(name)
+                    .spawn(move || {
+                        let result = vm.with_attached_daemon(&arguments, |env, token| {
+                            let _ = started_sender.send(Ok(()));
+                            ((), run_jobs(env, token, &receiver))
+                        });
+                        if let Err(error) = result {
+                            let _ = started_sender.send(Err(error));
+                        }
+                    })
+                    .unwrap()
+            })
+            .collect();
+        let executor = JvmExecutor {
+            sender: Some(sender),
+            ordered_jobs: Arc::new(Mutex::new(OrderedJobs {
+                jobs: VecDeque::new(),
+                draining: false,
+            })),
+            workers,
+        };
+        for _ in 0..thread_count {
+            // Every worker either reports a successful attach or an error.
+            started_receiver.recv().unwrap()?;
+        }
+        Ok(executor)
+    }
+
+    /// Run a task on one of the executor threads.
+    ///
+    /// Returns a receiver for the task result. Tasks submitted with this method run concurrently
+    /// and in no particular order.
+    pub fn submit<R: Send + 'static>(
+        &self,
+        task: impl for<'token> FnOnce(
+                &'token JniEnv<'token>,
+                NoException<'token>,
+            ) -> (R, NoException<'token>)
+            + Send
+            + 'static,
+    ) -> Receiver<thread::Result<R>> {
+        let (job, receiver) = Self::job(task);
+        self.send(job);
+        receiver
+    }
+
+    /// Run a task on one of the executor threads after all tasks previously submitted with
+    /// this method are finished.
+    ///
+    /// Returns a receiver for the task result. Tasks submitted with this method run
+    /// one at a time in the order of submission, but not necessarily on the same thread.
+    pub fn submit_ordered<R: Send + 'static>(
+        &self,
+        task: impl for<'token> FnOnce(
+                &'token JniEnv<'token>,
+                NoException<'token>,
+            ) -> (R, NoException<'token>)
+            + Send
+            + 'static,
+    ) -> Receiver<thread::Result<R>> {
+        let (job, receiver) = Self::job(task);
+        let mut ordered_jobs = self.ordered_jobs.lock().unwrap();
+        ordered_jobs.jobs.push_back(job);
+        if !ordered_jobs.draining {
+            ordered_jobs.draining = true;
+            let ordered_jobs = self.ordered_jobs.clone();
+            self.send(Box::new(move |env, token| {
+                drain_ordered_jobs(env, token, &ordered_jobs)
+            }));
+        }
+        receiver
+    }
+
+    /// Run a task on one of the executor threads if the current thread is a Java
+    /// [virtual thread](../fn.is_virtual_thread.html), or on the current thread otherwise.
+    ///
+    /// Native methods called from virtual threads pin them to their carrier threads, so
+    /// long-running JNI work there blocks other virtual threads. This method moves the work to
+    /// the executor's platform threads and waits for the result, while platform threads run it
+    /// in place without the cost of a context switch.
+    ///
+    /// Will panic if the task panics on an executor thread.
+    pub fn run_on_platform_thread<'a, R: Send + 'static>(
+        &self,
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+        task: impl for<'token> FnOnce(
+                &'token JniEnv<'token>,
+                NoException<'token>,
+            ) -> (R, NoException<'token>)
+            + Send
+            + 'static,
+    ) -> JavaResult<'a, R> {
+        if is_virtual_thread(env, token)? {
+            let result = self
+                .submit(task)
+                .recv()
+                .expect("Executor threads don't stop while the executor is alive.");
+            Ok(result.unwrap_or_else(|panic| panic::resume_unwind(panic)))
+        } else {
+            token.with_owned(env, |token| {
+                let (result, token) = task(env, token);
+                CallOutcome::Ok((result, token))
+            })
+        }
+    }
+
+    /// Return the number of threads in the executor.
+    pub fn thread_count(&self) -> usize {
+        self.workers.len()
+    }
+
+    fn job<R: Send + 'static>(
+        task: impl for<'token> FnOnce(
+                &'token JniEnv<'token>,
+                NoException<'token>,
+            ) -> (R, NoException<'token>)
+            + Send
+            + 'static,
+    ) -> (Job, Receiver<thread::Result<R>>) {
+        let (sender, receiver) = mpsc::channel();
+        let job: Job = Box::new(move |env, token| {
+            // The task can't observe a broken state after a panic: the only state it shares
+            // with the executor is the token, which is lost and replaced.
+            match panic::catch_unwind(AssertUnwindSafe(|| task(env, token))) {
+                Ok((result, token)) => {
+                    // It's fine if nobody is waiting for the result.
+                    let _ = sender.send(Ok(result));
+                    token
+                }
+                Err(panic) => {
+                    let _ = sender.send(Err(panic));
+                    recover_token(env)
+                }
+            }
+        });
+        (job, receiver)
+    }
+
+    fn send(&self, job: Job) {
+        // Sending only fails if all workers have stopped, which they don't while the sender
+        // is alive. The job would be dropped then and the result receiver disconnected.
+        let _ = self.sender.as_ref().unwrap().send(job);
+    }
+}
+
+/// Wait for all submitted tasks to finish and detach the threads.
+impl Drop for JvmExecutor {
+    fn drop(&mut self) {
+        // Dropping the sender stops the workers once the job queue is empty.
+        self.sender.take();
+        for worker in self.workers.drain(..) {
+            // Panics of tasks are caught and reported to the task submitters.
+            let _ = worker.join();
+        }
+    }
+}
+
+impl ::std::fmt::Debug for JvmExecutor {
+    fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        formatter
+            .debug_struct("JvmExecutor")
+            .field("thread_count", &self.thread_count())
+            .finish()
+    }
+}
+
+fn run_jobs<'token>(
+    env: &'token JniEnv<'token>,
+    mut token: NoException<'token>,
+    receiver: &Mutex<Receiver<Job>>,
+) -> NoException<'token> {
+    loop {
+        // The lock must be released before running the job.
+        let job = receiver.lock().unwrap().recv();
+        match job {
+            Ok(job) => token = job(env, token),
+            Err(_) => return token,
+        }
+    }
+}
+
+/// Get a new token for the thread after a task panicked.
+///
+/// The token of the task is dropped when the panic unwinds the task, so the thread
+/// has no live tokens.
+fn recover_token<'token>(env: &'token JniEnv<'token>) -> NoException<'token> {
+    // The exception was thrown for the panicked task and nobody is going to handle it.
+    drop(env.take_pending_exception());
+    *env.has_token.borrow_mut() = true;
+    env.token()
+}
+
+fn drain_ordered_jobs<'token>(
+    env: &'token JniEnv<'token>,
+    mut token: NoException<'token>,
+    ordered_jobs: &Mutex<OrderedJobs>,
+) -> NoException<'token> {
+    loop {
+        // The lock must be released before running the job.
+        let job = {
+            let mut ordered_jobs = ordered_jobs.lock().unwrap();
+            let job = ordered_jobs.jobs.pop_front();
+            if job.is_none() {
+                ordered_jobs.draining = false;
+            }
+            job
+        };
+        match job {
+            Some(job) => token = job(env, token),
+            None => return token,
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! A macro for adding Rust methods to Java class wrappers.
+
+/// Declare a newtype around a Java class wrapper, so that Rust methods can be added to it.
+///
+/// Wrappers, including generated ones, are defined in other crates, so applications can't add
+/// inherent methods to them. This macro declares a struct wrapping the class and implements
+/// the same traits the wrapper does, so that the struct can be used anywhere the wrapper can:
+///  - [`Deref`](https://doc.rust-lang.org/std/ops/trait.Deref.html) to the wrapped type, so all
+///    of it's methods are available
+///  - [`JniSignature`](trait.JniSignature.html) and [`FromObject`](trait.FromObject.html) with
+///    the values of the wrapped type, so the struct can be passed to and returned from Java calls
+///  - `AsRef<Object>`, `From` conversions into [`Object`](java/lang/struct.Object.html) and from
+///    the wrapped type, [`Detachable`](trait.Detachable.html),
+///    [`Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html) and `PartialEq`
+///    with other Java objects
+///
+/// Methods are then added with a regular `impl` block.
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::String;
+/// #
+/// extend_java_class! {
+///     /// A Java string with domain helpers.
+///     pub struct Name<'env>(String<'env>);
+/// }
+///
+/// impl<'env> Name<'env> {
+///     pub fn greeting(&self, token: &NoException<'env>) -> std::string::String {
+///         format!("Hello, {}!", self.as_string(token))
+///     }
+/// }
+///
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let name = Name::from(String::new(env, &token, "Java")?);
+/// assert_eq!(name.greeting(&token), "Hello, Java!");
+/// assert_eq!(Name::signature(), "Ljava/lang/String;");
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! extend_java_class {
+    (
+        $(#[$attribute:meta])*
+        $visibility:vis struct $name:ident<$lifetime:lifetime>(
+            $($wrapped:ident)::+<$wrapped_lifetime:lifetime>
+        );
+    ) => {
+        $(#[$attribute])*
+        #[derive(Debug, Clone)]
+        $visibility struct $name<$lifetime>($($wrapped)::+<$lifetime>);
+
+        impl<'env> $name<'env> {
+            /// Get the wrapped value.
+            #[allow(dead_code)]
+            #[inline(always)]
+            pub fn into_inner(self) -> $($wrapped)::+<'env> {
+                self.0
+            }
+        }
+
+        impl<'env> ::core::ops::Deref for $name<'env> {
+            type Target = $($wrapped)::+<'env>;
+
+            #[inline(always)]
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl<'env> ::core::convert::AsRef<$crate::java::lang::Object<'env>> for $name<'env> {
+            #[inline(always)]
+            fn as_ref(&self) -> &$crate::java::lang::Object<'env> {
+                self.0.as_ref()
+            }
+        }
+
+        impl<'env> ::core::convert::From<$name<'env>> for $crate::java::lang::Object<'env> {
+            #[inline(always)]
+            fn from(value: $name<'env>) -> Self {
+                value.0.into()
+            }
+        }
+
+        impl<'env> ::core::convert::From<$($wrapped)::+<'env>> for $name<'env> {
+            #[inline(always)]
+            fn from(value: $($wrapped)::+<'env>) -> Self {
+                $name(value)
+            }
+        }
+
+        impl<'env> $crate::FromObject<'env> for $name<'env> {
+            #[inline(always)]
+            unsafe fn from_object(object: $crate::java::lang::Object<'env>) -> Self {
+                $name(<$($wrapped)::+<'env> as $crate::FromObject<'env>>::from_object(object))
+            }
+        }
+
+        impl $crate::JniSignature for $name<'_> {
+            #[inline(always)]
+            fn signature() -> &'static str {
+                <$($wrapped)::+<'_> as $crate::JniSignature>::signature()
+            }
+        }
+
+        impl<'env> $crate::Detachable for $name<'env> {
+            type Attached<'a> = $name<'a>;
+        }
+
+        impl<'env> ::core::fmt::Display for $name<'env> {
+            fn fmt(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(
+                    ::core::convert::AsRef::<$crate::java::lang::Object<'env>>::as_ref(self),
+                    formatter,
+                )
+            }
+        }
+
+        impl<'env, T> ::core::cmp::PartialEq<T> for $name<'env>
+        where
+            T: ::core::convert::AsRef<$crate::java::lang::Object<'env>>,
+        {
+            fn eq(&self, other: &T) -> bool {
+                ::core::convert::AsRef::<$crate::java::lang::Object<'env>>::as_ref(self)
+                    .eq(other.as_ref())
+            }
+        }
+    };
+}
@@ -0,0 +1,148 @@
+use crate::env::JniEnv;
+use crate::jni_bool;
+use core::ffi::c_char;
+use core::ptr::NonNull;
+use core::sync::atomic::{AtomicU8, Ordering};
+use jni_sys;
+#[cfg(feature = "std")]
+use std::process;
+
+include!("call_jni_method.rs");
+
+/// What to do when a Java call throws a fatal error.
+///
+/// Fatal errors are instances of
+/// [`VirtualMachineError`](https://docs.oracle.com/javase/10/docs/api/java/lang/VirtualMachineError.html),
+/// most notably
+/// [`OutOfMemoryError`](https://docs.oracle.com/javase/10/docs/api/java/lang/OutOfMemoryError.html)
+/// and [`StackOverflowError`](https://docs.oracle.com/javase/10/docs/api/java/lang/StackOverflowError.html).
+/// They indicate that the Java VM is broken or has run out of resources, so handling them like
+/// regular exceptions usually leads to cascading failures.
+///
+/// Other [`Error`](https://docs.oracle.com/javase/10/docs/api/java/lang/Error.html)-s, like
+/// [`AssertionError`](https://docs.oracle.com/javase/10/docs/api/java/lang/AssertionError.html),
+/// are always returned as regular exceptions.
+///
+/// The policy is set for the whole process with
+/// [`set_fatal_error_policy`](fn.set_fatal_error_policy.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FatalErrorPolicy {
+    /// Return fatal errors as regular exceptions and attempt to continue.
+    ///
+    /// This is the default.
+    Continue,
+    /// Print the fatal error and panic.
+    Panic,
+    /// Print the fatal error and abort the process.
+    ///
+    /// Without the `std` feature the process is aborted with the JNI `FatalError` function.
+    Abort,
+}
+
+impl FatalErrorPolicy {
+    fn from_raw(value: u8) -> Self {
+        match value {
+            0 => FatalErrorPolicy::Continue,
+            1 => FatalErrorPolicy::Panic,
+            2 => FatalErrorPolicy::Abort,
+            value => panic!("Unexpected fatal error policy value {}.", value),
+        }
+    }
+
+    fn to_raw(self) -> u8 {
+        match self {
+            FatalErrorPolicy::Continue => 0,
+            FatalErrorPolicy::Panic => 1,
+            FatalErrorPolicy::Abort => 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod fatal_error_policy_tests {
+    use super::*;
+
+    #[test]
+    fn to_from_raw() {
+        for policy in &[
+            FatalErrorPolicy::Continue,
+            FatalErrorPolicy::Panic,
+            FatalErrorPolicy::Abort,
+        ] {
+            assert_eq!(FatalErrorPolicy::from_raw(policy.to_raw()), *policy);
+        }
+    }
+}
+
+static FATAL_ERROR_POLICY: AtomicU8 = AtomicU8::new(0);
+
+/// Set the [`FatalErrorPolicy`](enum.FatalErrorPolicy.html) for the whole process.
+pub fn set_fatal_error_policy(policy: FatalErrorPolicy) {
+    FATAL_ERROR_POLICY.store(policy.to_raw(), Ordering::SeqCst);
+}
+
+/// Get the current [`FatalErrorPolicy`](enum.FatalErrorPolicy.html).
+pub fn fatal_error_policy() -> FatalErrorPolicy {
+    FatalErrorPolicy::from_raw(FATAL_ERROR_POLICY.load(Ordering::SeqCst))
+}
+
+/// Apply the [`FatalErrorPolicy`](enum.FatalErrorPolicy.html) to a just cleared exception.
+///
+/// Only returns if the policy allows to continue.
+///
+/// Unsafe because an incorrect throwable reference can be passed.
+#[cold]
+pub(crate) unsafe fn handle_cleared_exception(
+    env: &JniEnv,
+    raw_throwable: NonNull<jni_sys::_jobject>,
+) {
+    let policy = fatal_error_policy();
+    if policy == FatalErrorPolicy::Continue || !is_fatal(env, raw_throwable) {
+        return;
+    }
+    // `ExceptionDescribe` prints the exception and it's stack trace without allocating
+    // Java objects, which is important when the Java VM is out of memory.
+    call_jni_method!(env, Throw, raw_throwable.as_ptr());
+    call_jni_method!(env, ExceptionDescribe);
+    match policy {
+        FatalErrorPolicy::Panic => panic!("A fatal Java error was thrown, see the output above."),
+        #[cfg(feature = "std")]
+        FatalErrorPolicy::Abort => process::abort(),
+        // `FatalError` doesn't return.
+        #[cfg(not(feature = "std"))]
+        FatalErrorPolicy::Abort => call_jni_method!(
+            env,
+            FatalError,
+            "A fatal Java error was thrown, see the output above.\0".as_ptr() as *const c_char
+        ),
+        FatalErrorPolicy::Continue => unreachable!(),
+    }
+}
+
+/// Check if the throwable is a `VirtualMachineError`.
+///
+/// Doesn't use the exception tokens as they call this function themselves.
+///
+/// Unsafe because an incorrect throwable reference can be passed.
+unsafe fn is_fatal(env: &JniEnv, raw_throwable: NonNull<jni_sys::_jobject>) -> bool {
+    let class_name = "java/lang/VirtualMachineError\0";
+    let raw_class = call_jni_method!(env, FindClass, class_name.as_ptr() as *const c_char);
+    match NonNull::new(raw_class) {
+        // Not being able to load a core class means that the Java VM is in a bad shape,
+        // most likely out of memory.
+        None => {
+            call_jni_method!(env, ExceptionClear);
+            true
+        }
+        Some(raw_class) => {
+            let is_fatal = jni_bool::to_rust(call_jni_method!(
+                env,
+                IsInstanceOf,
+                raw_throwable.as_ptr(),
+                raw_class.as_ptr()
+            ));
+            call_jni_method!(env, DeleteLocalRef, raw_class.as_ptr());
+            is_fatal
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use crate::env::JniEnv;
+use crate::error::JniError;
+use crate::vm::JavaVMRef;
+use alloc::boxed::Box;
+use core::ptr::{self, NonNull};
+#[cfg(feature = "std")]
+use core::sync::atomic::AtomicBool;
+use core::sync::atomic::{AtomicPtr, AtomicU8, AtomicUsize, Ordering};
+use jni_sys;
+
+include!("call_jni_method.rs");
+
+/// How global references are deleted when they are
+/// [`drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html#tymethod.drop)-ed on threads
+/// that are not attached to the Java VM.
+///
+/// The policy is set for the whole process with
+/// [`set_global_ref_drop_policy`](fn.set_global_ref_drop_policy.html). Global references
+/// dropped on attached threads are always deleted right away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlobalRefDropPolicy {
+    /// Leak the reference. Dropping never attaches threads implicitly.
+    ///
+    /// Leaked references are counted by [`leaked_global_refs`](fn.leaked_global_refs.html).
+    /// The first leak in the process is also reported to `stderr` with the `std` feature.
+    Leak,
+    /// Attach the thread as a daemon for the duration of the
+    /// [`DeleteGlobalRef`](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#deleteglobalref)
+    /// call.
+    ///
+    /// The reference is leaked if the thread can't be attached.
+    Attach,
+    /// Queue the reference and delete it later on an attached thread.
+    ///
+    /// Queued references are deleted when global references are created or dropped on
+    /// attached threads and when
+    /// [`flush_deferred_global_refs`](fn.flush_deferred_global_refs.html) is called.
+    /// Avoids the cost of attaching threads that don't call JNI otherwise.
+    ///
+    /// This is the default.
+    Defer,
+}
+
+impl GlobalRefDropPolicy {
+    fn from_raw(value: u8) -> Self {
+        match value {
+            0 => GlobalRefDropPolicy::Leak,
+            1 => GlobalRefDropPolicy::Attach,
+            2 => GlobalRefDropPolicy::Defer,
+            value => panic!("Unexpected global reference drop policy value {}.", value),
+        }
+    }
+
+    const fn to_raw(self) -> u8 {
+        match self {
+            GlobalRefDropPolicy::Leak => 0,
+            GlobalRefDropPolicy::Attach => 1,
+            GlobalRefDropPolicy::Defer => 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod global_ref_drop_policy_tests {
+    use super::*;
+
+    #[test]
+    fn to_from_raw() {
+        for policy in &[
+            GlobalRefDropPolicy::Leak,
+            GlobalRefDropPolicy::Attach,
+            GlobalRefDropPolicy::Defer,
+        ] {
+            assert_eq!(GlobalRefDropPolicy::from_raw(policy.to_raw()), *policy);
+        }
+    }
+}
+
+static GLOBAL_REF_DROP_POLICY: AtomicU8 = AtomicU8::new(GlobalRefDropPolicy::Defer.to_raw());
+
+/// Set the [`GlobalRefDropPolicy`](enum.GlobalRefDropPolicy.html) for the whole process.
+pub fn set_global_ref_drop_policy(policy: GlobalRefDropPolicy) {
+    GLOBAL_REF_DROP_POLICY.store(policy.to_raw(), Ordering::SeqCst);
+}
+
+/// Get the current [`GlobalRefDropPolicy`](enum.GlobalRefDropPolicy.html).
+pub fn global_ref_drop_policy() -> GlobalRefDropPolicy {
+    GlobalRefDropPolicy::from_raw(GLOBAL_REF_DROP_POLICY.load(Ordering::SeqCst))
+}
+
+/// A global reference waiting to be deleted.
+struct DeferredRef {
+    raw_object: NonNull<jni_sys::_jobject>,
+    next: *mut DeferredRef,
+}
+
+/// A lock-free stack of global references waiting to be deleted.
+///
+/// References are pushed one by one and taken all at once, so the stack is not subject to
+/// the ABA problem.
+static DEFERRED_REFS: AtomicPtr<DeferredRef> = AtomicPtr::new(ptr::null_mut());
+static DEFERRED_REFS_COUNT: AtomicUsize = AtomicUsize::new(0);
+static LEAKED_REFS_COUNT: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "std")]
+static LEAK_REPORTED: AtomicBool = AtomicBool::new(false);
+
+fn defer(raw_object: NonNull<jni_sys::_jobject>) {
+    let node = Box::into_raw(Box::new(DeferredRef {
+        raw_object,
+        next: ptr::null_mut(),
+    }));
+    DEFERRED_REFS_COUNT.fetch_add(1, Ordering::SeqCst);
+    let mut head = DEFERRED_REFS.load(Ordering::Acquire);
+    loop {
+        // Safe because the node is not shared until it's pushed.
+        unsafe { (*node).next = head };
+        match DEFERRED_REFS.compare_exchange_weak(head, node, Ordering::AcqRel, Ordering::Acquire) {
+            Ok(_) => break,
+            Err(current) => head = current,
+        }
+    }
+}
+
+/// Delete the global reference now if the current thread is attached to the Java VM,
+/// otherwise according to the [`GlobalRefDropPolicy`](enum.GlobalRefDropPolicy.html).
+///
+/// Unsafe because the reference must be a valid global reference of the Java VM.
+pub(crate) unsafe fn delete_global_ref(vm: &JavaVMRef, raw_object: NonNull<jni_sys::_jobject>) {
+    // `DeleteGlobalRef` can be called with a pending exception.
+    let deleted = vm.with_attached_env(|env| {
+        call_jni_method!(env, DeleteGlobalRef, raw_object.as_ptr());
+        flush_deferred_global_refs(env);
+    });
+    if deleted.is_none() {
+        match global_ref_drop_policy() {
+            GlobalRefDropPolicy::Leak => leak(raw_object, None),
+            GlobalRefDropPolicy::Attach => {
+                let deleted = vm.try_with_current_env(|env| {
+                    call_jni_method!(env, DeleteGlobalRef, raw_object.as_ptr());
+                });
+                if let Err(error) = deleted {
+                    leak(raw_object, Some(error));
+                }
+            }
+            GlobalRefDropPolicy::Defer => defer(raw_object),
+        }
+    }
+}
+
+/// Leak a global reference that can't be deleted on the current thread.
+///
+/// Dropping must not panic, so the leak is only counted and the first one is reported.
+#[cfg_attr(not(feature = "std"), allow(unused_variables))]
+fn leak(raw_object: NonNull<jni_sys::_jobject>, error: Option<JniError>) {
+    LEAKED_REFS_COUNT.fetch_add(1, Ordering::SeqCst);
+    #[cfg(feature = "std")]
+    {
+        if LEAK_REPORTED.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        match error {
+            None => eprintln!(
+                "rust-jni: leaked global reference {:?} dropped on a thread that is not attached \
+                 to the Java VM, see GlobalRefDropPolicy. Further leaks are only counted by \
+                 leaked_global_refs",
+                raw_object
+            ),
+            Some(error) => eprintln!(
+                "rust-jni: leaked global reference {:?} because the current thread could not be \
+                 attached to the Java VM. Status: {:?}. Further leaks are only counted by \
+                 leaked_global_refs",
+                raw_object, error
+            ),
+        }
+    }
+}
+
+/// Delete global references queued by the
+/// [`Defer`](enum.GlobalRefDropPolicy.html#variant.Defer) policy.
+///
+/// Returns the number of deleted references.
+///
+/// Only one Java VM per process is supported, so all queued references belong to the Java VM
+/// of `env`.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#deleteglobalref)
+pub fn flush_deferred_global_refs(env: &JniEnv) -> usize {
+    if DEFERRED_REFS.load(Ordering::Acquire).is_null() {
+        return 0;
+    }
+    let mut node = DEFERRED_REFS.swap(ptr::null_mut(), Ordering::AcqRel);
+    let mut count = 0;
+    while !node.is_null() {
+        // Safe because the nodes were taken from the stack and are owned by this function now.
+        let deferred = unsafe { Box::from_raw(node) };
+        // Safe because `DeleteGlobalRef` can be called with a pending exception and
+        // only valid global references are queued.
+        unsafe {
+            call_jni_method!(env, DeleteGlobalRef, deferred.raw_object.as_ptr());
+        }
+        node = deferred.next;
+        count += 1;
+    }
+    DEFERRED_REFS_COUNT.fetch_sub(count, Ordering::SeqCst);
+    count
+}
+
+/// Get the number of global references queued by the
+/// [`Defer`](enum.GlobalRefDropPolicy.html#variant.Defer) policy that are not deleted yet.
+pub fn deferred_global_refs() -> usize {
+    DEFERRED_REFS_COUNT.load(Ordering::SeqCst)
+}
+
+/// Get the number of global references leaked because they were dropped on threads that are
+/// not attached to the Java VM, see [`GlobalRefDropPolicy`](enum.GlobalRefDropPolicy.html).
+pub fn leaked_global_refs() -> usize {
+    LEAKED_REFS_COUNT.load(Ordering::SeqCst)
+}
@@ -0,0 +1,1570 @@
+// Without the invocation API init arguments are never converted to and from JNI.
+#![cfg_attr(feature = "no-invocation", allow(dead_code))]
+
+#[cfg(not(feature = "no-invocation"))]
+use crate::error::JniError;
+use crate::error::VmCreationError;
+use crate::jni_bool;
+use crate::version::JniVersion;
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use cfg_if::cfg_if;
+#[cfg(not(feature = "no-invocation"))]
+use core::ffi::c_void;
+use core::marker::PhantomData;
+use core::ptr;
+use core::slice;
+use jni_sys;
+use {alloc::ffi::CString, core::ffi::CStr};
+
+/// Verbose options for starting a Java VM.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JvmVerboseOption {
+    /// Verbose class option.
+    ///
+    /// Passed to the JVM as `-verbose:class`.
+    Class,
+    /// Verbose GC option.
+    ///
+    /// Passed to the JVM as `-verbose:gc`.
+    Gc,
+    /// Verbose JNI option.
+    ///
+    /// Passed to the JVM as `-verbose:jni`.
+    Jni,
+}
+
+impl JvmVerboseOption {
+    fn to_string(&self) -> &'static str {
+        match self {
+            JvmVerboseOption::Class => "class",
+            JvmVerboseOption::Gc => "gc",
+            JvmVerboseOption::Jni => "jni",
+        }
+    }
+}
+
+#[cfg(test)]
+mod verbose_option_to_string_tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(JvmVerboseOption::Class.to_string(), "class");
+        assert_eq!(JvmVerboseOption::Gc.to_string(), "gc");
+        assert_eq!(JvmVerboseOption::Jni.to_string(), "jni");
+    }
+}
+
+/// A memory size for starting a Java VM.
+///
+/// Rendered with the corresponding JVM size suffix: `1024`, `64k`, `512m` or `2g`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JvmMemorySize {
+    /// Size in bytes.
+    Bytes(u64),
+    /// Size in kilobytes.
+    Kilobytes(u64),
+    /// Size in megabytes.
+    Megabytes(u64),
+    /// Size in gigabytes.
+    Gigabytes(u64),
+}
+
+impl JvmMemorySize {
+    fn parse(value: &str) -> Option<Self> {
+        let (number, constructor): (&str, fn(u64) -> Self) = match value.chars().last()? {
+            'k' | 'K' => (&value[..value.len() - 1], JvmMemorySize::Kilobytes),
+            'm' | 'M' => (&value[..value.len() - 1], JvmMemorySize::Megabytes),
+            'g' | 'G' => (&value[..value.len() - 1], JvmMemorySize::Gigabytes),
+            _ => (value, JvmMemorySize::Bytes),
+        };
+        if number.is_empty() || !number.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+        number.parse().ok().map(constructor)
+    }
+
+    fn value(&self) -> u64 {
+        match self {
+            JvmMemorySize::Bytes(value)
+            | JvmMemorySize::Kilobytes(value)
+            | JvmMemorySize::Megabytes(value)
+            | JvmMemorySize::Gigabytes(value) => *value,
+        }
+    }
+
+    fn to_string(&self) -> String {
+        match self {
+            JvmMemorySize::Bytes(value) => format!("{}", value),
+            JvmMemorySize::Kilobytes(value) => format!("{}k", value),
+            JvmMemorySize::Megabytes(value) => format!("{}m", value),
+            JvmMemorySize::Gigabytes(value) => format!("{}g", value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod memory_size_tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            JvmMemorySize::parse("1024"),
+            Some(JvmMemorySize::Bytes(1024))
+        );
+        assert_eq!(
+            JvmMemorySize::parse("64k"),
+            Some(JvmMemorySize::Kilobytes(64))
+        );
+        assert_eq!(
+            JvmMemorySize::parse("512M"),
+            Some(JvmMemorySize::Megabytes(512))
+        );
+        assert_eq!(
+            JvmMemorySize::parse("2g"),
+            Some(JvmMemorySize::Gigabytes(2))
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(JvmMemorySize::parse(""), None);
+        assert_eq!(JvmMemorySize::parse("m"), None);
+        assert_eq!(JvmMemorySize::parse("-1m"), None);
+        assert_eq!(JvmMemorySize::parse("12t"), None);
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(JvmMemorySize::Bytes(1024).to_string(), "1024");
+        assert_eq!(JvmMemorySize::Kilobytes(64).to_string(), "64k");
+        assert_eq!(JvmMemorySize::Megabytes(512).to_string(), "512m");
+        assert_eq!(JvmMemorySize::Gigabytes(2).to_string(), "2g");
+    }
+}
+
+/// Scope of the assertion options for starting a Java VM.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JvmAssertionsScope {
+    /// All classes except system classes.
+    ///
+    /// Passed to the JVM without a scope, as in `-ea`.
+    All,
+    /// A package and all of it's subpackages. An empty package name denotes the unnamed package.
+    ///
+    /// Passed to the JVM as `-ea:${package}...`.
+    Package(String),
+    /// A single class.
+    ///
+    /// Passed to the JVM as `-ea:${class}`.
+    Class(String),
+}
+
+impl JvmAssertionsScope {
+    fn parse(value: &str) -> Option<Self> {
+        if value.is_empty() {
+            Some(JvmAssertionsScope::All)
+        } else if !value.starts_with(':') || value.len() == 1 {
+            None
+        } else if value.ends_with("...") {
+            Some(JvmAssertionsScope::Package(
+                value[1..value.len() - 3].to_owned(),
+            ))
+        } else {
+            Some(JvmAssertionsScope::Class(value[1..].to_owned()))
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let (name, allow_empty) = match self {
+            JvmAssertionsScope::All => return Ok(()),
+            JvmAssertionsScope::Package(name) => (name, true),
+            JvmAssertionsScope::Class(name) => (name, false),
+        };
+        if (name.is_empty() && !allow_empty)
+            || name.starts_with('.')
+            || name.ends_with('.')
+            || name.contains("..")
+            || !name
+                .chars()
+                .all(|c| c == '.' || c == '_' || c == '$' || c.is_alphanumeric())
+        {
+            Err(format!("Invalid assertions scope name \"{}\".", name))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn to_string(&self) -> String {
+        match self {
+            JvmAssertionsScope::All => String::new(),
+            JvmAssertionsScope::Package(name) => format!(":{}...", name),
+            JvmAssertionsScope::Class(name) => format!(":{}", name),
+        }
+    }
+}
+
+/// Options for starting a Java VM.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JvmOption {
+    /// Unknown option.
+    /// Needed for forward compability and to set custom options.
+    /// The string value is passed to the JVM without change.
+    Unknown(String),
+    /// Enable checking JNI calls.
+    ///
+    /// Passed to the JVM as `-check:jni`.
+    CheckedJni,
+    /// Verbose option.
+    ///
+    /// Passed to the JVM as `-verbose:${verbose_option}`.
+    Verbose(JvmVerboseOption),
+    /// Maximum heap size.
+    ///
+    /// Passed to the JVM as `-Xmx${size}`.
+    MaxHeapSize(JvmMemorySize),
+    /// Initial heap size.
+    ///
+    /// Passed to the JVM as `-Xms${size}`.
+    InitialHeapSize(JvmMemorySize),
+    /// Thread stack size.
+    ///
+    /// Passed to the JVM as `-Xss${size}`.
+    ThreadStackSize(JvmMemorySize),
+    /// Enable assertions.
+    ///
+    /// Passed to the JVM as `-ea` or `-ea:${scope}`.
+    EnableAssertions(JvmAssertionsScope),
+    /// Disable assertions.
+    ///
+    /// Passed to the JVM as `-da` or `-da:${scope}`.
+    DisableAssertions(JvmAssertionsScope),
+    /// Load a native agent library by name.
+    ///
+    /// Passed to the JVM as `-agentlib:${name}` or `-agentlib:${name}=${options}`.
+    AgentLibrary {
+        /// Name of the agent library, for example `jdwp`.
+        name: String,
+        /// Options passed to the agent.
+        options: Option<String>,
+    },
+    /// Load a Java programming language agent.
+    ///
+    /// Passed to the JVM as `-javaagent:${path}` or `-javaagent:${path}=${options}`.
+    JavaAgent {
+        /// Path to the agent JAR file.
+        path: String,
+        /// Options passed to the agent.
+        options: Option<String>,
+    },
+    /// Open a package of a module to other modules for deep reflection.
+    ///
+    /// Passed to the JVM as `--add-opens=${module}/${package}=${target_modules}`,
+    /// where target modules are separated by commas.
+    AddOpens {
+        /// Module containing the package, for example `java.base`.
+        module: String,
+        /// Package to open, for example `java.lang`.
+        package: String,
+        /// Modules the package is opened to, for example `ALL-UNNAMED`.
+        target_modules: Vec<String>,
+    },
+}
+
+impl JvmOption {
+    /// Unsafe because one can pass a non-UTF-8 or non-null-terminated option string.
+    unsafe fn from_raw(option: &jni_sys::JavaVMOption) -> Self {
+        // TODO(#14): support platform encodings other than UTF-8.
+        let option_string = CStr::from_ptr((*option).optionString).to_str().unwrap();
+        match option_string {
+            "-Xcheck:jni" => JvmOption::CheckedJni,
+            "-verbose:gc" => JvmOption::Verbose(JvmVerboseOption::Gc),
+            "-verbose:jni" => JvmOption::Verbose(JvmVerboseOption::Jni),
+            "-verbose:class" => JvmOption::Verbose(JvmVerboseOption::Class),
+            option => JvmOption::parse_typed(option)
+                .unwrap_or_else(|| JvmOption::Unknown(option.to_owned())),
+        }
+    }
+
+    /// Parse options that carry a value. Returns `None` for options that are not recognized
+    /// or not valid so that they can be kept as unknown options.
+    fn parse_typed(option: &str) -> Option<Self> {
+        fn split_options(value: &str) -> (String, Option<String>) {
+            match value.find('=') {
+                Some(index) => (
+                    value[..index].to_owned(),
+                    Some(value[index + 1..].to_owned()),
+                ),
+                None => (value.to_owned(), None),
+            }
+        }
+
+        let parsed = if option.starts_with("-Xmx") {
+            JvmMemorySize::parse(&option[4..]).map(JvmOption::MaxHeapSize)
+        } else if option.starts_with("-Xms") {
+            JvmMemorySize::parse(&option[4..]).map(JvmOption::InitialHeapSize)
+        } else if option.starts_with("-Xss") {
+            JvmMemorySize::parse(&option[4..]).map(JvmOption::ThreadStackSize)
+        } else if option.starts_with("-ea") {
+            JvmAssertionsScope::parse(&option[3..]).map(JvmOption::EnableAssertions)
+        } else if option.starts_with("-da") {
+            JvmAssertionsScope::parse(&option[3..]).map(JvmOption::DisableAssertions)
+        } else if option.starts_with("-agentlib:") {
+            let (name, options) = split_options(&option[10..]);
+            Some(JvmOption::AgentLibrary { name, options })
+        } else if option.starts_with("-javaagent:") {
+            let (path, options) = split_options(&option[11..]);
+            Some(JvmOption::JavaAgent { path, options })
+        } else if option.starts_with("--add-opens=") {
+            let value = &option[12..];
+            let slash = value.find('/')?;
+            let equals = value.find('=')?;
+            if equals < slash {
+                return None;
+            }
+            Some(JvmOption::AddOpens {
+                module: value[..slash].to_owned(),
+                package: value[slash + 1..equals].to_owned(),
+                target_modules: value[equals + 1..]
+                    .split(',')
+                    .map(|s| s.to_owned())
+                    .collect(),
+            })
+        } else {
+            None
+        }?;
+        parsed.validate().ok().map(|_| parsed)
+    }
+
+    /// Check that the option can be rendered correctly.
+    /// Returns a description of the problem otherwise.
+    fn validate(&self) -> Result<(), String> {
+        fn check_name(kind: &str, name: &str, forbidden: &[char]) -> Result<(), String> {
+            if name.is_empty() || name.contains(forbidden) || name.contains('\0') {
+                Err(format!("Invalid {} \"{}\".", kind, name))
+            } else {
+                Ok(())
+            }
+        }
+
+        match self {
+            JvmOption::Unknown(value) => {
+                if value.contains('\0') {
+                    return Err(format!("Invalid option \"{}\".", value));
+                }
+                JvmOption::validate_unknown(value)?
+            }
+            JvmOption::CheckedJni | JvmOption::Verbose(_) => {}
+            JvmOption::MaxHeapSize(size)
+            | JvmOption::InitialHeapSize(size)
+            | JvmOption::ThreadStackSize(size) => {
+                if size.value() == 0 {
+                    return Err(format!("Memory size must be positive, got {:?}.", size));
+                }
+            }
+            JvmOption::EnableAssertions(scope) | JvmOption::DisableAssertions(scope) => {
+                scope.validate()?
+            }
+            JvmOption::AgentLibrary { name, options } => {
+                check_name("agent library name", name, &['=', ' '])?;
+                if let Some(options) = options {
+                    check_name("agent library options", options, &[])?;
+                }
+            }
+            JvmOption::JavaAgent { path, options } => {
+                check_name("Java agent path", path, &['='])?;
+                if let Some(options) = options {
+                    check_name("Java agent options", options, &[])?;
+                }
+            }
+            JvmOption::AddOpens {
+                module,
+                package,
+                target_modules,
+            } => {
+                check_name("module name", module, &['/', '=', ',', ' '])?;
+                check_name("package name", package, &['/', '=', ',', ' '])?;
+                if target_modules.is_empty() {
+                    return Err(format!(
+                        "Package {}/{} must be opened to at least one module.",
+                        module, package
+                    ));
+                }
+                for target_module in target_modules {
+                    check_name("target module name", target_module, &['/', '=', ',', ' '])?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the syntax of `-X`, `-XX:` and `-D` options the Java VM would reject.
+    /// Other unknown options are passed to the Java VM unchecked.
+    fn validate_unknown(value: &str) -> Result<(), String> {
+        if value == "-X" {
+            Err("Missing option name after -X.".to_owned())
+        } else if let Some(flag) = value.strip_prefix("-XX:") {
+            let name = match flag.strip_prefix('+').or_else(|| flag.strip_prefix('-')) {
+                Some(name) => name,
+                None => match flag.find('=') {
+                    Some(index) => &flag[..index],
+                    None => {
+                        return Err(format!(
+                        "Invalid option \"{}\", expected -XX:+Name, -XX:-Name or -XX:Name=value.",
+                        value
+                    ))
+                    }
+                },
+            };
+            if name.is_empty()
+                || !name
+                    .chars()
+                    .all(|character| character.is_ascii_alphanumeric() || character == '_')
+            {
+                Err(format!("Invalid flag name in option \"{}\".", value))
+            } else {
+                Ok(())
+            }
+        } else if value.starts_with("-Xmx")
+            || value.starts_with("-Xms")
+            || value.starts_with("-Xss")
+        {
+            match JvmMemorySize::parse(&value[4..]) {
+                Some(size) if size.value() > 0 => Ok(()),
+                _ => Err(format!("Invalid memory size in option \"{}\".", value)),
+            }
+        } else if value.starts_with("-D") && JvmOption::system_property_key(value) == Some("") {
+            Err(format!(
+                "Missing system property name in option \"{}\".",
+                value
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Get the key of a system property option `-Dkey=value`.
+    fn system_property_key(value: &str) -> Option<&str> {
+        let property = value.strip_prefix("-D")?;
+        Some(match property.find('=') {
+            Some(index) => &property[..index],
+            None => property,
+        })
+    }
+
+    /// Render the option string passed to the Java VM.
+    fn option_string(&self) -> String {
+        fn append_options(value: String, options: &Option<String>) -> String {
+            match options {
+                Some(options) => format!("{}={}", value, options),
+                None => value,
+            }
+        }
+
+        match self {
+            JvmOption::Unknown(value) => value.clone(),
+            JvmOption::CheckedJni => "-Xcheck:jni".to_owned(),
+            JvmOption::Verbose(option) => format!("-verbose:{}", option.to_string()),
+            JvmOption::MaxHeapSize(size) => format!("-Xmx{}", size.to_string()),
+            JvmOption::InitialHeapSize(size) => format!("-Xms{}", size.to_string()),
+            JvmOption::ThreadStackSize(size) => format!("-Xss{}", size.to_string()),
+            JvmOption::EnableAssertions(scope) => format!("-ea{}", scope.to_string()),
+            JvmOption::DisableAssertions(scope) => {
+                format!("-da{}", scope.to_string())
+            }
+            JvmOption::AgentLibrary { name, options } => {
+                append_options(format!("-agentlib:{}", name), options)
+            }
+            JvmOption::JavaAgent { path, options } => {
+                append_options(format!("-javaagent:{}", path), options)
+            }
+            JvmOption::AddOpens {
+                module,
+                package,
+                target_modules,
+            } => format!(
+                "--add-opens={}/{}={}",
+                module,
+                package,
+                target_modules.join(",")
+            ),
+        }
+    }
+
+    fn to_string(&self) -> CString {
+        CString::new(self.option_string()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod jvm_option_tests {
+    use super::*;
+
+    fn raw_vm_option(option_string: &CStr) -> jni_sys::JavaVMOption {
+        jni_sys::JavaVMOption {
+            optionString: option_string.as_ptr() as *mut i8,
+            extraInfo: ptr::null_mut(),
+        }
+    }
+
+    #[test]
+    fn from_raw_unknown() {
+        let option_string = CStr::from_bytes_with_nul(b"tyhb\0").unwrap();
+        let option = raw_vm_option(&option_string);
+        assert_eq!(
+            unsafe { JvmOption::from_raw(&option) },
+            JvmOption::Unknown("tyhb".to_owned())
+        );
+    }
+
+    #[test]
+    fn from_raw_checked_jni() {
+        let option_string = CStr::from_bytes_with_nul(b"-Xcheck:jni\0").unwrap();
+        let option = raw_vm_option(&option_string);
+        assert_eq!(
+            unsafe { JvmOption::from_raw(&option) },
+            JvmOption::CheckedJni
+        );
+    }
+
+    #[test]
+    fn from_raw_verbose() {
+        let option_string = CStr::from_bytes_with_nul(b"-verbose:jni\0").unwrap();
+        let option = raw_vm_option(&option_string);
+        assert_eq!(
+            unsafe { JvmOption::from_raw(&option) },
+            JvmOption::Verbose(JvmVerboseOption::Jni)
+        );
+
+        let option_string = CStr::from_bytes_with_nul(b"-verbose:gc\0").unwrap();
+        let option = raw_vm_option(&option_string);
+        assert_eq!(
+            unsafe { JvmOption::from_raw(&option) },
+            JvmOption::Verbose(JvmVerboseOption::Gc)
+        );
+
+        let option_string = CStr::from_bytes_with_nul(b"-verbose:class\0").unwrap();
+        let option = raw_vm_option(&option_string);
+        assert_eq!(
+            unsafe { JvmOption::from_raw(&option) },
+            JvmOption::Verbose(JvmVerboseOption::Class)
+        );
+    }
+
+    fn parse(option: &str) -> JvmOption {
+        let option_string = CString::new(option).unwrap();
+        let option = raw_vm_option(&option_string);
+        unsafe { JvmOption::from_raw(&option) }
+    }
+
+    #[test]
+    fn from_raw_memory_sizes() {
+        assert_eq!(
+            parse("-Xmx512m"),
+            JvmOption::MaxHeapSize(JvmMemorySize::Megabytes(512))
+        );
+        assert_eq!(
+            parse("-Xms64K"),
+            JvmOption::InitialHeapSize(JvmMemorySize::Kilobytes(64))
+        );
+        assert_eq!(
+            parse("-Xss1048576"),
+            JvmOption::ThreadStackSize(JvmMemorySize::Bytes(1048576))
+        );
+        assert_eq!(parse("-Xmx0"), JvmOption::Unknown("-Xmx0".to_owned()));
+        assert_eq!(parse("-Xmxabc"), JvmOption::Unknown("-Xmxabc".to_owned()));
+    }
+
+    #[test]
+    fn from_raw_assertions() {
+        assert_eq!(
+            parse("-ea"),
+            JvmOption::EnableAssertions(JvmAssertionsScope::All)
+        );
+        assert_eq!(
+            parse("-ea:com.example..."),
+            JvmOption::EnableAssertions(JvmAssertionsScope::Package("com.example".to_owned()))
+        );
+        assert_eq!(
+            parse("-da:..."),
+            JvmOption::DisableAssertions(JvmAssertionsScope::Package("".to_owned()))
+        );
+        assert_eq!(
+            parse("-da:com.example.Main"),
+            JvmOption::DisableAssertions(JvmAssertionsScope::Class("com.example.Main".to_owned()))
+        );
+        assert_eq!(parse("-eax"), JvmOption::Unknown("-eax".to_owned()));
+    }
+
+    #[test]
+    fn from_raw_agents() {
+        assert_eq!(
+            parse("-agentlib:jdwp=transport=dt_socket,server=y"),
+            JvmOption::AgentLibrary {
+                name: "jdwp".to_owned(),
+                options: Some("transport=dt_socket,server=y".to_owned()),
+            }
+        );
+        assert_eq!(
+            parse("-javaagent:/path/agent.jar"),
+            JvmOption::JavaAgent {
+                path: "/path/agent.jar".to_owned(),
+                options: None,
+            }
+        );
+        assert_eq!(
+            parse("-agentlib:"),
+            JvmOption::Unknown("-agentlib:".to_owned())
+        );
+    }
+
+    #[test]
+    fn from_raw_add_opens() {
+        assert_eq!(
+            parse("--add-opens=java.base/java.lang=ALL-UNNAMED,my.module"),
+            JvmOption::AddOpens {
+                module: "java.base".to_owned(),
+                package: "java.lang".to_owned(),
+                target_modules: vec!["ALL-UNNAMED".to_owned(), "my.module".to_owned()],
+            }
+        );
+        assert_eq!(
+            parse("--add-opens=java.base=ALL-UNNAMED"),
+            JvmOption::Unknown("--add-opens=java.base=ALL-UNNAMED".to_owned())
+        );
+    }
+}
+
+#[cfg(test)]
+mod option_to_string_tests {
+    use super::*;
+
+    #[test]
+    fn to_string_unknown() {
+        assert_eq!(
+            JvmOption::Unknown("qwer".into()).to_string(),
+            CString::new("qwer").unwrap()
+        );
+    }
+
+    #[test]
+    fn to_string_checked_jni() {
+        assert_eq!(
+            JvmOption::CheckedJni.to_string(),
+            CString::new("-Xcheck:jni").unwrap()
+        );
+    }
+
+    #[test]
+    fn to_string_verbose() {
+        assert_eq!(
+            JvmOption::Verbose(JvmVerboseOption::Gc).to_string(),
+            CString::new("-verbose:gc").unwrap()
+        );
+        assert_eq!(
+            JvmOption::Verbose(JvmVerboseOption::Jni).to_string(),
+            CString::new("-verbose:jni").unwrap()
+        );
+        assert_eq!(
+            JvmOption::Verbose(JvmVerboseOption::Class).to_string(),
+            CString::new("-verbose:class").unwrap()
+        );
+    }
+
+    #[test]
+    fn to_string_memory_sizes() {
+        assert_eq!(
+            JvmOption::MaxHeapSize(JvmMemorySize::Gigabytes(2)).to_string(),
+            CString::new("-Xmx2g").unwrap()
+        );
+        assert_eq!(
+            JvmOption::InitialHeapSize(JvmMemorySize::Megabytes(256)).to_string(),
+            CString::new("-Xms256m").unwrap()
+        );
+        assert_eq!(
+            JvmOption::ThreadStackSize(JvmMemorySize::Kilobytes(512)).to_string(),
+            CString::new("-Xss512k").unwrap()
+        );
+    }
+
+    #[test]
+    fn to_string_assertions() {
+        assert_eq!(
+            JvmOption::EnableAssertions(JvmAssertionsScope::All).to_string(),
+            CString::new("-ea").unwrap()
+        );
+        assert_eq!(
+            JvmOption::EnableAssertions(JvmAssertionsScope::Package("com.example".to_owned()))
+                .to_string(),
+            CString::new("-ea:com.example...").unwrap()
+        );
+        assert_eq!(
+            JvmOption::DisableAssertions(JvmAssertionsScope::Class("com.example.Main".to_owned()))
+                .to_string(),
+            CString::new("-da:com.example.Main").unwrap()
+        );
+    }
+
+    #[test]
+    fn to_string_agents() {
+        assert_eq!(
+            JvmOption::AgentLibrary {
+                name: "jdwp".to_owned(),
+                options: Some("transport=dt_socket".to_owned()),
+            }
+            .to_string(),
+            CString::new("-agentlib:jdwp=transport=dt_socket").unwrap()
+        );
+        assert_eq!(
+            JvmOption::JavaAgent {
+                path: "agent.jar".to_owned(),
+                options: None,
+            }
+            .to_string(),
+            CString::new("-javaagent:agent.jar").unwrap()
+        );
+    }
+
+    #[test]
+    fn to_string_add_opens() {
+        assert_eq!(
+            JvmOption::AddOpens {
+                module: "java.base".to_owned(),
+                package: "java.lang".to_owned(),
+                target_modules: vec!["ALL-UNNAMED".to_owned()],
+            }
+            .to_string(),
+            CString::new("--add-opens=java.base/java.lang=ALL-UNNAMED").unwrap()
+        );
+    }
+}
+
+#[cfg(test)]
+mod option_validation_tests {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        assert_eq!(JvmOption::CheckedJni.validate(), Ok(()));
+        assert_eq!(
+            JvmOption::MaxHeapSize(JvmMemorySize::Megabytes(1)).validate(),
+            Ok(())
+        );
+        assert_eq!(
+            JvmOption::EnableAssertions(JvmAssertionsScope::Package("".to_owned())).validate(),
+            Ok(())
+        );
+        assert_eq!(
+            JvmOption::DisableAssertions(JvmAssertionsScope::Class("a.b$C".to_owned())).validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn zero_memory_size() {
+        assert!(JvmOption::ThreadStackSize(JvmMemorySize::Kilobytes(0))
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn invalid_assertions_scope() {
+        assert!(
+            JvmOption::EnableAssertions(JvmAssertionsScope::Class("".to_owned()))
+                .validate()
+                .is_err()
+        );
+        assert!(JvmOption::EnableAssertions(JvmAssertionsScope::Package(
+            "com..example".to_owned()
+        ))
+        .validate()
+        .is_err());
+        assert!(
+            JvmOption::DisableAssertions(JvmAssertionsScope::Class("com example".to_owned()))
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn invalid_agents() {
+        assert!(JvmOption::AgentLibrary {
+            name: "".to_owned(),
+            options: None,
+        }
+        .validate()
+        .is_err());
+        assert!(JvmOption::JavaAgent {
+            path: "a=b.jar".to_owned(),
+            options: None,
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn invalid_add_opens() {
+        assert!(JvmOption::AddOpens {
+            module: "java.base".to_owned(),
+            package: "java.lang".to_owned(),
+            target_modules: vec![],
+        }
+        .validate()
+        .is_err());
+        assert!(JvmOption::AddOpens {
+            module: "java.base".to_owned(),
+            package: "java/lang".to_owned(),
+            target_modules: vec!["ALL-UNNAMED".to_owned()],
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn invalid_unknown() {
+        assert!(JvmOption::Unknown("a\0b".to_owned()).validate().is_err());
+    }
+
+    #[test]
+    fn valid_unknown() {
+        for option in &[
+            "-Xcheck:jni",
+            "-Xmx2g",
+            "-XX:+UseG1GC",
+            "-XX:-UseCompressedOops",
+            "-XX:MaxMetaspaceSize=64m",
+            "-Dkey=value",
+            "-Dflag",
+            "--enable-preview",
+        ] {
+            assert_eq!(
+                JvmOption::Unknown((*option).to_owned()).validate(),
+                Ok(()),
+                "{}",
+                option
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_unknown_syntax() {
+        assert_eq!(
+            JvmOption::Unknown("-X".to_owned()).validate(),
+            Err("Missing option name after -X.".to_owned())
+        );
+        assert_eq!(
+            JvmOption::Unknown("-XX:UseG1GC".to_owned()).validate(),
+            Err(
+                "Invalid option \"-XX:UseG1GC\", expected -XX:+Name, -XX:-Name or -XX:Name=value."
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            JvmOption::Unknown("-XX:+".to_owned()).validate(),
+            Err("Invalid flag name in option \"-XX:+\".".to_owned())
+        );
+        assert_eq!(
+            JvmOption::Unknown("-XX:Max Size=1".to_owned()).validate(),
+            Err("Invalid flag name in option \"-XX:Max Size=1\".".to_owned())
+        );
+        assert_eq!(
+            JvmOption::Unknown("-Xmx512x".to_owned()).validate(),
+            Err("Invalid memory size in option \"-Xmx512x\".".to_owned())
+        );
+        assert_eq!(
+            JvmOption::Unknown("-Xss0".to_owned()).validate(),
+            Err("Invalid memory size in option \"-Xss0\".".to_owned())
+        );
+        assert_eq!(
+            JvmOption::Unknown("-D=value".to_owned()).validate(),
+            Err("Missing system property name in option \"-D=value\".".to_owned())
+        );
+    }
+}
+
+/// Arguments for creating a Java VM.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
+///
+/// # Example
+/// ```
+/// use rust_jni::{InitArguments, JniVersion, JvmMemorySize, JvmOption, JvmVerboseOption};
+///
+/// let options = InitArguments::default()
+///     .with_option(JvmOption::Unknown("-Xgc:parallel".to_owned()))
+///     .with_option(JvmOption::Verbose(JvmVerboseOption::Gc))
+///     .with_option(JvmOption::MaxHeapSize(JvmMemorySize::Megabytes(512)));
+///
+/// assert_eq!(options.version(), JniVersion::V8);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitArguments {
+    version: JniVersion,
+    options: Vec<JvmOption>,
+    ignore_unrecognized: bool,
+}
+
+/// Default JVM init arguments.
+///
+/// Defaut argumets are conservative towards safety and use JDK 8 as the most common one.
+///
+/// [JNI documentation](https://docs.oracle.com/en/java/javase/11/docs/specs/jni/invocation.html#jni_createjavavm)
+impl Default for InitArguments {
+    fn default() -> Self {
+        InitArguments {
+            version: JniVersion::V8,
+            options: vec![],
+            ignore_unrecognized: true,
+        }
+        // We enable CheckedJni by default for exatra safety.
+        // It can always be explicitly disabled with .unchecked().
+        .checked()
+        // We enable failing on unrecognized JVM aruments by default for exatra safety.
+        // It can always be explicitly disabled with .ignore_unrecognized_options().
+        .fail_on_unrecognized_options()
+    }
+}
+
+impl InitArguments {
+    /// Get default init arguments for the latest supported JNI version.
+    ///
+    /// Not available with the `no-invocation` feature.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_getdefaultjavavminitargs)
+    #[cfg(not(feature = "no-invocation"))]
+    pub fn get_latest_default() -> Result<Self, JniError> {
+        Self::get_default(JniVersion::V10)
+    }
+
+    /// Get default Java VM init arguments for a JNI version.
+    /// If the requested JNI version is not supported, returns [`JniError`](enum.JniError.html).
+    ///
+    /// Unlike [`InitArguments::default()`](struct.InitArguments.html#impl-Default), gets the defaut arguments
+    /// from a JNI call.
+    ///
+    /// Not available with the `no-invocation` feature.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_getdefaultjavavminitargs)
+    #[cfg(not(feature = "no-invocation"))]
+    pub fn get_default(version: JniVersion) -> Result<Self, JniError> {
+        let mut raw_arguments = jni_sys::JavaVMInitArgs {
+            version: version.to_raw(),
+            nOptions: 0,
+            options: ptr::null_mut(),
+            ignoreUnrecognized: jni_sys::JNI_FALSE,
+        };
+        // Safe because we pass a pointer to a correct data structure.
+        unsafe {
+            // It is fine if the requested version is not supported, we'll just use
+            // a supported one.
+            let error = JniError::from_raw(JNI_GetDefaultJavaVMInitArgs(
+                &mut raw_arguments as *mut jni_sys::JavaVMInitArgs as *mut c_void,
+            ));
+            if error.is_some() {
+                return Err(error.unwrap());
+            }
+        }
+        // Safe because raw arguments were correctly initialized by the
+        // `JNI_GetDefaultJavaVMInitArgs`.
+        let init_arguments = unsafe { Self::from_raw(&raw_arguments) };
+        // Version must be the same as the one specified before.
+        // See [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_getdefaultjavavminitargs)
+        // for details.
+        if version != init_arguments.version {
+            return Err(JniError::UnsupportedVersion);
+        }
+        Ok(init_arguments
+            // We enable CheckedJni by default for exatra safety.
+            // It can always be explicitly disabled with .unchecked().
+            .checked()
+            // We enable failing on unrecognized JVM aruments by default for exatra safety.
+            // It can always be explicitly disabled with .ignore_unrecognized_options().
+            .fail_on_unrecognized_options())
+    }
+
+    /// Unsafe because one can pass incorrect options.
+    pub(crate) unsafe fn from_raw(raw_arguments: &jni_sys::JavaVMInitArgs) -> InitArguments {
+        let options = slice::from_raw_parts(raw_arguments.options, raw_arguments.nOptions as usize)
+            .iter()
+            .map(|value| JvmOption::from_raw(value))
+            .collect();
+        InitArguments {
+            version: JniVersion::from_raw(raw_arguments.version),
+            ignore_unrecognized: jni_bool::to_rust(raw_arguments.ignoreUnrecognized),
+            options,
+        }
+    }
+
+    /// Set requested JNI version in the Java VM init arguments.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
+    pub fn with_version(mut self, version: JniVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Add init options to the Java VM init arguments.
+    ///
+    /// Options are checked when creating the Java VM, which returns
+    /// [`VmCreationError::InvalidOption`](enum.VmCreationError.html#variant.InvalidOption)
+    /// if an option is invalid, for example if it has an empty name or a zero memory size.
+    /// [`Unknown`](enum.JvmOption.html#variant.Unknown) options are checked for malformed
+    /// `-X`, `-XX:` and `-D` flags, and a system property can only be set once.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
+    pub fn with_options(mut self, options: &[JvmOption]) -> Self {
+        self.options.extend_from_slice(options);
+        self
+    }
+
+    /// Add an init option to the Java VM init arguments.
+    ///
+    /// See [`with_options`](struct.InitArguments.html#method.with_options).
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
+    pub fn with_option(self, option: JvmOption) -> Self {
+        self.with_options(&[option])
+    }
+
+    /// Disable checking JNI calls for correctness.
+    pub fn unchecked(self) -> Self {
+        InitArguments {
+            version: self.version,
+            ignore_unrecognized: self.ignore_unrecognized,
+            options: self
+                .options
+                .iter()
+                .filter(|&option| *option != JvmOption::CheckedJni)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Enable checking JNI calls for correctness.
+    ///
+    /// This is a default. Only needed to be called if checking JNI calls was explicitly disabled.
+    pub fn checked(self) -> Self {
+        self.with_option(JvmOption::CheckedJni)
+    }
+
+    /// Request for JVM to ignore unrecognized options on startup.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
+    pub fn ignore_unrecognized_options(mut self) -> Self {
+        self.ignore_unrecognized = true;
+        self
+    }
+
+    /// Request for JVM to fail in presence of unrecognized options on startup.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
+    pub fn fail_on_unrecognized_options(mut self) -> Self {
+        self.ignore_unrecognized = false;
+        self
+    }
+
+    /// Check that all options can be passed to the Java VM
+    /// and that no system property is set twice.
+    pub(crate) fn validate(&self) -> Result<(), VmCreationError> {
+        let invalid_option = |option: &JvmOption, reason| VmCreationError::InvalidOption {
+            option: option.clone(),
+            option_string: option.option_string(),
+            reason,
+        };
+        for option in self.options.iter() {
+            option
+                .validate()
+                .map_err(|reason| invalid_option(option, reason))?;
+        }
+
+        let mut properties: Vec<(&str, &JvmOption)> = vec![];
+        for option in self.options.iter() {
+            let key = match option {
+                JvmOption::Unknown(value) => JvmOption::system_property_key(value),
+                _ => None,
+            };
+            if let Some(key) = key {
+                if let Some((_, previous)) = properties.iter().find(|(other, _)| *other == key) {
+                    return Err(invalid_option(
+                        option,
+                        format!(
+                            "System property \"{}\" is already set by option \"{}\".",
+                            key,
+                            previous.option_string()
+                        ),
+                    ));
+                }
+                properties.push((key, option));
+            }
+        }
+        Ok(())
+    }
+
+    /// Return the JNI version these arguments will request when creating a Java VM.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
+    pub fn version(&self) -> JniVersion {
+        self.version
+    }
+}
+
+#[cfg(test)]
+pub mod init_arguments_manipulation_tests {
+    use super::*;
+
+    pub fn default_args() -> InitArguments {
+        InitArguments {
+            version: JniVersion::V4,
+            options: vec![],
+            ignore_unrecognized: false,
+        }
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(
+            InitArguments::default(),
+            InitArguments {
+                version: JniVersion::V8,
+                options: vec![JvmOption::CheckedJni],
+                ignore_unrecognized: false,
+            }
+        );
+    }
+
+    #[test]
+    fn with_version() {
+        let arguments = InitArguments {
+            version: JniVersion::V4,
+            ..default_args()
+        };
+        assert_eq!(
+            arguments.with_version(JniVersion::V8),
+            InitArguments {
+                version: JniVersion::V8,
+                ..default_args()
+            }
+        );
+    }
+
+    #[test]
+    fn with_options() {
+        let arguments = InitArguments {
+            options: vec![JvmOption::CheckedJni],
+            ..default_args()
+        };
+        assert_eq!(
+            arguments.with_options(&[
+                JvmOption::Verbose(JvmVerboseOption::Gc),
+                JvmOption::Unknown("test".to_owned()),
+            ]),
+            InitArguments {
+                options: vec![
+                    JvmOption::CheckedJni,
+                    JvmOption::Verbose(JvmVerboseOption::Gc),
+                    JvmOption::Unknown("test".to_owned()),
+                ],
+                ..default_args()
+            }
+        );
+    }
+
+    #[test]
+    fn with_option() {
+        let arguments = InitArguments {
+            options: vec![JvmOption::CheckedJni],
+            ..default_args()
+        };
+        assert_eq!(
+            arguments.with_option(JvmOption::Verbose(JvmVerboseOption::Gc)),
+            InitArguments {
+                options: vec![
+                    JvmOption::CheckedJni,
+                    JvmOption::Verbose(JvmVerboseOption::Gc),
+                ],
+                ..default_args()
+            }
+        );
+    }
+
+    #[test]
+    fn validate() {
+        assert_eq!(
+            default_args()
+                .with_option(JvmOption::MaxHeapSize(JvmMemorySize::Megabytes(1)))
+                .validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_invalid() {
+        assert_eq!(
+            default_args()
+                .with_option(JvmOption::CheckedJni)
+                .with_option(JvmOption::MaxHeapSize(JvmMemorySize::Megabytes(0)))
+                .validate(),
+            Err(VmCreationError::InvalidOption {
+                option: JvmOption::MaxHeapSize(JvmMemorySize::Megabytes(0)),
+                option_string: "-Xmx0m".to_owned(),
+                reason: "Memory size must be positive, got Megabytes(0).".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn validate_duplicate_system_property() {
+        assert_eq!(
+            default_args()
+                .with_option(JvmOption::Unknown("-Da.b=1".to_owned()))
+                .with_option(JvmOption::Unknown("-Dc=2".to_owned()))
+                .with_option(JvmOption::Unknown("-Da.b".to_owned()))
+                .validate(),
+            Err(VmCreationError::InvalidOption {
+                option: JvmOption::Unknown("-Da.b".to_owned()),
+                option_string: "-Da.b".to_owned(),
+                reason: "System property \"a.b\" is already set by option \"-Da.b=1\".".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn unchecked() {
+        let arguments = InitArguments {
+            options: vec![
+                JvmOption::Verbose(JvmVerboseOption::Gc),
+                JvmOption::CheckedJni,
+            ],
+            ..default_args()
+        };
+        assert_eq!(
+            arguments.unchecked(),
+            InitArguments {
+                options: vec![JvmOption::Verbose(JvmVerboseOption::Gc)],
+                ..default_args()
+            }
+        );
+    }
+
+    #[test]
+    fn checked() {
+        let arguments = InitArguments {
+            options: vec![JvmOption::Verbose(JvmVerboseOption::Gc)],
+            ..default_args()
+        };
+        assert_eq!(
+            arguments.checked(),
+            InitArguments {
+                options: vec![
+                    JvmOption::Verbose(JvmVerboseOption::Gc),
+                    JvmOption::CheckedJni,
+                ],
+                ..default_args()
+            }
+        );
+    }
+
+    #[test]
+    fn ignore_unrecognized_options() {
+        let arguments = InitArguments {
+            ignore_unrecognized: false,
+            ..default_args()
+        };
+        assert_eq!(
+            arguments.ignore_unrecognized_options(),
+            InitArguments {
+                ignore_unrecognized: true,
+                ..default_args()
+            }
+        );
+    }
+
+    #[test]
+    fn fail_on_unrecognized_options() {
+        let arguments = InitArguments {
+            ignore_unrecognized: true,
+            ..default_args()
+        };
+        assert_eq!(
+            arguments.fail_on_unrecognized_options(),
+            InitArguments {
+                ignore_unrecognized: false,
+                ..default_args()
+            }
+        );
+    }
+
+    #[test]
+    fn version() {
+        let arguments = InitArguments {
+            version: JniVersion::V6,
+            ..default_args()
+        };
+        assert_eq!(arguments.version(), JniVersion::V6);
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod init_arguments_creation_tests {
+    use super::*;
+    #[cfg(not(feature = "no-invocation"))]
+    use serial_test_derive::serial;
+
+    pub(crate) fn default_args() -> InitArguments {
+        InitArguments {
+            version: JniVersion::V4,
+            options: vec![],
+            ignore_unrecognized: false,
+        }
+    }
+
+    #[test]
+    #[serial]
+    #[cfg(not(feature = "no-invocation"))]
+    fn get_default() {
+        let resulting_arguments = InitArguments {
+            version: JniVersion::V4,
+            options: vec![
+                JvmOption::Unknown("qwer".to_owned()),
+                JvmOption::Verbose(JvmVerboseOption::Gc),
+            ],
+            ..default_args()
+        };
+        let mut strings_buffer = vec![];
+        let mut options_buffer = vec![];
+        let raw_resulting_arguments =
+            resulting_arguments.to_raw(&mut strings_buffer, &mut options_buffer);
+
+        let mock = jni_mock::JNI_GetDefaultJavaVMInitArgs_context();
+        mock.expect()
+            .times(1)
+            .withf(move |arguments: &*mut ::core::ffi::c_void| {
+                let arguments = *arguments as *mut jni_sys::JavaVMInitArgs;
+                // We know that this pointer points to a valid value.
+                match unsafe { arguments.as_mut() } {
+                    None => false,
+                    Some(arguments) => {
+                        if arguments.version != JniVersion::V4.to_raw()
+                            || arguments.nOptions != 0
+                            || arguments.options != ptr::null_mut()
+                            || arguments.ignoreUnrecognized != jni_sys::JNI_FALSE
+                        {
+                            false
+                        } else {
+                            *arguments = raw_resulting_arguments.raw_arguments;
+                            true
+                        }
+                    }
+                }
+            })
+            .return_const(jni_sys::JNI_OK);
+        assert_eq!(
+            InitArguments::get_default(JniVersion::V4),
+            Ok(InitArguments {
+                version: JniVersion::V4,
+                options: vec![
+                    JvmOption::Unknown("qwer".to_owned()),
+                    JvmOption::Verbose(JvmVerboseOption::Gc),
+                    JvmOption::CheckedJni,
+                ],
+                ..default_args()
+            })
+        );
+    }
+
+    #[test]
+    #[serial]
+    #[cfg(not(feature = "no-invocation"))]
+    fn get_default_error() {
+        let mock = jni_mock::JNI_GetDefaultJavaVMInitArgs_context();
+        mock.expect().times(1).return_const(jni_sys::JNI_ERR);
+        assert_eq!(
+            InitArguments::get_default(JniVersion::V4),
+            Err(JniError::Unknown(jni_sys::JNI_ERR))
+        );
+    }
+
+    #[test]
+    #[serial]
+    #[cfg(not(feature = "no-invocation"))]
+    fn get_default_changed_version() {
+        let resulting_arguments = InitArguments {
+            version: JniVersion::V4,
+            ..default_args()
+        };
+        let mut strings_buffer = vec![];
+        let mut options_buffer = vec![];
+        let raw_resulting_arguments =
+            resulting_arguments.to_raw(&mut strings_buffer, &mut options_buffer);
+
+        let mock = jni_mock::JNI_GetDefaultJavaVMInitArgs_context();
+        mock.expect()
+            .times(1)
+            .withf(move |arguments: &*mut ::core::ffi::c_void| {
+                let arguments = *arguments as *mut jni_sys::JavaVMInitArgs;
+                // We know that this pointer points to a valid value.
+                match unsafe { arguments.as_mut() } {
+                    None => false,
+                    Some(arguments) => {
+                        if arguments.version != JniVersion::V8.to_raw() {
+                            false
+                        } else {
+                            *arguments = raw_resulting_arguments.raw_arguments;
+                            true
+                        }
+                    }
+                }
+            })
+            .return_const(jni_sys::JNI_OK);
+        assert_eq!(
+            InitArguments::get_default(JniVersion::V8),
+            Err(JniError::UnsupportedVersion)
+        );
+    }
+
+    #[test]
+    #[serial]
+    #[cfg(not(feature = "no-invocation"))]
+    fn get_latest_default() {
+        let resulting_arguments = InitArguments {
+            version: JniVersion::V10,
+            ..default_args()
+        };
+        let mut strings_buffer = vec![];
+        let mut options_buffer = vec![];
+        let raw_resulting_arguments =
+            resulting_arguments.to_raw(&mut strings_buffer, &mut options_buffer);
+
+        let mock = jni_mock::JNI_GetDefaultJavaVMInitArgs_context();
+        mock.expect()
+            .times(1)
+            .withf(move |arguments: &*mut ::core::ffi::c_void| {
+                let arguments = *arguments as *mut jni_sys::JavaVMInitArgs;
+                // We know that this pointer points to a valid value.
+                match unsafe { arguments.as_mut() } {
+                    None => false,
+                    Some(arguments) => {
+                        if arguments.version != JniVersion::V10.to_raw() {
+                            false
+                        } else {
+                            *arguments = raw_resulting_arguments.raw_arguments;
+                            true
+                        }
+                    }
+                }
+            })
+            .return_const(jni_sys::JNI_OK);
+        assert_eq!(
+            InitArguments::get_latest_default(),
+            Ok(InitArguments {
+                version: JniVersion::V10,
+                options: vec![JvmOption::CheckedJni],
+                ..default_args()
+            })
+        );
+    }
+}
+
+/// A wrapper around `jni_sys::JavaVMInitArgs` with a lifetime to ensure
+/// there's no access to freed memory.
+pub(crate) struct RawInitArguments<'a> {
+    pub raw_arguments: jni_sys::JavaVMInitArgs,
+    _buffer: PhantomData<&'a Vec<CString>>,
+}
+
+/// Test implementation of Send to allow passing
+/// these objects to mock arguments matchers.
+#[cfg(test)]
+unsafe impl<'a> Send for RawInitArguments<'a> {}
+
+impl InitArguments {
+    pub(crate) fn to_raw<'a, 'b, 'c: 'a + 'b>(
+        &self,
+        strings_buffer: &'a mut Vec<CString>,
+        options_buffer: &'b mut Vec<jni_sys::JavaVMOption>,
+    ) -> RawInitArguments<'c> {
+        *strings_buffer = self
+            .options
+            .iter()
+            .map(|_| "")
+            .map(CString::new)
+            .map(Result::unwrap)
+            .collect();
+        *options_buffer = self
+            .options
+            .iter()
+            .zip(strings_buffer.iter_mut())
+            .map(|(option, ref mut buffer)| {
+                // TODO(#14): support platform encodings other than UTF-8.
+                let buffer: &mut CString = buffer;
+                *buffer = option.to_string();
+                jni_sys::JavaVMOption {
+                    optionString: buffer.as_ptr() as *mut i8,
+                    extraInfo: ptr::null_mut(),
+                }
+            })
+            .collect();
+        RawInitArguments {
+            raw_arguments: jni_sys::JavaVMInitArgs {
+                version: self.version.to_raw(),
+                nOptions: options_buffer.len() as i32,
+                options: options_buffer.as_mut_ptr(),
+                ignoreUnrecognized: jni_bool::to_jni(self.ignore_unrecognized),
+            },
+            _buffer: PhantomData::<&'c Vec<CString>>,
+        }
+    }
+}
+
+#[cfg(test)]
+mod init_arguments_to_raw_tests {
+    use super::*;
+
+    #[test]
+    fn to_raw_test() {
+        let arguments = InitArguments {
+            version: JniVersion::V4,
+            options: vec![
+                JvmOption::Unknown("qwer".to_owned()),
+                JvmOption::Verbose(JvmVerboseOption::Gc),
+            ],
+            ignore_unrecognized: false,
+        };
+        let mut strings_buffer = vec![];
+        let mut options_buffer = vec![];
+        let raw_arguments = arguments.to_raw(&mut strings_buffer, &mut options_buffer);
+        assert_eq!(raw_arguments.raw_arguments.version, JniVersion::V4.to_raw());
+        assert_eq!(raw_arguments.raw_arguments.nOptions, 2);
+        assert_eq!(
+            raw_arguments.raw_arguments.ignoreUnrecognized,
+            jni_sys::JNI_FALSE
+        );
+        let raw_options = unsafe {
+            slice::from_raw_parts(
+                raw_arguments.raw_arguments.options,
+                raw_arguments.raw_arguments.nOptions as usize,
+            )
+        };
+        assert_eq!(raw_options.len(), 2);
+        for (raw_option, option) in raw_options.iter().zip(arguments.options.into_iter()) {
+            assert_eq!(option, unsafe { JvmOption::from_raw(raw_option) });
+        }
+    }
+}
+
+cfg_if! {
+    if #[cfg(all(test, not(feature = "no-invocation")))] {
+        generate_jni_functions_mock!(jni_mock);
+    } else if #[cfg(not(feature = "no-invocation"))] {
+        use crate::backend::JNI_GetDefaultJavaVMInitArgs;
+    }
+}
@@ -0,0 +1,237 @@
+//! Runtime introspection of compiled-in Java bindings.
+//!
+//! Every `java_generate!` invocation registers a [`BindingInfo`](struct.BindingInfo.html)
+//! describing the wrappers it generated. [`all_bindings`](fn.all_bindings.html) returns all of them,
+//! which helps debugging mismatches between the Java code and the compiled bindings.
+//!
+//! [`verify_class_hierarchy`](fn.verify_class_hierarchy.html) checks that the Java classes
+//! still extend and implement the types declared in the bindings. With the `verify-hierarchy`
+//! feature this check runs automatically on the first call of a method of every generated class.
+
+use crate::class::Class;
+use crate::env::JniEnv;
+use crate::result::JavaResult;
+use crate::token::NoException;
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "verify-hierarchy")]
+use std::collections::BTreeSet;
+#[cfg(feature = "verify-hierarchy")]
+use std::sync::Mutex;
+
+#[doc(hidden)]
+pub use inventory as __inventory;
+
+/// Information about a generated Java class wrapper.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClassBindingInfo {
+    /// Name of the Rust wrapper type.
+    pub name: &'static str,
+    /// JNI signature of the Java class.
+    pub signature: &'static str,
+    /// Name of the superclass declared in the bindings, like `java/lang/Object`.
+    pub super_class: &'static str,
+    /// Names of the interfaces the class is declared to implement, like `java/lang/Runnable`.
+    pub interfaces: &'static [&'static str],
+}
+
+/// Information about bindings generated by one `java_generate!` invocation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BindingInfo {
+    /// Name of the crate containing the bindings.
+    pub crate_name: &'static str,
+    /// Version of the crate containing the bindings.
+    pub crate_version: &'static str,
+    /// Version of the generator that generated the bindings.
+    pub generator_version: &'static str,
+    /// Generated Java class wrappers.
+    pub classes: &'static [ClassBindingInfo],
+    /// Names of generated Java interface traits.
+    pub interfaces: &'static [&'static str],
+}
+
+/// A registration of generated bindings. Submitted by the generated code.
+#[doc(hidden)]
+pub struct BindingRegistration {
+    binding_info: fn() -> BindingInfo,
+}
+
+impl BindingRegistration {
+    #[doc(hidden)]
+    pub const fn new(binding_info: fn() -> BindingInfo) -> Self {
+        Self { binding_info }
+    }
+}
+
+inventory::collect!(BindingRegistration);
+
+/// Version of the generated code supported by this version of `rust-jni`.
+///
+/// Code generated by `java_generate!` checks at compile time that it was generated for this
+/// version, so that mismatched versions of `rust-jni` and `rust-jni-generator` fail to compile
+/// with a clear error. `rust-jni` and `rust-jni-generator` are released together, so both take
+/// it from the major and minor versions of their packages.
+pub const COMPATIBLE_WITH: &str = concat!(
+    env!("CARGO_PKG_VERSION_MAJOR"),
+    ".",
+    env!("CARGO_PKG_VERSION_MINOR")
+);
+
+/// Check the version of the generated code. Called by the generated code in a constant.
+///
+/// Code generated for another version fails to compile:
+/// ```compile_fail,E0080
+/// const _: () = rust_jni::introspection::__check_compatible_with("0.0");
+/// ```
+///
+/// ```
+/// const _: () =
+///     rust_jni::introspection::__check_compatible_with(rust_jni::introspection::COMPATIBLE_WITH);
+/// ```
+#[doc(hidden)]
+pub const fn __check_compatible_with(generated_for: &str) {
+    if !str_eq(generated_for, COMPATIBLE_WITH) {
+        panic!(
+            "The Java bindings were generated by a rust-jni-generator version incompatible with \
+             this rust-jni version. Use matching versions of both crates, for example by \
+             enabling the `macros` feature of rust-jni and using `rust_jni::java_generate!`."
+        );
+    }
+}
+
+const fn str_eq(left: &str, right: &str) -> bool {
+    let left = left.as_bytes();
+    let right = right.as_bytes();
+    if left.len() != right.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < left.len() {
+        if left[index] != right[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+#[cfg(test)]
+mod compatible_with_tests {
+    use super::*;
+
+    // Same as the code generated by `java_generate!`.
+    const _: () = __check_compatible_with(COMPATIBLE_WITH);
+
+    #[test]
+    fn str_eq_strings() {
+        assert!(str_eq("0.1", "0.1"));
+        assert!(str_eq("", ""));
+        assert!(!str_eq("0.1", "0.2"));
+        assert!(!str_eq("0.1", "0.1.0"));
+    }
+
+    #[test]
+    #[should_panic(expected = "generated by a rust-jni-generator version incompatible")]
+    fn incompatible() {
+        __check_compatible_with("0.0");
+    }
+}
+
+/// Get information about all bindings compiled into the program.
+///
+/// The order of the bindings is unspecified.
+pub fn all_bindings() -> Vec<BindingInfo> {
+    inventory::iter::<BindingRegistration>
+        .into_iter()
+        .map(|registration| (registration.binding_info)())
+        .collect()
+}
+
+/// Check that a Java class extends the superclass and implements the interfaces declared in
+/// its bindings.
+///
+/// Returns a description of the mismatch if there is one, which usually means that
+/// the bindings are stale against an updated jar. The superclass is checked with
+/// `GetSuperclass` and interfaces are checked with `IsAssignableFrom`.
+pub fn verify_class_hierarchy<'a>(
+    env: &'a JniEnv<'a>,
+    token: &NoException<'a>,
+    class: &ClassBindingInfo,
+) -> JavaResult<'a, Option<String>> {
+    // Class signatures are of the form "L${CLASS_NAME};".
+    let class_name = &class.signature[1..class.signature.len() - 1];
+    let java_class = Class::find(env, token, class_name)?;
+    let super_class = Class::find(env, token, class.super_class)?;
+    match java_class.parent(token) {
+        Some(ref parent) if parent.ref_eq(token, &super_class) => {}
+        parent => {
+            let actual = match parent {
+                None => "no class".to_owned(),
+                Some(parent) => match parent.get_name(token)? {
+                    None => "an unnamed class".to_owned(),
+                    Some(name) => name.as_string(token),
+                },
+            };
+            return Ok(Some(format!(
+                "Java class {} extends {} instead of {} declared in the bindings of {}.",
+                java_name(class_name),
+                actual,
+                java_name(class.super_class),
+                class.name,
+            )));
+        }
+    }
+    for interface in class.interfaces {
+        let java_interface = Class::find(env, token, interface)?;
+        if !java_class.is_subtype_of(token, &java_interface) {
+            return Ok(Some(format!(
+                "Java class {} doesn't implement {} declared in the bindings of {}.",
+                java_name(class_name),
+                java_name(interface),
+                class.name,
+            )));
+        }
+    }
+    Ok(None)
+}
+
+fn java_name(class_name: &str) -> String {
+    class_name.replace('/', ".")
+}
+
+/// Signatures of classes which hierarchy was already verified.
+#[cfg(feature = "verify-hierarchy")]
+static VERIFIED_SIGNATURES: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+/// Verify the hierarchy of generated classes with the `signature` the first time
+/// it's called for it.
+///
+/// Panics if the hierarchy doesn't match the bindings.
+#[cfg(feature = "verify-hierarchy")]
+pub(crate) fn verify_class_hierarchy_once<'a>(
+    env: &'a JniEnv<'a>,
+    token: &NoException<'a>,
+    signature: &'static str,
+) -> JavaResult<'a, ()> {
+    // Mark the signature as verified before verifying, as verification calls Java methods.
+    if !VERIFIED_SIGNATURES.lock().unwrap().insert(signature) {
+        return Ok(());
+    }
+    let classes = all_bindings()
+        .into_iter()
+        .flat_map(|binding| binding.classes.iter())
+        .filter(|class| class.signature == signature);
+    for class in classes {
+        match verify_class_hierarchy(env, token, class) {
+            Ok(None) => {}
+            Ok(Some(mismatch)) => panic!("{}", mismatch),
+            Err(error) => {
+                VERIFIED_SIGNATURES.lock().unwrap().remove(signature);
+                return Err(error);
+            }
+        }
+    }
+    Ok(())
+}
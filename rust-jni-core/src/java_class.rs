@@ -4,9 +4,11 @@ use crate::env::JniEnv;
 use crate::java_methods::FromObject;
 use crate::java_methods::JniSignature;
 use crate::object::Object;
+use crate::owned_object::{Detachable, OwnedObject};
 use crate::result::JavaResult;
 use crate::token::NoException;
-use std::ptr::NonNull;
+#[cfg(not(feature = "deny-unsafe-passthrough"))]
+use core::ptr::NonNull;
 
 pub trait JavaClassRef<'a>: JniSignature + AsRef<Object<'a>> {}
 
@@ -16,6 +18,17 @@ pub trait JavaClass<'a>: JavaClassRef<'a> + FromObject<'a> {}
 
 impl<'a, T> JavaClass<'a> for T where T: JavaClassRef<'a> + FromObject<'a> {}
 
+/// A marker trait for wrappers of Java classes that extend
+/// [`java.lang.Throwable`](https://docs.oracle.com/javase/10/docs/api/java/lang/Throwable.html).
+///
+/// Exceptions of these classes can be caught with
+/// [`JavaResultExt::catching`](trait.JavaResultExt.html#method.catching) and
+/// [`call_method_catching`](fn.call_method_catching.html).
+///
+/// Implementing it for a class that doesn't extend `java.lang.Throwable` is safe, but such
+/// exceptions are never caught.
+pub trait JavaExceptionClass<'a>: JavaClass<'a> {}
+
 /// Trait with additional methods on Java class wrappers.
 pub trait JavaClassExt<'a> {
     /// Clone the object. This is not a deep clone of the Java object,
@@ -30,7 +43,7 @@ pub trait JavaClassExt<'a> {
     /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newlocalref)
     fn clone_object(&self, token: &NoException<'a>) -> JavaResult<'a, Self>
     where
-        Self: std::marker::Sized;
+        Self: core::marker::Sized;
 
     /// Get the [`Class`](java/lang/struct.Class.html) for the wrapper type.
     ///
@@ -43,9 +56,23 @@ pub trait JavaClassExt<'a> {
     /// The caller is responsible for managing the Java object's lifecycle ofter calling this.
     ///
     /// This function provides low-level access to the Java object and thus is unsafe.
+    ///
+    /// Not available with the `deny-unsafe-passthrough` feature.
+    #[cfg(not(feature = "deny-unsafe-passthrough"))]
     unsafe fn take_raw_object(self) -> NonNull<jni_sys::_jobject>
     where
         Self: Into<Object<'a>>;
+
+    /// Detach the object from the [`JniEnv`](struct.JniEnv.html), swapping it's local reference
+    /// for a global one. See [`OwnedObject`](struct.OwnedObject.html).
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newglobalref)
+    fn detach_from_env(
+        self,
+        token: &NoException<'a>,
+    ) -> JavaResult<'a, OwnedObject<<Self as Detachable>::Attached<'static>>>
+    where
+        Self: Detachable + Into<Object<'a>>;
 }
 
 impl<'a, T> JavaClassExt<'a> for T
@@ -64,6 +91,7 @@ where
         find_class::<Self>(env, token)
     }
 
+    #[cfg(not(feature = "deny-unsafe-passthrough"))]
     #[inline(always)]
     unsafe fn take_raw_object(self) -> NonNull<jni_sys::_jobject>
     where
@@ -71,6 +99,17 @@ where
     {
         Object::take_raw_object(self)
     }
+
+    #[inline(always)]
+    fn detach_from_env(
+        self,
+        token: &NoException<'a>,
+    ) -> JavaResult<'a, OwnedObject<<Self as Detachable>::Attached<'static>>>
+    where
+        Self: Detachable + Into<Object<'a>>,
+    {
+        OwnedObject::new(self, token)
+    }
 }
 
 /// Extension trait that adds common helper methods for working with
@@ -135,11 +174,15 @@ where
 }
 
 #[inline(always)]
-pub fn find_class<'a, T: JavaClassRef<'a>>(
+pub fn find_class<'a, T: JniSignature>(
     env: &'a JniEnv<'a>,
     token: &NoException<'a>,
 ) -> JavaResult<'a, Class<'a>> {
     let signature = T::signature();
+    if signature.starts_with('[') {
+        // Array classes are found by their signatures.
+        return Class::find(env, token, signature);
+    }
     // Class signatures are of the form "L${CLASS_NAME};", so to get the class name
     // we remove the first and the last character.
     Class::find(env, token, &signature[1..signature.len() - 1])
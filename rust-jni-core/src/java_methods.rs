@@ -0,0 +1,900 @@
+use crate::env::JniEnv;
+use crate::java_class::find_class;
+use crate::java_class::JavaClass;
+use crate::java_class::JavaClassExt;
+use crate::java_class::JavaClassRef;
+use crate::java_class::JavaExceptionClass;
+use crate::jni_methods;
+use crate::jni_types::private::JniArgumentType;
+use crate::jni_types::private::JniArgumentTypeTuple;
+use crate::jni_types::private::JniType;
+use crate::object::Object;
+use crate::result::{JavaResult, JavaResultExt};
+use crate::signature_pool;
+use crate::token::NoException;
+use alloc::format;
+use core::ptr::{self, NonNull};
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// A trait to be implemented by all types that can be passed or returned from JNI.
+///
+/// To pass a type to Java it needs to:
+///  1. Be convertible into a type implementing the `JniType` trait: implement `JavaArgumentType` trait
+///  2. Provide a JNI signature (see
+///     [JNI documentation](https://docs.oracle.com/en/java/javase/11/docs/specs/jni/types.html#type-signatures)
+///     for more context).
+///
+/// To return a type from Java a type also needs to:
+///  3. Be convertible from a type implementing the `JniType` trait: implement `JavaMethodResult` trait
+///
+/// [`rust-jni`](index.html) implements all three conditions for for primitive types that can be passed to JNI.
+///
+/// Implementing those conditions for Java class wrappers requires cooperation with the wrappers author.
+/// [`Object`](java/lang/struct.Object.html) is convertible to and from [`jobject`](../jni_sys/type.jobject.html)
+/// which implements the `JniType` trait. So for Java class wrappers the conditions above translate into:
+///  1. Be convertible into [`Object`](java/lang/struct.Object.html)
+///  2. Provide a JNI signature. For Java classes the signature is `L${CLASS_PATH};`
+///  3. Be constructable from [`Object`](java/lang/struct.Object.html)
+///
+///  - To make a Java class wrapper convertible to [`Object`](java/lang/struct.Object.html) author of the wrapper
+///    needs to implement [`AsRef<Object>`](https://doc.rust-lang.org/std/convert/trait.AsRef.html) for it
+///  - To make a Java class wrapper constructable from [`Object`](java/lang/struct.Object.html) author of the wrapper
+///    needs to implement [`FromObject`](trait.FromObject.html) for it
+///  - To provide the JNI signature for a Java class wrapper author needs to implement
+///    [`JniSignature`](trait.JniSignature.html)
+pub trait JniSignature {
+    /// Return the JNI signature for `Self`.
+    ///
+    /// This method is not unsafe. Returning an incorrect signature will result in a panic, not any unsafe
+    /// behaviour.
+    fn signature() -> &'static str;
+}
+
+impl<T> JniSignature for &'_ T
+where
+    T: JniSignature,
+{
+    #[inline(always)]
+    fn signature() -> &'static str {
+        T::signature()
+    }
+}
+
+/// A trait for making Java class wrappers constructible from an [`Object`](java/lang/struct.Object.html).
+///
+/// See more detailed info for passing values betweed Java and rust in
+/// [`JniSignature`](trait.JniSignature.html) documentation.
+pub trait FromObject<'a> {
+    /// Construct `Self` from an [`Object`](java/lang/struct.Object.html).
+    ///
+    /// Unsafe because it's possible to pass an object of a different type.
+    unsafe fn from_object(object: Object<'a>) -> Self;
+}
+
+/// A trait that needs to be implemented for a type that needs to be passed to Java.
+///
+/// See more detailed info for passing values betweed Java and rust in
+/// [`JniSignature`](trait.JniSignature.html) documentation.
+pub trait JavaArgumentType: JniSignature {
+    type JniType: JniArgumentType;
+
+    fn to_jni(&self) -> Self::JniType;
+}
+
+impl<'a, T> JavaArgumentType for T
+where
+    T: JavaClassRef<'a>,
+{
+    type JniType = jni_sys::jobject;
+
+    #[inline(always)]
+    fn to_jni(&self) -> Self::JniType {
+        // Safe because we use the pointer only to pass it to JNI.
+        unsafe { self.as_ref().raw_object().as_ptr() }
+    }
+}
+
+impl<'a, T> JniSignature for Option<T>
+where
+    T: JavaClassRef<'a>,
+{
+    #[inline(always)]
+    fn signature() -> &'static str {
+        T::signature()
+    }
+}
+
+impl<'a, T> JavaArgumentType for Option<T>
+where
+    T: JavaClassRef<'a>,
+{
+    type JniType = jni_sys::jobject;
+
+    #[inline(always)]
+    fn to_jni(&self) -> Self::JniType {
+        // Safe because we use the pointer only to pass it to JNI.
+        unsafe {
+            self.as_ref().map_or(ptr::null_mut(), |value| {
+                value.as_ref().raw_object().as_ptr()
+            })
+        }
+    }
+}
+
+/// A Java `null` of the class `T`, for object arguments that can be `null`.
+///
+/// Object arguments are passed as `Option<&T>` to be nullable, and this is a typed
+/// [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None) that doesn't
+/// need the type to be spelled out in the method signature.
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::{Object, String};
+/// #
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// // Safe because correct arguments and return type are passed.
+/// // See `String::valueOf(Object)` javadoc:
+/// // https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#valueOf(java.lang.Object)
+/// let value = unsafe {
+///     call_static_method::<String, _, _, fn(Option<&Object<'a>>) -> String<'a>>(
+///         env,
+///         &token,
+///         "valueOf\0",
+///         (null::<Object>(),),
+///     )
+/// }?;
+/// assert_eq!(value.or_npe(env, &token)?.as_string(&token), "null");
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+#[inline(always)]
+pub const fn null<'a, 'b, T>() -> Option<&'b T>
+where
+    T: JavaClassRef<'a>,
+{
+    None
+}
+
+pub trait JavaArgumentTuple {
+    type JniType: JniArgumentTypeTuple;
+
+    fn to_jni(&self) -> Self::JniType;
+}
+
+pub trait JavaMethodSignature<In, Out>
+where
+    In: JavaArgumentTuple,
+{
+    /// Compute the null-terminated method signature.
+    fn compute_method_signature() -> alloc::string::String;
+
+    /// Get the null-terminated method signature.
+    ///
+    /// The signature is only computed once and is then taken from the global signature pool.
+    #[inline(always)]
+    fn method_signature() -> &'static str {
+        signature_pool::interned_signature(Self::compute_method_signature)
+    }
+}
+
+macro_rules! braces {
+    ($name:ident) => {
+        "{}"
+    };
+}
+
+macro_rules! peel_java_argument_type_impls {
+    () => ();
+    ($type:ident, $($other:ident,)*) => (java_argument_type_impls! { $($other,)* });
+}
+
+macro_rules! java_argument_type_impls {
+    ( $($type:ident,)*) => (
+        impl<'a, $($type),*> JavaArgumentTuple for ($($type,)*)
+        where
+            $($type: JavaArgumentType,)*
+        {
+            type JniType = ($($type::JniType,)*);
+
+            #[inline(always)]
+            fn to_jni(&self) -> Self::JniType {
+                #[allow(non_snake_case)]
+                let ($($type,)*) = self;
+                ($($type.to_jni(),)*)
+            }
+        }
+
+        impl<'a, $($type,)* Out, F> JavaMethodSignature<($($type,)*), Out> for F
+            where
+                $($type: JavaArgumentType,)*
+                Out: JniSignature,
+                F: FnOnce($($type,)*) -> Out + ?Sized,
+        {
+            fn compute_method_signature() -> alloc::string::String {
+                format!(
+                    concat!("(", $(braces!($type), )* "){}\0"),
+                    $(<$type as JniSignature>::signature(),)*
+                    <Out as JniSignature>::signature(),
+                )
+            }
+        }
+
+        peel_java_argument_type_impls! { $($type,)* }
+    );
+}
+
+java_argument_type_impls! {
+    T0,
+    T1,
+    T2,
+    T3,
+    T4,
+    T5,
+    T6,
+    T7,
+    T8,
+    T9,
+    T10,
+    T11,
+}
+
+/// Call a Java method.
+///
+/// The method has four generic parameters:
+///  - The first one is the class of the object. It doesn't have to be the exact class,
+///    a subclass can be passed as well. Can be inferred
+///  - The second one is the type of the arguments tuple. Can be inferred
+///  - The third one is the Java result type. Can be inferred
+///  - The fourth one is the signature of the Java method. Must be specified
+///
+/// As a result, only one generic parameter needs to be specified -- the last one.
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::String;
+/// # use std::ptr;
+/// #
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let object = String::empty(env, &token)?;
+/// // Safe because correct arguments are passed and correct return type specified.
+/// // See `Object::hashCode` javadoc:
+/// // https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#hashCode()
+/// let hash_code = unsafe {
+///     call_method::<_, _, _, fn() -> i32>(&object, &token, "hashCode\0", ())
+/// }?;
+/// assert_eq!(hash_code, 0);
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+///
+/// Note that method name string *must* be null-terminating.
+///
+/// JNI doesn't check Java access modifiers, so `private`, `protected` and package-private
+/// methods can be called the same way as `public` ones, without
+/// [`setAccessible`](https://docs.oracle.com/javase/10/docs/api/java/lang/reflect/AccessibleObject.html#setAccessible(boolean)).
+///
+/// See more info about how to pass or return types from Java calls in [`JniSignature`](trait.JniSignature.html)
+/// documentation
+///
+/// This method is unsafe because incorrect parameters can be passed to a method or incorrect return type specified.
+pub unsafe fn call_method<'a, T, A, R, F>(
+    object: &T,
+    token: &NoException<'a>,
+    name: &str,
+    arguments: A,
+) -> JavaResult<'a, R::ResultType>
+where
+    T: JavaClassRef<'a>,
+    A: JavaArgumentTuple,
+    R: JavaMethodResult<'a>,
+    F: JavaMethodSignature<A, R>,
+{
+    #[cfg(feature = "verify-hierarchy")]
+    crate::introspection::verify_class_hierarchy_once(
+        object.as_ref().env(),
+        token,
+        T::signature(),
+    )?;
+    #[cfg(feature = "std")]
+    let _call = crate::panic_hook::enter_call(object.as_ref().env(), T::signature(), name);
+    #[cfg(feature = "tracing")]
+    let _span = crate::call_span::enter_call(T::signature(), name, F::method_signature());
+    R::call_method::<T, A>(object, token, name, F::method_signature(), arguments)
+}
+
+/// Call a Java method, catching exceptions of the class `E`.
+///
+/// Same as [`call_method`](fn.call_method.html), but an exception of the class `E` thrown by
+/// the method is returned as a typed error in the inner result, while other exceptions are
+/// returned as usual. See [`JavaResultExt::catching`](trait.JavaResultExt.html#method.catching).
+///
+/// Note that method name string *must* be null-terminating.
+///
+/// This method is unsafe because incorrect parameters can be passed to a method or incorrect return type specified.
+pub unsafe fn call_method_catching<'a, T, A, R, F, E>(
+    object: &T,
+    token: &NoException<'a>,
+    name: &str,
+    arguments: A,
+) -> JavaResult<'a, Result<R::ResultType, E>>
+where
+    T: JavaClassRef<'a>,
+    A: JavaArgumentTuple,
+    R: JavaMethodResult<'a>,
+    F: JavaMethodSignature<A, R>,
+    E: JavaExceptionClass<'a>,
+{
+    call_method::<T, A, R, F>(object, token, name, arguments).catching(token)
+}
+
+/// A cache for a Java method id.
+///
+/// Used by the [`cached_call_method!`](macro.cached_call_method.html) macro to look up the
+/// method id only once per call-site. Method ids stay valid until the class is unloaded,
+/// so the cache can be shared between threads.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getmethodid)
+#[derive(Debug)]
+pub struct MethodIdCache {
+    method_id: AtomicPtr<jni_sys::_jmethodID>,
+}
+
+impl MethodIdCache {
+    /// Create an empty method id cache.
+    pub const fn new() -> Self {
+        Self {
+            method_id: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    /// Get the cached method id, if it was looked up.
+    #[inline(always)]
+    pub(crate) fn cached(&self) -> Option<NonNull<jni_sys::_jmethodID>> {
+        NonNull::new(self.method_id.load(Ordering::Acquire))
+    }
+
+    /// Get the cached method id, looking it up in the class `T` if it's not cached yet.
+    ///
+    /// Unsafe because signature must be null-terminated and because the cache must always be
+    /// used with the same class.
+    pub(crate) unsafe fn get<'a, T>(
+        &self,
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+        name: &str,
+        signature: &str,
+    ) -> JavaResult<'a, NonNull<jni_sys::_jmethodID>>
+    where
+        T: JavaClassRef<'a>,
+    {
+        if let Some(method_id) = self.cached() {
+            return Ok(method_id);
+        }
+
+        // Concurrent lookups race to store the same method id, which is harmless.
+        let class = find_class::<T>(env, token)?;
+        let method_id = jni_methods::get_method_id(&class, token, name, signature)?;
+        self.method_id.store(method_id.as_ptr(), Ordering::Release);
+        Ok(method_id)
+    }
+}
+
+impl Default for MethodIdCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Call a Java method with a cached method id.
+///
+/// Same as [`call_method`](fn.call_method.html), but only looks up the method id the first time
+/// it's called with a [`MethodIdCache`](struct.MethodIdCache.html). Prefer using the
+/// [`cached_call_method!`](macro.cached_call_method.html) macro which creates a cache for
+/// each call-site.
+///
+/// Unlike [`call_method`](fn.call_method.html), the method is looked up in the class `T`
+/// rather than in the runtime class of the object.
+///
+/// Note that method name string *must* be null-terminating.
+///
+/// This method is unsafe because incorrect parameters can be passed to a method or incorrect
+/// return type specified and because the cache must always be used with the same class `T`.
+pub unsafe fn call_method_cached<'a, T, A, R, F>(
+    cache: &MethodIdCache,
+    object: &T,
+    token: &NoException<'a>,
+    name: &str,
+    arguments: A,
+) -> JavaResult<'a, R::ResultType>
+where
+    T: JavaClassRef<'a>,
+    A: JavaArgumentTuple,
+    R: JavaMethodResult<'a>,
+    F: JavaMethodSignature<A, R>,
+{
+    #[cfg(feature = "verify-hierarchy")]
+    crate::introspection::verify_class_hierarchy_once(
+        object.as_ref().env(),
+        token,
+        T::signature(),
+    )?;
+    let method_id = cache.get::<T>(object.as_ref().env(), token, name, F::method_signature())?;
+    #[cfg(feature = "std")]
+    let _call = crate::panic_hook::enter_call(object.as_ref().env(), T::signature(), name);
+    #[cfg(feature = "tracing")]
+    let _span = crate::call_span::enter_call(T::signature(), name, F::method_signature());
+    R::call_method_with_id::<T, A>(object, token, method_id, arguments)
+}
+
+/// Call a Java method, looking up the method id only once per call-site.
+///
+/// Takes the object, the method name, the method signature, the arguments tuple and
+/// the [`NoException`](struct.NoException.html) token. The method name doesn't need to be
+/// null-terminated.
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::String;
+/// #
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let string = String::new(env, &token, "string")?;
+/// for index in 0..3 {
+///     // Safe because correct arguments are passed and correct return type specified.
+///     // See `String::codePointAt` javadoc:
+///     // https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#codePointAt(int)
+///     let code_point = unsafe {
+///         cached_call_method!(string, "codePointAt", fn(i32) -> i32, (index,), &token)
+///     }?;
+///     assert_eq!(code_point, "string".as_bytes()[index as usize] as i32);
+/// }
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+///
+/// The method id is looked up in the class of the object's static type. Objects passed at
+/// the same call-site must always have the same static type, so the macro must not be used
+/// in generic functions.
+///
+/// See [`call_method_cached`](fn.call_method_cached.html) for more details.
+///
+/// This macro is unsafe to call because incorrect parameters can be passed to a method or incorrect
+/// return type specified.
+#[macro_export]
+macro_rules! cached_call_method {
+    ($object:expr, $name:literal, $signature:ty, $arguments:expr, $token:expr) => {{
+        static METHOD_ID: $crate::MethodIdCache = $crate::MethodIdCache::new();
+        $crate::call_method_cached::<_, _, _, $signature>(
+            &METHOD_ID,
+            &$object,
+            $token,
+            concat!($name, "\0"),
+            $arguments,
+        )
+    }};
+}
+
+/// Call a static Java method.
+///
+/// The method has four generic parameters:
+///  - The first one is the class of the object. Can be inferred
+///  - The second one is the type of the arguments tuple. Can be inferred
+///  - The third one is the Java result type. Can be inferred
+///  - The fourth one is the signature of the Java method. Must be specified
+///
+/// As a result, only one generic parameter needs to be specified -- the last one.
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::String;
+/// # use std::ptr;
+/// #
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// // Safe because correct arguments are passed and correct return type specified.
+/// // See `String::valueOf(int)` javadoc:
+/// // https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#valueOf(int)
+/// let string_value = unsafe {
+///     call_static_method::<String<'a>, _, _, fn(i32) -> String<'a>>(
+///         env,
+///         &token,
+///         "valueOf\0",
+///         (17,),
+///     )
+/// }
+/// .or_npe(env, &token)?
+/// .as_string(&token);
+/// assert_eq!(string_value, "17");
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+///
+/// Note that method name string must be null-terminating.
+///
+/// See more info about how to pass or return types from Java calls in [`JniSignature`](trait.JniSignature.html)
+/// documentation
+///
+/// This method is unsafe because incorrect parameters can be passed to a method or incorrect return type specified.
+pub unsafe fn call_static_method<'a, T, A, R, F>(
+    env: &'a JniEnv<'a>,
+    token: &NoException<'a>,
+    name: &str,
+    arguments: A,
+) -> JavaResult<'a, R::ResultType>
+where
+    T: JniSignature,
+    A: JavaArgumentTuple,
+    R: JavaMethodResult<'a>,
+    F: JavaMethodSignature<A, R>,
+{
+    #[cfg(feature = "verify-hierarchy")]
+    crate::introspection::verify_class_hierarchy_once(env, token, T::signature())?;
+    #[cfg(feature = "std")]
+    let _call = crate::panic_hook::enter_call(env, T::signature(), name);
+    #[cfg(feature = "tracing")]
+    let _span = crate::call_span::enter_call(T::signature(), name, F::method_signature());
+    R::call_static_method::<T, A>(env, token, name, F::method_signature(), arguments)
+}
+
+/// Call a Java constructor
+///
+/// The method has three generic parameters:
+///  - The first one is the class of the object
+///  - The second one is the type of the arguments tuple. Can be inferred
+///  - The third one is the signature of the Java method. Can be inferred
+///
+/// As a result, only one generic parameter needs to be specified -- the class type.
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::String;
+/// # use std::ptr;
+/// #
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// // Safe because correct arguments are passed.
+/// // See `String()` javadoc:
+/// // https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#<init>()
+/// let empty_string = unsafe {
+///     call_constructor::<String<'a>, _, fn()>(env, &token, ())
+/// }?
+/// .as_string(&token);
+/// assert_eq!(empty_string, "");
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+///
+/// See more info about how to pass or return types from Java calls in [`JniSignature`](trait.JniSignature.html)
+/// documentation
+///
+/// This method is unsafe because incorrect parameters can be passed to a method.
+pub unsafe fn call_constructor<'a, R, A, F>(
+    env: &'a JniEnv<'a>,
+    token: &NoException<'a>,
+    arguments: A,
+) -> JavaResult<'a, R>
+where
+    A: JavaArgumentTuple,
+    R: JavaClass<'a>,
+    F: JavaMethodSignature<A, ()>,
+{
+    #[cfg(feature = "verify-hierarchy")]
+    crate::introspection::verify_class_hierarchy_once(env, token, R::signature())?;
+    let class = R::class(env, token)?;
+    #[cfg(feature = "std")]
+    let _call = crate::panic_hook::enter_call(env, R::signature(), "<init>");
+    #[cfg(feature = "tracing")]
+    let _span = crate::call_span::enter_call(R::signature(), "<init>", F::method_signature());
+    let result = jni_methods::call_constructor(
+        &class,
+        token,
+        F::method_signature(),
+        JavaArgumentTuple::to_jni(&arguments),
+    )?;
+    Ok(R::from_object(Object::from_raw(env, result)))
+}
+
+// Public for `rust-jni-java` through `__bindings`.
+#[allow(clippy::missing_safety_doc)]
+pub trait JavaMethodResult<'a> {
+    type JniType;
+    type ResultType: 'a;
+
+    unsafe fn call_method<T, A>(
+        object: &T,
+        token: &NoException<'a>,
+        name: &str,
+        signature: &str,
+        arguments: A,
+    ) -> JavaResult<'a, Self::ResultType>
+    where
+        T: JavaClassRef<'a>,
+        A: JavaArgumentTuple;
+
+    unsafe fn call_method_with_id<T, A>(
+        object: &T,
+        token: &NoException<'a>,
+        method_id: NonNull<jni_sys::_jmethodID>,
+        arguments: A,
+    ) -> JavaResult<'a, Self::ResultType>
+    where
+        T: JavaClassRef<'a>,
+        A: JavaArgumentTuple;
+
+    /// Call a method by the method id without checking for an exception.
+    unsafe fn call_method_with_id_unchecked<T, A>(
+        object: &T,
+        method_id: NonNull<jni_sys::_jmethodID>,
+        arguments: A,
+    ) -> Self::ResultType
+    where
+        T: JavaClassRef<'a>,
+        A: JavaArgumentTuple;
+
+    unsafe fn call_static_method<T, A>(
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+        name: &str,
+        signature: &str,
+        arguments: A,
+    ) -> JavaResult<'a, Self::ResultType>
+    where
+        T: JniSignature,
+        A: JavaArgumentTuple;
+
+    /// Convert the boxed result of a dynamic call, like
+    /// [`MethodHandle.invokeWithArguments`](https://docs.oracle.com/javase/10/docs/api/java/lang/invoke/MethodHandle.html#invokeWithArguments(java.lang.Object...)).
+    ///
+    /// Unsafe because the boxed value must have the right class.
+    unsafe fn from_boxed(
+        boxed: Option<Object<'a>>,
+        token: &NoException<'a>,
+    ) -> JavaResult<'a, Self::ResultType>;
+}
+
+impl<'a, S> JavaMethodResult<'a> for S
+where
+    S: JavaClass<'a> + 'a,
+{
+    type JniType = Option<NonNull<jni_sys::_jobject>>;
+    type ResultType = Option<Self>;
+
+    #[inline(always)]
+    unsafe fn call_method<T, A>(
+        object: &T,
+        token: &NoException<'a>,
+        name: &str,
+        signature: &str,
+        arguments: A,
+    ) -> JavaResult<'a, Self::ResultType>
+    where
+        T: JavaClassRef<'a>,
+        A: JavaArgumentTuple,
+    {
+        let result = jni_methods::call_object_method(
+            object.as_ref(),
+            token,
+            name,
+            signature,
+            JavaArgumentTuple::to_jni(&arguments),
+        )?;
+        Ok(result.map(
+            #[inline(always)]
+            |result| Self::from_object(Object::from_raw(object.as_ref().env(), result)),
+        ))
+    }
+
+    #[inline(always)]
+    unsafe fn call_method_with_id<T, A>(
+        object: &T,
+        token: &NoException<'a>,
+        method_id: NonNull<jni_sys::_jmethodID>,
+        arguments: A,
+    ) -> JavaResult<'a, Self::ResultType>
+    where
+        T: JavaClassRef<'a>,
+        A: JavaArgumentTuple,
+    {
+        let result = jni_methods::call_object_method_with_id(
+            object.as_ref(),
+            token,
+            method_id,
+            JavaArgumentTuple::to_jni(&arguments),
+        )?;
+        Ok(result.map(
+            #[inline(always)]
+            |result| Self::from_object(Object::from_raw(object.as_ref().env(), result)),
+        ))
+    }
+
+    #[inline(always)]
+    unsafe fn call_method_with_id_unchecked<T, A>(
+        object: &T,
+        method_id: NonNull<jni_sys::_jmethodID>,
+        arguments: A,
+    ) -> Self::ResultType
+    where
+        T: JavaClassRef<'a>,
+        A: JavaArgumentTuple,
+    {
+        let result = jni_sys::jobject::call_method(
+            object.as_ref(),
+            method_id.as_ptr(),
+            JavaArgumentTuple::to_jni(&arguments),
+        );
+        NonNull::new(result).map(
+            #[inline(always)]
+            |result| Self::from_object(Object::from_raw(object.as_ref().env(), result)),
+        )
+    }
+
+    #[inline(always)]
+    unsafe fn call_static_method<T, A>(
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+        name: &str,
+        signature: &str,
+        arguments: A,
+    ) -> JavaResult<'a, Self::ResultType>
+    where
+        T: JniSignature,
+        A: JavaArgumentTuple,
+    {
+        let class = find_class::<T>(env, token)?;
+        let result = jni_methods::call_static_object_method(
+            &class,
+            token,
+            name,
+            signature,
+            JavaArgumentTuple::to_jni(&arguments),
+        )?;
+        Ok(result.map(
+            #[inline(always)]
+            |result| Self::from_object(Object::from_raw(env, result)),
+        ))
+    }
+
+    #[inline(always)]
+    unsafe fn from_boxed(
+        boxed: Option<Object<'a>>,
+        _token: &NoException<'a>,
+    ) -> JavaResult<'a, Self::ResultType> {
+        Ok(boxed.map(
+            #[inline(always)]
+            |boxed| Self::from_object(boxed),
+        ))
+    }
+}
+
+/// Methods returning an [`Option`](https://doc.rust-lang.org/std/option/enum.Option.html) of
+/// a Java class wrapper, to make it explicit in the signature that the method can return `null`.
+/// The result is the same as for the class itself.
+impl<'a, S> JavaMethodResult<'a> for Option<S>
+where
+    S: JavaClass<'a> + 'a,
+{
+    type JniType = <S as JavaMethodResult<'a>>::JniType;
+    type ResultType = Option<S>;
+
+    #[inline(always)]
+    unsafe fn call_method<T, A>(
+        object: &T,
+        token: &NoException<'a>,
+        name: &str,
+        signature: &str,
+        arguments: A,
+    ) -> JavaResult<'a, Self::ResultType>
+    where
+        T: JavaClassRef<'a>,
+        A: JavaArgumentTuple,
+    {
+        S::call_method::<T, A>(object, token, name, signature, arguments)
+    }
+
+    #[inline(always)]
+    unsafe fn call_method_with_id<T, A>(
+        object: &T,
+        token: &NoException<'a>,
+        method_id: NonNull<jni_sys::_jmethodID>,
+        arguments: A,
+    ) -> JavaResult<'a, Self::ResultType>
+    where
+        T: JavaClassRef<'a>,
+        A: JavaArgumentTuple,
+    {
+        S::call_method_with_id::<T, A>(object, token, method_id, arguments)
+    }
+
+    #[inline(always)]
+    unsafe fn call_method_with_id_unchecked<T, A>(
+        object: &T,
+        method_id: NonNull<jni_sys::_jmethodID>,
+        arguments: A,
+    ) -> Self::ResultType
+    where
+        T: JavaClassRef<'a>,
+        A: JavaArgumentTuple,
+    {
+        S::call_method_with_id_unchecked::<T, A>(object, method_id, arguments)
+    }
+
+    #[inline(always)]
+    unsafe fn call_static_method<T, A>(
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+        name: &str,
+        signature: &str,
+        arguments: A,
+    ) -> JavaResult<'a, Self::ResultType>
+    where
+        T: JniSignature,
+        A: JavaArgumentTuple,
+    {
+        S::call_static_method::<T, A>(env, token, name, signature, arguments)
+    }
+
+    #[inline(always)]
+    unsafe fn from_boxed(
+        boxed: Option<Object<'a>>,
+        token: &NoException<'a>,
+    ) -> JavaResult<'a, Self::ResultType> {
+        S::from_boxed(boxed, token)
+    }
+}
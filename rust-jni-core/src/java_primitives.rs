@@ -7,12 +7,14 @@ use crate::java_methods::JavaMethodResult;
 use crate::java_methods::JniSignature;
 use crate::jni_bool;
 use crate::jni_methods;
-use crate::jni_types::private::JniPrimitiveType;
+use crate::jni_types::private::{JniPrimitiveType, JniType};
 use crate::native_method::ToJavaNativeArgument;
+use crate::object::Object;
 use crate::result::JavaResult;
 use crate::token::NoException;
-use std::char;
-use std::iter;
+use core::char;
+use core::iter;
+use core::ptr::NonNull;
 
 pub trait JavaPrimitiveResultType: JniSignature {
     type JniType: JniPrimitiveType;
@@ -34,17 +36,8 @@ macro_rules! jni_signature_trait {
     };
 }
 
-macro_rules! java_primitive_result_type_trait {
+macro_rules! java_method_result_trait {
     ($type:ty, $jni_type:ty) => {
-        impl JavaPrimitiveResultType for $type {
-            type JniType = $jni_type;
-
-            #[inline(always)]
-            fn from_jni(value: Self::JniType) -> Self {
-                value as Self
-            }
-        }
-
         impl<'a> JavaMethodResult<'a> for $type {
             type JniType = $jni_type;
             type ResultType = Self;
@@ -71,6 +64,44 @@ macro_rules! java_primitive_result_type_trait {
                 Ok(Self::from_jni(result))
             }
 
+            #[inline(always)]
+            unsafe fn call_method_with_id<T, A>(
+                object: &T,
+                token: &NoException<'a>,
+                method_id: NonNull<jni_sys::_jmethodID>,
+                arguments: A,
+            ) -> JavaResult<'a, Self::ResultType>
+            where
+                T: JavaClassRef<'a>,
+                A: JavaArgumentTuple,
+            {
+                let result: Self::JniType = jni_methods::call_primitive_method_with_id(
+                    object.as_ref(),
+                    token,
+                    method_id,
+                    JavaArgumentTuple::to_jni(&arguments),
+                )?;
+                Ok(Self::from_jni(result))
+            }
+
+            #[inline(always)]
+            unsafe fn call_method_with_id_unchecked<T, A>(
+                object: &T,
+                method_id: NonNull<jni_sys::_jmethodID>,
+                arguments: A,
+            ) -> Self::ResultType
+            where
+                T: JavaClassRef<'a>,
+                A: JavaArgumentTuple,
+            {
+                let result = <$jni_type as JniType>::call_method(
+                    object.as_ref(),
+                    method_id.as_ptr(),
+                    JavaArgumentTuple::to_jni(&arguments),
+                );
+                Self::from_jni(result)
+            }
+
             #[inline(always)]
             unsafe fn call_static_method<T, A>(
                 env: &'a JniEnv<'a>,
@@ -80,7 +111,7 @@ macro_rules! java_primitive_result_type_trait {
                 arguments: A,
             ) -> JavaResult<'a, Self::ResultType>
             where
-                T: JavaClassRef<'a>,
+                T: JniSignature,
                 A: JavaArgumentTuple,
             {
                 let class = find_class::<T>(env, token)?;
@@ -93,7 +124,55 @@ macro_rules! java_primitive_result_type_trait {
                 )?;
                 Ok(Self::from_jni(result))
             }
+
+            #[inline(always)]
+            unsafe fn from_boxed(
+                boxed: Option<Object<'a>>,
+                token: &NoException<'a>,
+            ) -> JavaResult<'a, Self::ResultType> {
+                match boxed {
+                    // Only `void` methods return `null` from dynamic calls.
+                    None => Ok(Self::from_jni(<$jni_type as JniType>::default())),
+                    Some(boxed) => {
+                        let (name, signature) =
+                            unboxing_method(<$jni_type as JniPrimitiveType>::signature());
+                        let result: Self::JniType =
+                            jni_methods::call_primitive_method(&boxed, token, name, signature, ())?;
+                        Ok(Self::from_jni(result))
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Get the name and the signature of the method unboxing a primitive value with the signature.
+fn unboxing_method(signature: &str) -> (&'static str, &'static str) {
+    match signature {
+        "Z" => ("booleanValue\0", "()Z\0"),
+        "C" => ("charValue\0", "()C\0"),
+        "B" => ("byteValue\0", "()B\0"),
+        "S" => ("shortValue\0", "()S\0"),
+        "I" => ("intValue\0", "()I\0"),
+        "J" => ("longValue\0", "()J\0"),
+        "F" => ("floatValue\0", "()F\0"),
+        "D" => ("doubleValue\0", "()D\0"),
+        _ => panic!("Values with the signature {:?} can't be boxed.", signature),
+    }
+}
+
+macro_rules! java_primitive_result_type_trait {
+    ($type:ty, $jni_type:ty) => {
+        impl JavaPrimitiveResultType for $type {
+            type JniType = $jni_type;
+
+            #[inline(always)]
+            fn from_jni(value: Self::JniType) -> Self {
+                value as Self
+            }
         }
+
+        java_method_result_trait!($type, $jni_type);
     };
 }
 
@@ -149,55 +228,7 @@ impl JavaPrimitiveResultType for bool {
     }
 }
 
-impl<'a> JavaMethodResult<'a> for bool {
-    type JniType = jni_sys::jboolean;
-    type ResultType = Self;
-
-    #[inline(always)]
-    unsafe fn call_method<T, A>(
-        object: &T,
-        token: &NoException<'a>,
-        name: &str,
-        signature: &str,
-        arguments: A,
-    ) -> JavaResult<'a, Self::ResultType>
-    where
-        T: JavaClassRef<'a>,
-        A: JavaArgumentTuple,
-    {
-        let result: Self::JniType = jni_methods::call_primitive_method(
-            object.as_ref(),
-            token,
-            name,
-            signature,
-            JavaArgumentTuple::to_jni(&arguments),
-        )?;
-        Ok(Self::from_jni(result))
-    }
-
-    #[inline(always)]
-    unsafe fn call_static_method<T, A>(
-        env: &'a JniEnv<'a>,
-        token: &NoException<'a>,
-        name: &str,
-        signature: &str,
-        arguments: A,
-    ) -> JavaResult<'a, Self::ResultType>
-    where
-        T: JavaClassRef<'a>,
-        A: JavaArgumentTuple,
-    {
-        let class = find_class::<T>(env, token)?;
-        let result = jni_methods::call_static_primitive_method(
-            &class,
-            token,
-            name,
-            signature,
-            JavaArgumentTuple::to_jni(&arguments),
-        )?;
-        Ok(Self::from_jni(result))
-    }
-}
+java_method_result_trait!(bool, jni_sys::jboolean);
 
 jni_primitive_argument_traits!(
     char,
@@ -227,6 +258,8 @@ impl JavaPrimitiveResultType for char {
     }
 }
 
+java_method_result_trait!(char, jni_sys::jchar);
+
 java_primitive_traits!(
     u8,
     jni_sys::jbyte,
@@ -257,3 +290,66 @@ java_primitive_traits!(
     jni_sys::jdouble,
     "[`f64`](https://doc.rust-lang.org/std/primitive.f64.html)"
 );
+
+#[cfg(test)]
+mod java_primitives_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn round_trip<T>(value: T) -> T
+    where
+        T: JavaArgumentType<JniType = <T as JavaPrimitiveResultType>::JniType>
+            + JavaPrimitiveResultType,
+    {
+        T::from_jni(JavaArgumentType::to_jni(&value))
+    }
+
+    proptest! {
+        #[test]
+        fn bool_from_jni(value: jni_sys::jboolean) {
+            prop_assert_eq!(bool::from_jni(value), value != jni_sys::JNI_FALSE);
+        }
+
+        #[test]
+        fn bool_round_trip(value: bool) {
+            prop_assert_eq!(round_trip(value), value);
+        }
+
+        #[test]
+        fn integer_round_trip(byte: u8, short: i16, int: i32, long: i64) {
+            prop_assert_eq!(round_trip(byte), byte);
+            prop_assert_eq!(round_trip(short), short);
+            prop_assert_eq!(round_trip(int), int);
+            prop_assert_eq!(round_trip(long), long);
+        }
+
+        #[test]
+        fn char_round_trip(value in any::<char>().prop_filter("UTF-16 code unit", |value| {
+            value.len_utf16() == 1
+        })) {
+            prop_assert_eq!(round_trip(value), value);
+        }
+
+        // Compare bits, so that NaN payloads and signed zeros are checked too.
+        #[test]
+        fn float_round_trip(float_bits: u32, double_bits: u64) {
+            prop_assert_eq!(round_trip(f32::from_bits(float_bits)).to_bits(), float_bits);
+            prop_assert_eq!(round_trip(f64::from_bits(double_bits)).to_bits(), double_bits);
+        }
+    }
+
+    #[test]
+    fn float_nan_payloads() {
+        for bits in &[0x7fc0_0000, 0x7f80_0001, 0xffc0_0001, 0x7fff_ffff] {
+            assert_eq!(round_trip(f32::from_bits(*bits)).to_bits(), *bits);
+        }
+        for bits in &[
+            0x7ff8_0000_0000_0000,
+            0x7ff0_0000_0000_0001,
+            0xfff8_0000_0000_0001,
+            0x7fff_ffff_ffff_ffff,
+        ] {
+            assert_eq!(round_trip(f64::from_bits(*bits)).to_bits(), *bits);
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
 /// Java uses
 /// [modified UTF-8 strings](https://docs.oracle.com/javase/10/docs/specs/jni/types.html#modified-utf-8-strings).
 /// JNI in addition uses null-terminated modified UTF-8 strings.
@@ -5,8 +7,7 @@
 /// uses the `cesu8` crate to provide tools for mapping Rust UTF-8 strings and
 /// JNI null-terminated CESU-8 strings.
 use cesu8::{self, Cesu8DecodingError};
-use std::borrow::Cow;
-use std::slice;
+use core::slice;
 
 /// Convert a Rust UTF-8 string into a buffer with a Java modified UTF-8 string.
 ///
@@ -0,0 +1,125 @@
+use crate::class::Class;
+use crate::env::JniEnv;
+use crate::error::UnsupportedOnThisJvm;
+use crate::jni_methods;
+use crate::result::JavaResult;
+use crate::string::String;
+use crate::token::NoException;
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// The feature release of the Java VM, `0` until it's first requested.
+///
+/// Only one Java VM per process is supported, so the value can be cached for the whole process.
+static JVM_FEATURE_LEVEL: AtomicU32 = AtomicU32::new(0);
+
+/// Get the feature release of the Java VM, like `8` for Java 8 or `17` for Java 17.
+///
+/// Allows bindings to check at runtime if newer Java library methods are available.
+/// The release is taken from the `java.specification.version` system property,
+/// which is `1.8` for Java 8 and `17` for Java 17, and is cached for the whole process.
+///
+/// [`System::getProperty` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/System.html#getProperty(java.lang.String))
+///
+/// # Example
+/// ```
+/// # use rust_jni::*;
+/// #
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// if jvm_feature_level(env, &token)? >= 11 {
+///     // Call methods added in Java 11.
+/// }
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+pub fn jvm_feature_level<'a>(env: &'a JniEnv<'a>, token: &NoException<'a>) -> JavaResult<'a, u32> {
+    let cached = JVM_FEATURE_LEVEL.load(Ordering::Relaxed);
+    if cached != 0 {
+        return Ok(cached);
+    }
+    let class = Class::find(env, token, "java/lang/System")?;
+    let key = String::new(env, token, "java.specification.version")?;
+    // Safe because we ensure correct arguments and return type.
+    let version = unsafe {
+        jni_methods::call_static_object_method(
+            &class,
+            token,
+            "getProperty\0",
+            "(Ljava/lang/String;)Ljava/lang/String;\0",
+            (key.raw_object().as_ptr(),),
+        )
+    }?;
+    // The property is required by the Java specification.
+    let version = version.expect("The java.specification.version property is not set.");
+    // Safe because the argument is a valid string reference.
+    let version = unsafe { String::from_raw(env, version) };
+    let feature_level = parse_feature_level(&version.as_string(token));
+    JVM_FEATURE_LEVEL.store(feature_level, Ordering::Relaxed);
+    Ok(feature_level)
+}
+
+/// Check that the Java VM is at least the `required` feature release.
+///
+/// Returns an [`UnsupportedOnThisJvm`](struct.UnsupportedOnThisJvm.html) error otherwise.
+/// Used by generated bindings of methods marked with `@since`.
+pub fn require_jvm_feature_level<'a>(
+    env: &'a JniEnv<'a>,
+    token: &NoException<'a>,
+    required: u32,
+) -> JavaResult<'a, Result<(), UnsupportedOnThisJvm>> {
+    let actual = jvm_feature_level(env, token)?;
+    Ok(if actual < required {
+        Err(UnsupportedOnThisJvm { required, actual })
+    } else {
+        Ok(())
+    })
+}
+
+/// Parse the feature release from a `java.specification.version` value.
+fn parse_feature_level(version: &str) -> u32 {
+    // Releases before Java 9 are versioned like `1.8`.
+    let feature = if version.starts_with("1.") {
+        &version[2..]
+    } else {
+        version
+    };
+    feature
+        .split('.')
+        .next()
+        .and_then(|feature| feature.parse().ok())
+        .unwrap_or_else(|| panic!("Unexpected Java specification version {:?}.", version))
+}
+
+#[cfg(test)]
+mod parse_feature_level_tests {
+    use super::*;
+
+    #[test]
+    fn legacy() {
+        assert_eq!(parse_feature_level("1.8"), 8);
+        assert_eq!(parse_feature_level("1.7"), 7);
+    }
+
+    #[test]
+    fn modern() {
+        assert_eq!(parse_feature_level("9"), 9);
+        assert_eq!(parse_feature_level("17"), 17);
+        assert_eq!(parse_feature_level("21.0"), 21);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected Java specification version \"unknown\".")]
+    fn unknown() {
+        parse_feature_level("unknown");
+    }
+}
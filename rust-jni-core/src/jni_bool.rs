@@ -0,0 +1,40 @@
+use jni_sys;
+
+/// Convert a `jboolean` returned by JNI into a `bool`.
+///
+/// Like in C, any non-zero `jboolean` is `true`. Some Java VMs and native code return values
+/// other than `JNI_TRUE`, so all conversions from JNI go through here to normalize them.
+pub fn to_rust(value: jni_sys::jboolean) -> bool {
+    value != jni_sys::JNI_FALSE
+}
+
+pub fn to_jni(value: bool) -> jni_sys::jboolean {
+    match value {
+        true => jni_sys::JNI_TRUE,
+        false => jni_sys::JNI_FALSE,
+    }
+}
+
+#[cfg(test)]
+mod jni_bool_tests {
+    use super::*;
+
+    #[test]
+    fn test_to_jni() {
+        assert_eq!(to_jni(true), jni_sys::JNI_TRUE);
+        assert_eq!(to_jni(false), jni_sys::JNI_FALSE);
+    }
+
+    #[test]
+    fn test_to_rust() {
+        assert_eq!(to_rust(jni_sys::JNI_TRUE), true);
+        assert_eq!(to_rust(jni_sys::JNI_FALSE), false);
+    }
+
+    #[test]
+    fn test_to_rust_non_canonical() {
+        assert_eq!(to_rust(2), true);
+        assert_eq!(to_rust(10), true);
+        assert_eq!(to_rust(255), true);
+    }
+}
@@ -1,3 +1,6 @@
+// Public for `rust-jni-java` through `__bindings`, safety requirements are documented in prose.
+#![allow(clippy::missing_safety_doc)]
+
 use crate::class::Class;
 use crate::java_string::{
     to_java_string_null_terminated, to_java_string_null_terminated_unchecked,
@@ -6,13 +9,15 @@ use crate::jni_types::private::{JniArgumentTypeTuple, JniPrimitiveType, JniType}
 use crate::object::Object;
 use crate::result::JavaResult;
 use crate::token::{CallOutcome, NoException};
+use core::ffi::c_char;
 use core::ptr::NonNull;
-use std::os::raw::c_char;
 
 include!("call_jni_method.rs");
 
+/// Get the method id of a Java method.
+///
 /// Unsafe because signature must be null-terminated.
-unsafe fn get_method_id<'a>(
+pub unsafe fn get_method_id<'a>(
     class: &Class<'a>,
     token: &NoException<'a>,
     name: &str,
@@ -35,8 +40,10 @@ unsafe fn get_method_id<'a>(
     }
 }
 
+/// Get the method id of a static Java method.
+///
 /// Unsafe because signature must be null-terminated.
-unsafe fn get_static_method_id<'a>(
+pub unsafe fn get_static_method_id<'a>(
     class: &Class<'a>,
     token: &NoException<'a>,
     name: &str,
@@ -62,7 +69,7 @@ unsafe fn get_static_method_id<'a>(
 /// Call a method on a Java object that returns a primitive value.
 ///
 /// Unsafe because it is possible to pass incorrect arguments or return type.
-pub(crate) unsafe fn call_primitive_method<'a, R: JniPrimitiveType>(
+pub unsafe fn call_primitive_method<'a, R: JniPrimitiveType>(
     object: &Object<'a>,
     token: &NoException<'a>,
     name: &str,
@@ -71,8 +78,20 @@ pub(crate) unsafe fn call_primitive_method<'a, R: JniPrimitiveType>(
 ) -> JavaResult<'a, R> {
     let class = object.class(token);
     let method_id = get_method_id(&class, token, name, signature)?;
+    call_primitive_method_with_id(object, token, method_id, arguments)
+}
+
+/// Call a method on a Java object that returns a primitive value by the method id.
+///
+/// Unsafe because it is possible to pass an incorrect method id, arguments or return type.
+pub unsafe fn call_primitive_method_with_id<'a, R: JniPrimitiveType>(
+    object: &Object<'a>,
+    token: &NoException<'a>,
+    method_id: NonNull<jni_sys::_jmethodID>,
+    arguments: impl JniArgumentTypeTuple,
+) -> JavaResult<'a, R> {
     token.with_owned(
-        class.env(),
+        object.env(),
         #[inline(always)]
         |_token| CallOutcome::Unknown(R::call_method(object, method_id.as_ptr(), arguments)),
     )
@@ -81,7 +100,7 @@ pub(crate) unsafe fn call_primitive_method<'a, R: JniPrimitiveType>(
 /// Call a method on a Java object that returns another object.
 ///
 /// Unsafe because it is possible to pass incorrect arguments or return type.
-pub(crate) unsafe fn call_object_method<'a>(
+pub unsafe fn call_object_method<'a>(
     object: &Object<'a>,
     token: &NoException<'a>,
     name: &str,
@@ -90,8 +109,20 @@ pub(crate) unsafe fn call_object_method<'a>(
 ) -> JavaResult<'a, Option<NonNull<jni_sys::_jobject>>> {
     let class = object.class(token);
     let method_id = get_method_id(&class, token, name, signature)?;
+    call_object_method_with_id(object, token, method_id, arguments)
+}
+
+/// Call a method on a Java object that returns another object by the method id.
+///
+/// Unsafe because it is possible to pass an incorrect method id, arguments or return type.
+pub unsafe fn call_object_method_with_id<'a>(
+    object: &Object<'a>,
+    token: &NoException<'a>,
+    method_id: NonNull<jni_sys::_jmethodID>,
+    arguments: impl JniArgumentTypeTuple,
+) -> JavaResult<'a, Option<NonNull<jni_sys::_jobject>>> {
     token.with_owned(
-        class.env(),
+        object.env(),
         #[inline(always)]
         |token| {
             let result = jni_sys::jobject::call_method(object, method_id.as_ptr(), arguments);
@@ -106,7 +137,7 @@ pub(crate) unsafe fn call_object_method<'a>(
 /// Call a static method on a Java class that returns a primitive value.
 ///
 /// Unsafe because it is possible to pass incorrect arguments or return type.
-pub(crate) unsafe fn call_static_primitive_method<'a, R: JniPrimitiveType>(
+pub unsafe fn call_static_primitive_method<'a, R: JniPrimitiveType>(
     class: &Class<'a>,
     token: &NoException<'a>,
     name: &str,
@@ -114,6 +145,18 @@ pub(crate) unsafe fn call_static_primitive_method<'a, R: JniPrimitiveType>(
     arguments: impl JniArgumentTypeTuple,
 ) -> JavaResult<'a, R> {
     let method_id = get_static_method_id(&class, token, name, signature)?;
+    call_static_primitive_method_with_id(class, token, method_id, arguments)
+}
+
+/// Call a static method on a Java class that returns a primitive value by the method id.
+///
+/// Unsafe because it is possible to pass an incorrect method id, arguments or return type.
+pub unsafe fn call_static_primitive_method_with_id<'a, R: JniPrimitiveType>(
+    class: &Class<'a>,
+    token: &NoException<'a>,
+    method_id: NonNull<jni_sys::_jmethodID>,
+    arguments: impl JniArgumentTypeTuple,
+) -> JavaResult<'a, R> {
     token.with_owned(
         class.env(),
         #[inline(always)]
@@ -124,7 +167,7 @@ pub(crate) unsafe fn call_static_primitive_method<'a, R: JniPrimitiveType>(
 /// Call a static method on a Java object that returns another object.
 ///
 /// Unsafe because it is possible to pass incorrect arguments or return type.
-pub(crate) unsafe fn call_static_object_method<'a>(
+pub unsafe fn call_static_object_method<'a>(
     class: &Class<'a>,
     token: &NoException<'a>,
     name: &str,
@@ -148,7 +191,7 @@ pub(crate) unsafe fn call_static_object_method<'a>(
 /// Call a constructor of a Java class.
 ///
 /// Unsafe because it is possible to pass incorrect arguments.
-pub(crate) unsafe fn call_constructor<'a, A: JniArgumentTypeTuple>(
+pub unsafe fn call_constructor<'a, A: JniArgumentTypeTuple>(
     class: &Class<'a>,
     token: &NoException<'a>,
     signature: &str,
@@ -1,11 +1,19 @@
 use crate::class::Class;
+use crate::env::JniEnv;
+use crate::jni_methods;
 use crate::object::Object;
+use crate::object_array::ObjectArray;
+use crate::result::JavaResult;
+use crate::token::NoException;
+use core::ptr;
 use jni_sys;
-use std::ptr;
 
 include!("call_jni_method.rs");
 
 pub(crate) mod private {
+    // Public for `rust-jni-java` through `__bindings`.
+    #![allow(clippy::missing_safety_doc)]
+
     use super::*;
 
     /// A trait that represents a JNI type. It's implemented for all JNI primitive types
@@ -14,6 +22,10 @@ pub(crate) mod private {
     pub trait JniType {
         fn default() -> Self;
 
+        /// Check if the value is a non-`null` object reference.
+        #[cfg(feature = "stats")]
+        fn is_reference(&self) -> bool;
+
         unsafe fn call_method<In: JniArgumentTypeTuple>(
             object: &Object,
             method_id: jni_sys::jmethodID,
@@ -34,7 +46,25 @@ pub(crate) mod private {
 
     /// A trait that represents JNI types that can be passed as arguments to JNI functions.
     /// Implemented for all JNI types except for [`()`](https://doc.rust-lang.org/stable/std/primitive.unit.html).
-    pub trait JniArgumentType: JniType {}
+    ///
+    /// Arguments are passed to variadic JNI functions, so they need to undergo the C default
+    /// argument promotions first: JNI reads `float` arguments as `double` and small integer
+    /// arguments as `int`.
+    pub trait JniArgumentType: JniType {
+        type Promoted;
+
+        fn promote(self) -> Self::Promoted;
+
+        /// Store the value into an `Object[]` array element, boxing primitive values.
+        ///
+        /// Unsafe because an object reference must be valid.
+        unsafe fn set_boxed_element<'a>(
+            self,
+            array: &ObjectArray<'a>,
+            token: &NoException<'a>,
+            index: usize,
+        ) -> JavaResult<'a, ()>;
+    }
 
     /// A trait that implements calling JNI variadic functions using a macro to generate
     /// it's instances for tuples of different sizes.
@@ -166,6 +196,16 @@ pub(crate) mod private {
             method_id: jni_sys::jmethodID,
             arguments: Self,
         ) -> jni_sys::jdouble;
+
+        /// Pack the arguments into an `Object[]` array for dynamic calls, like
+        /// [`MethodHandle.invokeWithArguments`](https://docs.oracle.com/javase/10/docs/api/java/lang/invoke/MethodHandle.html#invokeWithArguments(java.lang.Object...)).
+        ///
+        /// Unsafe because object references must be valid.
+        unsafe fn to_object_array<'a>(
+            self,
+            env: &'a JniEnv<'a>,
+            token: &NoException<'a>,
+        ) -> JavaResult<'a, ObjectArray<'a>>;
     }
 }
 
@@ -174,12 +214,21 @@ use private::*;
 /// A macro for generating [`JniType`](trait.JniType.html) implementation for primitive types.
 macro_rules! jni_type_trait {
     ($type:ty, $default:expr, $method:ident, $static_method:ident) => {
+        jni_type_trait!($type, $default, $method, $static_method, |_| false);
+    };
+    ($type:ty, $default:expr, $method:ident, $static_method:ident, $is_reference:expr) => {
         impl JniType for $type {
             #[inline(always)]
             fn default() -> Self {
                 $default
             }
 
+            #[cfg(feature = "stats")]
+            #[inline(always)]
+            fn is_reference(&self) -> bool {
+                ($is_reference)(self)
+            }
+
             #[inline(always)]
             unsafe fn call_method<In: JniArgumentTypeTuple>(
                 object: &Object,
@@ -205,7 +254,8 @@ jni_type_trait!(
     jni_sys::jobject,
     ptr::null_mut(),
     call_object_method,
-    call_static_object_method
+    call_static_object_method,
+    |value: &jni_sys::jobject| !value.is_null()
 );
 
 /// A macro for generating [`JniPrimitiveType`](trait.JniPrimitiveType.html) implementation for primitive types.
@@ -294,7 +344,7 @@ macro_rules! jni_method_call {
                 object,
                 $method,
                 method_id
-                $(,$argument)*
+                $(,JniArgumentType::promote($argument))*
             )
         }
     }
@@ -332,6 +382,25 @@ macro_rules! input_tuple_impls {
             jni_method_call!(call_static_float_method, Class, CallStaticFloatMethod, jni_sys::jfloat, $($type,)*);
             jni_method_call!(call_double_method, Object, CallDoubleMethod, jni_sys::jdouble, $($type,)*);
             jni_method_call!(call_static_double_method, Class, CallStaticDoubleMethod, jni_sys::jdouble, $($type,)*);
+
+            // The index is unused for an empty tuple.
+            #[allow(unused_mut, unused_variables, unused_assignments)]
+            #[inline(always)]
+            unsafe fn to_object_array<'env>(
+                self,
+                env: &'env JniEnv<'env>,
+                token: &NoException<'env>,
+            ) -> JavaResult<'env, ObjectArray<'env>> {
+                #[allow(non_snake_case)]
+                let ($($type,)*) = self;
+                let array = ObjectArray::new(env, token, <[&str]>::len(&[$(stringify!($type),)*]))?;
+                let mut index = 0;
+                $(
+                    $type.set_boxed_element(&array, token, index)?;
+                    index += 1;
+                )*
+                Ok(array)
+            }
         }
         peel_input_tuple_impls! { $($type,)* }
     );
@@ -352,16 +421,240 @@ input_tuple_impls! {
     T11,
 }
 
-impl JniArgumentType for jni_sys::jboolean {}
-impl JniArgumentType for jni_sys::jchar {}
-impl JniArgumentType for jni_sys::jbyte {}
-impl JniArgumentType for jni_sys::jshort {}
-impl JniArgumentType for jni_sys::jint {}
-impl JniArgumentType for jni_sys::jlong {}
-impl JniArgumentType for jni_sys::jfloat {}
-impl JniArgumentType for jni_sys::jdouble {}
-impl JniArgumentType for jni_sys::jobject {}
+/// A macro for generating [`JniArgumentType`](trait.JniArgumentType.html) implementations
+/// for primitive types, which are boxed with the `valueOf` method of the boxed class.
+macro_rules! jni_argument_type_trait {
+    ($type:ty, $promoted:ty, $boxed_class:expr, $value_of_signature:expr) => {
+        impl JniArgumentType for $type {
+            type Promoted = $promoted;
+
+            #[inline(always)]
+            fn promote(self) -> Self::Promoted {
+                self as $promoted
+            }
+
+            unsafe fn set_boxed_element<'a>(
+                self,
+                array: &ObjectArray<'a>,
+                token: &NoException<'a>,
+                index: usize,
+            ) -> JavaResult<'a, ()> {
+                let env = array.env();
+                let class = Class::find(env, token, $boxed_class)?;
+                let boxed = jni_methods::call_static_object_method(
+                    &class,
+                    token,
+                    "valueOf\0",
+                    $value_of_signature,
+                    (self,),
+                )?;
+                let boxed = boxed.map(|boxed| Object::from_raw(env, boxed));
+                array.set(token, index, boxed.as_ref())
+            }
+        }
+    };
+}
+
+jni_argument_type_trait!(
+    jni_sys::jboolean,
+    jni_sys::jint,
+    "java/lang/Boolean",
+    "(Z)Ljava/lang/Boolean;\0"
+);
+jni_argument_type_trait!(
+    jni_sys::jchar,
+    jni_sys::jint,
+    "java/lang/Character",
+    "(C)Ljava/lang/Character;\0"
+);
+jni_argument_type_trait!(
+    jni_sys::jbyte,
+    jni_sys::jint,
+    "java/lang/Byte",
+    "(B)Ljava/lang/Byte;\0"
+);
+jni_argument_type_trait!(
+    jni_sys::jshort,
+    jni_sys::jint,
+    "java/lang/Short",
+    "(S)Ljava/lang/Short;\0"
+);
+jni_argument_type_trait!(
+    jni_sys::jint,
+    jni_sys::jint,
+    "java/lang/Integer",
+    "(I)Ljava/lang/Integer;\0"
+);
+jni_argument_type_trait!(
+    jni_sys::jlong,
+    jni_sys::jlong,
+    "java/lang/Long",
+    "(J)Ljava/lang/Long;\0"
+);
+jni_argument_type_trait!(
+    jni_sys::jfloat,
+    jni_sys::jdouble,
+    "java/lang/Float",
+    "(F)Ljava/lang/Float;\0"
+);
+jni_argument_type_trait!(
+    jni_sys::jdouble,
+    jni_sys::jdouble,
+    "java/lang/Double",
+    "(D)Ljava/lang/Double;\0"
+);
+
+impl JniArgumentType for jni_sys::jobject {
+    type Promoted = jni_sys::jobject;
+
+    #[inline(always)]
+    fn promote(self) -> Self::Promoted {
+        self
+    }
+
+    #[inline(always)]
+    unsafe fn set_boxed_element<'a>(
+        self,
+        array: &ObjectArray<'a>,
+        token: &NoException<'a>,
+        index: usize,
+    ) -> JavaResult<'a, ()> {
+        array.set_raw(token, index, self)
+    }
+}
 
 // [`()`](https://doc.rust-lang.org/stable/std/primitive.unit.html)
 // can't be passed as an argument to a function.
 // impl !JniArgumentType for () {}
+
+#[cfg(test)]
+mod jni_type_tests {
+    use super::*;
+    use crate::env::JniEnv;
+    use crate::testing;
+    use crate::vm::JavaVMRef;
+    use core::mem;
+    use core::ptr::NonNull;
+
+    const OBJECT: jni_sys::jobject = 0x1234 as jni_sys::jobject;
+    const METHOD_ID: jni_sys::jmethodID = 0x5678 as jni_sys::jmethodID;
+
+    /// Generate a test checking that a JNI type calls the right `Call<Type>Method` and
+    /// `CallStatic<Type>Method` JNI functions and returns their result.
+    ///
+    /// The mock functions are not variadic, so the methods are called without arguments
+    /// to keep the calls ABI-compatible.
+    macro_rules! call_method_test {
+        ($name:ident, $type:ty, $value:expr, $method:ident, $static_method:ident) => {
+            #[test]
+            fn $name() {
+                unsafe extern "C" fn call_method(
+                    _env: *mut jni_sys::JNIEnv,
+                    object: jni_sys::jobject,
+                    method_id: jni_sys::jmethodID,
+                ) -> $type {
+                    if object == OBJECT && method_id == METHOD_ID {
+                        $value
+                    } else {
+                        <$type as JniType>::default()
+                    }
+                }
+
+                type Variadic = unsafe extern "C" fn(
+                    *mut jni_sys::JNIEnv,
+                    jni_sys::jobject,
+                    jni_sys::jmethodID,
+                    ...
+                ) -> $type;
+                type NonVariadic = unsafe extern "C" fn(
+                    *mut jni_sys::JNIEnv,
+                    jni_sys::jobject,
+                    jni_sys::jmethodID,
+                ) -> $type;
+
+                let raw_env = jni_sys::JNINativeInterface_ {
+                    // Safe because the functions are only called without variadic arguments.
+                    $method: Some(unsafe { mem::transmute::<NonVariadic, Variadic>(call_method) }),
+                    $static_method: Some(unsafe {
+                        mem::transmute::<NonVariadic, Variadic>(call_method)
+                    }),
+                    ..testing::empty_raw_jni_env()
+                };
+                let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+                let vm = JavaVMRef::test(0x9abc as *mut jni_sys::JavaVM);
+                let env = JniEnv::test(&vm, raw_env_ptr);
+                let object = unsafe { Object::from_raw(&env, NonNull::new(OBJECT).unwrap()) };
+                let class = unsafe { Class::from_raw(&env, NonNull::new(OBJECT).unwrap()) };
+                assert_eq!(
+                    unsafe { <$type as JniType>::call_method(&object, METHOD_ID, ()) },
+                    $value
+                );
+                assert_eq!(
+                    unsafe { <$type as JniType>::call_static_method(&class, METHOD_ID, ()) },
+                    $value
+                );
+                // `DeleteLocalRef` is not mocked.
+                mem::forget(object);
+                mem::forget(class);
+            }
+        };
+    }
+
+    call_method_test!(void, (), (), CallVoidMethod, CallStaticVoidMethod);
+    call_method_test!(
+        boolean,
+        jni_sys::jboolean,
+        jni_sys::JNI_TRUE,
+        CallBooleanMethod,
+        CallStaticBooleanMethod
+    );
+    call_method_test!(
+        char,
+        jni_sys::jchar,
+        0x1234,
+        CallCharMethod,
+        CallStaticCharMethod
+    );
+    call_method_test!(
+        byte,
+        jni_sys::jbyte,
+        -12,
+        CallByteMethod,
+        CallStaticByteMethod
+    );
+    call_method_test!(
+        short,
+        jni_sys::jshort,
+        -1234,
+        CallShortMethod,
+        CallStaticShortMethod
+    );
+    call_method_test!(
+        int,
+        jni_sys::jint,
+        123_456,
+        CallIntMethod,
+        CallStaticIntMethod
+    );
+    call_method_test!(
+        long,
+        jni_sys::jlong,
+        1 << 40,
+        CallLongMethod,
+        CallStaticLongMethod
+    );
+    call_method_test!(
+        float,
+        jni_sys::jfloat,
+        1.5,
+        CallFloatMethod,
+        CallStaticFloatMethod
+    );
+    call_method_test!(
+        double,
+        jni_sys::jdouble,
+        -2.25,
+        CallDoubleMethod,
+        CallStaticDoubleMethod
+    );
+}
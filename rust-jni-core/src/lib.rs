@@ -0,0 +1,250 @@
+//! # The runtime of `rust-jni`
+//!
+//! [`rust-jni-core`](index.html) provides the tokens, environments and calls that `rust-jni`
+//! uses to safely make calls from Rust to Java and from Java to Rust using
+//! [JNI](https://docs.oracle.com/javase/10/docs/specs/jni/index.html).
+//!
+//! See also [JNI documentation from Android](https://developer.android.com/training/articles/perf-jni).
+//!
+//! The main philosofy of this library is to push as many errors to compile-time as possible
+//! and panic whenever it's impossible to have a compile error.
+//!
+//! The core of the library (types, tokens and calls) only uses `core` and `alloc`.
+//! Parts that need the standard library, like the [`executor`](executor/index.html), are only
+//! available with the default `std` feature, without it the library is `#![no_std]`. Panics
+//! in native methods can't be caught without the standard library and are handled by the panic
+//! handler of the binary.
+//!
+//! This crate only contains the `java.lang` classes the runtime itself depends on. Bindings
+//! for the rest of the Java standard library live in `rust-jni-java`, and the `rust-jni`
+//! crate re-exports both under the same paths. Libraries that only implement native methods
+//! can depend on `rust-jni-core` directly to avoid compiling them.
+//!
+//! Commonly used types and traits can be imported at once from the
+//! [`prelude`](prelude/index.html).
+// TODO: a complete example.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+/// Define a raw JNI accessor that is only public without the `deny-unsafe-passthrough` feature.
+macro_rules! raw_passthrough {
+    ($(#[$attribute:meta])* unsafe fn $($definition:tt)*) => {
+        #[cfg(not(feature = "deny-unsafe-passthrough"))]
+        $(#[$attribute])*
+        pub unsafe fn $($definition)*
+
+        #[cfg(feature = "deny-unsafe-passthrough")]
+        #[allow(dead_code)]
+        $(#[$attribute])*
+        pub(crate) unsafe fn $($definition)*
+    };
+}
+
+#[cfg(any(test, feature = "testing"))]
+#[macro_use]
+pub mod testing;
+
+mod assertions;
+mod attach_arguments;
+mod attach_guard;
+#[cfg(all(not(test), not(feature = "no-invocation")))]
+mod backend;
+#[cfg(feature = "tracing")]
+mod call_span;
+mod class;
+#[cfg(feature = "std")]
+mod class_cache;
+mod classes;
+pub mod convert;
+pub mod debug;
+#[cfg(feature = "debug-names")]
+mod debug_name;
+mod env;
+mod error;
+#[cfg(feature = "std")]
+pub mod executor;
+mod extend_java_class;
+mod fatal_error;
+mod global_ref_drop;
+mod init_arguments;
+pub mod introspection;
+mod java_class;
+mod java_methods;
+mod java_primitives;
+mod java_string;
+mod java_version;
+mod jni_bool;
+mod jni_methods;
+mod jni_types;
+mod local_frame;
+mod monitor;
+#[cfg(feature = "std")]
+mod native_backtrace;
+mod native_method;
+mod native_registry;
+mod object;
+mod object_array;
+mod object_key;
+mod owned_object;
+#[cfg(feature = "std")]
+mod panic_hook;
+mod primitive_array;
+mod result;
+#[cfg(feature = "std")]
+mod scope;
+pub mod signature;
+mod signature_pool;
+#[cfg(feature = "stats")]
+mod stats;
+mod string;
+mod throwable;
+mod token;
+pub mod unchecked;
+mod version;
+mod virtual_thread;
+mod vm;
+
+#[doc(hidden)]
+pub use assertions::{__assert_java_eq, __downcast_exception};
+pub use attach_arguments::AttachArguments;
+pub use attach_guard::{attach_policy, set_attach_policy, AttachGuard, AttachPolicy};
+#[cfg(feature = "std")]
+pub use class_cache::{class_cache_enabled, clear_class_cache, set_class_cache_enabled};
+pub use classes::class_loader::{
+    context_class_loader_fallback_enabled, set_context_class_loader_fallback_enabled,
+};
+#[cfg(feature = "debug-names")]
+pub use debug_name::clear_debug_names;
+pub use env::JniEnv;
+pub use error::{
+    JniError, SignatureParseError, StringDecodingError, UnsupportedOnThisJvm, VmCreationError,
+};
+pub use fatal_error::{fatal_error_policy, set_fatal_error_policy, FatalErrorPolicy};
+pub use global_ref_drop::{
+    deferred_global_refs, flush_deferred_global_refs, global_ref_drop_policy, leaked_global_refs,
+    set_global_ref_drop_policy, GlobalRefDropPolicy,
+};
+pub use init_arguments::{
+    InitArguments, JvmAssertionsScope, JvmMemorySize, JvmOption, JvmVerboseOption,
+};
+pub use java_class::{JavaClassExt, JavaExceptionClass, NullableJavaClassExt};
+pub use java_methods::{
+    call_constructor, call_method, call_method_cached, call_method_catching, call_static_method,
+    null, FromObject, JniSignature, MethodIdCache,
+};
+pub use java_version::{jvm_feature_level, require_jvm_feature_level};
+pub use local_frame::LocalFrameResult;
+pub use monitor::MonitorGuard;
+#[cfg(feature = "std")]
+pub use native_backtrace::{native_backtraces_enabled, set_native_backtraces_enabled};
+pub use native_method::{native_method_implementation, static_native_method_implementation};
+pub use native_registry::{register_all_natives, NativeMethodRegistration, NativeRegistration};
+pub use object_array::{ObjectArray, ObjectArrayElement, ObjectArrayTuple};
+pub use object_key::ObjectKey;
+pub use owned_object::{Detachable, OwnedObject};
+#[cfg(feature = "std")]
+pub use panic_hook::{install_java_panic_hook, java_panic_context};
+pub use primitive_array::{
+    ArrayElementsGuard, ArrayElementsMutGuard, ArrayReleaseMode, JavaBooleanArray, JavaByteArray,
+    JavaCharArray, JavaDoubleArray, JavaFloatArray, JavaIntArray, JavaLongArray, JavaShortArray,
+    PrimitiveArrayElement,
+};
+pub use result::{JavaResult, JavaResultExt};
+#[cfg(feature = "std")]
+pub use scope::{scope, Scope, ScopedTask};
+#[cfg(feature = "stats")]
+pub use stats::{stats, Stats};
+pub use string::CharsChunks;
+pub use token::{ConsumedNoException, Exception, NoException};
+pub use version::JniVersion;
+pub use virtual_thread::is_virtual_thread;
+#[cfg(not(feature = "no-invocation"))]
+pub use vm::CreatedJavaVMs;
+pub use vm::{JavaVM, JavaVMRef};
+
+/// Items used by the code generated with `java_generate!`. Not a public API.
+#[doc(hidden)]
+pub mod __generator {
+    pub use crate::native_method::{
+        native_method_wrapper, to_native_result, NativeMethodArgument, ToJavaNativeResult,
+    };
+
+    // The generated code refers to `core` through this module, because `::std` isn't available
+    // in `no_std` crates and `::core` doesn't resolve in edition 2015 crates.
+    pub use core::{borrow, convert, ffi, fmt, marker, mem, ops, option, ptr, result};
+}
+
+/// Items used by the Java standard library bindings in `rust-jni-java`. Not a public API.
+#[doc(hidden)]
+pub mod __bindings {
+    pub use crate::java_methods::{JavaArgumentTuple, JavaMethodResult, JavaMethodSignature};
+    pub use crate::jni_types::private::JniArgumentTypeTuple;
+    pub use crate::object_array::new_exception;
+    pub use crate::token::CallOutcome;
+    use core::ptr::NonNull;
+
+    pub mod jni_bool {
+        pub use crate::jni_bool::*;
+    }
+
+    pub mod jni_methods {
+        pub use crate::jni_methods::*;
+    }
+
+    /// Get the raw JNI environment pointer, also with the `deny-unsafe-passthrough` feature.
+    #[inline(always)]
+    pub unsafe fn raw_env(env: &crate::JniEnv) -> NonNull<jni_sys::JNIEnv> {
+        env.raw_env()
+    }
+
+    /// Get the raw object pointer, also with the `deny-unsafe-passthrough` feature.
+    #[inline(always)]
+    pub unsafe fn raw_object(object: &crate::java::lang::Object) -> NonNull<jni_sys::_jobject> {
+        object.raw_object()
+    }
+}
+
+pub mod java {
+    pub mod lang {
+        //! Package java.lang.
+        //!
+        //! Provides classes that are fundamental to the design of the Java programming language.
+        //!
+        //! [`java.lang` javadoc](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/lang/package-summary.html)
+
+        pub use crate::class::Class;
+        pub use crate::classes::class_loader::ClassLoader;
+        pub use crate::classes::exception::Exception;
+        pub use crate::classes::null_pointer_exception::NullPointerException;
+        pub use crate::classes::runtime_exception::RuntimeException;
+        pub use crate::object::Object;
+        pub use crate::string::String;
+        pub use crate::throwable::Throwable;
+    }
+}
+
+pub mod prelude {
+    //! Commonly used types and traits.
+    //!
+    //! Import everything with `use rust_jni::prelude::*;` instead of listing each item:
+    //! ```
+    //! use rust_jni::prelude::*;
+    //!
+    //! fn describe<'a>(object: &Object<'a>, token: &NoException<'a>) -> JavaResult<'a, String> {
+    //!     let class_name = object.class(token).get_name(token)?;
+    //!     Ok(class_name.or_npe(object.env(), token)?.as_string(token))
+    //! }
+    //! ```
+    //!
+    //! [`java::lang::String`](../java/lang/struct.String.html) is not exported directly
+    //! so that it doesn't shadow the Rust `String`. Use it through the exported
+    //! [`java`](../java/index.html) module instead.
+
+    pub use crate::java;
+    pub use crate::java::lang::{Class, Object, Throwable};
+    pub use crate::{
+        AttachArguments, InitArguments, JavaClassExt, JavaResult, JavaResultExt, JavaVM, JniEnv,
+        NoException, NullableJavaClassExt,
+    };
+}
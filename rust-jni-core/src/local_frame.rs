@@ -0,0 +1,77 @@
+use crate::env::JniEnv;
+use crate::java_class::JavaClass;
+use crate::object::Object;
+use core::mem;
+use core::ptr::{self, NonNull};
+use jni_sys;
+
+include!("call_jni_method.rs");
+
+/// A type that can be returned from a local reference frame.
+///
+/// Implemented for all Java class wrappers, for
+/// [`Option`](https://doc.rust-lang.org/std/option/enum.Option.html)-s of them and for Java
+/// primitive types.
+///
+/// See [`JniEnv::with_local_frame`](struct.JniEnv.html#method.with_local_frame).
+pub trait LocalFrameResult<'a>: Sized {
+    /// Pop the current local reference frame, promoting the value to the previous frame.
+    ///
+    /// Unsafe because a local reference frame must have been pushed.
+    #[doc(hidden)]
+    unsafe fn __pop_local_frame(self, env: &'a JniEnv<'a>) -> Self;
+}
+
+#[inline(always)]
+unsafe fn pop_local_frame(env: &JniEnv, raw_result: jni_sys::jobject) -> jni_sys::jobject {
+    call_jni_method!(env, PopLocalFrame, raw_result)
+}
+
+impl<'a, T> LocalFrameResult<'a> for T
+where
+    T: JavaClass<'a>,
+{
+    #[inline(always)]
+    unsafe fn __pop_local_frame(self, env: &'a JniEnv<'a>) -> Self {
+        let raw_object = self.as_ref().raw_object().as_ptr();
+        // The reference is freed by popping the frame.
+        mem::forget(self);
+        #[cfg(feature = "stats")]
+        crate::stats::object_released();
+        let raw_object = pop_local_frame(env, raw_object);
+        // `PopLocalFrame` returns a new reference to the same non-null object.
+        T::from_object(Object::from_raw(env, NonNull::new_unchecked(raw_object)))
+    }
+}
+
+impl<'a, T> LocalFrameResult<'a> for Option<T>
+where
+    T: JavaClass<'a>,
+{
+    #[inline(always)]
+    unsafe fn __pop_local_frame(self, env: &'a JniEnv<'a>) -> Self {
+        match self {
+            Some(value) => Some(value.__pop_local_frame(env)),
+            None => {
+                pop_local_frame(env, ptr::null_mut());
+                None
+            }
+        }
+    }
+}
+
+macro_rules! local_frame_result_primitive_impls {
+    ($($type:ty),*) => (
+        $(
+            impl<'a> LocalFrameResult<'a> for $type {
+                #[inline(always)]
+                unsafe fn __pop_local_frame(self, env: &'a JniEnv<'a>) -> Self {
+                    pop_local_frame(env, ptr::null_mut());
+                    self
+                }
+            }
+        )*
+    );
+}
+
+local_frame_result_primitive_impls!((), bool, char, u8, i16, i32, i64, f32, f64);
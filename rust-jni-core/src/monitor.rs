@@ -0,0 +1,131 @@
+use crate::object::Object;
+use crate::result::JavaResult;
+use crate::token::{CallOutcome, NoException};
+use jni_sys;
+
+include!("call_jni_method.rs");
+
+/// A guard that holds the monitor of a Java object, like a Java `synchronized` block.
+///
+/// The monitor is entered when the guard is created and exited when the guard is
+/// [`drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html#tymethod.drop)-ed.
+/// Java monitors are reentrant, so the same thread can hold multiple guards for the same object.
+///
+/// On a Java [virtual thread](fn.is_virtual_thread.html) holding the guard pins the virtual
+/// thread to it's carrier thread, so guards should be held briefly there.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#monitor-operations)
+///
+/// # Example
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::Object;
+/// #
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let object = Object::new(env, &token)?;
+/// {
+///     let guard = MonitorGuard::new(&object, &token)?;
+///     guard.notify(&token)?;
+/// }
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MonitorGuard<'a, 'env> {
+    object: &'a Object<'env>,
+}
+
+impl<'a, 'env> MonitorGuard<'a, 'env> {
+    /// Enter the monitor of a Java object.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#monitorenter)
+    pub fn new(
+        object: &'a impl AsRef<Object<'env>>,
+        token: &NoException<'env>,
+    ) -> JavaResult<'env, Self> {
+        let object = object.as_ref();
+        let env = object.env();
+        // Safe because arguments are ensured to be the correct by construction.
+        let status = token.with_owned(
+            env,
+            #[inline(always)]
+            |_token| unsafe {
+                CallOutcome::Unknown(call_jni_method!(
+                    env,
+                    MonitorEnter,
+                    object.raw_object().as_ptr()
+                ))
+            },
+        )?;
+        if status != jni_sys::JNI_OK {
+            panic!(
+                "MonitorEnter failed with status {} without throwing an exception.",
+                status
+            );
+        }
+        Ok(Self { object })
+    }
+
+    /// Wait until the object is notified or the thread is interrupted.
+    ///
+    /// The monitor is released while waiting and reacquired before returning.
+    ///
+    /// [`Object::wait` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#wait())
+    pub fn wait(&self, token: &NoException<'env>) -> JavaResult<'env, ()> {
+        self.object.wait(token)
+    }
+
+    /// Wait until the object is notified, the thread is interrupted or `timeout_ms`
+    /// milliseconds pass. Waits without a timeout if `timeout_ms` is `0`.
+    ///
+    /// The monitor is released while waiting and reacquired before returning.
+    ///
+    /// [`Object::wait` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#wait(long))
+    pub fn wait_timeout(&self, token: &NoException<'env>, timeout_ms: i64) -> JavaResult<'env, ()> {
+        self.object.wait_timeout(token, timeout_ms)
+    }
+
+    /// Wake up a single thread waiting on the object.
+    ///
+    /// [`Object::notify` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#notify())
+    pub fn notify(&self, token: &NoException<'env>) -> JavaResult<'env, ()> {
+        self.object.notify(token)
+    }
+
+    /// Wake up all threads waiting on the object.
+    ///
+    /// [`Object::notifyAll` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#notifyAll())
+    pub fn notify_all(&self, token: &NoException<'env>) -> JavaResult<'env, ()> {
+        self.object.notify_all(token)
+    }
+}
+
+/// Exit the monitor when the [`MonitorGuard`](struct.MonitorGuard.html) is
+/// [`drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html#tymethod.drop)-ed.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#monitorexit)
+impl Drop for MonitorGuard<'_, '_> {
+    fn drop(&mut self) {
+        // Safe because `MonitorExit` can be called with a pending exception and
+        // the argument is ensured to be a correct reference by construction.
+        // `MonitorExit` can't fail because the current thread owns the monitor.
+        unsafe {
+            call_jni_method!(
+                self.object.env(),
+                MonitorExit,
+                self.object.raw_object().as_ptr()
+            );
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Capturing Rust backtraces of panics in native methods.
+//!
+//! Only compiled with the `std` feature.
+
+use core::cell::{Cell, RefCell};
+use core::sync::atomic::{AtomicBool, Ordering};
+use std::backtrace::Backtrace;
+use std::panic;
+
+static NATIVE_BACKTRACES_ENABLED: AtomicBool = AtomicBool::new(false);
+static BACKTRACE_HOOK_INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// Number of native methods implemented in Rust the current thread is in.
+    static NATIVE_DEPTH: Cell<usize> = const { Cell::new(0) };
+    /// Backtrace of the last panic in a native method on the current thread.
+    static PANIC_BACKTRACE: RefCell<Option<Backtrace>> = const { RefCell::new(None) };
+}
+
+/// Marks the current thread as running a native method until dropped.
+pub(crate) struct NativeGuard;
+
+impl Drop for NativeGuard {
+    fn drop(&mut self) {
+        let _ = NATIVE_DEPTH.try_with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+/// Mark the current thread as running a native method until the returned guard is dropped.
+#[inline(always)]
+pub(crate) fn enter_native() -> NativeGuard {
+    NATIVE_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    NativeGuard
+}
+
+/// Take the backtrace of the last panic in a native method on the current thread, if any.
+pub(crate) fn take_panic_backtrace() -> Option<Backtrace> {
+    PANIC_BACKTRACE
+        .try_with(|backtrace| backtrace.borrow_mut().take())
+        .ok()?
+}
+
+/// Enable or disable capturing Rust backtraces of panics in native methods.
+///
+/// When enabled, the `java.lang.RuntimeException` thrown to Java by
+/// [`native_method_implementation`](fn.native_method_implementation.html) and
+/// [`static_native_method_implementation`](fn.static_native_method_implementation.html)
+/// when the native method panics has the Rust backtrace of the panic appended to its message,
+/// so that native failures can be debugged from the Java side.
+///
+/// Backtraces are captured by a panic hook installed the first time capturing is enabled.
+/// The hook calls the previous panic hook first. Capturing doesn't depend on the
+/// `RUST_BACKTRACE` environment variable.
+///
+/// Errors returned from native methods are Java exceptions already and are thrown as is.
+///
+/// Capturing is disabled by default. Only available with the `std` feature.
+pub fn set_native_backtraces_enabled(enabled: bool) {
+    NATIVE_BACKTRACES_ENABLED.store(enabled, Ordering::SeqCst);
+    if enabled && !BACKTRACE_HOOK_INSTALLED.swap(true, Ordering::SeqCst) {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            previous_hook(info);
+            if native_backtraces_enabled()
+                && NATIVE_DEPTH.try_with(|depth| depth.get() > 0) == Ok(true)
+            {
+                let _ = PANIC_BACKTRACE.try_with(|backtrace| {
+                    *backtrace.borrow_mut() = Some(Backtrace::force_capture())
+                });
+            }
+        }));
+    }
+}
+
+/// Check if capturing Rust backtraces of panics in native methods is enabled.
+///
+/// Only available with the `std` feature.
+pub fn native_backtraces_enabled() -> bool {
+    NATIVE_BACKTRACES_ENABLED.load(Ordering::SeqCst)
+}
@@ -1,7 +1,7 @@
 use crate::class::Class;
 use crate::env::JniEnv;
 use crate::error::JniError;
-use crate::java_class::JavaClass;
+use crate::java_class::{JavaClass, NullableJavaClassExt};
 use crate::java_methods::FromObject;
 use crate::java_methods::JavaArgumentType;
 use crate::java_string::to_java_string_null_terminated;
@@ -12,11 +12,18 @@ use crate::object::Object;
 use crate::result::JavaResult;
 use crate::token::NoException;
 use crate::vm::JavaVMRef;
+use alloc::alloc::{dealloc, Layout};
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::format;
+#[cfg(not(feature = "std"))]
+use core::any::Any;
+use core::mem;
+use core::panic;
+use core::ptr::{self, NonNull};
 use jni_sys;
-use std::alloc;
-use std::mem;
-use std::panic;
-use std::ptr::{self, NonNull};
+#[cfg(feature = "std")]
+use std::panic::catch_unwind;
 
 /// A trait representing types that can be returned from a native Java method wrapper.
 ///
@@ -77,7 +84,11 @@ where
         // on HKTB-ed closure type in native method wrappers below.
         // TODO(monnoroch): clean this up once TODOs below are resolved.
         NonNull::new(value).map(|value| {
-            <T as FromObject>::from_object(extend_lifetime_object(Object::from_raw(env, value)))
+            let object = Object::from_raw(env, value);
+            // Native method arguments are borrowed from Java and are never dropped.
+            #[cfg(feature = "stats")]
+            crate::stats::object_released();
+            <T as FromObject>::from_object(extend_lifetime_object(object))
         })
     }
 }
@@ -308,15 +319,40 @@ java_argument_type_impls! {
 /// );
 /// assert_eq!(string, ptr::null_mut());
 ///
-/// let raw_env = env.raw_env().as_ptr();
-/// let jni_fn = ((**raw_env).ExceptionOccurred).unwrap();
-/// let throwable = jni_fn(raw_env);
-/// assert_ne!(throwable, ptr::null_mut());
+/// assert!(env.take_pending_exception().is_some());
 /// # }
 /// # Ok(token)
 /// # }
 /// ```
 ///
+/// The callback gets a [`NoException`](struct.NoException.html) token with a lifetime of it's own,
+/// so that tokens of other native frames can't be used with the objects of this one.
+/// The callback can't borrow anything from the calling code, including it's tokens:
+/// ```compile_fail
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::String;
+/// # use jni_sys;
+/// #
+/// # unsafe fn native_method<'a>(
+/// #     env: &'a JniEnv<'a>,
+/// #     token: &NoException<'a>,
+/// #     raw_env: *mut jni_sys::JNIEnv,
+/// #     raw_class: jni_sys::jclass,
+/// # ) {
+/// static_native_method_implementation::<(), (), _>(
+///     raw_env,
+///     raw_class,
+///     (),
+///     |_, inner_token, _| {
+///         let _ = String::empty(env, token); // doesn't compile!
+///         (Ok(Box::new(())), inner_token)
+///     },
+/// );
+/// # }
+/// #
+/// # fn main() {}
+/// ```
+///
 /// This function is unsafe because it is possible to pass an invalid [`JNIEnv`](../jni_sys/type.JNIEnv.html)
 /// pointer or an invalid [`jclass`](../jni_sys/type.jclass.html).
 pub unsafe fn static_native_method_implementation<A, R, F>(
@@ -334,7 +370,8 @@ where
         JavaResult<'a, Box<dyn ToJavaNativeResult<JniType = R::JniType> + 'a>>,
         NoException<'a>,
     ),
-    F: panic::UnwindSafe,
+    // The callback can't capture tokens of an outer native frame.
+    F: panic::UnwindSafe + 'static,
     // TODO(monnoroch): this should be + 'a for the 'a in the HKTB above.
     A: ToJavaNativeArgumentTuple,
     A::JniType: panic::UnwindSafe,
@@ -344,15 +381,16 @@ where
         raw_env,
         raw_arguments,
         |env, token, arguments| {
+            // We don't own the references, so they must not be dropped, even when unwinding.
             // Should not panic if the class pointer is valid.
-            let class = Class::from_raw(env, NonNull::new(raw_class).unwrap());
-            let arguments = <A as ToJavaNativeArgumentTuple>::from_raw(env, arguments);
-            let (result, token) = callback(&class, token, &arguments);
+            let class =
+                mem::ManuallyDrop::new(Class::from_raw(env, NonNull::new(raw_class).unwrap()));
+            let arguments =
+                mem::ManuallyDrop::new(<A as ToJavaNativeArgumentTuple>::from_raw(env, arguments));
+            let (result, token) = callback(&class, token.narrow(), &arguments);
             let java_result = to_jni_type::<R>(result, token);
-            // We don't own the reference.
-            mem::forget(arguments);
-            // We don't own the reference.
-            mem::forget(class);
+            #[cfg(feature = "stats")]
+            crate::stats::object_released();
             java_result
         },
     )
@@ -469,15 +507,40 @@ where
 /// );
 /// assert_eq!(equals, jni_sys::JNI_FALSE);
 ///
-/// let raw_env = env.raw_env().as_ptr();
-/// let jni_fn = ((**raw_env).ExceptionOccurred).unwrap();
-/// let throwable = jni_fn(raw_env);
-/// assert_ne!(throwable, ptr::null_mut());
+/// assert!(env.take_pending_exception().is_some());
 /// # }
 /// # Ok(token)
 /// # }
 /// ```
 ///
+/// The callback gets a [`NoException`](struct.NoException.html) token with a lifetime of it's own,
+/// so that tokens of other native frames can't be used with the objects of this one.
+/// The callback can't borrow anything from the calling code, including it's tokens:
+/// ```compile_fail
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::String;
+/// # use jni_sys;
+/// #
+/// # unsafe fn native_method<'a>(
+/// #     env: &'a JniEnv<'a>,
+/// #     token: &NoException<'a>,
+/// #     raw_env: *mut jni_sys::JNIEnv,
+/// #     raw_object: jni_sys::jobject,
+/// # ) {
+/// native_method_implementation::<(), (), _>(
+///     raw_env,
+///     raw_object,
+///     (),
+///     |_, inner_token, _| {
+///         let _ = String::empty(env, token); // doesn't compile!
+///         (Ok(Box::new(())), inner_token)
+///     },
+/// );
+/// # }
+/// #
+/// # fn main() {}
+/// ```
+///
 /// This function is unsafe because it is possible to pass an invalid [`JNIEnv`](../jni_sys/type.JNIEnv.html)
 /// pointer or an invalid [`jobject`](../jni_sys/type.jobject.html).
 pub unsafe fn native_method_implementation<A, R, F>(
@@ -495,7 +558,8 @@ where
         JavaResult<'a, Box<dyn ToJavaNativeResult<JniType = R::JniType> + 'a>>,
         NoException<'a>,
     ),
-    F: panic::UnwindSafe,
+    // The callback can't capture tokens of an outer native frame.
+    F: panic::UnwindSafe + 'static,
     // TODO(monnoroch): this should be + 'a for the 'a in the HKTB above.
     A: ToJavaNativeArgumentTuple,
     A::JniType: panic::UnwindSafe,
@@ -505,20 +569,142 @@ where
         raw_env,
         raw_arguments,
         |env, token, arguments| {
+            // We don't own the references, so they must not be dropped, even when unwinding.
             // Should not panic if the object pointer is valid.
-            let object = Object::from_raw(env, NonNull::new(raw_object).unwrap());
-            let arguments = <A as ToJavaNativeArgumentTuple>::from_raw(env, arguments);
-            let (result, token) = callback(&object, token, &arguments);
+            let object =
+                mem::ManuallyDrop::new(Object::from_raw(env, NonNull::new(raw_object).unwrap()));
+            let arguments =
+                mem::ManuallyDrop::new(<A as ToJavaNativeArgumentTuple>::from_raw(env, arguments));
+            let (result, token) = callback(&object, token.narrow(), &arguments);
             let java_result = to_jni_type::<R>(result, token);
-            // We don't own the reference.
-            mem::forget(arguments);
-            // We don't own the reference.
-            mem::forget(object);
+            #[cfg(feature = "stats")]
+            crate::stats::object_released();
             java_result
         },
     )
 }
 
+/// Implementation of a native Java method generated with `java_generate!`.
+///
+/// THIS FUNCTION SHOULD NOT BE CALLED MANUALLY.
+///
+/// The callback converts the arguments with [`NativeMethodArgument`](trait.NativeMethodArgument.html)
+/// and the result with [`to_native_result`](fn.to_native_result.html). An exception returned
+/// from the callback is thrown to Java.
+///
+/// This function is unsafe because it is possible to pass an invalid [`JNIEnv`](../jni_sys/type.JNIEnv.html)
+/// pointer.
+#[doc(hidden)]
+pub unsafe fn native_method_wrapper<R, F>(raw_env: *mut jni_sys::JNIEnv, callback: F) -> R
+where
+    for<'a> F: FnOnce(&'a JniEnv<'a>, &NoException<'a>) -> JavaResult<'a, R>,
+    F: panic::UnwindSafe,
+    R: JniType,
+{
+    generic_native_method_implementation::<R, (), _>(raw_env, (), |env, token, ()| {
+        match callback(env, &token) {
+            Ok(result) => result,
+            Err(exception) => {
+                let _ = exception.throw(token);
+                R::default()
+            }
+        }
+    })
+}
+
+/// A type of a native method argument in code generated with `java_generate!`: a Java
+/// primitive type or a Java class wrapper.
+///
+/// The argument is owned by the native method. Passing `null` as an argument of a class type
+/// throws a [`NullPointerException`](java/lang/struct.NullPointerException.html), unless the
+/// argument is an [`Option`](https://doc.rust-lang.org/std/option/enum.Option.html).
+#[doc(hidden)]
+pub trait NativeMethodArgument<'a>: Sized {
+    type JniType: JniArgumentType;
+
+    /// Unsafe because it's possible to pass a value of a different type.
+    unsafe fn from_jni(
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+        value: Self::JniType,
+    ) -> JavaResult<'a, Self>;
+}
+
+impl<'a, T> NativeMethodArgument<'a> for T
+where
+    T: JavaClass<'a>,
+{
+    type JniType = jni_sys::jobject;
+
+    #[inline(always)]
+    unsafe fn from_jni(
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+        value: Self::JniType,
+    ) -> JavaResult<'a, Self> {
+        <Option<T> as NativeMethodArgument<'a>>::from_jni(env, token, value)?.or_npe(env, token)
+    }
+}
+
+impl<'a, T> NativeMethodArgument<'a> for Option<T>
+where
+    T: JavaClass<'a>,
+{
+    type JniType = jni_sys::jobject;
+
+    #[inline(always)]
+    unsafe fn from_jni(
+        env: &'a JniEnv<'a>,
+        _token: &NoException<'a>,
+        value: Self::JniType,
+    ) -> JavaResult<'a, Self> {
+        Ok(NonNull::new(value).map(|value| T::from_object(Object::from_raw(env, value))))
+    }
+}
+
+macro_rules! native_method_argument_primitive_impls {
+    ($($type:ty),*) => (
+        $(
+            impl<'a> NativeMethodArgument<'a> for $type {
+                type JniType = <Self as ToJavaNativeArgument>::JniType;
+
+                #[inline(always)]
+                unsafe fn from_jni(
+                    env: &'a JniEnv<'a>,
+                    _token: &NoException<'a>,
+                    value: Self::JniType,
+                ) -> JavaResult<'a, Self> {
+                    Ok(<Self as ToJavaNativeArgument>::from_raw(env, value))
+                }
+            }
+        )*
+    );
+}
+
+native_method_argument_primitive_impls!(bool, char, u8, i16, i32, i64, f32, f64);
+
+/// Convert the result of a native method generated with `java_generate!` to its JNI value.
+///
+/// THIS FUNCTION SHOULD NOT BE CALLED MANUALLY.
+///
+/// The reference of a returned object is passed to Java.
+#[doc(hidden)]
+#[inline(always)]
+pub fn to_native_result<R>(value: R) -> R::JniType
+where
+    R: ToJavaNativeResult,
+{
+    let result = value.to_java_native_result();
+    mem::forget(value);
+    #[cfg(feature = "stats")]
+    {
+        if result.is_reference() {
+            crate::stats::object_released();
+        }
+    }
+    result
+}
+
 fn to_jni_type<'a, R>(
     result: JavaResult<'a, Box<dyn ToJavaNativeResult<JniType = R::JniType> + 'a>>,
     token: NoException<'a>,
@@ -528,7 +714,6 @@ where
 {
     match result {
         Ok(result) => {
-            mem::forget(token);
             let java_result = result.to_java_native_result();
             // Here we want to free memory of the Box, but don't want to run the destructor of the boxed value.
             // Running the destructor for primitive types won't do anything, but running the destructor
@@ -536,7 +721,14 @@ where
             // Here we could use mem::forget(result), but that would leak the Box-es memory, which we don't want.
             let result = Box::into_raw(result);
             // Safe because we just took ownership of this memory.
-            unsafe { alloc::dealloc(result as *mut u8, alloc::Layout::for_value(&*result)) };
+            unsafe { dealloc(result as *mut u8, Layout::for_value(&*result)) };
+            // The reference of a returned object is passed to Java.
+            #[cfg(feature = "stats")]
+            {
+                if java_result.is_reference() {
+                    crate::stats::object_released();
+                }
+            }
             java_result
         }
         #[cold]
@@ -548,7 +740,7 @@ where
 }
 
 unsafe fn extend_lifetime_object<'b>(r: Object<'b>) -> Object<'static> {
-    std::mem::transmute::<Object<'b>, Object<'static>>(r)
+    core::mem::transmute::<Object<'b>, Object<'static>>(r)
 }
 
 /// This function is unsafe because it is possible to pass an invalid [`JNIEnv`](../jni_sys/type.JNIEnv.html)
@@ -563,7 +755,7 @@ where
     R: JniType,
     A: JniArgumentTypeTuple + panic::UnwindSafe,
 {
-    let result = panic::catch_unwind(|| {
+    let result = catch_unwind(|| {
         let mut java_vm: *mut jni_sys::JavaVM = ptr::null_mut();
         let get_java_vm_fn = ((**raw_env).GetJavaVM).unwrap();
         let error = JniError::from_raw(get_java_vm_fn(
@@ -572,10 +764,7 @@ where
         ));
         if error.is_some() {
             #[cold]
-            panic!(format!(
-                "Could not get Java VM. Status: {:?}",
-                error.unwrap()
-            ));
+            panic!("Could not get Java VM. Status: {:?}", error.unwrap());
         }
 
         // Safe because we pass a valid `java_vm` pointer.
@@ -585,41 +774,49 @@ where
         // Safe because we pass a valid `raw_env` pointer.
         // Will not panic because JNI guarantees that pointers are not null.
         #[allow(unused_unsafe)]
-        let env = unsafe { JniEnv::native(&vm, NonNull::new(raw_env).unwrap()) };
+        // We don't own the reference, so the env must not be dropped, even when unwinding.
+        let env =
+            mem::ManuallyDrop::new(unsafe { JniEnv::native(&vm, NonNull::new(raw_env).unwrap()) });
+        #[cfg(feature = "std")]
+        let _native = crate::native_backtrace::enter_native();
         let token = env.token();
-        let result = callback(&env, token, arguments);
-        // We don't own the reference.
-        mem::forget(env);
-        result
+        callback(&env, token, arguments)
     });
     match result {
         Ok(result) => result,
         #[cold]
         Err(error) => {
-            if let Some(string) = error.downcast_ref::<std::string::String>() {
-                // Safe because we pass a correct `raw_env` pointer.
-                #[allow(unused_unsafe)]
-                unsafe {
-                    throw_new_runtime_exception(raw_env, format!("Rust panic: {}\0", string))
-                };
+            let message = if let Some(string) = error.downcast_ref::<alloc::string::String>() {
+                format!("Rust panic: {}", string)
             } else if let Some(string) = error.downcast_ref::<&str>() {
-                // Safe because we pass a correct `raw_env` pointer.
-                #[allow(unused_unsafe)]
-                unsafe {
-                    throw_new_runtime_exception(raw_env, format!("Rust panic: {}\0", string))
-                };
+                format!("Rust panic: {}", string)
             } else {
-                // Safe because we pass a correct `raw_env` pointer.
-                #[allow(unused_unsafe)]
-                unsafe {
-                    throw_new_runtime_exception(raw_env, "Rust panic: generic panic.\0")
-                };
-            }
+                "Rust panic: generic panic.".to_owned()
+            };
+            #[cfg(feature = "std")]
+            let message = match crate::native_backtrace::take_panic_backtrace() {
+                Some(backtrace) => format!("{}\n\nRust backtrace:\n{}", message, backtrace),
+                None => message,
+            };
+            // Safe because we pass a correct `raw_env` pointer.
+            #[allow(unused_unsafe)]
+            unsafe {
+                throw_new_runtime_exception(raw_env, format!("{}\0", message))
+            };
             R::default()
         }
     }
 }
 
+/// Panics can't be caught without the standard library, so they are handled by the panic
+/// handler of the binary, which usually aborts.
+#[cfg(not(feature = "std"))]
+fn catch_unwind<R>(
+    function: impl FnOnce() -> R + panic::UnwindSafe,
+) -> Result<R, Box<dyn Any + Send>> {
+    Ok(function())
+}
+
 /// Unsafe because an incorrect pointer can be passed as an argument.
 unsafe fn throw_new_runtime_exception(raw_env: *mut jni_sys::JNIEnv, message: impl AsRef<str>) {
     let message = to_java_string_null_terminated(message.as_ref());
@@ -0,0 +1,175 @@
+//! A registry of native method implementations declared with the `#[register_native]`
+//! attribute from the `rust-jni-generator` crate.
+
+use crate::class::Class;
+use crate::env::JniEnv;
+use crate::object_key::ObjectKey;
+use crate::result::JavaResult;
+use crate::token::{CallOutcome, NoException};
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ffi::{c_char, c_void};
+use jni_sys;
+
+include!("call_jni_method.rs");
+
+/// A native method implementation. Submitted by the code generated by `#[register_native]`.
+#[doc(hidden)]
+pub struct NativeMethodRegistration {
+    class: &'static str,
+    name: &'static str,
+    signature: &'static str,
+    function: *mut c_void,
+}
+
+/// Registrations are immutable and only hold pointers to functions.
+unsafe impl Sync for NativeMethodRegistration {}
+
+impl NativeMethodRegistration {
+    /// Create a registration of a native method.
+    ///
+    /// The class name is a JNI class name, like `a/b/Class`. The method name and signature
+    /// *must* be null-terminated.
+    #[doc(hidden)]
+    pub const fn new(
+        class: &'static str,
+        name: &'static str,
+        signature: &'static str,
+        function: *mut c_void,
+    ) -> Self {
+        Self {
+            class,
+            name,
+            signature,
+            function,
+        }
+    }
+}
+
+inventory::collect!(NativeMethodRegistration);
+
+/// Register all native methods declared with the `#[register_native]` attribute in the program.
+///
+/// Methods are grouped by class, so `RegisterNatives` is called once per class. Returns
+/// the exception thrown by `FindClass` or `RegisterNatives`, for example a
+/// [`NoSuchMethodError`](https://docs.oracle.com/javase/10/docs/api/java/lang/NoSuchMethodError.html)
+/// if the class doesn't declare a native method with the registered name and signature.
+/// Classes registered before the error keep their natives.
+///
+/// Use [`NativeRegistration`](struct.NativeRegistration.html) to unregister the natives later.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#registernatives)
+pub fn register_all_natives<'a>(
+    env: &'a JniEnv<'a>,
+    token: &NoException<'a>,
+) -> JavaResult<'a, ()> {
+    register_natives(env, token, |_class| Ok(()))
+}
+
+/// Register all native methods and call `registered` for every class.
+fn register_natives<'a>(
+    env: &'a JniEnv<'a>,
+    token: &NoException<'a>,
+    mut registered: impl FnMut(&Class<'a>) -> JavaResult<'a, ()>,
+) -> JavaResult<'a, ()> {
+    let mut classes = BTreeMap::<&'static str, Vec<jni_sys::JNINativeMethod>>::new();
+    for registration in inventory::iter::<NativeMethodRegistration> {
+        classes
+            .entry(registration.class)
+            .or_default()
+            .push(jni_sys::JNINativeMethod {
+                name: registration.name.as_ptr() as *mut c_char,
+                signature: registration.signature.as_ptr() as *mut c_char,
+                fnPtr: registration.function,
+            });
+    }
+    for (class_name, methods) in classes {
+        let class = Class::find(env, token, class_name)?;
+        // Safe because the arguments are correct and the names and signatures of methods are
+        // null-terminated strings that live for the duration of the program.
+        // `RegisterNatives` throws an exception when it fails.
+        let status = token.with_owned(
+            env,
+            #[inline(always)]
+            |_token| unsafe {
+                CallOutcome::Unknown(call_jni_method!(
+                    env,
+                    RegisterNatives,
+                    class.raw_object().as_ptr(),
+                    methods.as_ptr(),
+                    methods.len() as jni_sys::jint
+                ))
+            },
+        )?;
+        if status != jni_sys::JNI_OK {
+            panic!(
+                "RegisterNatives failed with status {} without throwing an exception.",
+                status
+            );
+        }
+        registered(&class)?;
+    }
+    Ok(())
+}
+
+/// A guard for native methods registered with the `#[register_native]` attribute that
+/// unregisters them when
+/// [`drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html#tymethod.drop)-ed.
+///
+/// Native methods keep pointing to the functions of the library that registered them. Dropping
+/// the guard before unloading the library allows to load a new version of it and register
+/// the natives again during development without the Java VM calling stale function pointers.
+///
+/// Like [`ObjectKey`](struct.ObjectKey.html), the guard holds global references to
+/// the classes and is not bound to a [`JniEnv`](struct.JniEnv.html).
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#unregisternatives)
+#[derive(Debug)]
+#[must_use = "native methods are unregistered when the guard is dropped"]
+pub struct NativeRegistration {
+    classes: Vec<ObjectKey>,
+}
+
+impl NativeRegistration {
+    /// Register all native methods declared with the `#[register_native]` attribute in
+    /// the program.
+    ///
+    /// Same as [`register_all_natives`](fn.register_all_natives.html), but natives of
+    /// the classes registered before an error are unregistered.
+    pub fn register_all<'a>(env: &'a JniEnv<'a>, token: &NoException<'a>) -> JavaResult<'a, Self> {
+        let mut registration = NativeRegistration { classes: vec![] };
+        register_natives(env, token, |class| {
+            registration.classes.push(ObjectKey::new(class, token)?);
+            Ok(())
+        })?;
+        Ok(registration)
+    }
+}
+
+/// Unregister the native methods when the [`NativeRegistration`](struct.NativeRegistration.html)
+/// is [`drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html#tymethod.drop)-ed.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#unregisternatives)
+impl Drop for NativeRegistration {
+    fn drop(&mut self) {
+        for class in &self.classes {
+            // Safe because the argument is ensured to be a correct class reference by
+            // construction. A pending exception is set aside for the duration of the call, as
+            // `UnregisterNatives` can't be called with a pending exception.
+            unsafe {
+                class.vm().with_current_env(|env| {
+                    let exception = call_jni_method!(env, ExceptionOccurred);
+                    if !exception.is_null() {
+                        call_jni_method!(env, ExceptionClear);
+                    }
+                    call_jni_method!(env, UnregisterNatives, class.raw_object().as_ptr());
+                    if !exception.is_null() {
+                        call_jni_method!(env, Throw, exception);
+                        call_jni_method!(env, DeleteLocalRef, exception);
+                    }
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,666 @@
+use crate::class::Class;
+use crate::env::JniEnv;
+use crate::java_methods::FromObject;
+use crate::java_methods::JniSignature;
+use crate::java_methods::{call_constructor, call_method};
+use crate::jni_bool;
+use crate::jni_methods;
+use crate::result::JavaResult;
+use crate::string::String;
+use crate::token::{CallOutcome, NoException};
+use core::fmt;
+use core::mem;
+use core::ptr::{self, NonNull};
+use core::sync::atomic::{AtomicPtr, Ordering};
+use jni_sys;
+
+include!("call_jni_method.rs");
+
+/// A type representing the
+/// [`java.lang.Object`](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html) class
+/// -- the root class of Java's class hierarchy.
+///
+/// [`Object` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html)
+// TODO: examples.
+pub struct Object<'env> {
+    env: &'env JniEnv<'env>,
+    raw_object: NonNull<jni_sys::_jobject>,
+}
+
+// [`Object`](struct.Object.html) can't be passed between threads.
+// TODO(https://github.com/rust-lang/rust/issues/13231): enable when !Send is stable.
+// impl<'env> !Send for Object<'env> {}
+// impl<'env> !Sync for Object<'env> {}
+
+impl<'env> Object<'env> {
+    raw_passthrough! {
+        /// Get the raw object pointer.
+        ///
+        /// This function provides low-level access to the Java object and thus is unsafe.
+        ///
+        /// Not public with the `deny-unsafe-passthrough` feature, use
+        /// [`with_raw_object`](#method.with_raw_object) instead.
+        #[inline(always)]
+        unsafe fn raw_object(&self) -> NonNull<jni_sys::_jobject> {
+            self.raw_object
+        }
+    }
+
+    raw_passthrough! {
+        /// Get the raw object pointer with ownership transfer.
+        ///
+        /// The caller is responsible for managing the Java object's lifecycle ofter calling this.
+        ///
+        /// This function provides low-level access to the Java object and thus is unsafe.
+        ///
+        /// Not public with the `deny-unsafe-passthrough` feature.
+        #[inline(always)]
+        unsafe fn take_raw_object(value: impl Into<Object<'env>>) -> NonNull<jni_sys::_jobject> {
+            let value = value.into();
+            let result = value.raw_object();
+            mem::forget(value);
+            #[cfg(feature = "stats")]
+            crate::stats::object_released();
+            result
+        }
+    }
+
+    /// Call `callback` with the raw object pointer.
+    ///
+    /// Unlike [`raw_object`](#method.raw_object), this is available with the
+    /// `deny-unsafe-passthrough` feature, as it can only be called when there is no pending
+    /// exception. The pointer must not be used after `callback` returns.
+    ///
+    /// This function provides low-level access to the Java object and thus is unsafe.
+    #[inline(always)]
+    pub unsafe fn with_raw_object<R>(
+        &self,
+        _token: &NoException<'env>,
+        callback: impl FnOnce(NonNull<jni_sys::_jobject>) -> R,
+    ) -> R {
+        callback(self.raw_object)
+    }
+
+    /// Get the [`JniEnv`](../../struct.JniEnv.html) this object is bound to.
+    #[inline(always)]
+    pub fn env(&self) -> &'env JniEnv<'env> {
+        self.env
+    }
+
+    /// Get the object's class.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getobjectclass)
+    pub fn class(&self, _token: &NoException) -> Class<'env> {
+        // Safe because arguments are ensured to be correct references by construction.
+        let raw_java_class = unsafe { call_jni_object_method!(self, GetObjectClass) };
+        NonNull::new(raw_java_class)
+            .map(|raw_java_class| {
+                // Safe because arguments are ensured to be correct references by construction.
+                unsafe { Class::from_raw(self.env, raw_java_class) }
+            })
+            .unwrap_or_else(|| panic!("Object {:?} doesn't have a class.", self.raw_object))
+    }
+
+    /// Compare with another Java object by reference.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#issameobject)
+    pub fn is_same_as(&self, _token: &NoException, other: &Object) -> bool {
+        // Safe because arguments are ensured to be correct references by construction.
+        let same =
+            unsafe { call_jni_object_method!(self, IsSameObject, other.raw_object().as_ptr()) };
+        jni_bool::to_rust(same)
+    }
+
+    /// Compare with another Java object by reference.
+    ///
+    /// Like [`is_same_as`](#method.is_same_as), but accepts any Java object wrapper. Unlike
+    /// comparing with `==`, can't panic, because the token guarantees that there is no pending
+    /// exception.
+    pub fn ref_eq(&self, token: &NoException, other: &impl AsRef<Object<'env>>) -> bool {
+        self.is_same_as(token, other.as_ref())
+    }
+
+    /// Compare to another Java object by value, using the `equals` Java method.
+    ///
+    /// Like [`equals`](#method.equals), but accepts any Java object wrapper.
+    ///
+    /// [`Object::equals` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#equals(java.lang.Object))
+    pub fn value_eq(
+        &self,
+        token: &NoException<'env>,
+        other: &impl AsRef<Object<'env>>,
+    ) -> JavaResult<'env, bool> {
+        self.equals(token, other.as_ref())
+    }
+
+    /// Check if the object is an instance of the class.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#isinstanceof)
+    pub fn is_instance_of(&self, _token: &NoException, class: &Class) -> bool {
+        // Safe because arguments are ensured to be correct references by construction.
+        let is_instance =
+            unsafe { call_jni_object_method!(self, IsInstanceOf, class.raw_object().as_ptr()) };
+        jni_bool::to_rust(is_instance)
+    }
+
+    /// Clone the [`Object`](struct.Object.html). This is not a deep clone of the Java object,
+    /// but a Rust-like clone of the value. Since Java objects are reference counted, this will
+    /// increment the reference count.
+    ///
+    /// This method has a different signature from the one in the
+    /// [`Clone`](https://doc.rust-lang.org/nightly/core/clone/trait.Clone.html) trait because
+    /// cloning a Java object is only safe when there is no pending exception and because
+    /// cloning a java object cat throw an exception.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newlocalref)
+    pub(crate) fn clone_object(&self, token: &NoException<'env>) -> JavaResult<'env, Object<'env>> {
+        // Safe because arguments are ensured to be the correct by construction and because
+        // `NewLocalRef` throws an exception before returning `null`.
+        let raw_object = unsafe {
+            call_nullable_jni_method!(self.env(), token, NewLocalRef, self.raw_object().as_ptr())?
+        };
+        // Safe because the argument is a valid class reference.
+        Ok(unsafe { Self::from_raw(self.env, raw_object) })
+    }
+
+    /// Convert the object to a string.
+    ///
+    /// [`Object::toString` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#toString())
+    pub fn to_string(&self, token: &NoException<'env>) -> JavaResult<'env, Option<String<'env>>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { call_method::<Self, _, _, fn() -> String<'env>>(self, token, "toString\0", ()) }
+    }
+
+    /// Compare to another Java object.
+    ///
+    /// [`Object::equals` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#equals(java.lang.Object))
+    pub fn equals(
+        &self,
+        token: &NoException<'env>,
+        other: &Object<'env>,
+    ) -> JavaResult<'env, bool> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe {
+            call_method::<Self, _, _, fn(&Object<'env>) -> bool>(self, token, "equals\0", (other,))
+        }
+    }
+
+    /// Get the hash code of the [`Object`](struct.Object.html).
+    ///
+    /// [`Object::hashCode` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#hashCode())
+    pub fn hash_code(&self, token: &NoException<'env>) -> JavaResult<'env, i32> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { call_method::<Self, _, _, fn() -> i32>(self, token, "hashCode\0", ()) }
+    }
+
+    /// Get the identity hash code of the [`Object`](struct.Object.html).
+    ///
+    /// Unlike [`hash_code`](struct.Object.html#method.hash_code) this hash is consistent with
+    /// by-reference comparison and can't be overriden by the Java class.
+    ///
+    /// [`System::identityHashCode` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/System.html#identityHashCode(java.lang.Object))
+    pub fn identity_hash(&self, token: &NoException<'env>) -> JavaResult<'env, i32> {
+        let (class, method_id) = identity_hash_code_method(self.env, token)?;
+        // Safe because we ensure correct arguments and return type.
+        unsafe {
+            jni_methods::call_static_primitive_method_with_id(
+                &class,
+                token,
+                method_id,
+                (self.raw_object().as_ptr(),),
+            )
+        }
+    }
+
+    /// Wait until the object is notified or the thread is interrupted.
+    ///
+    /// The current thread must own the monitor of the object, for example by holding a
+    /// [`MonitorGuard`](../../struct.MonitorGuard.html), otherwise an
+    /// `IllegalMonitorStateException` is thrown.
+    ///
+    /// [`Object::wait` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#wait())
+    pub fn wait(&self, token: &NoException<'env>) -> JavaResult<'env, ()> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { call_method::<Self, _, _, fn()>(self, token, "wait\0", ()) }
+    }
+
+    /// Wait until the object is notified, the thread is interrupted or `timeout_ms`
+    /// milliseconds pass. Waits without a timeout if `timeout_ms` is `0`.
+    ///
+    /// The current thread must own the monitor of the object, for example by holding a
+    /// [`MonitorGuard`](../../struct.MonitorGuard.html), otherwise an
+    /// `IllegalMonitorStateException` is thrown.
+    ///
+    /// [`Object::wait` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#wait(long))
+    pub fn wait_timeout(&self, token: &NoException<'env>, timeout_ms: i64) -> JavaResult<'env, ()> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { call_method::<Self, _, _, fn(i64)>(self, token, "wait\0", (timeout_ms,)) }
+    }
+
+    /// Wake up a single thread waiting on the object.
+    ///
+    /// The current thread must own the monitor of the object, otherwise an
+    /// `IllegalMonitorStateException` is thrown.
+    ///
+    /// [`Object::notify` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#notify())
+    pub fn notify(&self, token: &NoException<'env>) -> JavaResult<'env, ()> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { call_method::<Self, _, _, fn()>(self, token, "notify\0", ()) }
+    }
+
+    /// Wake up all threads waiting on the object.
+    ///
+    /// The current thread must own the monitor of the object, otherwise an
+    /// `IllegalMonitorStateException` is thrown.
+    ///
+    /// [`Object::notifyAll` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#notifyAll())
+    pub fn notify_all(&self, token: &NoException<'env>) -> JavaResult<'env, ()> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { call_method::<Self, _, _, fn()>(self, token, "notifyAll\0", ()) }
+    }
+
+    /// Set a name for the [`Object`](struct.Object.html) to show in it's
+    /// [`Debug`](struct.Object.html#impl-Debug) output.
+    ///
+    /// Names are stored in a global side table keyed by the
+    /// [`identity_hash`](struct.Object.html#method.identity_hash), so they are shared by all
+    /// references to the same Java object. Unrelated objects with the same identity hash share
+    /// names too and names outlive the objects, see
+    /// [`clear_debug_names`](../../fn.clear_debug_names.html).
+    ///
+    /// Only available with the `debug-names` feature.
+    #[cfg(feature = "debug-names")]
+    pub fn set_debug_name(&self, token: &NoException<'env>, name: &str) -> JavaResult<'env, ()> {
+        crate::debug_name::set(self.identity_hash(token)?, name);
+        Ok(())
+    }
+
+    /// Get the name set with [`set_debug_name`](struct.Object.html#method.set_debug_name).
+    ///
+    /// Doesn't call into Java when no names are set.
+    ///
+    /// Only available with the `debug-names` feature.
+    #[cfg(feature = "debug-names")]
+    pub fn debug_name(
+        &self,
+        token: &NoException<'env>,
+    ) -> JavaResult<'env, Option<alloc::string::String>> {
+        if crate::debug_name::is_empty() {
+            return Ok(None);
+        }
+        Ok(crate::debug_name::get(self.identity_hash(token)?))
+    }
+
+    /// Create a new [`Object`](struct.Object.html) with a message.
+    ///
+    /// [`Object()` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#<init>())
+    pub fn new(
+        env: &'env JniEnv<'env>,
+        token: &NoException<'env>,
+    ) -> JavaResult<'env, Object<'env>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { call_constructor::<Self, _, fn()>(&env, token, ()) }
+    }
+
+    /// Construct from a raw pointer. Unsafe because an invalid pointer may be passed
+    /// as the argument.
+    ///
+    /// Unsafe because an incorrect object reference can be passed.
+    #[inline(always)]
+    pub unsafe fn from_raw<'a>(
+        env: &'a JniEnv<'a>,
+        raw_object: NonNull<jni_sys::_jobject>,
+    ) -> Object<'a> {
+        #[cfg(feature = "stats")]
+        crate::stats::object_created();
+        Object { env, raw_object }
+    }
+}
+
+/// Make [`Object`](struct.Object.html)-s reference be deleted when the value is
+/// [`drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html#tymethod.drop)-ed.
+///
+/// Objects are often dropped while there is a pending exception, for example when
+/// unwinding after a Java method has thrown. `DeleteLocalRef` is one of the few JNI functions
+/// that are allowed to be called in this case, so dropping doesn't call any other JNI functions.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#deletelocalref)
+impl<'env> Drop for Object<'env> {
+    fn drop(&mut self) {
+        // Safe because the argument is ensured to be correct references by construction.
+        // DeleteLocalRef can handle nulls without any issues.
+        unsafe {
+            call_jni_object_method!(self, DeleteLocalRef);
+        }
+        #[cfg(feature = "stats")]
+        crate::stats::object_released();
+    }
+}
+
+impl<'env> AsRef<Object<'env>> for Object<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        self
+    }
+}
+
+impl<'env> FromObject<'env> for Object<'env> {
+    #[inline(always)]
+    // Actually it is safe.
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        object
+    }
+}
+
+impl JniSignature for Object<'_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "Ljava/lang/Object;"
+    }
+}
+
+/// Allow comparing [`Object`](struct.Object.html) to Java objects. Java objects are compared
+/// by-reference to preserve original Java semantics. To compare objects by value, call the
+/// [`value_eq`](struct.Object.html#method.value_eq) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`ref_eq`](struct.Object.html#method.ref_eq) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for Object<'env>
+where
+    T: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &T) -> bool {
+        // Safe because we are not leaking the tokens anywhere.
+        unsafe {
+            match NoException::check_pending_exception(self.env()) {
+                Err(_) => {
+                    panic!("Comparing Java objects with a pending exception in the current thread")
+                }
+                Ok(token) => self.is_same_as(&token, other.as_ref()),
+            }
+        }
+    }
+}
+
+/// A global reference to the `java.lang.System` class.
+///
+/// Classes loaded by the bootstrap class loader are never unloaded, so the reference is never
+/// deleted.
+static SYSTEM_CLASS: AtomicPtr<jni_sys::_jobject> = AtomicPtr::new(ptr::null_mut());
+
+/// The method id of `System::identityHashCode`.
+static IDENTITY_HASH_CODE: AtomicPtr<jni_sys::_jmethodID> = AtomicPtr::new(ptr::null_mut());
+
+/// Get the `java.lang.System` class and the `System::identityHashCode` method id.
+///
+/// Both are looked up once and cached for the whole process, because identity hashes are
+/// computed for every [`ObjectKey`](../../struct.ObjectKey.html) and debug name lookup.
+fn identity_hash_code_method<'a>(
+    env: &'a JniEnv<'a>,
+    token: &NoException<'a>,
+) -> JavaResult<'a, (Class<'a>, NonNull<jni_sys::_jmethodID>)> {
+    let global_class = NonNull::new(SYSTEM_CLASS.load(Ordering::Acquire));
+    let method_id = NonNull::new(IDENTITY_HASH_CODE.load(Ordering::Acquire));
+    if let (Some(global_class), Some(method_id)) = (global_class, method_id) {
+        // Safe because the argument is a valid global reference.
+        // `NewLocalRef` can throw an exception before returning `null`,
+        // so a runtime exception check is needed.
+        let raw_class = token.with_owned(
+            env,
+            #[inline(always)]
+            |_token| unsafe {
+                CallOutcome::Unknown(call_jni_method!(env, NewLocalRef, global_class.as_ptr()))
+            },
+        )?;
+        if let Some(raw_class) = NonNull::new(raw_class) {
+            // Safe because the argument is a valid class reference.
+            return Ok((unsafe { Class::from_raw(env, raw_class) }, method_id));
+        }
+    }
+
+    let class = Class::find(env, token, "java/lang/System")?;
+    // Safe because the signature is null-terminated.
+    let method_id = unsafe {
+        jni_methods::get_static_method_id(
+            &class,
+            token,
+            "identityHashCode\0",
+            "(Ljava/lang/Object;)I\0",
+        )?
+    };
+    // Safe because the argument is ensured to be the correct by construction.
+    // `NewGlobalRef` is supposed to throw an exception before returning `null`,
+    // but not all Java VMs do, so a runtime exception check is needed.
+    let global_class = token.with_owned(
+        env,
+        #[inline(always)]
+        |_token| unsafe {
+            CallOutcome::Unknown(call_jni_method!(
+                env,
+                NewGlobalRef,
+                class.raw_object().as_ptr()
+            ))
+        },
+    )?;
+    if !global_class.is_null() {
+        IDENTITY_HASH_CODE.store(method_id.as_ptr(), Ordering::Release);
+        // Concurrent lookups race to store the class, the loser deletes it's reference.
+        if SYSTEM_CLASS
+            .compare_exchange(
+                ptr::null_mut(),
+                global_class,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            )
+            .is_err()
+        {
+            // Safe because the argument is a valid global reference.
+            unsafe { call_jni_method!(env, DeleteGlobalRef, global_class) };
+        }
+    }
+    Ok((class, method_id))
+}
+
+fn string_or_null<'a>(string: &'a Option<alloc::string::String>) -> &'a str {
+    string
+        .as_ref()
+        .map(|string| string.as_ref())
+        .unwrap_or("<null>")
+}
+
+/// Panic when formatting a Java object with a pending exception if the `strict` feature is enabled.
+///
+/// Without the `strict` feature formatting falls back to printing the raw object pointer.
+#[inline(always)]
+fn check_strict_formatting() {
+    if cfg!(feature = "strict") {
+        panic!("Formatting a Java object with a pending exception in the current thread")
+    }
+}
+
+/// Format the debug name of an object as a [`Debug`](struct.Object.html#impl-Debug) field.
+///
+/// Returns an empty string if the object doesn't have a name. Looking up the name needs the
+/// identity hash, so this must only be called without a pending exception. If the lookup
+/// throws, the exception is cleared and the name is omitted, so that the object can still be
+/// formatted.
+#[cfg(feature = "debug-names")]
+fn debug_name_field<'env>(
+    object: &Object<'env>,
+    token: &NoException<'env>,
+) -> alloc::string::String {
+    match object.debug_name(token) {
+        Ok(Some(name)) => format!(", name: {:?}", name),
+        Ok(None) | Err(_) => alloc::string::String::new(),
+    }
+}
+
+#[cfg(not(feature = "debug-names"))]
+#[inline(always)]
+fn debug_name_field<'env>(_object: &Object<'env>, _token: &NoException<'env>) -> &'static str {
+    ""
+}
+
+/// Allow displaying Java objects for debug purposes.
+///
+/// [`Object::toString`](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#toString())
+///
+/// When there is a pending exception in the current thread `Object::toString` can't be called,
+/// so the raw object pointer is printed instead. With the `strict` feature enabled this
+/// panics instead.
+///
+/// With the `debug-names` feature enabled the name set with
+/// [`set_debug_name`](struct.Object.html#method.set_debug_name) is printed as well.
+///
+/// This is mostly a convenience for debugging. Always prefer using
+/// [`to_string`](struct.Object.html#methods.to_string) to printing the object as is, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env> fmt::Debug for Object<'env> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        // Safe because we are not leaking the tokens anywhere.
+        unsafe {
+            match NoException::check_pending_exception(self.env()) {
+                Err(_) => {
+                    check_strict_formatting();
+                    // Can't call `to_string` with a pending exception.
+                    write!(
+                        formatter,
+                        "Object {{ env: {:?}, object: {:?}, string: \
+                         <can't call Object::toString string because of a pending exception in the current thread> }}",
+                        self.env, self.raw_object
+                    )
+                }
+                Ok(token) => {
+                    let name = debug_name_field(self, &token);
+                    match self.to_string(&token) {
+                        Ok(string) => write!(
+                            formatter,
+                            "Object {{ env: {:?}, object: {:?}{}, string: {} }}",
+                            self.env,
+                            self.raw_object,
+                            name,
+                            string_or_null(&string.map(|string| string.as_string_lossy(&token)))
+                        ),
+                        Err(exception) => match exception.to_string(&token) {
+                            Ok(message) => write!(
+                                formatter,
+                                "Object {{ env: {:?}, object: {:?}{}, string: \
+                                 <Object::toString threw an exception: {:?}> }}",
+                                self.env,
+                                self.raw_object,
+                                name,
+                                string_or_null(
+                                    &message.map(|message| message.as_string_lossy(&token))
+                                )
+                            ),
+                            Err(_) => write!(
+                                formatter,
+                                "Object {{ env: {:?}, object: {:?}{}, string: \
+                                 <Object::toString threw an exception> }}",
+                                self.env, self.raw_object, name
+                            ),
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Allow displaying Java objects using
+/// [`Object::toString`](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#toString()).
+///
+/// When there is a pending exception in the current thread `Object::toString` can't be called,
+/// so the raw object pointer is printed instead. With the `strict` feature enabled this
+/// panics instead.
+///
+/// This is mostly a convenience for error messages and logging. Always prefer using
+/// [`to_string`](struct.Object.html#methods.to_string) to printing the object as is, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env> fmt::Display for Object<'env> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        // Safe because we are not leaking the tokens anywhere.
+        unsafe {
+            match NoException::check_pending_exception(self.env()) {
+                Err(_) => {
+                    check_strict_formatting();
+                    // Can't call `to_string` with a pending exception.
+                    write!(
+                        formatter,
+                        "<Object {:?}: can't call Object::toString because of a pending exception \
+                         in the current thread>",
+                        self.raw_object
+                    )
+                }
+                Ok(token) => match self.to_string(&token) {
+                    Ok(string) => write!(
+                        formatter,
+                        "{}",
+                        string_or_null(&string.map(|string| string.as_string_lossy(&token)))
+                    ),
+                    Err(_) => write!(
+                        formatter,
+                        "<Object {:?}: Object::toString threw an exception>",
+                        self.raw_object
+                    ),
+                },
+            }
+        }
+    }
+}
+
+/// Allow displaying Java objects for debug purposes.
+///
+/// [`Object::toString`](https://docs.oracle.com/javase/10/docs/api/java/lang/Object.html#toString())
+///
+/// This is mostly a convenience for debugging. Always prefer using
+/// [`to_string`](struct.Object.html#methods.to_string) to printing the object as is, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'a> Clone for Object<'a> {
+    fn clone(&self) -> Self {
+        // Safe because we are not leaking the tokens anywhere.
+        unsafe {
+            match NoException::check_pending_exception(self.env()) {
+                Err(_) => {
+                    panic!("Cloning a Java object with a pending exception in the current thread")
+                }
+                Ok(token) => self.clone_object(&token).unwrap(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod object_tests {
+    use super::*;
+    use crate::vm::JavaVMRef;
+    use serial_test_derive::serial;
+
+    generate_jni_env_mock!(jni_mock);
+
+    #[test]
+    #[serial]
+    fn drop() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_env_ptr_usize = raw_env_ptr as usize;
+        // Only `DeleteLocalRef` is mocked here, as other functions, like `ExceptionCheck`,
+        // must not be called with a pending exception.
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf(move |env, object| {
+                *env == raw_env_ptr_usize as *mut ::jni_sys::JNIEnv
+                    && *object == 0x1234 as jni_sys::jobject
+            })
+            .return_const(());
+        let vm = JavaVMRef::test(0x5678 as *mut jni_sys::JavaVM);
+        let env = JniEnv::test(&vm, raw_env_ptr);
+        let object = unsafe { Object::from_raw(&env, NonNull::new(0x1234 as _).unwrap()) };
+        mem::drop(object);
+    }
+}
@@ -0,0 +1,425 @@
+use crate::class::Class;
+use crate::convert;
+use crate::env::JniEnv;
+use crate::java_class::{find_class, JavaClass, NullableJavaClassExt};
+use crate::java_methods::FromObject;
+use crate::java_methods::JniSignature;
+use crate::jni_methods;
+use crate::object::Object;
+use crate::result::JavaResult;
+use crate::throwable::Throwable;
+use crate::token::{CallOutcome, NoException};
+use core::ptr::{self, NonNull};
+use jni_sys;
+
+include!("call_jni_method.rs");
+
+/// A type representing a Java `Object[]` array.
+///
+/// Besides regular array access, [`ObjectArray`](struct.ObjectArray.html) can be converted from and
+/// into tuples of Java objects with [`from_tuple`](struct.ObjectArray.html#method.from_tuple)
+/// and [`to_tuple`](struct.ObjectArray.html#method.to_tuple). This is useful for reflective calls
+/// and for passing heterogeneous arguments to varargs methods.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#array-operations)
+///
+/// # Example
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::{Object, String};
+/// #
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let string = String::new(env, &token, "test")?;
+/// let object = Object::new(env, &token)?;
+/// let array = ObjectArray::from_tuple(env, &token, &(string, Some(object), None::<String>))?;
+/// assert_eq!(array.len(&token), 3);
+/// let (string, object, null) = array.to_tuple::<(String, Object, Option<String>)>(&token)?;
+/// assert_eq!(string.as_string(&token), "test");
+/// assert!(null.is_none());
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ObjectArray<'env> {
+    object: Object<'env>,
+}
+
+impl<'env> ObjectArray<'env> {
+    /// Create a new array of `null`-s.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newobjectarray)
+    pub fn new(
+        env: &'env JniEnv<'env>,
+        token: &NoException<'env>,
+        length: usize,
+    ) -> JavaResult<'env, ObjectArray<'env>> {
+        let element_class = Class::find(env, token, "java/lang/Object")?;
+        // Safe because arguments are ensured to be the correct by construction and because
+        // `NewObjectArray` throws an exception before returning `null`.
+        let raw_array = unsafe {
+            call_nullable_jni_method!(
+                env,
+                token,
+                NewObjectArray,
+                convert::size_to_jni(length),
+                element_class.raw_object().as_ptr(),
+                ptr::null_mut()
+            )
+        }?;
+        // Safe because the argument is a valid array reference.
+        Ok(unsafe { Self::from_object(Object::from_raw(env, raw_array)) })
+    }
+
+    /// Create a new array from a tuple of Java objects.
+    ///
+    /// Tuple elements can be any Java class wrappers or [`Option`](https://doc.rust-lang.org/std/option/enum.Option.html)-s
+    /// of them for `null`-able elements.
+    pub fn from_tuple<T: ObjectArrayTuple<'env>>(
+        env: &'env JniEnv<'env>,
+        token: &NoException<'env>,
+        tuple: &T,
+    ) -> JavaResult<'env, ObjectArray<'env>> {
+        tuple.to_object_array(env, token)
+    }
+
+    /// Convert the array into a tuple of Java objects.
+    ///
+    /// Elements are checked to be instances of the corresponding tuple element classes.
+    /// Returns an
+    /// [`IllegalArgumentException`](https://docs.oracle.com/javase/10/docs/api/java/lang/IllegalArgumentException.html)
+    /// if the array length doesn't match the tuple size, a
+    /// [`ClassCastException`](https://docs.oracle.com/javase/10/docs/api/java/lang/ClassCastException.html)
+    /// if an element has an unexpected class and a
+    /// [`NullPointerException`](java/lang/struct.NullPointerException.html) if a non-optional element
+    /// is `null`.
+    pub fn to_tuple<T: ObjectArrayTuple<'env>>(
+        &self,
+        token: &NoException<'env>,
+    ) -> JavaResult<'env, T> {
+        T::from_object_array(self, token)
+    }
+
+    /// Get the length of the array.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getarraylength)
+    pub fn len(&self, _token: &NoException<'env>) -> usize {
+        // Safe because the argument is ensured to be the correct by construction.
+        let length = unsafe {
+            call_jni_method!(
+                self.object.env(),
+                GetArrayLength,
+                self.object.raw_object().as_ptr()
+            )
+        };
+        convert::size_from_jni(length)
+    }
+
+    /// Get an element of the array.
+    ///
+    /// Returns an
+    /// [`ArrayIndexOutOfBoundsException`](https://docs.oracle.com/javase/10/docs/api/java/lang/ArrayIndexOutOfBoundsException.html)
+    /// if the index is out of bounds.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getobjectarrayelement)
+    pub fn get(
+        &self,
+        token: &NoException<'env>,
+        index: usize,
+    ) -> JavaResult<'env, Option<Object<'env>>> {
+        let env = self.object.env();
+        // Safe because arguments are ensured to be the correct by construction.
+        // `GetObjectArrayElement` returns `null` both for `null` elements and when it throws,
+        // so a runtime exception check is needed.
+        let raw_element = token.with_owned(
+            env,
+            #[inline(always)]
+            |_token| unsafe {
+                CallOutcome::Unknown(call_jni_method!(
+                    env,
+                    GetObjectArrayElement,
+                    self.object.raw_object().as_ptr(),
+                    convert::size_to_jni(index)
+                ))
+            },
+        )?;
+        // Safe because the argument is a valid object reference.
+        Ok(NonNull::new(raw_element)
+            .map(|raw_element| unsafe { Object::from_raw(env, raw_element) }))
+    }
+
+    /// Set an element of the array.
+    ///
+    /// Returns an
+    /// [`ArrayIndexOutOfBoundsException`](https://docs.oracle.com/javase/10/docs/api/java/lang/ArrayIndexOutOfBoundsException.html)
+    /// if the index is out of bounds.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#setobjectarrayelement)
+    pub fn set(
+        &self,
+        token: &NoException<'env>,
+        index: usize,
+        value: Option<&Object<'env>>,
+    ) -> JavaResult<'env, ()> {
+        let raw_value = value.map_or(ptr::null_mut(), |value| {
+            // Safe because we only pass the pointer to JNI.
+            unsafe { value.raw_object().as_ptr() }
+        });
+        self.set_raw(token, index, raw_value)
+    }
+
+    pub(crate) fn set_raw(
+        &self,
+        token: &NoException<'env>,
+        index: usize,
+        raw_value: jni_sys::jobject,
+    ) -> JavaResult<'env, ()> {
+        let env = self.object.env();
+        // Safe because arguments are ensured to be the correct by construction.
+        // `SetObjectArrayElement` doesn't return a value, so a runtime exception
+        // check is needed.
+        token.with_owned(
+            env,
+            #[inline(always)]
+            |_token| unsafe {
+                call_jni_method!(
+                    env,
+                    SetObjectArrayElement,
+                    self.object.raw_object().as_ptr(),
+                    convert::size_to_jni(index),
+                    raw_value
+                );
+                CallOutcome::Unknown(())
+            },
+        )
+    }
+}
+
+/// Create a new exception of a class with a default constructor.
+pub fn new_exception<'a>(
+    env: &'a JniEnv<'a>,
+    token: &NoException<'a>,
+    class_name: &str,
+) -> JavaResult<'a, Throwable<'a>> {
+    let class = Class::find(env, token, class_name)?;
+    // Safe because we ensure correct arguments.
+    let exception = unsafe { jni_methods::call_constructor(&class, token, "()V\0", ()) }?;
+    // Safe because the argument is a valid throwable reference.
+    Ok(unsafe { Throwable::from_raw(env, exception) })
+}
+
+/// A type that can be an element of an [`ObjectArray`](struct.ObjectArray.html) tuple.
+///
+/// Implemented for all Java class wrappers and for
+/// [`Option`](https://doc.rust-lang.org/std/option/enum.Option.html)-s of them.
+pub trait ObjectArrayElement<'a>: Sized {
+    /// Get the raw element pointer to store in an array.
+    ///
+    /// Unsafe because the pointer must not outlive the element.
+    unsafe fn raw_element(&self) -> jni_sys::jobject;
+
+    /// Convert an array element, checking that it has the correct class.
+    fn from_element(
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+        element: Option<Object<'a>>,
+    ) -> JavaResult<'a, Self>;
+}
+
+impl<'a, T> ObjectArrayElement<'a> for T
+where
+    T: JavaClass<'a>,
+{
+    #[inline(always)]
+    unsafe fn raw_element(&self) -> jni_sys::jobject {
+        self.as_ref().raw_object().as_ptr()
+    }
+
+    fn from_element(
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+        element: Option<Object<'a>>,
+    ) -> JavaResult<'a, Self> {
+        let element = element.or_npe(env, token)?;
+        let class = find_class::<T>(env, token)?;
+        if !element.is_instance_of(token, &class) {
+            return Err(new_exception(env, token, "java/lang/ClassCastException")?);
+        }
+        // Safe because we checked the class of the element.
+        Ok(unsafe { T::from_object(element) })
+    }
+}
+
+impl<'a, T> ObjectArrayElement<'a> for Option<T>
+where
+    T: JavaClass<'a>,
+{
+    #[inline(always)]
+    unsafe fn raw_element(&self) -> jni_sys::jobject {
+        self.as_ref()
+            .map_or(ptr::null_mut(), |value| value.raw_element())
+    }
+
+    fn from_element(
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+        element: Option<Object<'a>>,
+    ) -> JavaResult<'a, Self> {
+        match element {
+            None => Ok(None),
+            Some(element) => T::from_element(env, token, Some(element)).map(Some),
+        }
+    }
+}
+
+/// A tuple that can be converted from and into an [`ObjectArray`](struct.ObjectArray.html).
+///
+/// Implemented for tuples of up to 12 [`ObjectArrayElement`](trait.ObjectArrayElement.html)-s.
+pub trait ObjectArrayTuple<'a>: Sized {
+    /// Create a new array with the tuple elements.
+    fn to_object_array(
+        &self,
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+    ) -> JavaResult<'a, ObjectArray<'a>>;
+
+    /// Convert an array into a tuple, checking the length of the array and classes of the elements.
+    fn from_object_array(array: &ObjectArray<'a>, token: &NoException<'a>) -> JavaResult<'a, Self>;
+}
+
+macro_rules! peel_object_array_tuple_impls {
+    () => ();
+    ($type:ident, $($other:ident,)*) => (object_array_tuple_impls! { $($other,)* });
+}
+
+macro_rules! object_array_tuple_impls {
+    ( $($type:ident,)*) => (
+        impl<'a, $($type),*> ObjectArrayTuple<'a> for ($($type,)*)
+        where
+            $($type: ObjectArrayElement<'a>,)*
+        {
+            fn to_object_array(
+                &self,
+                env: &'a JniEnv<'a>,
+                token: &NoException<'a>,
+            ) -> JavaResult<'a, ObjectArray<'a>> {
+                #[allow(non_snake_case)]
+                let ($($type,)*) = self;
+                // Safe because the elements outlive the array construction.
+                #[allow(unused_unsafe)]
+                let raw_elements = unsafe { [$($type.raw_element(),)*] };
+                let array = ObjectArray::new(env, token, raw_elements.len())?;
+                for (index, raw_element) in raw_elements.iter().enumerate() {
+                    array.set_raw(token, index, *raw_element)?;
+                }
+                Ok(array)
+            }
+
+            // The index is unused for an empty tuple.
+            #[allow(unused_mut, unused_variables, unused_assignments)]
+            fn from_object_array(
+                array: &ObjectArray<'a>,
+                token: &NoException<'a>,
+            ) -> JavaResult<'a, Self> {
+                let env = array.env();
+                let length = <[&str]>::len(&[$(stringify!($type),)*]);
+                if array.len(token) != length {
+                    return Err(new_exception(
+                        env,
+                        token,
+                        "java/lang/IllegalArgumentException",
+                    )?);
+                }
+                let mut index = 0;
+                Ok(($({
+                    let element = $type::from_element(env, token, array.get(token, index)?)?;
+                    index += 1;
+                    element
+                },)*))
+            }
+        }
+
+        peel_object_array_tuple_impls! { $($type,)* }
+    );
+}
+
+object_array_tuple_impls! {
+    T0,
+    T1,
+    T2,
+    T3,
+    T4,
+    T5,
+    T6,
+    T7,
+    T8,
+    T9,
+    T10,
+    T11,
+}
+
+/// Allow [`ObjectArray`](struct.ObjectArray.html) to be used in place of an [`Object`](java/lang/struct.Object.html).
+impl<'env> ::core::ops::Deref for ObjectArray<'env> {
+    type Target = Object<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Object<'env>> for ObjectArray<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        &self.object
+    }
+}
+
+impl<'a> Into<Object<'a>> for ObjectArray<'a> {
+    fn into(self) -> Object<'a> {
+        self.object
+    }
+}
+
+impl<'env> FromObject<'env> for ObjectArray<'env> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self { object }
+    }
+}
+
+impl JniSignature for ObjectArray<'_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "[Ljava/lang/Object;"
+    }
+}
+
+/// Allow comparing [`ObjectArray`](struct.ObjectArray.html)
+/// to Java objects. Java objects are compared by-reference to preserve
+/// original Java semantics. To compare objects by value, call the
+/// [`value_eq`](java/lang/struct.Object.html#method.value_eq) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`ref_eq`](java/lang/struct.Object.html#method.ref_eq) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for ObjectArray<'env>
+where
+    T: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &T) -> bool {
+        self.as_ref().eq(other.as_ref())
+    }
+}
@@ -0,0 +1,189 @@
+use crate::env::JniEnv;
+use crate::global_ref_drop::{delete_global_ref, flush_deferred_global_refs};
+use crate::jni_bool;
+use crate::object::Object;
+use crate::result::JavaResult;
+use crate::token::{CallOutcome, NoException};
+use crate::vm::JavaVMRef;
+use core::hash::{Hash, Hasher};
+use core::ptr::NonNull;
+use jni_sys;
+
+include!("call_jni_method.rs");
+
+/// A key for using Java objects in Rust collections like
+/// [`HashMap`](https://doc.rust-lang.org/std/collections/struct.HashMap.html).
+///
+/// [`ObjectKey`](struct.ObjectKey.html) holds a global reference to the object and it's
+/// [identity hash](java/lang/struct.Object.html#method.identity_hash). Keys are hashed and compared
+/// by reference identity, which preserves Java's `IdentityHashMap` semantics.
+///
+/// Unlike [`Object`](java/lang/struct.Object.html), [`ObjectKey`](struct.ObjectKey.html) is not bound
+/// to a [`JniEnv`](struct.JniEnv.html) and can be sent between threads. Comparing keys calls JNI
+/// on the current thread, attaching it to the Java VM for the duration of the call if needed.
+/// [`drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html#tymethod.drop)-ing a key on an
+/// attached thread deletes the global reference right away. Keys dropped on other threads are
+/// deleted according to the [`GlobalRefDropPolicy`](enum.GlobalRefDropPolicy.html), which defers
+/// deleting them to an attached thread by default.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newglobalref)
+///
+/// # Example
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::String;
+/// # use std::collections::HashMap;
+/// #
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let string = String::new(env, &token, "test")?;
+/// let mut map = HashMap::new();
+/// map.insert(ObjectKey::new(&string, &token)?, 1);
+/// assert_eq!(map.get(&ObjectKey::new(&string, &token)?), Some(&1));
+/// let other_string = String::new(env, &token, "test")?;
+/// assert_eq!(map.get(&ObjectKey::new(&other_string, &token)?), None);
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ObjectKey {
+    vm: JavaVMRef,
+    raw_object: NonNull<jni_sys::_jobject>,
+    identity_hash: i32,
+}
+
+/// Make [`ObjectKey`](struct.ObjectKey.html) sendable between threads.
+/// Global references are valid in all threads.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/design.html#global-and-local-references)
+unsafe impl Send for ObjectKey {}
+
+/// Make [`ObjectKey`](struct.ObjectKey.html) shareable by multiple threads.
+/// Global references are valid in all threads.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/design.html#global-and-local-references)
+unsafe impl Sync for ObjectKey {}
+
+impl ObjectKey {
+    /// Create a key for a Java object.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newglobalref)
+    pub fn new<'a>(
+        object: &impl AsRef<Object<'a>>,
+        token: &NoException<'a>,
+    ) -> JavaResult<'a, Self> {
+        let object = object.as_ref();
+        let env = object.env();
+        let identity_hash = object.identity_hash(token)?;
+        // Safe because arguments are ensured to be the correct by construction and because
+        // `NewGlobalRef` throws an exception before returning `null`.
+        let raw_object = unsafe {
+            call_nullable_jni_method!(env, token, NewGlobalRef, object.raw_object().as_ptr())
+        }?;
+        #[cfg(feature = "stats")]
+        crate::stats::global_ref_created();
+        flush_deferred_global_refs(env);
+        Ok(Self {
+            // Safe because the pointer is taken from a valid `JniEnv`.
+            vm: unsafe { JavaVMRef::from_ptr(env.raw_jvm()) },
+            raw_object,
+            identity_hash,
+        })
+    }
+
+    /// Get the [identity hash](java/lang/struct.Object.html#method.identity_hash) of the object.
+    #[inline(always)]
+    pub fn identity_hash(&self) -> i32 {
+        self.identity_hash
+    }
+
+    /// Get the Java VM the global reference belongs to.
+    #[inline(always)]
+    pub(crate) fn vm(&self) -> &JavaVMRef {
+        &self.vm
+    }
+
+    /// Get the raw global reference.
+    #[inline(always)]
+    pub(crate) fn raw_object(&self) -> NonNull<jni_sys::_jobject> {
+        self.raw_object
+    }
+
+    /// Get a local reference to the object.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newlocalref)
+    pub fn object<'a>(
+        &self,
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+    ) -> JavaResult<'a, Object<'a>> {
+        // Safe because arguments are ensured to be the correct by construction and because
+        // `NewLocalRef` throws an exception before returning `null`.
+        let raw_object = unsafe {
+            call_nullable_jni_method!(env, token, NewLocalRef, self.raw_object.as_ptr())
+        }?;
+        // Safe because the argument is a valid object reference.
+        Ok(unsafe { Object::from_raw(env, raw_object) })
+    }
+}
+
+/// Hash [`ObjectKey`](struct.ObjectKey.html) by the
+/// [identity hash](java/lang/struct.Object.html#method.identity_hash) of the object.
+impl Hash for ObjectKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.identity_hash.hash(state);
+    }
+}
+
+/// Compare [`ObjectKey`](struct.ObjectKey.html)-s by reference.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#issameobject)
+impl PartialEq for ObjectKey {
+    fn eq(&self, other: &Self) -> bool {
+        if self.identity_hash != other.identity_hash {
+            return false;
+        }
+
+        // Safe because `IsSameObject` can be called with a pending exception and
+        // arguments are ensured to be correct references by construction.
+        let same = unsafe {
+            self.vm.with_current_env(|env| {
+                call_jni_method!(
+                    env,
+                    IsSameObject,
+                    self.raw_object.as_ptr(),
+                    other.raw_object.as_ptr()
+                )
+            })
+        };
+        jni_bool::to_rust(same)
+    }
+}
+
+impl Eq for ObjectKey {}
+
+/// Delete the global reference when the [`ObjectKey`](struct.ObjectKey.html) is
+/// [`drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html#tymethod.drop)-ed.
+///
+/// Keys dropped on threads that are not attached to the Java VM are deleted according to the
+/// [`GlobalRefDropPolicy`](enum.GlobalRefDropPolicy.html).
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#deleteglobalref)
+impl Drop for ObjectKey {
+    fn drop(&mut self) {
+        // Safe because the argument is ensured to be a correct reference by construction.
+        unsafe { delete_global_ref(&self.vm, self.raw_object) };
+        #[cfg(feature = "stats")]
+        crate::stats::global_ref_deleted();
+    }
+}
@@ -0,0 +1,144 @@
+use crate::class::Class;
+use crate::classes::class_loader::ClassLoader;
+use crate::classes::exception::Exception;
+use crate::classes::null_pointer_exception::NullPointerException;
+use crate::classes::runtime_exception::RuntimeException;
+use crate::env::JniEnv;
+use crate::java_class::JavaClass;
+use crate::java_methods::FromObject;
+use crate::object::Object;
+use crate::object_array::ObjectArray;
+use crate::object_key::ObjectKey;
+use crate::primitive_array::{
+    JavaBooleanArray, JavaByteArray, JavaCharArray, JavaDoubleArray, JavaFloatArray, JavaIntArray,
+    JavaLongArray, JavaShortArray,
+};
+use crate::result::JavaResult;
+use crate::string::String;
+use crate::throwable::Throwable;
+use crate::token::NoException;
+use core::marker::PhantomData;
+
+/// A trait for Java class wrappers that can be detached from a [`JniEnv`](struct.JniEnv.html)
+/// into an [`OwnedObject`](struct.OwnedObject.html).
+pub trait Detachable {
+    /// The same wrapper type bound to a [`JniEnv`](struct.JniEnv.html) with the lifetime `'a`.
+    type Attached<'a>: JavaClass<'a> + Into<Object<'a>>;
+}
+
+macro_rules! detachable {
+    ($type:ident) => {
+        impl<'env> Detachable for $type<'env> {
+            type Attached<'a> = $type<'a>;
+        }
+    };
+}
+
+detachable!(Object);
+detachable!(Class);
+detachable!(String);
+detachable!(Throwable);
+detachable!(Exception);
+detachable!(RuntimeException);
+detachable!(NullPointerException);
+detachable!(ClassLoader);
+detachable!(ObjectArray);
+detachable!(JavaBooleanArray);
+detachable!(JavaByteArray);
+detachable!(JavaCharArray);
+detachable!(JavaShortArray);
+detachable!(JavaIntArray);
+detachable!(JavaLongArray);
+detachable!(JavaFloatArray);
+detachable!(JavaDoubleArray);
+
+/// A Java object that is not bound to a [`JniEnv`](struct.JniEnv.html).
+///
+/// Created with [`JavaClassExt::detach_from_env`](trait.JavaClassExt.html#method.detach_from_env),
+/// which swaps the local reference of the object for a global one. Can be stored in long-lived
+/// structs and sent between threads, and attached to a [`JniEnv`](struct.JniEnv.html) again
+/// with [`attach_to_env`](#method.attach_to_env).
+///
+/// The type parameter is the wrapper type with the `'static` lifetime, like
+/// `OwnedObject<String<'static>>`.
+///
+/// Like [`ObjectKey`](struct.ObjectKey.html), [`drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html#tymethod.drop)-ing
+/// the object on a thread attached to the Java VM deletes the global reference right away.
+/// Objects dropped on other threads are deleted according to the
+/// [`GlobalRefDropPolicy`](enum.GlobalRefDropPolicy.html), which defers deleting them to an
+/// attached thread by default.
+///
+/// # Example
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::String;
+/// #
+/// struct Cache {
+///     value: OwnedObject<String<'static>>,
+/// }
+///
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let string = String::new(env, &token, "value")?;
+/// let cache = Cache {
+///     value: string.detach_from_env(&token)?,
+/// };
+/// let string = cache.value.attach_to_env(env, &token)?;
+/// assert_eq!(string.as_string(&token), "value");
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct OwnedObject<T> {
+    key: ObjectKey,
+    // `fn() -> T` keeps the object `Send` and `Sync` regardless of `T`.
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T> OwnedObject<T> {
+    /// Swap the local reference of the object for a global one.
+    pub(crate) fn new<'a>(
+        object: impl Into<Object<'a>>,
+        token: &NoException<'a>,
+    ) -> JavaResult<'a, Self> {
+        let object = object.into();
+        Ok(Self {
+            key: ObjectKey::new(&object, token)?,
+            _type: PhantomData,
+        })
+    }
+
+    /// Get the [`ObjectKey`](struct.ObjectKey.html) holding the global reference to the object.
+    #[inline(always)]
+    pub fn as_key(&self) -> &ObjectKey {
+        &self.key
+    }
+}
+
+impl<T> OwnedObject<T>
+where
+    T: Detachable,
+{
+    /// Get a local reference to the object bound to the [`JniEnv`](struct.JniEnv.html).
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newlocalref)
+    pub fn attach_to_env<'a>(
+        &self,
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+    ) -> JavaResult<'a, T::Attached<'a>> {
+        let object = self.key.object(env, token)?;
+        // Safe because the object was detached from the same wrapper type.
+        Ok(unsafe { T::Attached::<'a>::from_object(object) })
+    }
+}
@@ -0,0 +1,230 @@
+//! A panic hook that adds the Java context of the current thread to panic messages.
+//!
+//! Only compiled with the `std` feature.
+
+use crate::env::JniEnv;
+use crate::signature::JavaType;
+use core::cell::RefCell;
+use core::ffi::{c_char, CStr};
+use core::ptr::{self, NonNull};
+use core::sync::atomic::{AtomicBool, Ordering};
+use jni_sys;
+use std::panic;
+
+/// Call a raw JNI function with a raw JNI environment pointer.
+macro_rules! raw_jni_call {
+    ($env:expr, $method:ident $(, $argument:expr)*) => {
+        ((**$env).$method.unwrap())($env $(, $argument)*)
+    };
+}
+
+/// A Java method or constructor called from Rust.
+#[derive(Debug, Clone)]
+struct CallFrame {
+    env: NonNull<jni_sys::JNIEnv>,
+    class_signature: &'static str,
+    method: String,
+}
+
+static PANIC_HOOK_INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// Java calls made by the current thread, innermost last.
+    static CALL_PATH: RefCell<Vec<CallFrame>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Removes the call from the call path when dropped.
+pub(crate) struct CallGuard;
+
+impl Drop for CallGuard {
+    fn drop(&mut self) {
+        let _ = CALL_PATH.try_with(|path| path.borrow_mut().pop());
+    }
+}
+
+/// Record a Java call in the call path of the current thread until the returned guard is dropped.
+///
+/// Does nothing unless the panic hook is installed.
+#[inline(always)]
+pub(crate) fn enter_call(
+    env: &JniEnv,
+    class_signature: &'static str,
+    method: &str,
+) -> Option<CallGuard> {
+    if !PANIC_HOOK_INSTALLED.load(Ordering::Relaxed) {
+        return None;
+    }
+    let frame = CallFrame {
+        // Safe because the pointer is only used on the current thread while the call is made.
+        env: unsafe { env.raw_env() },
+        class_signature,
+        method: method.trim_end_matches('\0').to_owned(),
+    };
+    CALL_PATH.with(|path| path.borrow_mut().push(frame));
+    Some(CallGuard)
+}
+
+/// Install a panic hook that adds the Java context of the panicking thread to panic messages:
+/// the name of the current Java thread, the Java methods being called from Rust and
+/// the pending Java exception, if any.
+///
+/// The context is printed to `stderr` after the message of the previous panic hook.
+/// Java calls are only tracked once the hook is installed, which adds a small overhead to
+/// every call. Installing the hook more than once has no effect.
+///
+/// Use [`java_panic_context`](fn.java_panic_context.html) to add the context to a custom
+/// panic hook instead.
+pub fn install_java_panic_hook() {
+    if PANIC_HOOK_INSTALLED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        previous_hook(info);
+        if let Some(context) = java_panic_context() {
+            eprintln!("{}", context);
+        }
+    }));
+}
+
+/// Describe the Java context of the current thread, as printed by the panic hook installed with
+/// [`install_java_panic_hook`](fn.install_java_panic_hook.html).
+///
+/// Returns [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None) if
+/// the current thread is not calling Java methods or if the panic hook was never installed, as
+/// Java calls are not tracked before that.
+///
+/// A pending exception is set aside while the context is collected and thrown again afterwards.
+pub fn java_panic_context() -> Option<String> {
+    let frames = CALL_PATH
+        .try_with(|path| path.try_borrow().map(|path| path.clone()).ok())
+        .ok()??;
+    let env = frames.last()?.env.as_ptr();
+    let mut context = "Java context:".to_owned();
+    // Safe because the environment belongs to the current thread and is valid until the
+    // outermost call returns.
+    let (thread_name, exception) = unsafe { describe_thread(env) };
+    context.push_str(&format!(
+        "\n  thread: {}",
+        thread_name.as_deref().unwrap_or("<unknown>")
+    ));
+    for frame in frames.iter().rev() {
+        context.push_str(&format!(
+            "\n  calling: {}.{}",
+            class_name(frame.class_signature),
+            frame.method
+        ));
+    }
+    if let Some(exception) = exception {
+        context.push_str(&format!("\n  pending exception: {}", exception));
+    }
+    Some(context)
+}
+
+/// Convert a class signature, like `La/b/C;`, into a class name, like `a.b.C`.
+fn class_name(signature: &str) -> String {
+    match JavaType::parse(signature) {
+        Ok(JavaType::Object(name)) => name.replace('/', "."),
+        _ => signature.to_string(),
+    }
+}
+
+/// Get the name of the current Java thread and the description of the pending exception.
+///
+/// Unsafe because the environment must be valid and belong to the current thread.
+unsafe fn describe_thread(env: *mut jni_sys::JNIEnv) -> (Option<String>, Option<String>) {
+    let exception = raw_jni_call!(env, ExceptionOccurred);
+    if !exception.is_null() {
+        raw_jni_call!(env, ExceptionClear);
+    }
+    let mut thread_name = None;
+    let mut exception_description = None;
+    if raw_jni_call!(env, PushLocalFrame, 16) == jni_sys::JNI_OK {
+        let thread_class = raw_jni_call!(
+            env,
+            FindClass,
+            "java/lang/Thread\0".as_ptr() as *const c_char
+        );
+        if !thread_class.is_null() {
+            let current_thread = raw_jni_call!(
+                env,
+                GetStaticMethodID,
+                thread_class,
+                "currentThread\0".as_ptr() as *const c_char,
+                "()Ljava/lang/Thread;\0".as_ptr() as *const c_char
+            );
+            if !current_thread.is_null() {
+                let thread =
+                    raw_jni_call!(env, CallStaticObjectMethod, thread_class, current_thread);
+                thread_name = call_string_method(env, thread, "getName\0");
+            }
+        }
+        if !exception.is_null() {
+            exception_description = call_string_method(env, exception, "toString\0");
+        }
+        raw_jni_call!(env, ExceptionClear);
+        raw_jni_call!(env, PopLocalFrame, ptr::null_mut());
+    } else {
+        raw_jni_call!(env, ExceptionClear);
+    }
+    if !exception.is_null() {
+        raw_jni_call!(env, Throw, exception);
+        raw_jni_call!(env, DeleteLocalRef, exception);
+    }
+    (thread_name, exception_description)
+}
+
+/// Call a Java method that takes no arguments and returns a `String`.
+/// The method name *must* be null-terminated.
+///
+/// Unsafe because the environment must be valid and belong to the current thread.
+unsafe fn call_string_method(
+    env: *mut jni_sys::JNIEnv,
+    object: jni_sys::jobject,
+    name: &str,
+) -> Option<String> {
+    if object.is_null() || raw_jni_call!(env, ExceptionCheck) == jni_sys::JNI_TRUE {
+        return None;
+    }
+    let class = raw_jni_call!(env, GetObjectClass, object);
+    let method = raw_jni_call!(
+        env,
+        GetMethodID,
+        class,
+        name.as_ptr() as *const c_char,
+        "()Ljava/lang/String;\0".as_ptr() as *const c_char
+    );
+    if method.is_null() {
+        return None;
+    }
+    let string = raw_jni_call!(env, CallObjectMethod, object, method);
+    if string.is_null() || raw_jni_call!(env, ExceptionCheck) == jni_sys::JNI_TRUE {
+        return None;
+    }
+    let chars = raw_jni_call!(env, GetStringUTFChars, string, ptr::null_mut());
+    if chars.is_null() {
+        return None;
+    }
+    let value = CStr::from_ptr(chars as *const c_char)
+        .to_string_lossy()
+        .into_owned();
+    raw_jni_call!(env, ReleaseStringUTFChars, string, chars);
+    Some(value)
+}
+
+#[cfg(test)]
+mod panic_hook_tests {
+    use super::*;
+
+    #[test]
+    fn class_names() {
+        assert_eq!(class_name("Ljava/lang/String;"), "java.lang.String");
+        assert_eq!(class_name("LTest;"), "Test");
+        assert_eq!(class_name("[I"), "[I");
+    }
+
+    #[test]
+    fn no_calls() {
+        assert!(java_panic_context().is_none());
+    }
+}
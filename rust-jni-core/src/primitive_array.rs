@@ -0,0 +1,695 @@
+use crate::convert;
+use crate::env::JniEnv;
+use crate::java_methods::FromObject;
+use crate::java_methods::JniSignature;
+use crate::jni_bool;
+use crate::object::Object;
+use crate::object_array::new_exception;
+use crate::result::JavaResult;
+use crate::token::{CallOutcome, NoException};
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
+use core::slice;
+use jni_sys;
+
+include!("call_jni_method.rs");
+
+/// Convert the bounds of an array region.
+///
+/// Returns an `ArrayIndexOutOfBoundsException` if they don't fit into a `jsize`, because such a
+/// region can't be inside of any array.
+fn region_to_jni<'env>(
+    env: &'env JniEnv<'env>,
+    token: &NoException<'env>,
+    start: usize,
+    length: usize,
+) -> JavaResult<'env, (jni_sys::jsize, jni_sys::jsize)> {
+    match (convert::to_jsize(start), convert::to_jsize(length)) {
+        (Ok(start), Ok(length)) => Ok((start, length)),
+        _ => Err(new_exception(
+            env,
+            token,
+            "java/lang/ArrayIndexOutOfBoundsException",
+        )?),
+    }
+}
+
+macro_rules! primitive_array {
+    (
+        $(#[$attribute:meta])*
+        $name:ident,
+        $element:ty,
+        $signature:expr,
+        $new:ident,
+        $get_elements:ident,
+        $release_elements:ident,
+        $get_region:ident,
+        $set_region:ident
+    ) => {
+        $(#[$attribute])*
+        #[derive(Debug, Clone)]
+        pub struct $name<'env> {
+            object: Object<'env>,
+        }
+
+        impl<'env> $name<'env> {
+            /// Create a new array of zeroes.
+            ///
+            /// Returns an
+            /// [`OutOfMemoryError`](https://docs.oracle.com/javase/10/docs/api/java/lang/OutOfMemoryError.html)
+            /// if the length doesn't fit into a `jsize`.
+            ///
+            /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newprimitivetypearray-routines)
+            pub fn new(
+                env: &'env JniEnv<'env>,
+                token: &NoException<'env>,
+                length: usize,
+            ) -> JavaResult<'env, $name<'env>> {
+                let length = match convert::to_jsize(length) {
+                    Ok(length) => length,
+                    // Java VMs throw the same error for arrays larger than they support.
+                    Err(_) => return Err(new_exception(env, token, "java/lang/OutOfMemoryError")?),
+                };
+                // Safe because arguments are ensured to be the correct by construction and
+                // because `New<Type>Array` throws an exception before returning `null`.
+                let raw_array =
+                    unsafe { call_nullable_jni_method!(env, token, $new, length) }?;
+                // Safe because the argument is a valid array reference.
+                Ok(unsafe { Self::from_object(Object::from_raw(env, raw_array)) })
+            }
+
+            /// Create a new array with a copy of the elements.
+            pub fn from_slice(
+                env: &'env JniEnv<'env>,
+                token: &NoException<'env>,
+                elements: &[$element],
+            ) -> JavaResult<'env, $name<'env>> {
+                let array = Self::new(env, token, elements.len())?;
+                array.set_region(token, 0, elements)?;
+                Ok(array)
+            }
+
+            /// Get the length of the array.
+            ///
+            /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getarraylength)
+            pub fn len(&self, _token: &NoException<'env>) -> usize {
+                // Safe because the argument is ensured to be the correct by construction.
+                let length = unsafe {
+                    call_jni_method!(
+                        self.object.env(),
+                        GetArrayLength,
+                        self.object.raw_object().as_ptr()
+                    )
+                };
+                convert::size_from_jni(length)
+            }
+
+            /// Copy elements of the array starting at `start` into the buffer, filling it.
+            ///
+            /// Returns an
+            /// [`ArrayIndexOutOfBoundsException`](https://docs.oracle.com/javase/10/docs/api/java/lang/ArrayIndexOutOfBoundsException.html)
+            /// if the region is out of bounds.
+            ///
+            /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getprimitivetypearrayregion-routines)
+            pub fn get_region(
+                &self,
+                token: &NoException<'env>,
+                start: usize,
+                buffer: &mut [$element],
+            ) -> JavaResult<'env, ()> {
+                let env = self.object.env();
+                let (start, length) = region_to_jni(env, token, start, buffer.len())?;
+                // Safe because arguments are ensured to be the correct by construction.
+                // `Get<Type>ArrayRegion` doesn't return a value, so a runtime exception
+                // check is needed.
+                token.with_owned(
+                    env,
+                    #[inline(always)]
+                    |_token| unsafe {
+                        CallOutcome::Unknown(call_jni_method!(
+                            env,
+                            $get_region,
+                            self.object.raw_object().as_ptr(),
+                            start,
+                            length,
+                            buffer.as_mut_ptr()
+                        ))
+                    },
+                )
+            }
+
+            /// Copy the elements into the array starting at `start`.
+            ///
+            /// Returns an
+            /// [`ArrayIndexOutOfBoundsException`](https://docs.oracle.com/javase/10/docs/api/java/lang/ArrayIndexOutOfBoundsException.html)
+            /// if the region is out of bounds.
+            ///
+            /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#setprimitivetypearrayregion-routines)
+            pub fn set_region(
+                &self,
+                token: &NoException<'env>,
+                start: usize,
+                elements: &[$element],
+            ) -> JavaResult<'env, ()> {
+                let env = self.object.env();
+                let (start, length) = region_to_jni(env, token, start, elements.len())?;
+                // Safe because arguments are ensured to be the correct by construction.
+                // `Set<Type>ArrayRegion` doesn't return a value, so a runtime exception
+                // check is needed.
+                token.with_owned(
+                    env,
+                    #[inline(always)]
+                    |_token| unsafe {
+                        CallOutcome::Unknown(call_jni_method!(
+                            env,
+                            $set_region,
+                            self.object.raw_object().as_ptr(),
+                            start,
+                            length,
+                            elements.as_ptr()
+                        ))
+                    },
+                )
+            }
+
+            /// Get read-only direct access to the array elements.
+            ///
+            /// The Java VM either pins the array or copies it's elements. The elements are
+            /// released without copying them back, use
+            /// [`elements_mut`](#method.elements_mut) to modify them.
+            ///
+            /// Unlike copying array regions, this avoids copies for large arrays when the Java VM
+            /// supports pinning.
+            ///
+            /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getprimitivetypearrayelements-routines)
+            pub fn elements<'a>(
+                &'a self,
+                token: &NoException<'env>,
+            ) -> JavaResult<'env, ArrayElementsGuard<'a, 'env, $element>> {
+                self.get_elements(token, ArrayReleaseMode::Abort)
+            }
+
+            /// Get mutable direct access to the array elements.
+            ///
+            /// The Java VM either pins the array or copies it's elements. In the latter case
+            /// changes are only visible in Java after the returned guard is dropped, and only if
+            /// it's [release mode](enum.ArrayReleaseMode.html) allows copying them back.
+            ///
+            /// Borrows the array mutably, so that there is only one mutable view of the elements
+            /// at a time:
+            /// ```compile_fail,E0499
+            /// # use rust_jni::*;
+            /// #
+            /// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+            #[doc = concat!("let mut array = ", stringify!($name), "::new(env, &token, 1)?;")]
+            /// let mut first = array.elements_mut(&token)?;
+            /// let mut second = array.elements_mut(&token)?;
+            /// first[0] = second[0];
+            /// # drop(first);
+            /// # Ok(token)
+            /// # }
+            /// ```
+            ///
+            /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getprimitivetypearrayelements-routines)
+            pub fn elements_mut<'a>(
+                &'a mut self,
+                token: &NoException<'env>,
+            ) -> JavaResult<'env, ArrayElementsMutGuard<'a, 'env, $element>> {
+                let guard = self.get_elements(token, ArrayReleaseMode::CopyBack)?;
+                Ok(ArrayElementsMutGuard { guard })
+            }
+
+            fn get_elements<'a>(
+                &'a self,
+                token: &NoException<'env>,
+                mode: ArrayReleaseMode,
+            ) -> JavaResult<'env, ArrayElementsGuard<'a, 'env, $element>> {
+                let env = self.object.env();
+                let length = self.len(token);
+                let mut is_copy = jni_sys::JNI_FALSE;
+                // Safe because arguments are ensured to be the correct by construction and
+                // because `Get<Type>ArrayElements` throws an exception before returning `null`.
+                let raw_elements = unsafe {
+                    call_nullable_jni_method!(
+                        env,
+                        token,
+                        $get_elements,
+                        self.object.raw_object().as_ptr(),
+                        &mut is_copy
+                    )
+                }?;
+                Ok(ArrayElementsGuard {
+                    array: &self.object,
+                    elements: raw_elements,
+                    length,
+                    is_copy: jni_bool::to_rust(is_copy),
+                    mode,
+                })
+            }
+        }
+
+        impl PrimitiveArrayElement for $element {
+            #[inline(always)]
+            unsafe fn __release_elements(
+                env: &JniEnv,
+                raw_array: jni_sys::jobject,
+                raw_elements: *mut Self,
+                mode: jni_sys::jint,
+            ) {
+                call_jni_method!(env, $release_elements, raw_array, raw_elements, mode)
+            }
+        }
+
+        #[doc = concat!("Allow [`", stringify!($name), "`](struct.", stringify!($name), ".html) to be used in place of an [`Object`](java/lang/struct.Object.html).")]
+        impl<'env> ::core::ops::Deref for $name<'env> {
+            type Target = Object<'env>;
+
+            #[inline(always)]
+            fn deref(&self) -> &Self::Target {
+                &self.object
+            }
+        }
+
+        impl<'env> AsRef<Object<'env>> for $name<'env> {
+            #[inline(always)]
+            fn as_ref(&self) -> &Object<'env> {
+                &self.object
+            }
+        }
+
+        impl<'a> Into<Object<'a>> for $name<'a> {
+            fn into(self) -> Object<'a> {
+                self.object
+            }
+        }
+
+        impl<'env> FromObject<'env> for $name<'env> {
+            #[inline(always)]
+            unsafe fn from_object(object: Object<'env>) -> Self {
+                Self { object }
+            }
+        }
+
+        impl JniSignature for $name<'_> {
+            #[inline(always)]
+            fn signature() -> &'static str {
+                $signature
+            }
+        }
+
+        #[doc = concat!("Allow comparing [`", stringify!($name), "`](struct.", stringify!($name), ".html)")]
+        /// to Java objects. Java objects are compared by-reference to preserve
+        /// original Java semantics. To compare objects by value, call the
+        /// [`value_eq`](java/lang/struct.Object.html#method.value_eq) method.
+        ///
+        /// Will panic if there is a pending exception in the current thread.
+        ///
+        /// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+        /// [`ref_eq`](java/lang/struct.Object.html#method.ref_eq) to comparing with `==`, because
+        /// the former checks for a pending exception in compile-time rather than the run-time.
+        impl<'env, T> PartialEq<T> for $name<'env>
+        where
+            T: AsRef<Object<'env>>,
+        {
+            fn eq(&self, other: &T) -> bool {
+                self.as_ref().eq(other.as_ref())
+            }
+        }
+    };
+}
+
+primitive_array!(
+    /// A type representing a Java `boolean[]` array.
+    ///
+    /// Elements are JNI booleans: `0` for `false` and `1` for `true`.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#array-operations)
+    JavaBooleanArray,
+    jni_sys::jboolean,
+    "[Z",
+    NewBooleanArray,
+    GetBooleanArrayElements,
+    ReleaseBooleanArrayElements,
+    GetBooleanArrayRegion,
+    SetBooleanArrayRegion
+);
+
+primitive_array!(
+    /// A type representing a Java `byte[]` array.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#array-operations)
+    JavaByteArray,
+    i8,
+    "[B",
+    NewByteArray,
+    GetByteArrayElements,
+    ReleaseByteArrayElements,
+    GetByteArrayRegion,
+    SetByteArrayRegion
+);
+
+primitive_array!(
+    /// A type representing a Java `char[]` array.
+    ///
+    /// Elements are UTF-16 code units.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#array-operations)
+    JavaCharArray,
+    u16,
+    "[C",
+    NewCharArray,
+    GetCharArrayElements,
+    ReleaseCharArrayElements,
+    GetCharArrayRegion,
+    SetCharArrayRegion
+);
+
+primitive_array!(
+    /// A type representing a Java `short[]` array.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#array-operations)
+    JavaShortArray,
+    i16,
+    "[S",
+    NewShortArray,
+    GetShortArrayElements,
+    ReleaseShortArrayElements,
+    GetShortArrayRegion,
+    SetShortArrayRegion
+);
+
+primitive_array!(
+    /// A type representing a Java `int[]` array.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#array-operations)
+    ///
+    /// # Example
+    /// ```
+    /// # use rust_jni::*;
+    /// #
+    /// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+    /// let mut array = JavaIntArray::new(env, &token, 3)?;
+    /// {
+    ///     let mut elements = array.elements_mut(&token)?;
+    ///     elements.copy_from_slice(&[1, 2, 3]);
+    /// }
+    /// assert_eq!(&*array.elements(&token)?, &[1, 2, 3]);
+    /// # Ok(token)
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     let init_arguments = InitArguments::default();
+    /// #     let vm = JavaVM::create(&init_arguments).unwrap();
+    /// #     let _ = vm.with_attached(
+    /// #        &AttachArguments::new(init_arguments.version()),
+    /// #        |env: &JniEnv, token: NoException| {
+    /// #            ((), jni_main(env, token).unwrap())
+    /// #        },
+    /// #     );
+    /// # }
+    /// ```
+    JavaIntArray,
+    i32,
+    "[I",
+    NewIntArray,
+    GetIntArrayElements,
+    ReleaseIntArrayElements,
+    GetIntArrayRegion,
+    SetIntArrayRegion
+);
+
+primitive_array!(
+    /// A type representing a Java `long[]` array.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#array-operations)
+    JavaLongArray,
+    i64,
+    "[J",
+    NewLongArray,
+    GetLongArrayElements,
+    ReleaseLongArrayElements,
+    GetLongArrayRegion,
+    SetLongArrayRegion
+);
+
+primitive_array!(
+    /// A type representing a Java `float[]` array.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#array-operations)
+    JavaFloatArray,
+    f32,
+    "[F",
+    NewFloatArray,
+    GetFloatArrayElements,
+    ReleaseFloatArrayElements,
+    GetFloatArrayRegion,
+    SetFloatArrayRegion
+);
+
+primitive_array!(
+    /// A type representing a Java `double[]` array.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#array-operations)
+    JavaDoubleArray,
+    f64,
+    "[D",
+    NewDoubleArray,
+    GetDoubleArrayElements,
+    ReleaseDoubleArrayElements,
+    GetDoubleArrayRegion,
+    SetDoubleArrayRegion
+);
+
+/// What to do with the elements of a Java array when releasing them.
+///
+/// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#releaseprimitivetypearrayelements-routines)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArrayReleaseMode {
+    /// Copy the elements back into the array. This is the default.
+    CopyBack,
+    /// Discard the changes made to the elements if they were copied.
+    ///
+    /// Changes made to pinned arrays are still visible in Java.
+    Abort,
+}
+
+impl ArrayReleaseMode {
+    fn to_jni(self) -> jni_sys::jint {
+        match self {
+            ArrayReleaseMode::CopyBack => 0,
+            ArrayReleaseMode::Abort => jni_sys::JNI_ABORT,
+        }
+    }
+}
+
+#[cfg(test)]
+mod array_release_mode_tests {
+    use super::*;
+
+    #[test]
+    fn to_jni() {
+        assert_eq!(ArrayReleaseMode::CopyBack.to_jni(), 0);
+        assert_eq!(ArrayReleaseMode::Abort.to_jni(), jni_sys::JNI_ABORT);
+    }
+}
+
+/// A type of Java primitive array elements.
+///
+/// Implemented for the element types of all Java primitive arrays.
+pub trait PrimitiveArrayElement: Copy {
+    #[doc(hidden)]
+    unsafe fn __release_elements(
+        env: &JniEnv,
+        raw_array: jni_sys::jobject,
+        raw_elements: *mut Self,
+        mode: jni_sys::jint,
+    );
+}
+
+/// Read-only direct access to the elements of a Java primitive array.
+///
+/// Dereferences to a slice of the elements. The elements are released without copying them
+/// back when the guard is dropped.
+///
+/// See [`JavaIntArray::elements`](struct.JavaIntArray.html#method.elements).
+pub struct ArrayElementsGuard<'a, 'env, T: PrimitiveArrayElement> {
+    array: &'a Object<'env>,
+    elements: NonNull<T>,
+    length: usize,
+    is_copy: bool,
+    mode: ArrayReleaseMode,
+}
+
+impl<'a, 'env, T: PrimitiveArrayElement> ArrayElementsGuard<'a, 'env, T> {
+    /// Whether the Java VM copied the elements instead of pinning the array.
+    pub fn is_copy(&self) -> bool {
+        self.is_copy
+    }
+}
+
+impl<'a, 'env, T: PrimitiveArrayElement> Deref for ArrayElementsGuard<'a, 'env, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        // Safe because the elements are valid until released.
+        unsafe { slice::from_raw_parts(self.elements.as_ptr(), self.length) }
+    }
+}
+
+/// Mutable direct access to the elements of a Java primitive array.
+///
+/// Dereferences to a mutable slice of the elements. The elements are released when the guard
+/// is dropped according to it's [release mode](enum.ArrayReleaseMode.html).
+///
+/// See [`JavaIntArray::elements_mut`](struct.JavaIntArray.html#method.elements_mut).
+pub struct ArrayElementsMutGuard<'a, 'env, T: PrimitiveArrayElement> {
+    guard: ArrayElementsGuard<'a, 'env, T>,
+}
+
+impl<'a, 'env, T: PrimitiveArrayElement> ArrayElementsMutGuard<'a, 'env, T> {
+    /// Whether the Java VM copied the elements instead of pinning the array.
+    pub fn is_copy(&self) -> bool {
+        self.guard.is_copy
+    }
+
+    /// Get the mode the elements will be released with.
+    pub fn release_mode(&self) -> ArrayReleaseMode {
+        self.guard.mode
+    }
+
+    /// Set the mode the elements will be released with.
+    pub fn set_release_mode(&mut self, mode: ArrayReleaseMode) {
+        self.guard.mode = mode;
+    }
+
+    /// Copy the elements back into the array without releasing them.
+    ///
+    /// Does nothing if the array is pinned.
+    pub fn commit(&self, _token: &NoException<'env>) {
+        // Safe because arguments are ensured to be the correct by construction.
+        unsafe {
+            T::__release_elements(
+                self.guard.array.env(),
+                self.guard.array.raw_object().as_ptr(),
+                self.guard.elements.as_ptr(),
+                jni_sys::JNI_COMMIT,
+            );
+        }
+    }
+}
+
+impl<'a, 'env, T: PrimitiveArrayElement> Deref for ArrayElementsMutGuard<'a, 'env, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.guard
+    }
+}
+
+impl<'a, 'env, T: PrimitiveArrayElement> DerefMut for ArrayElementsMutGuard<'a, 'env, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // Safe because the elements are valid until released and the guard was created from
+        // a mutable borrow of the array, so there are no other views of the elements.
+        unsafe { slice::from_raw_parts_mut(self.guard.elements.as_ptr(), self.guard.length) }
+    }
+}
+
+/// Release the elements.
+///
+/// Releasing array elements is allowed when there is a pending exception, so this
+/// doesn't need a token.
+impl<'a, 'env, T: PrimitiveArrayElement> Drop for ArrayElementsGuard<'a, 'env, T> {
+    fn drop(&mut self) {
+        // Safe because arguments are ensured to be the correct by construction.
+        unsafe {
+            T::__release_elements(
+                self.array.env(),
+                self.array.raw_object().as_ptr(),
+                self.elements.as_ptr(),
+                self.mode.to_jni(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod array_elements_guard_tests {
+    use super::*;
+    use crate::vm::JavaVMRef;
+    use core::mem;
+    use mockall::*;
+    use serial_test_derive::serial;
+
+    generate_jni_env_mock!(jni_mock);
+
+    #[test]
+    #[serial]
+    fn drop() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let mut elements = [1, 2];
+        let elements_ptr_usize = elements.as_mut_ptr() as usize;
+        // Both the elements and the array are released without checking for a pending exception.
+        let mut sequence = Sequence::new();
+        let release_mock = jni_mock::release_int_array_elements_context();
+        release_mock
+            .expect()
+            .times(1)
+            .withf(move |_, array, elements, mode| {
+                *array == 0x1234 as jni_sys::jobject
+                    && *elements == elements_ptr_usize as *mut jni_sys::jint
+                    && *mode == jni_sys::JNI_ABORT
+            })
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let delete_local_ref_mock = jni_mock::delete_local_ref_context();
+        delete_local_ref_mock
+            .expect()
+            .times(1)
+            .withf(|_, object| *object == 0x1234 as jni_sys::jobject)
+            .return_const(())
+            .in_sequence(&mut sequence);
+        let vm = JavaVMRef::test(0x5678 as *mut jni_sys::JavaVM);
+        let env = JniEnv::test(&vm, raw_env_ptr);
+        let array = unsafe { Object::from_raw(&env, NonNull::new(0x1234 as _).unwrap()) };
+        let guard = ArrayElementsGuard {
+            array: &array,
+            elements: NonNull::new(elements.as_mut_ptr()).unwrap(),
+            length: elements.len(),
+            is_copy: true,
+            mode: ArrayReleaseMode::Abort,
+        };
+        assert_eq!(&*guard, &[1, 2]);
+        mem::drop(guard);
+        mem::drop(array);
+    }
+}
+
+impl<'a, 'env, T: PrimitiveArrayElement + fmt::Debug> fmt::Debug
+    for ArrayElementsGuard<'a, 'env, T>
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("ArrayElementsGuard")
+            .field("array", &self.array)
+            .field("elements", &self.deref())
+            .field("is_copy", &self.is_copy)
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
+impl<'a, 'env, T: PrimitiveArrayElement + fmt::Debug> fmt::Debug
+    for ArrayElementsMutGuard<'a, 'env, T>
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("ArrayElementsMutGuard")
+            .field("array", &self.guard.array)
+            .field("elements", &self.deref())
+            .field("is_copy", &self.guard.is_copy)
+            .field("mode", &self.guard.mode)
+            .finish()
+    }
+}
@@ -0,0 +1,139 @@
+use crate::env::JniEnv;
+use crate::java_class::JavaClassRef;
+use crate::java_class::JavaExceptionClass;
+use crate::java_class::NullableJavaClassExt;
+use crate::throwable::Throwable;
+use crate::token::NoException;
+
+/// A type that represents a result of a Java method call. A Java method can either return
+/// a result or throw a
+/// [`Throwable`](https://docs.oracle.com/javase/10/docs/api/java/lang/Throwable.html)
+/// in which case it will be captured in a [`Throwable`](java/lang/struct.Throwable.html) value.
+pub type JavaResult<'env, T> = Result<T, Throwable<'env>>;
+
+/// Add nullable object helper methods from [`NullableJavaClassExt`](trait.NullableJavaClassExt.html)
+/// to [`JavaResult<Option<T: JavaClassRef>>`](type.JavaResult.html).
+impl<'a, R> NullableJavaClassExt<'a, R> for JavaResult<'a, Option<R>>
+where
+    R: JavaClassRef<'a>,
+{
+    #[inline(always)]
+    fn or_npe(self, env: &'a JniEnv<'a>, token: &NoException<'a>) -> JavaResult<'a, R> {
+        let result = self?;
+        result.or_npe(env, token)
+    }
+}
+
+/// Extension trait that adds combinators for chaining Java calls to
+/// [`JavaResult`](type.JavaResult.html).
+///
+/// The callbacks get the [`NoException`](struct.NoException.html) token passed in, so deep
+/// call chains don't need to name the token in every closure.
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::Object;
+/// #
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let class_name = Object::new(env, &token)
+///     .and_then_java(&token, |object, token| object.to_string(token))
+///     .or_npe(env, &token)
+///     .map_java(&token, |string, token| string.as_string(token))?;
+/// assert!(class_name.starts_with("java.lang.Object@"));
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+pub trait JavaResultExt<'a, T> {
+    /// Map the result value, leaving a thrown exception untouched.
+    fn map_java<U>(
+        self,
+        token: &NoException<'a>,
+        callback: impl FnOnce(T, &NoException<'a>) -> U,
+    ) -> JavaResult<'a, U>;
+
+    /// Call a Java method on the result value, leaving a thrown exception untouched.
+    fn and_then_java<U>(
+        self,
+        token: &NoException<'a>,
+        callback: impl FnOnce(T, &NoException<'a>) -> JavaResult<'a, U>,
+    ) -> JavaResult<'a, U>;
+
+    /// Catch a thrown exception of the class `E`, returning it as a typed error in the inner
+    /// result. Exceptions of other classes are left untouched.
+    ///
+    /// This encodes Java's checked exceptions into Rust signatures: expected exceptions
+    /// have to be handled by the caller, while unexpected ones propagate with `?`.
+    ///
+    /// Example:
+    /// ```
+    /// # use rust_jni::*;
+    /// # use rust_jni::java::lang::{NullPointerException, String};
+    /// #
+    /// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+    /// let result = None::<String>
+    ///     .or_npe(env, &token)
+    ///     .catching::<NullPointerException>(&token)?;
+    /// assert!(result.is_err());
+    /// # Ok(token)
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     let init_arguments = InitArguments::default();
+    /// #     let vm = JavaVM::create(&init_arguments).unwrap();
+    /// #     let _ = vm.with_attached(
+    /// #        &AttachArguments::new(init_arguments.version()),
+    /// #        |env: &JniEnv, token: NoException| {
+    /// #            ((), jni_main(env, token).unwrap())
+    /// #        },
+    /// #     );
+    /// # }
+    /// ```
+    fn catching<E>(self, token: &NoException<'a>) -> JavaResult<'a, Result<T, E>>
+    where
+        E: JavaExceptionClass<'a>;
+}
+
+impl<'a, T> JavaResultExt<'a, T> for JavaResult<'a, T> {
+    #[inline(always)]
+    fn map_java<U>(
+        self,
+        token: &NoException<'a>,
+        callback: impl FnOnce(T, &NoException<'a>) -> U,
+    ) -> JavaResult<'a, U> {
+        self.map(|value| callback(value, token))
+    }
+
+    #[inline(always)]
+    fn and_then_java<U>(
+        self,
+        token: &NoException<'a>,
+        callback: impl FnOnce(T, &NoException<'a>) -> JavaResult<'a, U>,
+    ) -> JavaResult<'a, U> {
+        self.and_then(|value| callback(value, token))
+    }
+
+    fn catching<E>(self, token: &NoException<'a>) -> JavaResult<'a, Result<T, E>>
+    where
+        E: JavaExceptionClass<'a>,
+    {
+        match self {
+            Ok(value) => Ok(Ok(value)),
+            Err(exception) => match exception.downcast::<E>(token)? {
+                Ok(exception) => Ok(Err(exception)),
+                Err(exception) => Err(exception),
+            },
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Scoped tasks running on threads attached to a Java VM.
+//!
+//! Only compiled with the `std` feature.
+
+use crate::attach_arguments::AttachArguments;
+use crate::env::JniEnv;
+use crate::error::JniError;
+use crate::token::NoException;
+use crate::vm::JavaVM;
+use std::thread;
+
+/// A scope for spawning tasks on threads attached to a Java VM.
+///
+/// Created by [`scope`](fn.scope.html).
+pub struct Scope<'scope, 'env: 'scope> {
+    scope: &'scope thread::Scope<'scope, 'env>,
+    vm: &'env JavaVM,
+    arguments: &'env AttachArguments,
+}
+
+impl<'scope, 'env> Scope<'scope, 'env> {
+    /// Spawn a task on a new thread attached to the Java VM.
+    ///
+    /// The task gets a [`JniEnv`](struct.JniEnv.html) and a
+    /// [`NoException`](struct.NoException.html) token, same as in
+    /// [`JavaVM::with_attached`](struct.JavaVM.html#method.with_attached). The thread is
+    /// detached when the task returns.
+    ///
+    /// Unlike threads spawned with
+    /// [`std::thread::spawn`](https://doc.rust-lang.org/std/thread/fn.spawn.html), the task can
+    /// borrow values that outlive the scope.
+    pub fn spawn_attached<R: Send + 'scope>(
+        &self,
+        task: impl for<'token> FnOnce(
+                &'token JniEnv<'token>,
+                NoException<'token>,
+            ) -> (R, NoException<'token>)
+            + Send
+            + 'scope,
+    ) -> ScopedTask<'scope, R> {
+        let vm = self.vm;
+        let arguments = self.arguments;
+        ScopedTask {
+            handle: self.scope.spawn(move || vm.with_attached(arguments, task)),
+        }
+    }
+}
+
+/// A handle of a task spawned with [`Scope::spawn_attached`](struct.Scope.html#method.spawn_attached).
+pub struct ScopedTask<'scope, R> {
+    handle: thread::ScopedJoinHandle<'scope, Result<R, JniError>>,
+}
+
+impl<'scope, R> ScopedTask<'scope, R> {
+    /// Wait for the task to finish and get it's result.
+    ///
+    /// Returns an `Err` if the task panicked and an `Ok` with a
+    /// [`JniError`](enum.JniError.html) if the thread could not be attached or detached.
+    pub fn join(self) -> thread::Result<Result<R, JniError>> {
+        self.handle.join()
+    }
+
+    /// Check if the task has finished running.
+    pub fn is_finished(&self) -> bool {
+        self.handle.is_finished()
+    }
+}
+
+/// Run a closure that spawns tasks on threads attached to the Java VM and wait for all of them
+/// to finish.
+///
+/// All tasks spawned with [`Scope::spawn_attached`](struct.Scope.html#method.spawn_attached)
+/// are joined before this function returns, so no [`JniEnv`](struct.JniEnv.html) of the
+/// spawned threads outlives the scope. Threads are attached with `arguments`.
+///
+/// Like [`std::thread::scope`](https://doc.rust-lang.org/std/thread/fn.scope.html), this
+/// function panics if any of the tasks that were not joined manually panicked.
+///
+/// Only available with the `std` feature.
+///
+/// # Example
+/// ```
+/// use rust_jni::java::lang::String;
+/// use rust_jni::*;
+///
+/// let init_arguments = InitArguments::default();
+/// let vm = JavaVM::create(&init_arguments).unwrap();
+/// let arguments = AttachArguments::new(init_arguments.version());
+/// let words = ["one", "two", "three"];
+/// let lengths = rust_jni::scope(&vm, &arguments, |scope| {
+///     let tasks = words
+///         .iter()
+///         .map(|word| {
+///             scope.spawn_attached(move |env, token| {
+///                 let string = String::new(env, &token, word).unwrap();
+///                 (string.len(&token), token)
+///             })
+///         })
+///         .collect::<Vec<_>>();
+///     tasks
+///         .into_iter()
+///         .map(|task| task.join().unwrap().unwrap())
+///         .collect::<Vec<_>>()
+/// });
+/// assert_eq!(lengths, [3, 3, 5]);
+/// ```
+pub fn scope<'env, T>(
+    vm: &'env JavaVM,
+    arguments: &'env AttachArguments,
+    closure: impl for<'scope> FnOnce(&Scope<'scope, 'env>) -> T,
+) -> T {
+    thread::scope(|scope| {
+        closure(&Scope {
+            scope,
+            vm,
+            arguments,
+        })
+    })
+}
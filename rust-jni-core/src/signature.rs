@@ -0,0 +1,427 @@
+//! Parsing and formatting of JNI type signatures and method descriptors.
+//!
+//! JNI identifies types with signatures, like `I` for `int` or `Ljava/lang/String;` for
+//! `java.lang.String`, and methods with descriptors, like `(ILjava/lang/String;)V`.
+//! [`JavaType`](enum.JavaType.html) and [`MethodSignature`](struct.MethodSignature.html) are
+//! parsed representations of them, which helps building dynamic call layers and validating
+//! signatures before passing them to Java.
+//!
+//! Both types parse with `parse` or [`str::parse`](https://doc.rust-lang.org/std/primitive.str.html#method.parse)
+//! and format back into the JNI form with
+//! [`Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html).
+//!
+//! [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/types.html#type-signatures)
+
+use crate::error::SignatureParseError;
+use crate::java_methods::JniSignature;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+/// A Java type, parsed from a JNI type signature.
+///
+/// Example:
+/// ```
+/// # use rust_jni::signature::JavaType;
+/// # use rust_jni::java::lang::String;
+/// #
+/// let java_type: JavaType = "[Ljava/lang/String;".parse().unwrap();
+/// assert_eq!(
+///     java_type,
+///     JavaType::Array(Box::new(JavaType::Object("java/lang/String".to_owned())))
+/// );
+/// assert_eq!(java_type.to_string(), "[Ljava/lang/String;");
+/// assert_eq!(java_type.java_name(), "java.lang.String[]");
+/// assert_eq!(JavaType::of::<String>(), JavaType::Object("java/lang/String".to_owned()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JavaType {
+    /// `boolean`, with the signature `Z`.
+    Boolean,
+    /// `byte`, with the signature `B`.
+    Byte,
+    /// `char`, with the signature `C`.
+    Char,
+    /// `short`, with the signature `S`.
+    Short,
+    /// `int`, with the signature `I`.
+    Int,
+    /// `long`, with the signature `J`.
+    Long,
+    /// `float`, with the signature `F`.
+    Float,
+    /// `double`, with the signature `D`.
+    Double,
+    /// `void`, with the signature `V`. Only valid as a method result type.
+    Void,
+    /// A class or an interface with the binary name in the internal form, like
+    /// `java/lang/String`.
+    Object(String),
+    /// An array of the element type.
+    Array(Box<JavaType>),
+}
+
+impl JavaType {
+    /// Parse a JNI type signature, like `I` or `Ljava/lang/String;`.
+    ///
+    /// `V` is not a valid type signature, as `void` can only be a method result type.
+    pub fn parse(signature: &str) -> Result<Self, SignatureParseError> {
+        let mut parser = Parser::new(signature);
+        let java_type = parser.parse_type()?;
+        parser.expect_end()?;
+        Ok(java_type)
+    }
+
+    /// Get the type of a Java class wrapper or a primitive type.
+    ///
+    /// Will panic if [`JniSignature::signature`](../trait.JniSignature.html#tymethod.signature)
+    /// of the type is invalid, which is a bug in it's implementation.
+    pub fn of<T: JniSignature + ?Sized>() -> Self {
+        let signature = T::signature();
+        let mut parser = Parser::new(signature);
+        parser
+            .parse_result_type()
+            .and_then(|java_type| parser.expect_end().map(|_| java_type))
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Check if the type is a primitive type, including `void`.
+    pub fn is_primitive(&self) -> bool {
+        !matches!(self, JavaType::Object(_) | JavaType::Array(_))
+    }
+
+    /// Get the name of the type as it is written in Java source code, like `int`
+    /// or `java.lang.String[]`.
+    pub fn java_name(&self) -> String {
+        match self {
+            JavaType::Boolean => "boolean".to_string(),
+            JavaType::Byte => "byte".to_string(),
+            JavaType::Char => "char".to_string(),
+            JavaType::Short => "short".to_string(),
+            JavaType::Int => "int".to_string(),
+            JavaType::Long => "long".to_string(),
+            JavaType::Float => "float".to_string(),
+            JavaType::Double => "double".to_string(),
+            JavaType::Void => "void".to_string(),
+            JavaType::Object(name) => name.replace('/', "."),
+            JavaType::Array(element) => format!("{}[]", element.java_name()),
+        }
+    }
+}
+
+impl FromStr for JavaType {
+    type Err = SignatureParseError;
+
+    fn from_str(signature: &str) -> Result<Self, Self::Err> {
+        JavaType::parse(signature)
+    }
+}
+
+/// Format the type as a JNI type signature.
+impl fmt::Display for JavaType {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JavaType::Boolean => write!(formatter, "Z"),
+            JavaType::Byte => write!(formatter, "B"),
+            JavaType::Char => write!(formatter, "C"),
+            JavaType::Short => write!(formatter, "S"),
+            JavaType::Int => write!(formatter, "I"),
+            JavaType::Long => write!(formatter, "J"),
+            JavaType::Float => write!(formatter, "F"),
+            JavaType::Double => write!(formatter, "D"),
+            JavaType::Void => write!(formatter, "V"),
+            JavaType::Object(name) => write!(formatter, "L{};", name),
+            JavaType::Array(element) => write!(formatter, "[{}", element),
+        }
+    }
+}
+
+/// A Java method signature, parsed from a JNI method descriptor.
+///
+/// Example:
+/// ```
+/// # use rust_jni::signature::{JavaType, MethodSignature};
+/// #
+/// let signature: MethodSignature = "(I[JLjava/lang/String;)V".parse().unwrap();
+/// assert_eq!(
+///     signature,
+///     MethodSignature {
+///         args: vec![
+///             JavaType::Int,
+///             JavaType::Array(Box::new(JavaType::Long)),
+///             JavaType::Object("java/lang/String".to_owned()),
+///         ],
+///         ret: JavaType::Void,
+///     }
+/// );
+/// assert_eq!(signature.to_string(), "(I[JLjava/lang/String;)V");
+/// assert!(MethodSignature::parse("(V)I").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MethodSignature {
+    /// Types of the method arguments.
+    pub args: Vec<JavaType>,
+    /// The result type of the method, [`JavaType::Void`](enum.JavaType.html#variant.Void)
+    /// for methods that don't return a value.
+    pub ret: JavaType,
+}
+
+impl MethodSignature {
+    /// Parse a JNI method descriptor, like `(ILjava/lang/String;)V`.
+    pub fn parse(descriptor: &str) -> Result<Self, SignatureParseError> {
+        let mut parser = Parser::new(descriptor);
+        parser.expect('(')?;
+        let mut args = Vec::new();
+        while !parser.consume(')') {
+            args.push(parser.parse_type()?);
+        }
+        let ret = parser.parse_result_type()?;
+        parser.expect_end()?;
+        Ok(MethodSignature { args, ret })
+    }
+}
+
+impl FromStr for MethodSignature {
+    type Err = SignatureParseError;
+
+    fn from_str(descriptor: &str) -> Result<Self, Self::Err> {
+        MethodSignature::parse(descriptor)
+    }
+}
+
+/// Format the signature as a JNI method descriptor.
+impl fmt::Display for MethodSignature {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "(")?;
+        for argument in &self.args {
+            write!(formatter, "{}", argument)?;
+        }
+        write!(formatter, "){}", self.ret)
+    }
+}
+
+struct Parser<'a> {
+    signature: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(signature: &'a str) -> Self {
+        Parser {
+            signature,
+            position: 0,
+        }
+    }
+
+    fn error(&self) -> SignatureParseError {
+        SignatureParseError {
+            signature: self.signature.to_string(),
+            position: self.position,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.signature[self.position..].chars().next()
+    }
+
+    fn consume(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += expected.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), SignatureParseError> {
+        if self.consume(expected) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn expect_end(&self) -> Result<(), SignatureParseError> {
+        if self.position == self.signature.len() {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn parse_result_type(&mut self) -> Result<JavaType, SignatureParseError> {
+        if self.consume('V') {
+            Ok(JavaType::Void)
+        } else {
+            self.parse_type()
+        }
+    }
+
+    fn parse_type(&mut self) -> Result<JavaType, SignatureParseError> {
+        let java_type = match self.peek() {
+            Some('Z') => JavaType::Boolean,
+            Some('B') => JavaType::Byte,
+            Some('C') => JavaType::Char,
+            Some('S') => JavaType::Short,
+            Some('I') => JavaType::Int,
+            Some('J') => JavaType::Long,
+            Some('F') => JavaType::Float,
+            Some('D') => JavaType::Double,
+            Some('[') => {
+                self.position += 1;
+                return Ok(JavaType::Array(Box::new(self.parse_type()?)));
+            }
+            Some('L') => {
+                let start = self.position + 1;
+                let length = self.signature[start..]
+                    .find(';')
+                    .ok_or_else(|| self.error())?;
+                let name = &self.signature[start..start + length];
+                if name.is_empty() || name.contains(|c| matches!(c, '.' | '[' | '(' | ')')) {
+                    return Err(self.error());
+                }
+                self.position = start + length + 1;
+                return Ok(JavaType::Object(name.to_string()));
+            }
+            _ => return Err(self.error()),
+        };
+        self.position += 1;
+        Ok(java_type)
+    }
+}
+
+#[cfg(test)]
+mod java_type_tests {
+    use super::*;
+
+    #[test]
+    fn primitives() {
+        for (signature, java_type) in &[
+            ("Z", JavaType::Boolean),
+            ("B", JavaType::Byte),
+            ("C", JavaType::Char),
+            ("S", JavaType::Short),
+            ("I", JavaType::Int),
+            ("J", JavaType::Long),
+            ("F", JavaType::Float),
+            ("D", JavaType::Double),
+        ] {
+            assert_eq!(JavaType::parse(signature).unwrap(), *java_type);
+            assert_eq!(java_type.to_string(), *signature);
+            assert!(java_type.is_primitive());
+        }
+    }
+
+    #[test]
+    fn object() {
+        let java_type = JavaType::parse("La/b/Test$Inner;").unwrap();
+        assert_eq!(java_type, JavaType::Object("a/b/Test$Inner".to_string()));
+        assert_eq!(java_type.to_string(), "La/b/Test$Inner;");
+        assert_eq!(java_type.java_name(), "a.b.Test$Inner");
+        assert!(!java_type.is_primitive());
+    }
+
+    #[test]
+    fn array() {
+        let java_type = JavaType::parse("[[I").unwrap();
+        assert_eq!(
+            java_type,
+            JavaType::Array(Box::new(JavaType::Array(Box::new(JavaType::Int))))
+        );
+        assert_eq!(java_type.to_string(), "[[I");
+        assert_eq!(java_type.java_name(), "int[][]");
+    }
+
+    #[test]
+    fn of() {
+        assert_eq!(JavaType::of::<i32>(), JavaType::Int);
+        assert_eq!(JavaType::of::<()>(), JavaType::Void);
+        assert_eq!(
+            JavaType::of::<crate::object::Object>(),
+            JavaType::Object("java/lang/Object".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        for (signature, position) in &[
+            ("", 0),
+            ("V", 0),
+            ("X", 0),
+            ("II", 1),
+            ("[", 1),
+            ("Ljava/lang/String", 0),
+            ("L;", 0),
+            ("Ljava.lang.String;", 0),
+        ] {
+            assert_eq!(
+                JavaType::parse(signature),
+                Err(SignatureParseError {
+                    signature: signature.to_string(),
+                    position: *position,
+                })
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod method_signature_tests {
+    use super::*;
+
+    #[test]
+    fn no_arguments() {
+        let signature = MethodSignature::parse("()V").unwrap();
+        assert_eq!(
+            signature,
+            MethodSignature {
+                args: vec![],
+                ret: JavaType::Void,
+            }
+        );
+        assert_eq!(signature.to_string(), "()V");
+    }
+
+    #[test]
+    fn arguments() {
+        let descriptor = "(Z[Ljava/lang/Object;J)Ljava/lang/String;";
+        let signature: MethodSignature = descriptor.parse().unwrap();
+        assert_eq!(
+            signature,
+            MethodSignature {
+                args: vec![
+                    JavaType::Boolean,
+                    JavaType::Array(Box::new(JavaType::Object("java/lang/Object".to_string()))),
+                    JavaType::Long,
+                ],
+                ret: JavaType::Object("java/lang/String".to_string()),
+            }
+        );
+        assert_eq!(signature.to_string(), descriptor);
+    }
+
+    #[test]
+    fn invalid() {
+        for (descriptor, position) in &[
+            ("", 0),
+            ("V", 0),
+            ("(", 1),
+            ("()", 2),
+            ("(V)I", 1),
+            ("(I)", 3),
+            ("()II", 3),
+            ("()V\0", 3),
+        ] {
+            assert_eq!(
+                MethodSignature::parse(descriptor),
+                Err(SignatureParseError {
+                    signature: descriptor.to_string(),
+                    position: *position,
+                })
+            );
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! A global pool of Java method signatures.
+//!
+//! Signatures are computed from the Rust types of arguments and the result, which requires
+//! formatting a string on every call. The pool computes each signature only once and keeps it
+//! for the lifetime of the program.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+struct Entry {
+    key: usize,
+    signature: &'static str,
+    next: *mut Entry,
+}
+
+/// Lock-free lists of signatures. Entries are never removed, so they can be read without
+/// synchronization once published.
+static BUCKETS: [AtomicPtr<Entry>; 64] = [const { AtomicPtr::new(ptr::null_mut()) }; 64];
+
+fn find(mut entry: *mut Entry, key: usize) -> Option<&'static str> {
+    while !entry.is_null() {
+        // Safe because published entries are never freed or modified.
+        let current = unsafe { &*entry };
+        if current.key == key {
+            return Some(current.signature);
+        }
+        entry = current.next;
+    }
+    None
+}
+
+/// Get the signature computed by `compute`, calling it only the first time.
+///
+/// The function pointer is used as the key, so it must always return the same signature.
+/// Different functions can only have the same address if they have identical code, in which
+/// case they also return the same signature.
+pub(crate) fn interned_signature(compute: fn() -> String) -> &'static str {
+    let key = compute as usize;
+    // Function addresses are aligned, so the lowest bits are not used for the bucket index.
+    let bucket = &BUCKETS[(key >> 4) % BUCKETS.len()];
+    let mut head = bucket.load(Ordering::Acquire);
+    if let Some(signature) = find(head, key) {
+        return signature;
+    }
+
+    let signature: &'static str = Box::leak(compute().into_boxed_str());
+    let entry = Box::into_raw(Box::new(Entry {
+        key,
+        signature,
+        next: head,
+    }));
+    loop {
+        match bucket.compare_exchange(head, entry, Ordering::AcqRel, Ordering::Acquire) {
+            Ok(_) => return signature,
+            Err(new_head) => {
+                if let Some(existing) = find(new_head, key) {
+                    // Another thread has published the same signature first.
+                    // Safe because the entry and the string were never published.
+                    unsafe {
+                        drop(Box::from_raw(entry));
+                        drop(Box::from_raw(signature as *const str as *mut str));
+                    }
+                    return existing;
+                }
+                // Safe because the entry is not published yet.
+                unsafe { (*entry).next = new_head };
+                head = new_head;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod interned_signature_tests {
+    use super::*;
+    use core::sync::atomic::AtomicUsize;
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    fn signature1() -> String {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+        "(I)J\0".into()
+    }
+
+    fn signature2() -> String {
+        "()V\0".into()
+    }
+
+    #[test]
+    fn interned() {
+        let first = interned_signature(signature1);
+        let second = interned_signature(signature1);
+        assert_eq!(first, "(I)J\0");
+        assert!(ptr::eq(first, second));
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+        assert_eq!(interned_signature(signature2), "()V\0");
+    }
+
+    #[test]
+    fn concurrent() {
+        fn signature() -> String {
+            "(Ljava/lang/Object;)Z\0".into()
+        }
+
+        let threads = (0..8)
+            .map(|_| {
+                std::thread::spawn(|| {
+                    interned_signature(signature) as *const str as *const u8 as usize
+                })
+            })
+            .collect::<Vec<_>>();
+        let addresses = threads
+            .into_iter()
+            .map(|thread| thread.join().unwrap())
+            .collect::<Vec<_>>();
+        assert!(addresses.iter().all(|address| *address == addresses[0]));
+    }
+}
@@ -0,0 +1,79 @@
+//! Counters of JNI resources held by [`rust-jni`](index.html) wrappers.
+//!
+//! Only compiled with the `stats` feature.
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+static LIVE_OBJECTS: AtomicUsize = AtomicUsize::new(0);
+static GLOBAL_REFS: AtomicUsize = AtomicUsize::new(0);
+static WEAK_REFS: AtomicUsize = AtomicUsize::new(0);
+static CREATED_ENVS: AtomicUsize = AtomicUsize::new(0);
+
+/// A snapshot of JNI resource counters returned by [`stats`](fn.stats.html).
+///
+/// Counters are process-wide and are updated without synchronization with each other,
+/// so a snapshot taken while other threads use JNI is only approximately consistent.
+///
+/// Only available with the `stats` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stats {
+    /// The number of live [`Object`](java/lang/struct.Object.html) wrappers, including
+    /// wrappers of other Java classes. Each one holds a local reference.
+    ///
+    /// References borrowed from Java by native method implementations are not counted.
+    pub live_objects: usize,
+    /// The number of global references held by [`ObjectKey`](struct.ObjectKey.html)-s.
+    pub global_refs: usize,
+    /// The number of weak global references held by the class cache.
+    pub weak_refs: usize,
+    /// The number of [`JniEnv`](struct.JniEnv.html)-s created since the process started,
+    /// both for attached threads and for native method calls.
+    pub created_envs: usize,
+}
+
+/// Get the current values of JNI resource counters.
+///
+/// Only available with the `stats` feature.
+pub fn stats() -> Stats {
+    Stats {
+        live_objects: LIVE_OBJECTS.load(Ordering::Relaxed),
+        global_refs: GLOBAL_REFS.load(Ordering::Relaxed),
+        weak_refs: WEAK_REFS.load(Ordering::Relaxed),
+        created_envs: CREATED_ENVS.load(Ordering::Relaxed),
+    }
+}
+
+#[inline(always)]
+pub(crate) fn object_created() {
+    LIVE_OBJECTS.fetch_add(1, Ordering::Relaxed);
+}
+
+#[inline(always)]
+pub(crate) fn object_released() {
+    LIVE_OBJECTS.fetch_sub(1, Ordering::Relaxed);
+}
+
+#[inline(always)]
+pub(crate) fn global_ref_created() {
+    GLOBAL_REFS.fetch_add(1, Ordering::Relaxed);
+}
+
+#[inline(always)]
+pub(crate) fn global_ref_deleted() {
+    GLOBAL_REFS.fetch_sub(1, Ordering::Relaxed);
+}
+
+#[inline(always)]
+pub(crate) fn weak_ref_created() {
+    WEAK_REFS.fetch_add(1, Ordering::Relaxed);
+}
+
+#[inline(always)]
+pub(crate) fn weak_ref_deleted() {
+    WEAK_REFS.fetch_sub(1, Ordering::Relaxed);
+}
+
+#[inline(always)]
+pub(crate) fn env_created() {
+    CREATED_ENVS.fetch_add(1, Ordering::Relaxed);
+}
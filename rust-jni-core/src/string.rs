@@ -0,0 +1,635 @@
+use crate::cached_call_method;
+use crate::convert;
+use crate::env::JniEnv;
+use crate::error::StringDecodingError;
+use crate::java_methods::FromObject;
+use crate::java_methods::{call_static_method, JniSignature};
+use crate::java_string::{from_java_string, to_java_string};
+use crate::object::Object;
+use crate::object_array::ObjectArray;
+use crate::result::JavaResult;
+use crate::token::{CallOutcome, NoException};
+use alloc::borrow::ToOwned;
+use alloc::vec::Vec;
+use core::ffi::{c_char, CStr};
+use core::ptr;
+use core::ptr::NonNull;
+use jni_sys;
+
+include!("call_jni_method.rs");
+
+/// A type representing a Java
+/// [`String`](https://docs.oracle.com/javase/10/docs/api/java/lang/String.html).
+// TODO: examples.
+#[derive(Debug, Clone)]
+pub struct String<'env> {
+    object: Object<'env>,
+}
+
+impl<'env> String<'env> {
+    /// Create a new empty string.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newstring)
+    pub fn empty<'a>(env: &'a JniEnv<'a>, token: &NoException<'a>) -> JavaResult<'a, String<'a>> {
+        // Safe because arguments are ensured to be the correct by construction and because
+        // `NewString` throws an exception before returning `null`.
+        let raw_string =
+            unsafe { call_nullable_jni_method!(env, token, NewString, ptr::null(), 0) }?;
+        // Safe because the argument is a valid string reference.
+        Ok(unsafe { Self::from_raw(env, raw_string) })
+    }
+
+    /// Create a new Java string from a Rust string.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newstringutf)
+    pub fn new<'a>(
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+        string: &str,
+    ) -> JavaResult<'a, String<'a>> {
+        if string.is_empty() {
+            return Self::empty(env, token);
+        }
+
+        let buffer = to_java_string(string);
+        // Safe because arguments are ensured to be the correct by construction and because
+        // `NewStringUTF` throws an exception before returning `null`.
+        let raw_string = unsafe {
+            call_nullable_jni_method!(env, token, NewStringUTF, buffer.as_ptr() as *const c_char)
+        }?;
+        // Safe because the argument is a valid string reference.
+        Ok(unsafe { Self::from_raw(env, raw_string) })
+    }
+
+    /// String length (the number of unicode characters).
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getstringlength)
+    pub fn len(&self, _token: &NoException) -> usize {
+        // Safe because arguments are ensured to be the correct by construction.
+        let length = unsafe { call_jni_object_method!(self, GetStringLength) };
+        convert::size_from_jni(length)
+    }
+
+    /// String size (the number of bytes in modified UTF-8).
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getstringutflength)
+    pub fn size(&self, _token: &NoException) -> usize {
+        // Safe because arguments are ensured to be the correct by construction.
+        let size = unsafe { call_jni_object_method!(self, GetStringUTFLength) };
+        convert::size_from_jni(size)
+    }
+
+    /// Convert the Java `String` into a Rust `String`.
+    ///
+    /// This method has a different signature from the one in the `ToString` trait because
+    /// extracting bytes from `String` is only safe when there is no pending exception.
+    ///
+    /// Will panic if the string contains unpaired surrogates. Use
+    /// [`try_as_string`](#method.try_as_string) or [`as_string_lossy`](#method.as_string_lossy)
+    /// for strings that can contain them.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getstringutfregion)
+    pub fn as_string(&self, token: &NoException) -> alloc::string::String {
+        self.try_as_string(token)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Convert the Java `String` into a Rust `String`.
+    ///
+    /// Returns an error if the string contains unpaired surrogates, which Java allows
+    /// but which are not valid Unicode.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getstringutfregion)
+    pub fn try_as_string(
+        &self,
+        token: &NoException,
+    ) -> Result<alloc::string::String, StringDecodingError> {
+        let length = self.len(token);
+        if length == 0 {
+            return Ok("".to_owned());
+        }
+
+        let size = self.size(token) + 1; // +1 for the '\0' byte.
+        let mut buffer: Vec<u8> = Vec::with_capacity(size);
+        // Safe because arguments are ensured to be the correct by construction.
+        unsafe {
+            call_jni_object_method!(
+                self,
+                GetStringUTFRegion,
+                0,
+                convert::size_to_jni(length),
+                buffer.as_mut_ptr() as *mut c_char
+            );
+            buffer.set_len(size);
+        }
+        // Modified UTF-8 encodes unpaired surrogates separately, which fails decoding.
+        from_java_string(buffer.as_slice())
+            .map(|string| string.into_owned())
+            .map_err(|_| StringDecodingError)
+    }
+
+    /// Convert the Java `String` into a Rust `String`, replacing unpaired surrogates with
+    /// [`U+FFFD REPLACEMENT CHARACTER`](https://doc.rust-lang.org/std/char/constant.REPLACEMENT_CHARACTER.html).
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getstringregion)
+    pub fn as_string_lossy(&self, token: &NoException) -> alloc::string::String {
+        let length = self.len(token);
+        if length == 0 {
+            return "".to_owned();
+        }
+
+        let mut buffer: Vec<u16> = Vec::with_capacity(length);
+        // Safe because arguments are ensured to be the correct by construction.
+        unsafe {
+            call_jni_object_method!(
+                self,
+                GetStringRegion,
+                0,
+                convert::size_to_jni(length),
+                buffer.as_mut_ptr()
+            );
+            buffer.set_len(length);
+        }
+        alloc::string::String::from_utf16_lossy(&buffer)
+    }
+
+    /// Iterate over the string in chunks of at most `chunk_size` UTF-16 code units, converting
+    /// each of them into a Rust `String`.
+    ///
+    /// Unlike [`as_string`](#method.as_string), this doesn't copy the whole string at once, which
+    /// is useful for logging or serializing huge strings. Surrogate pairs are never split between
+    /// chunks, so a chunk can be one code unit longer than `chunk_size`. Chunks with unpaired
+    /// surrogates are returned as errors.
+    ///
+    /// Will panic if `chunk_size` is zero.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getstringregion)
+    ///
+    /// # Example
+    /// ```
+    /// # use rust_jni::*;
+    /// # use rust_jni::java::lang::String;
+    /// #
+    /// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+    /// let string = String::new(env, &token, "Hello")?;
+    /// let chunks = string
+    ///     .chars_chunks(&token, 2)
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    /// assert_eq!(chunks, vec!["He", "ll", "o"]);
+    /// # Ok(token)
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     let init_arguments = InitArguments::default();
+    /// #     let vm = JavaVM::create(&init_arguments).unwrap();
+    /// #     let _ = vm.with_attached(
+    /// #        &AttachArguments::new(init_arguments.version()),
+    /// #        |env: &JniEnv, token: NoException| {
+    /// #            ((), jni_main(env, token).unwrap())
+    /// #        },
+    /// #     );
+    /// # }
+    /// ```
+    pub fn chars_chunks<'a>(
+        &'a self,
+        token: &'a NoException<'env>,
+        chunk_size: usize,
+    ) -> CharsChunks<'a, 'env> {
+        if chunk_size == 0 {
+            panic!("Chunk size must be at least one.");
+        }
+        CharsChunks {
+            string: self,
+            _token: token,
+            chunk_size,
+            position: 0,
+            length: self.len(token),
+        }
+    }
+
+    /// Write the string in UTF-8 into a sink, converting it in chunks of at most `chunk_size`
+    /// UTF-16 code units with [`chars_chunks`](#method.chars_chunks).
+    ///
+    /// Returns an error of the [`InvalidData`](https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData)
+    /// kind if the string contains unpaired surrogates. Chunks before the invalid one are
+    /// written to the sink.
+    ///
+    /// Only available with the `std` feature. Will panic if `chunk_size` is zero.
+    #[cfg(feature = "std")]
+    pub fn write_to(
+        &self,
+        token: &NoException<'env>,
+        chunk_size: usize,
+        sink: &mut impl std::io::Write,
+    ) -> std::io::Result<()> {
+        for chunk in self.chars_chunks(token, chunk_size) {
+            let chunk = chunk
+                .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))?;
+            sink.write_all(chunk.as_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Call a function with the contents of the string in modified UTF-8 without copying them
+    /// into a Rust `String`.
+    ///
+    /// Useful for passing strings to C libraries that accept modified UTF-8, as
+    /// [`as_string`](#method.as_string) copies the string twice. The characters are released
+    /// after the function returns.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getstringutfchars)
+    pub fn with_utf_chars<T>(
+        &self,
+        token: &NoException<'env>,
+        callback: impl FnOnce(&CStr) -> T,
+    ) -> JavaResult<'env, T> {
+        let env = self.env();
+        // Safe because arguments are ensured to be the correct by construction and because
+        // `GetStringUTFChars` throws an exception before returning `null`.
+        let chars = token.with_owned(
+            env,
+            #[inline(always)]
+            |token| unsafe {
+                let chars = call_jni_object_method!(self, GetStringUTFChars, ptr::null_mut());
+                match NonNull::new(chars as *mut c_char) {
+                    None => CallOutcome::Err(token.exchange(env)),
+                    Some(chars) => CallOutcome::Ok((chars, token)),
+                }
+            },
+        )?;
+        let chars = UtfChars {
+            string: self,
+            chars,
+        };
+        // Safe because `GetStringUTFChars` returns a null-terminated string that stays valid
+        // until it's released.
+        Ok(callback(unsafe { CStr::from_ptr(chars.chars.as_ptr()) }))
+    }
+
+    /// String length (the number of UTF-16 code units).
+    ///
+    /// Same as [`len`](#method.len), but calls the Java method.
+    ///
+    /// [`String::length` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#length())
+    pub fn length(&self, token: &NoException<'env>) -> JavaResult<'env, usize> {
+        // Safe because we ensure correct arguments and return type.
+        let length = unsafe { cached_call_method!(*self, "length", fn() -> i32, (), token) }?;
+        Ok(convert::size_from_jni(length))
+    }
+
+    /// Check if the string is empty.
+    ///
+    /// [`String::isEmpty` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#isEmpty())
+    pub fn is_empty(&self, token: &NoException<'env>) -> JavaResult<'env, bool> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { cached_call_method!(*self, "isEmpty", fn() -> bool, (), token) }
+    }
+
+    /// Get the character at an index.
+    ///
+    /// Returns a
+    /// [`StringIndexOutOfBoundsException`](https://docs.oracle.com/javase/10/docs/api/java/lang/StringIndexOutOfBoundsException.html)
+    /// if the index is out of bounds. Panics if the character is a part of a surrogate pair or if
+    /// the index doesn't fit into a `jsize`.
+    ///
+    /// [`String::charAt` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#charAt(int))
+    pub fn char_at(&self, token: &NoException<'env>, index: usize) -> JavaResult<'env, char> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe {
+            cached_call_method!(
+                *self,
+                "charAt",
+                fn(i32) -> char,
+                (convert::size_to_jni(index),),
+                token
+            )
+        }
+    }
+
+    /// Get a substring from the `begin` index inclusive to the `end` index exclusive.
+    ///
+    /// Returns a
+    /// [`StringIndexOutOfBoundsException`](https://docs.oracle.com/javase/10/docs/api/java/lang/StringIndexOutOfBoundsException.html)
+    /// if the indices are out of bounds. Panics if an index doesn't fit into a `jsize`.
+    ///
+    /// [`String::substring` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#substring(int,int))
+    pub fn substring(
+        &self,
+        token: &NoException<'env>,
+        begin: usize,
+        end: usize,
+    ) -> JavaResult<'env, Option<String<'env>>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe {
+            cached_call_method!(
+                *self,
+                "substring",
+                fn(i32, i32) -> String<'env>,
+                (convert::size_to_jni(begin), convert::size_to_jni(end)),
+                token
+            )
+        }
+    }
+
+    /// Concatenate the string with another one.
+    ///
+    /// [`String::concat` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#concat(java.lang.String))
+    pub fn concat(
+        &self,
+        token: &NoException<'env>,
+        other: &String<'env>,
+    ) -> JavaResult<'env, Option<String<'env>>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe {
+            cached_call_method!(
+                *self,
+                "concat",
+                fn(&String<'env>) -> String<'env>,
+                (other,),
+                token
+            )
+        }
+    }
+
+    /// Get the string value of a boolean.
+    ///
+    /// [`String::valueOf(boolean)` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#valueOf(boolean)).
+    pub fn value_of_bool(
+        env: &'env JniEnv<'env>,
+        token: &NoException<'env>,
+        value: bool,
+    ) -> JavaResult<'env, Option<String<'env>>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe {
+            call_static_method::<Self, _, _, fn(bool) -> String<'env>>(
+                &env,
+                token,
+                "valueOf\0",
+                (value,),
+            )
+        }
+    }
+
+    /// Get the string value of a character.
+    ///
+    /// [`String::valueOf(char)` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#valueOf(char)).
+    pub fn value_of_char(
+        env: &'env JniEnv<'env>,
+        token: &NoException<'env>,
+        value: char,
+    ) -> JavaResult<'env, Option<String<'env>>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe {
+            call_static_method::<Self, _, _, fn(char) -> String<'env>>(
+                &env,
+                token,
+                "valueOf\0",
+                (value,),
+            )
+        }
+    }
+
+    /// Get the string value of an integer.
+    ///
+    /// [`String::valueOf(int)` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#valueOf(int)).
+    pub fn value_of_int(
+        env: &'env JniEnv<'env>,
+        token: &NoException<'env>,
+        value: i32,
+    ) -> JavaResult<'env, Option<String<'env>>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe {
+            call_static_method::<Self, _, _, fn(i32) -> String<'env>>(
+                &env,
+                token,
+                "valueOf\0",
+                (value,),
+            )
+        }
+    }
+
+    /// Get the string value of a long integer.
+    ///
+    /// [`String::valueOf(long)` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#valueOf(long)).
+    pub fn value_of_long(
+        env: &'env JniEnv<'env>,
+        token: &NoException<'env>,
+        value: i64,
+    ) -> JavaResult<'env, Option<String<'env>>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe {
+            call_static_method::<Self, _, _, fn(i64) -> String<'env>>(
+                &env,
+                token,
+                "valueOf\0",
+                (value,),
+            )
+        }
+    }
+
+    /// Get the string value of a float.
+    ///
+    /// [`String::valueOf(float)` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#valueOf(float)).
+    pub fn value_of_float(
+        env: &'env JniEnv<'env>,
+        token: &NoException<'env>,
+        value: f32,
+    ) -> JavaResult<'env, Option<String<'env>>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe {
+            call_static_method::<Self, _, _, fn(f32) -> String<'env>>(
+                &env,
+                token,
+                "valueOf\0",
+                (value,),
+            )
+        }
+    }
+
+    /// Get the string value of a double.
+    ///
+    /// [`String::valueOf(double)` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#valueOf(double)).
+    pub fn value_of_double(
+        env: &'env JniEnv<'env>,
+        token: &NoException<'env>,
+        value: f64,
+    ) -> JavaResult<'env, Option<String<'env>>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe {
+            call_static_method::<Self, _, _, fn(f64) -> String<'env>>(
+                &env,
+                token,
+                "valueOf\0",
+                (value,),
+            )
+        }
+    }
+
+    /// Format a string with arguments passed in an [`ObjectArray`](../../struct.ObjectArray.html).
+    ///
+    /// Primitive arguments need to be boxed in Java, so it's easiest to pass strings.
+    ///
+    /// [`String::format` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#format(java.lang.String,java.lang.Object...)).
+    pub fn format(
+        env: &'env JniEnv<'env>,
+        token: &NoException<'env>,
+        format: &String<'env>,
+        arguments: &ObjectArray<'env>,
+    ) -> JavaResult<'env, Option<String<'env>>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe {
+            call_static_method::<Self, _, _, fn(&String<'env>, &ObjectArray<'env>) -> String<'env>>(
+                &env,
+                token,
+                "format\0",
+                (format, arguments),
+            )
+        }
+    }
+
+    /// Unsafe because an incorrect object reference can be passed.
+    #[inline(always)]
+    pub(crate) unsafe fn from_raw<'a>(
+        env: &'a JniEnv<'a>,
+        raw_string: NonNull<jni_sys::_jobject>,
+    ) -> String<'a> {
+        String {
+            object: Object::from_raw(env, raw_string.cast()),
+        }
+    }
+}
+
+/// Characters of a [`String`](struct.String.html) returned by `GetStringUTFChars`.
+/// Released when dropped, so that they are released even if the callback panics.
+struct UtfChars<'a, 'env> {
+    string: &'a String<'env>,
+    chars: NonNull<c_char>,
+}
+
+impl Drop for UtfChars<'_, '_> {
+    fn drop(&mut self) {
+        let string = self.string;
+        // Safe because the characters were returned by `GetStringUTFChars` for this string.
+        // `ReleaseStringUTFChars` can be called with a pending exception.
+        unsafe {
+            call_jni_object_method!(string, ReleaseStringUTFChars, self.chars.as_ptr());
+        }
+    }
+}
+
+/// An iterator over chunks of a [`String`](java/lang/struct.String.html) converted into Rust
+/// strings, returned by [`String::chars_chunks`](java/lang/struct.String.html#method.chars_chunks).
+#[derive(Debug)]
+pub struct CharsChunks<'a, 'env> {
+    string: &'a String<'env>,
+    // Borrowed, because reading the string can't be done with a pending exception.
+    _token: &'a NoException<'env>,
+    chunk_size: usize,
+    position: usize,
+    length: usize,
+}
+
+impl Iterator for CharsChunks<'_, '_> {
+    type Item = Result<alloc::string::String, StringDecodingError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.length - self.position;
+        if remaining == 0 {
+            return None;
+        }
+
+        // Read one more code unit to not split a surrogate pair at the end of the chunk.
+        let read = remaining.min(self.chunk_size + 1);
+        let mut buffer: Vec<u16> = Vec::with_capacity(read);
+        let string = self.string;
+        // Safe because arguments are ensured to be the correct by construction and the region
+        // is within the string.
+        unsafe {
+            call_jni_object_method!(
+                string,
+                GetStringRegion,
+                convert::size_to_jni(self.position),
+                convert::size_to_jni(read),
+                buffer.as_mut_ptr()
+            );
+            buffer.set_len(read);
+        }
+        let mut count = remaining.min(self.chunk_size);
+        if count < read && is_high_surrogate(buffer[count - 1]) {
+            count += 1;
+        }
+        buffer.truncate(count);
+        self.position += count;
+        Some(alloc::string::String::from_utf16(&buffer).map_err(|_| StringDecodingError))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.length - self.position;
+        // Chunks have `chunk_size` code units, or one more to keep a surrogate pair together.
+        (
+            remaining.div_ceil(self.chunk_size + 1),
+            Some(remaining.div_ceil(self.chunk_size)),
+        )
+    }
+}
+
+fn is_high_surrogate(code_unit: u16) -> bool {
+    (0xD800..0xDC00).contains(&code_unit)
+}
+
+/// Allow [`String`](struct.String.html) to be used in place of an [`Object`](struct.Object.html).
+impl<'env> ::core::ops::Deref for String<'env> {
+    type Target = Object<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Object<'env>> for String<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        &self.object
+    }
+}
+
+impl<'a> Into<Object<'a>> for String<'a> {
+    fn into(self) -> Object<'a> {
+        self.object
+    }
+}
+
+impl<'env> FromObject<'env> for String<'env> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self { object }
+    }
+}
+
+impl JniSignature for String<'_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "Ljava/lang/String;"
+    }
+}
+
+/// Allow comparing [`String`](struct.String.html)
+/// to Java objects. Java objects are compared by-reference to preserve
+/// original Java semantics. To compare objects by value, call the
+/// [`value_eq`](struct.Object.html#method.value_eq) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`ref_eq`](struct.Object.html#method.ref_eq) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for String<'env>
+where
+    T: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &T) -> bool {
+        self.as_ref().eq(other.as_ref())
+    }
+}
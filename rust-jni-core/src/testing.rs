@@ -1,6 +1,76 @@
+//! Mocks of JNI interfaces for unit tests.
+//!
+//! The `generate_*_mock!` macros generate [`mockall`](https://docs.rs/mockall) mocks of JNI
+//! functions, so crates using them need to depend on `mockall` and `jni-sys`.
+//!
+//! Mock `JNIEnv` functions track a simulated pending Java exception: it becomes pending when
+//! the mocked `ExceptionCheck` or `ExceptionOccurred` reports one and stops being pending on
+//! `ExceptionClear`. Calling any function not in
+//! [`EXCEPTION_SAFE_FUNCTIONS`](constant.EXCEPTION_SAFE_FUNCTIONS.html) while the exception is
+//! pending panics, which catches code that uses a JNI env without checking for exceptions.
+//!
+//! Only available in tests or with the `testing` feature.
+
 use jni_sys;
+use std::cell::Cell;
 use std::ptr;
 
+/// JNI functions that are allowed to be called with a pending exception.
+///
+/// [JNI documentation](https://docs.oracle.com/en/java/javase/11/docs/specs/jni/design.html#exception-handling)
+pub const EXCEPTION_SAFE_FUNCTIONS: &[&str] = &[
+    "ExceptionOccurred",
+    "ExceptionDescribe",
+    "ExceptionClear",
+    "ExceptionCheck",
+    "ReleaseStringChars",
+    "ReleaseStringUTFChars",
+    "ReleaseStringCritical",
+    "ReleaseBooleanArrayElements",
+    "ReleaseByteArrayElements",
+    "ReleaseCharArrayElements",
+    "ReleaseShortArrayElements",
+    "ReleaseIntArrayElements",
+    "ReleaseLongArrayElements",
+    "ReleaseFloatArrayElements",
+    "ReleaseDoubleArrayElements",
+    "ReleasePrimitiveArrayCritical",
+    "DeleteLocalRef",
+    "DeleteGlobalRef",
+    "DeleteWeakGlobalRef",
+    "MonitorExit",
+    "PushLocalFrame",
+    "PopLocalFrame",
+];
+
+thread_local! {
+    static EXCEPTION_PENDING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Set whether a simulated Java exception is pending on the current thread.
+pub fn set_exception_pending(pending: bool) {
+    EXCEPTION_PENDING.with(|exception_pending| exception_pending.set(pending));
+}
+
+/// Check whether a simulated Java exception is pending on the current thread.
+pub fn is_exception_pending() -> bool {
+    EXCEPTION_PENDING.with(|exception_pending| exception_pending.get())
+}
+
+/// Check that calling a JNI function is allowed. Called by mock `JNIEnv` functions.
+///
+/// Will panic if a simulated exception is pending and the function is not in
+/// [`EXCEPTION_SAFE_FUNCTIONS`](constant.EXCEPTION_SAFE_FUNCTIONS.html).
+pub fn check_jni_call(function: &str) {
+    if is_exception_pending() && !EXCEPTION_SAFE_FUNCTIONS.contains(&function) {
+        panic!(
+            "JNI function {} called with a pending exception, \
+             the exception must be checked and cleared first.",
+            function
+        );
+    }
+}
+
 /// Create an empty Java VM interface control structure for testing purposes.
 pub fn empty_raw_java_vm() -> jni_sys::JNIInvokeInterface_ {
     jni_sys::JNIInvokeInterface_ {
@@ -388,8 +458,11 @@ macro_rules! generate_java_vm_mock {
     };
 }
 
-/// A macro to generate a mock of a Java VM.
+/// A macro to generate a mock of a JNI env.
 /// The macro creates global variables and thus needs to be a macro, not a single definition.
+///
+/// Mock functions panic when called with a simulated pending exception, see the
+/// [module documentation](testing/index.html).
 #[macro_export]
 macro_rules! generate_jni_env_mock {
     ($module:ident) => {
@@ -411,41 +484,77 @@ macro_rules! generate_jni_env_mock {
                 pub fn exception_occured(env: *mut jni_sys::JNIEnv) -> jni_sys::jobject;
 
                 pub fn exception_clear(env: *mut jni_sys::JNIEnv);
+
+                pub fn release_int_array_elements(
+                    env: *mut jni_sys::JNIEnv,
+                    array: jni_sys::jintArray,
+                    elements: *mut jni_sys::jint,
+                    mode: jni_sys::jint,
+                );
             }
 
             /// Create a mock JNI interface control structure for testing purposes.
+            ///
+            /// Resets the simulated pending exception.
             pub fn raw_jni_env() -> jni_sys::JNINativeInterface_ {
+                $crate::testing::set_exception_pending(false);
+
                 unsafe extern "system" fn delete_local_ref_impl(
                     java_vm: *mut jni_sys::JNIEnv,
                     object: jni_sys::jobject,
                 ) {
+                    $crate::testing::check_jni_call("DeleteLocalRef");
                     mock_impl::delete_local_ref(java_vm, object)
                 }
 
                 unsafe extern "system" fn get_version_impl(
                     env: *mut jni_sys::JNIEnv,
                 ) -> jni_sys::jint {
+                    $crate::testing::check_jni_call("GetVersion");
                     mock_impl::get_version(env)
                 }
 
                 unsafe extern "system" fn exception_check_impl(
                     env: *mut ::jni_sys::JNIEnv,
                 ) -> jni_sys::jboolean {
-                    mock_impl::exception_check(env)
+                    $crate::testing::check_jni_call("ExceptionCheck");
+                    let result = mock_impl::exception_check(env);
+                    if result == jni_sys::JNI_TRUE {
+                        $crate::testing::set_exception_pending(true);
+                    }
+                    result
                 }
 
                 unsafe extern "system" fn exception_describe_impl(env: *mut ::jni_sys::JNIEnv) {
+                    $crate::testing::check_jni_call("ExceptionDescribe");
                     mock_impl::exception_describe(env)
                 }
 
                 unsafe extern "system" fn exception_occured_impl(
                     env: *mut jni_sys::JNIEnv,
                 ) -> jni_sys::jobject {
-                    mock_impl::exception_occured(env)
+                    $crate::testing::check_jni_call("ExceptionOccurred");
+                    let exception = mock_impl::exception_occured(env);
+                    if !exception.is_null() {
+                        $crate::testing::set_exception_pending(true);
+                    }
+                    exception
                 }
 
                 unsafe extern "system" fn exception_clear_impl(env: *mut jni_sys::JNIEnv) {
-                    mock_impl::exception_clear(env)
+                    $crate::testing::check_jni_call("ExceptionClear");
+                    mock_impl::exception_clear(env);
+                    $crate::testing::set_exception_pending(false);
+                }
+
+                unsafe extern "system" fn release_int_array_elements_impl(
+                    env: *mut jni_sys::JNIEnv,
+                    array: jni_sys::jintArray,
+                    elements: *mut jni_sys::jint,
+                    mode: jni_sys::jint,
+                ) {
+                    $crate::testing::check_jni_call("ReleaseIntArrayElements");
+                    mock_impl::release_int_array_elements(env, array, elements, mode)
                 }
 
                 jni_sys::JNINativeInterface_ {
@@ -455,6 +564,7 @@ macro_rules! generate_jni_env_mock {
                     ExceptionDescribe: Some(exception_describe_impl),
                     ExceptionOccurred: Some(exception_occured_impl),
                     ExceptionClear: Some(exception_clear_impl),
+                    ReleaseIntArrayElements: Some(release_int_array_elements_impl),
                     ..$crate::testing::empty_raw_jni_env()
                 }
             }
@@ -463,3 +573,29 @@ macro_rules! generate_jni_env_mock {
         }
     };
 }
+
+#[cfg(test)]
+mod exception_pending_tests {
+    use super::*;
+
+    #[test]
+    fn no_exception() {
+        set_exception_pending(false);
+        check_jni_call("GetVersion");
+    }
+
+    #[test]
+    fn exception_safe_function() {
+        set_exception_pending(true);
+        for function in EXCEPTION_SAFE_FUNCTIONS {
+            check_jni_call(function);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "JNI function GetVersion called with a pending exception")]
+    fn exception_pending() {
+        set_exception_pending(true);
+        check_jni_call("GetVersion");
+    }
+}
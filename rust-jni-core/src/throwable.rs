@@ -1,5 +1,6 @@
 use crate::env::JniEnv;
 use crate::error::JniError;
+use crate::java_class::{JavaClassExt, JavaExceptionClass};
 use crate::java_methods::FromObject;
 use crate::java_methods::{call_constructor, call_method, JniSignature};
 use crate::object::Object;
@@ -8,7 +9,7 @@ use crate::string::String;
 use crate::token::{Exception, NoException};
 use jni_sys;
 
-use std::ptr::NonNull;
+use core::ptr::NonNull;
 
 include!("call_jni_method.rs");
 
@@ -110,6 +111,26 @@ impl<'env> Throwable<'env> {
         }
     }
 
+    /// Down-cast the exception to a more specific exception class, like
+    /// [`IllegalArgumentException`](struct.IllegalArgumentException.html).
+    ///
+    /// Returns the exception back as an error if it is not an instance of `T`, so that exceptions
+    /// can be matched by type one class after another.
+    ///
+    /// [`Class::isInstance` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Class.html#isInstance(java.lang.Object))
+    pub fn downcast<T>(self, token: &NoException<'env>) -> JavaResult<'env, Result<T, Self>>
+    where
+        T: JavaExceptionClass<'env>,
+    {
+        let class = T::class(self.env(), token)?;
+        if self.is_instance_of(token, &class) {
+            // Safe because the exception is an instance of `T`.
+            Ok(Ok(unsafe { T::from_object(self.into()) }))
+        } else {
+            Ok(Err(self))
+        }
+    }
+
     /// Unsafe because the argument mught not be a valid class reference.
     #[inline(always)]
     pub(crate) unsafe fn from_raw<'a>(
@@ -123,7 +144,7 @@ impl<'env> Throwable<'env> {
 }
 
 /// Allow [`Throwable`](struct.Throwable.html) to be used in place of an [`Object`](struct.Object.html).
-impl<'env> ::std::ops::Deref for Throwable<'env> {
+impl<'env> ::core::ops::Deref for Throwable<'env> {
     type Target = Object<'env>;
 
     #[inline(always)]
@@ -159,15 +180,17 @@ impl JniSignature for Throwable<'_> {
     }
 }
 
+impl<'env> JavaExceptionClass<'env> for Throwable<'env> {}
+
 /// Allow comparing [`Throwable`](struct.Throwable.html)
 /// to Java objects. Java objects are compared by-reference to preserve
 /// original Java semantics. To compare objects by value, call the
-/// [`equals`](struct.Object.html#method.equals) method.
+/// [`value_eq`](struct.Object.html#method.value_eq) method.
 ///
 /// Will panic if there is a pending exception in the current thread.
 ///
 /// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
-/// [`is_same_as`](struct.Object.html#methods.is_same_as) to comparing with `==`, because
+/// [`ref_eq`](struct.Object.html#method.ref_eq) to comparing with `==`, because
 /// the former checks for a pending exception in compile-time rather than the run-time.
 impl<'env, T> PartialEq<T> for Throwable<'env>
 where
@@ -3,8 +3,6 @@ use crate::jni_bool;
 use crate::result::JavaResult;
 use crate::throwable::Throwable;
 use core::marker::PhantomData;
-use std::mem;
-use std::ptr::NonNull;
 
 include!("call_jni_method.rs");
 
@@ -126,6 +124,19 @@ include!("call_jni_method.rs");
 ///     token
 /// }; // doesn't compile!
 /// ```
+/// The token is invariant over it's lifetime, which brands it with the exact frame it was
+/// created for. A token can't be coerced to a shorter lifetime, so it can't be smuggled into
+/// structs or frames it doesn't belong to, even if it outlives them:
+/// ```compile_fail
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::String;
+/// #
+/// fn empty<'a, 'b: 'a>(env: &'a JniEnv<'a>, token: &NoException<'b>) {
+///     let _ = String::empty(env, token); // doesn't compile!
+/// }
+/// #
+/// # fn main() {}
+/// ```
 /// Some JNI methods throw exceptions themselves. In this case the token will be consumed
 /// so that there is no possible way to obtain a token when there is a pending exception:
 /// ```compile_fail
@@ -210,6 +221,9 @@ include!("call_jni_method.rs");
 #[derive(Debug)]
 pub struct NoException<'this> {
     _env: PhantomData<&'this JniEnv<'this>>,
+    // Makes the token invariant over `'this`, so that it's branded with the exact lifetime of
+    // the frame it was created for and can't be coerced to a shorter or longer one.
+    _brand: PhantomData<fn(&'this ()) -> &'this ()>,
 }
 
 /// A token that like [`NoException`](struct.NoException.html) represents that there is no
@@ -252,7 +266,7 @@ pub struct ConsumedNoException;
 /// or a pending exception ([`CallOutcome::Err`](enum.CallOutcome.html#variant.Err)) or a result when it is not known
 /// if there is a pending exception ([`CallOutcome::Unknown`](enum.CallOutcome.html#variant.Unknown)).
 // TODO(https://github.com/rust-lang/cargo/issues/7606): make documentation visible.
-pub(crate) enum CallOutcome<'a, T> {
+pub enum CallOutcome<'a, T> {
     /// Successfull JNI call. [`NoException`](struct.NoException.html) token is present as a proof of that.
     Ok((T, NoException<'a>)),
     /// JNI call resulted in a pending exception. [`Exception`](struct.Exception.html) token is present
@@ -270,6 +284,7 @@ impl<'this> NoException<'this> {
     pub(crate) unsafe fn new<'env>(_env: &JniEnv<'env>) -> NoException<'env> {
         NoException {
             _env: PhantomData::<&JniEnv>,
+            _brand: PhantomData,
         }
     }
 
@@ -281,13 +296,104 @@ impl<'this> NoException<'this> {
         ConsumedNoException
     }
 
+    /// Run a callback with a new [`NoException`](struct.NoException.html) token scoped to it.
+    ///
+    /// The token is reborrowed mutably for the duration of the callback, so only the scoped token
+    /// can be used inside it. The scoped token has the lifetime of the borrow, so objects created
+    /// with it can't outlive it. Like with
+    /// [`with_attached`](struct.JavaVM.html#method.with_attached), the callback must return the
+    /// scoped token to prove that it didn't leave a pending exception.
+    ///
+    /// Example:
+    /// ```
+    /// # use rust_jni::*;
+    /// # use rust_jni::java::lang::String;
+    /// #
+    /// # fn jni_main<'a>(env: &'a JniEnv<'a>, mut token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+    /// let length = token.scope(|token| {
+    ///     let string = String::new(env, &token, "string").unwrap();
+    ///     (string.len(&token), token)
+    /// });
+    /// assert_eq!(length, 6);
+    /// # Ok(token)
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     let init_arguments = InitArguments::default();
+    /// #     let vm = JavaVM::create(&init_arguments).unwrap();
+    /// #     let _ = vm.with_attached(
+    /// #        &AttachArguments::new(init_arguments.version()),
+    /// #        |env: &JniEnv, token: NoException| {
+    /// #            ((), jni_main(env, token).unwrap())
+    /// #        },
+    /// #     );
+    /// # }
+    /// ```
+    /// The outer token can't be used inside the scope:
+    /// ```compile_fail
+    /// # use rust_jni::*;
+    /// # use rust_jni::java::lang::String;
+    /// #
+    /// # fn jni_main<'a>(env: &'a JniEnv<'a>, mut token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+    /// let outer_token = &token;
+    /// token.scope(|token| {
+    ///     let _ = String::empty(env, outer_token); // doesn't compile!
+    ///     ((), token)
+    /// });
+    /// # Ok(token)
+    /// # }
+    /// #
+    /// # fn main() {}
+    /// ```
+    /// Objects created in the scope can't outlive it:
+    /// ```compile_fail
+    /// # use rust_jni::*;
+    /// # use rust_jni::java::lang::String;
+    /// #
+    /// # fn jni_main<'a>(env: &'a JniEnv<'a>, mut token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+    /// let string: String<'a> = token.scope(|token| {
+    ///     (String::empty(env, &token).unwrap(), token) // doesn't compile!
+    /// });
+    /// # Ok(token)
+    /// # }
+    /// #
+    /// # fn main() {}
+    /// ```
+    pub fn scope<'scope, R, F>(&'scope mut self, callback: F) -> R
+    where
+        F: FnOnce(NoException<'scope>) -> (R, NoException<'scope>),
+    {
+        // Safe because the current token is borrowed mutably while the scoped one is alive.
+        let token = unsafe { self.clone() }.narrow();
+        let (result, token) = callback(token);
+        // Discard the scoped token so there's only one live token (borrowed by this method).
+        let _ = token;
+        result
+    }
+
+    /// Narrow the token to a frame nested in the current one.
+    ///
+    /// The token is invariant over it's lifetime, so this has to be done explicitly. It's safe
+    /// because the token is consumed, so there's still only one live token.
+    #[inline(always)]
+    pub(crate) fn narrow<'short>(self) -> NoException<'short>
+    where
+        'this: 'short,
+    {
+        NoException {
+            _env: PhantomData::<&JniEnv>,
+            _brand: PhantomData,
+        }
+    }
+
     /// Exchange a [`NoException`](struct.NoException.html) for an
     /// [`Exception`](struct.Exception.html) token. This means that [`rust-jni`](index.html)
     /// no longer can prove that there is no pending exception.
     /// Unsafe because there might not actually be a pending exception when this method is called.
     #[cold]
     #[inline(always)]
-    pub(crate) unsafe fn exchange(self, env: &'this JniEnv<'this>) -> Exception<'this> {
+    #[doc(hidden)]
+    pub unsafe fn exchange(self, env: &'this JniEnv<'this>) -> Exception<'this> {
         Exception::new(env)
     }
 
@@ -328,7 +434,8 @@ impl<'this> NoException<'this> {
     /// This function by itself is safe, but it needs to execute trusted code
     /// that the caller promises is safe.
     // TODO(https://github.com/rust-lang/cargo/issues/7606): make documentation visible.
-    pub(crate) fn with_owned<Out, F: FnOnce(Self) -> CallOutcome<'this, Out>>(
+    #[doc(hidden)]
+    pub fn with_owned<Out, F: FnOnce(Self) -> CallOutcome<'this, Out>>(
         &self,
         env: &'this JniEnv<'this>,
         function: F,
@@ -341,31 +448,21 @@ impl<'this> NoException<'this> {
         let outcome = unsafe { function(token) };
         match outcome {
             CallOutcome::Ok((result, token)) => {
-                // Drop the additional token so there's only one live token (borrowed by this method).
-                mem::drop(token);
+                // Discard the additional token so there's only one live token (borrowed by this method).
+                let _ = token;
                 Ok(result)
             }
             #[cold]
             CallOutcome::Err(token) => {
                 let (throwable, token) = token.unwrap();
-                // Drop the additional token so there's only one live token (borrowed by this method).
-                mem::drop(token);
+                // Discard the additional token so there's only one live token (borrowed by this method).
+                let _ = token;
                 Err(throwable)
             }
-            CallOutcome::Unknown(result) => {
-                // Safe because the argument is ensured to be correct references by construction.
-                match NonNull::new(unsafe { call_jni_method!(env, ExceptionOccurred) }) {
-                    None => Ok(result),
-                    Some(raw_java_throwable) => {
-                        // Safe because the argument is ensured to be correct references by construction.
-                        unsafe {
-                            call_jni_method!(env, ExceptionClear);
-                        }
-                        // Safe because the arguments are correct.
-                        Err(unsafe { Throwable::from_raw(env, raw_java_throwable) })
-                    }
-                }
-            }
+            CallOutcome::Unknown(result) => match env.take_pending_exception() {
+                None => Ok(result),
+                Some(throwable) => Err(throwable),
+            },
         }
     }
 
@@ -400,6 +497,7 @@ impl<'this> NoException<'this> {
     unsafe fn clone(&self) -> Self {
         NoException {
             _env: PhantomData::<&JniEnv>,
+            _brand: PhantomData,
         }
     }
 
@@ -407,6 +505,7 @@ impl<'this> NoException<'this> {
     pub(crate) fn test<'a>() -> NoException<'a> {
         NoException {
             _env: PhantomData::<&JniEnv>,
+            _brand: PhantomData,
         }
     }
 }
@@ -415,9 +514,10 @@ impl<'this> NoException<'this> {
 mod no_exception_tests {
     use super::*;
     use crate::vm::JavaVMRef;
+    use core::mem;
+    use core::ptr;
     use mockall::*;
     use serial_test_derive::serial;
-    use std::ptr;
 
     generate_jni_env_mock!(jni_mock);
 
@@ -553,18 +653,10 @@ impl<'this> Exception<'this> {
     /// The [`Exception`](struct.Exception.html) token is consumed by this method and can't be used any more.
     #[cold]
     pub fn unwrap(self) -> (Throwable<'this>, NoException<'this>) {
-        let throwable = {
-            // Safe because there are no arguments to be invalid.
-            let raw_java_throwable = unsafe { call_jni_method!(self.env, ExceptionOccurred) };
-            // Should not fail because [`Exception`](struct.Exception.html) guarantees that
-            // there must be an exception in flight.
-            let raw_java_throwable = NonNull::new(raw_java_throwable).unwrap();
-            // Safe because we construct Throwable from a valid pointer.
-            unsafe { Throwable::from_raw(self.env, raw_java_throwable) }
-        };
+        // Should not fail because [`Exception`](struct.Exception.html) guarantees that
+        // there must be an exception in flight.
+        let throwable = self.env.take_pending_exception().unwrap();
         let token = {
-            // Safe because the argument is ensured to be a correct reference by construction.
-            unsafe { call_jni_method!(self.env, ExceptionClear) };
             // Safe because we just cleared the exception.
             unsafe { NoException::new(self.env) }
         };
@@ -582,9 +674,10 @@ impl<'this> Exception<'this> {
 mod exception_tests {
     use super::*;
     use crate::vm::JavaVMRef;
+    use core::mem;
+    use core::ptr;
     use mockall::*;
     use serial_test_derive::serial;
-    use std::ptr;
 
     generate_jni_env_mock!(jni_mock);
 
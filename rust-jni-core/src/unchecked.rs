@@ -0,0 +1,132 @@
+//! Java method calls that don't check for exceptions.
+//!
+//! Every call through the regular API, like [`call_method`](../fn.call_method.html), checks
+//! for a pending exception after the call. In measured hot loops calling a method that is known
+//! not to throw these checks can be noticeable. Functions in this module skip them and don't
+//! require a [`NoException`](../struct.NoException.html) token, so the caller can do a single
+//! check after the loop with
+//! [`JniEnv::take_pending_exception`](../struct.JniEnv.html#method.take_pending_exception).
+//!
+//! JNI doesn't allow calling Java methods with a pending exception, so when using this module
+//! the caller must guarantee that:
+//!  - No call throws an exception, or no other JNI calls are made after one does
+//!  - The pending exception is checked before using the regular API again
+//!
+//! Results of a call that has thrown an exception are unspecified: primitives are zero and
+//! objects are `null`.
+
+use crate::env::JniEnv;
+use crate::java_class::JavaClassRef;
+use crate::java_methods::{
+    JavaArgumentTuple, JavaMethodResult, JavaMethodSignature, MethodIdCache,
+};
+use crate::result::JavaResult;
+use crate::token::NoException;
+
+/// Look up a Java method id and store it in a [`MethodIdCache`](../struct.MethodIdCache.html)
+/// for [`call_method_unchecked`](fn.call_method_unchecked.html).
+///
+/// The method is looked up in the class `T` and has the signature `F`. Does nothing if
+/// the cache is already filled.
+///
+/// Note that method name string *must* be null-terminating.
+///
+/// This function is unsafe because the cache must always be used with the same class and
+/// signature.
+pub unsafe fn resolve_method<'a, T, A, R, F>(
+    cache: &MethodIdCache,
+    env: &'a JniEnv<'a>,
+    token: &NoException<'a>,
+    name: &str,
+) -> JavaResult<'a, ()>
+where
+    T: JavaClassRef<'a>,
+    A: JavaArgumentTuple,
+    R: JavaMethodResult<'a>,
+    F: JavaMethodSignature<A, R>,
+{
+    cache.get::<T>(env, token, name, F::method_signature())?;
+    Ok(())
+}
+
+/// Call a Java method without checking for an exception afterwards.
+///
+/// Same as [`call_method_cached`](../fn.call_method_cached.html), but doesn't require
+/// a [`NoException`](../struct.NoException.html) token and doesn't check for a pending exception.
+/// The method id must be looked up in advance with
+/// [`resolve_method`](fn.resolve_method.html).
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::String;
+/// use rust_jni::unchecked;
+/// #
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// static CODE_POINT_AT: MethodIdCache = MethodIdCache::new();
+///
+/// let string = String::new(env, &token, "string")?;
+/// // Safe because the cache is only used for `String::codePointAt`.
+/// unsafe {
+///     unchecked::resolve_method::<String, _, _, fn(i32) -> i32>(
+///         &CODE_POINT_AT,
+///         env,
+///         &token,
+///         "codePointAt\0",
+///     )
+/// }?;
+/// let mut sum = 0;
+/// for index in 0..6 {
+///     // Safe because correct arguments are passed, correct return type specified and
+///     // `codePointAt` doesn't throw for indices within the string.
+///     // See `String::codePointAt` javadoc:
+///     // https://docs.oracle.com/javase/10/docs/api/java/lang/String.html#codePointAt(int)
+///     sum += unsafe {
+///         unchecked::call_method_unchecked::<_, _, _, fn(i32) -> i32>(
+///             &CODE_POINT_AT,
+///             &string,
+///             (index,),
+///         )
+///     };
+/// }
+/// // A single exception check for the whole loop.
+/// if let Some(exception) = env.take_pending_exception() {
+///     return Err(exception);
+/// }
+/// assert_eq!(sum, "string".bytes().map(|byte| byte as i32).sum::<i32>());
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+///
+/// Panics if the method id wasn't looked up.
+///
+/// This function is unsafe because incorrect parameters can be passed to a method or incorrect
+/// return type specified, because the cache must always be used with the same class `T`
+/// and because the caller must uphold the [module](index.html) invariants about exceptions.
+pub unsafe fn call_method_unchecked<'a, T, A, R, F>(
+    cache: &MethodIdCache,
+    object: &T,
+    arguments: A,
+) -> R::ResultType
+where
+    T: JavaClassRef<'a>,
+    A: JavaArgumentTuple,
+    R: JavaMethodResult<'a>,
+    F: JavaMethodSignature<A, R>,
+{
+    let method_id = cache.cached().unwrap_or_else(|| {
+        panic!("The method id must be looked up with `resolve_method` before calling the method.")
+    });
+    R::call_method_with_id_unchecked::<T, A>(object, method_id, arguments)
+}
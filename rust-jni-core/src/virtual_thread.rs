@@ -0,0 +1,77 @@
+use crate::class::Class;
+use crate::env::JniEnv;
+use crate::java_version::jvm_feature_level;
+use crate::jni_bool;
+use crate::jni_methods;
+use crate::object::Object;
+use crate::result::JavaResult;
+use crate::token::NoException;
+use jni_sys;
+
+/// Check if the current thread is a Java
+/// [virtual thread](https://openjdk.org/jeps/444).
+///
+/// Virtual threads were added in Java 21, so this always returns `false` on older Java VMs.
+///
+/// Native code only runs on a virtual thread when a native method is called from one. While the
+/// native method runs, the virtual thread is pinned to it's carrier platform thread, so blocking
+/// in native code blocks the carrier and can starve other virtual threads. Holding a
+/// [`MonitorGuard`](struct.MonitorGuard.html) pins the virtual thread too. Long-running or
+/// blocking JNI work should be moved to platform threads, for example with
+/// [`JvmExecutor::run_on_platform_thread`](executor/struct.JvmExecutor.html#method.run_on_platform_thread).
+///
+/// Calls `Thread.currentThread().isVirtual()` every time, as the result is different per thread.
+///
+/// [`Thread::isVirtual` javadoc](https://docs.oracle.com/en/java/javase/21/docs/api/java.base/java/lang/Thread.html#isVirtual())
+///
+/// # Example
+/// ```
+/// # use rust_jni::*;
+/// #
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// // Threads attached from Rust are always platform threads.
+/// assert!(!is_virtual_thread(env, &token)?);
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+pub fn is_virtual_thread<'a>(env: &'a JniEnv<'a>, token: &NoException<'a>) -> JavaResult<'a, bool> {
+    if jvm_feature_level(env, token)? < 21 {
+        return Ok(false);
+    }
+    let class = Class::find(env, token, "java/lang/Thread")?;
+    // Safe because we ensure correct arguments and return type.
+    let thread = unsafe {
+        jni_methods::call_static_object_method(
+            &class,
+            token,
+            "currentThread\0",
+            "()Ljava/lang/Thread;\0",
+            (),
+        )
+    }?;
+    // Safe because the argument is a valid thread reference.
+    // Will not panic because the current thread is never `null`.
+    let thread = unsafe { Object::from_raw(env, thread.unwrap()) };
+    // Safe because we ensure correct arguments and return type.
+    let is_virtual = unsafe {
+        jni_methods::call_primitive_method::<jni_sys::jboolean>(
+            &thread,
+            token,
+            "isVirtual\0",
+            "()Z\0",
+            (),
+        )
+    }?;
+    Ok(jni_bool::to_rust(is_virtual))
+}
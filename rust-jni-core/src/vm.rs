@@ -1,14 +1,23 @@
 use crate::attach_arguments::AttachArguments;
+use crate::attach_guard::{attach_policy, AttachGuard, AttachPolicy};
+use crate::classes::class_loader::ClassLoader;
 use crate::env::JniEnv;
 use crate::error::JniError;
+#[cfg(not(feature = "no-invocation"))]
+use crate::error::VmCreationError;
+#[cfg(not(feature = "no-invocation"))]
 use crate::init_arguments::InitArguments;
+use crate::java_methods::FromObject;
+use crate::object_key::ObjectKey;
 use crate::token::NoException;
+use alloc::vec;
+use alloc::vec::Vec;
 use cfg_if::cfg_if;
+use core::ffi::c_void;
+use core::mem;
+use core::ptr;
 use core::ptr::NonNull;
 use jni_sys;
-use std;
-use std::os::raw::c_void;
-use std::ptr;
 
 /// A struct for interacting with the Java VM without owning it.
 ///
@@ -33,14 +42,18 @@ unsafe impl Send for JavaVMRef {}
 unsafe impl Sync for JavaVMRef {}
 
 impl JavaVMRef {
-    /// Get the raw Java VM pointer.
-    ///
-    /// This function provides low-level access to all of JNI and thus is unsafe.
-    ///
-    /// [JNI documentation](https://docs.oracle.com/en/java/javase/11/docs/specs/jni/invocation.html#invocation-api-functions).
-    #[inline(always)]
-    pub unsafe fn raw_jvm(&self) -> NonNull<jni_sys::JavaVM> {
-        self.java_vm
+    raw_passthrough! {
+        /// Get the raw Java VM pointer.
+        ///
+        /// This function provides low-level access to all of JNI and thus is unsafe.
+        ///
+        /// Not public with the `deny-unsafe-passthrough` feature.
+        ///
+        /// [JNI documentation](https://docs.oracle.com/en/java/javase/11/docs/specs/jni/invocation.html#invocation-api-functions).
+        #[inline(always)]
+        unsafe fn raw_jvm(&self) -> NonNull<jni_sys::JavaVM> {
+            self.java_vm
+        }
     }
 
     /// Unsafe because one can pass an invalid `java_vm` pointer.
@@ -48,6 +61,103 @@ impl JavaVMRef {
         Self { java_vm }
     }
 
+    /// Run a closure with the [`JniEnv`](struct.JniEnv.html) of the current thread.
+    ///
+    /// If the current thread is not attached to the Java VM, attach it as a daemon
+    /// for the duration of the call.
+    ///
+    /// Panics if the current thread can't be attached.
+    ///
+    /// Unsafe because the closure gets a [`JniEnv`](struct.JniEnv.html) without a token
+    /// and must not assume anything about pending exceptions.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#getenv)
+    pub(crate) unsafe fn with_current_env<T>(&self, function: impl FnOnce(&JniEnv) -> T) -> T {
+        self.try_with_current_env(function).unwrap_or_else(|error| {
+            panic!("Could not attach the current thread. Status: {:?}", error)
+        })
+    }
+
+    /// Run a closure with the [`JniEnv`](struct.JniEnv.html) of the current thread.
+    ///
+    /// Same as [`with_current_env`](struct.JavaVMRef.html#method.with_current_env), but returns
+    /// an error without running the closure if the current thread can't be attached.
+    ///
+    /// Unsafe because the closure gets a [`JniEnv`](struct.JniEnv.html) without a token
+    /// and must not assume anything about pending exceptions.
+    pub(crate) unsafe fn try_with_current_env<T>(
+        &self,
+        function: impl FnOnce(&JniEnv) -> T,
+    ) -> Result<T, JniError> {
+        let raw_jvm = self.raw_jvm().as_ptr();
+        let mut jni_env: *mut jni_sys::JNIEnv = ptr::null_mut();
+        let get_env_fn = (**raw_jvm).GetEnv.unwrap();
+        let error = JniError::from_raw(get_env_fn(
+            raw_jvm,
+            (&mut jni_env) as *mut *mut jni_sys::JNIEnv as *mut *mut c_void,
+            jni_sys::JNI_VERSION_1_2,
+        ));
+        let attached = match error {
+            None => false,
+            Some(JniError::ThreadDetached) => {
+                let attach_fn = (**raw_jvm).AttachCurrentThreadAsDaemon.unwrap();
+                let error = JniError::from_raw(attach_fn(
+                    raw_jvm,
+                    (&mut jni_env) as *mut *mut jni_sys::JNIEnv as *mut *mut c_void,
+                    ptr::null_mut(),
+                ));
+                if let Some(error) = error {
+                    return Err(error);
+                }
+                true
+            }
+            Some(error) => return Err(error),
+        };
+        // Should not fail: successful `GetEnv` and `AttachCurrentThreadAsDaemon` calls
+        // guarantee a non-null env pointer.
+        // The env must never be dropped as dropping it detaches the current thread.
+        let env = mem::ManuallyDrop::new(JniEnv::native(self, NonNull::new(jni_env).unwrap()));
+        let result = function(&env);
+        if attached {
+            JavaVM::detach_or_error(self.raw_jvm());
+        }
+        Ok(result)
+    }
+
+    /// Run a closure with the [`JniEnv`](struct.JniEnv.html) of the current thread only if
+    /// the thread is attached to the Java VM.
+    ///
+    /// Returns `None` without running the closure if the current thread is not attached.
+    ///
+    /// Unsafe because it allows to call JNI with a pending exception.
+    pub(crate) unsafe fn with_attached_env<T>(
+        &self,
+        function: impl FnOnce(&JniEnv) -> T,
+    ) -> Option<T> {
+        let raw_jvm = self.raw_jvm().as_ptr();
+        let mut jni_env: *mut jni_sys::JNIEnv = ptr::null_mut();
+        let get_env_fn = (**raw_jvm).GetEnv.unwrap();
+        let error = JniError::from_raw(get_env_fn(
+            raw_jvm,
+            (&mut jni_env) as *mut *mut jni_sys::JNIEnv as *mut *mut c_void,
+            jni_sys::JNI_VERSION_1_2,
+        ));
+        match error {
+            None => {
+                // Should not fail: successful `GetEnv` call guarantees a non-null env pointer.
+                // The env must never be dropped as dropping it detaches the current thread.
+                let env =
+                    mem::ManuallyDrop::new(JniEnv::native(self, NonNull::new(jni_env).unwrap()));
+                Some(function(&env))
+            }
+            Some(JniError::ThreadDetached) => None,
+            Some(error) => panic!(
+                "GetEnv JNI method returned an unexpected error code {:?}",
+                error
+            ),
+        }
+    }
+
     #[cfg(test)]
     pub(crate) fn test(ptr: *mut jni_sys::JavaVM) -> JavaVMRef {
         // It's fine if the VM is null in unit tests as they don't call the actual JNI API.
@@ -91,7 +201,7 @@ mod java_vm_ref_tests {
 ///
 /// let vms = JavaVM::list().unwrap();
 /// unsafe {
-///     assert_eq!(vms[0].raw_jvm(), vm.raw_jvm());
+///     assert_eq!(vms.java_vms[0].raw_jvm(), vm.raw_jvm());
 /// }
 /// ```
 /// [`JavaVM`](struct.JavaVM.html) is `Send + Sync`. It means it can be shared between threads.
@@ -121,6 +231,21 @@ pub struct JavaVM {
     need_drop: bool,
 }
 
+/// Java VMs created in the process, returned by [`JavaVM::list`](struct.JavaVM.html#method.list).
+///
+/// Not available with the `no-invocation` feature.
+#[cfg(not(feature = "no-invocation"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatedJavaVMs {
+    /// Non-owning references to the created Java VMs.
+    pub java_vms: Vec<JavaVMRef>,
+    /// The number of created Java VMs reported by JNI.
+    ///
+    /// Always equal to the length of [`java_vms`](#structfield.java_vms), as
+    /// [`JavaVM::list`](struct.JavaVM.html#method.list) retries until all Java VMs fit.
+    pub total_count: usize,
+}
+
 impl JavaVM {
     /// Create a Java VM with the specified arguments.
     ///
@@ -131,8 +256,18 @@ impl JavaVM {
     /// [`drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html#tymethod.drop)-ed.
     /// TODO(monnoroch): figure out why and document it.
     ///
+    /// Returns a [`VmCreationError`](enum.VmCreationError.html) describing the problem if the
+    /// Java VM could not be created.
+    ///
+    /// With the `graalvm` feature the Java VM is an isolate of a GraalVM native-image shared
+    /// library instead of a `libjvm` Java VM. Everything else works the same way.
+    ///
+    /// Not available with the `no-invocation` feature.
+    ///
     /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_createjavavm)
-    pub fn create(arguments: &InitArguments) -> Result<Self, JniError> {
+    #[cfg(not(feature = "no-invocation"))]
+    pub fn create(arguments: &InitArguments) -> Result<Self, VmCreationError> {
+        arguments.validate()?;
         let mut java_vm: *mut jni_sys::JavaVM = ptr::null_mut();
         let mut jni_env: *mut jni_sys::JNIEnv = ptr::null_mut();
         let mut strings_buffer = vec![];
@@ -166,64 +301,60 @@ impl JavaVM {
                     need_drop: true,
                 })
             }
-            Some(JniError::UnsupportedVersion) => panic!(
-                "Got upsupported version error when creating a Java VM. \
-                 Should not happen as `InitArguments` are supposed to check \
-                 for version support."
-            ),
-            Some(JniError::ThreadDetached) => {
-                panic!("Unexpected `EDETACHED` error when creating a Java VM.")
-            }
-            Some(error) => Err(error),
+            Some(error) => Err(VmCreationError::from_jni(error, arguments.version())),
         }
     }
 
     /// Get a list of created Java VMs.
     ///
-    /// Returns a list of non-owning [`JavaVMRef`](struct.JavaVMRef.html)-s.
+    /// Returns non-owning [`JavaVMRef`](struct.JavaVMRef.html)-s in a
+    /// [`CreatedJavaVMs`](struct.CreatedJavaVMs.html). If a Java VM is created while the list
+    /// is being retrieved, the retrieval is retried with a larger buffer until the list is complete.
+    ///
+    /// Not available with the `no-invocation` feature.
     ///
     /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#jni_getcreatedjavavms)
-    pub fn list() -> Result<Vec<JavaVMRef>, JniError> {
-        let mut vms_created: jni_sys::jsize = 0;
-        // Safe because arguments are correct.
-        let error = JniError::from_raw(unsafe {
-            JNI_GetCreatedJavaVMs(
-                ::std::ptr::null_mut(),
-                0,
-                (&mut vms_created) as *mut jni_sys::jsize,
-            )
-        });
-        match error {
-            None => {
-                let mut java_vms: Vec<*mut jni_sys::JavaVM> = vec![];
-                java_vms.resize(vms_created as usize, ::std::ptr::null_mut());
-                let mut tmp: jni_sys::jsize = 0;
-                // Safe because arguments are valid.
-                let error = JniError::from_raw(unsafe {
-                    JNI_GetCreatedJavaVMs(
-                        (java_vms.as_mut_ptr()) as *mut *mut jni_sys::JavaVM,
-                        vms_created,
-                        // Technically, a new VM could have been created since the previous call to
-                        // `JNI_GetCreatedJavaVMs`. But then we also technically should not return
-                        // any new ones, because they weren't there wneh this function was called.
-                        (&mut tmp) as *mut jni_sys::jsize,
-                    )
-                });
-                match error {
-                    None => Ok(java_vms
-                        .iter()
-                        .cloned()
+    #[cfg(not(feature = "no-invocation"))]
+    pub fn list() -> Result<CreatedJavaVMs, JniError> {
+        let mut java_vms: Vec<*mut jni_sys::JavaVM> = vec![];
+        loop {
+            let buffer = if java_vms.is_empty() {
+                ptr::null_mut()
+            } else {
+                java_vms.as_mut_ptr()
+            };
+            let mut vms_created: jni_sys::jsize = 0;
+            // Safe because the buffer has room for `java_vms.len()` pointers.
+            let error = JniError::from_raw(unsafe {
+                JNI_GetCreatedJavaVMs(
+                    buffer,
+                    java_vms.len() as jni_sys::jsize,
+                    (&mut vms_created) as *mut jni_sys::jsize,
+                )
+            });
+            if let Some(error) = error {
+                return Err(error);
+            }
+
+            let total_count = vms_created.max(0) as usize;
+            if total_count <= java_vms.len() {
+                java_vms.truncate(total_count);
+                return Ok(CreatedJavaVMs {
+                    java_vms: java_vms
+                        .into_iter()
                         // Safe as the validity of the pointer is guaranteed by JNI.
                         .map(|java_vm| unsafe {
                             // Should not fail because JNI_GetCreatedJavaVMs guarantees
                             // non-null Java VM pointers.
                             JavaVMRef::from_ptr(NonNull::new(java_vm).unwrap())
                         })
-                        .collect()),
-                    Some(error) => Err(error),
-                }
+                        .collect(),
+                    total_count,
+                });
             }
-            Some(error) => Err(error),
+            // JNI only fills the buffer up to its size, so a Java VM created since the previous
+            // call would be silently dropped. Grow the buffer and ask again.
+            java_vms.resize(total_count, ptr::null_mut());
         }
     }
 
@@ -249,14 +380,18 @@ impl JavaVM {
         JniError::from_raw(detach_fn(java_vm.as_ptr()))
     }
 
-    /// Get the raw Java VM pointer.
-    ///
-    /// This function provides low-level access to all of JNI and thus is unsafe.
-    ///
-    /// [JNI documentation](https://docs.oracle.com/en/java/javase/11/docs/specs/jni/invocation.html#invocation-api-functions).
-    #[inline(always)]
-    pub unsafe fn raw_jvm(&self) -> NonNull<jni_sys::JavaVM> {
-        self.java_vm.raw_jvm()
+    raw_passthrough! {
+        /// Get the raw Java VM pointer.
+        ///
+        /// This function provides low-level access to all of JNI and thus is unsafe.
+        ///
+        /// Not public with the `deny-unsafe-passthrough` feature.
+        ///
+        /// [JNI documentation](https://docs.oracle.com/en/java/javase/11/docs/specs/jni/invocation.html#invocation-api-functions).
+        #[inline(always)]
+        unsafe fn raw_jvm(&self) -> NonNull<jni_sys::JavaVM> {
+            self.java_vm.raw_jvm()
+        }
     }
 
     /// Attach the current thread to the Java VM and execute code that calls JNI on it.
@@ -363,6 +498,141 @@ impl JavaVM {
         }
     }
 
+    /// Attach the current thread to the Java VM and get a [`NoException`](struct.NoException.html)
+    /// token for it in one step.
+    ///
+    /// The [`JniEnv`](struct.JniEnv.html) is stored in the `env` slot provided by the caller, so
+    /// that the token can borrow it. Unlike [`JniEnv::token`](struct.JniEnv.html#method.token)
+    /// this never panics, as a newly attached thread has no token given out yet.
+    ///
+    /// The thread is detached when the slot is dropped or by taking the
+    /// [`JniEnv`](struct.JniEnv.html) out of it and calling
+    /// [`JniEnv::detach`](struct.JniEnv.html#method.detach):
+    /// ```
+    /// # use rust_jni::*;
+    /// #
+    /// # let init_arguments = InitArguments::default();
+    /// # let vm = JavaVM::create(&init_arguments).unwrap();
+    /// let mut env = None;
+    /// let (attached_env, token) = vm
+    ///     .attach_with_token(&AttachArguments::new(init_arguments.version()), &mut env)
+    ///     .unwrap();
+    /// {
+    ///     let string = java::lang::String::empty(attached_env, &token).unwrap();
+    ///     assert_eq!(string.len(&token), 0);
+    /// }
+    /// let token = token.consume();
+    /// env.take().unwrap().detach(token);
+    /// ```
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#attachcurrentthread)
+    pub fn attach_with_token<'vm: 'env, 'env, 'a>(
+        &'vm self,
+        arguments: &AttachArguments,
+        env: &'a mut Option<JniEnv<'env>>,
+    ) -> Result<(&'a JniEnv<'env>, NoException<'a>), JniError> {
+        let attached = self.attach(arguments)?;
+        Ok(Self::store_with_token(env, attached))
+    }
+
+    /// Attach the current thread to the Java VM as a daemon and get a
+    /// [`NoException`](struct.NoException.html) token for it in one step.
+    ///
+    /// See [`attach_with_token`](struct.JavaVM.html#method.attach_with_token) for details.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#attachcurrentthreadasdaemon)
+    pub fn attach_daemon_with_token<'vm: 'env, 'env, 'a>(
+        &'vm self,
+        arguments: &AttachArguments,
+        env: &'a mut Option<JniEnv<'env>>,
+    ) -> Result<(&'a JniEnv<'env>, NoException<'a>), JniError> {
+        let attached = self.attach_daemon(arguments)?;
+        Ok(Self::store_with_token(env, attached))
+    }
+
+    /// Get a [`JniEnv`](struct.JniEnv.html) for the current thread, attaching it to the Java VM
+    /// only if it's not attached yet.
+    ///
+    /// Unlike [`attach`](#method.attach), doesn't panic when the thread is already attached,
+    /// for example by another library or because it's a Java thread calling a native method.
+    /// The returned [`AttachGuard`](struct.AttachGuard.html) only detaches the thread if it
+    /// was attached by this call. Threads are attached according to the
+    /// [`AttachPolicy`](enum.AttachPolicy.html).
+    ///
+    /// The [`NoException`](struct.NoException.html) token of the returned
+    /// [`JniEnv`](struct.JniEnv.html) is independent of the tokens of other
+    /// [`JniEnv`](struct.JniEnv.html)-s of the same thread, so the callers must not hold on to
+    /// tokens across calls to code that might attach this way.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#getenv)
+    pub fn attach_or_current<'vm: 'env, 'env>(
+        &'vm self,
+        arguments: &AttachArguments,
+    ) -> Result<AttachGuard<'env>, JniError> {
+        let mut jni_env: *mut jni_sys::JNIEnv = ptr::null_mut();
+        // Safe because the arguments are correct.
+        let error = unsafe {
+            let get_env_fn = (**self.raw_jvm().as_ptr()).GetEnv.unwrap();
+            JniError::from_raw(get_env_fn(
+                self.raw_jvm().as_ptr(),
+                (&mut jni_env) as *mut *mut jni_sys::JNIEnv as *mut *mut c_void,
+                arguments.version().to_raw(),
+            ))
+        };
+        match error {
+            // Should not fail: successful `GetEnv` call guarantees a non-null env pointer.
+            // Safe because the env pointer belongs to the current thread.
+            None => Ok(AttachGuard::new(
+                unsafe { JniEnv::native(&self.java_vm, NonNull::new(jni_env).unwrap()) },
+                false,
+            )),
+            Some(JniError::ThreadDetached) => {
+                let env = match attach_policy() {
+                    AttachPolicy::Normal => self.attach(arguments)?,
+                    AttachPolicy::Daemon => self.attach_daemon(arguments)?,
+                };
+                Ok(AttachGuard::new(env, true))
+            }
+            // According to the
+            // [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/invocation.html#getenv),
+            // can only returd `OK`, `EDETACHED` and `EVERSION`.
+            // Will not return `EVERSION` here, because the version was already checked when
+            // creating the Java VM.
+            Some(error) => panic!(
+                "GetEnv JNI method returned an unexpected error code {:?}",
+                error
+            ),
+        }
+    }
+
+    fn store_with_token<'env, 'a>(
+        slot: &'a mut Option<JniEnv<'env>>,
+        env: JniEnv<'env>,
+    ) -> (&'a JniEnv<'env>, NoException<'a>) {
+        *slot = Some(env);
+        let env = slot.as_ref().unwrap();
+        // Safe because the token is obtained once from a newly attached `JniEnv`.
+        let token = unsafe { env.token_internal() };
+        (env, token)
+    }
+
+    /// Set the context class loader of a newly attached thread.
+    fn set_attached_context_class_loader(env: &JniEnv, class_loader: &ObjectKey) {
+        // Safe because a newly attached thread has no pending exception.
+        let token = unsafe { NoException::new(env) };
+        let result = class_loader.object(env, &token).and_then(|class_loader| {
+            // Safe because the key was created from a class loader.
+            let class_loader = unsafe { ClassLoader::from_object(class_loader) };
+            env.set_context_class_loader(&token, &class_loader)
+        });
+        if let Err(exception) = result {
+            panic!(
+                "Failed to set the context class loader of an attached thread: {:?}.",
+                exception
+            );
+        }
+    }
+
     /// Unsafe because:
     /// 1. One can pass an invalid `attach_fn`.
     /// 2. The current thread might already be attached.
@@ -377,7 +647,7 @@ impl JavaVM {
     ) -> Result<JniEnv, JniError> {
         let mut buffer: Vec<u8> = vec![];
         let mut raw_arguments = arguments.to_raw(&mut buffer);
-        let mut jni_env: *mut jni_sys::JNIEnv = ::std::ptr::null_mut();
+        let mut jni_env: *mut jni_sys::JNIEnv = ::core::ptr::null_mut();
         let get_env_fn = (**self.raw_jvm().as_ptr()).GetEnv.unwrap();
         // Safe, because the arguments are correct.
         let error = JniError::from_raw(get_env_fn(
@@ -395,10 +665,13 @@ impl JavaVM {
                 ));
                 match error {
                     // Shuld not fail: successful call to AttachCurrentThread guarantees a non-null env pointer.
-                    None => Ok(JniEnv::attached(
-                        &self.java_vm,
-                        NonNull::new(jni_env).unwrap(),
-                    )),
+                    None => {
+                        let env = JniEnv::attached(&self.java_vm, NonNull::new(jni_env).unwrap());
+                        if let Some(class_loader) = arguments.context_class_loader() {
+                            Self::set_attached_context_class_loader(&env, class_loader);
+                        }
+                        Ok(env)
+                    }
                     Some(JniError::UnsupportedVersion) => panic!(
                         "Got upsupported version error when creating a Java VM. \
                          Should not happen as `InitArguments` are supposed to check \
@@ -555,12 +828,14 @@ mod java_vm_drop_tests {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(feature = "no-invocation")))]
 mod java_vm_create_tests {
     use super::*;
+    use crate::init_arguments::JvmOption;
+    use crate::version::JniVersion;
+    use core::mem;
     use mockall::*;
     use serial_test_derive::serial;
-    use std::mem;
 
     generate_java_vm_mock!(mock);
 
@@ -623,44 +898,71 @@ mod java_vm_create_tests {
             .return_const(jni_sys::JNI_ERR);
         assert_eq!(
             JavaVM::create(&InitArguments::default()).err().unwrap(),
-            JniError::Unknown(jni_sys::JNI_ERR)
+            VmCreationError::Jni(JniError::Unknown(jni_sys::JNI_ERR))
         );
     }
 
     #[test]
     #[serial]
-    // `serial` messes up compiler lints for other attributes.
-    #[allow(unused_attributes)]
-    // Result unused because the funtion will panic.
-    #[allow(unused_must_use)]
-    #[should_panic(expected = "upsupported version")]
     fn create_error_version() {
         let create_vm_mock = jni_mock::JNI_CreateJavaVM_context();
         create_vm_mock
             .expect()
             .times(1)
             .return_const(jni_sys::JNI_EVERSION);
-        JavaVM::create(&InitArguments::default());
+        assert_eq!(
+            JavaVM::create(&InitArguments::default().with_version(JniVersion::V10))
+                .err()
+                .unwrap(),
+            VmCreationError::UnsupportedVersion(JniVersion::V10)
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn create_error_exists() {
+        let create_vm_mock = jni_mock::JNI_CreateJavaVM_context();
+        create_vm_mock
+            .expect()
+            .times(1)
+            .return_const(jni_sys::JNI_EEXIST);
+        assert_eq!(
+            JavaVM::create(&InitArguments::default()).err().unwrap(),
+            VmCreationError::VmExists
+        );
     }
 
     #[test]
     #[serial]
-    // `serial` messes up compiler lints for other attributes.
-    #[allow(unused_attributes)]
-    // Result unused because the funtion will panic.
-    #[allow(unused_must_use)]
-    #[should_panic(expected = "Unexpected `EDETACHED`")]
     fn create_error_detached() {
         let create_vm_mock = jni_mock::JNI_CreateJavaVM_context();
         create_vm_mock
             .expect()
             .times(1)
             .return_const(jni_sys::JNI_EDETACHED);
-        JavaVM::create(&InitArguments::default());
+        assert_eq!(
+            JavaVM::create(&InitArguments::default()).err().unwrap(),
+            VmCreationError::Jni(JniError::ThreadDetached)
+        );
+    }
+
+    #[test]
+    fn create_error_invalid_option() {
+        let option = JvmOption::Unknown("a\0b".to_owned());
+        assert_eq!(
+            JavaVM::create(&InitArguments::default().with_option(option.clone()))
+                .err()
+                .unwrap(),
+            VmCreationError::InvalidOption {
+                option,
+                option_string: "a\0b".to_owned(),
+                reason: "Invalid option \"a\0b\".".to_owned(),
+            }
+        );
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(feature = "no-invocation")))]
 mod java_vm_list_tests {
     use super::*;
     use mockall::*;
@@ -713,9 +1015,156 @@ mod java_vm_list_tests {
             .return_const(jni_sys::JNI_OK)
             .in_sequence(&mut sequence);
         let vms = JavaVM::list().unwrap();
+        assert_eq!(vms.total_count, 2);
+        assert_eq!(vms.java_vms.len(), 2);
+        unsafe {
+            assert_eq!(vms.java_vms[0].raw_jvm().as_ptr(), raw_java_vm_ptr_1);
+            assert_eq!(vms.java_vms[1].raw_jvm().as_ptr(), raw_java_vm_ptr_2);
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn list_empty() {
+        let list_vms_mock = jni_mock::JNI_GetCreatedJavaVMs_context();
+        list_vms_mock
+            .expect()
+            .times(1)
+            .withf(move |java_vms, buffer_size, vms_count| {
+                if *java_vms != ptr::null_mut() || *buffer_size != 0 {
+                    false
+                } else {
+                    unsafe {
+                        **vms_count = 0 as jni_sys::jint;
+                    }
+                    true
+                }
+            })
+            .return_const(jni_sys::JNI_OK);
+        assert_eq!(
+            JavaVM::list(),
+            Ok(CreatedJavaVMs {
+                java_vms: vec![],
+                total_count: 0,
+            })
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn list_vm_created_between_calls() {
+        let raw_java_vm_ptr_1 = 0x1234 as *mut jni_sys::JavaVM;
+        let raw_java_vm_ptr_2 = 0x1235 as *mut jni_sys::JavaVM;
+
+        // Need to pass a number to the closure below as pointers are not Send.
+        let raw_java_vm_ptr_1_usize = raw_java_vm_ptr_1 as usize;
+        let raw_java_vm_ptr_2_usize = raw_java_vm_ptr_2 as usize;
+
+        let mut sequence = Sequence::new();
+        let list_vms_mock = jni_mock::JNI_GetCreatedJavaVMs_context();
+        list_vms_mock
+            .expect()
+            .times(1)
+            .withf(move |java_vms, buffer_size, vms_count| {
+                if *java_vms != ptr::null_mut() || *buffer_size != 0 {
+                    false
+                } else {
+                    unsafe {
+                        **vms_count = 1 as jni_sys::jint;
+                    }
+                    true
+                }
+            })
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        // A second Java VM is created after the first call: it doesn't fit into the buffer.
+        list_vms_mock
+            .expect()
+            .times(1)
+            .withf(move |java_vms, buffer_size, vms_count| {
+                if *buffer_size != 1 {
+                    false
+                } else {
+                    unsafe {
+                        **java_vms = raw_java_vm_ptr_1_usize as *mut jni_sys::JavaVM;
+                        **vms_count = 2 as jni_sys::jint;
+                    }
+                    true
+                }
+            })
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        list_vms_mock
+            .expect()
+            .times(1)
+            .withf(move |java_vms, buffer_size, vms_count| {
+                if *buffer_size != 2 {
+                    false
+                } else {
+                    unsafe {
+                        **java_vms = raw_java_vm_ptr_1_usize as *mut jni_sys::JavaVM;
+                        *((*java_vms).offset(1)) = raw_java_vm_ptr_2_usize as *mut jni_sys::JavaVM;
+                        **vms_count = 2 as jni_sys::jint;
+                    }
+                    true
+                }
+            })
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let vms = JavaVM::list().unwrap();
+        assert_eq!(vms.total_count, 2);
+        assert_eq!(vms.java_vms.len(), 2);
+        unsafe {
+            assert_eq!(vms.java_vms[0].raw_jvm().as_ptr(), raw_java_vm_ptr_1);
+            assert_eq!(vms.java_vms[1].raw_jvm().as_ptr(), raw_java_vm_ptr_2);
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn list_vm_destroyed_between_calls() {
+        let raw_java_vm_ptr = 0x1234 as *mut jni_sys::JavaVM;
+        // Need to pass a number to the closure below as pointers are not Send.
+        let raw_java_vm_ptr_usize = raw_java_vm_ptr as usize;
+
+        let mut sequence = Sequence::new();
+        let list_vms_mock = jni_mock::JNI_GetCreatedJavaVMs_context();
+        list_vms_mock
+            .expect()
+            .times(1)
+            .withf(move |_java_vms, buffer_size, vms_count| {
+                if *buffer_size != 0 {
+                    false
+                } else {
+                    unsafe {
+                        **vms_count = 2 as jni_sys::jint;
+                    }
+                    true
+                }
+            })
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        list_vms_mock
+            .expect()
+            .times(1)
+            .withf(move |java_vms, buffer_size, vms_count| {
+                if *buffer_size != 2 {
+                    false
+                } else {
+                    unsafe {
+                        **java_vms = raw_java_vm_ptr_usize as *mut jni_sys::JavaVM;
+                        **vms_count = 1 as jni_sys::jint;
+                    }
+                    true
+                }
+            })
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let vms = JavaVM::list().unwrap();
+        assert_eq!(vms.total_count, 1);
+        assert_eq!(vms.java_vms.len(), 1);
         unsafe {
-            assert_eq!(vms[0].raw_jvm().as_ptr(), raw_java_vm_ptr_1);
-            assert_eq!(vms[1].raw_jvm().as_ptr(), raw_java_vm_ptr_2);
+            assert_eq!(vms.java_vms[0].raw_jvm().as_ptr(), raw_java_vm_ptr);
         }
     }
 
@@ -738,6 +1187,12 @@ mod java_vm_list_tests {
         list_vms_mock
             .expect()
             .times(1)
+            .withf(move |_java_vms, _buffer_size, vms_count| {
+                unsafe {
+                    **vms_count = 1 as jni_sys::jint;
+                }
+                true
+            })
             .return_const(jni_sys::JNI_OK)
             .in_sequence(&mut sequence);
         list_vms_mock
@@ -754,9 +1209,9 @@ mod java_vm_with_attached_tests {
     use super::*;
     use crate::java_string::from_java_string;
     use crate::version::JniVersion;
+    use core::cell::RefCell;
     use mockall::*;
     use serial_test_derive::serial;
-    use std::cell::RefCell;
     use std::ffi::{c_void, CStr};
 
     generate_java_vm_mock!(mock);
@@ -1092,13 +1547,14 @@ mod java_vm_with_attached_tests {
 #[cfg(test)]
 mod java_vm_attach_tests {
     use super::*;
+    use crate::attach_guard::set_attach_policy;
     use crate::java_string::from_java_string;
     use crate::version::JniVersion;
+    use core::cell::RefCell;
+    use core::mem;
     use mockall::*;
     use serial_test_derive::serial;
-    use std::cell::RefCell;
     use std::ffi::{c_void, CStr};
-    use std::mem;
 
     generate_java_vm_mock!(mock);
     generate_jni_env_mock!(jni_mock);
@@ -1356,13 +1812,83 @@ mod java_vm_attach_tests {
         // Don't want to drop a manually created `JniEnv` and `JavaVM`.
         mem::forget(env);
     }
+
+    #[test]
+    #[serial]
+    fn attach_or_current_already_attached() {
+        let raw_env_ptr = 0x1234 as *mut jni_sys::JNIEnv;
+        let raw_env_ptr_usize = raw_env_ptr as usize;
+        let raw_java_vm = mock::raw_java_vm();
+        let raw_java_vm_ptr = &mut (&raw_java_vm as jni_sys::JavaVM) as *mut jni_sys::JavaVM;
+        let get_env_mock = mock::get_env_context();
+        get_env_mock
+            .expect()
+            .times(1)
+            .withf(move |_java_vm, jni_env, version| unsafe {
+                **jni_env = raw_env_ptr_usize as *mut c_void;
+                *version == jni_sys::JNI_VERSION_1_8
+            })
+            .return_const(jni_sys::JNI_OK);
+        let vm = JavaVM::test(raw_java_vm_ptr);
+        let env = vm
+            .attach_or_current(&AttachArguments::new(JniVersion::V8))
+            .unwrap();
+        assert!(!env.attached());
+        unsafe {
+            assert_eq!(env.raw_env().as_ptr(), raw_env_ptr);
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn attach_or_current_daemon() {
+        let raw_env = jni_mock::raw_jni_env();
+        let raw_env_ptr = &mut (&raw_env as ::jni_sys::JNIEnv) as *mut ::jni_sys::JNIEnv;
+        let raw_env_ptr_usize = raw_env_ptr as usize;
+        let raw_java_vm = mock::raw_java_vm();
+        let raw_java_vm_ptr = &mut (&raw_java_vm as jni_sys::JavaVM) as *mut jni_sys::JavaVM;
+        let mut sequence = Sequence::new();
+        let get_env_mock = mock::get_env_context();
+        get_env_mock
+            .expect()
+            .times(2)
+            .return_const(jni_sys::JNI_EDETACHED)
+            .in_sequence(&mut sequence);
+        let attach_current_thread_as_daemon_mock = mock::attach_current_thread_as_daemon_context();
+        attach_current_thread_as_daemon_mock
+            .expect()
+            .times(1)
+            .withf(move |_java_vm, jni_env, _argument| unsafe {
+                **jni_env = raw_env_ptr_usize as *mut c_void;
+                true
+            })
+            .return_const(jni_sys::JNI_OK)
+            .in_sequence(&mut sequence);
+        let exception_check_mock = jni_mock::exception_check_context();
+        exception_check_mock
+            .expect()
+            .times(1)
+            .return_const(jni_sys::JNI_FALSE)
+            .in_sequence(&mut sequence);
+        let vm = JavaVM::test(raw_java_vm_ptr);
+        set_attach_policy(AttachPolicy::Daemon);
+        let env = vm.attach_or_current(&AttachArguments::new(JniVersion::V8));
+        set_attach_policy(AttachPolicy::Normal);
+        let env = env.unwrap();
+        assert!(env.attached());
+        unsafe {
+            assert_eq!(env.raw_env().as_ptr(), raw_env_ptr);
+        }
+        // Don't want to drop a manually created `JniEnv` and `JavaVM`.
+        mem::forget(env);
+    }
 }
 
 cfg_if! {
-    if #[cfg(test)] {
+    if #[cfg(all(test, not(feature = "no-invocation")))] {
         generate_jni_functions_mock!(jni_mock);
-    } else {
-        use jni_sys::JNI_CreateJavaVM;
-        use jni_sys::JNI_GetCreatedJavaVMs;
+    } else if #[cfg(not(feature = "no-invocation"))] {
+        use crate::backend::JNI_CreateJavaVM;
+        use crate::backend::JNI_GetCreatedJavaVMs;
     }
 }
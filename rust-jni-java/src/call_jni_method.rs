@@ -0,0 +1,37 @@
+// This file is textually included, not imported as a module.
+// Thus we need to disable "dead code" warnings as these macros are
+// actually used.
+
+// A copy of the `rust-jni-core` macros that goes through the raw pointer accessors
+// that don't depend on the `deny-unsafe-passthrough` feature.
+#[allow(unused_macros)]
+macro_rules! call_jni_method {
+    ($env:expr, $method:ident) => {
+        {
+            let raw_env = rust_jni_core::__bindings::raw_env($env).as_ptr();
+            let jni_fn = ((**raw_env).$method).unwrap();
+            jni_fn(raw_env)
+        }
+    };
+    ($env:expr, $method:ident, $($argument:expr),*) => {
+        {
+            let raw_env = rust_jni_core::__bindings::raw_env($env).as_ptr();
+            let jni_fn = ((**raw_env).$method).unwrap();
+            jni_fn(raw_env, $($argument),*)
+        }
+    };
+}
+
+// It's actually used.
+#[allow(unused_macros)]
+macro_rules! call_nullable_jni_method {
+    ($env:expr, $token:expr, $method:ident, $($argument:expr),*) => {
+        $token.with_owned($env, #[inline(always)] |token| {
+            let result = call_jni_method!($env, $method, $($argument),*);
+            match NonNull::new(result) {
+                None => CallOutcome::Err(token.exchange($env)),
+                Some(result) => CallOutcome::Ok((result, token)),
+            }
+        })
+    }
+}
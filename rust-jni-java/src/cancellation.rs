@@ -0,0 +1,104 @@
+use crate::classes::atomic_boolean::AtomicBoolean;
+use core::sync::atomic::{AtomicBool, Ordering};
+use rust_jni_core::JavaClassExt;
+use rust_jni_core::JavaResult;
+use rust_jni_core::JniEnv;
+use rust_jni_core::NoException;
+use rust_jni_core::OwnedObject;
+
+/// A cooperative cancellation flag shared between Rust and Java code.
+///
+/// Owns a global reference to a Java
+/// [`AtomicBoolean`](java/util/concurrent/atomic/struct.AtomicBoolean.html), which is passed to
+/// long-running Java methods with [`flag`](#method.flag). Java code checks it with
+/// `AtomicBoolean.get()` and stops when it's set, the same way it would check a flag set from
+/// Java.
+///
+/// [`cancel`](#method.cancel) sets both the Java flag and a Rust-side copy, so Rust code can check
+/// for cancellation with [`is_cancelled`](#method.is_cancelled) without calling into Java.
+/// Cancellations made by Java code setting the flag directly are only seen by
+/// [`poll`](#method.poll).
+///
+/// The source can be shared between threads. Once cancelled, it stays cancelled.
+///
+/// Only available with the `java-util` feature.
+///
+/// # Example
+/// ```
+/// # use rust_jni::*;
+/// #
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let source = CancellationSource::new(env, &token)?;
+/// // Pass the flag to a Java method, which stops once it's set.
+/// let flag = source.flag(env, &token)?;
+/// assert!(!source.is_cancelled());
+///
+/// source.cancel(env, &token)?;
+/// assert!(source.is_cancelled());
+/// assert!(flag.get(&token)?);
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CancellationSource {
+    flag: OwnedObject<AtomicBoolean<'static>>,
+    cancelled: AtomicBool,
+}
+
+impl CancellationSource {
+    /// Create a new source that is not cancelled.
+    pub fn new<'a>(env: &'a JniEnv<'a>, token: &NoException<'a>) -> JavaResult<'a, Self> {
+        let flag = AtomicBoolean::new(env, token, false)?;
+        Ok(Self {
+            flag: flag.detach_from_env(token)?,
+            cancelled: AtomicBool::new(false),
+        })
+    }
+
+    /// Get a local reference to the Java flag, to pass it to Java methods.
+    pub fn flag<'a>(
+        &self,
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+    ) -> JavaResult<'a, AtomicBoolean<'a>> {
+        self.flag.attach_to_env(env, token)
+    }
+
+    /// Request cancellation, setting the Java flag.
+    pub fn cancel<'a>(&self, env: &'a JniEnv<'a>, token: &NoException<'a>) -> JavaResult<'a, ()> {
+        self.cancelled.store(true, Ordering::Release);
+        self.flag(env, token)?.set(token, true)
+    }
+
+    /// Check if [`cancel`](#method.cancel) was called, without calling into Java.
+    #[inline(always)]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Check if the source was cancelled either from Rust or by Java code setting the flag.
+    ///
+    /// A cancellation seen in the Java flag is remembered, so later
+    /// [`is_cancelled`](#method.is_cancelled) calls see it too.
+    pub fn poll<'a>(&self, env: &'a JniEnv<'a>, token: &NoException<'a>) -> JavaResult<'a, bool> {
+        if self.is_cancelled() {
+            return Ok(true);
+        }
+        let cancelled = self.flag(env, token)?.get(token)?;
+        if cancelled {
+            self.cancelled.store(true, Ordering::Release);
+        }
+        Ok(cancelled)
+    }
+}
@@ -0,0 +1,238 @@
+use core::ptr;
+use rust_jni_core::__bindings::jni_methods;
+use rust_jni_core::__bindings::raw_object;
+use rust_jni_core::call_method;
+use rust_jni_core::convert;
+use rust_jni_core::java::lang::Class;
+use rust_jni_core::java::lang::Object;
+use rust_jni_core::FromObject;
+use rust_jni_core::JavaResult;
+use rust_jni_core::JniSignature;
+use rust_jni_core::NoException;
+
+include!("../call_jni_method.rs");
+
+/// A type representing a Java array of any type, accessed with
+/// [`java.lang.reflect.Array`](https://docs.oracle.com/javase/10/docs/api/java/lang/reflect/Array.html).
+///
+/// Allows creating and accessing arrays when the element type is only known at runtime.
+/// Primitive elements are boxed into and unboxed from their wrapper classes.
+///
+/// # Example
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::{Class, String};
+/// # use rust_jni::java::lang::reflect::Array;
+/// #
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let string_class = Class::find(env, &token, "java/lang/String")?;
+/// let array = Array::new_instance(&string_class, &token, 2)?;
+/// assert_eq!(array.len(&token), 2);
+/// let string = String::new(env, &token, "value")?;
+/// array.set(&token, 1, Some(&string))?;
+/// assert!(array.get(&token, 0)?.is_none());
+/// assert_eq!(array.get(&token, 1)?.unwrap(), string);
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Array<'env> {
+    object: Object<'env>,
+}
+
+impl<'env> Array<'env> {
+    /// Create a new array with elements of the given class and the given length.
+    ///
+    /// Elements of the new array are `null`-s, `false`-s or zeros depending on the element type.
+    /// Use [`Class::primitive`](../struct.Class.html#method.primitive) to create arrays
+    /// of primitive types.
+    ///
+    /// [`Array::newInstance` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/reflect/Array.html#newInstance(java.lang.Class,int))
+    pub fn new_instance(
+        component_class: &Class<'env>,
+        token: &NoException<'env>,
+        length: usize,
+    ) -> JavaResult<'env, Array<'env>> {
+        let env = component_class.env();
+        let class = Class::find(env, token, "java/lang/reflect/Array")?;
+        // Safe because we ensure correct arguments and return type.
+        let array = unsafe {
+            jni_methods::call_static_object_method(
+                &class,
+                token,
+                "newInstance\0",
+                "(Ljava/lang/Class;I)Ljava/lang/Object;\0",
+                (
+                    raw_object(component_class).as_ptr(),
+                    convert::size_to_jni(length),
+                ),
+            )
+        }?;
+        // `newInstance` never returns `null`.
+        let array = array.expect("Array.newInstance() returned null.");
+        // Safe because the argument is a valid array reference.
+        Ok(unsafe { Self::from_object(Object::from_raw(env, array)) })
+    }
+
+    /// Get the length of the array.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getarraylength)
+    pub fn len(&self, _token: &NoException<'env>) -> usize {
+        // Safe because the argument is ensured to be the correct by construction.
+        let length = unsafe {
+            call_jni_method!(
+                self.object.env(),
+                GetArrayLength,
+                raw_object(&self.object).as_ptr()
+            )
+        };
+        convert::size_from_jni(length)
+    }
+
+    /// Get the class of the array elements.
+    ///
+    /// [`Class::getComponentType` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Class.html#getComponentType())
+    pub fn component_type(&self, token: &NoException<'env>) -> JavaResult<'env, Class<'env>> {
+        let class = self.object.class(token);
+        // Safe because we ensure correct arguments and return type.
+        let component_type = unsafe {
+            call_method::<Class, _, _, fn() -> Class<'env>>(&class, token, "getComponentType\0", ())
+        }?;
+        // `getComponentType` only returns `null` for classes that are not arrays.
+        Ok(component_type.expect("Class.getComponentType() returned null for an array."))
+    }
+
+    /// Get an element of the array. Primitive elements are boxed.
+    ///
+    /// Returns an
+    /// [`ArrayIndexOutOfBoundsException`](https://docs.oracle.com/javase/10/docs/api/java/lang/ArrayIndexOutOfBoundsException.html)
+    /// if the index is out of bounds.
+    ///
+    /// [`Array::get` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/reflect/Array.html#get(java.lang.Object,int))
+    pub fn get(
+        &self,
+        token: &NoException<'env>,
+        index: usize,
+    ) -> JavaResult<'env, Option<Object<'env>>> {
+        let env = self.object.env();
+        let class = Class::find(env, token, "java/lang/reflect/Array")?;
+        // Safe because we ensure correct arguments and return type.
+        let element = unsafe {
+            jni_methods::call_static_object_method(
+                &class,
+                token,
+                "get\0",
+                "(Ljava/lang/Object;I)Ljava/lang/Object;\0",
+                (
+                    raw_object(&self.object).as_ptr(),
+                    convert::size_to_jni(index),
+                ),
+            )
+        }?;
+        // Safe because the argument is a valid object reference.
+        Ok(element.map(|element| unsafe { Object::from_raw(env, element) }))
+    }
+
+    /// Set an element of the array. Elements of primitive arrays are unboxed.
+    ///
+    /// Returns an
+    /// [`ArrayIndexOutOfBoundsException`](https://docs.oracle.com/javase/10/docs/api/java/lang/ArrayIndexOutOfBoundsException.html)
+    /// if the index is out of bounds and an
+    /// [`IllegalArgumentException`](https://docs.oracle.com/javase/10/docs/api/java/lang/IllegalArgumentException.html)
+    /// if the value can't be stored in the array.
+    ///
+    /// [`Array::set` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/reflect/Array.html#set(java.lang.Object,int,java.lang.Object))
+    pub fn set(
+        &self,
+        token: &NoException<'env>,
+        index: usize,
+        value: Option<&Object<'env>>,
+    ) -> JavaResult<'env, ()> {
+        let class = Class::find(self.object.env(), token, "java/lang/reflect/Array")?;
+        let raw_value = value.map_or(ptr::null_mut(), |value| {
+            // Safe because we only pass the pointer to JNI.
+            unsafe { raw_object(value).as_ptr() }
+        });
+        // Safe because we ensure correct arguments and return type.
+        unsafe {
+            jni_methods::call_static_primitive_method(
+                &class,
+                token,
+                "set\0",
+                "(Ljava/lang/Object;ILjava/lang/Object;)V\0",
+                (
+                    raw_object(&self.object).as_ptr(),
+                    convert::size_to_jni(index),
+                    raw_value,
+                ),
+            )
+        }
+    }
+}
+
+/// Allow [`Array`](struct.Array.html) to be used in place of an [`Object`](../struct.Object.html).
+impl<'env> ::core::ops::Deref for Array<'env> {
+    type Target = Object<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Object<'env>> for Array<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        &self.object
+    }
+}
+
+impl<'a> Into<Object<'a>> for Array<'a> {
+    fn into(self) -> Object<'a> {
+        self.object
+    }
+}
+
+impl<'env> FromObject<'env> for Array<'env> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self { object }
+    }
+}
+
+/// Arrays of any type are passed to Java as `Object`-s.
+impl JniSignature for Array<'_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "Ljava/lang/Object;"
+    }
+}
+
+/// Allow comparing [`Array`](struct.Array.html) to Java objects. Java objects are compared
+/// by-reference to preserve original Java semantics. To compare objects by value, call the
+/// [`value_eq`](../struct.Object.html#method.value_eq) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`ref_eq`](../struct.Object.html#method.ref_eq) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for Array<'env>
+where
+    T: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &T) -> bool {
+        self.as_ref().eq(other.as_ref())
+    }
+}
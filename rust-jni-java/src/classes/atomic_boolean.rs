@@ -0,0 +1,125 @@
+use jni_sys;
+use rust_jni_core::__bindings::jni_bool;
+use rust_jni_core::__bindings::jni_methods;
+use rust_jni_core::java::lang::Class;
+use rust_jni_core::java::lang::Object;
+use rust_jni_core::FromObject;
+use rust_jni_core::JavaResult;
+use rust_jni_core::JniEnv;
+use rust_jni_core::JniSignature;
+use rust_jni_core::NoException;
+
+/// A type representing a Java
+/// [`AtomicBoolean`](https://docs.oracle.com/javase/10/docs/api/java/util/concurrent/atomic/AtomicBoolean.html).
+#[derive(Debug, Clone)]
+pub struct AtomicBoolean<'env> {
+    object: Object<'env>,
+}
+
+impl<'env> AtomicBoolean<'env> {
+    /// Create a new atomic boolean with the initial value.
+    ///
+    /// [`AtomicBoolean(boolean)` javadoc](https://docs.oracle.com/javase/10/docs/api/java/util/concurrent/atomic/AtomicBoolean.html#<init>(boolean))
+    pub fn new(
+        env: &'env JniEnv<'env>,
+        token: &NoException<'env>,
+        value: bool,
+    ) -> JavaResult<'env, AtomicBoolean<'env>> {
+        let class = Class::find(env, token, "java/util/concurrent/atomic/AtomicBoolean")?;
+        // Safe because we ensure correct arguments.
+        let object = unsafe {
+            jni_methods::call_constructor(&class, token, "(Z)V\0", (jni_bool::to_jni(value),))
+        }?;
+        // Safe because the argument is a valid atomic boolean reference.
+        Ok(unsafe { Self::from_object(Object::from_raw(env, object)) })
+    }
+
+    /// Get the current value.
+    ///
+    /// [`AtomicBoolean::get` javadoc](https://docs.oracle.com/javase/10/docs/api/java/util/concurrent/atomic/AtomicBoolean.html#get())
+    pub fn get(&self, token: &NoException<'env>) -> JavaResult<'env, bool> {
+        // Safe because we ensure correct arguments and return type.
+        let value = unsafe {
+            jni_methods::call_primitive_method::<jni_sys::jboolean>(
+                &self.object,
+                token,
+                "get\0",
+                "()Z\0",
+                (),
+            )
+        }?;
+        Ok(jni_bool::to_rust(value))
+    }
+
+    /// Set the value.
+    ///
+    /// [`AtomicBoolean::set` javadoc](https://docs.oracle.com/javase/10/docs/api/java/util/concurrent/atomic/AtomicBoolean.html#set(boolean))
+    pub fn set(&self, token: &NoException<'env>, value: bool) -> JavaResult<'env, ()> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe {
+            jni_methods::call_primitive_method(
+                &self.object,
+                token,
+                "set\0",
+                "(Z)V\0",
+                (jni_bool::to_jni(value),),
+            )
+        }
+    }
+}
+
+/// Allow [`AtomicBoolean`](struct.AtomicBoolean.html) to be used in place of an
+/// [`Object`](../../../lang/struct.Object.html).
+impl<'env> ::core::ops::Deref for AtomicBoolean<'env> {
+    type Target = Object<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Object<'env>> for AtomicBoolean<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        &self.object
+    }
+}
+
+impl<'a> Into<Object<'a>> for AtomicBoolean<'a> {
+    fn into(self) -> Object<'a> {
+        self.object
+    }
+}
+
+impl<'env> FromObject<'env> for AtomicBoolean<'env> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self { object }
+    }
+}
+
+impl JniSignature for AtomicBoolean<'_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "Ljava/util/concurrent/atomic/AtomicBoolean;"
+    }
+}
+
+/// Allow comparing [`AtomicBoolean`](struct.AtomicBoolean.html) to Java objects. Java objects
+/// are compared by-reference to preserve original Java semantics. To compare objects by value,
+/// call the [`value_eq`](../../../lang/struct.Object.html#method.value_eq) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`ref_eq`](../../../lang/struct.Object.html#method.ref_eq) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for AtomicBoolean<'env>
+where
+    T: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &T) -> bool {
+        self.as_ref().eq(other.as_ref())
+    }
+}
@@ -0,0 +1,371 @@
+use core::convert::TryFrom;
+use core::ptr::NonNull;
+use jni_sys;
+use rust_jni_core::__bindings::jni_bool;
+use rust_jni_core::__bindings::jni_methods;
+use rust_jni_core::__bindings::raw_object;
+use rust_jni_core::__bindings::CallOutcome;
+use rust_jni_core::convert;
+use rust_jni_core::java::lang::Object;
+use rust_jni_core::FromObject;
+use rust_jni_core::JavaResult;
+use rust_jni_core::JniEnv;
+use rust_jni_core::JniSignature;
+use rust_jni_core::NoException;
+#[cfg(feature = "bytes")]
+use rust_jni_core::ObjectKey;
+
+include!("../call_jni_method.rs");
+
+/// A type representing a Java
+/// [`ByteBuffer`](https://docs.oracle.com/javase/10/docs/api/java/nio/ByteBuffer.html).
+///
+/// With the `bytes` feature, direct buffers can be converted to and from
+/// [`bytes::Bytes`](https://docs.rs/bytes/1/bytes/struct.Bytes.html) and
+/// [`bytes::BytesMut`](https://docs.rs/bytes/1/bytes/struct.BytesMut.html) without copying
+/// the data.
+#[derive(Debug, Clone)]
+pub struct ByteBuffer<'env> {
+    object: Object<'env>,
+}
+
+impl<'env> ByteBuffer<'env> {
+    /// Create a direct buffer over the memory at `address`.
+    ///
+    /// Unsafe because the memory must stay valid for `capacity` bytes for as long as Java can
+    /// access the buffer.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#newdirectbytebuffer)
+    pub unsafe fn new_direct(
+        env: &'env JniEnv<'env>,
+        token: &NoException<'env>,
+        address: *mut u8,
+        capacity: usize,
+    ) -> JavaResult<'env, ByteBuffer<'env>> {
+        let object = call_nullable_jni_method!(
+            env,
+            token,
+            NewDirectByteBuffer,
+            address as *mut core::ffi::c_void,
+            capacity as jni_sys::jlong
+        )?;
+        Ok(Self::from_object(Object::from_raw(env, object)))
+    }
+
+    /// Check if the buffer is direct, that is, backed by native memory.
+    ///
+    /// [`ByteBuffer::isDirect` javadoc](https://docs.oracle.com/javase/10/docs/api/java/nio/ByteBuffer.html#isDirect())
+    pub fn is_direct(&self, token: &NoException<'env>) -> JavaResult<'env, bool> {
+        // Safe because we ensure correct arguments and return type.
+        let value = unsafe {
+            jni_methods::call_primitive_method::<jni_sys::jboolean>(
+                &self.object,
+                token,
+                "isDirect\0",
+                "()Z\0",
+                (),
+            )
+        }?;
+        Ok(jni_bool::to_rust(value))
+    }
+
+    /// Get the position of the buffer.
+    ///
+    /// [`Buffer::position` javadoc](https://docs.oracle.com/javase/10/docs/api/java/nio/Buffer.html#position())
+    pub fn position(&self, token: &NoException<'env>) -> JavaResult<'env, usize> {
+        // Safe because we ensure correct arguments and return type.
+        let position = unsafe {
+            jni_methods::call_primitive_method::<i32>(
+                &self.object,
+                token,
+                "position\0",
+                "()I\0",
+                (),
+            )
+        }?;
+        Ok(convert::size_from_jni(position))
+    }
+
+    /// Get the limit of the buffer.
+    ///
+    /// [`Buffer::limit` javadoc](https://docs.oracle.com/javase/10/docs/api/java/nio/Buffer.html#limit())
+    pub fn limit(&self, token: &NoException<'env>) -> JavaResult<'env, usize> {
+        // Safe because we ensure correct arguments and return type.
+        let limit = unsafe {
+            jni_methods::call_primitive_method::<i32>(&self.object, token, "limit\0", "()I\0", ())
+        }?;
+        Ok(convert::size_from_jni(limit))
+    }
+
+    /// Get the address and the capacity of the memory backing a direct buffer.
+    /// Returns `None` if the buffer is not direct.
+    ///
+    /// [JNI documentation](https://docs.oracle.com/javase/10/docs/specs/jni/functions.html#getdirectbufferaddress)
+    pub fn direct_memory(&self, _token: &NoException<'env>) -> Option<(NonNull<u8>, usize)> {
+        let env = self.object.env();
+        // Safe because the argument is ensured to be the correct by construction.
+        let address = unsafe {
+            call_jni_method!(
+                env,
+                GetDirectBufferAddress,
+                raw_object(&self.object).as_ptr()
+            )
+        };
+        let address = NonNull::new(address as *mut u8)?;
+        // Safe because the argument is ensured to be the correct by construction.
+        let capacity = unsafe {
+            call_jni_method!(
+                env,
+                GetDirectBufferCapacity,
+                raw_object(&self.object).as_ptr()
+            )
+        };
+        Some((address, usize::try_from(capacity).ok()?))
+    }
+
+    /// Create a read-only view of the buffer.
+    ///
+    /// [`ByteBuffer::asReadOnlyBuffer` javadoc](https://docs.oracle.com/javase/10/docs/api/java/nio/ByteBuffer.html#asReadOnlyBuffer())
+    pub fn as_read_only_buffer(
+        &self,
+        token: &NoException<'env>,
+    ) -> JavaResult<'env, ByteBuffer<'env>> {
+        // Safe because we ensure correct arguments and return type.
+        let object = unsafe {
+            jni_methods::call_object_method(
+                &self.object,
+                token,
+                "asReadOnlyBuffer\0",
+                "()Ljava/nio/ByteBuffer;\0",
+                (),
+            )
+        }?;
+        // Should not panic: `asReadOnlyBuffer` never returns `null`.
+        // Safe because the argument is a valid byte buffer reference.
+        Ok(unsafe { Self::from_object(Object::from_raw(self.object.env(), object.unwrap())) })
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<'env> ByteBuffer<'env> {
+    /// Create a read-only direct buffer over the memory of a
+    /// [`Bytes`](https://docs.rs/bytes/1/bytes/struct.Bytes.html) without copying it.
+    ///
+    /// The returned [`BytesByteBuffer`](struct.BytesByteBuffer.html) keeps the memory alive and
+    /// derefs to the [`ByteBuffer`](struct.ByteBuffer.html), so it can be passed to Java methods.
+    ///
+    /// Unsafe because Java must not access the buffer after the
+    /// [`BytesByteBuffer`](struct.BytesByteBuffer.html) is dropped, for example, by storing it
+    /// in a field.
+    pub unsafe fn from_bytes(
+        env: &'env JniEnv<'env>,
+        token: &NoException<'env>,
+        bytes: bytes::Bytes,
+    ) -> JavaResult<'env, BytesByteBuffer<'env>> {
+        // Java never writes to a read-only buffer, so it's fine to cast away `const`.
+        let buffer = Self::new_direct(env, token, bytes.as_ptr() as *mut u8, bytes.len())?;
+        let buffer = buffer.as_read_only_buffer(token)?;
+        Ok(BytesByteBuffer { buffer, bytes })
+    }
+
+    /// Create a writable direct buffer over the memory of a
+    /// [`BytesMut`](https://docs.rs/bytes/1/bytes/struct.BytesMut.html) without copying it.
+    ///
+    /// The returned [`BytesMutByteBuffer`](struct.BytesMutByteBuffer.html) keeps the memory alive
+    /// and derefs to the [`ByteBuffer`](struct.ByteBuffer.html), so it can be passed to Java
+    /// methods. Writes made by Java are visible in the
+    /// [`BytesMut`](https://docs.rs/bytes/1/bytes/struct.BytesMut.html) returned by
+    /// [`into_bytes_mut`](struct.BytesMutByteBuffer.html#method.into_bytes_mut).
+    ///
+    /// Unsafe because Java must not access the buffer after the
+    /// [`BytesMutByteBuffer`](struct.BytesMutByteBuffer.html) is dropped, for example, by storing
+    /// it in a field.
+    pub unsafe fn from_bytes_mut(
+        env: &'env JniEnv<'env>,
+        token: &NoException<'env>,
+        mut bytes: bytes::BytesMut,
+    ) -> JavaResult<'env, BytesMutByteBuffer<'env>> {
+        let buffer = Self::new_direct(env, token, bytes.as_mut_ptr(), bytes.len())?;
+        Ok(BytesMutByteBuffer { buffer, bytes })
+    }
+
+    /// Get the contents of a direct buffer between it's position and limit as
+    /// [`Bytes`](https://docs.rs/bytes/1/bytes/struct.Bytes.html) without copying them.
+    /// Returns `None` if the buffer is not direct.
+    ///
+    /// The [`Bytes`](https://docs.rs/bytes/1/bytes/struct.Bytes.html) holds a global reference to
+    /// the buffer, which keeps the memory alive and is deleted when the last clone of the
+    /// [`Bytes`](https://docs.rs/bytes/1/bytes/struct.Bytes.html) is dropped.
+    ///
+    /// Unsafe because Java must not modify the buffer while the
+    /// [`Bytes`](https://docs.rs/bytes/1/bytes/struct.Bytes.html) is alive and because the
+    /// memory of buffers created with JNI, rather than `ByteBuffer.allocateDirect`, is not
+    /// owned by the buffer and must stay valid for that long.
+    pub unsafe fn to_bytes(
+        &self,
+        token: &NoException<'env>,
+    ) -> JavaResult<'env, Option<bytes::Bytes>> {
+        let (address, capacity) = match self.direct_memory(token) {
+            None => return Ok(None),
+            Some(memory) => memory,
+        };
+        let position = self.position(token)?;
+        let limit = self.limit(token)?;
+        assert!(
+            position <= limit && limit <= capacity,
+            "ByteBuffer position {} and limit {} are out of capacity {}.",
+            position,
+            limit,
+            capacity
+        );
+        Ok(Some(bytes::Bytes::from_owner(DirectBufferOwner {
+            _buffer: ObjectKey::new(self, token)?,
+            address: NonNull::new_unchecked(address.as_ptr().add(position)),
+            length: limit - position,
+        })))
+    }
+}
+
+/// Allow [`ByteBuffer`](struct.ByteBuffer.html) to be used in place of an
+/// [`Object`](../lang/struct.Object.html).
+impl<'env> ::core::ops::Deref for ByteBuffer<'env> {
+    type Target = Object<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Object<'env>> for ByteBuffer<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        &self.object
+    }
+}
+
+impl<'a> Into<Object<'a>> for ByteBuffer<'a> {
+    fn into(self) -> Object<'a> {
+        self.object
+    }
+}
+
+impl<'env> FromObject<'env> for ByteBuffer<'env> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self { object }
+    }
+}
+
+impl JniSignature for ByteBuffer<'_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "Ljava/nio/ByteBuffer;"
+    }
+}
+
+/// Allow comparing [`ByteBuffer`](struct.ByteBuffer.html) to Java objects. Java objects
+/// are compared by-reference to preserve original Java semantics. To compare objects by value,
+/// call the [`value_eq`](../lang/struct.Object.html#method.value_eq) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`ref_eq`](../lang/struct.Object.html#method.ref_eq) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for ByteBuffer<'env>
+where
+    T: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &T) -> bool {
+        self.as_ref().eq(other.as_ref())
+    }
+}
+
+/// A read-only direct [`ByteBuffer`](struct.ByteBuffer.html) over the memory of a
+/// [`Bytes`](https://docs.rs/bytes/1/bytes/struct.Bytes.html).
+///
+/// Created by [`ByteBuffer::from_bytes`](struct.ByteBuffer.html#method.from_bytes).
+#[cfg(feature = "bytes")]
+#[derive(Debug)]
+pub struct BytesByteBuffer<'env> {
+    buffer: ByteBuffer<'env>,
+    bytes: bytes::Bytes,
+}
+
+#[cfg(feature = "bytes")]
+impl<'env> BytesByteBuffer<'env> {
+    /// Get the [`Bytes`](https://docs.rs/bytes/1/bytes/struct.Bytes.html) back.
+    pub fn into_bytes(self) -> bytes::Bytes {
+        self.bytes
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<'env> ::core::ops::Deref for BytesByteBuffer<'env> {
+    type Target = ByteBuffer<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+/// A writable direct [`ByteBuffer`](struct.ByteBuffer.html) over the memory of a
+/// [`BytesMut`](https://docs.rs/bytes/1/bytes/struct.BytesMut.html).
+///
+/// Created by [`ByteBuffer::from_bytes_mut`](struct.ByteBuffer.html#method.from_bytes_mut).
+#[cfg(feature = "bytes")]
+#[derive(Debug)]
+pub struct BytesMutByteBuffer<'env> {
+    buffer: ByteBuffer<'env>,
+    bytes: bytes::BytesMut,
+}
+
+#[cfg(feature = "bytes")]
+impl<'env> BytesMutByteBuffer<'env> {
+    /// Get the [`BytesMut`](https://docs.rs/bytes/1/bytes/struct.BytesMut.html) back, with the
+    /// changes made by Java.
+    pub fn into_bytes_mut(self) -> bytes::BytesMut {
+        self.bytes
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<'env> ::core::ops::Deref for BytesMutByteBuffer<'env> {
+    type Target = ByteBuffer<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+/// The owner of the memory of [`Bytes`](https://docs.rs/bytes/1/bytes/struct.Bytes.html)
+/// created by [`ByteBuffer::to_bytes`](struct.ByteBuffer.html#method.to_bytes).
+/// Deletes the global reference to the buffer when dropped.
+#[cfg(feature = "bytes")]
+struct DirectBufferOwner {
+    _buffer: ObjectKey,
+    address: NonNull<u8>,
+    length: usize,
+}
+
+/// Make [`DirectBufferOwner`](struct.DirectBufferOwner.html) sendable between threads.
+/// The memory is only read and the global reference is valid in all threads.
+#[cfg(feature = "bytes")]
+unsafe impl Send for DirectBufferOwner {}
+
+/// Make [`DirectBufferOwner`](struct.DirectBufferOwner.html) shareable by multiple threads.
+/// The memory is only read and the global reference is valid in all threads.
+#[cfg(feature = "bytes")]
+unsafe impl Sync for DirectBufferOwner {}
+
+#[cfg(feature = "bytes")]
+impl AsRef<[u8]> for DirectBufferOwner {
+    fn as_ref(&self) -> &[u8] {
+        // Safe because the global reference keeps the buffer alive and
+        // `ByteBuffer::to_bytes` requires Java to not modify it.
+        unsafe { core::slice::from_raw_parts(self.address.as_ptr(), self.length) }
+    }
+}
@@ -0,0 +1,109 @@
+use rust_jni_core::call_constructor;
+use rust_jni_core::java::lang::Object;
+use rust_jni_core::java::lang::String;
+use rust_jni_core::java::lang::Throwable;
+use rust_jni_core::FromObject;
+use rust_jni_core::JavaExceptionClass;
+use rust_jni_core::JavaResult;
+use rust_jni_core::JniEnv;
+use rust_jni_core::JniSignature;
+use rust_jni_core::NoException;
+
+/// A type representing a Java
+/// [`Error`](https://docs.oracle.com/javase/10/docs/api/java/lang/Error.html).
+#[derive(Debug, Clone)]
+pub struct Error<'env> {
+    pub(crate) object: Throwable<'env>,
+}
+
+impl<'this> Error<'this> {
+    /// Create a new [`Error`](struct.Error.html).
+    ///
+    /// [`Error()` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Error.html#<init>())
+    pub fn new(
+        env: &'this JniEnv<'this>,
+        token: &NoException<'this>,
+    ) -> JavaResult<'this, Error<'this>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { call_constructor::<Self, _, fn()>(env, token, ()) }
+    }
+
+    /// Create a new [`Error`](struct.Error.html) with a message.
+    ///
+    /// [`Error(String)` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Error.html#<init>(java.lang.String))
+    pub fn new_with_message(
+        env: &'this JniEnv<'this>,
+        token: &NoException<'this>,
+        message: &String<'this>,
+    ) -> JavaResult<'this, Error<'this>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { call_constructor::<Self, _, fn(&String<'this>)>(env, token, (message,)) }
+    }
+}
+
+/// Allow [`Error`](struct.Error.html) to be used in place of a
+/// [`Throwable`](struct.Throwable.html).
+impl<'env> ::core::ops::Deref for Error<'env> {
+    type Target = Throwable<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Object<'env>> for Error<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        self.object.as_ref()
+    }
+}
+
+impl<'a> Into<Throwable<'a>> for Error<'a> {
+    fn into(self) -> Throwable<'a> {
+        self.object
+    }
+}
+
+impl<'a> Into<Object<'a>> for Error<'a> {
+    fn into(self) -> Object<'a> {
+        self.object.into()
+    }
+}
+
+impl<'env> FromObject<'env> for Error<'env> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self {
+            object: Throwable::from_object(object),
+        }
+    }
+}
+
+impl JniSignature for Error<'_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "Ljava/lang/Error;"
+    }
+}
+
+impl<'env> JavaExceptionClass<'env> for Error<'env> {}
+
+/// Allow comparing [`Error`](struct.Error.html)
+/// to Java objects. Java objects are compared by-reference to preserve
+/// original Java semantics. To compare objects by value, call the
+/// [`value_eq`](struct.Object.html#method.value_eq) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`ref_eq`](struct.Object.html#method.ref_eq) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for Error<'env>
+where
+    T: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &T) -> bool {
+        self.as_ref().eq(other.as_ref())
+    }
+}
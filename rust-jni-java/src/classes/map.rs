@@ -0,0 +1,300 @@
+use rust_jni_core::java::lang::Object;
+use rust_jni_core::FromObject;
+use rust_jni_core::JniSignature;
+
+/// A type representing a Java
+/// [`Map`](https://docs.oracle.com/javase/10/docs/api/java/util/Map.html).
+///
+/// Example:
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::util::Map;
+/// # use std::collections::HashMap;
+/// #
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let mut headers = HashMap::new();
+/// headers.insert("Content-Type".to_owned(), "text/plain".to_owned());
+/// let map = Map::from_hash_map(env, &token, &headers)?;
+/// assert_eq!(map.to_hash_map(&token)?, headers);
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Map<'env> {
+    object: Object<'env>,
+}
+
+/// Conversions between Java maps and Rust maps.
+///
+/// Only available with the `std` feature.
+#[cfg(feature = "std")]
+mod hash_map {
+    use super::*;
+    use core::ptr::NonNull;
+    use jni_sys;
+    use rust_jni_core::__bindings::jni_methods;
+    use rust_jni_core::__bindings::raw_object;
+    use rust_jni_core::__bindings::CallOutcome;
+    use rust_jni_core::convert;
+    use rust_jni_core::java::lang::Class;
+    use rust_jni_core::java::lang::String;
+    use rust_jni_core::java::lang::Throwable;
+    use rust_jni_core::JavaResult;
+    use rust_jni_core::JniEnv;
+    use rust_jni_core::NoException;
+    use rust_jni_core::{JavaClassExt, NullableJavaClassExt};
+    use std::collections::HashMap;
+
+    include!("../call_jni_method.rs");
+
+    impl<'env> Map<'env> {
+        /// Create a new Java [`HashMap`](https://docs.oracle.com/javase/10/docs/api/java/util/HashMap.html)
+        /// with the contents of a Rust map of strings.
+        ///
+        /// The Java map is pre-sized to fit all the entries without rehashing.
+        ///
+        /// Only available with the `std` feature.
+        ///
+        /// [`HashMap(int)` javadoc](https://docs.oracle.com/javase/10/docs/api/java/util/HashMap.html#<init>(int))
+        pub fn from_hash_map<'a>(
+            env: &'a JniEnv<'a>,
+            token: &NoException<'a>,
+            map: &HashMap<alloc::string::String, alloc::string::String>,
+        ) -> JavaResult<'a, Map<'a>> {
+            let class = Class::find(env, token, "java/util/HashMap")?;
+            // `HashMap` grows when it's size exceeds 3/4 of it's capacity. Java limits the capacity
+            // anyway, so maps that don't fit into a `jsize` ask for the largest one.
+            let capacity = convert::to_jsize(map.len().saturating_mul(4) / 3 + 1)
+                .unwrap_or(jni_sys::jsize::max_value());
+            // Safe because we ensure correct arguments.
+            let raw_map =
+                unsafe { jni_methods::call_constructor(&class, token, "(I)V\0", (capacity,)) }?;
+            // Safe because the argument is a valid map reference.
+            let java_map = unsafe { Map::from_raw(env, raw_map) };
+            // Safe because the signature is null-terminated.
+            let put_method_id = unsafe {
+                jni_methods::get_method_id(
+                    &class,
+                    token,
+                    "put\0",
+                    "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;\0",
+                )
+            }?;
+            for (key, value) in map {
+                let key = String::new(env, token, key)?;
+                let value = String::new(env, token, value)?;
+                // Safe because we ensure correct arguments and return type.
+                let previous = unsafe {
+                    jni_methods::call_object_method_with_id(
+                        &java_map.object,
+                        token,
+                        put_method_id,
+                        (raw_object(&key).as_ptr(), raw_object(&value).as_ptr()),
+                    )
+                }?;
+                // Rust map keys are unique, so there is never a previous value, but if there were one
+                // it's reference would need to be deleted.
+                // Safe because the argument is a valid object reference.
+                previous.map(|previous| unsafe { Object::from_raw(env, previous) });
+            }
+            Ok(java_map)
+        }
+
+        /// Convert the Java map of strings into a Rust map.
+        ///
+        /// Iterates over an array of map entries to avoid calling `Iterator` methods for each entry.
+        /// Returns a [`NullPointerException`](struct.NullPointerException.html) if the map has `null`
+        /// keys or values and a
+        /// [`ClassCastException`](https://docs.oracle.com/javase/10/docs/api/java/lang/ClassCastException.html)
+        /// if the map has keys or values that are not strings.
+        ///
+        /// Only available with the `std` feature.
+        ///
+        /// [`Map::entrySet` javadoc](https://docs.oracle.com/javase/10/docs/api/java/util/Map.html#entrySet())
+        pub fn to_hash_map(
+            &self,
+            token: &NoException<'env>,
+        ) -> JavaResult<'env, HashMap<alloc::string::String, alloc::string::String>> {
+            let env = self.env();
+            // Safe because we ensure correct arguments and return type.
+            let size: jni_sys::jint = unsafe {
+                jni_methods::call_primitive_method(&self.object, token, "size\0", "()I\0", ())
+            }?;
+            // Safe because we ensure correct arguments and return type.
+            let entry_set = unsafe {
+                jni_methods::call_object_method(
+                    &self.object,
+                    token,
+                    "entrySet\0",
+                    "()Ljava/util/Set;\0",
+                    (),
+                )
+            }?
+            // Safe because the argument is a valid object reference.
+            .map(|entry_set| unsafe { Object::from_raw(env, entry_set) })
+            .or_npe(env, token)?;
+            // Safe because we ensure correct arguments and return type.
+            let entries = unsafe {
+                jni_methods::call_object_method(
+                    &entry_set,
+                    token,
+                    "toArray\0",
+                    "()[Ljava/lang/Object;\0",
+                    (),
+                )
+            }?
+            // Safe because the argument is a valid object reference.
+            .map(|entries| unsafe { Object::from_raw(env, entries) })
+            .or_npe(env, token)?;
+
+            let entry_class = Class::find(env, token, "java/util/Map$Entry")?;
+            // Safe because signatures are null-terminated.
+            let get_key_method_id = unsafe {
+                jni_methods::get_method_id(
+                    &entry_class,
+                    token,
+                    "getKey\0",
+                    "()Ljava/lang/Object;\0",
+                )
+            }?;
+            // Safe because signatures are null-terminated.
+            let get_value_method_id = unsafe {
+                jni_methods::get_method_id(
+                    &entry_class,
+                    token,
+                    "getValue\0",
+                    "()Ljava/lang/Object;\0",
+                )
+            }?;
+            let string_class = String::class(env, token)?;
+
+            // Safe because arguments are ensured to be the correct by construction.
+            let length =
+                unsafe { call_jni_method!(env, GetArrayLength, raw_object(&entries).as_ptr()) };
+            // The size is only a hint, a broken `Map` implementation can return a negative one.
+            let mut result = HashMap::with_capacity(convert::from_jsize(size).unwrap_or(0));
+            for index in 0..length {
+                // Safe because arguments are ensured to be the correct by construction and because
+                // `GetObjectArrayElement` only returns `null` for `null` elements which are not present
+                // in entry set arrays or when an exception was thrown.
+                let raw_entry = unsafe {
+                    call_nullable_jni_method!(
+                        env,
+                        token,
+                        GetObjectArrayElement,
+                        raw_object(&entries).as_ptr(),
+                        index
+                    )
+                }?;
+                // Safe because the argument is a valid object reference.
+                let entry = unsafe { Object::from_raw(env, raw_entry) };
+                // Safe because the method ids belong to the `Map.Entry` class.
+                let key = unsafe { entry_string(&entry, token, get_key_method_id, &string_class) }?;
+                // Safe because the method ids belong to the `Map.Entry` class.
+                let value =
+                    unsafe { entry_string(&entry, token, get_value_method_id, &string_class) }?;
+                result.insert(key, value);
+            }
+            Ok(result)
+        }
+
+        /// Unsafe because an incorrect object reference can be passed.
+        #[inline(always)]
+        pub(crate) unsafe fn from_raw<'a>(
+            env: &'a JniEnv<'a>,
+            raw_map: NonNull<jni_sys::_jobject>,
+        ) -> Map<'a> {
+            Map {
+                object: Object::from_raw(env, raw_map),
+            }
+        }
+    }
+
+    /// Call a `Map.Entry` getter and convert the result into a Rust string.
+    ///
+    /// Unsafe because the method id must belong to a `Map.Entry` getter.
+    unsafe fn entry_string<'a>(
+        entry: &Object<'a>,
+        token: &NoException<'a>,
+        method_id: NonNull<jni_sys::_jmethodID>,
+        string_class: &Class<'a>,
+    ) -> JavaResult<'a, alloc::string::String> {
+        let env = entry.env();
+        let value = jni_methods::call_object_method_with_id(entry, token, method_id, ())?
+            .map(|value| Object::from_raw(env, value))
+            .or_npe(env, token)?;
+        if !value.is_instance_of(token, string_class) {
+            let class = Class::find(env, token, "java/lang/ClassCastException")?;
+            let exception = jni_methods::call_constructor(&class, token, "()V\0", ())?;
+            return Err(Throwable::from_object(Object::from_raw(env, exception)));
+        }
+        Ok(String::from_object(value).as_string(token))
+    }
+}
+
+/// Allow [`Map`](struct.Map.html) to be used in place of an [`Object`](../lang/struct.Object.html).
+impl<'env> ::core::ops::Deref for Map<'env> {
+    type Target = Object<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Object<'env>> for Map<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        &self.object
+    }
+}
+
+impl<'a> Into<Object<'a>> for Map<'a> {
+    fn into(self) -> Object<'a> {
+        self.object
+    }
+}
+
+impl<'env> FromObject<'env> for Map<'env> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self { object }
+    }
+}
+
+impl JniSignature for Map<'_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "Ljava/util/Map;"
+    }
+}
+
+/// Allow comparing [`Map`](struct.Map.html)
+/// to Java objects. Java objects are compared by-reference to preserve
+/// original Java semantics. To compare objects by value, call the
+/// [`value_eq`](../lang/struct.Object.html#method.value_eq) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`ref_eq`](../lang/struct.Object.html#method.ref_eq) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for Map<'env>
+where
+    T: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &T) -> bool {
+        self.as_ref().eq(other.as_ref())
+    }
+}
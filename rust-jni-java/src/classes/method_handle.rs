@@ -0,0 +1,409 @@
+use core::ptr;
+use rust_jni_core::__bindings::jni_methods;
+use rust_jni_core::__bindings::new_exception;
+use rust_jni_core::__bindings::raw_object;
+use rust_jni_core::__bindings::JavaArgumentTuple;
+use rust_jni_core::__bindings::JavaMethodResult;
+use rust_jni_core::__bindings::JavaMethodSignature;
+use rust_jni_core::__bindings::JniArgumentTypeTuple;
+use rust_jni_core::java::lang::Class;
+use rust_jni_core::java::lang::Object;
+use rust_jni_core::java::lang::String;
+use rust_jni_core::FromObject;
+use rust_jni_core::JavaResult;
+use rust_jni_core::JniEnv;
+use rust_jni_core::JniSignature;
+use rust_jni_core::NoException;
+
+/// Static methods of the Java
+/// [`MethodHandles`](https://docs.oracle.com/javase/10/docs/api/java/lang/invoke/MethodHandles.html)
+/// class.
+#[derive(Debug)]
+pub enum MethodHandles {}
+
+impl MethodHandles {
+    /// Get a lookup object for finding method handles.
+    ///
+    /// Native code has no Java caller class, so the lookup has the access of the
+    /// [public lookup](https://docs.oracle.com/javase/10/docs/api/java/lang/invoke/MethodHandles.html#publicLookup()):
+    /// only public members of public classes can be found.
+    ///
+    /// [`MethodHandles::publicLookup` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/invoke/MethodHandles.html#publicLookup())
+    pub fn lookup<'env>(
+        env: &'env JniEnv<'env>,
+        token: &NoException<'env>,
+    ) -> JavaResult<'env, Lookup<'env>> {
+        let class = Class::find(env, token, "java/lang/invoke/MethodHandles")?;
+        // Safe because we ensure correct arguments and return type.
+        let lookup = unsafe {
+            jni_methods::call_static_object_method(
+                &class,
+                token,
+                "publicLookup\0",
+                "()Ljava/lang/invoke/MethodHandles$Lookup;\0",
+                (),
+            )
+        }?;
+        // `publicLookup` never returns `null`.
+        let lookup = lookup.expect("MethodHandles.publicLookup() returned null.");
+        // Safe because the argument is a valid lookup reference.
+        Ok(unsafe { Lookup::from_object(Object::from_raw(env, lookup)) })
+    }
+}
+
+/// A type representing a Java
+/// [`MethodHandles.Lookup`](https://docs.oracle.com/javase/10/docs/api/java/lang/invoke/MethodHandles.Lookup.html).
+#[derive(Debug, Clone)]
+pub struct Lookup<'env> {
+    object: Object<'env>,
+}
+
+impl<'env> Lookup<'env> {
+    /// Find a handle for a static method of the class.
+    ///
+    /// The method type is given as a JNI method descriptor, like `"(II)I"`.
+    /// Returns a
+    /// [`NoSuchMethodException`](https://docs.oracle.com/javase/10/docs/api/java/lang/NoSuchMethodException.html)
+    /// if the method doesn't exist.
+    ///
+    /// [`MethodHandles.Lookup::findStatic` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/invoke/MethodHandles.Lookup.html#findStatic(java.lang.Class,java.lang.String,java.lang.invoke.MethodType))
+    pub fn find_static(
+        &self,
+        token: &NoException<'env>,
+        class: &Class<'env>,
+        name: &str,
+        descriptor: &str,
+    ) -> JavaResult<'env, MethodHandle<'env>> {
+        self.find(token, "findStatic\0", class, name, descriptor)
+    }
+
+    /// Find a handle for an instance method of the class.
+    ///
+    /// The receiver is the first argument of the handle. The method type is given as a JNI
+    /// method descriptor without the receiver, like `"()I"`.
+    /// Returns a
+    /// [`NoSuchMethodException`](https://docs.oracle.com/javase/10/docs/api/java/lang/NoSuchMethodException.html)
+    /// if the method doesn't exist.
+    ///
+    /// [`MethodHandles.Lookup::findVirtual` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/invoke/MethodHandles.Lookup.html#findVirtual(java.lang.Class,java.lang.String,java.lang.invoke.MethodType))
+    pub fn find_virtual(
+        &self,
+        token: &NoException<'env>,
+        class: &Class<'env>,
+        name: &str,
+        descriptor: &str,
+    ) -> JavaResult<'env, MethodHandle<'env>> {
+        self.find(token, "findVirtual\0", class, name, descriptor)
+    }
+
+    fn find(
+        &self,
+        token: &NoException<'env>,
+        method: &str,
+        class: &Class<'env>,
+        name: &str,
+        descriptor: &str,
+    ) -> JavaResult<'env, MethodHandle<'env>> {
+        let env = self.object.env();
+        let method_type = method_type(env, token, class, descriptor)?;
+        let name = String::new(env, token, name)?;
+        // Safe because we ensure correct arguments and return type.
+        let handle = unsafe {
+            jni_methods::call_object_method(
+                &self.object,
+                token,
+                method,
+                "(Ljava/lang/Class;Ljava/lang/String;Ljava/lang/invoke/MethodType;)\
+                 Ljava/lang/invoke/MethodHandle;\0",
+                (
+                    raw_object(class).as_ptr(),
+                    raw_object(&name).as_ptr(),
+                    raw_object(&method_type).as_ptr(),
+                ),
+            )
+        }?;
+        // `find*` methods throw instead of returning `null`.
+        let handle = handle.expect("MethodHandles.Lookup.find*() returned null.");
+        // Safe because the argument is a valid method handle reference.
+        Ok(unsafe { MethodHandle::from_object(Object::from_raw(env, handle)) })
+    }
+}
+
+/// Create a `MethodType` from the descriptor, resolving classes with the class loader of `class`.
+fn method_type<'env>(
+    env: &'env JniEnv<'env>,
+    token: &NoException<'env>,
+    class: &Class<'env>,
+    descriptor: &str,
+) -> JavaResult<'env, Object<'env>> {
+    // Safe because we ensure correct arguments and return type.
+    let class_loader = unsafe {
+        jni_methods::call_object_method(
+            class,
+            token,
+            "getClassLoader\0",
+            "()Ljava/lang/ClassLoader;\0",
+            (),
+        )
+    }?;
+    // Safe because the argument is a valid class loader reference.
+    let class_loader =
+        class_loader.map(|class_loader| unsafe { Object::from_raw(env, class_loader) });
+    let descriptor = String::new(env, token, descriptor)?;
+    let method_type_class = Class::find(env, token, "java/lang/invoke/MethodType")?;
+    // Safe because we ensure correct arguments and return type.
+    let method_type = unsafe {
+        jni_methods::call_static_object_method(
+            &method_type_class,
+            token,
+            "fromMethodDescriptorString\0",
+            "(Ljava/lang/String;Ljava/lang/ClassLoader;)Ljava/lang/invoke/MethodType;\0",
+            (
+                raw_object(&descriptor).as_ptr(),
+                class_loader
+                    .as_ref()
+                    .map_or(ptr::null_mut(), |class_loader| {
+                        raw_object(class_loader).as_ptr()
+                    }),
+            ),
+        )
+    }?;
+    // `fromMethodDescriptorString` throws instead of returning `null`.
+    let method_type = method_type.expect("MethodType.fromMethodDescriptorString() returned null.");
+    // Safe because the argument is a valid method type reference.
+    Ok(unsafe { Object::from_raw(env, method_type) })
+}
+
+/// A type representing a Java
+/// [`MethodHandle`](https://docs.oracle.com/javase/10/docs/api/java/lang/invoke/MethodHandle.html).
+///
+/// Method handles are found with a [`Lookup`](struct.Lookup.html):
+/// ```
+/// # use rust_jni::*;
+/// # use rust_jni::java::lang::{Class, String};
+/// # use rust_jni::java::lang::invoke::MethodHandles;
+/// #
+/// # fn jni_main<'a>(env: &'a JniEnv<'a>, token: NoException<'a>) -> JavaResult<'a, NoException<'a>> {
+/// let class = Class::find(env, &token, "java/lang/Integer")?;
+/// let lookup = MethodHandles::lookup(env, &token)?;
+/// let handle = lookup.find_static(&token, &class, "sum", "(II)I")?;
+/// assert_eq!(handle.invoke_exact::<_, _, fn(i32, i32) -> i32>(&token, (2, 3))?, 5);
+/// # Ok(token)
+/// # }
+/// #
+/// # fn main() {
+/// #     let init_arguments = InitArguments::default();
+/// #     let vm = JavaVM::create(&init_arguments).unwrap();
+/// #     let _ = vm.with_attached(
+/// #        &AttachArguments::new(init_arguments.version()),
+/// #        |env: &JniEnv, token: NoException| {
+/// #            ((), jni_main(env, token).unwrap())
+/// #        },
+/// #     );
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MethodHandle<'env> {
+    object: Object<'env>,
+}
+
+impl<'env> MethodHandle<'env> {
+    /// Get the JNI method descriptor of the handle type, like `"(II)I"`.
+    ///
+    /// [`MethodType::toMethodDescriptorString` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/invoke/MethodType.html#toMethodDescriptorString())
+    pub fn type_descriptor(&self, token: &NoException<'env>) -> JavaResult<'env, String<'env>> {
+        let env = self.object.env();
+        // Safe because we ensure correct arguments and return type.
+        let method_type = unsafe {
+            jni_methods::call_object_method(
+                &self.object,
+                token,
+                "type\0",
+                "()Ljava/lang/invoke/MethodType;\0",
+                (),
+            )
+        }?;
+        // `type` never returns `null`.
+        let method_type = method_type.expect("MethodHandle.type() returned null.");
+        // Safe because the argument is a valid method type reference.
+        let method_type = unsafe { Object::from_raw(env, method_type) };
+        // Safe because we ensure correct arguments and return type.
+        let descriptor = unsafe {
+            jni_methods::call_object_method(
+                &method_type,
+                token,
+                "toMethodDescriptorString\0",
+                "()Ljava/lang/String;\0",
+                (),
+            )
+        }?;
+        // `toMethodDescriptorString` never returns `null`.
+        let descriptor = descriptor.expect("MethodType.toMethodDescriptorString() returned null.");
+        // Safe because the argument is a valid string reference.
+        Ok(unsafe { String::from_object(Object::from_raw(env, descriptor)) })
+    }
+
+    /// Invoke the method handle with the exact type `F`.
+    ///
+    /// The type is specified the same way as for [`call_method`](../../../fn.call_method.html)
+    /// and is checked against the handle type. Returns a
+    /// [`WrongMethodTypeException`](https://docs.oracle.com/javase/10/docs/api/java/lang/invoke/WrongMethodTypeException.html)
+    /// if they are different, like Java does.
+    ///
+    /// `invokeExact` is signature-polymorphic and can't be called through JNI, so the handle
+    /// is called with
+    /// [`invokeWithArguments`](https://docs.oracle.com/javase/10/docs/api/java/lang/invoke/MethodHandle.html#invokeWithArguments(java.lang.Object...))
+    /// instead, boxing primitive arguments and the result.
+    ///
+    /// [`MethodHandle::invokeExact` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/invoke/MethodHandle.html#invokeExact(java.lang.Object...))
+    pub fn invoke_exact<A, R, F>(
+        &self,
+        token: &NoException<'env>,
+        arguments: A,
+    ) -> JavaResult<'env, R::ResultType>
+    where
+        A: JavaArgumentTuple,
+        R: JavaMethodResult<'env>,
+        F: JavaMethodSignature<A, R>,
+    {
+        let env = self.object.env();
+        let signature = F::method_signature();
+        // Drop the null terminator.
+        let signature = &signature[..signature.len() - 1];
+        if self.type_descriptor(token)?.as_string(token) != signature {
+            return Err(new_exception(
+                env,
+                token,
+                "java/lang/invoke/WrongMethodTypeException",
+            )?);
+        }
+        // Safe because the arguments are valid by construction.
+        let arguments =
+            unsafe { JavaArgumentTuple::to_jni(&arguments).to_object_array(env, token) }?;
+        // Safe because we ensure correct arguments and return type.
+        let result = unsafe {
+            jni_methods::call_object_method(
+                &self.object,
+                token,
+                "invokeWithArguments\0",
+                "([Ljava/lang/Object;)Ljava/lang/Object;\0",
+                (raw_object(&arguments).as_ptr(),),
+            )
+        }?;
+        // Safe because the argument is a valid object reference.
+        let result = result.map(|result| unsafe { Object::from_raw(env, result) });
+        // Safe because the handle type matches the result type.
+        unsafe { R::from_boxed(result, token) }
+    }
+}
+
+/// Allow [`Lookup`](struct.Lookup.html) to be used in place of an [`Object`](../struct.Object.html).
+impl<'env> ::core::ops::Deref for Lookup<'env> {
+    type Target = Object<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Object<'env>> for Lookup<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        &self.object
+    }
+}
+
+impl<'a> Into<Object<'a>> for Lookup<'a> {
+    fn into(self) -> Object<'a> {
+        self.object
+    }
+}
+
+impl<'env> FromObject<'env> for Lookup<'env> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self { object }
+    }
+}
+
+impl JniSignature for Lookup<'_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "Ljava/lang/invoke/MethodHandles$Lookup;"
+    }
+}
+
+/// Allow comparing [`Lookup`](struct.Lookup.html) to Java objects. Java objects are compared
+/// by-reference to preserve original Java semantics. To compare objects by value, call the
+/// [`value_eq`](../struct.Object.html#method.value_eq) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`ref_eq`](../struct.Object.html#method.ref_eq) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for Lookup<'env>
+where
+    T: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &T) -> bool {
+        self.as_ref().eq(other.as_ref())
+    }
+}
+
+/// Allow [`MethodHandle`](struct.MethodHandle.html) to be used in place of an
+/// [`Object`](../struct.Object.html).
+impl<'env> ::core::ops::Deref for MethodHandle<'env> {
+    type Target = Object<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Object<'env>> for MethodHandle<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        &self.object
+    }
+}
+
+impl<'a> Into<Object<'a>> for MethodHandle<'a> {
+    fn into(self) -> Object<'a> {
+        self.object
+    }
+}
+
+impl<'env> FromObject<'env> for MethodHandle<'env> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self { object }
+    }
+}
+
+impl JniSignature for MethodHandle<'_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "Ljava/lang/invoke/MethodHandle;"
+    }
+}
+
+/// Allow comparing [`MethodHandle`](struct.MethodHandle.html) to Java objects. Java objects are
+/// compared by-reference to preserve original Java semantics. To compare objects by value, call
+/// the [`value_eq`](../struct.Object.html#method.value_eq) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`ref_eq`](../struct.Object.html#method.ref_eq) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for MethodHandle<'env>
+where
+    T: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &T) -> bool {
+        self.as_ref().eq(other.as_ref())
+    }
+}
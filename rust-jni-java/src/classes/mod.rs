@@ -0,0 +1,12 @@
+pub mod array;
+#[cfg(feature = "java-util")]
+pub mod atomic_boolean;
+pub mod byte_buffer;
+pub mod error;
+pub mod illegal_argument_exception;
+pub mod illegal_state_exception;
+#[cfg(feature = "java-util")]
+pub mod map;
+pub mod method_handle;
+pub mod runtime;
+pub mod unsupported_operation_exception;
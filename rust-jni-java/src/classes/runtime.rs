@@ -0,0 +1,180 @@
+use jni_sys;
+use rust_jni_core::__bindings::jni_bool;
+use rust_jni_core::__bindings::jni_methods;
+use rust_jni_core::__bindings::new_exception;
+use rust_jni_core::__bindings::raw_object;
+use rust_jni_core::java::lang::Class;
+use rust_jni_core::java::lang::Object;
+use rust_jni_core::FromObject;
+use rust_jni_core::JavaResult;
+use rust_jni_core::JniEnv;
+use rust_jni_core::JniSignature;
+use rust_jni_core::NoException;
+
+/// A type representing a Java
+/// [`Runtime`](https://docs.oracle.com/javase/10/docs/api/java/lang/Runtime.html).
+#[derive(Debug, Clone)]
+pub struct Runtime<'env> {
+    object: Object<'env>,
+}
+
+impl<'env> Runtime<'env> {
+    /// Get the runtime of the current Java VM.
+    ///
+    /// [`Runtime::getRuntime` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Runtime.html#getRuntime())
+    pub fn get_runtime(
+        env: &'env JniEnv<'env>,
+        token: &NoException<'env>,
+    ) -> JavaResult<'env, Runtime<'env>> {
+        let class = Class::find(env, token, "java/lang/Runtime")?;
+        // Safe because we ensure correct arguments and return type.
+        let runtime = unsafe {
+            jni_methods::call_static_object_method(
+                &class,
+                token,
+                "getRuntime\0",
+                "()Ljava/lang/Runtime;\0",
+                (),
+            )
+        }?;
+        // `getRuntime` never returns `null`.
+        let runtime = runtime.expect("Runtime.getRuntime() returned null.");
+        // Safe because the argument is a valid runtime reference.
+        Ok(unsafe { Self::from_object(Object::from_raw(env, runtime)) })
+    }
+
+    /// Register a [`Thread`](https://docs.oracle.com/javase/10/docs/api/java/lang/Thread.html)
+    /// to be started when the Java VM begins it's shutdown sequence.
+    ///
+    /// Returns a
+    /// [`ClassCastException`](https://docs.oracle.com/javase/10/docs/api/java/lang/ClassCastException.html)
+    /// if `hook` is not a `Thread`.
+    ///
+    /// [`Runtime::addShutdownHook` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Runtime.html#addShutdownHook(java.lang.Thread))
+    pub fn add_shutdown_hook(
+        &self,
+        token: &NoException<'env>,
+        hook: &Object<'env>,
+    ) -> JavaResult<'env, ()> {
+        self.check_thread(token, hook)?;
+        // Safe because we ensure correct arguments and return type.
+        unsafe {
+            jni_methods::call_primitive_method(
+                &self.object,
+                token,
+                "addShutdownHook\0",
+                "(Ljava/lang/Thread;)V\0",
+                (raw_object(hook).as_ptr(),),
+            )
+        }
+    }
+
+    /// Unregister a previously registered shutdown hook.
+    ///
+    /// Returns `true` if the hook was registered. Returns a
+    /// [`ClassCastException`](https://docs.oracle.com/javase/10/docs/api/java/lang/ClassCastException.html)
+    /// if `hook` is not a `Thread`.
+    ///
+    /// [`Runtime::removeShutdownHook` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Runtime.html#removeShutdownHook(java.lang.Thread))
+    pub fn remove_shutdown_hook(
+        &self,
+        token: &NoException<'env>,
+        hook: &Object<'env>,
+    ) -> JavaResult<'env, bool> {
+        self.check_thread(token, hook)?;
+        // Safe because we ensure correct arguments and return type.
+        let removed = unsafe {
+            jni_methods::call_primitive_method::<jni_sys::jboolean>(
+                &self.object,
+                token,
+                "removeShutdownHook\0",
+                "(Ljava/lang/Thread;)Z\0",
+                (raw_object(hook).as_ptr(),),
+            )
+        }?;
+        Ok(jni_bool::to_rust(removed))
+    }
+
+    /// Get the number of processors available to the Java VM.
+    ///
+    /// [`Runtime::availableProcessors` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/Runtime.html#availableProcessors())
+    pub fn available_processors(&self, token: &NoException<'env>) -> JavaResult<'env, usize> {
+        // Safe because we ensure correct arguments and return type.
+        let processors = unsafe {
+            jni_methods::call_primitive_method::<jni_sys::jint>(
+                &self.object,
+                token,
+                "availableProcessors\0",
+                "()I\0",
+                (),
+            )
+        }?;
+        Ok(processors as usize)
+    }
+
+    /// Shutdown hooks are passed to Java as `Thread`-s, so they need to be checked
+    /// before calling the method.
+    fn check_thread(&self, token: &NoException<'env>, hook: &Object<'env>) -> JavaResult<'env, ()> {
+        let env = self.object.env();
+        let thread_class = Class::find(env, token, "java/lang/Thread")?;
+        if !hook.is_instance_of(token, &thread_class) {
+            return Err(new_exception(env, token, "java/lang/ClassCastException")?);
+        }
+        Ok(())
+    }
+}
+
+/// Allow [`Runtime`](struct.Runtime.html) to be used in place of an [`Object`](struct.Object.html).
+impl<'env> ::core::ops::Deref for Runtime<'env> {
+    type Target = Object<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Object<'env>> for Runtime<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        &self.object
+    }
+}
+
+impl<'a> Into<Object<'a>> for Runtime<'a> {
+    fn into(self) -> Object<'a> {
+        self.object
+    }
+}
+
+impl<'env> FromObject<'env> for Runtime<'env> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self { object }
+    }
+}
+
+impl JniSignature for Runtime<'_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "Ljava/lang/Runtime;"
+    }
+}
+
+/// Allow comparing [`Runtime`](struct.Runtime.html) to Java objects. Java objects are compared
+/// by-reference to preserve original Java semantics. To compare objects by value, call the
+/// [`value_eq`](struct.Object.html#method.value_eq) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`ref_eq`](struct.Object.html#method.ref_eq) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for Runtime<'env>
+where
+    T: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &T) -> bool {
+        self.as_ref().eq(other.as_ref())
+    }
+}
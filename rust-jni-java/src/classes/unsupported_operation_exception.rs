@@ -0,0 +1,123 @@
+use rust_jni_core::call_constructor;
+use rust_jni_core::java::lang::Exception;
+use rust_jni_core::java::lang::Object;
+use rust_jni_core::java::lang::RuntimeException;
+use rust_jni_core::java::lang::String;
+use rust_jni_core::java::lang::Throwable;
+use rust_jni_core::FromObject;
+use rust_jni_core::JavaExceptionClass;
+use rust_jni_core::JavaResult;
+use rust_jni_core::JniEnv;
+use rust_jni_core::JniSignature;
+use rust_jni_core::NoException;
+
+/// A type representing a Java
+/// [`UnsupportedOperationException`](https://docs.oracle.com/javase/10/docs/api/java/lang/UnsupportedOperationException.html).
+#[derive(Debug, Clone)]
+pub struct UnsupportedOperationException<'env> {
+    pub(crate) object: RuntimeException<'env>,
+}
+
+impl<'this> UnsupportedOperationException<'this> {
+    /// Create a new [`UnsupportedOperationException`](struct.UnsupportedOperationException.html).
+    ///
+    /// [`UnsupportedOperationException()` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/UnsupportedOperationException.html#<init>())
+    pub fn new(
+        env: &'this JniEnv<'this>,
+        token: &NoException<'this>,
+    ) -> JavaResult<'this, UnsupportedOperationException<'this>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { call_constructor::<Self, _, fn()>(env, token, ()) }
+    }
+
+    /// Create a new [`UnsupportedOperationException`](struct.UnsupportedOperationException.html) with a message.
+    ///
+    /// [`UnsupportedOperationException(String)` javadoc](https://docs.oracle.com/javase/10/docs/api/java/lang/UnsupportedOperationException.html#<init>(java.lang.String))
+    pub fn new_with_message(
+        env: &'this JniEnv<'this>,
+        token: &NoException<'this>,
+        message: &String<'this>,
+    ) -> JavaResult<'this, UnsupportedOperationException<'this>> {
+        // Safe because we ensure correct arguments and return type.
+        unsafe { call_constructor::<Self, _, fn(&String<'this>)>(env, token, (message,)) }
+    }
+}
+
+/// Allow [`UnsupportedOperationException`](struct.UnsupportedOperationException.html) to be used in place of a
+/// [`RuntimeException`](struct.RuntimeException.html).
+impl<'env> ::core::ops::Deref for UnsupportedOperationException<'env> {
+    type Target = RuntimeException<'env>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<'env> AsRef<Object<'env>> for UnsupportedOperationException<'env> {
+    #[inline(always)]
+    fn as_ref(&self) -> &Object<'env> {
+        self.object.as_ref()
+    }
+}
+
+impl<'a> Into<RuntimeException<'a>> for UnsupportedOperationException<'a> {
+    fn into(self) -> RuntimeException<'a> {
+        self.object
+    }
+}
+
+impl<'a> Into<Exception<'a>> for UnsupportedOperationException<'a> {
+    fn into(self) -> Exception<'a> {
+        self.object.into()
+    }
+}
+
+impl<'a> Into<Throwable<'a>> for UnsupportedOperationException<'a> {
+    fn into(self) -> Throwable<'a> {
+        self.object.into()
+    }
+}
+
+impl<'a> Into<Object<'a>> for UnsupportedOperationException<'a> {
+    fn into(self) -> Object<'a> {
+        self.object.into()
+    }
+}
+
+impl<'env> FromObject<'env> for UnsupportedOperationException<'env> {
+    #[inline(always)]
+    unsafe fn from_object(object: Object<'env>) -> Self {
+        Self {
+            object: RuntimeException::from_object(object),
+        }
+    }
+}
+
+impl JniSignature for UnsupportedOperationException<'_> {
+    #[inline(always)]
+    fn signature() -> &'static str {
+        "Ljava/lang/UnsupportedOperationException;"
+    }
+}
+
+impl<'env> JavaExceptionClass<'env> for UnsupportedOperationException<'env> {}
+
+/// Allow comparing [`UnsupportedOperationException`](struct.UnsupportedOperationException.html)
+/// to Java objects. Java objects are compared by-reference to preserve
+/// original Java semantics. To compare objects by value, call the
+/// [`value_eq`](struct.Object.html#method.value_eq) method.
+///
+/// Will panic if there is a pending exception in the current thread.
+///
+/// This is mostly a convenience for using `assert_eq!()` in tests. Always prefer using
+/// [`ref_eq`](struct.Object.html#method.ref_eq) to comparing with `==`, because
+/// the former checks for a pending exception in compile-time rather than the run-time.
+impl<'env, T> PartialEq<T> for UnsupportedOperationException<'env>
+where
+    T: AsRef<Object<'env>>,
+{
+    fn eq(&self, other: &T) -> bool {
+        self.as_ref().eq(other.as_ref())
+    }
+}
@@ -0,0 +1,101 @@
+//! # Bindings for the Java standard library
+//!
+//! Wrappers for the Java standard library classes that the
+//! [`rust-jni-core`](https://docs.rs/rust-jni-core/) runtime doesn't depend on itself.
+//!
+//! Use them through the `rust-jni` crate, which re-exports the runtime and these bindings
+//! under the same [`java`](java/index.html) module. Libraries that only implement native
+//! methods can depend on `rust-jni-core` alone to avoid compiling them.
+//!
+//! Like the core, the bindings only use `core` and `alloc` without the default `std` feature.
+//! Bindings for [`java::util`](java/util/index.html) are behind the default `java-util` feature.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "java-util")]
+mod cancellation;
+mod classes;
+mod owned_object;
+
+#[cfg(feature = "java-util")]
+pub use cancellation::CancellationSource;
+
+pub mod java {
+    pub mod lang {
+        //! Package java.lang.
+        //!
+        //! Provides classes that are fundamental to the design of the Java programming language.
+        //!
+        //! [`java.lang` javadoc](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/lang/package-summary.html)
+
+        pub use crate::classes::error::Error;
+        pub use crate::classes::illegal_argument_exception::IllegalArgumentException;
+        pub use crate::classes::illegal_state_exception::IllegalStateException;
+        pub use crate::classes::runtime::Runtime;
+        pub use crate::classes::unsupported_operation_exception::UnsupportedOperationException;
+
+        pub mod invoke {
+            //! Package java.lang.invoke.
+            //!
+            //! Provides low-level primitives for interacting with the Java Virtual Machine.
+            //!
+            //! [`java.lang.invoke` javadoc](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/lang/invoke/package-summary.html)
+
+            pub use crate::classes::method_handle::{Lookup, MethodHandle, MethodHandles};
+        }
+
+        pub mod reflect {
+            //! Package java.lang.reflect.
+            //!
+            //! Provides classes and interfaces for obtaining reflective information about classes
+            //! and objects.
+            //!
+            //! [`java.lang.reflect` javadoc](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/lang/reflect/package-summary.html)
+
+            pub use crate::classes::array::Array;
+        }
+    }
+
+    pub mod nio {
+        //! Package java.nio.
+        //!
+        //! Defines buffers, which are containers for data.
+        //!
+        //! [`java.nio` javadoc](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/nio/package-summary.html)
+
+        pub use crate::classes::byte_buffer::ByteBuffer;
+        #[cfg(feature = "bytes")]
+        pub use crate::classes::byte_buffer::{BytesByteBuffer, BytesMutByteBuffer};
+    }
+
+    #[cfg(feature = "java-util")]
+    pub mod util {
+        //! Package java.util.
+        //!
+        //! Contains the collections framework and miscellaneous utility classes.
+        //!
+        //! [`java.util` javadoc](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/util/package-summary.html)
+
+        pub use crate::classes::map::Map;
+
+        pub mod concurrent {
+            //! Package java.util.concurrent.
+            //!
+            //! Utility classes commonly useful in concurrent programming.
+            //!
+            //! [`java.util.concurrent` javadoc](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/util/concurrent/package-summary.html)
+
+            pub mod atomic {
+                //! Package java.util.concurrent.atomic.
+                //!
+                //! A small toolkit of classes that support lock-free thread-safe programming on
+                //! single variables.
+                //!
+                //! [`java.util.concurrent.atomic` javadoc](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/util/concurrent/atomic/package-summary.html)
+
+                pub use crate::classes::atomic_boolean::AtomicBoolean;
+            }
+        }
+    }
+}
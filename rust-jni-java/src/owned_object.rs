@@ -0,0 +1,32 @@
+use crate::classes::array::Array;
+#[cfg(feature = "java-util")]
+use crate::classes::atomic_boolean::AtomicBoolean;
+use crate::classes::byte_buffer::ByteBuffer;
+use crate::classes::error::Error;
+use crate::classes::illegal_argument_exception::IllegalArgumentException;
+use crate::classes::illegal_state_exception::IllegalStateException;
+#[cfg(feature = "java-util")]
+use crate::classes::map::Map;
+use crate::classes::runtime::Runtime;
+use crate::classes::unsupported_operation_exception::UnsupportedOperationException;
+use rust_jni_core::Detachable;
+
+macro_rules! detachable {
+    ($type:ident) => {
+        impl<'env> Detachable for $type<'env> {
+            type Attached<'a> = $type<'a>;
+        }
+    };
+}
+
+detachable!(Error);
+detachable!(IllegalArgumentException);
+detachable!(IllegalStateException);
+detachable!(UnsupportedOperationException);
+detachable!(Runtime);
+detachable!(ByteBuffer);
+detachable!(Array);
+#[cfg(feature = "java-util")]
+detachable!(Map);
+#[cfg(feature = "java-util")]
+detachable!(AtomicBoolean);
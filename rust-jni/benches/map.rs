@@ -0,0 +1,44 @@
+//! Benchmarks of conversions between Java maps and Rust maps of strings.
+//!
+//! Run with `cargo bench --features libjvm`.
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rust_jni::java::util::Map;
+use rust_jni::*;
+use std::collections::HashMap;
+
+fn string_map(size: usize) -> HashMap<String, String> {
+    (0..size)
+        .map(|index| (format!("header-{}", index), format!("value-{}", index)))
+        .collect()
+}
+
+fn map_conversions(criterion: &mut Criterion) {
+    let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+    let vm = JavaVM::create(&init_arguments).unwrap();
+    vm.with_attached(
+        &AttachArguments::new(init_arguments.version()),
+        |env, token| {
+            let mut group = criterion.benchmark_group("map");
+            for size in [1, 16, 256, 4096].iter() {
+                let map = string_map(*size);
+                group.bench_with_input(
+                    BenchmarkId::new("from_hash_map", size),
+                    &map,
+                    |bench, map| bench.iter(|| Map::from_hash_map(env, &token, map).unwrap()),
+                );
+                let java_map = Map::from_hash_map(env, &token, &map).unwrap();
+                group.bench_with_input(
+                    BenchmarkId::new("to_hash_map", size),
+                    &java_map,
+                    |bench, java_map| bench.iter(|| java_map.to_hash_map(&token).unwrap()),
+                );
+            }
+            group.finish();
+            ((), token)
+        },
+    )
+    .unwrap();
+}
+
+criterion_group!(benches, map_conversions);
+criterion_main!(benches);
@@ -8,47 +8,32 @@
 //!
 //! The main philosofy of this library is to push as many errors to compile-time as possible
 //! and panic whenever it's impossible to have a compile error.
-// TODO: a complete example.
-
-#[cfg(test)]
-#[macro_use]
-pub mod testing;
-
-mod attach_arguments;
-mod class;
-mod classes;
-mod env;
-mod error;
-mod init_arguments;
-mod java_class;
-mod java_methods;
-mod java_primitives;
-mod java_string;
-mod jni_bool;
-mod jni_methods;
-mod jni_types;
-mod native_method;
-mod object;
-mod result;
-mod string;
-mod throwable;
-mod token;
-mod version;
-mod vm;
+//!
+//! The core of the library (types, tokens and calls) only uses `core` and `alloc`.
+//! Parts that need the standard library, like the [`executor`](executor/index.html), are only
+//! available with the default `std` feature, without it the library is `#![no_std]`. Panics
+//! in native methods can't be caught without the standard library and are handled by the panic
+//! handler of the binary.
+//!
+//! This crate re-exports two crates under the same paths: `rust-jni-core` with the tokens,
+//! environments, calls and the `java.lang` classes they depend on, and `rust-jni-java` with
+//! bindings for the rest of the Java standard library. Libraries that only implement native
+//! methods can depend on `rust-jni-core` directly to avoid compiling the bindings. Bindings for
+//! [`java::util`](java/util/index.html) are also behind the default `java-util` feature.
+//!
+//! Commonly used types and traits can be imported at once from the
+//! [`prelude`](prelude/index.html).
+#![cfg_attr(not(feature = "std"), no_std)]
 
-pub use attach_arguments::AttachArguments;
-pub use env::JniEnv;
-pub use error::JniError;
-pub use init_arguments::{InitArguments, JvmOption, JvmVerboseOption};
-pub use java_class::{JavaClassExt, NullableJavaClassExt};
-pub use java_methods::{
-    call_constructor, call_method, call_static_method, FromObject, JniSignature,
-};
-pub use native_method::{native_method_implementation, static_native_method_implementation};
-pub use result::JavaResult;
-pub use token::{ConsumedNoException, Exception, NoException};
-pub use version::JniVersion;
-pub use vm::{JavaVM, JavaVMRef};
+pub use rust_jni_core::*;
+/// Generate wrappers for Java classes and interfaces with the `rust-jni-generator` version
+/// matching this `rust-jni` version.
+///
+/// Only available with the `macros` feature.
+#[cfg(feature = "macros")]
+pub use rust_jni_generator::java_generate;
+#[cfg(feature = "java-util")]
+pub use rust_jni_java::CancellationSource;
 
 pub mod java {
     pub mod lang {
@@ -58,11 +43,41 @@ pub mod java {
         //!
         //! [`java.lang` javadoc](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/lang/package-summary.html)
 
-        pub use crate::class::Class;
-        pub use crate::classes::exception::Exception;
-        pub use crate::classes::null_pointer_exception::NullPointerException;
-        pub use crate::object::Object;
-        pub use crate::string::String;
-        pub use crate::throwable::Throwable;
+        pub use rust_jni_core::java::lang::*;
+        pub use rust_jni_java::java::lang::*;
     }
+
+    pub use rust_jni_java::java::nio;
+    #[cfg(feature = "java-util")]
+    pub use rust_jni_java::java::util;
+}
+
+pub mod prelude {
+    //! Commonly used types and traits.
+    //!
+    //! Import everything with `use rust_jni::prelude::*;` instead of listing each item:
+    //! ```
+    //! use rust_jni::prelude::*;
+    //!
+    //! fn describe<'a>(object: &Object<'a>, token: &NoException<'a>) -> JavaResult<'a, String> {
+    //!     let class_name = object.class(token).get_name(token)?;
+    //!     Ok(class_name.or_npe(object.env(), token)?.as_string(token))
+    //! }
+    //! ```
+    //!
+    //! [`java::lang::String`](../java/lang/struct.String.html) is not exported directly
+    //! so that it doesn't shadow the Rust `String`. Use it through the exported
+    //! [`java`](../java/index.html) module instead.
+    //!
+    //! With the `macros` feature the prelude also exports
+    //! [`java_generate!`](../macro.java_generate.html).
+
+    pub use crate::java;
+    pub use crate::java::lang::{Class, Object, Throwable};
+    #[cfg(feature = "macros")]
+    pub use crate::java_generate;
+    pub use crate::{
+        AttachArguments, InitArguments, JavaClassExt, JavaResult, JavaResultExt, JavaVM, JniEnv,
+        NoException, NullableJavaClassExt,
+    };
 }
@@ -0,0 +1,39 @@
+/// An integration test for calling non-public Java methods.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod access {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let string = String::new(env, &token, "latin").unwrap();
+
+                // Safe because the method name and arguments are correct.
+                // `protected void Object.finalize()`.
+                let result =
+                    unsafe { call_method::<Object, _, _, fn()>(&string, &token, "finalize\0", ()) };
+                assert!(result.is_ok());
+
+                // Safe because the method name and arguments are correct.
+                // Package-private `byte String.coder()`.
+                let coder =
+                    unsafe { call_method::<_, _, _, fn() -> u8>(&string, &token, "coder\0", ()) };
+                assert_eq!(coder.unwrap(), 0);
+
+                // Safe because the method name and arguments are correct.
+                // `private boolean String.isLatin1()`.
+                let is_latin1 = unsafe {
+                    call_method::<_, _, _, fn() -> bool>(&string, &token, "isLatin1\0", ())
+                };
+                assert!(is_latin1.unwrap());
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,54 @@
+/// An integration test for the `assert_java_eq!` and `assert_throws!` macros.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod assertions {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+    use std::panic::{self, AssertUnwindSafe};
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let first = String::new(env, &token, "string").unwrap();
+                let second = String::new(env, &token, "string").unwrap();
+                assert_java_eq!(first, second, &token);
+
+                let other = String::new(env, &token, "other").unwrap();
+                let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                    assert_java_eq!(first, other, &token);
+                }));
+                assert_eq!(
+                    result.unwrap_err().downcast_ref::<std::string::String>(),
+                    Some(
+                        &"assertion failed: `left.equals(right)`\n  left: `string`,\n right: `other`"
+                            .to_owned()
+                    )
+                );
+
+                let exception: NullPointerException = assert_throws!(
+                    NullPointerException,
+                    None::<String>.or_npe(env, &token),
+                    &token
+                );
+                let exception: Throwable = exception.into();
+                assert!(exception.get_message(&token).unwrap().is_none());
+                // A superclass of the thrown exception also matches.
+                assert_throws!(java::lang::Exception, first.char_at(&token, 10), &token);
+
+                let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                    assert_throws!(NullPointerException, first.char_at(&token, 10), &token);
+                }));
+                assert!(result.is_err());
+                let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                    assert_throws!(NullPointerException, first.char_at(&token, 0), &token);
+                }));
+                assert!(result.is_err());
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
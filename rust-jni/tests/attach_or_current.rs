@@ -0,0 +1,58 @@
+/// An integration test for attaching threads with `JavaVM::attach_or_current`.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod attach_or_current {
+    use rust_jni::java::lang::String;
+    use rust_jni::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = Arc::new(JavaVM::create(&init_arguments).unwrap());
+
+        // An already attached thread is not attached again and stays attached.
+        let _ = vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                {
+                    let guard = vm
+                        .attach_or_current(&AttachArguments::new(init_arguments.version()))
+                        .unwrap();
+                    assert!(!guard.attached());
+                    let token = guard.token();
+                    let string = String::new(&guard, &token, "current").unwrap();
+                    assert_eq!(string.as_string(&token), "current");
+                }
+                let string = String::new(env, &token, "still attached").unwrap();
+                assert_eq!(string.as_string(&token), "still attached");
+                ((), token)
+            },
+        );
+
+        // A detached thread is attached according to the policy.
+        for policy in &[AttachPolicy::Normal, AttachPolicy::Daemon] {
+            set_attach_policy(*policy);
+            let vm = vm.clone();
+            let version = init_arguments.version();
+            ::std::thread::spawn(move || {
+                {
+                    let guard = vm
+                        .attach_or_current(&AttachArguments::new(version))
+                        .unwrap();
+                    assert!(guard.attached());
+                    let token = guard.token();
+                    let string = String::new(&guard, &token, "attached").unwrap();
+                    assert_eq!(string.as_string(&token), "attached");
+                }
+                // The guard detached the thread, so it can be attached again.
+                let guard = vm
+                    .attach_or_current(&AttachArguments::new(version))
+                    .unwrap();
+                assert!(guard.attached());
+            })
+            .join()
+            .unwrap();
+        }
+        set_attach_policy(AttachPolicy::Normal);
+    }
+}
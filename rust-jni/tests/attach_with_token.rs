@@ -0,0 +1,36 @@
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod attach_with_token {
+    use rust_jni::java::lang::String;
+    use rust_jni::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = Arc::new(JavaVM::create(&init_arguments).unwrap());
+
+        let mut env = None;
+        let (attached_env, token) = vm
+            .attach_with_token(&AttachArguments::new(init_arguments.version()), &mut env)
+            .unwrap();
+        let string = String::new(attached_env, &token, "value").unwrap();
+        assert_eq!(string.as_string(&token), "value");
+        drop(string);
+        let token = token.consume();
+        assert_eq!(env.take().unwrap().detach(token), None);
+
+        let child = {
+            let vm = vm.clone();
+            let attach_arguments = AttachArguments::new(init_arguments.version());
+            ::std::thread::spawn(move || {
+                let mut env = None;
+                let (attached_env, token) = vm
+                    .attach_daemon_with_token(&attach_arguments, &mut env)
+                    .unwrap();
+                let string = String::empty(attached_env, &token).unwrap();
+                string.len(&token)
+            })
+        };
+        assert_eq!(child.join().unwrap(), 0);
+    }
+}
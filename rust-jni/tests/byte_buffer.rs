@@ -0,0 +1,117 @@
+/// An integration test for `java.nio.ByteBuffer` conversions to and from `bytes`.
+#[cfg(all(
+    test,
+    feature = "libjvm",
+    feature = "bytes",
+    not(feature = "no-invocation")
+))]
+mod byte_buffer {
+    use bytes::{Bytes, BytesMut};
+    use rust_jni::java::lang::Class;
+    use rust_jni::java::nio::*;
+    use rust_jni::*;
+
+    fn get<'a>(buffer: &ByteBuffer<'a>, token: &NoException<'a>, index: i32) -> u8 {
+        unsafe { call_method::<_, _, _, fn(i32) -> u8>(buffer, token, "get\0", (index,)) }.unwrap()
+    }
+
+    fn put<'a>(
+        buffer: &ByteBuffer<'a>,
+        token: &NoException<'a>,
+        index: i32,
+        value: u8,
+    ) -> JavaResult<'a, ()> {
+        unsafe {
+            call_method::<_, _, _, fn(i32, u8) -> ByteBuffer<'a>>(
+                buffer,
+                token,
+                "put\0",
+                (index, value),
+            )
+        }
+        .map(|_| ())
+    }
+
+    fn set_position<'a>(buffer: &ByteBuffer<'a>, token: &NoException<'a>, position: i32) {
+        unsafe {
+            call_method::<_, _, _, fn(i32) -> ByteBuffer<'a>>(
+                buffer,
+                token,
+                "position\0",
+                (position,),
+            )
+        }
+        .unwrap();
+    }
+
+    fn allocate<'a>(
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+        name: &str,
+        capacity: i32,
+    ) -> ByteBuffer<'a> {
+        unsafe {
+            call_static_method::<ByteBuffer, _, _, fn(i32) -> ByteBuffer<'a>>(
+                env,
+                token,
+                name,
+                (capacity,),
+            )
+        }
+        .unwrap()
+        .unwrap()
+    }
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let bytes = Bytes::from_static(b"payload");
+                let buffer = unsafe { ByteBuffer::from_bytes(env, &token, bytes.clone()) }.unwrap();
+                assert!(buffer.is_direct(&token).unwrap());
+                assert_eq!(buffer.limit(&token).unwrap(), 7);
+                assert_eq!(get(&buffer, &token, 1), b'a');
+                // Buffers over `Bytes` are read-only.
+                let exception = put(&buffer, &token, 0, 0).unwrap_err();
+                assert!(exception.is_instance_of(
+                    &token,
+                    &Class::find(env, &token, "java/nio/ReadOnlyBufferException").unwrap()
+                ));
+                // The `Bytes` returned by `to_bytes` point to the same memory.
+                let copy = unsafe { buffer.to_bytes(&token) }.unwrap().unwrap();
+                assert_eq!(copy, bytes);
+                assert_eq!(copy.as_ptr(), bytes.as_ptr());
+                assert_eq!(buffer.into_bytes(), bytes);
+
+                let bytes = BytesMut::from(&b"abc"[..]);
+                let buffer = unsafe { ByteBuffer::from_bytes_mut(env, &token, bytes) }.unwrap();
+                put(&buffer, &token, 1, b'x').unwrap();
+                assert_eq!(&buffer.into_bytes_mut()[..], b"axc");
+
+                let buffer = allocate(env, &token, "allocateDirect\0", 4);
+                put(&buffer, &token, 2, 1).unwrap();
+                set_position(&buffer, &token, 1);
+                // Only the bytes between the position and the limit are returned.
+                let bytes = unsafe { buffer.to_bytes(&token) }.unwrap().unwrap();
+                drop(buffer);
+                assert_eq!(&bytes[..], &[0, 1, 0]);
+                // The global reference keeps the buffer alive on other threads and is deleted
+                // when the `Bytes` are dropped there.
+                std::thread::spawn(move || assert_eq!(&bytes[..], &[0, 1, 0]))
+                    .join()
+                    .unwrap();
+
+                let buffer = allocate(env, &token, "allocate\0", 4);
+                assert!(!buffer.is_direct(&token).unwrap());
+                assert!(buffer.direct_memory(&token).is_none());
+                assert!(unsafe { buffer.to_bytes(&token) }.unwrap().is_none());
+
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
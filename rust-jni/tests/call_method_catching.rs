@@ -0,0 +1,66 @@
+/// An integration test for catching expected exceptions with `call_method_catching` and
+/// `JavaResultExt::catching`.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod call_method_catching {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    unsafe fn substring<'a, E: JavaExceptionClass<'a>>(
+        string: &String<'a>,
+        token: &NoException<'a>,
+        begin: i32,
+        end: i32,
+    ) -> JavaResult<'a, Result<Option<String<'a>>, E>> {
+        call_method_catching::<_, _, _, fn(i32, i32) -> String<'a>, E>(
+            string,
+            token,
+            "substring\0",
+            (begin, end),
+        )
+    }
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let string = String::new(env, &token, "string").unwrap();
+
+                let result = unsafe { substring::<Exception>(&string, &token, 1, 3) }
+                    .unwrap()
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(result.as_string(&token), "tr");
+
+                // Expected exceptions become the inner error.
+                let exception = unsafe { substring::<Exception>(&string, &token, 3, 1) }
+                    .unwrap()
+                    .unwrap_err();
+                assert!(exception.is_instance_of(
+                    &token,
+                    &Class::find(env, &token, "java/lang/StringIndexOutOfBoundsException").unwrap()
+                ));
+
+                // Unexpected exceptions propagate.
+                let exception = unsafe { substring::<NullPointerException>(&string, &token, 3, 1) }
+                    .unwrap_err();
+                assert!(exception.is_instance_of(
+                    &token,
+                    &Class::find(env, &token, "java/lang/StringIndexOutOfBoundsException").unwrap()
+                ));
+
+                // Any result can be filtered.
+                let result = None::<String>
+                    .or_npe(env, &token)
+                    .catching::<Throwable>(&token)
+                    .unwrap();
+                assert!(result.is_err());
+
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,62 @@
+/// An integration test for the `CancellationSource` type.
+#[cfg(all(
+    test,
+    feature = "libjvm",
+    feature = "java-util",
+    not(feature = "no-invocation")
+))]
+mod cancellation {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+    use std::thread;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        let attach_arguments = AttachArguments::new(init_arguments.version());
+        let source = vm
+            .with_attached(&attach_arguments, |env, token| {
+                let source = CancellationSource::new(env, &token).unwrap();
+                let flag = source.flag(env, &token).unwrap();
+                assert!(flag.is_instance_of(
+                    &token,
+                    &Class::find(env, &token, "java/util/concurrent/atomic/AtomicBoolean").unwrap()
+                ));
+                assert!(!flag.get(&token).unwrap());
+                assert!(!source.is_cancelled());
+                assert!(!source.poll(env, &token).unwrap());
+
+                // Every call returns a reference to the same Java flag.
+                assert!(flag.is_same_as(&token, &source.flag(env, &token).unwrap()));
+                (source, token)
+            })
+            .unwrap();
+
+        // Cancel on another thread.
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                vm.with_attached(&attach_arguments, |env, token| {
+                    source.cancel(env, &token).unwrap();
+                    ((), token)
+                })
+                .unwrap();
+            });
+        });
+
+        assert!(source.is_cancelled());
+        vm.with_attached(&attach_arguments, |env, token| {
+            assert!(source.flag(env, &token).unwrap().get(&token).unwrap());
+            assert!(source.poll(env, &token).unwrap());
+
+            // Java code can set the flag itself.
+            let source = CancellationSource::new(env, &token).unwrap();
+            source.flag(env, &token).unwrap().set(&token, true).unwrap();
+            assert!(!source.is_cancelled());
+            assert!(source.poll(env, &token).unwrap());
+            assert!(source.is_cancelled());
+            ((), token)
+        })
+        .unwrap();
+    }
+}
@@ -1,5 +1,5 @@
 /// An integration test for the `java::lang::Class` type.
-#[cfg(all(test, feature = "libjvm"))]
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
 mod class {
     use rust_jni::java::lang::*;
     use rust_jni::*;
@@ -29,6 +29,59 @@ mod class {
                     .unwrap()
                     .is_same_as(&token, &parent_class));
 
+                let int_class = Class::primitive::<i32>(env, &token).unwrap();
+                assert_eq!(
+                    int_class
+                        .get_name(&token)
+                        .or_npe(env, &token)
+                        .unwrap()
+                        .as_string(&token),
+                    "int"
+                );
+                let void_class = Class::primitive::<()>(env, &token).unwrap();
+                assert_eq!(
+                    void_class
+                        .get_name(&token)
+                        .or_npe(env, &token)
+                        .unwrap()
+                        .as_string(&token),
+                    "void"
+                );
+
+                let int_array_class = Class::array_of::<i32>(env, &token).unwrap();
+                assert!(
+                    int_array_class.is_same_as(&token, &Class::find(env, &token, "[I").unwrap())
+                );
+                let string_array_class = Class::array_of::<String>(env, &token).unwrap();
+                assert_eq!(
+                    string_array_class
+                        .get_name(&token)
+                        .or_npe(env, &token)
+                        .unwrap()
+                        .as_string(&token),
+                    "[Ljava.lang.String;"
+                );
+                let nested_array_class = Class::array_of::<ObjectArray>(env, &token).unwrap();
+                assert_eq!(
+                    nested_array_class
+                        .get_name(&token)
+                        .or_npe(env, &token)
+                        .unwrap()
+                        .as_string(&token),
+                    "[[Ljava.lang.Object;"
+                );
+
+                let string_class = Class::of::<String>(env, &token).unwrap();
+                assert!(string_class.is_same_as(
+                    &token,
+                    &Class::find(env, &token, "java/lang/String").unwrap()
+                ));
+                let object_array_class = Class::of::<ObjectArray>(env, &token).unwrap();
+                assert!(object_array_class.is_same_as(
+                    &token,
+                    &Class::find(env, &token, "[Ljava/lang/Object;").unwrap()
+                ));
+
                 let exception = Class::find(env, &token, "java/lang/Invalid").unwrap_err();
                 assert_eq!(
                     exception
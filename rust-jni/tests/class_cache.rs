@@ -0,0 +1,49 @@
+/// An integration test for caching classes found by name.
+#[cfg(all(
+    test,
+    feature = "libjvm",
+    feature = "std",
+    not(feature = "no-invocation")
+))]
+mod class_cache {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                assert!(class_cache_enabled());
+                let class = Class::find(env, &token, "java/lang/String").unwrap();
+                let cached_class = Class::find(env, &token, "java/lang/String").unwrap();
+                assert!(class.is_same_as(&token, &cached_class));
+
+                // Missing classes are not cached.
+                for _ in 0..2 {
+                    let exception = Class::find(env, &token, "a/b/Missing").unwrap_err();
+                    let class = Class::find(env, &token, "java/lang/NoClassDefFoundError").unwrap();
+                    assert!(exception.is_instance_of(&token, &class));
+                }
+
+                set_class_cache_enabled(false);
+                assert!(!class_cache_enabled());
+                let uncached_class = Class::find(env, &token, "java/lang/String").unwrap();
+                assert!(class.is_same_as(&token, &uncached_class));
+                set_class_cache_enabled(true);
+
+                clear_class_cache(env);
+                let class = Class::find(env, &token, "java/lang/String").unwrap();
+                assert_eq!(
+                    class.get_name(&token).unwrap().unwrap().as_string(&token),
+                    "java.lang.String"
+                );
+
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,161 @@
+/// An integration test for the `java::lang::ClassLoader` type.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod class_loader {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+    use std::fs;
+    use std::sync::Arc;
+
+    /// Minimal wrappers for calling `java.net` and `java.util` APIs.
+    macro_rules! wrapper {
+        ($name:ident, $signature:expr) => {
+            struct $name<'env> {
+                object: Object<'env>,
+            }
+
+            impl<'env> AsRef<Object<'env>> for $name<'env> {
+                fn as_ref(&self) -> &Object<'env> {
+                    &self.object
+                }
+            }
+
+            impl<'env> FromObject<'env> for $name<'env> {
+                unsafe fn from_object(object: Object<'env>) -> Self {
+                    Self { object }
+                }
+            }
+
+            impl JniSignature for $name<'_> {
+                fn signature() -> &'static str {
+                    $signature
+                }
+            }
+        };
+    }
+
+    wrapper!(Url, "Ljava/net/URL;");
+    wrapper!(UrlArray, "[Ljava/net/URL;");
+    wrapper!(UrlClassLoader, "Ljava/net/URLClassLoader;");
+    wrapper!(Arrays, "Ljava/util/Arrays;");
+
+    const CLASS_NAME: &str = "ContextClassLoaderTest";
+
+    /// Class file of `public class ContextClassLoaderTest {}` without any methods.
+    fn class_file() -> Vec<u8> {
+        let mut bytes = vec![0xCA, 0xFE, 0xBA, 0xBE, 0, 0, 0, 52, 0, 5];
+        let mut utf8 = |value: &str| {
+            bytes.push(1);
+            bytes.extend_from_slice(&(value.len() as u16).to_be_bytes());
+            bytes.extend_from_slice(value.as_bytes());
+        };
+        utf8(CLASS_NAME);
+        utf8("java/lang/Object");
+        // Classes referencing names #1 and #2, access flags, this class #3 and super class #4.
+        bytes.extend_from_slice(&[7, 0, 1, 7, 0, 2, 0, 0x21, 0, 3, 0, 4]);
+        // No interfaces, fields, methods or attributes.
+        bytes.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 0]);
+        bytes
+    }
+
+    /// Create a class loader that can load `ContextClassLoaderTest`, unlike the system one.
+    fn url_class_loader<'a>(env: &'a JniEnv<'a>, token: &NoException<'a>) -> ClassLoader<'a> {
+        let directory = std::env::temp_dir().join("rust-jni-class-loader-test");
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(
+            directory.join(format!("{}.class", CLASS_NAME)),
+            class_file(),
+        )
+        .unwrap();
+        let url = format!("file:{}/", directory.display());
+        let url = String::new(env, token, &url).unwrap();
+        // Safe because `URL(String)` is a valid constructor.
+        let url =
+            unsafe { call_constructor::<Url, _, fn(String<'a>)>(env, token, (url,)) }.unwrap();
+        let urls = ObjectArray::new(env, token, 1).unwrap();
+        urls.set(token, 0, Some(&url.object)).unwrap();
+        let url_array_class = Class::find(env, token, "[Ljava/net/URL;").unwrap();
+        // Safe because the method name and arguments are correct.
+        let urls =
+            unsafe {
+                call_static_method::<
+                    Arrays,
+                    _,
+                    _,
+                    fn(ObjectArray<'a>, i32, Class<'a>) -> ObjectArray<'a>,
+                >(env, token, "copyOf\0", (urls, 1, url_array_class))
+            }
+            .unwrap()
+            .unwrap();
+        // Safe because `urls` is a `URL[]` array.
+        let urls = unsafe { UrlArray::from_object(urls.into()) };
+        // Safe because `URLClassLoader(URL[])` is a valid constructor.
+        let class_loader =
+            unsafe { call_constructor::<UrlClassLoader, _, fn(UrlArray<'a>)>(env, token, (urls,)) }
+                .unwrap();
+        // Safe because `URLClassLoader` is a `ClassLoader`.
+        unsafe { ClassLoader::from_object(class_loader.object) }
+    }
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = Arc::new(JavaVM::create(&init_arguments).unwrap());
+        let attach_arguments = vm
+            .with_attached(
+                &AttachArguments::new(init_arguments.version()),
+                |env, token| {
+                    let system = ClassLoader::system(env, &token).unwrap();
+                    assert!(system
+                        .load_class(&token, "java.lang.String")
+                        .unwrap()
+                        .is_same_as(
+                            &token,
+                            &Class::find(env, &token, "java/lang/String").unwrap()
+                        ));
+
+                    let class_loader = url_class_loader(env, &token);
+                    let class = class_loader.load_class(&token, CLASS_NAME).unwrap();
+
+                    assert!(!context_class_loader_fallback_enabled());
+                    env.set_context_class_loader(&token, &class_loader).unwrap();
+                    let exception = Class::find(env, &token, CLASS_NAME).unwrap_err();
+                    assert!(exception.is_instance_of(
+                        &token,
+                        &Class::find(env, &token, "java/lang/NoClassDefFoundError").unwrap()
+                    ));
+
+                    set_context_class_loader_fallback_enabled(true);
+                    assert!(Class::find(env, &token, CLASS_NAME)
+                        .unwrap()
+                        .is_same_as(&token, &class));
+
+                    env.set_context_class_loader(&token, &system).unwrap();
+                    let attach_arguments = AttachArguments::new(init_arguments.version())
+                        .with_context_class_loader(&token, &class_loader)
+                        .unwrap();
+                    (attach_arguments, token)
+                },
+            )
+            .unwrap();
+
+        let child = {
+            let vm = vm.clone();
+            ::std::thread::spawn(move || {
+                vm.with_attached(&attach_arguments, |env, token| {
+                    let context = ClassLoader::context(env, &token).unwrap().unwrap();
+                    let class_loader = attach_arguments
+                        .context_class_loader()
+                        .unwrap()
+                        .object(env, &token)
+                        .unwrap();
+                    assert!(context.is_same_as(&token, &class_loader));
+                    let class = Class::find(env, &token, CLASS_NAME).unwrap();
+                    let name = class.get_name(&token).unwrap().unwrap();
+                    (name.as_string(&token), token)
+                })
+                .unwrap()
+            })
+        };
+        assert_eq!(child.join().unwrap(), CLASS_NAME);
+    }
+}
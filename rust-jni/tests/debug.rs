@@ -0,0 +1,82 @@
+/// An integration test for `debug::dump`.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod debug {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let message = String::new(env, &token, &"a".repeat(100)).unwrap();
+                let throwable = Throwable::new_with_message(env, &token, &message).unwrap();
+                let header = format!(
+                    "java.lang.Throwable@{:x}",
+                    throwable.identity_hash(&token).unwrap() as u32
+                );
+
+                assert_eq!(debug::dump(&throwable, 0, &token).unwrap(), header);
+
+                let dump = debug::dump(&throwable, 1, &token).unwrap();
+                assert!(dump.starts_with(&format!("{}\n", header)), "{}", dump);
+                // Strings are truncated.
+                assert!(
+                    dump.contains(&format!(
+                        "\n  detailMessage: java.lang.String = \"{}\"...\n",
+                        "a".repeat(64)
+                    )),
+                    "{}",
+                    dump
+                );
+                // The cause of a `Throwable` without a cause is the `Throwable` itself.
+                assert!(
+                    dump.contains(&format!(
+                        "\n  cause: java.lang.Throwable = {} (cycle)",
+                        header
+                    )),
+                    "{}",
+                    dump
+                );
+                assert!(dump.contains("\n  depth: int = 0"), "{}", dump);
+                assert!(
+                    dump.contains("\n  stackTrace: java.lang.StackTraceElement[] = "),
+                    "{}",
+                    dump
+                );
+
+                let cause = Throwable::new_with_message(env, &token, &message).unwrap();
+                let throwable = Throwable::new_with_cause(env, &token, &cause).unwrap();
+                let cause_header = format!(
+                    "java.lang.Throwable@{:x}",
+                    cause.identity_hash(&token).unwrap() as u32
+                );
+                let dump = debug::dump(&throwable, 1, &token).unwrap();
+                // Objects deeper than `depth` are not expanded.
+                assert!(
+                    dump.contains(&format!(
+                        "\n  cause: java.lang.Throwable = {}\n",
+                        cause_header
+                    )),
+                    "{}",
+                    dump
+                );
+                let dump = debug::dump(&throwable, 2, &token).unwrap();
+                assert!(dump.contains("\n    detailMessage: java.lang.String = \"aaa"));
+                assert!(
+                    dump.contains(&format!(
+                        "\n  cause: java.lang.Throwable = {}\n    backtrace: ",
+                        cause_header
+                    )),
+                    "{}",
+                    dump
+                );
+
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,47 @@
+/// An integration test for debug names of Java objects.
+#[cfg(all(
+    test,
+    feature = "libjvm",
+    feature = "debug-names",
+    not(feature = "no-invocation")
+))]
+mod debug_name {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let object = Object::new(env, &token).unwrap();
+                assert_eq!(object.debug_name(&token).unwrap(), None);
+                assert!(!format!("{:?}", object).contains("name:"));
+
+                object.set_debug_name(&token, "my-object").unwrap();
+                assert_eq!(
+                    object.debug_name(&token).unwrap(),
+                    Some("my-object".to_owned())
+                );
+                // The second lookup uses the cached `System::identityHashCode` method.
+                assert_eq!(
+                    object.identity_hash(&token).unwrap(),
+                    object.clone().identity_hash(&token).unwrap()
+                );
+                // The name is shared by all references to the same object.
+                assert_eq!(
+                    object.clone().debug_name(&token).unwrap(),
+                    Some("my-object".to_owned())
+                );
+                assert!(format!("{:?}", object).contains("name: \"my-object\""));
+
+                clear_debug_names();
+                assert_eq!(object.debug_name(&token).unwrap(), None);
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,73 @@
+/// An integration test for the `java.lang.Throwable` subclasses.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod exception_hierarchy {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let message = String::new(env, &token, "message").unwrap();
+                let exception =
+                    IllegalArgumentException::new_with_message(env, &token, &message).unwrap();
+                // Methods of superclasses are available through `Deref`.
+                assert_eq!(
+                    exception
+                        .get_message(&token)
+                        .unwrap()
+                        .unwrap()
+                        .as_string(&token),
+                    "message"
+                );
+                let throwable: Throwable = exception.into();
+
+                // Down-casting to an unrelated class returns the exception back.
+                let throwable = throwable
+                    .downcast::<NullPointerException>(&token)
+                    .unwrap()
+                    .unwrap_err();
+                let throwable = throwable.downcast::<Error>(&token).unwrap().unwrap_err();
+
+                // Down-casting to a superclass succeeds.
+                let runtime_exception = throwable
+                    .clone_object(&token)
+                    .unwrap()
+                    .downcast::<RuntimeException>(&token)
+                    .unwrap()
+                    .unwrap();
+                let exception: Exception = runtime_exception.into();
+                assert_eq!(exception, throwable);
+
+                let exception = throwable
+                    .downcast::<IllegalArgumentException>(&token)
+                    .unwrap()
+                    .unwrap();
+                assert!(exception.class(&token).is_same_as(
+                    &token,
+                    &IllegalArgumentException::class(env, &token).unwrap()
+                ));
+
+                let npe = NullPointerException::new_with_message(env, &token, &message).unwrap();
+                let npe: RuntimeException = npe.into();
+                assert!(npe.is_instance_of(&token, &Exception::class(env, &token).unwrap()));
+                assert!(!npe.is_instance_of(&token, &Error::class(env, &token).unwrap()));
+
+                let _ = Exception::new(env, &token).unwrap();
+                let _ = RuntimeException::new(env, &token).unwrap();
+                let _ = IllegalStateException::new(env, &token).unwrap();
+                let _ = UnsupportedOperationException::new(env, &token).unwrap();
+                let error = Error::new_with_message(env, &token, &message).unwrap();
+                assert!(error
+                    .class(&token)
+                    .is_same_as(&token, &Error::class(env, &token).unwrap()));
+
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,105 @@
+/// An integration test for the `JvmExecutor` type.
+#[cfg(all(
+    test,
+    feature = "libjvm",
+    feature = "std",
+    not(feature = "no-invocation")
+))]
+mod executor {
+    use rust_jni::executor::JvmExecutor;
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    /// Tasks are not picked up if the executor threads stopped, so waiting for them fails
+    /// instead of blocking forever.
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = Arc::new(JavaVM::create(&init_arguments).unwrap());
+        let executor = JvmExecutor::new(vm, init_arguments.version(), 3).unwrap();
+        assert_eq!(executor.thread_count(), 3);
+
+        // Submit from threads that are not attached to the Java VM.
+        let executor = Arc::new(executor);
+        let submitters: Vec<_> = (0..4)
+            .map(|index| {
+                let executor = executor.clone();
+                ::std::thread::spawn(move || {
+                    executor
+                        .submit(move |env, token| {
+                            let string = String::new(env, &token, &"a".repeat(index)).unwrap();
+                            (string.len(&token), token)
+                        })
+                        .recv()
+                        .unwrap()
+                        .unwrap()
+                })
+            })
+            .collect();
+        let lengths: Vec<_> = submitters
+            .into_iter()
+            .map(|submitter| submitter.join().unwrap())
+            .collect();
+        assert_eq!(lengths, vec![0, 1, 2, 3]);
+
+        let receivers: Vec<_> = (0..100)
+            .map(|index| executor.submit_ordered(move |_, token| (index, token)))
+            .collect();
+        let results: Vec<_> = receivers
+            .into_iter()
+            .map(|receiver| receiver.recv().unwrap().unwrap())
+            .collect();
+        assert_eq!(results, (0..100).collect::<Vec<_>>());
+
+        // Ordered tasks never run concurrently.
+        let running = Arc::new(AtomicBool::new(false));
+        let receivers: Vec<_> = (0..50)
+            .map(|_| {
+                let running = running.clone();
+                executor.submit_ordered(move |_, token| {
+                    assert!(!running.swap(true, Ordering::SeqCst));
+                    ::std::thread::yield_now();
+                    running.store(false, Ordering::SeqCst);
+                    ((), token)
+                })
+            })
+            .collect();
+        for receiver in receivers {
+            receiver.recv().unwrap().unwrap();
+        }
+
+        // Panics are sent to the task submitter and the threads keep running tasks.
+        let receivers: Vec<_> = (0..10)
+            .map(|_| {
+                executor.submit(|env, token| -> ((), NoException) {
+                    let exception = Throwable::new(env, &token).unwrap();
+                    // The pending exception is cleared after the panic.
+                    let _exception = exception.throw(token);
+                    panic!("task failure");
+                })
+            })
+            .collect();
+        for receiver in receivers {
+            let panic = receiver.recv().unwrap().unwrap_err();
+            assert_eq!(panic.downcast_ref::<&str>(), Some(&"task failure"));
+        }
+        let ordered_panic =
+            executor.submit_ordered(|_, _| -> ((), NoException) { panic!("ordered task failure") });
+        let ordered = executor.submit_ordered(|_, token| (1, token));
+        let receivers: Vec<_> = (0..10)
+            .map(|index| executor.submit(move |_, token| (index, token)))
+            .collect();
+        assert!(ordered_panic.recv().unwrap().is_err());
+        assert_eq!(ordered.recv_timeout(TIMEOUT).unwrap().unwrap(), 1);
+        let results: Vec<_> = receivers
+            .into_iter()
+            .map(|receiver| receiver.recv_timeout(TIMEOUT).unwrap().unwrap())
+            .collect();
+        assert_eq!(results, (0..10).collect::<Vec<_>>());
+    }
+}
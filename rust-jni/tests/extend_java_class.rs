@@ -0,0 +1,70 @@
+/// An integration test for the `extend_java_class!` macro.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod extend_java_class {
+    use rust_jni::java::lang::{Object, String};
+    use rust_jni::*;
+
+    extend_java_class! {
+        /// A string with additional Rust methods.
+        pub struct Name<'env>(String<'env>);
+    }
+
+    impl<'env> Name<'env> {
+        fn shout(&self, token: &NoException<'env>) -> std::string::String {
+            self.as_string(token).to_uppercase()
+        }
+
+        fn substring(
+            &self,
+            token: &NoException<'env>,
+            begin: i32,
+            end: i32,
+        ) -> JavaResult<'env, Option<Name<'env>>> {
+            // Safe because the method name and arguments are correct.
+            unsafe {
+                call_method::<Self, _, _, fn(i32, i32) -> Name<'env>>(
+                    self,
+                    token,
+                    "substring\0",
+                    (begin, end),
+                )
+            }
+        }
+    }
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        let _ = vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let string = String::new(env, &token, "name").unwrap();
+                let name = Name::from(string.clone());
+                assert_eq!(name.shout(&token), "NAME");
+                assert_eq!(name, string);
+                assert_eq!(format!("{}", name), "name");
+                assert!(name.is_instance_of(&token, &Name::class(env, &token).unwrap()));
+
+                // The newtype can be passed to and returned from Java calls.
+                // Safe because the method name and arguments are correct.
+                let length = unsafe {
+                    call_method::<Name, _, _, fn() -> i32>(&name, &token, "length\0", ())
+                }
+                .unwrap();
+                assert_eq!(length, 4);
+                let name = name.substring(&token, 1, 3).unwrap().unwrap();
+                assert_eq!(name.shout(&token), "AM");
+
+                let owned: OwnedObject<Name<'static>> = name.detach_from_env(&token).unwrap();
+                let name = owned.attach_to_env(env, &token).unwrap();
+                assert_eq!(name.shout(&token), "AM");
+
+                let object: Object = name.clone().into();
+                assert_eq!(object, name);
+                assert_eq!(name.into_inner().as_string(&token), "am");
+                ((), token)
+            },
+        );
+    }
+}
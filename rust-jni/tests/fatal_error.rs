@@ -0,0 +1,50 @@
+/// An integration test for the fatal error policy.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod fatal_error {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+    use std::panic::{self, AssertUnwindSafe};
+
+    fn repeat<'a>(
+        string: &String<'a>,
+        token: &NoException<'a>,
+        count: i32,
+    ) -> JavaResult<'a, Option<String<'a>>> {
+        unsafe {
+            call_method::<_, _, _, fn(i32) -> String<'a>>(string, token, "repeat\0", (count,))
+        }
+    }
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8)
+            .unwrap()
+            .with_option(JvmOption::MaxHeapSize(JvmMemorySize::Megabytes(32)));
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let string = String::new(env, &token, "a").unwrap();
+                let out_of_memory_error =
+                    Class::find(env, &token, "java/lang/OutOfMemoryError").unwrap();
+                let illegal_argument_exception =
+                    Class::find(env, &token, "java/lang/IllegalArgumentException").unwrap();
+                let repeat = |count: i32| repeat(&string, &token, count);
+
+                assert_eq!(fatal_error_policy(), FatalErrorPolicy::Continue);
+                let error = repeat(1_000_000_000).unwrap_err();
+                assert!(error.is_instance_of(&token, &out_of_memory_error));
+
+                set_fatal_error_policy(FatalErrorPolicy::Panic);
+                // Regular exceptions are not affected by the policy.
+                let exception = repeat(-1).unwrap_err();
+                assert!(exception.is_instance_of(&token, &illegal_argument_exception));
+                assert!(panic::catch_unwind(AssertUnwindSafe(|| repeat(1_000_000_000))).is_err());
+
+                set_fatal_error_policy(FatalErrorPolicy::Continue);
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,65 @@
+/// An integration test for deleting global references dropped on threads that are not attached.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod global_ref_drop {
+    use rust_jni::java::lang::String;
+    use rust_jni::*;
+    use std::thread;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        let arguments = AttachArguments::new(init_arguments.version());
+        let new_key = || {
+            vm.with_attached(&arguments, |env, token| {
+                let string = String::new(env, &token, "test").unwrap();
+                (ObjectKey::new(&string, &token).unwrap(), token)
+            })
+            .unwrap()
+        };
+
+        // Dropping doesn't attach threads implicitly and defers deleting by default.
+        assert_eq!(global_ref_drop_policy(), GlobalRefDropPolicy::Defer);
+        set_global_ref_drop_policy(GlobalRefDropPolicy::Leak);
+        let key = new_key();
+        thread::spawn(move || drop(key)).join().unwrap();
+        assert_eq!(leaked_global_refs(), 1);
+        assert_eq!(deferred_global_refs(), 0);
+
+        set_global_ref_drop_policy(GlobalRefDropPolicy::Attach);
+        let key = new_key();
+        thread::spawn(move || drop(key)).join().unwrap();
+        assert_eq!(leaked_global_refs(), 1);
+        assert_eq!(deferred_global_refs(), 0);
+
+        set_global_ref_drop_policy(GlobalRefDropPolicy::Defer);
+        let keys = vec![new_key(), new_key()];
+        thread::spawn(move || drop(keys)).join().unwrap();
+        assert_eq!(deferred_global_refs(), 2);
+
+        vm.with_attached(&arguments, |env, token| {
+            assert_eq!(flush_deferred_global_refs(env), 2);
+            assert_eq!(flush_deferred_global_refs(env), 0);
+            ((), token)
+        })
+        .unwrap();
+        assert_eq!(deferred_global_refs(), 0);
+
+        // Creating global references on attached threads flushes the queue.
+        let key = new_key();
+        thread::spawn(move || drop(key)).join().unwrap();
+        assert_eq!(deferred_global_refs(), 1);
+        let key = new_key();
+        assert_eq!(deferred_global_refs(), 0);
+
+        // Keys dropped on attached threads are deleted right away.
+        vm.with_attached(&arguments, |_env, token| {
+            drop(key);
+            ((), token)
+        })
+        .unwrap();
+        assert_eq!(deferred_global_refs(), 0);
+        assert_eq!(leaked_global_refs(), 1);
+        set_global_ref_drop_policy(GlobalRefDropPolicy::Defer);
+    }
+}
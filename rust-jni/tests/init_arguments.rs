@@ -1,4 +1,4 @@
-#[cfg(all(test, feature = "libjvm"))]
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
 mod default_jvm_arguments {
     #[test]
     fn supported_versions() {
@@ -0,0 +1,33 @@
+/// An integration test for the `introspection` module.
+#[cfg(test)]
+mod introspection {
+    use rust_jni::introspection::*;
+
+    fn binding_info() -> BindingInfo {
+        BindingInfo {
+            crate_name: env!("CARGO_PKG_NAME"),
+            crate_version: env!("CARGO_PKG_VERSION"),
+            generator_version: "0.1.0",
+            classes: &[ClassBindingInfo {
+                name: "TestClass",
+                signature: "Lc/d/TestClass;",
+                super_class: "java/lang/Object",
+                interfaces: &["c/d/TestInterface"],
+            }],
+            interfaces: &["TestInterface"],
+        }
+    }
+
+    // Same as the code generated by `java_generate!`.
+    __inventory::submit! {
+        BindingRegistration::new(binding_info)
+    }
+
+    #[test]
+    fn all_bindings() {
+        assert_eq!(
+            rust_jni::introspection::all_bindings(),
+            vec![binding_info()]
+        );
+    }
+}
@@ -0,0 +1,41 @@
+/// An integration test for detecting Java versions.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod java_version {
+    use rust_jni::java::lang::Class;
+    use rust_jni::*;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let feature_level = jvm_feature_level(env, &token).unwrap();
+                assert!(feature_level >= 8);
+                // Cached values are returned on subsequent calls.
+                assert_eq!(jvm_feature_level(env, &token).unwrap(), feature_level);
+
+                assert_eq!(
+                    require_jvm_feature_level(env, &token, feature_level).unwrap(),
+                    Ok(())
+                );
+                assert_eq!(
+                    require_jvm_feature_level(env, &token, feature_level + 1).unwrap(),
+                    Err(UnsupportedOnThisJvm {
+                        required: feature_level + 1,
+                        actual: feature_level,
+                    })
+                );
+
+                let class = Class::find(env, &token, "java/lang/String").unwrap();
+                assert_eq!(class.java_version(&token).unwrap(), Some(feature_level));
+
+                let array_class = Class::find(env, &token, "[I").unwrap();
+                assert_eq!(array_class.java_version(&token).unwrap(), None);
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
@@ -1,4 +1,4 @@
-#[cfg(all(test, feature = "libjvm"))]
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
 mod create_jvm {
     use rust_jni::*;
     use std::ptr;
@@ -9,9 +9,10 @@ mod create_jvm {
         unsafe { assert_ne!(vm.raw_jvm().as_ptr(), ptr::null_mut()) };
 
         let vms = JavaVM::list().unwrap();
-        assert_eq!(vms.len(), 1);
+        assert_eq!(vms.total_count, 1);
+        assert_eq!(vms.java_vms.len(), 1);
         unsafe {
-            assert_eq!(vms[0].raw_jvm(), vm.raw_jvm());
+            assert_eq!(vms.java_vms[0].raw_jvm(), vm.raw_jvm());
         }
     }
 }
@@ -0,0 +1,29 @@
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod create_jvm {
+    use rust_jni::*;
+
+    #[test]
+    fn create_errors() {
+        let option = JvmOption::MaxHeapSize(JvmMemorySize::Megabytes(0));
+        let error =
+            JavaVM::create(&InitArguments::default().with_option(option.clone())).unwrap_err();
+        assert_eq!(
+            error,
+            VmCreationError::InvalidOption {
+                option,
+                option_string: "-Xmx0m".to_owned(),
+                reason: "Memory size must be positive, got Megabytes(0).".to_owned(),
+            }
+        );
+
+        // Invalid options are reported before creating the Java VM.
+        let _vm = JavaVM::create(&InitArguments::default()).unwrap();
+
+        let error = JavaVM::create(&InitArguments::default()).unwrap_err();
+        assert_eq!(error, VmCreationError::VmExists);
+        assert_eq!(
+            error.to_string(),
+            "A Java VM already exists in this process. Only one Java VM per process is supported."
+        );
+    }
+}
@@ -1,4 +1,4 @@
-#[cfg(all(test, feature = "libjvm"))]
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
 mod create_jvm {
     use rust_jni::*;
     use std::ptr;
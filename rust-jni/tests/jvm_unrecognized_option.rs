@@ -1,4 +1,4 @@
-#[cfg(all(test, feature = "libjvm"))]
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
 mod create_jvm {
     use jni_sys;
     use rust_jni::*;
@@ -13,7 +13,7 @@ mod create_jvm {
                     .fail_on_unrecognized_options()
             )
             .unwrap_err(),
-            JniError::Unknown(jni_sys::JNI_ERR)
+            VmCreationError::Jni(JniError::Unknown(jni_sys::JNI_ERR))
         );
     }
 }
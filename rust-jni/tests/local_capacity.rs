@@ -0,0 +1,38 @@
+/// An integration test for reserving local reference capacity.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod local_capacity {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let objects = env
+                    .with_capacity_hint(&token, 10000, |token| {
+                        (0..10000)
+                            .map(|_| Object::new(env, token))
+                            .collect::<JavaResult<Vec<_>>>()
+                    })
+                    .unwrap();
+                assert_eq!(objects.len(), 10000);
+                drop(objects);
+
+                // Errors from the callback are returned as is.
+                let exception = env
+                    .with_capacity_hint(&token, 1, |token| {
+                        Err::<(), _>(NullPointerException::new(env, token).unwrap().into())
+                    })
+                    .unwrap_err();
+                let class = Class::find(env, &token, "java/lang/NullPointerException").unwrap();
+                assert!(exception.is_instance_of(&token, &class));
+
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,66 @@
+/// An integration test for local reference frames.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod local_frame {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+    use std::mem;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                // Local references leaked in a frame are freed when it's popped.
+                for _ in 0..10000 {
+                    let length = unsafe {
+                        env.with_local_frame(&token, 1, |token| {
+                            let string = String::new(env, token, "string")?;
+                            let length = string.len(token) as i32;
+                            mem::forget(string);
+                            Ok(length)
+                        })
+                    }
+                    .unwrap();
+                    assert_eq!(length, 6);
+                }
+
+                // Objects are promoted to the previous frame.
+                let string = unsafe {
+                    env.with_local_frame(&token, 2, |token| {
+                        let _other = Object::new(env, token)?;
+                        String::new(env, token, "string")
+                    })
+                }
+                .unwrap();
+                assert_eq!(string.as_string(&token), "string");
+
+                let string = unsafe {
+                    env.with_local_frame(&token, 1, |token| {
+                        Ok(Some(String::new(env, token, "string")?))
+                    })
+                }
+                .unwrap();
+                assert_eq!(string.unwrap().as_string(&token), "string");
+
+                let none = unsafe { env.with_local_frame(&token, 1, |_token| Ok(None::<String>)) }
+                    .unwrap();
+                assert!(none.is_none());
+
+                // Exceptions are promoted to the previous frame.
+                let exception = unsafe {
+                    env.with_local_frame(&token, 1, |token| {
+                        Err::<(), _>(NullPointerException::new(env, token).unwrap().into())
+                    })
+                }
+                .unwrap_err();
+                let class = Class::find(env, &token, "java/lang/NullPointerException").unwrap();
+                assert!(exception.is_instance_of(&token, &class));
+
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,45 @@
+/// An integration test for the `java::util::Map` type.
+#[cfg(all(
+    test,
+    feature = "libjvm",
+    feature = "std",
+    feature = "java-util",
+    not(feature = "no-invocation")
+))]
+mod map {
+    use rust_jni::java::lang::*;
+    use rust_jni::java::util::*;
+    use rust_jni::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let empty = Map::from_hash_map(env, &token, &HashMap::new()).unwrap();
+                assert_eq!(
+                    empty.to_string(&token).unwrap().unwrap().as_string(&token),
+                    "{}"
+                );
+                assert_eq!(empty.to_hash_map(&token).unwrap(), HashMap::new());
+
+                let mut map = HashMap::new();
+                for index in 0..100 {
+                    map.insert(format!("key {}", index), format!("значение {}", index));
+                }
+                let java_map = Map::from_hash_map(env, &token, &map).unwrap();
+                assert_eq!(java_map.to_hash_map(&token).unwrap(), map);
+
+                let object: Object = java_map.into();
+                let class = Class::find(env, &token, "java/util/Map").unwrap();
+                assert!(object.is_instance_of(&token, &class));
+
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
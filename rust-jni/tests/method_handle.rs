@@ -0,0 +1,120 @@
+/// An integration test for the `java::lang::invoke::MethodHandle` type.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod method_handle {
+    use rust_jni::java::lang::invoke::*;
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    fn invoke_concat<'a>(
+        concat: &MethodHandle<'a>,
+        token: &NoException<'a>,
+        left: &String<'a>,
+        right: &String<'a>,
+    ) -> JavaResult<'a, Option<String<'a>>> {
+        concat
+            .invoke_exact::<_, _, fn(&String<'a>, &String<'a>) -> String<'a>>(token, (left, right))
+    }
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let lookup = MethodHandles::lookup(env, &token).unwrap();
+                let integer = Class::find(env, &token, "java/lang/Integer").unwrap();
+                let math = Class::find(env, &token, "java/lang/Math").unwrap();
+                let character = Class::find(env, &token, "java/lang/Character").unwrap();
+                let string = Class::find(env, &token, "java/lang/String").unwrap();
+                let thread = Class::find(env, &token, "java/lang/Thread").unwrap();
+
+                let sum = lookup
+                    .find_static(&token, &integer, "sum", "(II)I")
+                    .unwrap();
+                assert_eq!(
+                    sum.type_descriptor(&token).unwrap().as_string(&token),
+                    "(II)I"
+                );
+                assert_eq!(
+                    sum.invoke_exact::<_, _, fn(i32, i32) -> i32>(&token, (2, 3))
+                        .unwrap(),
+                    5
+                );
+
+                // Primitive arguments and results of all sizes are boxed and unboxed.
+                let max = lookup.find_static(&token, &math, "max", "(JJ)J").unwrap();
+                assert_eq!(
+                    max.invoke_exact::<_, _, fn(i64, i64) -> i64>(&token, (i64::MIN, 7))
+                        .unwrap(),
+                    7
+                );
+                let sqrt = lookup.find_static(&token, &math, "sqrt", "(D)D").unwrap();
+                assert_eq!(
+                    sqrt.invoke_exact::<_, _, fn(f64) -> f64>(&token, (16.,))
+                        .unwrap(),
+                    4.
+                );
+                let is_digit = lookup
+                    .find_static(&token, &character, "isDigit", "(C)Z")
+                    .unwrap();
+                assert!(is_digit
+                    .invoke_exact::<_, _, fn(char) -> bool>(&token, ('7',))
+                    .unwrap());
+
+                // Instance methods take the receiver as the first argument.
+                let concat = lookup
+                    .find_virtual(
+                        &token,
+                        &string,
+                        "concat",
+                        "(Ljava/lang/String;)Ljava/lang/String;",
+                    )
+                    .unwrap();
+                let left = String::new(env, &token, "left").unwrap();
+                let right = String::new(env, &token, "right").unwrap();
+                let result = invoke_concat(&concat, &token, &left, &right)
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(result.as_string(&token), "leftright");
+
+                let on_spin_wait = lookup
+                    .find_static(&token, &thread, "onSpinWait", "()V")
+                    .unwrap();
+                on_spin_wait.invoke_exact::<_, _, fn()>(&token, ()).unwrap();
+
+                // The type must match exactly.
+                let exception = sum
+                    .invoke_exact::<_, _, fn(i64, i64) -> i64>(&token, (2, 3))
+                    .unwrap_err();
+                assert!(exception.is_instance_of(
+                    &token,
+                    &Class::find(env, &token, "java/lang/invoke/WrongMethodTypeException").unwrap()
+                ));
+
+                // Exceptions thrown by the method are returned.
+                let parse_int = lookup
+                    .find_static(&token, &integer, "parseInt", "(Ljava/lang/String;)I")
+                    .unwrap();
+                let exception = parse_int
+                    .invoke_exact::<_, _, fn(&String) -> i32>(&token, (&left,))
+                    .unwrap_err();
+                assert!(exception.is_instance_of(
+                    &token,
+                    &Class::find(env, &token, "java/lang/NumberFormatException").unwrap()
+                ));
+
+                let exception = lookup
+                    .find_static(&token, &integer, "missing", "()V")
+                    .unwrap_err();
+                assert!(exception.is_instance_of(
+                    &token,
+                    &Class::find(env, &token, "java/lang/NoSuchMethodException").unwrap()
+                ));
+
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,41 @@
+/// An integration test for the `MonitorGuard` type.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod monitor {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let object = Object::new(env, &token).unwrap();
+                let illegal_monitor_state_exception =
+                    Class::find(env, &token, "java/lang/IllegalMonitorStateException").unwrap();
+
+                // `Object::notify` throws when the current thread doesn't own the monitor.
+                let exception = object.notify(&token).unwrap_err();
+                assert!(exception.is_instance_of(&token, &illegal_monitor_state_exception));
+                let exception = object.wait_timeout(&token, 1).unwrap_err();
+                assert!(exception.is_instance_of(&token, &illegal_monitor_state_exception));
+
+                {
+                    let _guard = MonitorGuard::new(&object, &token).unwrap();
+                    let reentrant_guard = MonitorGuard::new(&object, &token).unwrap();
+                    object.notify(&token).unwrap();
+                    object.notify_all(&token).unwrap();
+                    // Returns after the timeout, as nothing notifies the object.
+                    reentrant_guard.wait_timeout(&token, 1).unwrap();
+                }
+
+                let exception = object.notify_all(&token).unwrap_err();
+                assert!(exception.is_instance_of(&token, &illegal_monitor_state_exception));
+
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
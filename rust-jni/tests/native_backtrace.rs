@@ -0,0 +1,59 @@
+/// An integration test for capturing Rust backtraces of panics in native methods.
+#[cfg(all(
+    test,
+    feature = "libjvm",
+    feature = "std",
+    not(feature = "no-invocation")
+))]
+mod native_backtrace {
+    use rust_jni::java::lang::Class;
+    use rust_jni::*;
+
+    /// Call a static native method implementation that panics and return the exception message.
+    fn panic_message<'a>(env: &'a JniEnv<'a>, token: &NoException<'a>) -> String {
+        let class = Class::find(env, token, "java/lang/Object").unwrap();
+        // Safe because the arguments are correct.
+        unsafe {
+            static_native_method_implementation::<(), (), _>(
+                env.raw_env().as_ptr(),
+                class.raw_object().as_ptr(),
+                (),
+                |_class, _token, _arguments| panic!("native failure"),
+            )
+        };
+        let exception = env.take_pending_exception().unwrap();
+        exception
+            .get_message(token)
+            .unwrap()
+            .unwrap()
+            .as_string(token)
+    }
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        let _ = vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                assert!(!native_backtraces_enabled());
+                assert_eq!(panic_message(env, &token), "Rust panic: native failure");
+
+                set_native_backtraces_enabled(true);
+                assert!(native_backtraces_enabled());
+                let message = panic_message(env, &token);
+                assert!(
+                    message.starts_with("Rust panic: native failure\n\nRust backtrace:\n"),
+                    "{}",
+                    message
+                );
+
+                // Panics outside of native methods don't leave backtraces behind.
+                assert!(std::panic::catch_unwind(|| panic!("outside")).is_err());
+                set_native_backtraces_enabled(false);
+                assert_eq!(panic_message(env, &token), "Rust panic: native failure");
+                ((), token)
+            },
+        );
+    }
+}
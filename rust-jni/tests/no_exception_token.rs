@@ -1,4 +1,4 @@
-#[cfg(all(test, feature = "libjvm"))]
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
 mod create_envs {
     use rust_jni::*;
 
@@ -57,6 +57,22 @@ mod create_envs {
             .unwrap();
     }
 
+    fn example_scope(vm: &JavaVM, init_arguments: &InitArguments) {
+        let length = vm
+            .with_attached(
+                &AttachArguments::new(init_arguments.version()),
+                |env, mut token| {
+                    let length = token.scope(|token| {
+                        let string = java::lang::String::new(env, &token, "string").unwrap();
+                        (string.len(&token), token)
+                    });
+                    (length, token)
+                },
+            )
+            .unwrap();
+        assert_eq!(length, 6);
+    }
+
     #[test]
     fn test() {
         let init_arguments = InitArguments::default();
@@ -66,5 +82,6 @@ mod create_envs {
         example_attach_manually(&vm, &init_arguments);
         example_throws_exception(&vm, &init_arguments);
         example_rethrows_exception(&vm, &init_arguments);
+        example_scope(&vm, &init_arguments);
     }
 }
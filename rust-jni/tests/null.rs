@@ -0,0 +1,50 @@
+/// An integration test for passing `null` to Java methods.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod null {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    fn value_of<'a>(
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+        value: Option<&Object<'a>>,
+    ) -> JavaResult<'a, Option<String<'a>>> {
+        unsafe {
+            call_static_method::<String, _, _, fn(Option<&Object<'a>>) -> String<'a>>(
+                env,
+                token,
+                "valueOf\0",
+                (value,),
+            )
+        }
+    }
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let value = value_of(env, &token, null::<Object>())
+                    .or_npe(env, &token)
+                    .unwrap();
+                assert_eq!(value.as_string(&token), "null");
+
+                let string = String::new(env, &token, "test").unwrap();
+                let equals = unsafe {
+                    call_method::<_, _, _, fn(Option<&Object>) -> bool>(
+                        &string,
+                        &token,
+                        "equals\0",
+                        (null::<Object>(),),
+                    )
+                }
+                .unwrap();
+                assert!(!equals);
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
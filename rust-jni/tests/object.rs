@@ -1,5 +1,5 @@
 /// An integration test for the `java::lang::Object` type.
-#[cfg(all(test, feature = "libjvm"))]
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
 mod class {
     use rust_jni::java::lang::*;
     use rust_jni::*;
@@ -52,13 +52,48 @@ mod class {
                 assert!(string1.equals(&token, &string2).unwrap());
                 assert!(string1.equals(&token, &string3).unwrap());
 
+                assert!(string1.ref_eq(&token, &string2));
+                assert!(!string1.ref_eq(&token, &string3));
+                assert!(!object.ref_eq(&token, &string1));
+                assert!(string1.value_eq(&token, &string3).unwrap());
+                assert!(!string1.value_eq(&token, &object).unwrap());
+                assert!(object.value_eq(&token, &object).unwrap());
+
                 assert_eq!(
                     object.to_string(&token).unwrap().unwrap().as_string(&token),
                     format!("java.lang.Object@{:x}", object.hash_code(&token).unwrap())
                 );
 
                 assert!(format!("{:?}", object).contains("java.lang.Object@"));
+                assert_eq!(
+                    format!("{}", object),
+                    format!("java.lang.Object@{:x}", object.hash_code(&token).unwrap())
+                );
+
+                ((), token)
+            },
+        )
+        .unwrap();
+
+        #[cfg(not(feature = "strict"))]
+        format_with_pending_exception(&vm, &init_arguments);
+    }
+
+    // Only one Java VM can be created per process, so this runs as a part of `test`.
+    #[cfg(not(feature = "strict"))]
+    fn format_with_pending_exception(vm: &JavaVM, init_arguments: &InitArguments) {
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let object = Object::new(env, &token).unwrap();
+                let exception = Class::find(env, &token, "invalid").unwrap_err();
+                let exception_token = exception.throw(token);
+
+                assert!(format!("{}", object).contains("because of a pending exception"));
+                assert!(format!("{:?}", object).contains("because of a pending exception"));
 
+                let (_exception, token) = exception_token.unwrap();
+                assert!(format!("{}", object).starts_with("java.lang.Object@"));
                 ((), token)
             },
         )
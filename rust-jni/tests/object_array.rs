@@ -0,0 +1,100 @@
+/// An integration test for the `ObjectArray` type.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod object_array {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    fn format<'a>(
+        env: &'a JniEnv<'a>,
+        token: &NoException<'a>,
+        format: String<'a>,
+        arguments: ObjectArray<'a>,
+    ) -> JavaResult<'a, Option<String<'a>>> {
+        unsafe {
+            call_static_method::<String, _, _, fn(String<'a>, ObjectArray<'a>) -> String<'a>>(
+                env,
+                token,
+                "format\0",
+                (format, arguments),
+            )
+        }
+    }
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let string = String::new(env, &token, "value").unwrap();
+                let object = Object::new(env, &token).unwrap();
+
+                let array = ObjectArray::new(env, &token, 2).unwrap();
+                assert_eq!(array.len(&token), 2);
+                assert!(array.get(&token, 0).unwrap().is_none());
+                array.set(&token, 1, Some(&object)).unwrap();
+                assert!(array
+                    .get(&token, 1)
+                    .unwrap()
+                    .unwrap()
+                    .is_same_as(&token, &object));
+
+                let index_out_of_bounds_exception =
+                    Class::find(env, &token, "java/lang/ArrayIndexOutOfBoundsException").unwrap();
+                let exception = array.get(&token, 2).unwrap_err();
+                assert!(exception.is_instance_of(&token, &index_out_of_bounds_exception));
+                let exception = array.set(&token, 2, None).unwrap_err();
+                assert!(exception.is_instance_of(&token, &index_out_of_bounds_exception));
+
+                // Round trip.
+                let array = ObjectArray::from_tuple(
+                    env,
+                    &token,
+                    &(string.clone(), None::<Object>, object.clone()),
+                )
+                .unwrap();
+                let (first, second, third) = array
+                    .to_tuple::<(String, Option<String>, Object)>(&token)
+                    .unwrap();
+                assert!(first.is_same_as(&token, &string));
+                assert!(second.is_none());
+                assert!(third.is_same_as(&token, &object));
+
+                // Wrong length.
+                let exception = array.to_tuple::<(Object, Object)>(&token).unwrap_err();
+                let illegal_argument_exception =
+                    Class::find(env, &token, "java/lang/IllegalArgumentException").unwrap();
+                assert!(exception.is_instance_of(&token, &illegal_argument_exception));
+
+                // Wrong class.
+                let exception = array
+                    .to_tuple::<(String, Option<Object>, String)>(&token)
+                    .unwrap_err();
+                let class_cast_exception =
+                    Class::find(env, &token, "java/lang/ClassCastException").unwrap();
+                assert!(exception.is_instance_of(&token, &class_cast_exception));
+
+                // Unexpected null.
+                let exception = array
+                    .to_tuple::<(String, Object, Object)>(&token)
+                    .unwrap_err();
+                let null_pointer_exception =
+                    Class::find(env, &token, "java/lang/NullPointerException").unwrap();
+                assert!(exception.is_instance_of(&token, &null_pointer_exception));
+
+                // Varargs.
+                let format_string = String::new(env, &token, "%s-%s").unwrap();
+                let arguments =
+                    ObjectArray::from_tuple(env, &token, &(string.clone(), string)).unwrap();
+                let formatted = format(env, &token, format_string, arguments)
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(formatted.as_string(&token), "value-value");
+
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,46 @@
+/// An integration test for the `ObjectKey` type.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod object_key {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let string1 = String::new(env, &token, "test").unwrap();
+                let string2 = string1.clone_object(&token).unwrap();
+                let string3 = String::new(env, &token, "test").unwrap();
+
+                assert_eq!(
+                    string1.identity_hash(&token).unwrap(),
+                    string2.identity_hash(&token).unwrap()
+                );
+
+                let key1 = ObjectKey::new(&string1, &token).unwrap();
+                let key2 = ObjectKey::new(&string2, &token).unwrap();
+                let key3 = ObjectKey::new(&string3, &token).unwrap();
+                assert_eq!(key1, key2);
+                assert_ne!(key1, key3);
+                assert_eq!(key1.identity_hash(), string1.identity_hash(&token).unwrap());
+                assert!(key1
+                    .object(env, &token)
+                    .unwrap()
+                    .is_same_as(&token, &string1));
+
+                let mut map = HashMap::new();
+                map.insert(key1, 1);
+                map.insert(key3, 3);
+                assert_eq!(map.get(&key2), Some(&1));
+                assert_eq!(map.len(), 2);
+
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
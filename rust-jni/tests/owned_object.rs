@@ -0,0 +1,56 @@
+/// An integration test for the `OwnedObject` type.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod owned_object {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+    use std::sync::Arc;
+
+    /// A long-lived struct that is not bound to a `JniEnv`.
+    struct State {
+        string: OwnedObject<String<'static>>,
+        class: OwnedObject<Class<'static>>,
+    }
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = Arc::new(JavaVM::create(&init_arguments).unwrap());
+        let state = vm
+            .with_attached(
+                &AttachArguments::new(init_arguments.version()),
+                |env, token| {
+                    let string = String::new(env, &token, "owned").unwrap();
+                    let class = string.class(&token);
+                    let state = State {
+                        string: string.detach_from_env(&token).unwrap(),
+                        class: class.detach_from_env(&token).unwrap(),
+                    };
+                    let string = state.string.attach_to_env(env, &token).unwrap();
+                    assert_eq!(string.as_string(&token), "owned");
+                    (state, token)
+                },
+            )
+            .unwrap();
+
+        let child = {
+            let vm = vm.clone();
+            ::std::thread::spawn(move || {
+                vm.with_attached(
+                    &AttachArguments::new(init_arguments.version()),
+                    |env, token| {
+                        let string = state.string.attach_to_env(env, &token).unwrap();
+                        let class = state.class.attach_to_env(env, &token).unwrap();
+                        assert!(string.class(&token).is_same_as(&token, &class));
+                        assert_eq!(
+                            state.string.as_key().identity_hash(),
+                            string.identity_hash(&token).unwrap()
+                        );
+                        (string.as_string(&token), token)
+                    },
+                )
+                .unwrap()
+            })
+        };
+        assert_eq!(child.join().unwrap(), "owned");
+    }
+}
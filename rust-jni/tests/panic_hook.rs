@@ -0,0 +1,131 @@
+/// An integration test for the Java panic hook.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod panic_hook {
+    use rust_jni::introspection::__inventory;
+    use rust_jni::java::lang::{Class, Object};
+    use rust_jni::*;
+    use std::ffi::c_void;
+    use std::os::raw::c_char;
+    use std::sync::Mutex;
+
+    const CLASS_NAME: &str = "PanicHookTest";
+
+    /// A minimal wrapper to call static methods of the test class.
+    struct TestClass<'env> {
+        object: Object<'env>,
+    }
+
+    impl<'env> AsRef<Object<'env>> for TestClass<'env> {
+        fn as_ref(&self) -> &Object<'env> {
+            &self.object
+        }
+    }
+
+    impl JniSignature for TestClass<'_> {
+        fn signature() -> &'static str {
+            "LPanicHookTest;"
+        }
+    }
+
+    fn utf8(bytes: &mut Vec<u8>, value: &str) {
+        bytes.push(1);
+        bytes.extend_from_slice(&(value.len() as u16).to_be_bytes());
+        bytes.extend_from_slice(value.as_bytes());
+    }
+
+    /// Class file of `public class PanicHookTest { public static native void nativeMethod(); }`.
+    fn class_file() -> Vec<u8> {
+        let mut bytes = vec![0xCA, 0xFE, 0xBA, 0xBE, 0, 0, 0, 52, 0, 7];
+        // Constant pool: classes #1 and #3 referencing names #2 and #4.
+        bytes.extend_from_slice(&[7, 0, 2]);
+        utf8(&mut bytes, CLASS_NAME);
+        bytes.extend_from_slice(&[7, 0, 4]);
+        utf8(&mut bytes, "java/lang/Object");
+        utf8(&mut bytes, "nativeMethod");
+        utf8(&mut bytes, "()V");
+        // Access flags, this class #1, super class #3 and no interfaces or fields.
+        bytes.extend_from_slice(&[0, 0x21, 0, 1, 0, 3, 0, 0, 0, 0]);
+        // A public static native method named #5 with descriptor #6 and no attributes.
+        bytes.extend_from_slice(&[0, 1, 0x01, 0x09, 0, 5, 0, 6, 0, 0]);
+        // No class attributes.
+        bytes.extend_from_slice(&[0, 0]);
+        bytes
+    }
+
+    static CONTEXT: Mutex<Option<String>> = Mutex::new(None);
+
+    /// Throw an exception and record the Java context the panic hook would print.
+    extern "system" fn native_method(env: *mut jni_sys::JNIEnv, _class: jni_sys::jclass) {
+        // Safe because the arguments are correct.
+        unsafe {
+            let class = ((**env).FindClass.unwrap())(
+                env,
+                "java/lang/IllegalStateException\0".as_ptr() as *const c_char,
+            );
+            ((**env).ThrowNew.unwrap())(env, class, "test\0".as_ptr() as *const c_char);
+        }
+        *CONTEXT.lock().unwrap() = java_panic_context();
+    }
+
+    __inventory::submit! {
+        NativeMethodRegistration::new(
+            "PanicHookTest",
+            "nativeMethod\0",
+            "()V\0",
+            native_method as *mut c_void,
+        )
+    }
+
+    #[test]
+    fn test() {
+        install_java_panic_hook();
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        let _ = vm.with_attached(
+            &AttachArguments::named(init_arguments.version(), "panic-hook-thread"),
+            |env: &JniEnv, token: NoException| {
+                assert!(java_panic_context().is_none());
+
+                let class_file = class_file();
+                let name = format!("{}\0", CLASS_NAME);
+                // Safe because the arguments are correct.
+                let class = unsafe {
+                    env.with_raw_env(&token, |raw_env| {
+                        let raw_env = raw_env.as_ptr();
+                        ((**raw_env).DefineClass.unwrap())(
+                            raw_env,
+                            name.as_ptr() as *const c_char,
+                            std::ptr::null_mut(),
+                            class_file.as_ptr() as *const jni_sys::jbyte,
+                            class_file.len() as jni_sys::jsize,
+                        )
+                    })
+                };
+                assert!(!class.is_null());
+                let _registration = NativeRegistration::register_all(env, &token).unwrap();
+
+                // Safe because the method name and arguments are correct.
+                let exception = unsafe {
+                    call_static_method::<TestClass, _, _, fn()>(env, &token, "nativeMethod\0", ())
+                }
+                .unwrap_err();
+                // The pending exception is thrown again after collecting the context.
+                assert!(exception.is_instance_of(
+                    &token,
+                    &Class::find(env, &token, "java/lang/IllegalStateException").unwrap()
+                ));
+                assert_eq!(
+                    CONTEXT.lock().unwrap().as_deref(),
+                    Some(
+                        "Java context:\n  \
+                         thread: panic-hook-thread\n  \
+                         calling: PanicHookTest.nativeMethod\n  \
+                         pending exception: java.lang.IllegalStateException: test"
+                    )
+                );
+                assert!(java_panic_context().is_none());
+                ((), token)
+            },
+        );
+    }
+}
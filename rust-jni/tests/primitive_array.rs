@@ -0,0 +1,102 @@
+/// An integration test for primitive arrays.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod primitive_array {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let mut array = JavaIntArray::new(env, &token, 3).unwrap();
+                assert_eq!(array.len(&token), 3);
+                assert_eq!(&*array.elements(&token).unwrap(), &[0, 0, 0]);
+
+                // Changes are copied back by default.
+                {
+                    let mut elements = array.elements_mut(&token).unwrap();
+                    assert_eq!(elements.release_mode(), ArrayReleaseMode::CopyBack);
+                    elements.copy_from_slice(&[1, 2, 3]);
+                }
+                assert_eq!(&*array.elements(&token).unwrap(), &[1, 2, 3]);
+
+                // Committed changes are visible before release.
+                let view = array.clone();
+                {
+                    let mut elements = array.elements_mut(&token).unwrap();
+                    elements[0] = 10;
+                    elements.commit(&token);
+                    let mut first = [0];
+                    view.get_region(&token, 0, &mut first).unwrap();
+                    assert_eq!(first, [10]);
+                    elements.set_release_mode(ArrayReleaseMode::Abort);
+                }
+                assert_eq!(&*array.elements(&token).unwrap(), &[10, 2, 3]);
+
+                // Aborted changes are only discarded for copies.
+                let is_copy = {
+                    let mut elements = array.elements_mut(&token).unwrap();
+                    elements.set_release_mode(ArrayReleaseMode::Abort);
+                    elements[1] = 20;
+                    elements.is_copy()
+                };
+                let expected = if is_copy { [10, 2, 3] } else { [10, 20, 3] };
+                assert_eq!(&*array.elements(&token).unwrap(), &expected);
+
+                let array = JavaIntArray::new(env, &token, 0).unwrap();
+                assert!(array.elements(&token).unwrap().is_empty());
+
+                // Regions are copied into and out of Rust slices.
+                let array = JavaLongArray::from_slice(env, &token, &[1, 2, 3, 4]).unwrap();
+                array.set_region(&token, 1, &[20, 30]).unwrap();
+                let mut buffer = [0; 3];
+                array.get_region(&token, 1, &mut buffer).unwrap();
+                assert_eq!(buffer, [20, 30, 4]);
+                let exception = array.get_region(&token, 2, &mut buffer).unwrap_err();
+                let class =
+                    Class::find(env, &token, "java/lang/ArrayIndexOutOfBoundsException").unwrap();
+                assert!(exception.is_instance_of(&token, &class));
+                let exception = array.set_region(&token, 4, &[1]).unwrap_err();
+                assert!(exception.is_instance_of(&token, &class));
+                let exception = array
+                    .get_region(&token, usize::MAX, &mut buffer)
+                    .unwrap_err();
+                assert!(exception.is_instance_of(&token, &class));
+
+                // Lengths that don't fit into a `jsize` are errors rather than panics.
+                let exception = JavaIntArray::new(env, &token, usize::MAX).unwrap_err();
+                let class = Class::find(env, &token, "java/lang/OutOfMemoryError").unwrap();
+                assert!(exception.is_instance_of(&token, &class));
+
+                let array = JavaBooleanArray::from_slice(env, &token, &[1, 0]).unwrap();
+                assert_eq!(&*array.elements(&token).unwrap(), &[1, 0]);
+                let array = JavaByteArray::from_slice(env, &token, &[-1, 2]).unwrap();
+                assert_eq!(&*array.elements(&token).unwrap(), &[-1, 2]);
+                let array = JavaCharArray::from_slice(env, &token, &[0x61, 0xd800]).unwrap();
+                assert_eq!(&*array.elements(&token).unwrap(), &[0x61, 0xd800]);
+                let array = JavaShortArray::from_slice(env, &token, &[-1, 2]).unwrap();
+                assert_eq!(&*array.elements(&token).unwrap(), &[-1, 2]);
+                let array = JavaFloatArray::from_slice(env, &token, &[1.5, -2.0]).unwrap();
+                assert_eq!(&*array.elements(&token).unwrap(), &[1.5, -2.0]);
+                let array = JavaDoubleArray::new(env, &token, 2).unwrap();
+                array.set_region(&token, 0, &[0.25, 4.0]).unwrap();
+                assert_eq!(&*array.elements(&token).unwrap(), &[0.25, 4.0]);
+                assert_eq!(
+                    array
+                        .class(&token)
+                        .get_name(&token)
+                        .unwrap()
+                        .unwrap()
+                        .as_string(&token),
+                    "[D"
+                );
+
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
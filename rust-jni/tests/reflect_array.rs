@@ -0,0 +1,63 @@
+/// An integration test for the `java::lang::reflect::Array` type.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod reflect_array {
+    use rust_jni::java::lang::reflect::Array;
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        let _ = vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let string_class = Class::find(env, &token, "java/lang/String").unwrap();
+                let array = Array::new_instance(&string_class, &token, 3).unwrap();
+                assert_eq!(array.len(&token), 3);
+                assert!(array
+                    .class(&token)
+                    .is_same_as(&token, &Class::array_of::<String>(env, &token).unwrap()));
+                assert!(array
+                    .component_type(&token)
+                    .unwrap()
+                    .is_same_as(&token, &string_class));
+
+                let string = String::new(env, &token, "value").unwrap();
+                array.set(&token, 2, Some(&string)).unwrap();
+                assert!(array.get(&token, 0).unwrap().is_none());
+                assert_eq!(array.get(&token, 2).unwrap().unwrap(), string);
+                array.set(&token, 2, None).unwrap();
+                assert!(array.get(&token, 2).unwrap().is_none());
+
+                let object = Object::new(env, &token).unwrap();
+                assert_throws!(Throwable, array.set(&token, 0, Some(&object)), &token);
+                let exception: Throwable = assert_throws!(Throwable, array.get(&token, 3), &token);
+                assert!(exception.is_instance_of(
+                    &token,
+                    &Class::find(env, &token, "java/lang/ArrayIndexOutOfBoundsException").unwrap()
+                ));
+
+                // Primitive elements are boxed.
+                let int_class = Class::primitive::<i32>(env, &token).unwrap();
+                let array = Array::new_instance(&int_class, &token, 1).unwrap();
+                assert!(array
+                    .class(&token)
+                    .is_same_as(&token, &Class::array_of::<i32>(env, &token).unwrap()));
+                let element = array.get(&token, 0).unwrap().unwrap();
+                assert_eq!(format!("{}", element), "0");
+                assert!(element.is_instance_of(
+                    &token,
+                    &Class::find(env, &token, "java/lang/Integer").unwrap()
+                ));
+                let exception: Throwable =
+                    assert_throws!(Throwable, array.set(&token, 0, Some(&string)), &token);
+                assert!(exception.is_instance_of(
+                    &token,
+                    &Class::find(env, &token, "java/lang/IllegalArgumentException").unwrap()
+                ));
+                ((), token)
+            },
+        );
+    }
+}
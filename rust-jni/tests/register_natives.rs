@@ -0,0 +1,126 @@
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod register_natives {
+    use rust_jni::introspection::__inventory;
+    use rust_jni::java::lang::{Class, Object};
+    use rust_jni::*;
+    use std::ffi::c_void;
+    use std::os::raw::c_char;
+
+    const CLASS_NAME: &str = "RegisterNativesTest";
+
+    /// A minimal wrapper to call static methods of the test class.
+    struct TestClass<'env> {
+        object: Object<'env>,
+    }
+
+    impl<'env> AsRef<Object<'env>> for TestClass<'env> {
+        fn as_ref(&self) -> &Object<'env> {
+            &self.object
+        }
+    }
+
+    impl JniSignature for TestClass<'_> {
+        fn signature() -> &'static str {
+            "LRegisterNativesTest;"
+        }
+    }
+
+    fn utf8(bytes: &mut Vec<u8>, value: &str) {
+        bytes.push(1);
+        bytes.extend_from_slice(&(value.len() as u16).to_be_bytes());
+        bytes.extend_from_slice(value.as_bytes());
+    }
+
+    /// Class file of
+    /// `public class RegisterNativesTest { public static native long nativeMethod(int value); }`.
+    fn class_file() -> Vec<u8> {
+        let mut bytes = vec![0xCA, 0xFE, 0xBA, 0xBE, 0, 0, 0, 52, 0, 7];
+        // Constant pool: classes #1 and #3 referencing names #2 and #4.
+        bytes.extend_from_slice(&[7, 0, 2]);
+        utf8(&mut bytes, CLASS_NAME);
+        bytes.extend_from_slice(&[7, 0, 4]);
+        utf8(&mut bytes, "java/lang/Object");
+        utf8(&mut bytes, "nativeMethod");
+        utf8(&mut bytes, "(I)J");
+        // Access flags, this class #1, super class #3 and no interfaces or fields.
+        bytes.extend_from_slice(&[0, 0x21, 0, 1, 0, 3, 0, 0, 0, 0]);
+        // A public static native method named #5 with descriptor #6 and no attributes.
+        bytes.extend_from_slice(&[0, 1, 0x01, 0x09, 0, 5, 0, 6, 0, 0]);
+        // No class attributes.
+        bytes.extend_from_slice(&[0, 0]);
+        bytes
+    }
+
+    extern "system" fn native_method(
+        _env: *mut jni_sys::JNIEnv,
+        _class: jni_sys::jclass,
+        value: jni_sys::jint,
+    ) -> jni_sys::jlong {
+        value as jni_sys::jlong * 2
+    }
+
+    // Same as the code generated by `#[register_native("RegisterNativesTest", "nativeMethod", "(I)J")]`.
+    __inventory::submit! {
+        NativeMethodRegistration::new(
+            "RegisterNativesTest",
+            "nativeMethod\0",
+            "(I)J\0",
+            native_method as *mut c_void,
+        )
+    }
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        let _ = vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env: &JniEnv, token: NoException| {
+                let class_file = class_file();
+                let name = format!("{}\0", CLASS_NAME);
+                // Safe because the arguments are correct.
+                let class = unsafe {
+                    env.with_raw_env(&token, |raw_env| {
+                        let raw_env = raw_env.as_ptr();
+                        ((**raw_env).DefineClass.unwrap())(
+                            raw_env,
+                            name.as_ptr() as *const c_char,
+                            std::ptr::null_mut(),
+                            class_file.as_ptr() as *const jni_sys::jbyte,
+                            class_file.len() as jni_sys::jsize,
+                        )
+                    })
+                };
+                assert!(!class.is_null());
+                let class = Class::find(env, &token, CLASS_NAME).unwrap();
+                let unsatisfied_link_error =
+                    Class::find(env, &token, "java/lang/UnsatisfiedLinkError").unwrap();
+                // Safe because the method name and arguments are correct.
+                let call = || unsafe {
+                    call_static_method::<TestClass, _, _, fn(i32) -> i64>(
+                        env,
+                        &token,
+                        "nativeMethod\0",
+                        (21,),
+                    )
+                };
+
+                register_all_natives(env, &token).unwrap();
+                assert_eq!(call().unwrap(), 42);
+
+                class.unregister_natives(&token).unwrap();
+                assert!(call()
+                    .unwrap_err()
+                    .is_instance_of(&token, &unsatisfied_link_error));
+
+                let registration = NativeRegistration::register_all(env, &token).unwrap();
+                assert_eq!(call().unwrap(), 42);
+                drop(registration);
+                assert!(call()
+                    .unwrap_err()
+                    .is_instance_of(&token, &unsatisfied_link_error));
+                ((), token)
+            },
+        );
+    }
+}
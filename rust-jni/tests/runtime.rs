@@ -0,0 +1,76 @@
+/// An integration test for the `java::lang::Runtime` type.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod runtime {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    /// A minimal wrapper for `java.lang.Thread` to register as a shutdown hook.
+    struct Thread<'env> {
+        object: Object<'env>,
+    }
+
+    impl<'env> AsRef<Object<'env>> for Thread<'env> {
+        fn as_ref(&self) -> &Object<'env> {
+            &self.object
+        }
+    }
+
+    impl<'env> FromObject<'env> for Thread<'env> {
+        unsafe fn from_object(object: Object<'env>) -> Self {
+            Self { object }
+        }
+    }
+
+    impl JniSignature for Thread<'_> {
+        fn signature() -> &'static str {
+            "Ljava/lang/Thread;"
+        }
+    }
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let runtime = Runtime::get_runtime(env, &token).unwrap();
+                assert!(runtime.class(&token).is_same_as(
+                    &token,
+                    &Class::find(env, &token, "java/lang/Runtime").unwrap()
+                ));
+                assert!(runtime.is_same_as(&token, &Runtime::get_runtime(env, &token).unwrap()));
+                assert!(runtime.available_processors(&token).unwrap() > 0);
+
+                // Safe because `Thread()` is a valid constructor.
+                let hook = unsafe { call_constructor::<Thread, _, fn()>(env, &token, ()) }.unwrap();
+                runtime.add_shutdown_hook(&token, &hook.object).unwrap();
+                let exception = runtime.add_shutdown_hook(&token, &hook.object).unwrap_err();
+                assert!(exception.is_instance_of(
+                    &token,
+                    &Class::find(env, &token, "java/lang/IllegalArgumentException").unwrap()
+                ));
+                assert_eq!(
+                    runtime.remove_shutdown_hook(&token, &hook.object).unwrap(),
+                    true
+                );
+                assert_eq!(
+                    runtime.remove_shutdown_hook(&token, &hook.object).unwrap(),
+                    false
+                );
+
+                let not_a_thread = Object::new(env, &token).unwrap();
+                let exception = runtime
+                    .add_shutdown_hook(&token, &not_a_thread)
+                    .unwrap_err();
+                assert!(exception.is_instance_of(
+                    &token,
+                    &Class::find(env, &token, "java/lang/ClassCastException").unwrap()
+                ));
+
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
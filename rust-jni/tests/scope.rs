@@ -0,0 +1,45 @@
+/// An integration test for running scoped tasks on attached threads.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod scope {
+    use rust_jni::java::lang::String;
+    use rust_jni::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        let arguments = AttachArguments::new(init_arguments.version());
+
+        // Tasks can borrow from the enclosing stack frame.
+        let total = AtomicUsize::new(0);
+        let words = vec!["a", "bb", "ccc", "dddd"];
+        rust_jni::scope(&vm, &arguments, |scope| {
+            for word in &words {
+                let total = &total;
+                scope.spawn_attached(move |env, token| {
+                    let string = String::new(env, &token, word).unwrap();
+                    total.fetch_add(string.len(&token), Ordering::SeqCst);
+                    ((), token)
+                });
+            }
+        });
+        // All tasks are joined when the scope returns.
+        assert_eq!(total.load(Ordering::SeqCst), 10);
+
+        let result = rust_jni::scope(&vm, &arguments, |scope| {
+            let task = scope.spawn_attached(|env, token| {
+                let string = String::new(env, &token, "value").unwrap();
+                (string.as_string(&token), token)
+            });
+            task.join().unwrap().unwrap()
+        });
+        assert_eq!(result, "value");
+
+        // Spawned threads are detached after the tasks finish.
+        assert_eq!(
+            vm.with_attached(&arguments, |_env, token| ((), token)),
+            Ok(())
+        );
+    }
+}
@@ -0,0 +1,57 @@
+/// An integration test for calling Java methods with `short` arguments and results.
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod short {
+    use rust_jni::java::lang::Object;
+    use rust_jni::*;
+
+    /// A minimal wrapper to call static methods of `java.lang.Short`.
+    struct Short<'env> {
+        object: Object<'env>,
+    }
+
+    impl<'env> AsRef<Object<'env>> for Short<'env> {
+        fn as_ref(&self) -> &Object<'env> {
+            &self.object
+        }
+    }
+
+    impl JniSignature for Short<'_> {
+        fn signature() -> &'static str {
+            "Ljava/lang/Short;"
+        }
+    }
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                // Safe because the method name and arguments are correct.
+                let reversed = unsafe {
+                    call_static_method::<Short, _, _, fn(i16) -> i16>(
+                        env,
+                        &token,
+                        "reverseBytes\0",
+                        (0x1234,),
+                    )
+                };
+                assert_eq!(reversed.unwrap(), 0x3412);
+
+                // Safe because the method name and arguments are correct.
+                let value = unsafe {
+                    call_static_method::<Short, _, _, fn(i16) -> i32>(
+                        env,
+                        &token,
+                        "toUnsignedInt\0",
+                        (-1,),
+                    )
+                };
+                assert_eq!(value.unwrap(), 0xffff);
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
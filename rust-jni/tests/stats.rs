@@ -0,0 +1,49 @@
+/// An integration test for JNI resource counters.
+#[cfg(all(
+    test,
+    feature = "libjvm",
+    feature = "stats",
+    not(feature = "no-invocation")
+))]
+mod stats {
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        let created_envs = stats().created_envs;
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                let before = stats();
+                assert_eq!(before.created_envs, created_envs + 1);
+
+                let object = Object::new(env, &token).unwrap();
+                let string = String::new(env, &token, "test").unwrap();
+                assert_eq!(stats().live_objects, before.live_objects + 2);
+
+                let key = ObjectKey::new(&string, &token).unwrap();
+                assert_eq!(stats().global_refs, before.global_refs + 1);
+                drop(key);
+                assert_eq!(stats().global_refs, before.global_refs);
+
+                drop(object);
+                drop(string);
+                assert_eq!(stats().live_objects, before.live_objects);
+
+                clear_class_cache(env);
+                assert_eq!(stats().weak_refs, 0);
+                let class = Class::find(env, &token, "java/lang/Runtime").unwrap();
+                assert_eq!(stats().weak_refs, 1);
+                drop(class);
+                clear_class_cache(env);
+                assert_eq!(stats().weak_refs, 0);
+
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
@@ -1,9 +1,17 @@
 /// An integration test for the `java::lang::String` type.
-#[cfg(all(test, feature = "libjvm"))]
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
 mod string {
     use rust_jni::java::lang::*;
     use rust_jni::*;
 
+    fn chunks<'a>(
+        string: &String<'a>,
+        token: &NoException<'a>,
+        chunk_size: usize,
+    ) -> Vec<Result<::std::string::String, StringDecodingError>> {
+        string.chars_chunks(token, chunk_size).collect()
+    }
+
     #[test]
     fn test() {
         let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
@@ -32,6 +40,20 @@ mod string {
                 assert_eq!(string.as_string(&token), "строка");
                 assert_eq!(string.len(&token), 6);
                 assert_eq!(string.size(&token), 12);
+                assert_eq!(
+                    string
+                        .with_utf_chars(&token, |chars| chars.to_bytes().to_vec())
+                        .unwrap(),
+                    "строка".as_bytes()
+                );
+                // Modified UTF-8 encodes `\0` as two bytes, so the C string is not cut short.
+                let string_with_null = String::new(&env, &token, "a\0b").unwrap();
+                assert_eq!(
+                    string_with_null
+                        .with_utf_chars(&token, |chars| chars.to_bytes().to_vec())
+                        .unwrap(),
+                    b"a\xC0\x80b"
+                );
 
                 assert_eq!(
                     String::value_of_int(&env, &token, 17)
@@ -41,6 +63,103 @@ mod string {
                     "17"
                 );
 
+                assert_eq!(string.length(&token).unwrap(), 6);
+                assert_eq!(string.is_empty(&token).unwrap(), false);
+                assert_eq!(
+                    String::empty(env, &token)
+                        .unwrap()
+                        .is_empty(&token)
+                        .unwrap(),
+                    true
+                );
+                assert_eq!(string.char_at(&token, 1).unwrap(), 'т');
+                assert!(string.char_at(&token, 6).is_err());
+
+                let substring = string.substring(&token, 1, 4).unwrap().unwrap();
+                assert_eq!(substring.as_string(&token), "тро");
+                assert!(string.substring(&token, 4, 1).is_err());
+
+                let emoji = String::new(&env, &token, "a😀").unwrap();
+                assert_eq!(emoji.try_as_string(&token), Ok("a😀".to_owned()));
+                assert_eq!(emoji.as_string_lossy(&token), "a😀");
+                // Cut the surrogate pair in half.
+                let unpaired = emoji.substring(&token, 0, 2).unwrap().unwrap();
+                assert_eq!(unpaired.try_as_string(&token), Err(StringDecodingError));
+                assert_eq!(unpaired.as_string_lossy(&token), "a\u{FFFD}");
+
+                assert_eq!(
+                    chunks(&string, &token, 4),
+                    vec![Ok("стро".to_owned()), Ok("ка".to_owned())]
+                );
+                assert_eq!(
+                    chunks(&String::empty(env, &token).unwrap(), &token, 4),
+                    vec![]
+                );
+                assert_eq!(string.chars_chunks(&token, 4).size_hint(), (2, Some(2)));
+                // Surrogate pairs are not split between chunks.
+                let emojis = String::new(&env, &token, "a😀b😀").unwrap();
+                assert_eq!(
+                    chunks(&emojis, &token, 2),
+                    vec![Ok("a😀".to_owned()), Ok("b😀".to_owned())]
+                );
+                assert_eq!(chunks(&emojis, &token, 1).len(), 4);
+                assert_eq!(
+                    chunks(&unpaired, &token, 1),
+                    vec![Ok("a".to_owned()), Err(StringDecodingError)]
+                );
+
+                #[cfg(feature = "std")]
+                {
+                    let mut sink = Vec::new();
+                    emojis.write_to(&token, 3, &mut sink).unwrap();
+                    assert_eq!(sink, "a😀b😀".as_bytes());
+                    let mut sink = Vec::new();
+                    let error = unpaired.write_to(&token, 1, &mut sink).unwrap_err();
+                    assert_eq!(error.kind(), ::std::io::ErrorKind::InvalidData);
+                    assert_eq!(sink, b"a");
+                }
+
+                let suffix = String::new(&env, &token, "-ка").unwrap();
+                assert_eq!(
+                    string
+                        .concat(&token, &suffix)
+                        .unwrap()
+                        .unwrap()
+                        .as_string(&token),
+                    "строка-ка"
+                );
+
+                let value_of = |string: Option<String>| string.unwrap().as_string(&token);
+                assert_eq!(
+                    value_of(String::value_of_bool(&env, &token, true).unwrap()),
+                    "true"
+                );
+                assert_eq!(
+                    value_of(String::value_of_char(&env, &token, 'ю').unwrap()),
+                    "ю"
+                );
+                assert_eq!(
+                    value_of(String::value_of_long(&env, &token, -1 << 40).unwrap()),
+                    "-1099511627776"
+                );
+                assert_eq!(
+                    value_of(String::value_of_float(&env, &token, 1.5).unwrap()),
+                    "1.5"
+                );
+                assert_eq!(
+                    value_of(String::value_of_double(&env, &token, -0.25).unwrap()),
+                    "-0.25"
+                );
+
+                let format = String::new(&env, &token, "%s and %s").unwrap();
+                let arguments =
+                    ObjectArray::from_tuple(env, &token, &(string.clone(), Option::<String>::None))
+                        .unwrap();
+                assert_eq!(
+                    value_of(String::format(&env, &token, &format, &arguments).unwrap()),
+                    "строка and null"
+                );
+
                 ((), token)
             },
         )
@@ -1,5 +1,5 @@
 /// An integration test for the `java::lang::Throwable` type.
-#[cfg(all(test, feature = "libjvm"))]
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
 mod throwable {
     use rust_jni::java::lang::*;
     use rust_jni::*;
@@ -0,0 +1,87 @@
+/// An integration test for `tracing` spans of Java calls.
+#[cfg(all(
+    test,
+    feature = "libjvm",
+    feature = "tracing",
+    not(feature = "no-invocation")
+))]
+mod tracing_spans {
+    use rust_jni::java::lang::String;
+    use rust_jni::*;
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    type Fields = Vec<(&'static str, std::string::String)>;
+
+    /// Records the fields of all created spans.
+    struct SpanRecorder {
+        spans: Arc<Mutex<Vec<Fields>>>,
+    }
+
+    struct FieldVisitor<'a>(&'a mut Fields);
+
+    impl<'a> Visit for FieldVisitor<'a> {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0.push((field.name(), format!("{:?}", value)));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.push((field.name(), value.to_owned()));
+        }
+    }
+
+    impl Subscriber for SpanRecorder {
+        fn enabled(&self, _metadata: &Metadata) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes) -> Id {
+            let mut fields = vec![("name", span.metadata().name().to_owned())];
+            span.record(&mut FieldVisitor(&mut fields));
+            let mut spans = self.spans.lock().unwrap();
+            spans.push(fields);
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record) {}
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, _event: &Event) {}
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        let spans = Arc::new(Mutex::new(vec![]));
+        let recorder = SpanRecorder {
+            spans: spans.clone(),
+        };
+        tracing::subscriber::with_default(recorder, || {
+            vm.with_attached(
+                &AttachArguments::new(init_arguments.version()),
+                |env, token| {
+                    let string = String::new(env, &token, "test").unwrap();
+                    assert_eq!(string.is_empty(&token).unwrap(), false);
+                    ((), token)
+                },
+            )
+            .unwrap();
+        });
+        let expected: Fields = vec![
+            ("name", "java_call".to_owned()),
+            ("class", "Ljava/lang/String;".to_owned()),
+            ("method", "isEmpty".to_owned()),
+            ("signature", "()Z".to_owned()),
+        ];
+        assert!(spans.lock().unwrap().contains(&expected));
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod unchecked {
+    use rust_jni::java::lang::String;
+    use rust_jni::*;
+
+    #[test]
+    fn test() {
+        static CHAR_AT: MethodIdCache = MethodIdCache::new();
+
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        let _ = vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env: &JniEnv, token: NoException| {
+                let string = String::new(env, &token, "value").unwrap();
+                unsafe {
+                    unchecked::resolve_method::<String, _, _, fn(i32) -> char>(
+                        &CHAR_AT, env, &token, "charAt\0",
+                    )
+                }
+                .unwrap();
+
+                let characters = (0..5)
+                    .map(|index| unsafe {
+                        unchecked::call_method_unchecked::<_, _, _, fn(i32) -> char>(
+                            &CHAR_AT,
+                            &string,
+                            (index,),
+                        )
+                    })
+                    .collect::<std::string::String>();
+                assert_eq!(characters, "value");
+                assert!(env.take_pending_exception().is_none());
+
+                // The exception is only seen by the batched check.
+                let character = unsafe {
+                    unchecked::call_method_unchecked::<_, _, _, fn(i32) -> char>(
+                        &CHAR_AT,
+                        &string,
+                        (10,),
+                    )
+                };
+                assert_eq!(character, '\0');
+                let exception = env.take_pending_exception().unwrap();
+                assert_eq!(
+                    exception
+                        .class(&token)
+                        .get_name(&token)
+                        .unwrap()
+                        .unwrap()
+                        .as_string(&token),
+                    "java.lang.StringIndexOutOfBoundsException"
+                );
+                ((), token)
+            },
+        );
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(all(test, feature = "libjvm", not(feature = "no-invocation")))]
+mod verify_hierarchy {
+    use rust_jni::introspection::*;
+    use rust_jni::java::lang::String;
+    use rust_jni::*;
+    use std::panic::{self, AssertUnwindSafe};
+
+    const STALE_STRING: ClassBindingInfo = ClassBindingInfo {
+        name: "String",
+        signature: "Ljava/lang/String;",
+        super_class: "java/lang/Number",
+        interfaces: &[],
+    };
+
+    fn binding_info() -> BindingInfo {
+        BindingInfo {
+            crate_name: env!("CARGO_PKG_NAME"),
+            crate_version: env!("CARGO_PKG_VERSION"),
+            generator_version: "0.1.0",
+            classes: &[STALE_STRING],
+            interfaces: &[],
+        }
+    }
+
+    // Same as the code generated by `java_generate!`.
+    __inventory::submit! {
+        BindingRegistration::new(binding_info)
+    }
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = JavaVM::create(&init_arguments).unwrap();
+        let _ = vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env: &JniEnv, token: NoException| {
+                let string = ClassBindingInfo {
+                    name: "String",
+                    signature: "Ljava/lang/String;",
+                    super_class: "java/lang/Object",
+                    interfaces: &["java/lang/CharSequence", "java/lang/Comparable"],
+                };
+                assert_eq!(verify_class_hierarchy(env, &token, &string).unwrap(), None);
+
+                assert_eq!(
+                    verify_class_hierarchy(env, &token, &STALE_STRING).unwrap(),
+                    Some(
+                        "Java class java.lang.String extends java.lang.Object instead of \
+                         java.lang.Number declared in the bindings of String."
+                            .to_owned()
+                    )
+                );
+
+                let string = ClassBindingInfo {
+                    interfaces: &["java/lang/CharSequence", "java/lang/Runnable"],
+                    ..string
+                };
+                assert_eq!(
+                    verify_class_hierarchy(env, &token, &string).unwrap(),
+                    Some(
+                        "Java class java.lang.String doesn't implement java.lang.Runnable \
+                         declared in the bindings of String."
+                            .to_owned()
+                    )
+                );
+
+                let value = String::new(env, &token, "value").unwrap();
+                let call = || unsafe {
+                    call_method::<_, _, _, fn() -> i32>(&value, &token, "length\0", ())
+                };
+                if cfg!(feature = "verify-hierarchy") {
+                    let error = panic::catch_unwind(AssertUnwindSafe(call)).unwrap_err();
+                    assert_eq!(
+                        error.downcast_ref::<std::string::String>().unwrap(),
+                        "Java class java.lang.String extends java.lang.Object instead of \
+                         java.lang.Number declared in the bindings of String."
+                    );
+                    // The hierarchy is only verified on the first call.
+                    assert_eq!(call().unwrap(), 5);
+                } else {
+                    assert_eq!(call().unwrap(), 5);
+                }
+                ((), token)
+            },
+        );
+    }
+}
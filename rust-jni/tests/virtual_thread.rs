@@ -0,0 +1,44 @@
+/// An integration test for virtual thread support.
+#[cfg(all(
+    test,
+    feature = "libjvm",
+    feature = "std",
+    not(feature = "no-invocation")
+))]
+mod virtual_thread {
+    use rust_jni::executor::JvmExecutor;
+    use rust_jni::java::lang::*;
+    use rust_jni::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn test() {
+        let init_arguments = InitArguments::get_default(JniVersion::V8).unwrap();
+        let vm = Arc::new(JavaVM::create(&init_arguments).unwrap());
+        let executor = JvmExecutor::new(vm.clone(), init_arguments.version(), 1).unwrap();
+        vm.with_attached(
+            &AttachArguments::new(init_arguments.version()),
+            |env, token| {
+                // Threads attached from Rust are platform threads.
+                assert!(!is_virtual_thread(env, &token).unwrap());
+
+                // Tasks run in place on platform threads.
+                let current = thread::current().id();
+                let (same_thread, length) = executor
+                    .run_on_platform_thread(env, &token, move |env, token| {
+                        let string = String::new(env, &token, "string").unwrap();
+                        (
+                            (thread::current().id() == current, string.len(&token)),
+                            token,
+                        )
+                    })
+                    .unwrap();
+                assert!(same_thread);
+                assert_eq!(length, 6);
+                ((), token)
+            },
+        )
+        .unwrap();
+    }
+}
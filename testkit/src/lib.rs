@@ -0,0 +1,347 @@
+//! # Test utilities for `rust-jni`
+//!
+//! Helpers for testing code that calls Java classes which only exist in the test itself.
+//! Add this crate as a dev-dependency and call [`compile_and_run`](fn.compile_and_run.html)
+//! with the Java source of the classes under test:
+//! ```no_run
+//! # use rust_jni::*;
+//! # use rust_jni::java::lang::Class;
+//! #
+//! rust_jni_testkit::compile_and_run(
+//!     "package example; public class Fixture {}",
+//!     |env, token| {
+//!         let class = Class::find(env, &token, "example/Fixture").unwrap();
+//!         let name = class.get_name(&token).unwrap().unwrap();
+//!         assert_eq!(name.as_string(&token), "example.Fixture");
+//!         ((), token)
+//!     },
+//! );
+//! ```
+//!
+//! The sources are compiled with the
+//! [`javax.tools.JavaCompiler`](https://docs.oracle.com/javase/10/docs/api/javax/tools/JavaCompiler.html)
+//! API, so the tests need to run on a JDK, not a JRE.
+//!
+//! All tests in a process share one Java VM, which is created on first use by
+//! [`java_vm`](fn.java_vm.html).
+//!
+//! Needs the `libjvm` feature to link the tests with the Java VM library.
+
+use rust_jni::java::lang::reflect::Array;
+use rust_jni::java::lang::{Class, ClassLoader, Object, String};
+use rust_jni::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+
+/// The JNI version the test Java VM is created with.
+const VERSION: JniVersion = JniVersion::V8;
+
+static JAVA_VM: OnceLock<JavaVM> = OnceLock::new();
+
+/// Counter for unique source directories within the process.
+static NEXT_DIRECTORY: AtomicUsize = AtomicUsize::new(0);
+
+/// Get the Java VM shared by all tests in the process, creating it on the first call.
+///
+/// Classes compiled by [`compile_and_run`](fn.compile_and_run.html) are loaded by a separate
+/// class loader for each call, so the VM is set up to make [`Class::find`] see them:
+/// the context class loader fallback is enabled and the class cache is disabled.
+///
+/// Will panic if the Java VM can't be created.
+///
+/// [`Class::find`]: ../rust_jni/java/lang/struct.Class.html#method.find
+pub fn java_vm() -> &'static JavaVM {
+    JAVA_VM.get_or_init(|| {
+        let init_arguments =
+            InitArguments::get_default(VERSION).expect("Failed to get default init arguments.");
+        let vm = JavaVM::create(&init_arguments).expect("Failed to create a Java VM.");
+        set_context_class_loader_fallback_enabled(true);
+        set_class_cache_enabled(false);
+        vm
+    })
+}
+
+/// Compile the Java source and call `test` on a thread which can load the compiled classes.
+///
+/// The source is compiled into a fresh temporary directory, which is loaded by a new
+/// [`URLClassLoader`](https://docs.oracle.com/javase/10/docs/api/java/net/URLClassLoader.html)
+/// set as the context class loader of the attached thread. The directory is removed after
+/// `test` returns.
+///
+/// The source file is named after the first `public` type in the source, so the source
+/// can declare a package and a public top-level class, like a regular Java file.
+///
+/// Will panic if the source doesn't compile. Compilation errors are printed to the standard
+/// error stream.
+pub fn compile_and_run<R>(
+    java_source: &str,
+    test: impl for<'a> FnOnce(&'a JniEnv<'a>, NoException<'a>) -> (R, NoException<'a>),
+) -> R {
+    let directory = TemporaryDirectory::new();
+    let source_file = directory.path.join(format!(
+        "{}.java",
+        public_type_name(java_source).unwrap_or("Source")
+    ));
+    fs::write(&source_file, java_source).expect("Failed to write the Java source file.");
+    java_vm()
+        .with_attached(&AttachArguments::new(VERSION), |env, token| {
+            let class_loader = compile(env, &token, &directory.path, &source_file).unwrap();
+            set_context_class_loader(env, &token, &class_loader).unwrap();
+            test(env, token)
+        })
+        .expect("Failed to attach the thread to the Java VM.")
+}
+
+/// Compile the source file into the directory and create a class loader for it.
+fn compile<'a>(
+    env: &'a JniEnv<'a>,
+    token: &NoException<'a>,
+    directory: &Path,
+    source_file: &Path,
+) -> JavaResult<'a, ClassLoader<'a>> {
+    // Safe because we ensure correct arguments and return type.
+    let compiler = unsafe {
+        call_static_method::<ToolProvider, _, _, fn() -> JavaCompiler<'a>>(
+            env,
+            token,
+            "getSystemJavaCompiler\0",
+            (),
+        )
+    }?
+    .expect("No Java compiler is available, the tests need to run on a JDK.");
+    let arguments = [
+        "-d",
+        path_to_str(directory),
+        "-encoding",
+        "UTF-8",
+        path_to_str(source_file),
+    ];
+    let arguments = object_array::<StringArray>(
+        env,
+        token,
+        "java/lang/String",
+        arguments
+            .iter()
+            .map(|argument| String::new(env, token, argument).map(Into::into))
+            .collect::<JavaResult<'a, Vec<Object<'a>>>>()?,
+    )?;
+    // Safe because we ensure correct arguments and return type.
+    let result = unsafe {
+        call_method::<
+            _,
+            _,
+            _,
+            fn(
+                Option<&InputStream>,
+                Option<&OutputStream>,
+                Option<&OutputStream>,
+                &StringArray,
+            ) -> i32,
+        >(&compiler, token, "run\0", (None, None, None, &arguments))
+    }?;
+    if result != 0 {
+        panic!(
+            "Failed to compile the Java source, javac exited with code {}.",
+            result
+        );
+    }
+
+    let path = String::new(env, token, path_to_str(directory))?;
+    // Safe because we ensure correct arguments.
+    let file = unsafe { call_constructor::<File, _, fn(&String)>(env, token, (&path,)) }?;
+    // Safe because we ensure correct arguments and return type.
+    let uri = unsafe { call_method::<_, _, _, fn() -> Uri<'a>>(&file, token, "toURI\0", ()) }?
+        .expect("File.toURI() returned null.");
+    // Safe because we ensure correct arguments and return type.
+    let url = unsafe { call_method::<_, _, _, fn() -> Url<'a>>(&uri, token, "toURL\0", ()) }?
+        .expect("URI.toURL() returned null.");
+    let urls = object_array::<UrlArray>(env, token, "java/net/URL", vec![url.object])?;
+    // Safe because we ensure correct arguments and return type.
+    let class_loader = unsafe {
+        call_static_method::<UrlClassLoader, _, _, fn(&UrlArray) -> UrlClassLoader<'a>>(
+            env,
+            token,
+            "newInstance\0",
+            (&urls,),
+        )
+    }?
+    .expect("URLClassLoader.newInstance() returned null.");
+    // Safe because a `URLClassLoader` is a `ClassLoader`.
+    Ok(unsafe { ClassLoader::from_object(class_loader.object) })
+}
+
+/// Set the context class loader of the current thread.
+fn set_context_class_loader<'a>(
+    env: &'a JniEnv<'a>,
+    token: &NoException<'a>,
+    class_loader: &ClassLoader<'a>,
+) -> JavaResult<'a, ()> {
+    // Safe because we ensure correct arguments and return type.
+    let thread = unsafe {
+        call_static_method::<Thread, _, _, fn() -> Thread<'a>>(env, token, "currentThread\0", ())
+    }?
+    .expect("Thread.currentThread() returned null.");
+    // Safe because we ensure correct arguments and return type.
+    unsafe {
+        call_method::<_, _, _, fn(&ClassLoader)>(
+            &thread,
+            token,
+            "setContextClassLoader\0",
+            (class_loader,),
+        )
+    }
+}
+
+/// Create a Java array of `elements` with the element class `class_name`.
+fn object_array<'a, T: FromObject<'a>>(
+    env: &'a JniEnv<'a>,
+    token: &NoException<'a>,
+    class_name: &str,
+    elements: Vec<Object<'a>>,
+) -> JavaResult<'a, T> {
+    let class = Class::find(env, token, class_name)?;
+    let array = Array::new_instance(&class, token, elements.len())?;
+    for (index, element) in elements.iter().enumerate() {
+        array.set(token, index, Some(element))?;
+    }
+    // Safe because the array is created with the element class of `T`.
+    Ok(unsafe { T::from_object(array.into()) })
+}
+
+fn path_to_str(path: &Path) -> &str {
+    path.to_str()
+        .expect("The temporary directory path is not valid UTF-8.")
+}
+
+/// Find the name of the first `public` class, interface, enum or record in the Java source.
+fn public_type_name(java_source: &str) -> Option<&str> {
+    let mut words = java_source
+        .split(|character: char| {
+            !(character.is_alphanumeric() || character == '_' || character == '$')
+        })
+        .filter(|word| !word.is_empty());
+    while let Some(word) = words.next() {
+        if word != "public" {
+            continue;
+        }
+        for word in words.by_ref() {
+            match word {
+                "abstract" | "final" | "static" | "strictfp" | "sealed" | "non" => continue,
+                "class" | "interface" | "enum" | "record" => return words.next(),
+                _ => break,
+            }
+        }
+    }
+    None
+}
+
+/// A uniquely named directory in the system temporary directory, removed on drop.
+struct TemporaryDirectory {
+    path: PathBuf,
+}
+
+impl TemporaryDirectory {
+    fn new() -> Self {
+        let path = std::env::temp_dir().join(format!(
+            "rust-jni-testkit-{}-{}",
+            process::id(),
+            NEXT_DIRECTORY.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&path).expect("Failed to create a temporary directory.");
+        Self { path }
+    }
+}
+
+impl Drop for TemporaryDirectory {
+    fn drop(&mut self) {
+        // Failing to clean up shouldn't fail the test.
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Declare a minimal wrapper for a Java class used only to pass values between JNI calls.
+macro_rules! java_class {
+    ($name:ident, $signature:expr) => {
+        struct $name<'env> {
+            object: Object<'env>,
+        }
+
+        impl<'env> AsRef<Object<'env>> for $name<'env> {
+            fn as_ref(&self) -> &Object<'env> {
+                &self.object
+            }
+        }
+
+        impl<'env> FromObject<'env> for $name<'env> {
+            unsafe fn from_object(object: Object<'env>) -> Self {
+                Self { object }
+            }
+        }
+
+        impl JniSignature for $name<'_> {
+            fn signature() -> &'static str {
+                $signature
+            }
+        }
+    };
+}
+
+java_class!(ToolProvider, "Ljavax/tools/ToolProvider;");
+java_class!(JavaCompiler, "Ljavax/tools/JavaCompiler;");
+java_class!(InputStream, "Ljava/io/InputStream;");
+java_class!(OutputStream, "Ljava/io/OutputStream;");
+java_class!(StringArray, "[Ljava/lang/String;");
+java_class!(File, "Ljava/io/File;");
+java_class!(Uri, "Ljava/net/URI;");
+java_class!(Url, "Ljava/net/URL;");
+java_class!(UrlArray, "[Ljava/net/URL;");
+java_class!(UrlClassLoader, "Ljava/net/URLClassLoader;");
+java_class!(Thread, "Ljava/lang/Thread;");
+
+#[cfg(test)]
+mod public_type_name_tests {
+    use super::*;
+
+    #[test]
+    fn class() {
+        assert_eq!(
+            public_type_name("package a.b; public class Test { public void f() {} }"),
+            Some("Test")
+        );
+    }
+
+    #[test]
+    fn modifiers() {
+        assert_eq!(public_type_name("public final class Test {}"), Some("Test"));
+        assert_eq!(
+            public_type_name("public abstract class Test {}"),
+            Some("Test")
+        );
+        assert_eq!(
+            public_type_name("public non-sealed interface Test {}"),
+            Some("Test")
+        );
+    }
+
+    #[test]
+    fn other_types() {
+        assert_eq!(public_type_name("public interface Test {}"), Some("Test"));
+        assert_eq!(public_type_name("public enum Test { A }"), Some("Test"));
+        assert_eq!(
+            public_type_name("public record Test(int a) {}"),
+            Some("Test")
+        );
+    }
+
+    #[test]
+    fn package_private() {
+        assert_eq!(
+            public_type_name("class Other {} public class Test {}"),
+            Some("Test")
+        );
+        assert_eq!(public_type_name("class Test { public int a; }"), None);
+    }
+}
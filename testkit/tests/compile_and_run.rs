@@ -0,0 +1,99 @@
+/// An integration test for compiling and calling Java sources at test time.
+#[cfg(all(test, feature = "libjvm"))]
+mod compile_and_run {
+    use rust_jni::java::lang::{Class, Object};
+    use rust_jni::*;
+    use rust_jni_testkit::compile_and_run;
+
+    struct Adder<'env> {
+        object: Object<'env>,
+    }
+
+    impl<'env> AsRef<Object<'env>> for Adder<'env> {
+        fn as_ref(&self) -> &Object<'env> {
+            &self.object
+        }
+    }
+
+    impl JniSignature for Adder<'_> {
+        fn signature() -> &'static str {
+            "Lrust/jni/testkit/Adder;"
+        }
+    }
+
+    struct Constant<'env> {
+        object: Object<'env>,
+    }
+
+    impl<'env> AsRef<Object<'env>> for Constant<'env> {
+        fn as_ref(&self) -> &Object<'env> {
+            &self.object
+        }
+    }
+
+    impl JniSignature for Constant<'_> {
+        fn signature() -> &'static str {
+            "Lrust/jni/testkit/Constant;"
+        }
+    }
+
+    #[test]
+    fn test() {
+        let result = compile_and_run(
+            r#"
+            package rust.jni.testkit;
+
+            public final class Adder {
+                public static int add(int left, int right) {
+                    return left + right;
+                }
+            }
+            "#,
+            |env, token| {
+                let class = Class::find(env, &token, "rust/jni/testkit/Adder").unwrap();
+                assert_eq!(
+                    class.get_name(&token).unwrap().unwrap().as_string(&token),
+                    "rust.jni.testkit.Adder"
+                );
+                // Safe because we ensure correct arguments and return type.
+                let result = unsafe {
+                    call_static_method::<Adder, _, _, fn(i32, i32) -> i32>(
+                        env,
+                        &token,
+                        "add\0",
+                        (2, 3),
+                    )
+                }
+                .unwrap();
+                (result, token)
+            },
+        );
+        assert_eq!(result, 5);
+    }
+
+    #[test]
+    fn same_name() {
+        // Each call has it's own class loader, so classes can be redefined.
+        for value in &[1, 2] {
+            let source = format!(
+                "package rust.jni.testkit; public class Constant {{ public static int value() {{ return {}; }} }}",
+                value
+            );
+            let result = compile_and_run(&source, |env, token| {
+                // Safe because we ensure correct arguments and return type.
+                let result = unsafe {
+                    call_static_method::<Constant, _, _, fn() -> i32>(env, &token, "value\0", ())
+                }
+                .unwrap();
+                (result, token)
+            });
+            assert_eq!(result, *value);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to compile the Java source")]
+    fn compilation_error() {
+        compile_and_run("public class Broken { int }", |_, token| ((), token));
+    }
+}